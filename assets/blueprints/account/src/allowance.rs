@@ -0,0 +1,73 @@
+use scrypto::prelude::*;
+
+/// A standing approval for the holder of a badge to pull a resource from an account, up to a fixed
+/// amount per window of epochs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode, Describe)]
+pub struct Allowance {
+    pub spender_badge: NonFungibleAddress,
+    pub resource_address: ResourceAddress,
+    pub per_period_amount: Decimal,
+    pub period_epochs: u64,
+    /// The first epoch of the current window
+    pub period_start: u64,
+    /// The amount pulled within the current window
+    pub consumed: Decimal,
+    pub revoked: bool,
+}
+
+impl Allowance {
+    pub fn new(
+        spender_badge: NonFungibleAddress,
+        resource_address: ResourceAddress,
+        per_period_amount: Decimal,
+        period_epochs: u64,
+        current_epoch: u64,
+    ) -> Self {
+        assert!(
+            period_epochs > 0,
+            "Allowance period must be at least one epoch"
+        );
+        assert!(
+            !per_period_amount.is_negative(),
+            "Allowance amount must not be negative"
+        );
+
+        Self {
+            spender_badge,
+            resource_address,
+            per_period_amount,
+            period_epochs,
+            period_start: current_epoch,
+            consumed: Decimal::zero(),
+            revoked: false,
+        }
+    }
+
+    pub fn revoke(&mut self) {
+        self.revoked = true;
+    }
+
+    /// Records a pull of the given amount at the given epoch, moving to a new window first if the
+    /// current one has elapsed.
+    ///
+    /// # Panics
+    /// Panics if the allowance has been revoked, or if the amount would exceed what is left of
+    /// the allowance in the current window.
+    pub fn consume(&mut self, amount: Decimal, current_epoch: u64) {
+        assert!(!self.revoked, "Allowance revoked");
+        assert!(!amount.is_negative(), "Pull amount must not be negative");
+
+        let elapsed = current_epoch.saturating_sub(self.period_start);
+        if elapsed >= self.period_epochs {
+            self.period_start = current_epoch - elapsed % self.period_epochs;
+            self.consumed = Decimal::zero();
+        }
+
+        assert!(
+            self.consumed + amount <= self.per_period_amount,
+            "Allowance exceeded"
+        );
+        self.consumed += amount;
+    }
+}
@@ -0,0 +1,24 @@
+use scrypto::prelude::*;
+
+/// Whether a resource is on an account's allowlist or denylist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode, Describe)]
+pub enum ResourceDepositRule {
+    Allowed,
+    Denied,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountError {
+    /// The account's deposit rules don't accept this resource.
+    DepositNotAllowed(ResourceAddress),
+}
+
+/// Whether an account in the given mode accepts a resource with the given rule, if any.
+pub fn is_deposit_allowed(mode: AccountDepositMode, rule: Option<ResourceDepositRule>) -> bool {
+    match mode {
+        AccountDepositMode::AllowAll => true,
+        AccountDepositMode::DenyAllExceptAllowlist => rule == Some(ResourceDepositRule::Allowed),
+        AccountDepositMode::AllowAllExceptDenylist => rule != Some(ResourceDepositRule::Denied),
+    }
+}
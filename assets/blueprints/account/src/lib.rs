@@ -1,14 +1,28 @@
 use scrypto::prelude::*;
 
+mod allowance;
+mod deposit_rules;
+
+pub use allowance::Allowance;
+pub use deposit_rules::{AccountError, ResourceDepositRule};
+
 blueprint! {
     struct Account {
         vaults: KeyValueStore<ResourceAddress, Vault>,
+        allowances: KeyValueStore<u64, Allowance>,
+        next_allowance_id: u64,
+        deposit_mode: AccountDepositMode,
+        resource_deposit_rules: KeyValueStore<ResourceAddress, ResourceDepositRule>,
     }
 
     impl Account {
         pub fn create(withdraw_rule: AccessRule) -> AccountComponent {
             let mut account = Self {
                 vaults: KeyValueStore::new(),
+                allowances: KeyValueStore::new(),
+                next_allowance_id: 0,
+                deposit_mode: AccountDepositMode::AllowAll,
+                resource_deposit_rules: KeyValueStore::new(),
             }
             .instantiate();
 
@@ -16,6 +30,8 @@ blueprint! {
                 .method("balance", rule!(allow_all), LOCKED)
                 .method("deposit", rule!(allow_all), LOCKED)
                 .method("deposit_batch", rule!(allow_all), LOCKED)
+                .method("try_deposit_or_refund", rule!(allow_all), LOCKED)
+                .method("pull", rule!(allow_all), LOCKED)
                 .default(withdraw_rule.clone(), MUTABLE(withdraw_rule));
             account.add_access_check(access_rules);
 
@@ -25,6 +41,10 @@ blueprint! {
         fn internal_new(withdraw_rule: AccessRule, bucket: Option<Bucket>) -> ComponentAddress {
             let mut account = Self {
                 vaults: KeyValueStore::new(),
+                allowances: KeyValueStore::new(),
+                next_allowance_id: 0,
+                deposit_mode: AccountDepositMode::AllowAll,
+                resource_deposit_rules: KeyValueStore::new(),
             }
             .instantiate();
 
@@ -37,6 +57,8 @@ blueprint! {
                 .method("balance", rule!(allow_all), LOCKED)
                 .method("deposit", rule!(allow_all), LOCKED)
                 .method("deposit_batch", rule!(allow_all), LOCKED)
+                .method("try_deposit_or_refund", rule!(allow_all), LOCKED)
+                .method("pull", rule!(allow_all), LOCKED)
                 .default(withdraw_rule.clone(), MUTABLE(withdraw_rule));
             account.add_access_check(access_rules);
 
@@ -79,15 +101,15 @@ blueprint! {
         }
 
         /// Deposits resource into this account.
+        ///
+        /// Fails with `AccountError::DepositNotAllowed` if the deposit rules don't accept the
+        /// resource.
         pub fn deposit(&mut self, bucket: Bucket) {
             let resource_address = bucket.resource_address();
-            if self.vaults.get(&resource_address).is_none() {
-                let v = Vault::with_bucket(bucket);
-                self.vaults.insert(resource_address, v);
-            } else {
-                let mut v = self.vaults.get_mut(&resource_address).unwrap();
-                v.put(bucket);
+            if !self.is_deposit_allowed(resource_address) {
+                panic!("{:?}", AccountError::DepositNotAllowed(resource_address));
             }
+            self.put(bucket);
         }
 
         /// Deposit a batch of buckets into this account
@@ -97,6 +119,53 @@ blueprint! {
             }
         }
 
+        /// Deposits resource into this account if the deposit rules accept it, otherwise returns
+        /// the bucket to the caller.
+        pub fn try_deposit_or_refund(&mut self, bucket: Bucket) -> Option<Bucket> {
+            if self.is_deposit_allowed(bucket.resource_address()) {
+                self.put(bucket);
+                None
+            } else {
+                Some(bucket)
+            }
+        }
+
+        /// Sets which resources `deposit` and `deposit_batch` accept.
+        pub fn set_deposit_mode(&mut self, deposit_mode: AccountDepositMode) {
+            self.deposit_mode = deposit_mode;
+        }
+
+        /// Adds a resource to the allowlist, removing it from the denylist.
+        pub fn allow_resource(&mut self, resource_address: ResourceAddress) {
+            self.resource_deposit_rules
+                .insert(resource_address, ResourceDepositRule::Allowed);
+        }
+
+        /// Adds a resource to the denylist, removing it from the allowlist.
+        pub fn deny_resource(&mut self, resource_address: ResourceAddress) {
+            self.resource_deposit_rules
+                .insert(resource_address, ResourceDepositRule::Denied);
+        }
+
+        fn is_deposit_allowed(&self, resource_address: ResourceAddress) -> bool {
+            let rule = self
+                .resource_deposit_rules
+                .get(&resource_address)
+                .map(|rule| *rule);
+            deposit_rules::is_deposit_allowed(self.deposit_mode, rule)
+        }
+
+        fn put(&mut self, bucket: Bucket) {
+            let resource_address = bucket.resource_address();
+            if self.vaults.get(&resource_address).is_none() {
+                let v = Vault::with_bucket(bucket);
+                self.vaults.insert(resource_address, v);
+            } else {
+                let mut v = self.vaults.get_mut(&resource_address).unwrap();
+                v.put(bucket);
+            }
+        }
+
         /// Withdraws resource from this account.
         pub fn withdraw(&mut self, resource_address: ResourceAddress) -> Bucket {
             let vault = self.vaults.get_mut(&resource_address);
@@ -167,6 +236,62 @@ blueprint! {
             self.withdraw_by_ids(ids, resource_address)
         }
 
+        /// Allows the holder of `spender_badge` to pull up to `per_period_amount` of a resource
+        /// from this account in every window of `period_epochs` epochs, starting from the current
+        /// epoch. Returns the id of the allowance.
+        pub fn create_allowance(
+            &mut self,
+            spender_badge: NonFungibleAddress,
+            resource_address: ResourceAddress,
+            per_period_amount: Decimal,
+            period_epochs: u64,
+        ) -> u64 {
+            let allowance = Allowance::new(
+                spender_badge,
+                resource_address,
+                per_period_amount,
+                period_epochs,
+                Runtime::current_epoch(),
+            );
+            let allowance_id = self.next_allowance_id;
+            self.allowances.insert(allowance_id, allowance);
+            self.next_allowance_id += 1;
+            allowance_id
+        }
+
+        /// Revokes an allowance, so that no further pulls can be made against it.
+        pub fn revoke_allowance(&mut self, allowance_id: u64) {
+            let mut allowance = self
+                .allowances
+                .get_mut(&allowance_id)
+                .expect("No such allowance in account");
+            allowance.revoke();
+        }
+
+        pub fn allowance(&self, allowance_id: u64) -> Option<Allowance> {
+            self.allowances.get(&allowance_id).map(|a| a.clone())
+        }
+
+        /// Withdraws resource from this account against an allowance.
+        ///
+        /// Callable by anyone, but the proof must contain the spender badge of the allowance.
+        pub fn pull(&mut self, allowance_id: u64, amount: Decimal, spender_proof: Proof) -> Bucket {
+            let resource_address = {
+                let mut allowance = self
+                    .allowances
+                    .get_mut(&allowance_id)
+                    .expect("No such allowance in account");
+                let spender_proof = spender_proof
+                    .validate_proof(allowance.spender_badge.clone())
+                    .expect("Proof does not contain the spender badge");
+                allowance.consume(amount, Runtime::current_epoch());
+                spender_proof.drop();
+                allowance.resource_address
+            };
+
+            self.withdraw_by_amount(amount, resource_address)
+        }
+
         /// Create proof of resource.
         pub fn create_proof(&self, resource_address: ResourceAddress) -> Proof {
             let vault = self.vaults.get(&resource_address);
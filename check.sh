@@ -13,6 +13,7 @@ cd "$(dirname "$0")"
 (cd scrypto-tests; cargo fmt --check --quiet)
 (cd scrypto-unit; cargo fmt --check --quiet)
 (cd radix-engine; cargo fmt --check --quiet)
+(cd radix-engine-ffi; cargo fmt --check --quiet)
 (cd radix-engine-stores; cargo fmt --check --quiet)
 (cd simulator; cargo fmt --check --quiet)
 (cd transaction; cargo fmt --check --quiet)
@@ -21,5 +22,12 @@ cd "$(dirname "$0")"
 (cd assets/blueprints/faucet; scrypto fmt --check --quiet)
 (cd examples; find . -maxdepth 1 -type d \( ! -name . \) -print0 | xargs -0 -n1 -I '{}' scrypto fmt --path {} --check --quiet)
 (cd radix-engine/tests/blueprints; find . -maxdepth 1 -type d \( ! -name . \) -print0 | xargs -0 -n1 -I '{}' scrypto fmt --path {} --check --quiet)
+(cd radix-engine-ffi; cbindgen --quiet --config cbindgen.toml | diff - radix_engine_ffi.h)
+
+# Blueprints must use `ScryptoHashMap`, as the iteration order of a `HashMap` is not deterministic
+if grep -rn --include=*.rs --exclude-dir=target "std::collections::.*HashMap" assets/blueprints examples radix-engine/tests/blueprints simulator/tests/blueprints; then
+    echo "Blueprints must use ScryptoHashMap instead of std::collections::HashMap"
+    exit 1
+fi
 
 echo "Code format check passed!"
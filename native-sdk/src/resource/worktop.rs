@@ -25,6 +25,15 @@ impl Worktop {
         }
     }
 
+    sys_env_native_fn! {
+        pub fn sys_take_or_default(resource_address: ResourceAddress, amount: Decimal) -> Bucket {
+            WorktopTakeOrDefaultInvocation {
+                resource_address,
+                amount,
+            }
+        }
+    }
+
     sys_env_native_fn! {
         pub fn sys_take_all(resource_address: ResourceAddress) -> Bucket {
             WorktopTakeAllInvocation {
@@ -57,6 +66,14 @@ impl Worktop {
         }
     }
 
+    sys_env_native_fn! {
+        pub fn sys_assert_contains_exact_amount(resource_address: ResourceAddress, amount: Decimal) -> () {
+            WorktopAssertContainsExactAmountInvocation {
+                resource_address, amount,
+            }
+        }
+    }
+
     sys_env_native_fn! {
         pub fn sys_assert_contains_non_fungibles(resource_address: ResourceAddress, ids: BTreeSet<NonFungibleId>) -> () {
             WorktopAssertContainsNonFungiblesInvocation {
@@ -65,6 +82,14 @@ impl Worktop {
         }
     }
 
+    sys_env_native_fn! {
+        pub fn sys_get_amount(resource_address: ResourceAddress) -> Decimal {
+            WorktopGetAmountInvocation {
+                resource_address,
+            }
+        }
+    }
+
     sys_env_native_fn! {
         pub fn sys_drain() -> Vec<Bucket> {
             WorktopDrainInvocation {}
@@ -27,6 +27,9 @@ pub const DEFAULT_MAX_TIP_PERCENTAGE: u8 = u8::MAX;
 /// The max epoch range
 pub const DEFAULT_MAX_EPOCH_RANGE: u64 = 100;
 
+/// The max number of instructions in a transaction manifest
+pub const DEFAULT_MAX_INSTRUCTIONS_PER_TRANSACTION: usize = 500;
+
 //==========================
 // Transaction execution
 //==========================
@@ -37,5 +40,14 @@ pub const DEFAULT_SYSTEM_LOAN: u32 = 1_000_000;
 /// The default max call depth, used by transaction executor.
 pub const DEFAULT_MAX_CALL_DEPTH: usize = 12;
 
+/// The default max number of nodes a call frame may own, used by transaction executor.
+pub const DEFAULT_MAX_OWNED_NODES_PER_FRAME: usize = 512;
+
+/// The default max number of proofs a single auth zone may hold, used by transaction executor.
+pub const DEFAULT_MAX_AUTH_ZONE_PROOFS: usize = 64;
+
+/// The default max number of invocations a transaction may make, used by transaction executor.
+pub const DEFAULT_MAX_SYSCALLS_PER_TRANSACTION: u32 = 10_000;
+
 /// The default cost unit price.
 pub const DEFAULT_COST_UNIT_PRICE: u128 = 100_000_000_000u128;
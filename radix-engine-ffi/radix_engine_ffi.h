@@ -0,0 +1,80 @@
+#ifndef RADIX_ENGINE_FFI_H
+#define RADIX_ENGINE_FFI_H
+
+/* Generated with cbindgen from radix-engine-ffi, do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// The call succeeded.
+#define RADIX_ENGINE_OK 0
+
+// A required pointer argument was null.
+#define RADIX_ENGINE_ERROR_NULL_POINTER 1
+
+// An argument could not be interpreted, eg a path which is not valid UTF-8.
+#define RADIX_ENGINE_ERROR_INVALID_ARGUMENT 2
+
+// The transaction could not be decoded, or failed static validation.
+#define RADIX_ENGINE_ERROR_INVALID_TRANSACTION 3
+
+// The receipt could not be encoded.
+#define RADIX_ENGINE_ERROR_ENCODING 4
+
+// The engine panicked.
+#define RADIX_ENGINE_ERROR_PANIC 5
+
+// A substate store, along with the state needed to execute transactions against it.
+typedef struct RadixEngineStore RadixEngineStore;
+
+// A byte buffer allocated by the engine.
+typedef struct RadixEngineBuffer {
+  uint8_t *data;
+  size_t len;
+} RadixEngineBuffer;
+
+// Creates a bootstrapped in-memory store, writing it to `out_store`.
+//
+// # Safety
+// `out_store` must be valid for writes.
+int32_t radix_engine_store_new_in_memory(RadixEngineStore **out_store);
+
+#if defined(RADIX_ENGINE_ROCKSDB)
+// Opens, or creates and bootstraps, a RocksDB store at the NUL-terminated UTF-8 `path`,
+// writing it to `out_store`.
+//
+// # Safety
+// `path` must point to a NUL-terminated string, and `out_store` must be valid for writes.
+int32_t radix_engine_store_new_rocksdb(const char *path, RadixEngineStore **out_store);
+#endif
+
+// Frees a store. Passing null is a no-op.
+//
+// # Safety
+// `store` must be null or have been created by a `radix_engine_store_new_*` function, and must
+// not be used afterwards.
+void radix_engine_store_free(RadixEngineStore *store);
+
+// Validates and executes an SBOR-encoded notarized transaction for the given network, committing
+// the result to the store. On success, the SBOR-encoded `TransactionReceipt` is written to
+// `out_receipt`, including when the transaction itself was rejected or failed.
+//
+// # Safety
+// `store` must have been created by a `radix_engine_store_new_*` function, `transaction` must be
+// valid for reads of `transaction_len` bytes, and `out_receipt` must be valid for writes.
+int32_t radix_engine_execute_transaction(RadixEngineStore *store,
+                                         uint8_t network_id,
+                                         const uint8_t *transaction,
+                                         size_t transaction_len,
+                                         RadixEngineBuffer *out_receipt);
+
+// Frees a buffer written by the engine. Passing an empty buffer is a no-op.
+//
+// # Safety
+// `buffer` must have been written by the engine, and must not be used afterwards.
+void radix_engine_buffer_free(RadixEngineBuffer buffer);
+
+#endif /* RADIX_ENGINE_FFI_H */
@@ -0,0 +1,258 @@
+//! A C-compatible interface for embedding the Radix Engine.
+//!
+//! All functions return one of the `RADIX_ENGINE_*` status codes, with results written through
+//! out-pointers. No panic ever crosses the boundary: a panic is reported as
+//! [`RADIX_ENGINE_ERROR_PANIC`], after which the store involved should be freed.
+//!
+//! Memory ownership:
+//! * A store created by `radix_engine_store_new_*` is owned by the caller, and must be released
+//!   with [`radix_engine_store_free`] exactly once.
+//! * A buffer written by the engine is owned by the caller, and must be released with
+//!   [`radix_engine_buffer_free`] exactly once.
+//! * Input bytes and strings are only borrowed for the duration of the call.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+
+use radix_engine::engine::ScryptoInterpreter;
+use radix_engine::ledger::{
+    ReadableSubstateStore, StateTreeStore, TypedInMemorySubstateStore, WriteableSubstateStore,
+};
+use radix_engine::transaction::{
+    execute_and_commit_transaction, ExecutionConfig, FeeReserveConfig, TransactionReceipt,
+};
+use radix_engine::types::*;
+use radix_engine::wasm::{
+    DefaultWasmEngine, InstructionCostRules, WasmInstrumenter, WasmMeteringConfig,
+};
+#[cfg(feature = "rocksdb")]
+use radix_engine_stores::rocks_db::RadixEngineDB;
+use transaction::model::Executable;
+use transaction::validation::{
+    HashStatus, NotarizedTransactionValidator, TestIntentHashManager, TransactionValidator,
+    ValidationConfig,
+};
+
+/// The call succeeded.
+pub const RADIX_ENGINE_OK: i32 = 0;
+/// A required pointer argument was null.
+pub const RADIX_ENGINE_ERROR_NULL_POINTER: i32 = 1;
+/// An argument could not be interpreted, eg a path which is not valid UTF-8.
+pub const RADIX_ENGINE_ERROR_INVALID_ARGUMENT: i32 = 2;
+/// The transaction could not be decoded, or failed static validation.
+pub const RADIX_ENGINE_ERROR_INVALID_TRANSACTION: i32 = 3;
+/// The receipt could not be encoded.
+pub const RADIX_ENGINE_ERROR_ENCODING: i32 = 4;
+/// The engine panicked.
+pub const RADIX_ENGINE_ERROR_PANIC: i32 = 5;
+
+/// A byte buffer allocated by the engine.
+#[repr(C)]
+pub struct RadixEngineBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+impl RadixEngineBuffer {
+    fn empty() -> Self {
+        Self {
+            data: ptr::null_mut(),
+            len: 0,
+        }
+    }
+
+    fn from_vec(bytes: Vec<u8>) -> Self {
+        let len = bytes.len();
+        let data = Box::into_raw(bytes.into_boxed_slice()) as *mut u8;
+        Self { data, len }
+    }
+}
+
+enum SubstateStore {
+    InMemory(TypedInMemorySubstateStore),
+    #[cfg(feature = "rocksdb")]
+    RocksDb(RadixEngineDB),
+}
+
+/// A substate store, along with the state needed to execute transactions against it.
+pub struct RadixEngineStore {
+    substate_store: SubstateStore,
+    intent_hash_manager: TestIntentHashManager,
+    scrypto_interpreter: ScryptoInterpreter<DefaultWasmEngine>,
+}
+
+impl RadixEngineStore {
+    fn new(substate_store: SubstateStore) -> Self {
+        Self {
+            substate_store,
+            intent_hash_manager: TestIntentHashManager::new(),
+            scrypto_interpreter: ScryptoInterpreter {
+                wasm_engine: DefaultWasmEngine::default(),
+                wasm_instrumenter: WasmInstrumenter::default(),
+                wasm_metering_config: WasmMeteringConfig::new(
+                    InstructionCostRules::tiered(1, 5, 10, 5000),
+                    1024,
+                ),
+            },
+        }
+    }
+
+    fn execute(&mut self, executable: &Executable) -> TransactionReceipt {
+        match &mut self.substate_store {
+            SubstateStore::InMemory(store) => {
+                execute_and_commit(store, &self.scrypto_interpreter, executable)
+            }
+            #[cfg(feature = "rocksdb")]
+            SubstateStore::RocksDb(store) => {
+                execute_and_commit(store, &self.scrypto_interpreter, executable)
+            }
+        }
+    }
+}
+
+fn execute_and_commit<S: ReadableSubstateStore + WriteableSubstateStore + StateTreeStore>(
+    substate_store: &mut S,
+    scrypto_interpreter: &ScryptoInterpreter<DefaultWasmEngine>,
+    executable: &Executable,
+) -> TransactionReceipt {
+    execute_and_commit_transaction(
+        substate_store,
+        scrypto_interpreter,
+        &FeeReserveConfig::default(),
+        &ExecutionConfig::default(),
+        executable,
+    )
+}
+
+/// Runs the closure, mapping any panic to `RADIX_ENGINE_ERROR_PANIC`.
+fn catch_panic<F: FnOnce() -> i32>(f: F) -> i32 {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or(RADIX_ENGINE_ERROR_PANIC)
+}
+
+/// Creates a bootstrapped in-memory store, writing it to `out_store`.
+///
+/// # Safety
+/// `out_store` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn radix_engine_store_new_in_memory(
+    out_store: *mut *mut RadixEngineStore,
+) -> i32 {
+    catch_panic(|| {
+        if out_store.is_null() {
+            return RADIX_ENGINE_ERROR_NULL_POINTER;
+        }
+        let store = RadixEngineStore::new(SubstateStore::InMemory(
+            TypedInMemorySubstateStore::with_bootstrap(),
+        ));
+        *out_store = Box::into_raw(Box::new(store));
+        RADIX_ENGINE_OK
+    })
+}
+
+/// Opens, or creates and bootstraps, a RocksDB store at the NUL-terminated UTF-8 `path`,
+/// writing it to `out_store`.
+///
+/// # Safety
+/// `path` must point to a NUL-terminated string, and `out_store` must be valid for writes.
+#[cfg(feature = "rocksdb")]
+#[no_mangle]
+pub unsafe extern "C" fn radix_engine_store_new_rocksdb(
+    path: *const std::os::raw::c_char,
+    out_store: *mut *mut RadixEngineStore,
+) -> i32 {
+    catch_panic(|| {
+        if path.is_null() || out_store.is_null() {
+            return RADIX_ENGINE_ERROR_NULL_POINTER;
+        }
+        let path = match std::ffi::CStr::from_ptr(path).to_str() {
+            Ok(path) => path,
+            Err(_) => return RADIX_ENGINE_ERROR_INVALID_ARGUMENT,
+        };
+        let store = RadixEngineStore::new(SubstateStore::RocksDb(RadixEngineDB::with_bootstrap(
+            path.into(),
+        )));
+        *out_store = Box::into_raw(Box::new(store));
+        RADIX_ENGINE_OK
+    })
+}
+
+/// Frees a store. Passing null is a no-op.
+///
+/// # Safety
+/// `store` must be null or have been created by a `radix_engine_store_new_*` function, and must
+/// not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn radix_engine_store_free(store: *mut RadixEngineStore) {
+    if !store.is_null() {
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| drop(Box::from_raw(store))));
+    }
+}
+
+/// Validates and executes an SBOR-encoded notarized transaction for the given network, committing
+/// the result to the store. On success, the SBOR-encoded `TransactionReceipt` is written to
+/// `out_receipt`, including when the transaction itself was rejected or failed.
+///
+/// # Safety
+/// `store` must have been created by a `radix_engine_store_new_*` function, `transaction` must be
+/// valid for reads of `transaction_len` bytes, and `out_receipt` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn radix_engine_execute_transaction(
+    store: *mut RadixEngineStore,
+    network_id: u8,
+    transaction: *const u8,
+    transaction_len: usize,
+    out_receipt: *mut RadixEngineBuffer,
+) -> i32 {
+    catch_panic(|| {
+        if store.is_null() || transaction.is_null() || out_receipt.is_null() {
+            return RADIX_ENGINE_ERROR_NULL_POINTER;
+        }
+        *out_receipt = RadixEngineBuffer::empty();
+        let store = &mut *store;
+        let transaction = slice::from_raw_parts(transaction, transaction_len);
+
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::default(network_id));
+        let transaction = match validator.check_length_and_decode_from_slice(transaction) {
+            Ok(transaction) => transaction,
+            Err(_) => return RADIX_ENGINE_ERROR_INVALID_TRANSACTION,
+        };
+        let intent_hash = match transaction.intent_hash() {
+            Ok(intent_hash) => intent_hash,
+            Err(_) => return RADIX_ENGINE_ERROR_INVALID_TRANSACTION,
+        };
+        let executable = match validator.validate(&transaction, &store.intent_hash_manager) {
+            Ok(executable) => executable,
+            Err(_) => return RADIX_ENGINE_ERROR_INVALID_TRANSACTION,
+        };
+
+        let receipt = store.execute(&executable);
+        if receipt.is_commit() {
+            store
+                .intent_hash_manager
+                .insert(intent_hash, HashStatus::Commited);
+        }
+
+        match scrypto_encode(&receipt) {
+            Ok(bytes) => {
+                *out_receipt = RadixEngineBuffer::from_vec(bytes);
+                RADIX_ENGINE_OK
+            }
+            Err(_) => RADIX_ENGINE_ERROR_ENCODING,
+        }
+    })
+}
+
+/// Frees a buffer written by the engine. Passing an empty buffer is a no-op.
+///
+/// # Safety
+/// `buffer` must have been written by the engine, and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn radix_engine_buffer_free(buffer: RadixEngineBuffer) {
+    if !buffer.data.is_null() {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+            buffer.data,
+            buffer.len,
+        )));
+    }
+}
@@ -0,0 +1,110 @@
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_ffi::*;
+use radix_engine_interface::core::NetworkDefinition;
+use std::ptr;
+use transaction::builder::{ManifestBuilder, TransactionBuilder};
+use transaction::model::{NotarizedTransaction, TransactionHeader};
+use transaction::signing::EcdsaSecp256k1PrivateKey;
+
+fn create_notarized_transaction(nonce: u64) -> NotarizedTransaction {
+    let sk_notary = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap();
+
+    TransactionBuilder::new()
+        .header(TransactionHeader {
+            version: 1,
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: 0,
+            end_epoch_exclusive: 100,
+            nonce,
+            notary_public_key: sk_notary.public_key().into(),
+            notary_as_signatory: false,
+            cost_unit_limit: 1_000_000,
+            tip_percentage: 5,
+        })
+        .manifest(
+            ManifestBuilder::new(&NetworkDefinition::simulator())
+                .lock_fee(FAUCET_COMPONENT, 10.into())
+                .clear_auth_zone()
+                .build(),
+        )
+        .notarize(&sk_notary)
+        .build()
+}
+
+unsafe fn execute(store: *mut RadixEngineStore, transaction: &[u8]) -> (i32, Vec<u8>) {
+    let mut receipt = RadixEngineBuffer {
+        data: ptr::null_mut(),
+        len: 0,
+    };
+    let code = radix_engine_execute_transaction(
+        store,
+        NetworkDefinition::simulator().id,
+        transaction.as_ptr(),
+        transaction.len(),
+        &mut receipt,
+    );
+    let bytes = if receipt.data.is_null() {
+        Vec::new()
+    } else {
+        std::slice::from_raw_parts(receipt.data, receipt.len).to_vec()
+    };
+    radix_engine_buffer_free(receipt);
+    (code, bytes)
+}
+
+#[test]
+fn test_execute_transaction_through_ffi() {
+    unsafe {
+        // Arrange
+        let mut store = ptr::null_mut();
+        assert_eq!(
+            radix_engine_store_new_in_memory(&mut store),
+            RADIX_ENGINE_OK
+        );
+        let transaction = create_notarized_transaction(5).to_bytes().unwrap();
+
+        // Act
+        let (code, bytes) = execute(store, &transaction);
+        let (replay_code, _) = execute(store, &transaction);
+        radix_engine_store_free(store);
+
+        // Assert
+        assert_eq!(code, RADIX_ENGINE_OK);
+        let receipt: TransactionReceipt = scrypto_decode(&bytes).unwrap();
+        receipt.expect_commit_success();
+        assert_eq!(replay_code, RADIX_ENGINE_ERROR_INVALID_TRANSACTION);
+    }
+}
+
+#[test]
+fn test_invalid_arguments_are_reported_as_error_codes() {
+    unsafe {
+        // Arrange
+        let mut store = ptr::null_mut();
+        assert_eq!(
+            radix_engine_store_new_in_memory(&mut store),
+            RADIX_ENGINE_OK
+        );
+
+        // Act
+        let (invalid_code, invalid_receipt) = execute(store, &[1, 2, 3]);
+        let null_code = radix_engine_execute_transaction(
+            store,
+            NetworkDefinition::simulator().id,
+            ptr::null(),
+            0,
+            ptr::null_mut(),
+        );
+        radix_engine_store_free(store);
+
+        // Assert
+        assert_eq!(invalid_code, RADIX_ENGINE_ERROR_INVALID_TRANSACTION);
+        assert!(invalid_receipt.is_empty());
+        assert_eq!(null_code, RADIX_ENGINE_ERROR_NULL_POINTER);
+        assert_eq!(
+            radix_engine_store_new_in_memory(ptr::null_mut()),
+            RADIX_ENGINE_ERROR_NULL_POINTER
+        );
+    }
+}
@@ -0,0 +1,23 @@
+/// The header is generated with `cbindgen --config cbindgen.toml --output radix_engine_ffi.h`,
+/// which `check.sh` verifies; this test catches exports which were added without regenerating it.
+#[test]
+fn test_header_declares_all_exports() {
+    let source = include_str!("../src/lib.rs");
+    let header = include_str!("../radix_engine_ffi.h");
+
+    for line in source.lines() {
+        let line = line.trim();
+        let name = if let Some(rest) = line.strip_prefix("pub unsafe extern \"C\" fn ") {
+            rest.split('(').next().unwrap()
+        } else if let Some(rest) = line.strip_prefix("pub const ") {
+            rest.split(':').next().unwrap()
+        } else {
+            continue;
+        };
+        assert!(
+            header.contains(name),
+            "{} is not declared in radix_engine_ffi.h",
+            name
+        );
+    }
+}
@@ -66,6 +66,8 @@ impl Bech32Encoder {
         let data = match system_address {
             SystemAddress::EpochManager(data) => data,
             SystemAddress::Clock(data) => data,
+            SystemAddress::Burner(data) => data,
+            SystemAddress::TimeLockedVault(data) => data,
         };
 
         self.encode_to_fmt(fmt, EntityType::system(system_address), data)
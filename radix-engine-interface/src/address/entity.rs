@@ -24,6 +24,12 @@ pub const ECDSA_SECP_256K1_VIRTUAL_ACCOUNT_COMPONENT_ADDRESS_ENTITY_ID: u8 = 0x0
 /// A unique identifier used in the addressing of a virtual Account Component Addresses.
 pub const EDDSA_ED_25519_VIRTUAL_ACCOUNT_COMPONENT_ADDRESS_ENTITY_ID: u8 = 0x07;
 
+/// A unique identifier used in the addressing of Burner System Addresses.
+pub const BURNER_SYSTEM_ADDRESS_ENTITY_ID: u8 = 0x08;
+
+/// A unique identifier used in the addressing of Time-Locked Vault System Addresses.
+pub const TIME_LOCKED_VAULT_SYSTEM_ADDRESS_ENTITY_ID: u8 = 0x09;
+
 /// An enum which represents the different addressable entities.
 #[derive(PartialEq, Eq)]
 pub enum EntityType {
@@ -35,6 +41,8 @@ pub enum EntityType {
     EddsaEd25519VirtualAccountComponent,
     EpochManager,
     Clock,
+    Burner,
+    TimeLockedVault,
 }
 
 impl EntityType {
@@ -61,6 +69,8 @@ impl EntityType {
         match address {
             SystemAddress::EpochManager(_) => Self::EpochManager,
             SystemAddress::Clock(_) => Self::Clock,
+            SystemAddress::Burner(_) => Self::Burner,
+            SystemAddress::TimeLockedVault(_) => Self::TimeLockedVault,
         }
     }
 
@@ -78,6 +88,8 @@ impl EntityType {
             }
             Self::EpochManager => EPOCH_MANAGER_SYSTEM_ADDRESS_ENTITY_ID,
             Self::Clock => CLOCK_SYSTEM_ADDRESS_ENTITY_ID,
+            Self::Burner => BURNER_SYSTEM_ADDRESS_ENTITY_ID,
+            Self::TimeLockedVault => TIME_LOCKED_VAULT_SYSTEM_ADDRESS_ENTITY_ID,
         }
     }
 }
@@ -99,6 +111,8 @@ impl TryFrom<u8> for EntityType {
             }
             EPOCH_MANAGER_SYSTEM_ADDRESS_ENTITY_ID => Ok(Self::EpochManager),
             CLOCK_SYSTEM_ADDRESS_ENTITY_ID => Ok(Self::Clock),
+            BURNER_SYSTEM_ADDRESS_ENTITY_ID => Ok(Self::Burner),
+            TIME_LOCKED_VAULT_SYSTEM_ADDRESS_ENTITY_ID => Ok(Self::TimeLockedVault),
             _ => Err(EntityTypeError::InvalidEntityTypeId(value)),
         }
     }
@@ -29,6 +29,8 @@ impl HrpSet {
             EntityType::EddsaEd25519VirtualAccountComponent => &self.account_component,
             EntityType::EpochManager => &self.system,
             EntityType::Clock => &self.system,
+            EntityType::Burner => &self.system,
+            EntityType::TimeLockedVault => &self.system,
         }
     }
 }
@@ -19,6 +19,12 @@ macro_rules! construct_address {
     (EntityType::Clock, $($bytes:expr),*) => {
         radix_engine_interface::model::SystemAddress::Clock([$($bytes),*])
     };
+    (EntityType::Burner, $($bytes:expr),*) => {
+        radix_engine_interface::model::SystemAddress::Burner([$($bytes),*])
+    };
+    (EntityType::TimeLockedVault, $($bytes:expr),*) => {
+        radix_engine_interface::model::SystemAddress::TimeLockedVault([$($bytes),*])
+    };
 }
 
 /// (Deprecated) Constructs an RE address.
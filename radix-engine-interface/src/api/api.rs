@@ -1,5 +1,5 @@
-use crate::api::types::ScryptoActor;
-use crate::crypto::Hash;
+use crate::api::types::{CallerInfo, ScryptoActor};
+use crate::crypto::{Hash, HashAlgorithm};
 use crate::model::*;
 use sbor::rust::fmt::Debug;
 use sbor::rust::string::String;
@@ -28,9 +28,22 @@ pub trait EngineApi<E: Debug> {
     fn sys_read(&mut self, lock_handle: LockHandle) -> Result<Vec<u8>, E>;
     fn sys_write(&mut self, lock_handle: LockHandle, buffer: Vec<u8>) -> Result<(), E>;
     fn sys_drop_lock(&mut self, lock_handle: LockHandle) -> Result<(), E>;
+    fn sys_batch_read_substates(
+        &mut self,
+        substates: Vec<(RENodeId, SubstateOffset)>,
+    ) -> Result<Vec<Vec<u8>>, E>;
+    fn sys_batch_write_substates(
+        &mut self,
+        substates: Vec<(RENodeId, SubstateOffset, Vec<u8>)>,
+    ) -> Result<(), E>;
     fn sys_get_actor(&mut self) -> Result<ScryptoActor, E>;
+    fn sys_get_caller(&mut self) -> Result<Option<CallerInfo>, E>;
     fn sys_generate_uuid(&mut self) -> Result<u128, E>;
     fn sys_get_transaction_hash(&mut self) -> Result<Hash, E>;
+    fn sys_get_current_epoch(&mut self) -> Result<u64, E>;
+    fn sys_get_virtual_proof_resources(&mut self) -> Result<Vec<ResourceAddress>, E>;
+    fn sys_emit_event(&mut self, payload: Vec<u8>) -> Result<(), E>;
+    fn sys_hash(&mut self, algorithm: HashAlgorithm, data: Vec<u8>) -> Result<Hash, E>;
 }
 
 pub trait LoggerApi<E: Debug> {
@@ -47,6 +60,15 @@ pub trait InvokableModel<E>:
     + Invokable<ClockSetCurrentTimeInvocation, E>
     + Invokable<ClockGetCurrentTimeInvocation, E>
     + Invokable<ClockCompareCurrentTimeInvocation, E>
+    + Invokable<BurnerCreateInvocation, E>
+    + Invokable<BurnerDepositInvocation, E>
+    + Invokable<BurnerDepositBatchInvocation, E>
+    + Invokable<TimeLockedVaultCreateInvocation, E>
+    + Invokable<TimeLockedVaultDepositInvocation, E>
+    + Invokable<TimeLockedVaultWithdrawInvocation, E>
+    + Invokable<TimeLockedVaultExtendLockInvocation, E>
+    + Invokable<TimeLockedVaultGetUnlockEpochInvocation, E>
+    + Invokable<TimeLockedVaultGetAmountInvocation, E>
     + Invokable<MetadataSetInvocation, E>
     + Invokable<MetadataGetInvocation, E>
     + Invokable<AccessRulesAddAccessCheckInvocation, E>
@@ -67,9 +89,12 @@ pub trait InvokableModel<E>:
     + Invokable<ComponentGlobalizeInvocation, E>
     + Invokable<ComponentGlobalizeWithOwnerInvocation, E>
     + Invokable<ComponentSetRoyaltyConfigInvocation, E>
+    + Invokable<ComponentSetAuthMutabilityInvocation, E>
     + Invokable<ComponentClaimRoyaltyInvocation, E>
+    + Invokable<ComponentStateSizeBytesInvocation, E>
     + Invokable<PackageSetRoyaltyConfigInvocation, E>
     + Invokable<PackageClaimRoyaltyInvocation, E>
+    + Invokable<PackageGetBuildFingerprintInvocation, E>
     + Invokable<PackagePublishInvocation, E>
     + Invokable<PackagePublishWithOwnerInvocation, E>
     + Invokable<BucketTakeInvocation, E>
@@ -84,6 +109,7 @@ pub trait InvokableModel<E>:
     + Invokable<ProofGetAmountInvocation, E>
     + Invokable<ProofGetNonFungibleIdsInvocation, E>
     + Invokable<ProofGetResourceAddressInvocation, E>
+    + Invokable<ProofIsValidInvocation, E>
     + Invokable<ResourceManagerBucketBurnInvocation, E>
     + Invokable<ResourceManagerCreateInvocation, E>
     + Invokable<ResourceManagerCreateWithOwnerInvocation, E>
@@ -95,12 +121,18 @@ pub trait InvokableModel<E>:
     + Invokable<ResourceManagerMintInvocation, E>
     + Invokable<ResourceManagerGetResourceTypeInvocation, E>
     + Invokable<ResourceManagerGetTotalSupplyInvocation, E>
+    + Invokable<ResourceManagerGetSummaryInvocation, E>
+    + Invokable<ResourceManagerGetHolderVaultCountInvocation, E>
+    + Invokable<ResourceManagerGetAmountMintedInvocation, E>
+    + Invokable<ResourceManagerGetAmountBurnedInvocation, E>
+    + Invokable<ResourceManagerCreateSyntheticProofInvocation, E>
     + Invokable<ResourceManagerUpdateNonFungibleDataInvocation, E>
     + Invokable<ResourceManagerNonFungibleExistsInvocation, E>
     + Invokable<ResourceManagerGetNonFungibleInvocation, E>
     + Invokable<VaultTakeInvocation, E>
     + Invokable<VaultPutInvocation, E>
     + Invokable<VaultLockFeeInvocation, E>
+    + Invokable<VaultLockFeeWithCallbackInvocation, E>
     + Invokable<VaultTakeNonFungiblesInvocation, E>
     + Invokable<VaultGetAmountInvocation, E>
     + Invokable<VaultGetResourceAddressInvocation, E>
@@ -112,11 +144,14 @@ pub trait InvokableModel<E>:
     + Invokable<VaultRecallNonFungiblesInvocation, E>
     + Invokable<WorktopPutInvocation, E>
     + Invokable<WorktopTakeAmountInvocation, E>
+    + Invokable<WorktopTakeOrDefaultInvocation, E>
     + Invokable<WorktopTakeAllInvocation, E>
     + Invokable<WorktopTakeNonFungiblesInvocation, E>
     + Invokable<WorktopAssertContainsInvocation, E>
     + Invokable<WorktopAssertContainsAmountInvocation, E>
+    + Invokable<WorktopAssertContainsExactAmountInvocation, E>
     + Invokable<WorktopAssertContainsNonFungiblesInvocation, E>
+    + Invokable<WorktopGetAmountInvocation, E>
     + Invokable<WorktopDrainInvocation, E>
 {
 }
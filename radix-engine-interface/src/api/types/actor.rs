@@ -4,6 +4,7 @@ use sbor::*;
 use crate::api::types::ComponentId;
 use crate::model::*;
 use crate::scrypto;
+use crate::Describe;
 
 #[derive(Debug, Clone)]
 #[scrypto(TypeId, Encode, Decode)]
@@ -57,3 +58,11 @@ impl ScryptoActor {
         }
     }
 }
+
+/// The entity which directly invoked the running actor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode, Describe)]
+pub enum CallerInfo {
+    Component(ComponentAddress),
+    Blueprint(PackageAddress, String),
+}
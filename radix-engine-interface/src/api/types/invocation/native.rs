@@ -1,6 +1,9 @@
 use crate::api::types::*;
+use crate::constants::*;
 use crate::scrypto;
 use crate::Describe;
+use sbor::rust::borrow::ToOwned;
+use sbor::rust::str::FromStr;
 
 // Native function identifier used by transaction model
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
@@ -41,6 +44,8 @@ pub enum NativeMethod {
     Proof(ProofMethod),
     Worktop(WorktopMethod),
     Clock(ClockMethod),
+    Burner(BurnerMethod),
+    TimeLockedVault(TimeLockedVaultMethod),
 }
 
 // Native method enum used by Kernel SystemAPI and WASM
@@ -53,6 +58,224 @@ pub enum NativeFunction {
     Package(PackageFunction),
     TransactionProcessor(TransactionProcessorFunction),
     Clock(ClockFunction),
+    Burner(BurnerFunction),
+    TimeLockedVault(TimeLockedVaultFunction),
+}
+
+/// Represents an error when parsing a native function or method.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseNativeFnError {
+    InvalidFormat(String),
+    UnknownIdent(String),
+}
+
+#[cfg(not(feature = "alloc"))]
+impl std::error::Error for ParseNativeFnError {}
+
+#[cfg(not(feature = "alloc"))]
+impl fmt::Display for ParseNativeFnError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl NativeMethod {
+    pub fn blueprint_name(&self) -> &'static str {
+        match self {
+            NativeMethod::AccessRulesChain(..) => "AccessRulesChain",
+            NativeMethod::Component(..) => "Component",
+            NativeMethod::Package(..) => "Package",
+            NativeMethod::Metadata(..) => "Metadata",
+            NativeMethod::EpochManager(..) => "EpochManager",
+            NativeMethod::AuthZoneStack(..) => "AuthZoneStack",
+            NativeMethod::ResourceManager(..) => "ResourceManager",
+            NativeMethod::Bucket(..) => "Bucket",
+            NativeMethod::Vault(..) => "Vault",
+            NativeMethod::Proof(..) => "Proof",
+            NativeMethod::Worktop(..) => "Worktop",
+            NativeMethod::Clock(..) => "Clock",
+            NativeMethod::Burner(..) => BURNER_BLUEPRINT,
+            NativeMethod::TimeLockedVault(..) => TIME_LOCKED_VAULT_BLUEPRINT,
+        }
+    }
+
+    pub fn method_name(&self) -> &'static str {
+        match self {
+            NativeMethod::AccessRulesChain(method) => method.into(),
+            NativeMethod::Component(method) => method.into(),
+            NativeMethod::Package(method) => method.into(),
+            NativeMethod::Metadata(method) => method.into(),
+            NativeMethod::EpochManager(method) => method.into(),
+            NativeMethod::AuthZoneStack(method) => method.into(),
+            NativeMethod::ResourceManager(method) => method.into(),
+            NativeMethod::Bucket(method) => method.into(),
+            NativeMethod::Vault(method) => method.into(),
+            NativeMethod::Proof(method) => method.into(),
+            NativeMethod::Worktop(method) => method.into(),
+            NativeMethod::Clock(method) => method.into(),
+            NativeMethod::Burner(method) => method.into(),
+            NativeMethod::TimeLockedVault(method) => method.into(),
+        }
+    }
+
+    pub fn from_ident(blueprint_name: &str, method_name: &str) -> Option<Self> {
+        match blueprint_name {
+            "AccessRulesChain" => AccessRulesChainMethod::from_str(method_name)
+                .ok()
+                .map(NativeMethod::AccessRulesChain),
+            "Component" => ComponentMethod::from_str(method_name)
+                .ok()
+                .map(NativeMethod::Component),
+            "Package" => PackageMethod::from_str(method_name)
+                .ok()
+                .map(NativeMethod::Package),
+            "Metadata" => MetadataMethod::from_str(method_name)
+                .ok()
+                .map(NativeMethod::Metadata),
+            "EpochManager" => EpochManagerMethod::from_str(method_name)
+                .ok()
+                .map(NativeMethod::EpochManager),
+            "AuthZoneStack" => AuthZoneStackMethod::from_str(method_name)
+                .ok()
+                .map(NativeMethod::AuthZoneStack),
+            "ResourceManager" => ResourceManagerMethod::from_str(method_name)
+                .ok()
+                .map(NativeMethod::ResourceManager),
+            "Bucket" => BucketMethod::from_str(method_name)
+                .ok()
+                .map(NativeMethod::Bucket),
+            "Vault" => VaultMethod::from_str(method_name)
+                .ok()
+                .map(NativeMethod::Vault),
+            "Proof" => ProofMethod::from_str(method_name)
+                .ok()
+                .map(NativeMethod::Proof),
+            "Worktop" => WorktopMethod::from_str(method_name)
+                .ok()
+                .map(NativeMethod::Worktop),
+            "Clock" => ClockMethod::from_str(method_name)
+                .ok()
+                .map(NativeMethod::Clock),
+            BURNER_BLUEPRINT => BurnerMethod::from_str(method_name)
+                .ok()
+                .map(NativeMethod::Burner),
+            TIME_LOCKED_VAULT_BLUEPRINT => TimeLockedVaultMethod::from_str(method_name)
+                .ok()
+                .map(NativeMethod::TimeLockedVault),
+            _ => None,
+        }
+    }
+}
+
+/// Formats a native method as `Blueprint::method_name`, e.g. `ResourceManager::mint`.
+///
+/// This format is stable and round-trips through [`FromStr`].
+impl fmt::Display for NativeMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}::{}", self.blueprint_name(), self.method_name())
+    }
+}
+
+impl FromStr for NativeMethod {
+    type Err = ParseNativeFnError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (blueprint_name, method_name) = s
+            .split_once("::")
+            .ok_or(ParseNativeFnError::InvalidFormat(s.to_owned()))?;
+        Self::from_ident(blueprint_name, method_name)
+            .ok_or(ParseNativeFnError::UnknownIdent(s.to_owned()))
+    }
+}
+
+impl NativeFunction {
+    pub fn blueprint_name(&self) -> &'static str {
+        match self {
+            NativeFunction::Component(..) => "Component",
+            NativeFunction::EpochManager(..) => EPOCH_MANAGER_BLUEPRINT,
+            NativeFunction::ResourceManager(..) => RESOURCE_MANAGER_BLUEPRINT,
+            NativeFunction::Package(..) => PACKAGE_BLUEPRINT,
+            NativeFunction::TransactionProcessor(..) => TRANSACTION_PROCESSOR_BLUEPRINT,
+            NativeFunction::Clock(..) => CLOCK_BLUEPRINT,
+            NativeFunction::Burner(..) => BURNER_BLUEPRINT,
+            NativeFunction::TimeLockedVault(..) => TIME_LOCKED_VAULT_BLUEPRINT,
+        }
+    }
+
+    pub fn function_name(&self) -> &'static str {
+        match self {
+            NativeFunction::Component(function) => function.into(),
+            NativeFunction::EpochManager(function) => function.into(),
+            NativeFunction::ResourceManager(function) => function.into(),
+            NativeFunction::Package(function) => function.into(),
+            NativeFunction::TransactionProcessor(function) => function.into(),
+            NativeFunction::Clock(function) => function.into(),
+            NativeFunction::Burner(function) => function.into(),
+            NativeFunction::TimeLockedVault(function) => function.into(),
+        }
+    }
+
+    pub fn from_ident(blueprint_name: &str, function_name: &str) -> Option<Self> {
+        match blueprint_name {
+            "Component" => ComponentFunction::from_str(function_name)
+                .ok()
+                .map(NativeFunction::Component),
+            EPOCH_MANAGER_BLUEPRINT => EpochManagerFunction::from_str(function_name)
+                .ok()
+                .map(NativeFunction::EpochManager),
+            RESOURCE_MANAGER_BLUEPRINT => ResourceManagerFunction::from_str(function_name)
+                .ok()
+                .map(NativeFunction::ResourceManager),
+            PACKAGE_BLUEPRINT => PackageFunction::from_str(function_name)
+                .ok()
+                .map(NativeFunction::Package),
+            TRANSACTION_PROCESSOR_BLUEPRINT => {
+                TransactionProcessorFunction::from_str(function_name)
+                    .ok()
+                    .map(NativeFunction::TransactionProcessor)
+            }
+            CLOCK_BLUEPRINT => ClockFunction::from_str(function_name)
+                .ok()
+                .map(NativeFunction::Clock),
+            BURNER_BLUEPRINT => BurnerFunction::from_str(function_name)
+                .ok()
+                .map(NativeFunction::Burner),
+            TIME_LOCKED_VAULT_BLUEPRINT => TimeLockedVaultFunction::from_str(function_name)
+                .ok()
+                .map(NativeFunction::TimeLockedVault),
+            _ => None,
+        }
+    }
+}
+
+/// Formats a native function as `Blueprint::function_name`, e.g. `ResourceManager::create`.
+///
+/// This format is stable and round-trips through [`FromStr`].
+impl fmt::Display for NativeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}::{}", self.blueprint_name(), self.function_name())
+    }
+}
+
+impl FromStr for NativeFunction {
+    type Err = ParseNativeFnError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (blueprint_name, function_name) = s
+            .split_once("::")
+            .ok_or(ParseNativeFnError::InvalidFormat(s.to_owned()))?;
+        Self::from_ident(blueprint_name, function_name)
+            .ok_or(ParseNativeFnError::UnknownIdent(s.to_owned()))
+    }
+}
+
+impl fmt::Display for NativeFn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NativeFn::Method(method) => method.fmt(f),
+            NativeFn::Function(function) => function.fmt(f),
+        }
+    }
 }
 
 #[derive(
@@ -166,6 +389,8 @@ pub enum EpochManagerFunction {
 pub enum ComponentMethod {
     SetRoyaltyConfig,
     ClaimRoyalty,
+    SetAuthMutability,
+    StateSizeBytes,
 }
 
 #[derive(
@@ -188,6 +413,7 @@ pub enum ComponentMethod {
 pub enum PackageMethod {
     SetRoyaltyConfig,
     ClaimRoyalty,
+    GetBuildFingerprint,
 }
 
 #[derive(
@@ -292,6 +518,11 @@ pub enum ResourceManagerMethod {
     NonFungibleExists,
     CreateBucket,
     CreateVault,
+    GetSummary,
+    CreateSyntheticProof,
+    GetHolderVaultCount,
+    GetAmountMinted,
+    GetAmountBurned,
 }
 
 #[derive(
@@ -351,6 +582,7 @@ pub enum VaultMethod {
     CreateProofByIds,
     Recall,
     RecallNonFungibles,
+    LockFeeWithCallback,
 }
 
 #[derive(
@@ -375,6 +607,7 @@ pub enum ProofMethod {
     GetAmount,
     GetNonFungibleIds,
     GetResourceAddress,
+    IsValid,
 }
 
 #[derive(
@@ -397,11 +630,14 @@ pub enum ProofMethod {
 pub enum WorktopMethod {
     TakeAll,
     TakeAmount,
+    TakeOrDefault,
     TakeNonFungibles,
     Put,
     AssertContains,
     AssertContainsAmount,
+    AssertContainsExactAmount,
     AssertContainsNonFungibles,
+    GetAmount,
     Drain,
 }
 
@@ -449,6 +685,95 @@ pub enum ClockMethod {
     CompareCurrentTime,
 }
 
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    EnumString,
+    EnumVariantNames,
+    IntoStaticStr,
+    AsRefStr,
+    Display,
+)]
+#[scrypto(TypeId, Encode, Decode, Describe)]
+#[strum(serialize_all = "snake_case")]
+pub enum BurnerFunction {
+    Create,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    EnumString,
+    EnumVariantNames,
+    IntoStaticStr,
+    AsRefStr,
+    Display,
+)]
+#[scrypto(TypeId, Encode, Decode, Describe)]
+#[strum(serialize_all = "snake_case")]
+pub enum BurnerMethod {
+    Deposit,
+    DepositBatch,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    EnumString,
+    EnumVariantNames,
+    IntoStaticStr,
+    AsRefStr,
+    Display,
+)]
+#[scrypto(TypeId, Encode, Decode, Describe)]
+#[strum(serialize_all = "snake_case")]
+pub enum TimeLockedVaultFunction {
+    Create,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    EnumString,
+    EnumVariantNames,
+    IntoStaticStr,
+    AsRefStr,
+    Display,
+)]
+#[scrypto(TypeId, Encode, Decode, Describe)]
+#[strum(serialize_all = "snake_case")]
+pub enum TimeLockedVaultMethod {
+    Deposit,
+    Withdraw,
+    ExtendLock,
+    GetUnlockEpoch,
+    GetAmount,
+}
+
 #[derive(
     Debug,
     Clone,
@@ -491,3 +816,155 @@ pub enum PackageFunction {
 pub enum TransactionProcessorFunction {
     Run,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every native method, built from the variant names of each blueprint's method enum.
+    /// The match in `blueprint_of` fails to compile when a blueprint is added but not listed here.
+    fn all_native_methods() -> Vec<NativeMethod> {
+        fn blueprint_of(method: NativeMethod) -> &'static [&'static str] {
+            match method {
+                NativeMethod::AccessRulesChain(..) => AccessRulesChainMethod::VARIANTS,
+                NativeMethod::Component(..) => ComponentMethod::VARIANTS,
+                NativeMethod::Package(..) => PackageMethod::VARIANTS,
+                NativeMethod::Metadata(..) => MetadataMethod::VARIANTS,
+                NativeMethod::EpochManager(..) => EpochManagerMethod::VARIANTS,
+                NativeMethod::AuthZoneStack(..) => AuthZoneStackMethod::VARIANTS,
+                NativeMethod::ResourceManager(..) => ResourceManagerMethod::VARIANTS,
+                NativeMethod::Bucket(..) => BucketMethod::VARIANTS,
+                NativeMethod::Vault(..) => VaultMethod::VARIANTS,
+                NativeMethod::Proof(..) => ProofMethod::VARIANTS,
+                NativeMethod::Worktop(..) => WorktopMethod::VARIANTS,
+                NativeMethod::Clock(..) => ClockMethod::VARIANTS,
+                NativeMethod::Burner(..) => BurnerMethod::VARIANTS,
+                NativeMethod::TimeLockedVault(..) => TimeLockedVaultMethod::VARIANTS,
+            }
+        }
+        let representatives = [
+            NativeMethod::AccessRulesChain(AccessRulesChainMethod::GetLength),
+            NativeMethod::Component(ComponentMethod::ClaimRoyalty),
+            NativeMethod::Package(PackageMethod::ClaimRoyalty),
+            NativeMethod::Metadata(MetadataMethod::Get),
+            NativeMethod::EpochManager(EpochManagerMethod::GetCurrentEpoch),
+            NativeMethod::AuthZoneStack(AuthZoneStackMethod::Pop),
+            NativeMethod::ResourceManager(ResourceManagerMethod::Mint),
+            NativeMethod::Bucket(BucketMethod::Take),
+            NativeMethod::Vault(VaultMethod::Take),
+            NativeMethod::Proof(ProofMethod::Clone),
+            NativeMethod::Worktop(WorktopMethod::Drain),
+            NativeMethod::Clock(ClockMethod::GetCurrentTime),
+            NativeMethod::Burner(BurnerMethod::Deposit),
+            NativeMethod::TimeLockedVault(TimeLockedVaultMethod::Withdraw),
+        ];
+        representatives
+            .into_iter()
+            .flat_map(|representative| {
+                blueprint_of(representative).iter().map(move |method_name| {
+                    NativeMethod::from_ident(representative.blueprint_name(), method_name).unwrap()
+                })
+            })
+            .collect()
+    }
+
+    /// Every native function, built the same way as [`all_native_methods`].
+    fn all_native_functions() -> Vec<NativeFunction> {
+        fn blueprint_of(function: NativeFunction) -> &'static [&'static str] {
+            match function {
+                NativeFunction::Component(..) => ComponentFunction::VARIANTS,
+                NativeFunction::EpochManager(..) => EpochManagerFunction::VARIANTS,
+                NativeFunction::ResourceManager(..) => ResourceManagerFunction::VARIANTS,
+                NativeFunction::Package(..) => PackageFunction::VARIANTS,
+                NativeFunction::TransactionProcessor(..) => TransactionProcessorFunction::VARIANTS,
+                NativeFunction::Clock(..) => ClockFunction::VARIANTS,
+                NativeFunction::Burner(..) => BurnerFunction::VARIANTS,
+                NativeFunction::TimeLockedVault(..) => TimeLockedVaultFunction::VARIANTS,
+            }
+        }
+        let representatives = [
+            NativeFunction::Component(ComponentFunction::Globalize),
+            NativeFunction::EpochManager(EpochManagerFunction::Create),
+            NativeFunction::ResourceManager(ResourceManagerFunction::Create),
+            NativeFunction::Package(PackageFunction::Publish),
+            NativeFunction::TransactionProcessor(TransactionProcessorFunction::Run),
+            NativeFunction::Clock(ClockFunction::Create),
+            NativeFunction::Burner(BurnerFunction::Create),
+            NativeFunction::TimeLockedVault(TimeLockedVaultFunction::Create),
+        ];
+        representatives
+            .into_iter()
+            .flat_map(|representative| {
+                blueprint_of(representative)
+                    .iter()
+                    .map(move |function_name| {
+                        NativeFunction::from_ident(representative.blueprint_name(), function_name)
+                            .unwrap()
+                    })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn native_method_display_round_trips() {
+        let methods = all_native_methods();
+        assert!(methods.contains(&NativeMethod::Vault(VaultMethod::LockFee)));
+        for method in methods {
+            let string = method.to_string();
+            assert_eq!(NativeMethod::from_str(&string), Ok(method), "{}", string);
+        }
+    }
+
+    #[test]
+    fn native_function_display_round_trips() {
+        let functions = all_native_functions();
+        assert!(functions.contains(&NativeFunction::ResourceManager(
+            ResourceManagerFunction::BurnBucket
+        )));
+        for function in functions {
+            let string = function.to_string();
+            assert_eq!(
+                NativeFunction::from_str(&string),
+                Ok(function),
+                "{}",
+                string
+            );
+        }
+    }
+
+    #[test]
+    fn native_fn_uses_blueprint_and_ident_format() {
+        assert_eq!(
+            NativeMethod::ResourceManager(ResourceManagerMethod::Mint).to_string(),
+            "ResourceManager::mint"
+        );
+        assert_eq!(
+            NativeFunction::ResourceManager(ResourceManagerFunction::BurnBucket).to_string(),
+            "ResourceManager::burn_bucket"
+        );
+        assert_eq!(
+            NativeFn::Method(NativeMethod::Vault(VaultMethod::LockFee)).to_string(),
+            "Vault::lock_fee"
+        );
+    }
+
+    #[test]
+    fn parsing_malformed_native_fn_fails() {
+        assert_eq!(
+            NativeMethod::from_str("ResourceManager.mint"),
+            Err(ParseNativeFnError::InvalidFormat(
+                "ResourceManager.mint".to_owned()
+            ))
+        );
+        assert_eq!(
+            NativeMethod::from_str("ResourceManager::fly"),
+            Err(ParseNativeFnError::UnknownIdent(
+                "ResourceManager::fly".to_owned()
+            ))
+        );
+        assert_eq!(
+            NativeFunction::from_str("Vault::create"),
+            Err(ParseNativeFnError::UnknownIdent("Vault::create".to_owned()))
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use super::*;
+use crate::address::{AddressDisplayContext, AddressError};
 use crate::model::*;
 use crate::scrypto;
+use utils::ContextualDisplay;
 
 // TODO: Remove and replace with real HeapRENodes
 #[derive(Debug, Clone)]
@@ -24,6 +26,8 @@ pub enum RENodeType {
     GlobalPackage,
     GlobalEpochManager,
     GlobalClock,
+    GlobalBurner,
+    GlobalTimeLockedVault,
     KeyValueStore,
     NonFungibleStore,
     Component,
@@ -32,6 +36,8 @@ pub enum RENodeType {
     Package,
     EpochManager,
     Clock,
+    Burner,
+    TimeLockedVault,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
@@ -51,6 +57,8 @@ pub enum RENodeId {
     Package(PackageId),
     EpochManager(EpochManagerId),
     Clock(ClockId),
+    Burner(BurnerId),
+    TimeLockedVault(TimeLockedVaultId),
 }
 
 impl Into<[u8; 36]> for RENodeId {
@@ -64,11 +72,48 @@ impl Into<[u8; 36]> for RENodeId {
             RENodeId::Package(id) => id,
             RENodeId::EpochManager(id) => id,
             RENodeId::Clock(id) => id,
+            RENodeId::Burner(id) => id,
+            RENodeId::TimeLockedVault(id) => id,
             _ => panic!("Not a stored id"),
         }
     }
 }
 
+impl<'a> ContextualDisplay<AddressDisplayContext<'a>> for RENodeId {
+    type Error = AddressError;
+
+    fn contextual_format<F: fmt::Write>(
+        &self,
+        f: &mut F,
+        context: &AddressDisplayContext<'a>,
+    ) -> Result<(), Self::Error> {
+        match self {
+            RENodeId::Bucket(id) => write!(f, "Bucket[{}]", id),
+            RENodeId::Proof(id) => write!(f, "Proof[{}]", id),
+            RENodeId::AuthZoneStack(id) => write!(f, "AuthZoneStack[{}]", id),
+            RENodeId::FeeReserve(id) => write!(f, "FeeReserve[{}]", id),
+            RENodeId::Worktop => write!(f, "Worktop"),
+            RENodeId::Global(address) => {
+                f.write_str("Global[")
+                    .map_err(|err| AddressError::FormatError(err))?;
+                address.contextual_format(f, context)?;
+                f.write_str("]")
+            }
+            RENodeId::KeyValueStore(id) => write!(f, "KeyValueStore[{}]", hex::encode(id)),
+            RENodeId::NonFungibleStore(id) => write!(f, "NonFungibleStore[{}]", hex::encode(id)),
+            RENodeId::Component(id) => write!(f, "Component[{}]", hex::encode(id)),
+            RENodeId::Vault(id) => write!(f, "Vault[{}]", hex::encode(id)),
+            RENodeId::ResourceManager(id) => write!(f, "ResourceManager[{}]", hex::encode(id)),
+            RENodeId::Package(id) => write!(f, "Package[{}]", hex::encode(id)),
+            RENodeId::EpochManager(id) => write!(f, "EpochManager[{}]", hex::encode(id)),
+            RENodeId::Clock(id) => write!(f, "Clock[{}]", hex::encode(id)),
+            RENodeId::Burner(id) => write!(f, "Burner[{}]", hex::encode(id)),
+            RENodeId::TimeLockedVault(id) => write!(f, "TimeLockedVault[{}]", hex::encode(id)),
+        }
+        .map_err(|err| AddressError::FormatError(err))
+    }
+}
+
 impl Into<u32> for RENodeId {
     fn into(self) -> u32 {
         match self {
@@ -126,6 +171,23 @@ pub enum GlobalAddress {
     System(SystemAddress),
 }
 
+impl<'a> ContextualDisplay<AddressDisplayContext<'a>> for GlobalAddress {
+    type Error = AddressError;
+
+    fn contextual_format<F: fmt::Write>(
+        &self,
+        f: &mut F,
+        context: &AddressDisplayContext<'a>,
+    ) -> Result<(), Self::Error> {
+        match self {
+            GlobalAddress::Component(address) => address.contextual_format(f, context),
+            GlobalAddress::Package(address) => address.contextual_format(f, context),
+            GlobalAddress::Resource(address) => address.contextual_format(f, context),
+            GlobalAddress::System(address) => address.contextual_format(f, context),
+        }
+    }
+}
+
 impl Into<ComponentAddress> for GlobalAddress {
     fn into(self) -> ComponentAddress {
         match self {
@@ -239,6 +301,16 @@ pub enum ClockOffset {
     CurrentTimeRoundedToMinutes,
 }
 
+#[derive(Debug, Clone, TypeId, Encode, Decode, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum BurnerOffset {
+    Burner,
+}
+
+#[derive(Debug, Clone, TypeId, Encode, Decode, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TimeLockedVaultOffset {
+    TimeLockedVault,
+}
+
 /// Specifies a specific Substate into a given RENode
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[scrypto(TypeId, Encode, Decode)]
@@ -260,9 +332,564 @@ pub enum SubstateOffset {
     Proof(ProofOffset),
     Worktop(WorktopOffset),
     Clock(ClockOffset),
+    Burner(BurnerOffset),
+    TimeLockedVault(TimeLockedVaultOffset),
+}
+
+impl SubstateOffset {
+    /// Returns a short name of the substate, for use in error messages and debug output.
+    pub fn human_name(&self) -> &'static str {
+        match self {
+            SubstateOffset::Global(GlobalOffset::Global) => "global",
+            SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack) => "auth_zone_stack",
+            SubstateOffset::FeeReserve(FeeReserveOffset::FeeReserve) => "fee_reserve",
+            SubstateOffset::Component(ComponentOffset::Info) => "component_info",
+            SubstateOffset::Component(ComponentOffset::State) => "component_state",
+            SubstateOffset::Component(ComponentOffset::RoyaltyConfig) => "component_royalty_config",
+            SubstateOffset::Component(ComponentOffset::RoyaltyAccumulator) => {
+                "component_royalty_accumulator"
+            }
+            SubstateOffset::AccessRulesChain(AccessRulesChainOffset::AccessRulesChain) => {
+                "access_rules_chain"
+            }
+            SubstateOffset::VaultAccessRulesChain(AccessRulesChainOffset::AccessRulesChain) => {
+                "vault_access_rules_chain"
+            }
+            SubstateOffset::Metadata(MetadataOffset::Metadata) => "metadata",
+            SubstateOffset::Package(PackageOffset::Info) => "package_info",
+            SubstateOffset::Package(PackageOffset::RoyaltyConfig) => "package_royalty_config",
+            SubstateOffset::Package(PackageOffset::RoyaltyAccumulator) => {
+                "package_royalty_accumulator"
+            }
+            SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager) => {
+                "resource_manager"
+            }
+            SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(..)) => "kv_store_entry",
+            SubstateOffset::NonFungibleStore(NonFungibleStoreOffset::Entry(..)) => "non_fungible",
+            SubstateOffset::Vault(VaultOffset::Vault) => "vault",
+            SubstateOffset::EpochManager(EpochManagerOffset::EpochManager) => "epoch_manager",
+            SubstateOffset::Bucket(BucketOffset::Bucket) => "bucket",
+            SubstateOffset::Proof(ProofOffset::Proof) => "proof",
+            SubstateOffset::Worktop(WorktopOffset::Worktop) => "worktop",
+            SubstateOffset::Clock(ClockOffset::CurrentTimeRoundedToMinutes) => "clock",
+            SubstateOffset::Burner(BurnerOffset::Burner) => "burner",
+            SubstateOffset::TimeLockedVault(TimeLockedVaultOffset::TimeLockedVault) => {
+                "time_locked_vault"
+            }
+        }
+    }
+}
+
+impl SubstateOffset {
+    /// Whether a node of the given kind has a substate at this offset.
+    pub fn is_valid_for(&self, node_id: &RENodeId) -> bool {
+        match (node_id, self) {
+            (RENodeId::Global(..), SubstateOffset::Global(..))
+            | (RENodeId::Bucket(..), SubstateOffset::Bucket(..))
+            | (RENodeId::Proof(..), SubstateOffset::Proof(..))
+            | (RENodeId::AuthZoneStack(..), SubstateOffset::AuthZoneStack(..))
+            | (RENodeId::FeeReserve(..), SubstateOffset::FeeReserve(..))
+            | (RENodeId::Worktop, SubstateOffset::Worktop(..))
+            | (RENodeId::KeyValueStore(..), SubstateOffset::KeyValueStore(..))
+            | (RENodeId::NonFungibleStore(..), SubstateOffset::NonFungibleStore(..))
+            | (RENodeId::Vault(..), SubstateOffset::Vault(..))
+            | (RENodeId::Burner(..), SubstateOffset::Burner(..)) => true,
+            (
+                RENodeId::Component(..),
+                SubstateOffset::Component(..)
+                | SubstateOffset::Metadata(..)
+                | SubstateOffset::AccessRulesChain(..),
+            ) => true,
+            (
+                RENodeId::Package(..),
+                SubstateOffset::Package(..)
+                | SubstateOffset::Metadata(..)
+                | SubstateOffset::AccessRulesChain(..),
+            ) => true,
+            (
+                RENodeId::ResourceManager(..),
+                SubstateOffset::ResourceManager(..)
+                | SubstateOffset::Metadata(..)
+                | SubstateOffset::AccessRulesChain(..)
+                | SubstateOffset::VaultAccessRulesChain(..),
+            ) => true,
+            (
+                RENodeId::EpochManager(..),
+                SubstateOffset::EpochManager(..) | SubstateOffset::AccessRulesChain(..),
+            ) => true,
+            (
+                RENodeId::Clock(..),
+                SubstateOffset::Clock(..) | SubstateOffset::AccessRulesChain(..),
+            ) => true,
+            (
+                RENodeId::TimeLockedVault(..),
+                SubstateOffset::TimeLockedVault(..) | SubstateOffset::AccessRulesChain(..),
+            ) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<'a> ContextualDisplay<AddressDisplayContext<'a>> for SubstateOffset {
+    type Error = fmt::Error;
+
+    fn contextual_format<F: fmt::Write>(
+        &self,
+        f: &mut F,
+        _context: &AddressDisplayContext<'a>,
+    ) -> Result<(), Self::Error> {
+        match self {
+            SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)) => {
+                write!(f, "{}[{}]", self.human_name(), hex::encode(key))
+            }
+            SubstateOffset::NonFungibleStore(NonFungibleStoreOffset::Entry(id)) => {
+                write!(f, "{}[{}]", self.human_name(), id)
+            }
+            _ => f.write_str(self.human_name()),
+        }
+    }
 }
 
 /// TODO: separate space addresses?
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[scrypto(TypeId, Encode, Decode)]
 pub struct SubstateId(pub RENodeId, pub SubstateOffset);
+
+impl SubstateId {
+    /// Creates a substate id, checking that the node has a substate at the offset.
+    ///
+    /// # Panics
+    /// If the offset does not belong to the node.
+    pub fn new(node_id: RENodeId, offset: SubstateOffset) -> Self {
+        Self::try_new(node_id, offset).expect("Substate offset does not belong to the node")
+    }
+
+    /// Creates a substate id, or returns `None` if the offset does not belong to the node.
+    pub fn try_new(node_id: RENodeId, offset: SubstateOffset) -> Option<Self> {
+        if offset.is_valid_for(&node_id) {
+            Some(Self(node_id, offset))
+        } else {
+            None
+        }
+    }
+
+    pub fn global(global_address: GlobalAddress) -> Self {
+        Self(
+            RENodeId::Global(global_address),
+            SubstateOffset::Global(GlobalOffset::Global),
+        )
+    }
+
+    pub fn component_info(component_id: ComponentId) -> Self {
+        Self(
+            RENodeId::Component(component_id),
+            SubstateOffset::Component(ComponentOffset::Info),
+        )
+    }
+
+    pub fn component_state(component_id: ComponentId) -> Self {
+        Self(
+            RENodeId::Component(component_id),
+            SubstateOffset::Component(ComponentOffset::State),
+        )
+    }
+
+    pub fn package_info(package_id: PackageId) -> Self {
+        Self(
+            RENodeId::Package(package_id),
+            SubstateOffset::Package(PackageOffset::Info),
+        )
+    }
+
+    pub fn resource_manager(resource_manager_id: ResourceManagerId) -> Self {
+        Self(
+            RENodeId::ResourceManager(resource_manager_id),
+            SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager),
+        )
+    }
+
+    pub fn kv_entry(kv_store_id: KeyValueStoreId, key: Vec<u8>) -> Self {
+        Self(
+            RENodeId::KeyValueStore(kv_store_id),
+            SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)),
+        )
+    }
+
+    pub fn non_fungible(non_fungible_store_id: NonFungibleStoreId, id: NonFungibleId) -> Self {
+        Self(
+            RENodeId::NonFungibleStore(non_fungible_store_id),
+            SubstateOffset::NonFungibleStore(NonFungibleStoreOffset::Entry(id)),
+        )
+    }
+
+    pub fn vault(vault_id: VaultId) -> Self {
+        Self(
+            RENodeId::Vault(vault_id),
+            SubstateOffset::Vault(VaultOffset::Vault),
+        )
+    }
+
+    pub fn epoch_manager(epoch_manager_id: EpochManagerId) -> Self {
+        Self(
+            RENodeId::EpochManager(epoch_manager_id),
+            SubstateOffset::EpochManager(EpochManagerOffset::EpochManager),
+        )
+    }
+
+    pub fn node_id(&self) -> RENodeId {
+        self.0
+    }
+
+    pub fn offset(&self) -> &SubstateOffset {
+        &self.1
+    }
+
+    pub fn try_into_global(self) -> Option<GlobalAddress> {
+        match self {
+            Self(RENodeId::Global(address), SubstateOffset::Global(GlobalOffset::Global)) => {
+                Some(address)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn try_into_component_info(self) -> Option<ComponentId> {
+        match self {
+            Self(RENodeId::Component(id), SubstateOffset::Component(ComponentOffset::Info)) => {
+                Some(id)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn try_into_component_state(self) -> Option<ComponentId> {
+        match self {
+            Self(RENodeId::Component(id), SubstateOffset::Component(ComponentOffset::State)) => {
+                Some(id)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn try_into_package_info(self) -> Option<PackageId> {
+        match self {
+            Self(RENodeId::Package(id), SubstateOffset::Package(PackageOffset::Info)) => Some(id),
+            _ => None,
+        }
+    }
+
+    pub fn try_into_resource_manager(self) -> Option<ResourceManagerId> {
+        match self {
+            Self(
+                RENodeId::ResourceManager(id),
+                SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager),
+            ) => Some(id),
+            _ => None,
+        }
+    }
+
+    pub fn try_into_kv_entry(self) -> Option<(KeyValueStoreId, Vec<u8>)> {
+        match self {
+            Self(
+                RENodeId::KeyValueStore(id),
+                SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)),
+            ) => Some((id, key)),
+            _ => None,
+        }
+    }
+
+    pub fn try_into_non_fungible(self) -> Option<(NonFungibleStoreId, NonFungibleId)> {
+        match self {
+            Self(
+                RENodeId::NonFungibleStore(store_id),
+                SubstateOffset::NonFungibleStore(NonFungibleStoreOffset::Entry(id)),
+            ) => Some((store_id, id)),
+            _ => None,
+        }
+    }
+
+    pub fn try_into_vault(self) -> Option<VaultId> {
+        match self {
+            Self(RENodeId::Vault(id), SubstateOffset::Vault(VaultOffset::Vault)) => Some(id),
+            _ => None,
+        }
+    }
+
+    /// Returns a short name of the substate, for use in error messages and debug output.
+    pub fn human_name(&self) -> &'static str {
+        self.1.human_name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::{Bech32Encoder, NO_NETWORK};
+    use crate::constants::{FAUCET_COMPONENT, RADIX_TOKEN};
+    use sbor::rust::collections::BTreeSet;
+
+    #[test]
+    fn human_name_is_distinct_for_every_substate_offset() {
+        let offsets = vec![
+            (SubstateOffset::Global(GlobalOffset::Global), "global"),
+            (
+                SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack),
+                "auth_zone_stack",
+            ),
+            (
+                SubstateOffset::FeeReserve(FeeReserveOffset::FeeReserve),
+                "fee_reserve",
+            ),
+            (
+                SubstateOffset::Component(ComponentOffset::Info),
+                "component_info",
+            ),
+            (
+                SubstateOffset::Component(ComponentOffset::State),
+                "component_state",
+            ),
+            (
+                SubstateOffset::Component(ComponentOffset::RoyaltyConfig),
+                "component_royalty_config",
+            ),
+            (
+                SubstateOffset::Component(ComponentOffset::RoyaltyAccumulator),
+                "component_royalty_accumulator",
+            ),
+            (
+                SubstateOffset::AccessRulesChain(AccessRulesChainOffset::AccessRulesChain),
+                "access_rules_chain",
+            ),
+            (
+                SubstateOffset::VaultAccessRulesChain(AccessRulesChainOffset::AccessRulesChain),
+                "vault_access_rules_chain",
+            ),
+            (
+                SubstateOffset::Metadata(MetadataOffset::Metadata),
+                "metadata",
+            ),
+            (SubstateOffset::Package(PackageOffset::Info), "package_info"),
+            (
+                SubstateOffset::Package(PackageOffset::RoyaltyConfig),
+                "package_royalty_config",
+            ),
+            (
+                SubstateOffset::Package(PackageOffset::RoyaltyAccumulator),
+                "package_royalty_accumulator",
+            ),
+            (
+                SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager),
+                "resource_manager",
+            ),
+            (
+                SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(vec![1, 2, 3])),
+                "kv_store_entry",
+            ),
+            (
+                SubstateOffset::NonFungibleStore(NonFungibleStoreOffset::Entry(
+                    NonFungibleId::U32(1),
+                )),
+                "non_fungible",
+            ),
+            (SubstateOffset::Vault(VaultOffset::Vault), "vault"),
+            (
+                SubstateOffset::EpochManager(EpochManagerOffset::EpochManager),
+                "epoch_manager",
+            ),
+            (SubstateOffset::Bucket(BucketOffset::Bucket), "bucket"),
+            (SubstateOffset::Proof(ProofOffset::Proof), "proof"),
+            (SubstateOffset::Worktop(WorktopOffset::Worktop), "worktop"),
+            (
+                SubstateOffset::Clock(ClockOffset::CurrentTimeRoundedToMinutes),
+                "clock",
+            ),
+            (SubstateOffset::Burner(BurnerOffset::Burner), "burner"),
+            (
+                SubstateOffset::TimeLockedVault(TimeLockedVaultOffset::TimeLockedVault),
+                "time_locked_vault",
+            ),
+        ];
+
+        let mut names = BTreeSet::new();
+        for (offset, expected) in offsets {
+            assert_eq!(offset.human_name(), expected);
+            assert!(names.insert(expected), "Duplicate name {}", expected);
+        }
+    }
+
+    #[test]
+    fn substate_id_human_name_uses_offset() {
+        let substate_id = SubstateId(
+            RENodeId::Vault([0u8; 36]),
+            SubstateOffset::Vault(VaultOffset::Vault),
+        );
+        assert_eq!(substate_id.human_name(), "vault");
+    }
+
+    #[test]
+    fn typed_substate_ids_are_consistent() {
+        let id = [1u8; 36];
+        let substate_ids = vec![
+            SubstateId::global(GlobalAddress::Resource(RADIX_TOKEN)),
+            SubstateId::component_info(id),
+            SubstateId::component_state(id),
+            SubstateId::package_info(id),
+            SubstateId::resource_manager(id),
+            SubstateId::kv_entry(id, vec![1, 2, 3]),
+            SubstateId::non_fungible(id, NonFungibleId::U32(1)),
+            SubstateId::vault(id),
+            SubstateId::epoch_manager(id),
+        ];
+
+        for substate_id in substate_ids {
+            assert_eq!(
+                SubstateId::try_new(substate_id.node_id(), substate_id.offset().clone()),
+                Some(substate_id)
+            );
+        }
+    }
+
+    #[test]
+    fn try_new_rejects_offset_of_another_node() {
+        let id = [1u8; 36];
+        assert_eq!(
+            SubstateId::try_new(
+                RENodeId::Vault(id),
+                SubstateOffset::Component(ComponentOffset::State)
+            ),
+            None
+        );
+        assert_eq!(
+            SubstateId::try_new(
+                RENodeId::Component(id),
+                SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager)
+            ),
+            None
+        );
+        assert_eq!(
+            SubstateId::try_new(
+                RENodeId::Global(GlobalAddress::Resource(RADIX_TOKEN)),
+                SubstateOffset::Vault(VaultOffset::Vault)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_panics_on_offset_of_another_node() {
+        SubstateId::new(
+            RENodeId::Package([1u8; 36]),
+            SubstateOffset::Vault(VaultOffset::Vault),
+        );
+    }
+
+    #[test]
+    fn try_into_only_matches_its_own_substate() {
+        let id = [1u8; 36];
+        assert_eq!(SubstateId::vault(id).try_into_vault(), Some(id));
+        assert_eq!(SubstateId::vault(id).try_into_component_state(), None);
+        assert_eq!(
+            SubstateId::component_state(id).try_into_component_state(),
+            Some(id)
+        );
+        assert_eq!(
+            SubstateId::component_state(id).try_into_component_info(),
+            None
+        );
+        assert_eq!(
+            SubstateId::kv_entry(id, vec![7]).try_into_kv_entry(),
+            Some((id, vec![7]))
+        );
+        assert_eq!(
+            SubstateId::global(GlobalAddress::Component(FAUCET_COMPONENT)).try_into_global(),
+            Some(GlobalAddress::Component(FAUCET_COMPONENT))
+        );
+    }
+
+    #[test]
+    fn re_node_id_display_without_network() {
+        let id = [1u8; 36];
+        let id_hex = hex::encode(id);
+        let node_ids = vec![
+            (RENodeId::Bucket(1), "Bucket[1]".to_string()),
+            (RENodeId::Proof(2), "Proof[2]".to_string()),
+            (RENodeId::AuthZoneStack(3), "AuthZoneStack[3]".to_string()),
+            (RENodeId::FeeReserve(4), "FeeReserve[4]".to_string()),
+            (RENodeId::Worktop, "Worktop".to_string()),
+            (
+                RENodeId::Global(GlobalAddress::Resource(RADIX_TOKEN)),
+                format!("Global[NormalResource[{}]]", RADIX_TOKEN.to_hex()),
+            ),
+            (
+                RENodeId::KeyValueStore(id),
+                format!("KeyValueStore[{}]", id_hex),
+            ),
+            (
+                RENodeId::NonFungibleStore(id),
+                format!("NonFungibleStore[{}]", id_hex),
+            ),
+            (RENodeId::Component(id), format!("Component[{}]", id_hex)),
+            (RENodeId::Vault(id), format!("Vault[{}]", id_hex)),
+            (
+                RENodeId::ResourceManager(id),
+                format!("ResourceManager[{}]", id_hex),
+            ),
+            (RENodeId::Package(id), format!("Package[{}]", id_hex)),
+            (
+                RENodeId::EpochManager(id),
+                format!("EpochManager[{}]", id_hex),
+            ),
+            (RENodeId::Clock(id), format!("Clock[{}]", id_hex)),
+            (RENodeId::Burner(id), format!("Burner[{}]", id_hex)),
+            (
+                RENodeId::TimeLockedVault(id),
+                format!("TimeLockedVault[{}]", id_hex),
+            ),
+        ];
+
+        for (node_id, expected) in node_ids {
+            assert_eq!(node_id.display(NO_NETWORK).to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn global_re_node_id_display_with_network_uses_bech32() {
+        let encoder = Bech32Encoder::for_simulator();
+        let node_id = RENodeId::Global(GlobalAddress::Component(FAUCET_COMPONENT));
+
+        assert_eq!(
+            node_id.display(&encoder).to_string(),
+            format!(
+                "Global[{}]",
+                encoder.encode_component_address_to_string(&FAUCET_COMPONENT)
+            )
+        );
+    }
+
+    #[test]
+    fn substate_offset_display() {
+        let offsets = vec![
+            (SubstateOffset::Global(GlobalOffset::Global), "global"),
+            (SubstateOffset::Vault(VaultOffset::Vault), "vault"),
+            (
+                SubstateOffset::Component(ComponentOffset::State),
+                "component_state",
+            ),
+            (
+                SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(vec![1, 2, 3])),
+                "kv_store_entry[010203]",
+            ),
+            (
+                SubstateOffset::NonFungibleStore(NonFungibleStoreOffset::Entry(
+                    NonFungibleId::U32(1),
+                )),
+                "non_fungible[1]",
+            ),
+        ];
+
+        for (offset, expected) in offsets {
+            assert_eq!(offset.display(NO_NETWORK).to_string(), expected);
+        }
+    }
+}
@@ -256,6 +256,37 @@ pub const CLOCK: SystemAddress = construct_address!(
     39
 );
 
+/// The address of the burner component, which burns or permanently locks away deposits.
+pub const SYS_BURNER_COMPONENT: SystemAddress = construct_address!(
+    EntityType::Burner,
+    81,
+    130,
+    179,
+    100,
+    248,
+    151,
+    66,
+    205,
+    2,
+    212,
+    180,
+    65,
+    90,
+    181,
+    166,
+    24,
+    91,
+    47,
+    48,
+    75,
+    176,
+    51,
+    61,
+    100,
+    210,
+    105
+);
+
 /// The ED25519 virtual resource address.
 pub const EDDSA_ED25519_TOKEN: ResourceAddress = construct_address!(
     EntityType::Resource,
@@ -289,6 +320,8 @@ pub const EDDSA_ED25519_TOKEN: ResourceAddress = construct_address!(
 
 pub const EPOCH_MANAGER_BLUEPRINT: &str = "EpochManager";
 pub const CLOCK_BLUEPRINT: &str = "Clock";
+pub const BURNER_BLUEPRINT: &str = "Burner";
+pub const TIME_LOCKED_VAULT_BLUEPRINT: &str = "TimeLockedVault";
 pub const RESOURCE_MANAGER_BLUEPRINT: &str = "ResourceManager";
 pub const PACKAGE_BLUEPRINT: &str = "Package";
 pub const TRANSACTION_PROCESSOR_BLUEPRINT: &str = "TransactionProcessor";
@@ -1,6 +1,7 @@
 use sbor::rust::borrow::ToOwned;
 use sbor::rust::convert::TryFrom;
 use sbor::rust::fmt;
+use sbor::rust::format;
 use sbor::rust::str::FromStr;
 use sbor::rust::string::String;
 use sbor::rust::vec::Vec;
@@ -10,6 +11,9 @@ use crate::data::*;
 use crate::scrypto_type;
 use scrypto_abi::Type;
 
+/// The prefix of expressions which read a local variable set with `SET_LOCAL`.
+const LOCAL_PREFIX: &str = "LOCAL:";
+
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Expression(pub String);
 
@@ -25,6 +29,16 @@ impl Expression {
     pub fn entire_auth_zone() -> Self {
         Self::new("ENTIRE_AUTH_ZONE")
     }
+
+    /// An expression which resolves to the value of the named manifest local variable.
+    pub fn local(name: &str) -> Self {
+        Self(format!("{}{}", LOCAL_PREFIX, name))
+    }
+
+    /// Returns the variable name, if this is a local variable expression.
+    pub fn local_name(&self) -> Option<&str> {
+        self.0.strip_prefix(LOCAL_PREFIX)
+    }
 }
 
 //========
@@ -118,4 +132,10 @@ mod tests {
         let s2 = Expression::try_from(s.to_vec().as_slice()).unwrap();
         assert_eq!(s2, s);
     }
+
+    #[test]
+    fn test_local_name() {
+        assert_eq!(Expression::local("price").local_name(), Some("price"));
+        assert_eq!(Expression::entire_worktop().local_name(), None);
+    }
 }
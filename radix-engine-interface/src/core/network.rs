@@ -45,6 +45,18 @@ impl NetworkDefinition {
             hrp_suffix: String::from("rdx"),
         }
     }
+
+    /// Looks up one of the networks defined here by its HRP suffix.
+    pub fn from_hrp_suffix(hrp_suffix: &str) -> Option<NetworkDefinition> {
+        [
+            Self::simulator(),
+            Self::adapanet(),
+            Self::nebunet(),
+            Self::mainnet(),
+        ]
+        .into_iter()
+        .find(|network| network.hrp_suffix == hrp_suffix)
+    }
 }
 
 impl FromStr for NetworkDefinition {
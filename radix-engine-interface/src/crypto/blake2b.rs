@@ -0,0 +1,125 @@
+use crate::crypto::*;
+
+const BLOCK_LENGTH: usize = 128;
+
+const IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+const SIGMA: [[usize; 16]; 12] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+];
+
+/// Computes the BLAKE2b digest of a message, with a 256-bit output.
+pub fn blake2b_256<T: AsRef<[u8]>>(data: T) -> Hash {
+    let data = data.as_ref();
+
+    // Parameter block: 32-byte digest, no key, fanout and depth of 1
+    let mut h = IV;
+    h[0] ^= 0x01010000 ^ Hash::LENGTH as u64;
+
+    // The last block is always compressed as the final one, even when it is full or empty
+    let mut offset = 0;
+    while data.len() - offset > BLOCK_LENGTH {
+        let block: &[u8; BLOCK_LENGTH] = data[offset..offset + BLOCK_LENGTH].try_into().unwrap();
+        offset += BLOCK_LENGTH;
+        compress(&mut h, block, offset as u128, false);
+    }
+    let mut last = [0u8; BLOCK_LENGTH];
+    last[..data.len() - offset].copy_from_slice(&data[offset..]);
+    compress(&mut h, &last, data.len() as u128, true);
+
+    let mut digest = [0u8; Hash::LENGTH];
+    for (chunk, word) in digest.chunks_exact_mut(8).zip(h.iter()) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    Hash(digest)
+}
+
+fn compress(h: &mut [u64; 8], block: &[u8; BLOCK_LENGTH], counter: u128, last: bool) {
+    let mut m = [0u64; 16];
+    for (word, chunk) in m.iter_mut().zip(block.chunks_exact(8)) {
+        *word = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+
+    let mut v = [0u64; 16];
+    v[..8].copy_from_slice(h);
+    v[8..].copy_from_slice(&IV);
+    v[12] ^= counter as u64;
+    v[13] ^= (counter >> 64) as u64;
+    if last {
+        v[14] = !v[14];
+    }
+
+    for s in SIGMA.iter() {
+        mix(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+        mix(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+        mix(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+        mix(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+        mix(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+        mix(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+        mix(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+        mix(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+    }
+
+    for i in 0..8 {
+        h[i] ^= v[i] ^ v[i + 8];
+    }
+}
+
+fn mix(v: &mut [u64; 16], a: usize, b: usize, c: usize, d: usize, x: u64, y: u64) {
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sbor::rust::str::FromStr;
+    use sbor::rust::vec::Vec;
+
+    #[test]
+    fn test_blake2b_256() {
+        let long: Vec<u8> = (0..=255u8).cycle().take(768).collect();
+        for (data, expected) in [
+            (
+                &b""[..],
+                "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8",
+            ),
+            (
+                &b"Hello Radix"[..],
+                "48f1bd08444b5e713db9e14caac2faae71836786ac94d645b00679728202a935",
+            ),
+            (
+                &long[..],
+                "b8007121274217790e2923e0ad7027986e5a99d5531ef6ae7d294140fc81615d",
+            ),
+        ] {
+            assert_eq!(blake2b_256(data), Hash::from_str(expected).unwrap());
+        }
+    }
+}
@@ -9,6 +9,7 @@ use utils::copy_u8_array;
 
 use crate::abi::*;
 use crate::data::*;
+use crate::scrypto;
 use crate::scrypto_type;
 
 /// Represents a 32-byte hash digest.
@@ -47,6 +48,28 @@ pub fn hash<T: AsRef<[u8]>>(data: T) -> Hash {
     crate::crypto::sha256(data)
 }
 
+/// The maximum size of the data a blueprint may hash through the engine, in bytes.
+pub const MAX_HASH_INPUT_SIZE: usize = 1024 * 1024;
+
+/// A hash function the engine offers to blueprints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum HashAlgorithm {
+    Sha256,
+    Blake2b256,
+    Keccak256,
+}
+
+impl HashAlgorithm {
+    pub fn hash<T: AsRef<[u8]>>(&self, data: T) -> Hash {
+        match self {
+            HashAlgorithm::Sha256 => crate::crypto::sha256(data),
+            HashAlgorithm::Blake2b256 => crate::crypto::blake2b_256(data),
+            HashAlgorithm::Keccak256 => crate::crypto::keccak256(data),
+        }
+    }
+}
+
 //========
 // error
 //========
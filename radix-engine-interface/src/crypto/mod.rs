@@ -1,4 +1,5 @@
 mod any;
+mod blake2b;
 mod blob;
 mod ecdsa_secp256k1;
 mod eddsa_ed25519;
@@ -7,9 +8,10 @@ mod sha2;
 mod sha3;
 
 pub use self::any::*;
+pub use self::blake2b::blake2b_256;
 pub use self::blob::*;
 pub use self::ecdsa_secp256k1::*;
 pub use self::eddsa_ed25519::*;
 pub use self::hash::*;
 pub use self::sha2::{sha256, sha256_twice};
-pub use self::sha3::sha3;
+pub use self::sha3::{keccak256, sha3};
@@ -1,4 +1,4 @@
-use ::sha3::{Digest, Sha3_256};
+use ::sha3::{Digest, Keccak256, Sha3_256};
 
 use crate::crypto::*;
 
@@ -11,6 +11,15 @@ pub fn sha3<T: AsRef<[u8]>>(data: T) -> Hash {
     Hash(result.into())
 }
 
+/// Computes the Keccak-256 digest of a message, as used by Ethereum.
+pub fn keccak256<T: AsRef<[u8]>>(data: T) -> Hash {
+    let mut instance = Keccak256::new();
+    instance.update(data);
+    let result = instance.finalize();
+
+    Hash(result.into())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -26,4 +35,14 @@ mod tests {
                 .unwrap()
         );
     }
+
+    #[test]
+    fn test_keccak256() {
+        let hash = keccak256("");
+        assert_eq!(
+            hash,
+            Hash::from_str("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+                .unwrap()
+        );
+    }
 }
@@ -137,6 +137,10 @@ impl IndexedScryptoValue {
         proof_replacements: &mut HashMap<ProofId, ProofId>,
         bucket_replacements: &mut HashMap<BucketId, BucketId>,
     ) -> Result<(), ValueReplacingError> {
+        if self.proof_ids.is_empty() && self.bucket_ids.is_empty() {
+            return Ok(());
+        }
+
         let mut new_proof_ids = HashMap::new();
         for (proof_id, path) in self.proof_ids.drain() {
             let next_id = proof_replacements
@@ -169,7 +173,7 @@ impl IndexedScryptoValue {
         }
         self.bucket_ids = new_bucket_ids;
 
-        self.raw = scrypto_encode(&self.dom)
+        scrypto_encode_into(&self.dom, &mut self.raw)
             .expect("Previously encodable raw value is no longer encodable after replacement");
 
         Ok(())
@@ -332,8 +336,26 @@ impl CustomValueVisitor<ScryptoCustomValue> for ScryptoCustomValueVisitor {
 
 #[cfg(test)]
 mod tests {
+    use super::rust::borrow::ToOwned;
     use super::rust::vec;
     use super::*;
+    use crate::model::{Bucket, Proof};
+
+    #[test]
+    fn should_replace_ids_in_raw_value() {
+        let mut value =
+            IndexedScryptoValue::from_typed(&(Bucket(1), Proof(2), "unchanged".to_owned()));
+        let mut proof_replacements = HashMap::from([(2, 20)]);
+        let mut bucket_replacements = HashMap::from([(1, 10)]);
+
+        let result = value.replace_ids(&mut proof_replacements, &mut bucket_replacements);
+
+        assert!(result.is_ok());
+        assert_eq!(
+            value,
+            IndexedScryptoValue::from_typed(&(Bucket(10), Proof(20), "unchanged".to_owned()))
+        );
+    }
 
     #[test]
     fn should_reject_duplicate_ids() {
@@ -4,6 +4,8 @@ mod custom_type_id;
 mod custom_value;
 /// Indexed Scrypto value.
 mod indexed_value;
+/// Pre-encoded Scrypto value.
+mod raw_value;
 /// Matches a Scrypto schema type with a Scrypto value.
 mod schema_matcher;
 /// Defines a way to uniquely identify an element within a Scrypto schema type.
@@ -18,6 +20,7 @@ pub use crate::args;
 pub use custom_type_id::*;
 pub use custom_value::*;
 pub use indexed_value::*;
+pub use raw_value::*;
 use sbor::rust::vec::Vec;
 use sbor::{
     Decode, DecodeError, Decoder, Encode, EncodeError, Encoder, SborTypeId, SborValue, TypeId,
@@ -67,6 +70,17 @@ pub fn scrypto_encode<T: ScryptoEncode + ?Sized>(value: &T) -> Result<Vec<u8>, E
     Ok(buf)
 }
 
+/// Encodes a data structure into the given byte array, replacing its contents but reusing its
+/// allocation.
+pub fn scrypto_encode_into<T: ScryptoEncode + ?Sized>(
+    value: &T,
+    buf: &mut Vec<u8>,
+) -> Result<(), EncodeError> {
+    buf.clear();
+    let encoder = ScryptoEncoder::new(buf);
+    encoder.encode_payload(value, SCRYPTO_SBOR_V1_PAYLOAD_PREFIX)
+}
+
 /// Decodes a data structure from a byte array.
 pub fn scrypto_decode<T: ScryptoDecode>(buf: &[u8]) -> Result<T, DecodeError> {
     ScryptoDecoder::new(buf).decode_payload(SCRYPTO_SBOR_V1_PAYLOAD_PREFIX)
@@ -133,6 +147,13 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_encode_into_replaces_buffer_contents() {
+        let mut buf = scrypto_encode(&"a much longer value than the next").unwrap();
+        scrypto_encode_into(&1u32, &mut buf).unwrap();
+        assert_eq!(buf, scrypto_encode(&1u32).unwrap());
+    }
+
     #[test]
     fn test_args_with_non_fungible_id() {
         let id = NonFungibleId::U32(1);
@@ -0,0 +1,148 @@
+use sbor::path::SborPathBuf;
+use sbor::rust::vec::Vec;
+use sbor::*;
+
+use crate::data::*;
+
+#[derive(Debug, Clone, PartialEq, Eq, TypeId, Encode, Decode)]
+pub enum RawSborValueError {
+    DecodeError(DecodeError),
+    ValueIndexingError(ValueIndexingError),
+    /// Buckets can't be passed in a raw value, as their ids would not be replaced
+    BucketNotAllowed,
+    /// Proofs can't be passed in a raw value, as their ids would not be replaced
+    ProofNotAllowed,
+}
+
+/// A Scrypto value that is already encoded, for passing a value whose type isn't known to this
+/// crate, such as a struct defined only in the blueprint being called.
+///
+/// The bytes are a single value without the payload prefix, i.e. its type id followed by its
+/// body, and are spliced as is into the encoding of the value containing them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawSborValue(Vec<u8>);
+
+impl RawSborValue {
+    /// Checks that the bytes form exactly one value, without buckets or proofs.
+    pub fn new(bytes: Vec<u8>) -> Result<Self, RawSborValueError> {
+        let value = decode_raw_value(&bytes)?;
+
+        let mut visitor = ScryptoCustomValueVisitor::new();
+        traverse_any(&mut SborPathBuf::new(), &value, &mut visitor)
+            .map_err(RawSborValueError::ValueIndexingError)?;
+        if !visitor.buckets.is_empty() {
+            return Err(RawSborValueError::BucketNotAllowed);
+        }
+        if !visitor.proofs.is_empty() {
+            return Err(RawSborValueError::ProofNotAllowed);
+        }
+
+        Ok(Self(bytes))
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn to_value(&self) -> ScryptoValue {
+        decode_raw_value(&self.0).expect("Raw value was checked on creation")
+    }
+}
+
+fn decode_raw_value(bytes: &[u8]) -> Result<ScryptoValue, RawSborValueError> {
+    let mut decoder = ScryptoDecoder::new(bytes);
+    let value = decoder
+        .decode::<ScryptoValue>()
+        .map_err(RawSborValueError::DecodeError)?;
+    decoder
+        .check_end()
+        .map_err(RawSborValueError::DecodeError)?;
+    Ok(value)
+}
+
+impl<E: Encoder<ScryptoCustomTypeId>> Encode<ScryptoCustomTypeId, E> for RawSborValue {
+    #[inline]
+    fn encode_type_id(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        encoder.write_byte(self.0[0])
+    }
+
+    #[inline]
+    fn encode_body(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        encoder.write_slice(&self.0[1..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::*;
+    use crate::scrypto;
+    use sbor::rust::string::String;
+    use sbor::rust::vec;
+
+    #[scrypto(TypeId, Encode, Decode)]
+    struct Order {
+        price: u32,
+        resource_address: ResourceAddress,
+        note: String,
+    }
+
+    /// Encodes a value without the payload prefix.
+    fn encode_fragment<T: ScryptoEncode>(value: &T) -> Vec<u8> {
+        scrypto_encode(value).unwrap()[1..].to_vec()
+    }
+
+    #[test]
+    fn should_splice_struct_fragment() {
+        let order = Order {
+            price: 5,
+            resource_address: ResourceAddress::Normal([1u8; 26]),
+            note: "raw".into(),
+        };
+        let raw = RawSborValue::new(encode_fragment(&order)).unwrap();
+
+        let args = args!(1u8, raw);
+
+        assert_eq!(args, args!(1u8, order));
+        let value = IndexedScryptoValue::from_slice(&args).unwrap();
+        assert!(value
+            .resource_addresses
+            .contains(&ResourceAddress::Normal([1u8; 26])));
+    }
+
+    #[test]
+    fn should_reject_invalid_fragment() {
+        let fragment = encode_fragment(&(1u32, 2u32));
+
+        assert!(matches!(
+            RawSborValue::new(fragment[..fragment.len() - 1].to_vec()),
+            Err(RawSborValueError::DecodeError(
+                DecodeError::BufferUnderflow { .. }
+            ))
+        ));
+        let mut trailing = fragment.clone();
+        trailing.push(0);
+        assert_eq!(
+            RawSborValue::new(trailing),
+            Err(RawSborValueError::DecodeError(
+                DecodeError::ExtraTrailingBytes(1)
+            ))
+        );
+        assert!(matches!(
+            RawSborValue::new(vec![]),
+            Err(RawSborValueError::DecodeError(..))
+        ));
+    }
+
+    #[test]
+    fn should_reject_bucket_or_proof_in_fragment() {
+        assert_eq!(
+            RawSborValue::new(encode_fragment(&(1u32, Bucket(1)))),
+            Err(RawSborValueError::BucketNotAllowed)
+        );
+        assert_eq!(
+            RawSborValue::new(encode_fragment(&vec![Proof(1)])),
+            Err(RawSborValueError::ProofNotAllowed)
+        );
+    }
+}
@@ -34,7 +34,9 @@ pub fn sbor_type_id(ty: &Type) -> Option<ScryptoSborTypeId> {
         Type::SystemAddress => Some(SborTypeId::Custom(ScryptoCustomTypeId::SystemAddress)),
         Type::Component => Some(SborTypeId::Custom(ScryptoCustomTypeId::Component)),
         Type::KeyValueStore { .. } => Some(SborTypeId::Custom(ScryptoCustomTypeId::KeyValueStore)),
-        Type::Bucket => Some(SborTypeId::Custom(ScryptoCustomTypeId::Bucket)),
+        Type::Bucket | Type::BorrowedBucket => {
+            Some(SborTypeId::Custom(ScryptoCustomTypeId::Bucket))
+        }
         Type::Proof => Some(SborTypeId::Custom(ScryptoCustomTypeId::Proof)),
         Type::Vault => Some(SborTypeId::Custom(ScryptoCustomTypeId::Vault)),
         Type::Expression => Some(SborTypeId::Custom(ScryptoCustomTypeId::Expression)),
@@ -299,7 +301,7 @@ pub fn match_schema_with_value(ty: &Type, value: &ScryptoValue) -> bool {
                 false
             }
         }
-        Type::Bucket => {
+        Type::Bucket | Type::BorrowedBucket => {
             if let SborValue::Custom { value } = value {
                 matches!(value, ScryptoCustomValue::Bucket(_))
             } else {
@@ -311,7 +311,11 @@ pub fn format_custom_value<F: fmt::Write>(
         }
         // Other interpreted types
         ScryptoCustomValue::Expression(value) => {
-            write!(f, "Expression(\"{}\")", value)?;
+            if let Some(name) = value.local_name() {
+                write!(f, "Local(\"{}\")", name)?;
+            } else {
+                write!(f, "Expression(\"{}\")", value)?;
+            }
         }
         ScryptoCustomValue::Blob(value) => {
             write!(f, "Blob(\"{}\")", value)?;
@@ -64,6 +64,21 @@ impl Decimal {
         Self::ZERO
     }
 
+    /// Parses a `Decimal`, failing with `TooManyDecimalPlaces` if the string has more decimal
+    /// places than `Decimal::SCALE`.
+    pub fn from_str_exact(s: &str) -> Result<Self, ParseDecimalError> {
+        if let Some((_, fraction)) = s.split_once('.') {
+            let count = fraction.chars().count();
+            if count > Self::SCALE as usize {
+                return Err(ParseDecimalError::TooManyDecimalPlaces {
+                    count: u8::try_from(count).unwrap_or(u8::MAX),
+                    max: Self::SCALE as u8,
+                });
+            }
+        }
+        Self::from_str(s)
+    }
+
     /// Returns `Decimal` of 1.
     pub fn one() -> Self {
         Self::ONE
@@ -89,6 +104,23 @@ impl Decimal {
         Decimal(self.0.abs())
     }
 
+    /// Whether this decimal is within `epsilon` of `other`, i.e. `|self - other| <= epsilon`.
+    pub fn approx_eq(&self, other: Decimal, epsilon: Decimal) -> bool {
+        (*self - other).abs() <= epsilon
+    }
+
+    /// Whether the difference between this decimal and `other`, relative to the larger of
+    /// their magnitudes, is within `rel_epsilon`.
+    ///
+    /// Two zero values are always considered equal.
+    pub fn relative_approx_eq(&self, other: Decimal, rel_epsilon: Decimal) -> bool {
+        let largest = self.abs().max(other.abs());
+        if largest.is_zero() {
+            return true;
+        }
+        (*self - other).abs() / largest <= rel_epsilon
+    }
+
     /// Returns the largest integer that is equal to or less than this number.
     pub fn floor(&self) -> Self {
         self.round(0, RoundingMode::TowardsNegativeInfinity)
@@ -564,6 +596,7 @@ pub enum ParseDecimalError {
     InvalidDecimal(String),
     InvalidChar(char),
     UnsupportedDecimalPlace,
+    TooManyDecimalPlaces { count: u8, max: u8 },
     InvalidLength(usize),
     Overflow,
 }
@@ -1126,6 +1159,31 @@ mod tests {
         assert_eq!(dec, Err(ParseDecimalError::InvalidChar('n')));
     }
 
+    #[test]
+    fn test_from_str_exact_decimal() {
+        assert_eq!(Decimal::from_str_exact("5"), Ok(dec!(5)));
+        assert_eq!(
+            Decimal::from_str_exact("0.000000000000000001"),
+            Ok(Decimal(I256::from(1u8)))
+        );
+        assert_eq!(
+            Decimal::from_str_exact("-1.123456789012345678"),
+            Ok(dec!("-1.123456789012345678"))
+        );
+        assert_eq!(
+            Decimal::from_str_exact("0.0000000000000000001"),
+            Err(ParseDecimalError::TooManyDecimalPlaces { count: 19, max: 18 })
+        );
+        assert_eq!(
+            Decimal::from_str_exact("1.1234567890123456780"),
+            Err(ParseDecimalError::TooManyDecimalPlaces { count: 19, max: 18 })
+        );
+        assert_eq!(
+            Decimal::from_str_exact("1.x"),
+            Err(ParseDecimalError::InvalidChar('x'))
+        );
+    }
+
     #[test]
     fn test_sqrt() {
         let sqrt_of_42 = dec!(42).sqrt();
@@ -1168,4 +1226,25 @@ mod tests {
         assert_eq!(root_neg_5_42.unwrap(), dec!("-2.111785764966753912"));
         assert_eq!(root_0, None);
     }
+
+    #[test]
+    fn test_approx_eq() {
+        assert!(dec!(0).approx_eq(dec!(0), dec!(0)));
+        assert!(dec!("1.0001").approx_eq(dec!(1), dec!("0.0001")));
+        assert!(dec!(1).approx_eq(dec!("1.0001"), dec!("0.0001")));
+        assert!(!dec!("1.0002").approx_eq(dec!(1), dec!("0.0001")));
+        assert!(dec!("-0.5").approx_eq(dec!("0.5"), dec!(1)));
+        assert!(!dec!("-0.5").approx_eq(dec!("0.5"), dec!("0.999999999999999999")));
+    }
+
+    #[test]
+    fn test_relative_approx_eq() {
+        assert!(dec!(0).relative_approx_eq(dec!(0), dec!(0)));
+        assert!(!dec!(0).relative_approx_eq(dec!("0.000000000000000001"), dec!("0.5")));
+        assert!(dec!(1000).relative_approx_eq(dec!(1001), dec!("0.001")));
+        assert!(dec!(1001).relative_approx_eq(dec!(1000), dec!("0.001")));
+        assert!(!dec!(1000).relative_approx_eq(dec!(1002), dec!("0.001")));
+        assert!(dec!("-1").relative_approx_eq(dec!(1), dec!(2)));
+        assert!(!dec!("-1").relative_approx_eq(dec!(1), dec!("1.999999999999999999")));
+    }
 }
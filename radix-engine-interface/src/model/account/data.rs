@@ -0,0 +1,13 @@
+use crate::Describe;
+use sbor::*;
+
+/// Which resources an account accepts through `deposit` and `deposit_batch`.
+#[derive(Debug, Clone, Copy, TypeId, Encode, Decode, Describe, PartialEq, Eq)]
+pub enum AccountDepositMode {
+    /// Accepts deposits of any resource.
+    AllowAll,
+    /// Accepts deposits of allowlisted resources only.
+    DenyAllExceptAllowlist,
+    /// Accepts deposits of any resource which is not denylisted.
+    AllowAllExceptDenylist,
+}
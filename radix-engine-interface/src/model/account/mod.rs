@@ -0,0 +1,3 @@
+mod data;
+
+pub use data::*;
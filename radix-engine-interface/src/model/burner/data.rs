@@ -0,0 +1,16 @@
+use crate::constants::SYSTEM_TOKEN;
+use crate::model::*;
+use crate::rule;
+
+/// The non-fungible id of the system badge which the burner presents when burning deposits.
+pub const BURNER_BADGE_ID: NonFungibleId = NonFungibleId::U32(2);
+
+/// The system badge which the burner presents when burning deposits.
+pub fn burner_badge() -> NonFungibleAddress {
+    NonFungibleAddress::new(SYSTEM_TOKEN, BURNER_BADGE_ID)
+}
+
+/// A burn access rule which lets the burner burn any deposit of the resource.
+pub fn burnable_by_burner() -> AccessRule {
+    rule!(require(burner_badge()))
+}
@@ -0,0 +1,77 @@
+use sbor::rust::fmt::Debug;
+use sbor::rust::vec::Vec;
+use sbor::*;
+
+use crate::api::api::*;
+use crate::model::*;
+use crate::scrypto;
+use crate::wasm::*;
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct BurnerCreateInvocation {}
+
+impl Invocation for BurnerCreateInvocation {
+    type Output = SystemAddress;
+}
+
+impl SerializableInvocation for BurnerCreateInvocation {
+    type ScryptoOutput = SystemAddress;
+}
+
+impl Into<SerializedInvocation> for BurnerCreateInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Function(NativeFunctionInvocation::Burner(
+            BurnerFunctionInvocation::Create(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct BurnerDepositInvocation {
+    pub receiver: SystemAddress,
+    pub bucket: Bucket,
+}
+
+impl Invocation for BurnerDepositInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for BurnerDepositInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for BurnerDepositInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Burner(
+            BurnerMethodInvocation::Deposit(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct BurnerDepositBatchInvocation {
+    pub receiver: SystemAddress,
+    pub buckets: Vec<Bucket>,
+}
+
+impl Invocation for BurnerDepositBatchInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for BurnerDepositBatchInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for BurnerDepositBatchInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Burner(
+            BurnerMethodInvocation::DepositBatch(self),
+        ))
+        .into()
+    }
+}
@@ -79,6 +79,31 @@ impl Into<SerializedInvocation> for ComponentSetRoyaltyConfigInvocation {
     }
 }
 
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct ComponentSetAuthMutabilityInvocation {
+    /// TODO: change to component id, after `borrow_component` returns component id
+    pub receiver: RENodeId,
+    pub access_rule: AccessRule,
+}
+
+impl Invocation for ComponentSetAuthMutabilityInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for ComponentSetAuthMutabilityInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for ComponentSetAuthMutabilityInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Component(
+            ComponentMethodInvocation::SetAuthMutability(self),
+        ))
+        .into()
+    }
+}
+
 #[derive(Debug)]
 #[scrypto(TypeId, Encode, Decode)]
 pub struct ComponentClaimRoyaltyInvocation {
@@ -102,3 +127,27 @@ impl Into<SerializedInvocation> for ComponentClaimRoyaltyInvocation {
         .into()
     }
 }
+
+/// Returns the length of the encoded state of a component, without decoding it.
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct ComponentStateSizeBytesInvocation {
+    pub receiver: RENodeId,
+}
+
+impl Invocation for ComponentStateSizeBytesInvocation {
+    type Output = usize;
+}
+
+impl SerializableInvocation for ComponentStateSizeBytesInvocation {
+    type ScryptoOutput = usize;
+}
+
+impl Into<SerializedInvocation> for ComponentStateSizeBytesInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Component(
+            ComponentMethodInvocation::StateSizeBytes(self),
+        ))
+        .into()
+    }
+}
@@ -1,4 +1,6 @@
+mod account;
 mod auth;
+mod burner;
 mod clock;
 mod component;
 mod epoch_manager;
@@ -8,8 +10,11 @@ mod package;
 mod resource;
 mod scrypto;
 mod system;
+mod time_locked_vault;
 
+pub use account::*;
 pub use auth::*;
+pub use burner::*;
 pub use clock::*;
 pub use component::*;
 pub use epoch_manager::*;
@@ -19,3 +24,4 @@ pub use package::*;
 pub use resource::*;
 pub use scrypto::*;
 pub use system::*;
+pub use time_locked_vault::*;
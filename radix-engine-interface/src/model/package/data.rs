@@ -6,8 +6,9 @@ use utils::{copy_u8_array, ContextualDisplay};
 
 use crate::abi::*;
 use crate::address::{AddressDisplayContext, AddressError, EntityType, NO_NETWORK};
+use crate::crypto::Hash;
 use crate::data::ScryptoCustomTypeId;
-use crate::scrypto_type;
+use crate::{scrypto, scrypto_type};
 
 /// A collection of blueprints, compiled and published as a single unit.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -15,6 +16,19 @@ pub enum PackageAddress {
     Normal([u8; 26]),
 }
 
+/// Identifies the source and toolchain a package was built from.
+///
+/// `scrypto build` embeds this into the package code, so that anyone holding the source can
+/// rebuild the package and check that it matches the code on ledger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct BuildFingerprint {
+    /// The hash of the canonicalized source tree of the package
+    pub source_hash: Hash,
+    /// The version of the Rust toolchain, as reported by `rustc --version`
+    pub toolchain: String,
+}
+
 //========
 // binary
 //========
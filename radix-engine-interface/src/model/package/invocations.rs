@@ -4,7 +4,7 @@ use crate::crypto::Blob;
 use crate::model::*;
 use crate::scrypto;
 use crate::wasm::*;
-use sbor::rust::collections::HashMap;
+use sbor::rust::collections::{BTreeMap, HashMap};
 use sbor::rust::string::String;
 use sbor::*;
 
@@ -14,7 +14,7 @@ pub struct PackagePublishInvocation {
     pub code: Blob,
     pub abi: Blob,
     pub royalty_config: HashMap<String, RoyaltyConfig>,
-    pub metadata: HashMap<String, String>,
+    pub metadata: BTreeMap<String, String>,
     pub access_rules: AccessRules,
 }
 
@@ -41,7 +41,7 @@ pub struct PackagePublishWithOwnerInvocation {
     pub code: Blob,
     pub abi: Blob,
     pub royalty_config: HashMap<String, RoyaltyConfig>,
-    pub metadata: HashMap<String, String>,
+    pub metadata: BTreeMap<String, String>,
     pub owner_badge: NonFungibleAddress,
 }
 
@@ -121,3 +121,33 @@ impl Into<SerializedInvocation> for PackageClaimRoyaltyInvocation {
 pub struct PackageClaimRoyaltyExecutable {
     pub receiver: RENodeId,
 }
+
+/// Returns the build fingerprint embedded in the code of a package, if any.
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct PackageGetBuildFingerprintInvocation {
+    pub receiver: PackageAddress,
+}
+
+impl Invocation for PackageGetBuildFingerprintInvocation {
+    type Output = Option<BuildFingerprint>;
+}
+
+impl SerializableInvocation for PackageGetBuildFingerprintInvocation {
+    type ScryptoOutput = Option<BuildFingerprint>;
+}
+
+impl Into<SerializedInvocation> for PackageGetBuildFingerprintInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Package(
+            PackageMethodInvocation::GetBuildFingerprint(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct PackageGetBuildFingerprintExecutable {
+    pub receiver: RENodeId,
+}
@@ -182,6 +182,13 @@ impl Into<SerializedInvocation> for BucketCreateProofInvocation {
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub struct Bucket(pub BucketId);
 
+/// Represents a bucket lent to a callee, which remains owned by the caller.
+///
+/// Encoded the same as a `Bucket`, but described as `Type::BorrowedBucket` so that the engine
+/// passes a reference to the bucket instead of moving it.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct BorrowedBucket(pub BucketId);
+
 //========
 // error
 //========
@@ -224,3 +231,24 @@ impl Bucket {
 }
 
 scrypto_type!(Bucket, ScryptoCustomTypeId::Bucket, Type::Bucket, 4);
+
+impl TryFrom<&[u8]> for BorrowedBucket {
+    type Error = ParseBucketError;
+
+    fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
+        Bucket::try_from(slice).map(|bucket| Self(bucket.0))
+    }
+}
+
+impl BorrowedBucket {
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.0.to_le_bytes().to_vec()
+    }
+}
+
+scrypto_type!(
+    BorrowedBucket,
+    ScryptoCustomTypeId::Bucket,
+    Type::BorrowedBucket,
+    4
+);
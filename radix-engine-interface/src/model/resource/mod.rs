@@ -7,6 +7,7 @@ mod non_fungible_id;
 mod proof;
 mod proof_rule;
 mod resource_manager;
+mod resource_metadata;
 mod resource_type;
 mod vault;
 mod worktop;
@@ -21,6 +22,7 @@ pub use proof::*;
 pub use proof_rule::*;
 pub use resource_manager::ResourceMethodAuthKey::*;
 pub use resource_manager::*;
+pub use resource_metadata::*;
 pub use resource_type::ResourceType;
 pub use vault::*;
 pub use worktop::*;
@@ -180,23 +180,33 @@ impl fmt::Debug for NonFungibleAddress {
     }
 }
 
+/// Returns the virtual signature badge of a public key, which the auth zone holds a proof of
+/// whenever a transaction is signed with the corresponding private key.
+///
+/// The badge is a non-fungible of [`ECDSA_SECP256K1_TOKEN`] or [`EDDSA_ED25519_TOKEN`],
+/// depending on the curve, whose id is the lower 26 bytes of the hash of the public key.
+/// Virtual accounts are addressed by the same bytes, and are protected by this badge.
+pub fn virtual_signature_badge<P: Into<PublicKey> + Clone>(public_key: &P) -> NonFungibleAddress {
+    let public_key: PublicKey = public_key.clone().into();
+    match public_key {
+        PublicKey::EcdsaSecp256k1(public_key) => NonFungibleAddress::new(
+            ECDSA_SECP256K1_TOKEN,
+            NonFungibleId::Bytes(hash(public_key.to_vec()).lower_26_bytes().into()),
+        ),
+        PublicKey::EddsaEd25519(public_key) => NonFungibleAddress::new(
+            EDDSA_ED25519_TOKEN,
+            NonFungibleId::Bytes(hash(public_key.to_vec()).lower_26_bytes().into()),
+        ),
+    }
+}
+
 pub trait FromPublicKey: Sized {
     fn from_public_key<P: Into<PublicKey> + Clone>(public_key: &P) -> Self;
 }
 
 impl FromPublicKey for NonFungibleAddress {
     fn from_public_key<P: Into<PublicKey> + Clone>(public_key: &P) -> Self {
-        let public_key: PublicKey = public_key.clone().into();
-        match public_key {
-            PublicKey::EcdsaSecp256k1(public_key) => NonFungibleAddress::new(
-                ECDSA_SECP256K1_TOKEN,
-                NonFungibleId::Bytes(hash(public_key.to_vec()).lower_26_bytes().into()),
-            ),
-            PublicKey::EddsaEd25519(public_key) => NonFungibleAddress::new(
-                EDDSA_ED25519_TOKEN,
-                NonFungibleId::Bytes(hash(public_key.to_vec()).lower_26_bytes().into()),
-            ),
-        }
+        virtual_signature_badge(public_key)
     }
 }
 
@@ -1,7 +1,9 @@
 use sbor::rust::vec::Vec;
-use sbor::{DecodeError, EncodeError};
+use sbor::*;
 use scrypto_abi::Type;
 
+use crate::scrypto;
+
 /// Represents the data structure of a non-fungible.
 pub trait NonFungibleData {
     /// Decodes `Self` from the serialized immutable and mutable parts.
@@ -21,3 +23,21 @@ pub trait NonFungibleData {
     /// Returns the schema of the mutable data.
     fn mutable_data_schema() -> Type;
 }
+
+/// The schemas of the immutable and mutable parts of a non-fungible's data, against which a
+/// resource manager validates minted and updated data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct NonFungibleDataSchema {
+    pub immutable: Type,
+    pub mutable: Type,
+}
+
+impl NonFungibleDataSchema {
+    pub fn new<T: NonFungibleData>() -> Self {
+        Self {
+            immutable: T::immutable_data_schema(),
+            mutable: T::mutable_data_schema(),
+        }
+    }
+}
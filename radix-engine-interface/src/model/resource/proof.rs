@@ -79,6 +79,28 @@ impl Into<SerializedInvocation> for ProofGetResourceAddressInvocation {
     }
 }
 
+#[derive(Debug, TypeId, Encode, Decode)]
+pub struct ProofIsValidInvocation {
+    pub receiver: ProofId,
+}
+
+impl Invocation for ProofIsValidInvocation {
+    type Output = bool;
+}
+
+impl SerializableInvocation for ProofIsValidInvocation {
+    type ScryptoOutput = bool;
+}
+
+impl Into<SerializedInvocation> for ProofIsValidInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Proof(
+            ProofMethodInvocation::IsValid(self),
+        ))
+        .into()
+    }
+}
+
 #[derive(Debug, TypeId, Encode, Decode)]
 pub struct ProofCloneInvocation {
     pub receiver: ProofId,
@@ -276,3 +276,222 @@ pub enum AccessRule {
     DenyAll,
     Protected(AccessRuleNode),
 }
+
+impl AccessRule {
+    /// Returns whether this rule is at least as permissive as `other`, i.e. whether every auth
+    /// zone which satisfies `other` is guaranteed to also satisfy `self`.
+    ///
+    /// The check is conservative: rules which depend on component state (dynamic rules) are
+    /// only considered implied by structurally identical rules, so `false` may be returned for
+    /// some rules which are in fact implied.
+    pub fn implies(&self, other: &AccessRule) -> bool {
+        match (self, other) {
+            (AccessRule::AllowAll, _) => true,
+            (AccessRule::DenyAll, other) => other.eq(&AccessRule::DenyAll),
+            (AccessRule::Protected(_), AccessRule::AllowAll) => false,
+            (AccessRule::Protected(_), AccessRule::DenyAll) => true,
+            (AccessRule::Protected(node), AccessRule::Protected(other_node)) => {
+                node.is_implied_by(other_node)
+            }
+        }
+    }
+}
+
+impl AccessRuleNode {
+    /// Returns whether every auth zone satisfying `other` also satisfies `self`.
+    fn is_implied_by(&self, other: &AccessRuleNode) -> bool {
+        if self.eq(other) {
+            return true;
+        }
+
+        // Replace `self` with an equivalent or stronger rule, and `other` with an equivalent or
+        // weaker one; both substitutions preserve soundness.
+        if let Some(strengthened) = self.expand(true) {
+            return strengthened.is_implied_by(other);
+        }
+        if let Some(weakened) = other.expand(false) {
+            return self.is_implied_by(&weakened);
+        }
+
+        if let AccessRuleNode::AnyOf(others) = other {
+            return others.iter().all(|o| self.is_implied_by(o));
+        }
+        if let AccessRuleNode::AllOf(rules) = self {
+            return rules.iter().all(|r| r.is_implied_by(other));
+        }
+
+        if let AccessRuleNode::AnyOf(rules) = self {
+            if rules.iter().any(|r| r.is_implied_by(other)) {
+                return true;
+            }
+        }
+        if let AccessRuleNode::AllOf(others) = other {
+            if others.iter().any(|o| self.is_implied_by(o)) {
+                return true;
+            }
+        }
+
+        match (self, other) {
+            (
+                AccessRuleNode::ProofRule(ProofRule::CountOf(
+                    SoftCount::Static(count),
+                    SoftResourceOrNonFungibleList::Static(resources),
+                )),
+                _,
+            ) if *count > 0 => {
+                let implied = resources
+                    .iter()
+                    .filter(|r| {
+                        AccessRuleNode::ProofRule(ProofRule::Require((*r).clone()))
+                            .is_implied_by(other)
+                    })
+                    .count();
+                implied >= *count as usize
+            }
+            (AccessRuleNode::ProofRule(rule), AccessRuleNode::ProofRule(other_rule)) => {
+                rule.is_implied_by(other_rule)
+            }
+            _ => false,
+        }
+    }
+
+    /// Rewrites list-based proof rules in terms of `AnyOf`/`AllOf` nodes of single resource
+    /// requirements. If `exact` is false, the result may be weaker than the original rule.
+    fn expand(&self, exact: bool) -> Option<AccessRuleNode> {
+        let require_all = |resources: &Vec<SoftResourceOrNonFungible>| {
+            resources
+                .iter()
+                .map(|r| AccessRuleNode::ProofRule(ProofRule::Require(r.clone())))
+                .collect()
+        };
+
+        match self {
+            AccessRuleNode::ProofRule(ProofRule::AllOf(SoftResourceOrNonFungibleList::Static(
+                resources,
+            ))) => Some(AllOf(require_all(resources))),
+            AccessRuleNode::ProofRule(ProofRule::AnyOf(SoftResourceOrNonFungibleList::Static(
+                resources,
+            ))) => Some(AnyOf(require_all(resources))),
+            AccessRuleNode::ProofRule(ProofRule::CountOf(
+                SoftCount::Static(count),
+                SoftResourceOrNonFungibleList::Static(resources),
+            )) => {
+                let count = *count as usize;
+                if count == 0 {
+                    None
+                } else if count == resources.len() || (!exact && count > resources.len()) {
+                    Some(AllOf(require_all(resources)))
+                } else if count == 1 || (!exact && count < resources.len()) {
+                    Some(AnyOf(require_all(resources)))
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+impl ProofRule {
+    /// Returns whether every auth zone satisfying the single-resource rule `other` also
+    /// satisfies `self`.
+    fn is_implied_by(&self, other: &ProofRule) -> bool {
+        match (self, other) {
+            (
+                ProofRule::Require(SoftResourceOrNonFungible::StaticResource(resource_address)),
+                ProofRule::AmountOf(SoftDecimal::Static(_), SoftResource::Static(other_address)),
+            ) => resource_address.eq(other_address),
+            (
+                ProofRule::AmountOf(SoftDecimal::Static(amount), SoftResource::Static(address)),
+                ProofRule::AmountOf(
+                    SoftDecimal::Static(other_amount),
+                    SoftResource::Static(other_address),
+                ),
+            ) => address.eq(other_address) && other_amount >= amount,
+            _ => self.eq(other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dec;
+
+    fn resource(n: u8) -> ResourceAddress {
+        ResourceAddress::Normal([n; 26])
+    }
+
+    fn protected(rule: ProofRule) -> AccessRule {
+        AccessRule::Protected(AccessRuleNode::ProofRule(rule))
+    }
+
+    #[test]
+    fn test_allow_all_and_deny_all() {
+        let rule = protected(require(resource(1)));
+
+        assert!(AccessRule::AllowAll.implies(&AccessRule::AllowAll));
+        assert!(AccessRule::AllowAll.implies(&AccessRule::DenyAll));
+        assert!(AccessRule::AllowAll.implies(&rule));
+        assert!(AccessRule::DenyAll.implies(&AccessRule::DenyAll));
+        assert!(!AccessRule::DenyAll.implies(&AccessRule::AllowAll));
+        assert!(!AccessRule::DenyAll.implies(&rule));
+        assert!(rule.implies(&AccessRule::DenyAll));
+        assert!(!rule.implies(&AccessRule::AllowAll));
+    }
+
+    #[test]
+    fn test_single_resource_rules() {
+        let badge = NonFungibleAddress::new(resource(3), NonFungibleId::U32(1));
+
+        assert!(protected(require(resource(1))).implies(&protected(require(resource(1)))));
+        assert!(!protected(require(resource(1))).implies(&protected(require(resource(2)))));
+        assert!(protected(require(badge.clone())).implies(&protected(require(badge.clone()))));
+        assert!(!protected(require(badge)).implies(&protected(require(resource(3)))));
+        assert!(protected(require_amount(dec!("1"), resource(1)))
+            .implies(&protected(require_amount(dec!("2"), resource(1)))));
+        assert!(!protected(require_amount(dec!("2"), resource(1)))
+            .implies(&protected(require_amount(dec!("1"), resource(1)))));
+        assert!(protected(require(resource(1)))
+            .implies(&protected(require_amount(dec!("5"), resource(1)))));
+    }
+
+    #[test]
+    fn test_composite_rules() {
+        let any = protected(require_any_of(vec![resource(1), resource(2)]));
+        let all = protected(require_all_of(vec![resource(1), resource(2)]));
+        let one = protected(require(resource(1)));
+        let two_of_three = protected(require_n_of(2, vec![resource(1), resource(2), resource(3)]));
+
+        assert!(any.implies(&all));
+        assert!(!all.implies(&any));
+        assert!(any.implies(&one));
+        assert!(one.implies(&all));
+        assert!(!all.implies(&one));
+        assert!(two_of_three.implies(&all));
+        assert!(!two_of_three.implies(&one));
+        assert!(
+            protected(require_any_of(vec![resource(1), resource(2), resource(3)]))
+                .implies(&two_of_three)
+        );
+        assert!(!all.implies(&two_of_three));
+
+        let nested = AccessRule::Protected(
+            AccessRuleNode::ProofRule(require(resource(1)))
+                .and(AccessRuleNode::ProofRule(require(resource(2))))
+                .or(AccessRuleNode::ProofRule(require(resource(3)))),
+        );
+        assert!(nested.implies(&all));
+        assert!(nested.implies(&protected(require(resource(3)))));
+        assert!(!nested.implies(&one));
+    }
+
+    #[test]
+    fn test_dynamic_rules() {
+        let dynamic = protected(require("badge"));
+
+        assert!(dynamic.implies(&dynamic));
+        assert!(!dynamic.implies(&protected(require(resource(1)))));
+        assert!(!protected(require(resource(1))).implies(&dynamic));
+    }
+}
@@ -1,4 +1,4 @@
-use sbor::rust::collections::HashMap;
+use sbor::rust::collections::{BTreeMap, HashMap};
 use sbor::rust::fmt;
 use sbor::rust::string::String;
 use sbor::rust::vec::Vec;
@@ -35,15 +35,20 @@ pub enum ResourceMethodAuthKey {
     Withdraw,
     Deposit,
     Recall,
+    CreateSyntheticProof,
 }
 
 #[derive(Debug)]
 #[scrypto(TypeId, Encode, Decode)]
 pub struct ResourceManagerCreateInvocation {
     pub resource_type: ResourceType,
-    pub metadata: HashMap<String, String>,
+    pub metadata: BTreeMap<String, String>,
     pub access_rules: HashMap<ResourceMethodAuthKey, (AccessRule, AccessRule)>,
     pub mint_params: Option<MintParams>,
+    /// The schema that minted and updated non-fungible data must match, if any
+    pub non_fungible_data_schema: Option<NonFungibleDataSchema>,
+    /// Whether the resource manager keeps a count of vaults holding a non-zero balance
+    pub track_holders: bool,
 }
 
 impl Invocation for ResourceManagerCreateInvocation {
@@ -67,9 +72,13 @@ impl Into<SerializedInvocation> for ResourceManagerCreateInvocation {
 #[scrypto(TypeId, Encode, Decode)]
 pub struct ResourceManagerCreateWithOwnerInvocation {
     pub resource_type: ResourceType,
-    pub metadata: HashMap<String, String>,
+    pub metadata: BTreeMap<String, String>,
     pub owner_badge: NonFungibleAddress,
     pub mint_params: Option<MintParams>,
+    /// The schema that minted and updated non-fungible data must match, if any
+    pub non_fungible_data_schema: Option<NonFungibleDataSchema>,
+    /// Whether the resource manager keeps a count of vaults holding a non-zero balance
+    pub track_holders: bool,
 }
 
 impl Invocation for ResourceManagerCreateWithOwnerInvocation {
@@ -302,6 +311,150 @@ impl Into<SerializedInvocation> for ResourceManagerGetTotalSupplyInvocation {
     }
 }
 
+/// The state of a resource manager, as read in a single call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode, Describe)]
+pub struct ResourceManagerSummary {
+    pub resource_type: ResourceType,
+    pub total_supply: Decimal,
+    pub metadata: BTreeMap<String, String>,
+    /// Whether minting is allowed by any access rule
+    pub mintable: bool,
+    /// Whether burning is allowed by any access rule
+    pub burnable: bool,
+}
+
+impl ResourceManagerSummary {
+    /// Returns the divisibility of a fungible resource, or `None` for a non-fungible resource.
+    pub fn divisibility(&self) -> Option<u8> {
+        match self.resource_type {
+            ResourceType::Fungible { divisibility } => Some(divisibility),
+            ResourceType::NonFungible { .. } => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct ResourceManagerGetSummaryInvocation {
+    pub receiver: ResourceAddress,
+}
+
+impl Invocation for ResourceManagerGetSummaryInvocation {
+    type Output = ResourceManagerSummary;
+}
+
+impl SerializableInvocation for ResourceManagerGetSummaryInvocation {
+    type ScryptoOutput = ResourceManagerSummary;
+}
+
+impl Into<SerializedInvocation> for ResourceManagerGetSummaryInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::ResourceManager(
+            ResourceManagerMethodInvocation::GetSummary(self),
+        ))
+        .into()
+    }
+}
+
+/// Creates a proof of a fungible amount which is vouched for by the resource manager, without
+/// locking any resource held in a vault or bucket.
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct ResourceManagerCreateSyntheticProofInvocation {
+    pub receiver: ResourceAddress,
+    pub amount: Decimal,
+}
+
+impl Invocation for ResourceManagerCreateSyntheticProofInvocation {
+    type Output = Proof;
+}
+
+impl SerializableInvocation for ResourceManagerCreateSyntheticProofInvocation {
+    type ScryptoOutput = Proof;
+}
+
+impl Into<SerializedInvocation> for ResourceManagerCreateSyntheticProofInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::ResourceManager(
+            ResourceManagerMethodInvocation::CreateSyntheticProof(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct ResourceManagerGetHolderVaultCountInvocation {
+    pub receiver: ResourceAddress,
+}
+
+impl Invocation for ResourceManagerGetHolderVaultCountInvocation {
+    /// The number of vaults holding a non-zero balance, or `None` if holders are not tracked
+    type Output = Option<u64>;
+}
+
+impl SerializableInvocation for ResourceManagerGetHolderVaultCountInvocation {
+    type ScryptoOutput = Option<u64>;
+}
+
+impl Into<SerializedInvocation> for ResourceManagerGetHolderVaultCountInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::ResourceManager(
+            ResourceManagerMethodInvocation::GetHolderVaultCount(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct ResourceManagerGetAmountMintedInvocation {
+    pub receiver: ResourceAddress,
+}
+
+impl Invocation for ResourceManagerGetAmountMintedInvocation {
+    /// The cumulative amount ever minted, including any initial supply
+    type Output = Decimal;
+}
+
+impl SerializableInvocation for ResourceManagerGetAmountMintedInvocation {
+    type ScryptoOutput = Decimal;
+}
+
+impl Into<SerializedInvocation> for ResourceManagerGetAmountMintedInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::ResourceManager(
+            ResourceManagerMethodInvocation::GetAmountMinted(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct ResourceManagerGetAmountBurnedInvocation {
+    pub receiver: ResourceAddress,
+}
+
+impl Invocation for ResourceManagerGetAmountBurnedInvocation {
+    /// The cumulative amount ever burned
+    type Output = Decimal;
+}
+
+impl SerializableInvocation for ResourceManagerGetAmountBurnedInvocation {
+    type ScryptoOutput = Decimal;
+}
+
+impl Into<SerializedInvocation> for ResourceManagerGetAmountBurnedInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::ResourceManager(
+            ResourceManagerMethodInvocation::GetAmountBurned(self),
+        ))
+        .into()
+    }
+}
+
 #[derive(Debug)]
 #[scrypto(TypeId, Encode, Decode)]
 pub struct ResourceManagerUpdateNonFungibleDataInvocation {
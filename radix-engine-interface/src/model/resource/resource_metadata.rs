@@ -0,0 +1,208 @@
+use sbor::rust::collections::BTreeMap;
+use sbor::rust::string::String;
+use sbor::*;
+
+use crate::scrypto;
+
+/// The maximum number of metadata entries a resource can have.
+pub const MAX_RESOURCE_METADATA_ENTRIES: usize = 64;
+/// The maximum length of a resource metadata key, in bytes.
+pub const MAX_RESOURCE_METADATA_KEY_LENGTH: usize = 100;
+/// The maximum length of a resource metadata value, in bytes.
+pub const MAX_RESOURCE_METADATA_VALUE_LENGTH: usize = 4096;
+/// The maximum length of the `symbol` of a resource, in characters.
+pub const MAX_RESOURCE_SYMBOL_LENGTH: usize = 16;
+
+pub const RESOURCE_METADATA_NAME: &str = "name";
+pub const RESOURCE_METADATA_SYMBOL: &str = "symbol";
+pub const RESOURCE_METADATA_ICON_URL: &str = "icon_url";
+
+/// Why a resource metadata entry was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum InvalidMetadataReason {
+    TooManyEntries {
+        limit: usize,
+    },
+    EmptyKey,
+    KeyTooLong {
+        length: usize,
+        limit: usize,
+    },
+    ValueTooLong {
+        length: usize,
+        limit: usize,
+    },
+    /// The symbol is not 1 to 16 uppercase alphanumeric characters
+    InvalidSymbol,
+    /// The icon URL is not an absolute `http` or `https` URL
+    InvalidIconUrl,
+}
+
+/// Checks the metadata a resource is created with.
+///
+/// On failure, returns the key of the first entry that is rejected.
+pub fn validate_resource_metadata(
+    metadata: &BTreeMap<String, String>,
+) -> Result<(), (String, InvalidMetadataReason)> {
+    for (i, (key, value)) in metadata.iter().enumerate() {
+        if i >= MAX_RESOURCE_METADATA_ENTRIES {
+            return Err((
+                key.clone(),
+                InvalidMetadataReason::TooManyEntries {
+                    limit: MAX_RESOURCE_METADATA_ENTRIES,
+                },
+            ));
+        }
+        validate_resource_metadata_entry(key, value).map_err(|reason| (key.clone(), reason))?;
+    }
+    Ok(())
+}
+
+/// Checks a single resource metadata entry, including the format of the well-known keys.
+pub fn validate_resource_metadata_entry(
+    key: &str,
+    value: &str,
+) -> Result<(), InvalidMetadataReason> {
+    if key.is_empty() {
+        return Err(InvalidMetadataReason::EmptyKey);
+    }
+    if key.len() > MAX_RESOURCE_METADATA_KEY_LENGTH {
+        return Err(InvalidMetadataReason::KeyTooLong {
+            length: key.len(),
+            limit: MAX_RESOURCE_METADATA_KEY_LENGTH,
+        });
+    }
+    if value.len() > MAX_RESOURCE_METADATA_VALUE_LENGTH {
+        return Err(InvalidMetadataReason::ValueTooLong {
+            length: value.len(),
+            limit: MAX_RESOURCE_METADATA_VALUE_LENGTH,
+        });
+    }
+
+    match key {
+        RESOURCE_METADATA_SYMBOL => {
+            if value.is_empty()
+                || value.len() > MAX_RESOURCE_SYMBOL_LENGTH
+                || !value
+                    .chars()
+                    .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+            {
+                return Err(InvalidMetadataReason::InvalidSymbol);
+            }
+        }
+        RESOURCE_METADATA_ICON_URL => {
+            if !is_url_shaped(value) {
+                return Err(InvalidMetadataReason::InvalidIconUrl);
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn is_url_shaped(value: &str) -> bool {
+    let rest = match value
+        .strip_prefix("https://")
+        .or_else(|| value.strip_prefix("http://"))
+    {
+        Some(rest) => rest,
+        None => return false,
+    };
+    let host = rest.split(&['/', '?', '#'][..]).next().unwrap_or("");
+    !host.is_empty() && !host.starts_with('.') && value.chars().all(|c| c.is_ascii_graphic())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sbor::rust::format;
+    use sbor::rust::string::ToString;
+
+    fn metadata(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+        entries
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_metadata() {
+        assert_eq!(
+            validate_resource_metadata(&metadata(&[
+                ("name", "Radix"),
+                ("symbol", "XRD"),
+                ("icon_url", "https://assets.radixdlt.com/icons/xrd.png"),
+                ("description", "The native token"),
+            ])),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_invalid_symbol() {
+        for symbol in ["", "xrd", "X-RD", "ABCDEFGHIJKLMNOPQ", "XRĐ"] {
+            assert_eq!(
+                validate_resource_metadata_entry("symbol", symbol),
+                Err(InvalidMetadataReason::InvalidSymbol)
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_icon_url() {
+        for url in [
+            "",
+            "radixdlt.com/icon.png",
+            "ftp://radixdlt.com/icon.png",
+            "https://",
+            "https:///icon.png",
+            "https://radix dlt.com/icon.png",
+        ] {
+            assert_eq!(
+                validate_resource_metadata_entry("icon_url", url),
+                Err(InvalidMetadataReason::InvalidIconUrl)
+            );
+        }
+    }
+
+    #[test]
+    fn test_length_limits() {
+        let long_key = "k".repeat(MAX_RESOURCE_METADATA_KEY_LENGTH + 1);
+        assert_eq!(
+            validate_resource_metadata_entry(&long_key, "v"),
+            Err(InvalidMetadataReason::KeyTooLong {
+                length: MAX_RESOURCE_METADATA_KEY_LENGTH + 1,
+                limit: MAX_RESOURCE_METADATA_KEY_LENGTH,
+            })
+        );
+        let long_value = "v".repeat(MAX_RESOURCE_METADATA_VALUE_LENGTH + 1);
+        assert_eq!(
+            validate_resource_metadata(&metadata(&[("description", &long_value)])),
+            Err((
+                "description".to_string(),
+                InvalidMetadataReason::ValueTooLong {
+                    length: MAX_RESOURCE_METADATA_VALUE_LENGTH + 1,
+                    limit: MAX_RESOURCE_METADATA_VALUE_LENGTH,
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_too_many_entries() {
+        let metadata: BTreeMap<String, String> = (0..=MAX_RESOURCE_METADATA_ENTRIES)
+            .map(|i| (format!("key{:03}", i), "value".to_string()))
+            .collect();
+        assert_eq!(
+            validate_resource_metadata(&metadata),
+            Err((
+                format!("key{:03}", MAX_RESOURCE_METADATA_ENTRIES),
+                InvalidMetadataReason::TooManyEntries {
+                    limit: MAX_RESOURCE_METADATA_ENTRIES,
+                }
+            ))
+        );
+    }
+}
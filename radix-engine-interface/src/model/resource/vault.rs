@@ -301,6 +301,34 @@ impl Into<SerializedInvocation> for VaultLockFeeInvocation {
     }
 }
 
+/// Locks a fee like [`VaultLockFeeInvocation`], and registers a callback function which is
+/// invoked at the end of the transaction with the XRD amount charged to the vault.
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct VaultLockFeeWithCallbackInvocation {
+    pub receiver: VaultId,
+    pub amount: Decimal,
+    pub contingent: bool,
+    pub callback: ScryptoFunctionIdent,
+}
+
+impl Invocation for VaultLockFeeWithCallbackInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for VaultLockFeeWithCallbackInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for VaultLockFeeWithCallbackInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Vault(
+            VaultMethodInvocation::LockFeeWithCallback(self),
+        ))
+        .into()
+    }
+}
+
 #[derive(PartialEq, Eq, Hash)]
 pub struct Vault(pub VaultId);
 
@@ -54,6 +54,32 @@ impl Into<SerializedInvocation> for WorktopTakeAmountInvocation {
     }
 }
 
+/// Takes an amount of a resource from the worktop, or an empty bucket if the worktop holds none
+/// of the resource.
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct WorktopTakeOrDefaultInvocation {
+    pub amount: Decimal,
+    pub resource_address: ResourceAddress,
+}
+
+impl Invocation for WorktopTakeOrDefaultInvocation {
+    type Output = Bucket;
+}
+
+impl SerializableInvocation for WorktopTakeOrDefaultInvocation {
+    type ScryptoOutput = Bucket;
+}
+
+impl Into<SerializedInvocation> for WorktopTakeOrDefaultInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Worktop(
+            WorktopMethodInvocation::TakeOrDefault(self),
+        ))
+        .into()
+    }
+}
+
 #[derive(Debug)]
 #[scrypto(TypeId, Encode, Decode)]
 pub struct WorktopTakeNonFungiblesInvocation {
@@ -147,6 +173,30 @@ impl Into<SerializedInvocation> for WorktopAssertContainsAmountInvocation {
     }
 }
 
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct WorktopAssertContainsExactAmountInvocation {
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+}
+
+impl Invocation for WorktopAssertContainsExactAmountInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for WorktopAssertContainsExactAmountInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for WorktopAssertContainsExactAmountInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Worktop(
+            WorktopMethodInvocation::AssertContainsExactAmount(self),
+        ))
+        .into()
+    }
+}
+
 #[derive(Debug)]
 #[scrypto(TypeId, Encode, Decode)]
 pub struct WorktopAssertContainsNonFungiblesInvocation {
@@ -171,6 +221,30 @@ impl Into<SerializedInvocation> for WorktopAssertContainsNonFungiblesInvocation
     }
 }
 
+/// Reads the total amount of a resource on the worktop, including any locked by proofs.
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct WorktopGetAmountInvocation {
+    pub resource_address: ResourceAddress,
+}
+
+impl Invocation for WorktopGetAmountInvocation {
+    type Output = Decimal;
+}
+
+impl SerializableInvocation for WorktopGetAmountInvocation {
+    type ScryptoOutput = Decimal;
+}
+
+impl Into<SerializedInvocation> for WorktopGetAmountInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::Worktop(
+            WorktopMethodInvocation::GetAmount(self),
+        ))
+        .into()
+    }
+}
+
 #[derive(Debug)]
 #[scrypto(TypeId, Encode, Decode)]
 pub struct WorktopDrainInvocation {}
@@ -0,0 +1,46 @@
+use crate::crypto::{sha256, Hash};
+use crate::model::PackageAddress;
+use sbor::rust::format;
+
+/// Computes the discriminant which prefixes the payload of an event emitted by a blueprint,
+/// i.e. `sha256("<package_address>::<blueprint_name>::<event_name>")`.
+pub fn scrypto_event_discriminant(
+    package_address: &PackageAddress,
+    blueprint_name: &str,
+    event_name: &str,
+) -> Hash {
+    sha256(format!(
+        "{}::{}::{}",
+        package_address.to_hex(),
+        blueprint_name,
+        event_name
+    ))
+}
+
+/// Splits a Scrypto event payload into its discriminant and SBOR encoded event.
+pub fn split_scrypto_event(payload: &[u8]) -> Option<(Hash, &[u8])> {
+    if payload.len() < Hash::LENGTH {
+        return None;
+    }
+    let (discriminant, event) = payload.split_at(Hash::LENGTH);
+    Some((Hash(discriminant.try_into().ok()?), event))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_scrypto_event() {
+        let discriminant =
+            scrypto_event_discriminant(&PackageAddress::Normal([1u8; 26]), "Vault", "DepositEvent");
+        let mut payload = discriminant.to_vec();
+        payload.extend([1u8, 2, 3]);
+
+        assert_eq!(
+            split_scrypto_event(&payload),
+            Some((discriminant, &[1u8, 2, 3][..]))
+        );
+        assert_eq!(split_scrypto_event(&[0u8; 3]), None);
+    }
+}
@@ -1,3 +1,5 @@
+mod event;
 mod invocations;
 
+pub use event::*;
 pub use invocations::*;
@@ -13,6 +13,8 @@ use crate::scrypto_type;
 pub enum SystemAddress {
     EpochManager([u8; 26]),
     Clock([u8; 26]),
+    Burner([u8; 26]),
+    TimeLockedVault([u8; 26]),
 }
 
 //========
@@ -29,6 +31,10 @@ impl TryFrom<&[u8]> for SystemAddress {
             {
                 EntityType::EpochManager => Ok(Self::EpochManager(copy_u8_array(&slice[1..]))),
                 EntityType::Clock => Ok(Self::Clock(copy_u8_array(&slice[1..]))),
+                EntityType::Burner => Ok(Self::Burner(copy_u8_array(&slice[1..]))),
+                EntityType::TimeLockedVault => {
+                    Ok(Self::TimeLockedVault(copy_u8_array(&slice[1..])))
+                }
                 _ => Err(AddressError::InvalidEntityTypeId(slice[0])),
             },
             _ => Err(AddressError::InvalidLength(slice.len())),
@@ -43,6 +49,8 @@ impl SystemAddress {
         match self {
             Self::EpochManager(v) => buf.extend(v),
             Self::Clock(v) => buf.extend(v),
+            Self::Burner(v) => buf.extend(v),
+            Self::TimeLockedVault(v) => buf.extend(v),
         }
         buf
     }
@@ -91,6 +99,10 @@ impl<'a> ContextualDisplay<AddressDisplayContext<'a>> for SystemAddress {
         match self {
             SystemAddress::EpochManager(_) => write!(f, "EpochManagerSystem[{}]", self.to_hex()),
             SystemAddress::Clock(_) => write!(f, "ClockSystem[{}]", self.to_hex()),
+            SystemAddress::Burner(_) => write!(f, "BurnerSystem[{}]", self.to_hex()),
+            SystemAddress::TimeLockedVault(_) => {
+                write!(f, "TimeLockedVaultSystem[{}]", self.to_hex())
+            }
         }
         .map_err(|err| AddressError::FormatError(err))
     }
@@ -0,0 +1,156 @@
+use sbor::rust::fmt::Debug;
+use sbor::*;
+
+use crate::api::api::*;
+use crate::math::Decimal;
+use crate::model::*;
+use crate::scrypto;
+use crate::wasm::*;
+
+/// Creates a vault of `resource_address` which only releases its contents once the epoch reaches
+/// `unlock_epoch`.
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct TimeLockedVaultCreateInvocation {
+    pub resource_address: ResourceAddress,
+    /// The rule which must be met to withdraw from the vault once it is unlocked
+    pub beneficiary_rule: AccessRule,
+    /// The rule which must be met to extend the lock
+    pub depositor_rule: AccessRule,
+    pub unlock_epoch: u64,
+}
+
+impl Invocation for TimeLockedVaultCreateInvocation {
+    type Output = SystemAddress;
+}
+
+impl SerializableInvocation for TimeLockedVaultCreateInvocation {
+    type ScryptoOutput = SystemAddress;
+}
+
+impl Into<SerializedInvocation> for TimeLockedVaultCreateInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Function(NativeFunctionInvocation::TimeLockedVault(
+            TimeLockedVaultFunctionInvocation::Create(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct TimeLockedVaultDepositInvocation {
+    pub receiver: SystemAddress,
+    pub bucket: Bucket,
+}
+
+impl Invocation for TimeLockedVaultDepositInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for TimeLockedVaultDepositInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for TimeLockedVaultDepositInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::TimeLockedVault(
+            TimeLockedVaultMethodInvocation::Deposit(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct TimeLockedVaultWithdrawInvocation {
+    pub receiver: SystemAddress,
+    pub amount: Decimal,
+}
+
+impl Invocation for TimeLockedVaultWithdrawInvocation {
+    type Output = Bucket;
+}
+
+impl SerializableInvocation for TimeLockedVaultWithdrawInvocation {
+    type ScryptoOutput = Bucket;
+}
+
+impl Into<SerializedInvocation> for TimeLockedVaultWithdrawInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::TimeLockedVault(
+            TimeLockedVaultMethodInvocation::Withdraw(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct TimeLockedVaultExtendLockInvocation {
+    pub receiver: SystemAddress,
+    pub unlock_epoch: u64,
+}
+
+impl Invocation for TimeLockedVaultExtendLockInvocation {
+    type Output = ();
+}
+
+impl SerializableInvocation for TimeLockedVaultExtendLockInvocation {
+    type ScryptoOutput = ();
+}
+
+impl Into<SerializedInvocation> for TimeLockedVaultExtendLockInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::TimeLockedVault(
+            TimeLockedVaultMethodInvocation::ExtendLock(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct TimeLockedVaultGetUnlockEpochInvocation {
+    pub receiver: SystemAddress,
+}
+
+impl Invocation for TimeLockedVaultGetUnlockEpochInvocation {
+    type Output = u64;
+}
+
+impl SerializableInvocation for TimeLockedVaultGetUnlockEpochInvocation {
+    type ScryptoOutput = u64;
+}
+
+impl Into<SerializedInvocation> for TimeLockedVaultGetUnlockEpochInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::TimeLockedVault(
+            TimeLockedVaultMethodInvocation::GetUnlockEpoch(self),
+        ))
+        .into()
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct TimeLockedVaultGetAmountInvocation {
+    pub receiver: SystemAddress,
+}
+
+impl Invocation for TimeLockedVaultGetAmountInvocation {
+    type Output = Decimal;
+}
+
+impl SerializableInvocation for TimeLockedVaultGetAmountInvocation {
+    type ScryptoOutput = Decimal;
+}
+
+impl Into<SerializedInvocation> for TimeLockedVaultGetAmountInvocation {
+    fn into(self) -> SerializedInvocation {
+        NativeFnInvocation::Method(NativeMethodInvocation::TimeLockedVault(
+            TimeLockedVaultMethodInvocation::GetAmount(self),
+        ))
+        .into()
+    }
+}
@@ -0,0 +1,3 @@
+mod invocations;
+
+pub use invocations::*;
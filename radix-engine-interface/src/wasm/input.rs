@@ -1,4 +1,5 @@
 use crate::api::types::*;
+use crate::crypto::HashAlgorithm;
 use crate::scrypto;
 use radix_engine_interface::api::api::InvokableModel;
 use radix_engine_interface::data::IndexedScryptoValue;
@@ -19,11 +20,18 @@ pub enum RadixEngineInput {
     DropLock(LockHandle),
     Read(LockHandle),
     Write(LockHandle, Vec<u8>),
+    BatchReadSubstates(Vec<(RENodeId, SubstateOffset)>),
+    BatchWriteSubstates(Vec<(RENodeId, SubstateOffset, Vec<u8>)>),
 
     GetActor(),
+    GetCaller(),
     EmitLog(Level, String),
+    EmitEvent(Vec<u8>),
     GenerateUuid(),
     GetTransactionHash(),
+    GetCurrentEpoch(),
+    GetVirtualProofResources(),
+    Hash(HashAlgorithm, Vec<u8>),
 }
 
 #[derive(Debug)]
@@ -55,6 +63,8 @@ pub enum NativeMethodInvocation {
     Component(ComponentMethodInvocation),
     EpochManager(EpochManagerMethodInvocation),
     Clock(ClockMethodInvocation),
+    Burner(BurnerMethodInvocation),
+    TimeLockedVault(TimeLockedVaultMethodInvocation),
     AuthZoneStack(AuthZoneStackMethodInvocation),
     ResourceManager(ResourceManagerMethodInvocation),
     Bucket(BucketMethodInvocation),
@@ -69,6 +79,8 @@ pub enum NativeFunctionInvocation {
     Component(ComponentFunctionInvocation),
     EpochManager(EpochManagerFunctionInvocation),
     Clock(ClockFunctionInvocation),
+    Burner(BurnerFunctionInvocation),
+    TimeLockedVault(TimeLockedVaultFunctionInvocation),
     ResourceManager(ResourceManagerFunctionInvocation),
     Package(PackageFunctionInvocation),
 }
@@ -117,11 +129,42 @@ pub enum ClockMethodInvocation {
     SetCurrentTime(ClockSetCurrentTimeInvocation),
 }
 
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum BurnerFunctionInvocation {
+    Create(BurnerCreateInvocation),
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum BurnerMethodInvocation {
+    Deposit(BurnerDepositInvocation),
+    DepositBatch(BurnerDepositBatchInvocation),
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum TimeLockedVaultFunctionInvocation {
+    Create(TimeLockedVaultCreateInvocation),
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum TimeLockedVaultMethodInvocation {
+    Deposit(TimeLockedVaultDepositInvocation),
+    Withdraw(TimeLockedVaultWithdrawInvocation),
+    ExtendLock(TimeLockedVaultExtendLockInvocation),
+    GetUnlockEpoch(TimeLockedVaultGetUnlockEpochInvocation),
+    GetAmount(TimeLockedVaultGetAmountInvocation),
+}
+
 #[derive(Debug)]
 #[scrypto(TypeId, Encode, Decode)]
 pub enum ComponentMethodInvocation {
     SetRoyaltyConfig(ComponentSetRoyaltyConfigInvocation),
     ClaimRoyalty(ComponentClaimRoyaltyInvocation),
+    SetAuthMutability(ComponentSetAuthMutabilityInvocation),
+    StateSizeBytes(ComponentStateSizeBytesInvocation),
 }
 
 #[derive(Debug)]
@@ -129,6 +172,7 @@ pub enum ComponentMethodInvocation {
 pub enum PackageMethodInvocation {
     SetRoyaltyConfig(PackageSetRoyaltyConfigInvocation),
     ClaimRoyalty(PackageClaimRoyaltyInvocation),
+    GetBuildFingerprint(PackageGetBuildFingerprintInvocation),
 }
 
 #[derive(Debug)]
@@ -173,6 +217,11 @@ pub enum ResourceManagerMethodInvocation {
     UpdateNonFungibleData(ResourceManagerUpdateNonFungibleDataInvocation),
     GetNonFungible(ResourceManagerGetNonFungibleInvocation),
     NonFungibleExists(ResourceManagerNonFungibleExistsInvocation),
+    GetSummary(ResourceManagerGetSummaryInvocation),
+    GetHolderVaultCount(ResourceManagerGetHolderVaultCountInvocation),
+    GetAmountMinted(ResourceManagerGetAmountMintedInvocation),
+    GetAmountBurned(ResourceManagerGetAmountBurnedInvocation),
+    CreateSyntheticProof(ResourceManagerCreateSyntheticProofInvocation),
 }
 
 #[derive(Debug)]
@@ -202,6 +251,7 @@ pub enum VaultMethodInvocation {
     CreateProofByIds(VaultCreateProofByIdsInvocation),
     Recall(VaultRecallInvocation),
     RecallNonFungibles(VaultRecallNonFungiblesInvocation),
+    LockFeeWithCallback(VaultLockFeeWithCallbackInvocation),
 }
 
 #[derive(Debug)]
@@ -211,6 +261,7 @@ pub enum ProofMethodInvocation {
     GetAmount(ProofGetAmountInvocation),
     GetNonFungibleIds(ProofGetNonFungibleIdsInvocation),
     GetResourceAddress(ProofGetResourceAddressInvocation),
+    IsValid(ProofIsValidInvocation),
 }
 
 #[derive(Debug)]
@@ -218,11 +269,14 @@ pub enum ProofMethodInvocation {
 pub enum WorktopMethodInvocation {
     TakeAll(WorktopTakeAllInvocation),
     TakeAmount(WorktopTakeAmountInvocation),
+    TakeOrDefault(WorktopTakeOrDefaultInvocation),
     TakeNonFungibles(WorktopTakeNonFungiblesInvocation),
     Put(WorktopPutInvocation),
     AssertContains(WorktopAssertContainsInvocation),
     AssertContainsAmount(WorktopAssertContainsAmountInvocation),
+    AssertContainsExactAmount(WorktopAssertContainsExactAmountInvocation),
     AssertContainsNonFungibles(WorktopAssertContainsNonFungiblesInvocation),
+    GetAmount(WorktopGetAmountInvocation),
     Drain(WorktopDrainInvocation),
 }
 
@@ -250,6 +304,16 @@ impl NativeFnInvocation {
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a)),
                 },
+                NativeFunctionInvocation::Burner(invocation) => match invocation {
+                    BurnerFunctionInvocation::Create(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                },
+                NativeFunctionInvocation::TimeLockedVault(invocation) => match invocation {
+                    TimeLockedVaultFunctionInvocation::Create(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                },
                 NativeFunctionInvocation::ResourceManager(invocation) => match invocation {
                     ResourceManagerFunctionInvocation::Create(invocation) => api
                         .invoke(invocation)
@@ -286,6 +350,12 @@ impl NativeFnInvocation {
                     ComponentMethodInvocation::ClaimRoyalty(invocation) => api
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    ComponentMethodInvocation::SetAuthMutability(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    ComponentMethodInvocation::StateSizeBytes(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
                 },
                 NativeMethodInvocation::Package(package_method) => match package_method {
                     PackageMethodInvocation::SetRoyaltyConfig(invocation) => api
@@ -294,6 +364,9 @@ impl NativeFnInvocation {
                     PackageMethodInvocation::ClaimRoyalty(invocation) => api
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    PackageMethodInvocation::GetBuildFingerprint(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
                 },
                 NativeMethodInvocation::Bucket(bucket_method) => match bucket_method {
                     BucketMethodInvocation::Take(invocation) => api
@@ -354,6 +427,9 @@ impl NativeFnInvocation {
                     ProofMethodInvocation::GetResourceAddress(invocation) => api
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    ProofMethodInvocation::IsValid(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
                     ProofMethodInvocation::Clone(invocation) => api
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a)),
@@ -395,6 +471,9 @@ impl NativeFnInvocation {
                     VaultMethodInvocation::RecallNonFungibles(invocation) => api
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    VaultMethodInvocation::LockFeeWithCallback(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
                 },
                 NativeMethodInvocation::AccessRulesChain(access_rules_method) => {
                     match access_rules_method {
@@ -460,6 +539,21 @@ impl NativeFnInvocation {
                     ResourceManagerMethodInvocation::GetNonFungible(invocation) => api
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    ResourceManagerMethodInvocation::GetSummary(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    ResourceManagerMethodInvocation::GetHolderVaultCount(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    ResourceManagerMethodInvocation::GetAmountMinted(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    ResourceManagerMethodInvocation::GetAmountBurned(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    ResourceManagerMethodInvocation::CreateSyntheticProof(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
                 },
                 NativeMethodInvocation::EpochManager(epoch_manager_method) => {
                     match epoch_manager_method {
@@ -482,6 +576,33 @@ impl NativeFnInvocation {
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a)),
                 },
+                NativeMethodInvocation::Burner(burner_method) => match burner_method {
+                    BurnerMethodInvocation::Deposit(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    BurnerMethodInvocation::DepositBatch(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                },
+                NativeMethodInvocation::TimeLockedVault(time_locked_vault_method) => {
+                    match time_locked_vault_method {
+                        TimeLockedVaultMethodInvocation::Deposit(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        TimeLockedVaultMethodInvocation::Withdraw(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        TimeLockedVaultMethodInvocation::ExtendLock(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        TimeLockedVaultMethodInvocation::GetUnlockEpoch(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                        TimeLockedVaultMethodInvocation::GetAmount(invocation) => api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    }
+                }
                 NativeMethodInvocation::Worktop(worktop_method) => match worktop_method {
                     WorktopMethodInvocation::TakeNonFungibles(invocation) => api
                         .invoke(invocation)
@@ -501,12 +622,21 @@ impl NativeFnInvocation {
                     WorktopMethodInvocation::AssertContainsAmount(invocation) => api
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    WorktopMethodInvocation::AssertContainsExactAmount(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    WorktopMethodInvocation::GetAmount(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
                     WorktopMethodInvocation::TakeAll(invocation) => api
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a)),
                     WorktopMethodInvocation::TakeAmount(invocation) => api
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a)),
+                    WorktopMethodInvocation::TakeOrDefault(invocation) => api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a)),
                 },
             },
         }
@@ -0,0 +1,53 @@
+//! A `no_std` consumer of Radix Engine, which re-executes transactions against a substate store
+//! provided by the host.
+//!
+//! This crate builds Radix Engine with the `alloc` feature, and is tested in CI to make sure the
+//! engine keeps working without the standard library.
+
+#![no_std]
+
+use radix_engine::engine::ScryptoInterpreter;
+use radix_engine::ledger::{ReadableSubstateStore, StateTreeStore, WriteableSubstateStore};
+use radix_engine::transaction::{
+    execute_and_commit_transaction, ExecutionConfig, FeeReserveConfig, TransactionReceipt,
+};
+use radix_engine::wasm::{
+    DefaultWasmEngine, InstructionCostRules, WasmInstrumenter, WasmMeteringConfig,
+};
+use transaction::model::Executable;
+
+/// Executes transactions against a substate store, committing the results.
+pub struct Verifier<S: ReadableSubstateStore + WriteableSubstateStore + StateTreeStore> {
+    substate_store: S,
+    scrypto_interpreter: ScryptoInterpreter<DefaultWasmEngine>,
+}
+
+impl<S: ReadableSubstateStore + WriteableSubstateStore + StateTreeStore> Verifier<S> {
+    pub fn new(substate_store: S) -> Self {
+        Self {
+            substate_store,
+            scrypto_interpreter: ScryptoInterpreter {
+                wasm_engine: DefaultWasmEngine::default(),
+                wasm_instrumenter: WasmInstrumenter::default(),
+                wasm_metering_config: WasmMeteringConfig::new(
+                    InstructionCostRules::tiered(1, 5, 10, 5000),
+                    1024,
+                ),
+            },
+        }
+    }
+
+    pub fn substate_store(&self) -> &S {
+        &self.substate_store
+    }
+
+    pub fn execute(&mut self, executable: &Executable) -> TransactionReceipt {
+        execute_and_commit_transaction(
+            &mut self.substate_store,
+            &self.scrypto_interpreter,
+            &FeeReserveConfig::default(),
+            &ExecutionConfig::default(),
+            executable,
+        )
+    }
+}
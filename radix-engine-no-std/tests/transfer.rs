@@ -0,0 +1,41 @@
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::types::*;
+use radix_engine_constants::DEFAULT_COST_UNIT_LIMIT;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::data::*;
+use radix_engine_no_std::Verifier;
+use transaction::builder::ManifestBuilder;
+use transaction::model::TestTransaction;
+use transaction::signing::EcdsaSecp256k1PrivateKey;
+
+#[test]
+fn transfer_from_faucet_should_succeed_without_std() {
+    // Arrange
+    let mut verifier = Verifier::new(TypedInMemorySubstateStore::with_bootstrap());
+    let public_key = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let account = ComponentAddress::virtual_account_from_public_key(&public_key);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(FAUCET_COMPONENT, "free", args!())
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let transaction = TestTransaction::new(manifest, 1, DEFAULT_COST_UNIT_LIMIT);
+    let receipt = verifier.execute(&transaction.get_executable(vec![]));
+
+    // Assert
+    receipt.expect_commit_success();
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(account, "balance", args!(RADIX_TOKEN))
+        .build();
+    let transaction = TestTransaction::new(manifest, 2, DEFAULT_COST_UNIT_LIMIT);
+    let receipt = verifier.execute(&transaction.get_executable(vec![]));
+    let balance: Decimal = receipt.output(1);
+    assert_eq!(balance, 1000.into());
+}
@@ -1,20 +1,22 @@
 use radix_engine::ledger::{
-    bootstrap, OutputValue, QueryableSubstateStore, ReadableSubstateStore, WriteableSubstateStore,
+    bootstrap, InMemoryStateTree, OutputValue, QueryableSubstateStore, ReadableSubstateStore,
+    StateTreeNodeKey, StateTreeStore, WriteableSubstateStore,
 };
 use radix_engine::model::PersistedSubstate;
 use radix_engine::types::*;
-use radix_engine_interface::api::types::RENodeId;
 
 /// A substate store that stores all typed substates in host memory.
 #[derive(Debug, PartialEq, Eq)]
 pub struct SerializedInMemorySubstateStore {
     substates: HashMap<Vec<u8>, Vec<u8>>,
+    state_tree: InMemoryStateTree,
 }
 
 impl SerializedInMemorySubstateStore {
     pub fn new() -> Self {
         Self {
             substates: HashMap::new(),
+            state_tree: InMemoryStateTree::new(),
         }
     }
 
@@ -48,6 +50,16 @@ impl WriteableSubstateStore for SerializedInMemorySubstateStore {
     }
 }
 
+impl StateTreeStore for SerializedInMemorySubstateStore {
+    fn get_tree_node(&self, key: &StateTreeNodeKey) -> Option<Hash> {
+        self.state_tree.get_tree_node(key)
+    }
+
+    fn put_tree_node(&mut self, key: StateTreeNodeKey, hash: Hash) {
+        self.state_tree.put_tree_node(key, hash);
+    }
+}
+
 impl QueryableSubstateStore for SerializedInMemorySubstateStore {
     fn get_kv_store_entries(
         &self,
@@ -57,14 +69,28 @@ impl QueryableSubstateStore for SerializedInMemorySubstateStore {
             .iter()
             .filter_map(|(key, value)| {
                 let substate_id: SubstateId = scrypto_decode(key).unwrap();
-                if let SubstateId(
-                    RENodeId::KeyValueStore(id),
-                    SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)),
-                ) = substate_id
-                {
+                if let Some((id, key)) = substate_id.try_into_kv_entry() {
                     let output_value: OutputValue = scrypto_decode(value).unwrap();
                     if id == *kv_store_id {
-                        Some((key.clone(), output_value.substate))
+                        Some((key, output_value.substate))
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+    fn get_resource_vaults(&self, resource_address: &ResourceAddress) -> Vec<VaultId> {
+        self.substates
+            .iter()
+            .filter_map(|(key, value)| {
+                let substate_id: SubstateId = scrypto_decode(key).unwrap();
+                if let Some(id) = substate_id.try_into_vault() {
+                    let output_value: OutputValue = scrypto_decode(value).unwrap();
+                    if output_value.substate.vault().0.resource_address() == *resource_address {
+                        Some(id)
                     } else {
                         None
                     }
@@ -74,4 +100,11 @@ impl QueryableSubstateStore for SerializedInMemorySubstateStore {
             })
             .collect()
     }
+
+    fn get_all_substates(&self) -> Vec<(SubstateId, OutputValue)> {
+        self.substates
+            .iter()
+            .map(|(key, value)| (scrypto_decode(key).unwrap(), scrypto_decode(value).unwrap()))
+            .collect()
+    }
 }
@@ -2,18 +2,45 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 
 use radix_engine::ledger::*;
-use radix_engine::model::PersistedSubstate;
+use radix_engine::model::{KeyValueStoreEntrySubstate, NonFungibleSubstate, PersistedSubstate};
 use radix_engine::types::*;
 use radix_engine_interface::{api::types::RENodeId, data::ScryptoDecode};
-use rocksdb::{DBWithThreadMode, Direction, IteratorMode, SingleThreaded, DB};
+use rocksdb::{DBWithThreadMode, Direction, IteratorMode, Options, SingleThreaded, DB};
+
+/// Column family indexing vaults by the resource they hold, keyed by `(ResourceAddress, VaultId)`.
+const RESOURCE_VAULTS_CF: &str = "resource_vaults";
+
+/// Column family holding the state tree node hashes, keyed by `StateTreeNodeKey`.
+const STATE_TREE_CF: &str = "state_tree";
 
 pub struct RadixEngineDB {
     db: DBWithThreadMode<SingleThreaded>,
 }
 
+/// Statistics about the substates kept in a [`RadixEngineDB`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StoreStats {
+    pub substate_count_by_type: BTreeMap<&'static str, u64>,
+    /// The encoded size of all substates, including their ids
+    pub total_bytes: u64,
+    /// Deleted key value store entries and burnt non-fungibles, which [`RadixEngineDB::prune`]
+    /// can remove
+    pub tombstone_count: u64,
+    /// The encoded size of the tombstones, including their ids
+    pub tombstone_bytes: u64,
+}
+
 impl RadixEngineDB {
     pub fn new(root: PathBuf) -> Self {
-        let db = DB::open_default(root.as_path()).unwrap();
+        let mut options = Options::default();
+        options.create_if_missing(true);
+        options.create_missing_column_families(true);
+        let db = DB::open_cf(
+            &options,
+            root.as_path(),
+            [RESOURCE_VAULTS_CF, STATE_TREE_CF],
+        )
+        .unwrap();
         Self { db }
     }
 
@@ -24,25 +51,19 @@ impl RadixEngineDB {
     }
 
     pub fn list_packages(&self) -> Vec<PackageAddress> {
-        let start = &scrypto_encode(&SubstateId(
-            RENodeId::Global(GlobalAddress::Package(PackageAddress::Normal([0; 26]))),
-            SubstateOffset::Global(GlobalOffset::Global),
-        ))
+        let start = &scrypto_encode(&SubstateId::global(GlobalAddress::Package(
+            PackageAddress::Normal([0; 26]),
+        )))
         .unwrap();
-        let end = &scrypto_encode(&SubstateId(
-            RENodeId::Global(GlobalAddress::Package(PackageAddress::Normal([255; 26]))),
-            SubstateOffset::Global(GlobalOffset::Global),
-        ))
+        let end = &scrypto_encode(&SubstateId::global(GlobalAddress::Package(
+            PackageAddress::Normal([255; 26]),
+        )))
         .unwrap();
         let substate_ids: Vec<SubstateId> = self.list_items(start, end);
         substate_ids
             .into_iter()
             .map(|id| {
-                if let SubstateId(
-                    RENodeId::Global(GlobalAddress::Package(package_address)),
-                    SubstateOffset::Global(GlobalOffset::Global),
-                ) = id
-                {
+                if let Some(GlobalAddress::Package(package_address)) = id.try_into_global() {
                     package_address
                 } else {
                     panic!("Expected a package global substate id.")
@@ -56,25 +77,13 @@ impl RadixEngineDB {
         start: ComponentAddress,
         end: ComponentAddress,
     ) -> Vec<ComponentAddress> {
-        let start = &scrypto_encode(&SubstateId(
-            RENodeId::Global(GlobalAddress::Component(start)),
-            SubstateOffset::Global(GlobalOffset::Global),
-        ))
-        .unwrap();
-        let end = &scrypto_encode(&SubstateId(
-            RENodeId::Global(GlobalAddress::Component(end)),
-            SubstateOffset::Global(GlobalOffset::Global),
-        ))
-        .unwrap();
+        let start = &scrypto_encode(&SubstateId::global(GlobalAddress::Component(start))).unwrap();
+        let end = &scrypto_encode(&SubstateId::global(GlobalAddress::Component(end))).unwrap();
         let substate_ids: Vec<SubstateId> = self.list_items(start, end);
         substate_ids
             .into_iter()
             .map(|id| {
-                if let SubstateId(
-                    RENodeId::Global(GlobalAddress::Component(component_address)),
-                    SubstateOffset::Global(GlobalOffset::Global),
-                ) = id
-                {
+                if let Some(GlobalAddress::Component(component_address)) = id.try_into_global() {
                     component_address
                 } else {
                     panic!("Expected a component global substate id.")
@@ -97,25 +106,19 @@ impl RadixEngineDB {
     }
 
     pub fn list_resource_managers(&self) -> Vec<ResourceAddress> {
-        let start = &scrypto_encode(&SubstateId(
-            RENodeId::Global(GlobalAddress::Resource(ResourceAddress::Normal([0; 26]))),
-            SubstateOffset::Global(GlobalOffset::Global),
-        ))
+        let start = &scrypto_encode(&SubstateId::global(GlobalAddress::Resource(
+            ResourceAddress::Normal([0; 26]),
+        )))
         .unwrap();
-        let end = &scrypto_encode(&SubstateId(
-            RENodeId::Global(GlobalAddress::Resource(ResourceAddress::Normal([255; 26]))),
-            SubstateOffset::Global(GlobalOffset::Global),
-        ))
+        let end = &scrypto_encode(&SubstateId::global(GlobalAddress::Resource(
+            ResourceAddress::Normal([255; 26]),
+        )))
         .unwrap();
         let substate_ids: Vec<SubstateId> = self.list_items(start, end);
         substate_ids
             .into_iter()
             .map(|id| {
-                if let SubstateId(
-                    RENodeId::Global(GlobalAddress::Resource(resource_address)),
-                    SubstateOffset::Global(GlobalOffset::Global),
-                ) = id
-                {
+                if let Some(GlobalAddress::Resource(resource_address)) = id.try_into_global() {
                     resource_address
                 } else {
                     panic!("Expected a resource manager global substate id.")
@@ -124,6 +127,62 @@ impl RadixEngineDB {
             .collect()
     }
 
+    pub fn stats(&self) -> StoreStats {
+        let mut stats = StoreStats::default();
+        for kv in self.db.iterator(IteratorMode::Start) {
+            let (key, value) = kv.unwrap();
+            let output_value: OutputValue =
+                scrypto_decode(value.as_ref()).expect("Could not decode persisted substate");
+            let bytes = (key.len() + value.len()) as u64;
+
+            *stats
+                .substate_count_by_type
+                .entry(substate_type_name(&output_value.substate))
+                .or_default() += 1;
+            stats.total_bytes += bytes;
+            if is_tombstone(&output_value.substate) {
+                stats.tombstone_count += 1;
+                stats.tombstone_bytes += bytes;
+            }
+        }
+        stats
+    }
+
+    /// Removes the tombstones last written before the given substate version, together with their
+    /// state tree leaves, and returns how many were removed.
+    ///
+    /// Only the latest version of each substate is kept, so there is no older history to prune.
+    /// A removed tombstone reads the same as one never written, except that its version restarts
+    /// from zero.
+    pub fn prune(&mut self, before_version: u64) -> u64 {
+        let pruned: Vec<SubstateId> = self
+            .db
+            .iterator(IteratorMode::Start)
+            .filter_map(|kv| {
+                let (key, value) = kv.unwrap();
+                let output_value: OutputValue =
+                    scrypto_decode(value.as_ref()).expect("Could not decode persisted substate");
+                if is_tombstone(&output_value.substate)
+                    && (output_value.version as u64) < before_version
+                {
+                    Some(scrypto_decode(key.as_ref()).expect("Could not decode substate id"))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        for substate_id in &pruned {
+            self.db
+                .delete(scrypto_encode(substate_id).expect("Could not encode substate id"))
+                .unwrap();
+        }
+        remove_from_state_tree(self, pruned.iter().cloned());
+        self.db.compact_range::<&[u8], &[u8]>(None, None);
+
+        pruned.len() as u64
+    }
+
     fn list_items<T: ScryptoDecode>(&self, start: &[u8], inclusive_end: &[u8]) -> Vec<T> {
         let mut iter = self
             .db
@@ -148,6 +207,17 @@ impl RadixEngineDB {
             .unwrap()
     }
 
+    fn index_vault(&self, vault_id: VaultId, resource_address: ResourceAddress) {
+        let cf = self.db.cf_handle(RESOURCE_VAULTS_CF).unwrap();
+        self.db
+            .put_cf(
+                cf,
+                scrypto_encode(&(resource_address, vault_id)).expect("Could not encode index key"),
+                Vec::<u8>::new(),
+            )
+            .unwrap();
+    }
+
     fn write(&self, substate_id: SubstateId, value: Vec<u8>) {
         self.db
             .put(
@@ -158,17 +228,49 @@ impl RadixEngineDB {
     }
 }
 
+fn is_tombstone(substate: &PersistedSubstate) -> bool {
+    matches!(
+        substate,
+        PersistedSubstate::KeyValueStoreEntry(KeyValueStoreEntrySubstate(None))
+            | PersistedSubstate::NonFungible(NonFungibleSubstate {
+                non_fungible: None,
+                ..
+            })
+    )
+}
+
+fn substate_type_name(substate: &PersistedSubstate) -> &'static str {
+    match substate {
+        PersistedSubstate::Global(..) => "Global",
+        PersistedSubstate::EpochManager(..) => "EpochManager",
+        PersistedSubstate::CurrentTimeRoundedToMinutes(..) => "CurrentTimeRoundedToMinutes",
+        PersistedSubstate::Burner(..) => "Burner",
+        PersistedSubstate::TimeLockedVault(..) => "TimeLockedVault",
+        PersistedSubstate::ResourceManager(..) => "ResourceManager",
+        PersistedSubstate::AccessRulesChain(..) => "AccessRulesChain",
+        PersistedSubstate::Metadata(..) => "Metadata",
+        PersistedSubstate::ComponentInfo(..) => "ComponentInfo",
+        PersistedSubstate::ComponentState(..) => "ComponentState",
+        PersistedSubstate::ComponentRoyaltyConfig(..) => "ComponentRoyaltyConfig",
+        PersistedSubstate::ComponentRoyaltyAccumulator(..) => "ComponentRoyaltyAccumulator",
+        PersistedSubstate::PackageInfo(..) => "PackageInfo",
+        PersistedSubstate::PackageRoyaltyConfig(..) => "PackageRoyaltyConfig",
+        PersistedSubstate::PackageRoyaltyAccumulator(..) => "PackageRoyaltyAccumulator",
+        PersistedSubstate::Vault(..) => "Vault",
+        PersistedSubstate::NonFungible(..) => "NonFungible",
+        PersistedSubstate::KeyValueStoreEntry(..) => "KeyValueStoreEntry",
+    }
+}
+
 impl QueryableSubstateStore for RadixEngineDB {
     fn get_kv_store_entries(
         &self,
         kv_store_id: &KeyValueStoreId,
     ) -> HashMap<Vec<u8>, PersistedSubstate> {
         let unit = scrypto_encode(&()).unwrap();
-        let id = scrypto_encode(&SubstateId(
-            RENodeId::KeyValueStore(kv_store_id.clone()),
-            SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(
-                scrypto_encode(&unit).unwrap(),
-            )),
+        let id = scrypto_encode(&SubstateId::kv_entry(
+            kv_store_id.clone(),
+            scrypto_encode(&unit).unwrap(),
         ))
         .unwrap();
 
@@ -180,11 +282,7 @@ impl QueryableSubstateStore for RadixEngineDB {
             let (key, value) = kv.unwrap();
             let substate: OutputValue = scrypto_decode(&value.to_vec()).unwrap();
             let substate_id: SubstateId = scrypto_decode(&key).unwrap();
-            if let SubstateId(
-                RENodeId::KeyValueStore(id),
-                SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)),
-            ) = substate_id
-            {
+            if let Some((id, key)) = substate_id.try_into_kv_entry() {
                 if id == *kv_store_id {
                     items.insert(key, substate.substate)
                 } else {
@@ -196,6 +294,63 @@ impl QueryableSubstateStore for RadixEngineDB {
         }
         items
     }
+
+    fn get_resource_vaults(&self, resource_address: &ResourceAddress) -> Vec<VaultId> {
+        let start = scrypto_encode(&(*resource_address, [0u8; 36])).unwrap();
+        let end = scrypto_encode(&(*resource_address, [255u8; 36])).unwrap();
+
+        let cf = self.db.cf_handle(RESOURCE_VAULTS_CF).unwrap();
+        let mut iter = self
+            .db
+            .iterator_cf(cf, IteratorMode::From(&start, Direction::Forward));
+        let mut items = Vec::new();
+        while let Some(kv) = iter.next() {
+            let (key, _value) = kv.unwrap();
+            if key.as_ref() > end.as_slice() {
+                break;
+            }
+            let (_, vault_id): (ResourceAddress, VaultId) = scrypto_decode(key.as_ref()).unwrap();
+            items.push(vault_id);
+        }
+        items
+    }
+
+    fn get_all_substates(&self) -> Vec<(SubstateId, OutputValue)> {
+        self.db
+            .iterator(IteratorMode::Start)
+            .map(|kv| {
+                let (key, value) = kv.unwrap();
+                (
+                    scrypto_decode(key.as_ref()).unwrap(),
+                    scrypto_decode(value.as_ref()).unwrap(),
+                )
+            })
+            .collect()
+    }
+}
+
+impl StateTreeStore for RadixEngineDB {
+    fn get_tree_node(&self, key: &StateTreeNodeKey) -> Option<Hash> {
+        let cf = self.db.cf_handle(STATE_TREE_CF).unwrap();
+        self.db
+            .get_cf(
+                cf,
+                scrypto_encode(key).expect("Could not encode state tree node key"),
+            )
+            .unwrap()
+            .map(|b| scrypto_decode(&b).expect("Could not decode state tree node"))
+    }
+
+    fn put_tree_node(&mut self, key: StateTreeNodeKey, hash: Hash) {
+        let cf = self.db.cf_handle(STATE_TREE_CF).unwrap();
+        self.db
+            .put_cf(
+                cf,
+                scrypto_encode(&key).expect("Could not encode state tree node key"),
+                scrypto_encode(&hash).expect("Could not encode state tree node"),
+            )
+            .unwrap();
+    }
 }
 
 impl ReadableSubstateStore for RadixEngineDB {
@@ -207,6 +362,12 @@ impl ReadableSubstateStore for RadixEngineDB {
 
 impl WriteableSubstateStore for RadixEngineDB {
     fn put_substate(&mut self, substate_id: SubstateId, substate: OutputValue) {
+        // Vaults never change resource, so the index only ever needs inserting into
+        if let (RENodeId::Vault(vault_id), PersistedSubstate::Vault(vault)) =
+            (&substate_id.0, &substate.substate)
+        {
+            self.index_vault(*vault_id, vault.0.resource_address());
+        }
         self.write(
             substate_id,
             scrypto_encode(&substate).expect("Could not encode substate for persistence"),
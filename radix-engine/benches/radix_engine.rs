@@ -12,6 +12,7 @@ use radix_engine_interface::data::*;
 use radix_engine_interface::dec;
 use radix_engine_interface::model::FromPublicKey;
 use radix_engine_interface::rule;
+use scrypto_unit::TestRunner;
 use transaction::builder::ManifestBuilder;
 use transaction::model::TestTransaction;
 use transaction::signing::EcdsaSecp256k1PrivateKey;
@@ -120,5 +121,98 @@ fn bench_transfer(c: &mut Criterion) {
     });
 }
 
-criterion_group!(radix_engine, bench_transfer);
+fn bench_kv_store_initialization(c: &mut Criterion) {
+    let mut substate_store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(false, &mut substate_store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/kv_store");
+
+    for (name, function_name) in [
+        ("KeyValueStore 100 inserts", "insert_one_by_one"),
+        ("KeyValueStore 100-entry batch put", "batch_put"),
+    ] {
+        let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+            .lock_fee(FAUCET_COMPONENT, 100.into())
+            .call_function(
+                package_address,
+                "BatchKeyValueStore",
+                function_name,
+                args!(100u32),
+            )
+            .build();
+        c.bench_function(name, |b| {
+            b.iter(|| {
+                test_runner
+                    .execute_manifest(manifest.clone(), vec![])
+                    .expect_commit_success();
+            })
+        });
+    }
+}
+
+fn bench_current_epoch(c: &mut Criterion) {
+    let mut substate_store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(false, &mut substate_store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/epoch_manager");
+
+    for (name, function_name) in [
+        ("Runtime::current_epoch x1000", "get_epoch_repeatedly"),
+        (
+            "EpochManager::get_current_epoch x1000",
+            "get_epoch_via_invocation_repeatedly",
+        ),
+    ] {
+        let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+            .lock_fee(FAUCET_COMPONENT, 100.into())
+            .call_function(
+                package_address,
+                "EpochManagerTest",
+                function_name,
+                args!(1000u32),
+            )
+            .build();
+        c.bench_function(name, |b| {
+            b.iter(|| {
+                test_runner
+                    .execute_manifest(manifest.clone(), vec![])
+                    .expect_commit_success();
+            })
+        });
+    }
+}
+
+fn bench_nested_calls(c: &mut Criterion) {
+    let mut substate_store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(false, &mut substate_store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/recursion");
+
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 100.into())
+        .call_function(package_address, "Caller", "recursive", args!(50u32))
+        .build();
+    let transaction = TestTransaction::new(manifest, 1, DEFAULT_COST_UNIT_LIMIT);
+    let executable = transaction.get_executable(vec![]);
+    let execution_config = ExecutionConfig {
+        max_call_depth: 64,
+        ..ExecutionConfig::default()
+    };
+    c.bench_function("Caller::recursive 50 nested calls", |b| {
+        b.iter(|| {
+            test_runner
+                .execute_transaction_with_config(
+                    &executable,
+                    &FeeReserveConfig::default(),
+                    &execution_config,
+                )
+                .expect_commit_success();
+        })
+    });
+}
+
+criterion_group!(
+    radix_engine,
+    bench_transfer,
+    bench_kv_store_initialization,
+    bench_current_epoch,
+    bench_nested_calls
+);
 criterion_main!(radix_engine);
@@ -0,0 +1,396 @@
+//! Generates the golden test vectors under `test-vectors/`, for testing other implementations
+//! of the manifest and transaction formats against this one.
+//!
+//! Each vector is a directory holding:
+//! * `manifest.rtm` - the manifest text
+//! * `instructions.hex` - the SBOR-encoded instruction list
+//! * `blobs.txt` - the hash of each blob
+//! * `intent_hash.txt` - the intent hash
+//! * `receipt.txt` - the key fields of the receipt, after executing on a fresh ledger
+//!
+//! Usage: `generate_test_vectors [--check] [<directory>]`. With `--check`, nothing is written, and
+//! the process fails if the vectors differ from those in the directory.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use radix_engine::engine::ScryptoInterpreter;
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::transaction::{
+    execute_and_commit_transaction, ExecutionConfig, FeeReserveConfig, TransactionOutcome,
+    TransactionReceipt, TransactionResult,
+};
+use radix_engine::types::*;
+use radix_engine::wasm::{
+    DefaultWasmEngine, InstructionCostRules, WasmInstrumenter, WasmMeteringConfig,
+};
+use radix_engine_interface::abi::BlueprintAbi;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::data::*;
+use radix_engine_interface::model::FromPublicKey;
+use radix_engine_interface::rule;
+use transaction::builder::{ManifestBuilder, TransactionBuilder};
+use transaction::manifest::decompile;
+use transaction::model::{NotarizedTransaction, TransactionHeader, TransactionManifest};
+use transaction::signing::EcdsaSecp256k1PrivateKey;
+use transaction::validation::{
+    NotarizedTransactionValidator, TestIntentHashManager, TransactionValidator, ValidationConfig,
+};
+
+/// Executes transactions against a fresh ledger, signing them with fixed keys and nonces so that
+/// every run produces the same hashes and addresses.
+struct VectorRunner {
+    substate_store: TypedInMemorySubstateStore,
+    scrypto_interpreter: ScryptoInterpreter<DefaultWasmEngine>,
+    network: NetworkDefinition,
+    notary: EcdsaSecp256k1PrivateKey,
+    nonce: u64,
+}
+
+impl VectorRunner {
+    fn new() -> Self {
+        Self {
+            substate_store: TypedInMemorySubstateStore::with_bootstrap(),
+            scrypto_interpreter: ScryptoInterpreter {
+                wasm_engine: DefaultWasmEngine::default(),
+                wasm_instrumenter: WasmInstrumenter::default(),
+                wasm_metering_config: WasmMeteringConfig::new(
+                    InstructionCostRules::tiered(1, 5, 10, 5000),
+                    1024,
+                ),
+            },
+            network: NetworkDefinition::simulator(),
+            notary: EcdsaSecp256k1PrivateKey::from_u64(100).unwrap(),
+            nonce: 0,
+        }
+    }
+
+    fn manifest_builder(&self) -> ManifestBuilder {
+        ManifestBuilder::new(&self.network)
+    }
+
+    fn sign(
+        &mut self,
+        manifest: TransactionManifest,
+        signers: &[&EcdsaSecp256k1PrivateKey],
+    ) -> NotarizedTransaction {
+        self.nonce += 1;
+        let mut builder = TransactionBuilder::new()
+            .header(TransactionHeader {
+                version: 1,
+                network_id: self.network.id,
+                start_epoch_inclusive: 0,
+                end_epoch_exclusive: 100,
+                nonce: self.nonce,
+                notary_public_key: self.notary.public_key().into(),
+                notary_as_signatory: false,
+                cost_unit_limit: 10_000_000,
+                tip_percentage: 0,
+            })
+            .manifest(manifest);
+        for signer in signers {
+            builder = builder.sign(*signer);
+        }
+        builder.notarize(&self.notary).build()
+    }
+
+    fn execute(&mut self, transaction: &NotarizedTransaction) -> TransactionReceipt {
+        let executable =
+            NotarizedTransactionValidator::new(ValidationConfig::default(self.network.id))
+                .validate(transaction, &TestIntentHashManager::new())
+                .expect("Test vector transaction should be valid");
+
+        execute_and_commit_transaction(
+            &mut self.substate_store,
+            &self.scrypto_interpreter,
+            &FeeReserveConfig::default(),
+            &ExecutionConfig::default(),
+            &executable,
+        )
+    }
+
+    /// Executes a transaction which is not part of any vector, expecting it to succeed.
+    fn execute_setup(
+        &mut self,
+        manifest: TransactionManifest,
+        signers: &[&EcdsaSecp256k1PrivateKey],
+    ) -> TransactionReceipt {
+        let transaction = self.sign(manifest, signers);
+        let receipt = self.execute(&transaction);
+        receipt.expect_commit_success();
+        receipt
+    }
+
+    fn new_account(&mut self, withdraw_auth: AccessRule) -> ComponentAddress {
+        let manifest = self
+            .manifest_builder()
+            .lock_fee(FAUCET_COMPONENT, 10.into())
+            .call_method(FAUCET_COMPONENT, "free", args!())
+            .take_from_worktop(RADIX_TOKEN, |builder, bucket_id| {
+                builder.new_account_with_resource(&withdraw_auth, bucket_id)
+            })
+            .build();
+        self.execute_setup(manifest, &[]).new_component_addresses()[0]
+    }
+
+    /// Signs and executes a transaction, rendering it as a vector.
+    fn vector(
+        &mut self,
+        manifest: TransactionManifest,
+        signers: &[&EcdsaSecp256k1PrivateKey],
+    ) -> (BTreeMap<&'static str, String>, TransactionReceipt) {
+        let transaction = self.sign(manifest, signers);
+        let receipt = self.execute(&transaction);
+        let intent = &transaction.signed_intent.intent;
+
+        let mut files = BTreeMap::new();
+        files.insert(
+            "manifest.rtm",
+            decompile(&intent.manifest.instructions, &self.network).unwrap(),
+        );
+        files.insert(
+            "instructions.hex",
+            format!(
+                "{}\n",
+                hex::encode(scrypto_encode(&intent.manifest.instructions).unwrap())
+            ),
+        );
+        files.insert(
+            "blobs.txt",
+            intent
+                .manifest
+                .blobs
+                .iter()
+                .map(|blob| format!("{}\n", hash(blob)))
+                .collect(),
+        );
+        files.insert(
+            "intent_hash.txt",
+            format!("{}\n", transaction.intent_hash().unwrap()),
+        );
+        files.insert("receipt.txt", self.receipt_summary(&receipt));
+
+        (files, receipt)
+    }
+
+    fn receipt_summary(&self, receipt: &TransactionReceipt) -> String {
+        let encoder = Bech32Encoder::new(&self.network);
+        let mut summary = String::new();
+        match &receipt.result {
+            TransactionResult::Commit(commit) => {
+                let outcome = match &commit.outcome {
+                    TransactionOutcome::Success(..) => "success",
+                    TransactionOutcome::Failure(..) => "failure",
+                };
+                summary.push_str(&format!("outcome: {}\n", outcome));
+                summary.push_str(&format!(
+                    "cost_units_consumed: {}\n",
+                    receipt.execution.fee_summary.cost_unit_consumed
+                ));
+                for package_address in &commit.entity_changes.new_package_addresses {
+                    summary.push_str(&format!(
+                        "new_package: {}\n",
+                        encoder.encode_package_address_to_string(package_address)
+                    ));
+                }
+                for component_address in &commit.entity_changes.new_component_addresses {
+                    summary.push_str(&format!(
+                        "new_component: {}\n",
+                        encoder.encode_component_address_to_string(component_address)
+                    ));
+                }
+                for resource_address in &commit.entity_changes.new_resource_addresses {
+                    summary.push_str(&format!(
+                        "new_resource: {}\n",
+                        encoder.encode_resource_address_to_string(resource_address)
+                    ));
+                }
+            }
+            TransactionResult::Reject(..) => summary.push_str("outcome: rejected\n"),
+        }
+        summary
+    }
+}
+
+/// Generates every vector, keyed by path relative to the vectors directory.
+fn generate() -> BTreeMap<PathBuf, String> {
+    let mut runner = VectorRunner::new();
+    let key1 = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap();
+    let key2 = EcdsaSecp256k1PrivateKey::from_u64(2).unwrap();
+    let badge1 = NonFungibleAddress::from_public_key(&key1.public_key());
+    let badge2 = NonFungibleAddress::from_public_key(&key2.public_key());
+
+    let account1 = runner.new_account(rule!(require(badge1.clone())));
+    let account2 = runner.new_account(rule!(require(badge2.clone())));
+    let multi_sig_account = runner.new_account(rule!(require(badge1.clone()) && require(badge2)));
+
+    let mut access_rules = HashMap::new();
+    access_rules.insert(
+        ResourceMethodAuthKey::Mint,
+        (rule!(require(badge1)), rule!(deny_all)),
+    );
+    access_rules.insert(
+        ResourceMethodAuthKey::Withdraw,
+        (rule!(allow_all), rule!(deny_all)),
+    );
+    let manifest = runner
+        .manifest_builder()
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .create_resource(
+            ResourceType::NonFungible {
+                id_type: NonFungibleIdType::U32,
+            },
+            BTreeMap::new(),
+            access_rules,
+            None,
+        )
+        .build();
+    let non_fungible_resource = runner
+        .execute_setup(manifest, &[])
+        .expect_commit()
+        .entity_changes
+        .new_resource_addresses[0];
+
+    let mut vectors = Vec::new();
+
+    let manifest = runner
+        .manifest_builder()
+        .lock_fee(account1, 10.into())
+        .withdraw_from_account_by_amount(account1, 100.into(), RADIX_TOKEN)
+        .call_method(
+            account2,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    vectors.push(("simple_transfer", runner.vector(manifest, &[&key1])));
+
+    let mut metadata = BTreeMap::new();
+    metadata.insert("name".to_owned(), "Test Token".to_owned());
+    metadata.insert("symbol".to_owned(), "TT".to_owned());
+    let manifest = runner
+        .manifest_builder()
+        .lock_fee(account1, 10.into())
+        .new_token_fixed(metadata, 1000.into())
+        .call_method(
+            account1,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    vectors.push(("resource_creation", runner.vector(manifest, &[&key1])));
+
+    let mut entries = HashMap::new();
+    entries.insert(
+        NonFungibleId::U32(1),
+        (scrypto_encode(&()).unwrap(), scrypto_encode(&()).unwrap()),
+    );
+    let manifest = runner
+        .manifest_builder()
+        .lock_fee(account1, 10.into())
+        .mint_non_fungible(non_fungible_resource, entries)
+        .call_method(
+            account1,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    vectors.push(("nft_mint", runner.vector(manifest, &[&key1])));
+
+    let manifest = runner
+        .manifest_builder()
+        .lock_fee(multi_sig_account, 10.into())
+        .withdraw_from_account_by_amount(multi_sig_account, 100.into(), RADIX_TOKEN)
+        .call_method(
+            account1,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    vectors.push((
+        "multi_proof_auth_call",
+        runner.vector(manifest, &[&key1, &key2]),
+    ));
+
+    let code = include_bytes!("../../../assets/faucet.wasm").to_vec();
+    let abi: HashMap<String, BlueprintAbi> =
+        scrypto_decode(include_bytes!("../../../assets/faucet.abi")).unwrap();
+    let manifest = runner
+        .manifest_builder()
+        .lock_fee(account1, 100.into())
+        .publish_package(
+            code,
+            abi,
+            HashMap::new(),
+            BTreeMap::new(),
+            AccessRules::new().default(AccessRule::DenyAll, AccessRule::DenyAll),
+        )
+        .build();
+    vectors.push(("package_publish", runner.vector(manifest, &[&key1])));
+
+    // Many account calls in one transaction, covering the Scrypto invocation paths end to end
+    let mut builder = runner.manifest_builder();
+    builder.lock_fee(account1, 10.into());
+    builder.create_proof_from_account_by_amount(account1, 1.into(), RADIX_TOKEN);
+    for _ in 0..5 {
+        builder.withdraw_from_account_by_amount(account1, 1.into(), RADIX_TOKEN);
+        builder.take_from_worktop(RADIX_TOKEN, |builder, bucket_id| {
+            builder.call_method(account2, "deposit", args!(Bucket(bucket_id)))
+        });
+    }
+    builder.withdraw_from_account_by_amount(account1, 1.into(), RADIX_TOKEN);
+    builder.call_method(
+        account2,
+        "deposit_batch",
+        args!(Expression::entire_worktop()),
+    );
+    builder.drop_all_proofs();
+    let manifest = builder.build();
+    vectors.push(("account_call_sequence", runner.vector(manifest, &[&key1])));
+
+    let mut files = BTreeMap::new();
+    for (name, (vector_files, receipt)) in vectors {
+        receipt.expect_commit_success();
+        for (file_name, contents) in vector_files {
+            files.insert(Path::new(name).join(file_name), contents);
+        }
+    }
+    files
+}
+
+fn main() {
+    let mut check = false;
+    let mut directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("test-vectors");
+    for arg in std::env::args().skip(1) {
+        if arg == "--check" {
+            check = true;
+        } else {
+            directory = PathBuf::from(arg);
+        }
+    }
+
+    let files = generate();
+    if check {
+        let mut mismatches = Vec::new();
+        for (path, contents) in &files {
+            if fs::read_to_string(directory.join(path)).ok().as_ref() != Some(contents) {
+                mismatches.push(path);
+            }
+        }
+        if !mismatches.is_empty() {
+            for path in mismatches {
+                eprintln!("Test vector differs: {}", directory.join(path).display());
+            }
+            eprintln!("Regenerate with `cargo run -p radix-engine --features test-vectors --bin generate_test_vectors`");
+            process::exit(1);
+        }
+    } else {
+        for (path, contents) in &files {
+            let path = directory.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        }
+    }
+}
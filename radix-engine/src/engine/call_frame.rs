@@ -8,9 +8,20 @@ use radix_engine_interface::api::types::{
     SubstateOffset, TransactionProcessorFunction,
 };
 
+/// Key value store and non-fungible store entries are not locked in track
+fn is_locked_in_track(offset: &SubstateOffset) -> bool {
+    !(matches!(offset, SubstateOffset::KeyValueStore(..))
+        || matches!(
+            offset,
+            SubstateOffset::NonFungibleStore(NonFungibleStoreOffset::Entry(..))
+        ))
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CallFrameUpdate {
     pub nodes_to_move: Vec<RENodeId>,
+    /// Nodes lent to the callee, which may only read them and which remain owned by the caller.
+    pub nodes_to_borrow: Vec<RENodeId>,
     pub node_refs_to_copy: HashSet<RENodeId>,
 }
 
@@ -18,6 +29,7 @@ impl CallFrameUpdate {
     pub fn empty() -> Self {
         CallFrameUpdate {
             nodes_to_move: Vec::new(),
+            nodes_to_borrow: Vec::new(),
             node_refs_to_copy: HashSet::new(),
         }
     }
@@ -25,6 +37,7 @@ impl CallFrameUpdate {
     pub fn move_node(node_id: RENodeId) -> Self {
         CallFrameUpdate {
             nodes_to_move: vec![node_id],
+            nodes_to_borrow: vec![],
             node_refs_to_copy: HashSet::new(),
         }
     }
@@ -34,6 +47,7 @@ impl CallFrameUpdate {
         node_refs_to_copy.insert(node_id);
         CallFrameUpdate {
             nodes_to_move: vec![],
+            nodes_to_borrow: vec![],
             node_refs_to_copy,
         }
     }
@@ -51,6 +65,15 @@ pub enum RENodeVisibilityOrigin {
     DirectAccess,
 }
 
+/// What a call frame may do with a node it can see.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RENodeVisibilityLevel {
+    /// Found through a read-only read of a node the frame has no write rights over, so the node
+    /// may be read but not locked mutably.
+    ReadOnly,
+    Full,
+}
+
 /// A lock on a substate controlled by a call frame
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SubstateLock {
@@ -64,6 +87,10 @@ pub struct SubstateLock {
 struct RENodeRefData {
     location: RENodeLocation,
     visibility: RENodeVisibilityOrigin,
+    level: RENodeVisibilityLevel,
+    /// Whether the node was found as a child of a node the frame has write rights over, which
+    /// extends those rights to the node itself.
+    writable_parent: bool,
 }
 
 impl RENodeRefData {
@@ -71,6 +98,21 @@ impl RENodeRefData {
         RENodeRefData {
             location,
             visibility,
+            level: RENodeVisibilityLevel::Full,
+            writable_parent: false,
+        }
+    }
+
+    fn child(location: RENodeLocation, writable_parent: bool) -> Self {
+        RENodeRefData {
+            location,
+            visibility: RENodeVisibilityOrigin::Normal,
+            level: if writable_parent {
+                RENodeVisibilityLevel::Full
+            } else {
+                RENodeVisibilityLevel::ReadOnly
+            },
+            writable_parent,
         }
     }
 }
@@ -93,6 +135,9 @@ pub struct CallFrame {
     /// Also keeps track of number of locks on this node
     owned_root_nodes: HashMap<RENodeId, u32>,
 
+    /// Heap nodes lent to this call frame by an upstream frame, which can't be moved or mutated.
+    borrowed_nodes: HashSet<RENodeId>,
+
     next_lock_handle: LockHandle,
     locks: HashMap<LockHandle, SubstateLock>,
 
@@ -109,12 +154,19 @@ impl CallFrame {
         flags: LockFlags,
     ) -> Result<LockHandle, RuntimeError> {
         let location = self.get_node_location(node_id)?;
-        if !(matches!(offset, SubstateOffset::KeyValueStore(..))
-            || matches!(
-                offset,
-                SubstateOffset::NonFungibleStore(NonFungibleStoreOffset::Entry(..))
-            ))
+        if flags.contains(LockFlags::MUTABLE) && self.borrowed_nodes.contains(&node_id) {
+            return Err(RuntimeError::CallFrameError(
+                CallFrameError::MutatingBorrowedRENode(node_id),
+            ));
+        }
+        if flags.contains(LockFlags::MUTABLE)
+            && self.get_node_visibility_level(node_id)? == RENodeVisibilityLevel::ReadOnly
         {
+            return Err(RuntimeError::KernelError(
+                KernelError::WriteAccessNotAllowed(node_id),
+            ));
+        }
+        if is_locked_in_track(&offset) {
             let substate_id = SubstateId(node_id, offset.clone());
             match location {
                 RENodeLocation::Store => track
@@ -144,11 +196,18 @@ impl CallFrame {
                     RENodeRefData::new(RENodeLocation::Store, RENodeVisibilityOrigin::Normal),
                 );
             }
+            // Children read about through a node the frame can't write may only be read
+            let writable_parent = self.has_write_rights(node_id, flags);
             for child_id in &substate_owned_nodes {
-                self.node_refs.insert(
-                    *child_id,
-                    RENodeRefData::new(location, RENodeVisibilityOrigin::Normal),
-                );
+                let child_ref = RENodeRefData::child(location, writable_parent);
+                self.node_refs
+                    .entry(*child_id)
+                    .and_modify(|e| {
+                        if writable_parent {
+                            *e = child_ref
+                        }
+                    })
+                    .or_insert(child_ref);
             }
         }
 
@@ -237,12 +296,7 @@ impl CallFrame {
 
         let flags = substate_lock.flags;
 
-        if !(matches!(offset, SubstateOffset::KeyValueStore(..))
-            || matches!(
-                offset,
-                SubstateOffset::NonFungibleStore(NonFungibleStoreOffset::Entry(..))
-            ))
-        {
+        if is_locked_in_track(&offset) {
             match location {
                 RENodeLocation::Store => track
                     .release_lock(
@@ -282,6 +336,7 @@ impl CallFrame {
             )),
             node_refs: HashMap::new(),
             owned_root_nodes: HashMap::new(),
+            borrowed_nodes: HashSet::new(),
             next_lock_handle: 0u32,
             locks: HashMap::new(),
             allocated_ids: HashSet::new(),
@@ -294,6 +349,7 @@ impl CallFrame {
         call_frame_update: CallFrameUpdate,
     ) -> Result<Self, RuntimeError> {
         let mut owned_heap_nodes = HashMap::new();
+        let mut borrowed_nodes = HashSet::new();
         let mut next_node_refs = HashMap::new();
 
         for node_id in call_frame_update.nodes_to_move {
@@ -301,10 +357,36 @@ impl CallFrame {
             owned_heap_nodes.insert(node_id, 0u32);
         }
 
+        for node_id in call_frame_update.nodes_to_borrow {
+            if !parent.owned_root_nodes.contains_key(&node_id)
+                && !parent.borrowed_nodes.contains(&node_id)
+            {
+                return Err(RuntimeError::CallFrameError(
+                    CallFrameError::RENodeNotOwned(node_id),
+                ));
+            }
+            next_node_refs.insert(
+                node_id,
+                RENodeRefData::new(RENodeLocation::Heap, RENodeVisibilityOrigin::Normal),
+            );
+            borrowed_nodes.insert(node_id);
+        }
+
         for node_id in call_frame_update.node_refs_to_copy {
             let location = parent.get_node_location(node_id)?;
             let visibility = parent.get_node_visibility(node_id)?;
-            next_node_refs.insert(node_id, RENodeRefData::new(location, visibility));
+            let ref_data = match parent.node_refs.get(&node_id) {
+                Some(ref_data) => RENodeRefData {
+                    location,
+                    visibility,
+                    ..*ref_data
+                },
+                None => RENodeRefData::new(location, visibility),
+            };
+            next_node_refs.insert(node_id, ref_data);
+            if parent.borrowed_nodes.contains(&node_id) {
+                borrowed_nodes.insert(node_id);
+            }
         }
 
         let frame = Self {
@@ -312,6 +394,7 @@ impl CallFrame {
             actor,
             node_refs: next_node_refs,
             owned_root_nodes: owned_heap_nodes,
+            borrowed_nodes,
             next_lock_handle: 0u32,
             locks: HashMap::new(),
             allocated_ids: HashSet::new(),
@@ -333,10 +416,24 @@ impl CallFrame {
 
         for node_id in update.node_refs_to_copy {
             // Make sure not to allow owned nodes to be passed as references upstream
-            let ref_data = from
-                .node_refs
-                .get(&node_id)
-                .ok_or(CallFrameError::RENodeNotVisible(node_id))?;
+            let ref_data = match from.node_refs.get(&node_id) {
+                Some(ref_data) => ref_data.clone(),
+                // The global address the receiver was derefed from is visible to the callee,
+                // even though only the derefed node is in its refs
+                None if from.derefed_from() == Some(node_id) => {
+                    RENodeRefData::new(RENodeLocation::Store, RENodeVisibilityOrigin::Normal)
+                }
+                None => {
+                    return Err(match node_id {
+                        RENodeId::Global(global_address) => RuntimeError::KernelError(
+                            KernelError::InvalidReferenceReturn(global_address),
+                        ),
+                        _ => {
+                            RuntimeError::CallFrameError(CallFrameError::RENodeNotVisible(node_id))
+                        }
+                    });
+                }
+            };
 
             to.node_refs
                 .entry(node_id)
@@ -345,12 +442,25 @@ impl CallFrame {
                         e.visibility = ref_data.visibility
                     }
                 })
-                .or_insert(ref_data.clone());
+                .or_insert(ref_data);
         }
 
         Ok(())
     }
 
+    fn derefed_from(&self) -> Option<RENodeId> {
+        match &self.actor {
+            REActor::Method(
+                _,
+                ResolvedReceiver {
+                    derefed_from: Some((node_id, _)),
+                    ..
+                },
+            ) => Some(*node_id),
+            _ => None,
+        }
+    }
+
     pub fn drop_all_locks<'s, R: FeeReserve>(
         &mut self,
         heap: &mut Heap,
@@ -367,6 +477,9 @@ impl CallFrame {
 
     fn take_node_internal(&mut self, node_id: RENodeId) -> Result<(), CallFrameError> {
         match self.owned_root_nodes.remove(&node_id) {
+            None if self.borrowed_nodes.contains(&node_id) => {
+                Err(CallFrameError::MovingBorrowedRENode(node_id))
+            }
             None => Err(CallFrameError::RENodeNotOwned(node_id)),
             Some(lock_count) => {
                 if lock_count == 0 {
@@ -441,6 +554,10 @@ impl CallFrame {
         self.owned_root_nodes.keys().cloned().collect()
     }
 
+    pub fn owned_node_count(&self) -> usize {
+        self.owned_root_nodes.len()
+    }
+
     pub fn verify_allocated_ids_empty(&self) -> Result<(), RuntimeError> {
         if !self.allocated_ids.is_empty() {
             return Err(RuntimeError::CallFrameError(
@@ -546,6 +663,41 @@ impl CallFrame {
         Ok(visibility)
     }
 
+    pub fn get_node_visibility_level(
+        &self,
+        node_id: RENodeId,
+    ) -> Result<RENodeVisibilityLevel, CallFrameError> {
+        let level = if self.owned_root_nodes.contains_key(&node_id) {
+            RENodeVisibilityLevel::Full
+        } else if let Some(ref_data) = self.node_refs.get(&node_id) {
+            ref_data.level
+        } else {
+            return Err(CallFrameError::RENodeNotVisible(node_id));
+        };
+
+        Ok(level)
+    }
+
+    /// Whether children found through a lock on the node may be written by this frame: the frame
+    /// must own the node, be acting on it, lock it mutably or have found it through a node it
+    /// has write rights over. Globals are public, so what they point to is always writable.
+    fn has_write_rights(&self, node_id: RENodeId, flags: LockFlags) -> bool {
+        if self.owned_root_nodes.contains_key(&node_id) || matches!(node_id, RENodeId::Global(..)) {
+            return true;
+        }
+        match self.node_refs.get(&node_id) {
+            Some(ref_data) if ref_data.level == RENodeVisibilityLevel::Full => {
+                flags.contains(LockFlags::MUTABLE)
+                    || ref_data.writable_parent
+                    || matches!(
+                        &self.actor,
+                        REActor::Method(_, ResolvedReceiver { receiver, .. }) if *receiver == node_id
+                    )
+            }
+            _ => false,
+        }
+    }
+
     pub fn get_node_location(&self, node_id: RENodeId) -> Result<RENodeLocation, CallFrameError> {
         // Find node
         let node_pointer = {
@@ -568,4 +720,193 @@ impl CallFrame {
         node_ids.sort(); // Required to make sure returned vector is deterministic
         node_ids
     }
+
+    /// Checks that every owned node and every lock of this frame is backed by the heap or track.
+    ///
+    /// Panics with a description of the first violation found.
+    pub fn assert_invariants<'s, R: FeeReserve>(&self, heap: &Heap, track: &Track<'s, R>) {
+        for node_id in self.owned_root_nodes.keys() {
+            assert!(
+                heap.contains_node(node_id),
+                "Frame {} owns node {:?} which is not on the heap",
+                self.depth,
+                node_id
+            );
+        }
+
+        for (lock_handle, lock) in &self.locks {
+            assert!(
+                *lock_handle < self.next_lock_handle,
+                "Frame {} has lock {} which was never handed out",
+                self.depth,
+                lock_handle
+            );
+
+            let (location, node_id, offset) = &lock.substate_pointer;
+            match location {
+                RENodeLocation::Heap => {
+                    assert!(
+                        heap.contains_node(node_id),
+                        "Frame {} has lock {} on {:?} which is not on the heap",
+                        self.depth,
+                        lock_handle,
+                        node_id
+                    );
+                }
+                RENodeLocation::Store => {
+                    if !is_locked_in_track(offset) {
+                        continue;
+                    }
+                    let substate_id = SubstateId(*node_id, offset.clone());
+                    let lock_state = track.get_lock_state(&substate_id);
+                    let is_open = if lock.flags.contains(LockFlags::MUTABLE) {
+                        matches!(lock_state, Some(LockState::Write))
+                    } else {
+                        matches!(lock_state, Some(LockState::Read(n)) if n > 0)
+                    };
+                    assert!(
+                        is_open,
+                        "Frame {} has lock {} on {:?} but track lock state is {:?}",
+                        self.depth, lock_handle, substate_id, lock_state
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fee::{FeeTable, SystemLoanFeeReserve};
+    use crate::ledger::{OutputValue, TypedInMemorySubstateStore, WriteableSubstateStore};
+    use crate::model::{ComponentStateSubstate, PersistedSubstate, Resource, VaultSubstate};
+    use radix_engine_interface::api::types::{ComponentMethod, NativeMethod, VaultOffset};
+    use radix_engine_interface::model::Vault;
+
+    const COMPONENT_ID: ComponentId = [1u8; 36];
+    const OTHER_COMPONENT_ID: ComponentId = [2u8; 36];
+    const VAULT_ID: VaultId = [3u8; 36];
+
+    fn new_store_with_component_owning_vault() -> TypedInMemorySubstateStore {
+        let mut store = TypedInMemorySubstateStore::new();
+        store.put_substate(
+            SubstateId(
+                RENodeId::Component(COMPONENT_ID),
+                SubstateOffset::Component(ComponentOffset::State),
+            ),
+            OutputValue {
+                substate: PersistedSubstate::ComponentState(ComponentStateSubstate::new(
+                    scrypto_encode(&Vault(VAULT_ID)).unwrap(),
+                )),
+                version: 0,
+            },
+        );
+        store.put_substate(
+            SubstateId(
+                RENodeId::Vault(VAULT_ID),
+                SubstateOffset::Vault(VaultOffset::Vault),
+            ),
+            OutputValue {
+                substate: PersistedSubstate::Vault(VaultSubstate(Resource::new_empty(
+                    RADIX_TOKEN,
+                    ResourceType::Fungible { divisibility: 18 },
+                ))),
+                version: 0,
+            },
+        );
+        store
+    }
+
+    fn new_frame_acting_on(receiver: ComponentId) -> CallFrame {
+        let mut frame = CallFrame::new_root();
+        frame.actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::Component(ComponentMethod::SetRoyaltyConfig)),
+            ResolvedReceiver::new(RENodeId::Component(receiver)),
+        );
+        frame.add_stored_ref(
+            RENodeId::Component(COMPONENT_ID),
+            RENodeVisibilityOrigin::Normal,
+        );
+        frame
+    }
+
+    fn read_state_then_lock_vault_mutably(
+        frame: &mut CallFrame,
+        store: &TypedInMemorySubstateStore,
+    ) -> Result<LockHandle, RuntimeError> {
+        let mut heap = Heap::new();
+        let mut track = Track::new(store, SystemLoanFeeReserve::no_fee(), FeeTable::new());
+        frame
+            .acquire_lock(
+                &mut heap,
+                &mut track,
+                RENodeId::Component(COMPONENT_ID),
+                SubstateOffset::Component(ComponentOffset::State),
+                LockFlags::read_only(),
+            )
+            .unwrap();
+        frame.acquire_lock(
+            &mut heap,
+            &mut track,
+            RENodeId::Vault(VAULT_ID),
+            SubstateOffset::Vault(VaultOffset::Vault),
+            LockFlags::MUTABLE,
+        )
+    }
+
+    #[test]
+    fn test_vault_read_through_other_component_is_read_only() {
+        let store = new_store_with_component_owning_vault();
+        let mut frame = new_frame_acting_on(OTHER_COMPONENT_ID);
+
+        let result = read_state_then_lock_vault_mutably(&mut frame, &store);
+
+        assert_eq!(
+            frame.get_node_visibility_level(RENodeId::Vault(VAULT_ID)),
+            Ok(RENodeVisibilityLevel::ReadOnly)
+        );
+        assert!(matches!(
+            result,
+            Err(RuntimeError::KernelError(
+                KernelError::WriteAccessNotAllowed(RENodeId::Vault(VAULT_ID))
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_vault_read_through_own_component_is_writable() {
+        let store = new_store_with_component_owning_vault();
+        let mut frame = new_frame_acting_on(COMPONENT_ID);
+
+        let result = read_state_then_lock_vault_mutably(&mut frame, &store);
+
+        assert_eq!(
+            frame.get_node_visibility_level(RENodeId::Vault(VAULT_ID)),
+            Ok(RENodeVisibilityLevel::Full)
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_read_only_child_stays_read_only_in_callee() {
+        let store = new_store_with_component_owning_vault();
+        let mut frame = new_frame_acting_on(OTHER_COMPONENT_ID);
+        let _ = read_state_then_lock_vault_mutably(&mut frame, &store);
+
+        let callee = CallFrame::new_child_from_parent(
+            &mut frame,
+            REActor::Method(
+                ResolvedMethod::Native(NativeMethod::Vault(VaultMethod::Take)),
+                ResolvedReceiver::new(RENodeId::Vault(VAULT_ID)),
+            ),
+            CallFrameUpdate::copy_ref(RENodeId::Vault(VAULT_ID)),
+        )
+        .unwrap();
+
+        assert_eq!(
+            callee.get_node_visibility_level(RENodeId::Vault(VAULT_ID)),
+            Ok(RENodeVisibilityLevel::ReadOnly)
+        );
+    }
 }
@@ -77,12 +77,20 @@ pub enum KernelError {
     WasmError(WasmError),
 
     InvalidReferenceWrite(GlobalAddress),
+    InvalidReferenceReturn(GlobalAddress),
 
     RENodeNotFound(RENodeId),
 
     MaxCallDepthLimitReached,
+    SyscallLimitExceeded {
+        count: u32,
+    },
+    OwnedNodeLimitExceeded {
+        limit: usize,
+    },
     InvalidScryptoFnOutput,
     MethodReceiverNotMatch(NativeMethod, RENodeId),
+    PublishNotAuthorized(ResourceAddress),
 
     // ID allocation
     IdAllocationError(IdAllocationError),
@@ -98,8 +106,13 @@ pub enum KernelError {
     TrackError(TrackError),
     LockDoesNotExist(LockHandle),
     LockNotMutable(LockHandle),
+    WriteAccessNotAllowed(RENodeId),
     BlobNotFound(Hash),
     DropNodeFailure(RENodeId),
+    HashInputTooLarge {
+        size: usize,
+        limit: usize,
+    },
 
     // Substate Constraints
     InvalidOffset(SubstateOffset),
@@ -133,6 +146,8 @@ pub enum CallFrameError {
     RENodeNotVisible(RENodeId),
     RENodeNotOwned(RENodeId),
     MovingLockedRENode(RENodeId),
+    MovingBorrowedRENode(RENodeId),
+    MutatingBorrowedRENode(RENodeId),
     RENodeIdWasNotAllocated(RENodeId),
     CallFrameCleanupAllocatedIdsNotEmpty,
 }
@@ -197,6 +212,18 @@ impl<E> InvokeError<E> {
     }
 }
 
+/// Converts the error of a model-level result into an [`InvokeError`], so that it can be
+/// propagated with `?`, e.g. `vault.put(bucket).into_invoke::<VaultError>()?`.
+pub trait IntoInvokeError<T, S> {
+    fn into_invoke<E: From<S>>(self) -> Result<T, InvokeError<E>>;
+}
+
+impl<T, S> IntoInvokeError<T, S> for Result<T, S> {
+    fn into_invoke<E: From<S>>(self) -> Result<T, InvokeError<E>> {
+        self.map_err(|e| InvokeError::Error(e.into()))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[scrypto(TypeId, Encode, Decode)]
 pub enum ApplicationError {
@@ -206,6 +233,10 @@ pub enum ApplicationError {
 
     EpochManagerError(EpochManagerError),
 
+    BurnerError(BurnerError),
+
+    TimeLockedVaultError(TimeLockedVaultError),
+
     ResourceManagerError(ResourceManagerError),
 
     AccessRulesChainError(AccessRulesChainError),
@@ -219,6 +250,45 @@ pub enum ApplicationError {
     WorktopError(WorktopError),
 
     AuthZoneError(AuthZoneError),
+
+    /// A blueprint function or method declared to return `Result<T, E>` returned `Err`.
+    /// The value is the SBOR-encoded `E`.
+    BlueprintError {
+        actor: REActor,
+        value: Vec<u8>,
+    },
+}
+
+impl RuntimeError {
+    /// Returns the index of the manifest instruction which failed with this error, if known.
+    pub fn failed_instruction_index(&self) -> Option<usize> {
+        match self {
+            RuntimeError::ApplicationError(ApplicationError::TransactionProcessorError(
+                TransactionProcessorError::InstructionFailed { index, .. },
+            )) => Some(*index),
+            _ => None,
+        }
+    }
+
+    /// Returns the encoded error value returned by a blueprint, if this is a blueprint error.
+    pub fn blueprint_error_value(&self) -> Option<&[u8]> {
+        match self.cause() {
+            RuntimeError::ApplicationError(ApplicationError::BlueprintError { value, .. }) => {
+                Some(value)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the underlying error, stripped of any failed instruction context.
+    pub fn cause(&self) -> &RuntimeError {
+        match self {
+            RuntimeError::ApplicationError(ApplicationError::TransactionProcessorError(
+                TransactionProcessorError::InstructionFailed { error, .. },
+            )) => error.cause(),
+            _ => self,
+        }
+    }
 }
 
 impl fmt::Display for RuntimeError {
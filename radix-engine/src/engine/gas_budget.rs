@@ -0,0 +1,95 @@
+use crate::types::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct BudgetExhausted {
+    pub limit: u32,
+    pub consumed: u32,
+    pub requested: u32,
+}
+
+/// Tracks the cost units consumed against a limit, independently of how they are paid for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasBudget {
+    /// The max number of cost units that can be consumed
+    limit: u32,
+    /// The number of cost units consumed
+    consumed: u32,
+}
+
+impl GasBudget {
+    pub fn new(limit: u32) -> Self {
+        Self { limit, consumed: 0 }
+    }
+
+    /// Consumes the given number of cost units, failing without any change if that would
+    /// exceed the limit.
+    pub fn consume(&mut self, units: u32) -> Result<(), BudgetExhausted> {
+        if units > self.remaining() {
+            return Err(BudgetExhausted {
+                limit: self.limit,
+                consumed: self.consumed,
+                requested: units,
+            });
+        }
+
+        self.consumed += units;
+        Ok(())
+    }
+
+    pub fn remaining(&self) -> u32 {
+        self.limit - self.consumed
+    }
+
+    /// Restores the full limit, by forgetting all cost units consumed.
+    pub fn reset_to_limit(&mut self) {
+        self.consumed = 0;
+    }
+
+    pub fn limit(&self) -> u32 {
+        self.limit
+    }
+
+    pub fn consumed(&self) -> u32 {
+        self.consumed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consume_within_limit() {
+        let mut budget = GasBudget::new(100);
+        budget.consume(30).unwrap();
+        budget.consume(70).unwrap();
+        assert_eq!(budget.consumed(), 100);
+        assert_eq!(budget.remaining(), 0);
+    }
+
+    #[test]
+    fn test_consume_beyond_limit() {
+        let mut budget = GasBudget::new(100);
+        budget.consume(60).unwrap();
+        assert_eq!(
+            budget.consume(41),
+            Err(BudgetExhausted {
+                limit: 100,
+                consumed: 60,
+                requested: 41,
+            })
+        );
+        assert_eq!(budget.consumed(), 60);
+        assert_eq!(budget.consume(u32::MAX).is_err(), true);
+    }
+
+    #[test]
+    fn test_reset_to_limit() {
+        let mut budget = GasBudget::new(100);
+        budget.consume(100).unwrap();
+        budget.reset_to_limit();
+        assert_eq!(budget.remaining(), 100);
+        assert_eq!(budget.limit(), 100);
+    }
+}
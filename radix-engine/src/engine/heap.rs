@@ -42,7 +42,7 @@ impl Heap {
                 let entry = node
                     .substates
                     .entry(offset.clone())
-                    .or_insert(RuntimeSubstate::NonFungible(NonFungibleSubstate(None)));
+                    .or_insert(RuntimeSubstate::NonFungible(NonFungibleSubstate::empty()));
                 Ok(entry.to_ref())
             }
             _ => node
@@ -75,7 +75,7 @@ impl Heap {
                 let entry = node
                     .substates
                     .entry(offset.clone())
-                    .or_insert(RuntimeSubstate::NonFungible(NonFungibleSubstate(None)));
+                    .or_insert(RuntimeSubstate::NonFungible(NonFungibleSubstate::empty()));
                 Ok(entry.to_ref_mut())
             }
             _ => node
@@ -86,6 +86,14 @@ impl Heap {
         }
     }
 
+    pub fn get_node(&self, node_id: &RENodeId) -> Option<&HeapRENode> {
+        self.nodes.get(node_id)
+    }
+
+    pub fn contains_node(&self, node_id: &RENodeId) -> bool {
+        self.nodes.contains_key(node_id)
+    }
+
     pub fn create_node(&mut self, node_id: RENodeId, node: HeapRENode) {
         self.nodes.insert(node_id, node);
     }
@@ -2,13 +2,13 @@ use crate::engine::*;
 use crate::model::*;
 use crate::types::*;
 use crate::wasm::WasmEngine;
-use radix_engine_interface::api::api::{EngineApi, Invokable, InvokableModel};
+use radix_engine_interface::api::api::{EngineApi, Invokable, InvokableModel, LoggerApi};
 use radix_engine_interface::api::types::RENodeId;
 use sbor::rust::fmt::Debug;
 
-impl<E: Into<ApplicationError>> Into<RuntimeError> for InvokeError<E> {
-    fn into(self) -> RuntimeError {
-        match self {
+impl<E: Into<ApplicationError>> From<InvokeError<E>> for RuntimeError {
+    fn from(error: InvokeError<E>) -> Self {
+        match error {
             InvokeError::Downstream(runtime_error) => runtime_error,
             InvokeError::Error(e) => RuntimeError::ApplicationError(e.into()),
         }
@@ -75,6 +75,18 @@ impl Into<ApplicationError> for EpochManagerError {
     }
 }
 
+impl Into<ApplicationError> for BurnerError {
+    fn into(self) -> ApplicationError {
+        ApplicationError::BurnerError(self)
+    }
+}
+
+impl Into<ApplicationError> for TimeLockedVaultError {
+    fn into(self) -> ApplicationError {
+        ApplicationError::TimeLockedVaultError(self)
+    }
+}
+
 pub trait NativeProcedure {
     type Output: Debug;
     fn main<Y>(self, system_api: &mut Y) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
@@ -82,7 +94,8 @@ pub trait NativeProcedure {
         Y: SystemApi
             + Invokable<ScryptoInvocation, RuntimeError>
             + EngineApi<RuntimeError>
-            + InvokableModel<RuntimeError>;
+            + InvokableModel<RuntimeError>
+            + LoggerApi<RuntimeError>;
 }
 
 pub struct NativeExecutor<N: NativeProcedure>(pub N);
@@ -95,7 +108,8 @@ impl<N: NativeProcedure> Executor for NativeExecutor<N> {
         Y: SystemApi
             + Invokable<ScryptoInvocation, RuntimeError>
             + EngineApi<RuntimeError>
-            + InvokableModel<RuntimeError>,
+            + InvokableModel<RuntimeError>
+            + LoggerApi<RuntimeError>,
     {
         self.0.main(system_api)
     }
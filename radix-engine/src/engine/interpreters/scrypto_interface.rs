@@ -1,6 +1,6 @@
 use crate::engine::{
-    Kernel, KernelError, LockFlags, REActor, RENode, ResolvedFunction, ResolvedMethod,
-    ResolvedReceiver, RuntimeError, SystemApi,
+    AuthModule, Event, ExecutionMode, Kernel, KernelError, LockFlags, REActor, RENode,
+    ResolvedFunction, ResolvedMethod, ResolvedReceiver, RuntimeError, SystemApi, TrackedEvent,
 };
 use crate::fee::FeeReserve;
 use crate::model::{
@@ -8,17 +8,17 @@ use crate::model::{
     ComponentRoyaltyConfigSubstate, ComponentStateSubstate, KeyValueStore, RuntimeSubstate,
 };
 use crate::model::{MetadataSubstate, Resource};
-use crate::types::HashMap;
+use crate::types::BTreeMap;
 use crate::wasm::WasmEngine;
 use radix_engine_interface::api::api::EngineApi;
 use radix_engine_interface::api::types::{
-    ComponentMethod, LockHandle, NativeFn, NativeMethod, RENodeId, RENodeType, ScryptoActor,
-    ScryptoRENode, SubstateOffset,
+    CallerInfo, ComponentMethod, GlobalAddress, LockHandle, NativeFn, NativeMethod, RENodeId,
+    RENodeType, ScryptoActor, ScryptoRENode, SubstateOffset,
 };
 use radix_engine_interface::constants::RADIX_TOKEN;
-use radix_engine_interface::crypto::Hash;
+use radix_engine_interface::crypto::{Hash, HashAlgorithm};
 use radix_engine_interface::model::{
-    AccessRule, AccessRuleKey, AccessRules, ResourceType, RoyaltyConfig,
+    AccessRule, AccessRuleKey, AccessRules, ResourceAddress, ResourceType, RoyaltyConfig,
 };
 use sbor::rust::string::ToString;
 use sbor::rust::vec;
@@ -75,7 +75,7 @@ where
                     royalty_config,
                     royalty_accumulator,
                     MetadataSubstate {
-                        metadata: HashMap::new(),
+                        metadata: BTreeMap::new(),
                     },
                     AccessRulesChainSubstate {
                         access_rules_chain: vec![access_rules],
@@ -123,7 +123,7 @@ where
 
     fn sys_read(&mut self, lock_handle: LockHandle) -> Result<Vec<u8>, RuntimeError> {
         self.get_ref(lock_handle)
-            .map(|substate_ref| substate_ref.to_scrypto_value().raw)
+            .map(|substate_ref| substate_ref.to_scrypto_bytes())
     }
 
     fn sys_write(&mut self, lock_handle: LockHandle, buffer: Vec<u8>) -> Result<(), RuntimeError> {
@@ -149,6 +149,32 @@ where
         self.drop_lock(lock_handle)
     }
 
+    // TODO: Batch in the kernel rather than looping over single substate operations
+    fn sys_batch_read_substates(
+        &mut self,
+        substates: Vec<(RENodeId, SubstateOffset)>,
+    ) -> Result<Vec<Vec<u8>>, RuntimeError> {
+        let mut buffers = Vec::new();
+        for (node_id, offset) in substates {
+            let lock_handle = self.sys_lock_substate(node_id, offset, false)?;
+            buffers.push(self.sys_read(lock_handle)?);
+            self.sys_drop_lock(lock_handle)?;
+        }
+        Ok(buffers)
+    }
+
+    fn sys_batch_write_substates(
+        &mut self,
+        substates: Vec<(RENodeId, SubstateOffset, Vec<u8>)>,
+    ) -> Result<(), RuntimeError> {
+        for (node_id, offset, buffer) in substates {
+            let lock_handle = self.sys_lock_substate(node_id, offset, true)?;
+            self.sys_write(lock_handle, buffer)?;
+            self.sys_drop_lock(lock_handle)?;
+        }
+        Ok(())
+    }
+
     fn sys_get_actor(&mut self) -> Result<ScryptoActor, RuntimeError> {
         let actor = match self.get_actor() {
             REActor::Method(
@@ -178,6 +204,29 @@ where
         Ok(actor)
     }
 
+    fn sys_get_caller(&mut self) -> Result<Option<CallerInfo>, RuntimeError> {
+        let caller = match self.get_caller() {
+            Some(REActor::Method(
+                ResolvedMethod::Scrypto { .. },
+                ResolvedReceiver {
+                    derefed_from: Some((RENodeId::Global(GlobalAddress::Component(address)), ..)),
+                    ..
+                },
+            )) => Some(CallerInfo::Component(*address)),
+            Some(REActor::Function(ResolvedFunction::Scrypto {
+                package_address,
+                blueprint_name,
+                ..
+            })) => Some(CallerInfo::Blueprint(
+                *package_address,
+                blueprint_name.clone(),
+            )),
+            _ => None,
+        };
+
+        Ok(caller)
+    }
+
     fn sys_generate_uuid(&mut self) -> Result<u128, RuntimeError> {
         self.generate_uuid()
     }
@@ -185,4 +234,22 @@ where
     fn sys_get_transaction_hash(&mut self) -> Result<Hash, RuntimeError> {
         self.read_transaction_hash()
     }
+
+    fn sys_get_current_epoch(&mut self) -> Result<u64, RuntimeError> {
+        self.read_epoch()
+    }
+
+    fn sys_get_virtual_proof_resources(&mut self) -> Result<Vec<ResourceAddress>, RuntimeError> {
+        self.execute_in_mode(ExecutionMode::AuthModule, |system_api| {
+            AuthModule::virtual_proof_resources(system_api)
+        })
+    }
+
+    fn sys_emit_event(&mut self, payload: Vec<u8>) -> Result<(), RuntimeError> {
+        self.emit_event(Event::Tracked(TrackedEvent::Scrypto(payload)))
+    }
+
+    fn sys_hash(&mut self, algorithm: HashAlgorithm, data: Vec<u8>) -> Result<Hash, RuntimeError> {
+        self.hash(algorithm, &data)
+    }
 }
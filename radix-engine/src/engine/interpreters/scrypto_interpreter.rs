@@ -3,7 +3,7 @@ use crate::types::*;
 use crate::wasm::{WasmEngine, WasmInstance, WasmInstrumenter, WasmMeteringConfig, WasmRuntime};
 use radix_engine_interface::api::api::{EngineApi, InvokableModel, LoggerApi};
 use radix_engine_interface::api::types::RENodeId;
-use radix_engine_interface::data::{match_schema_with_value, IndexedScryptoValue};
+use radix_engine_interface::data::{match_schema_with_value, IndexedScryptoValue, ScryptoValue};
 
 pub struct ScryptoExecutorToParsed<I: WasmInstance> {
     instance: I,
@@ -58,6 +58,13 @@ impl<I: WasmInstance> Executor for ScryptoExecutorToParsed<I> {
             Err(RuntimeError::KernelError(
                 KernelError::InvalidScryptoFnOutput,
             ))
+        } else if let Some(value) = Self::err_value(&return_type, &output.dom) {
+            Err(RuntimeError::ApplicationError(
+                ApplicationError::BlueprintError {
+                    actor: api.get_actor().clone(),
+                    value: scrypto_encode(value).expect("Failed to encode blueprint error"),
+                },
+            ))
         } else {
             let update = CallFrameUpdate {
                 node_refs_to_copy: output
@@ -66,6 +73,7 @@ impl<I: WasmInstance> Executor for ScryptoExecutorToParsed<I> {
                     .map(|a| RENodeId::Global(a))
                     .collect(),
                 nodes_to_move: output.node_ids().into_iter().collect(),
+                nodes_to_borrow: vec![],
             };
             Ok((output, update))
         };
@@ -74,11 +82,38 @@ impl<I: WasmInstance> Executor for ScryptoExecutorToParsed<I> {
     }
 }
 
+impl<I: WasmInstance> ScryptoExecutorToParsed<I> {
+    pub fn new(instance: I, args: IndexedScryptoValue) -> Self {
+        Self { instance, args }
+    }
+
+    /// Returns the error value if the function is declared to return a `Result` and returned
+    /// `Err`; such a return fails the invocation instead of being passed back to the caller.
+    fn err_value<'a>(return_type: &Type, value: &'a ScryptoValue) -> Option<&'a ScryptoValue> {
+        match (return_type, value) {
+            (
+                Type::Result { .. },
+                SborValue::Enum {
+                    discriminator,
+                    fields,
+                },
+            ) if discriminator == RESULT_VARIANT_ERR && fields.len() == 1 => Some(&fields[0]),
+            _ => None,
+        }
+    }
+}
+
 pub struct ScryptoExecutor<I: WasmInstance> {
     instance: I,
     args: IndexedScryptoValue,
 }
 
+impl<I: WasmInstance> ScryptoExecutor<I> {
+    pub fn new(instance: I, args: IndexedScryptoValue) -> Self {
+        Self { instance, args }
+    }
+}
+
 impl<I: WasmInstance> Executor for ScryptoExecutor<I> {
     type Output = Vec<u8>;
 
@@ -107,34 +142,11 @@ pub struct ScryptoInterpreter<W: WasmEngine> {
 }
 
 impl<W: WasmEngine> ScryptoInterpreter<W> {
-    pub fn create_executor(
-        &self,
-        code: &[u8],
-        args: IndexedScryptoValue,
-    ) -> ScryptoExecutor<W::WasmInstance> {
-        let instrumented_code = self
-            .wasm_instrumenter
-            .instrument(code, &self.wasm_metering_config);
-        let instance = self.wasm_engine.instantiate(&instrumented_code);
-        ScryptoExecutor {
-            instance,
-            args: args,
-        }
-    }
-
-    pub fn create_executor_to_parsed(
-        &self,
-        code: &[u8],
-        args: IndexedScryptoValue,
-    ) -> ScryptoExecutorToParsed<W::WasmInstance> {
+    pub fn instantiate(&self, code: &[u8]) -> W::WasmInstance {
         let instrumented_code = self
             .wasm_instrumenter
             .instrument(code, &self.wasm_metering_config);
-        let instance = self.wasm_engine.instantiate(&instrumented_code);
-        ScryptoExecutorToParsed {
-            instance,
-            args: args,
-        }
+        self.wasm_engine.instantiate(&instrumented_code)
     }
 }
 
@@ -69,12 +69,25 @@ where
             RadixEngineInput::DropLock(lock_handle) => {
                 encode(self.api.sys_drop_lock(lock_handle)?)?
             }
+            RadixEngineInput::BatchReadSubstates(substates) => {
+                encode(self.api.sys_batch_read_substates(substates)?)?
+            }
+            RadixEngineInput::BatchWriteSubstates(substates) => {
+                encode(self.api.sys_batch_write_substates(substates)?)?
+            }
             RadixEngineInput::GetActor() => encode(self.api.sys_get_actor()?)?,
+            RadixEngineInput::GetCaller() => encode(self.api.sys_get_caller()?)?,
             RadixEngineInput::GetTransactionHash() => encode(self.api.sys_get_transaction_hash()?)?,
+            RadixEngineInput::GetCurrentEpoch() => encode(self.api.sys_get_current_epoch()?)?,
+            RadixEngineInput::GetVirtualProofResources() => {
+                encode(self.api.sys_get_virtual_proof_resources()?)?
+            }
             RadixEngineInput::GenerateUuid() => encode(self.api.sys_generate_uuid()?)?,
             RadixEngineInput::EmitLog(level, message) => {
                 encode(self.api.emit_log(level, message)?)?
             }
+            RadixEngineInput::EmitEvent(payload) => encode(self.api.sys_emit_event(payload)?)?,
+            RadixEngineInput::Hash(algorithm, data) => encode(self.api.sys_hash(algorithm, data)?)?,
         };
 
         Ok(rtn)
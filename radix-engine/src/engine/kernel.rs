@@ -11,6 +11,7 @@ use radix_engine_interface::data::*;
 
 use radix_engine_interface::rule;
 use sbor::rust::fmt::Debug;
+use sbor::rust::iter;
 use sbor::rust::mem;
 use transaction::errors::IdAllocationError;
 use transaction::model::AuthZoneParams;
@@ -71,6 +72,14 @@ pub struct Kernel<
     modules: Vec<Box<dyn Module<R>>>,
     /// The max call depth, TODO: Move into costing module
     max_depth: usize,
+    /// The max number of nodes a single call frame may own
+    max_owned_nodes_per_frame: usize,
+    /// The badge required to publish packages, if publishing is permissioned
+    publish_badge: Option<ResourceAddress>,
+    /// The number of invocations made so far in this transaction
+    syscall_count: u32,
+    /// The max number of invocations a single transaction may make
+    max_syscalls_per_transaction: u32,
 }
 
 impl<'g, 's, W, R> Kernel<'g, 's, W, R>
@@ -83,6 +92,10 @@ where
         auth_zone_params: AuthZoneParams,
         blobs: &'g HashMap<Hash, &'g [u8]>,
         max_depth: usize,
+        max_owned_nodes_per_frame: usize,
+        max_auth_zone_proofs: usize,
+        max_syscalls_per_transaction: u32,
+        publish_badge: Option<ResourceAddress>,
         track: Track<'s, R>,
         scrypto_interpreter: &'g ScryptoInterpreter<W>,
         modules: Vec<Box<dyn Module<R>>>,
@@ -92,6 +105,10 @@ where
             transaction_hash,
             blobs,
             max_depth,
+            max_owned_nodes_per_frame,
+            publish_badge,
+            syscall_count: 0,
+            max_syscalls_per_transaction,
             heap: Heap::new(),
             track,
             scrypto_interpreter,
@@ -109,6 +126,7 @@ where
                     vec![],
                     auth_zone_params.virtualizable_proofs_resource_addresses,
                     auth_zone_params.initial_proofs.into_iter().collect(),
+                    max_auth_zone_proofs,
                 );
 
                 let node_id = system_api.allocate_node_id(RENodeType::AuthZoneStack)?;
@@ -154,6 +172,11 @@ where
         kernel
     }
 
+    /// The number of invocations made so far in this transaction
+    pub fn syscall_count(&self) -> u32 {
+        self.syscall_count
+    }
+
     fn new_uuid(
         id_allocator: &mut IdAllocator,
         transaction_hash: Hash,
@@ -304,6 +327,54 @@ where
         Ok(())
     }
 
+    fn verify_publish_permission(&mut self, actor: &REActor) -> Result<(), RuntimeError> {
+        let badge = match (self.publish_badge, actor) {
+            (
+                Some(badge),
+                REActor::Function(ResolvedFunction::Native(NativeFunction::Package(
+                    PackageFunction::Publish | PackageFunction::PublishWithOwner,
+                ))),
+            ) => badge,
+            _ => return Ok(()),
+        };
+
+        let authorization = convert(
+            &Type::Any,
+            &IndexedScryptoValue::unit(),
+            &rule!(require(badge)),
+        );
+        let is_authorized = self.execute_in_mode(ExecutionMode::AuthModule, |system_api| {
+            let auth_zone_id = system_api
+                .get_visible_node_ids()?
+                .into_iter()
+                .find(|e| matches!(e, RENodeId::AuthZoneStack(..)))
+                .unwrap();
+            let handle = system_api.lock_substate(
+                auth_zone_id,
+                SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack),
+                LockFlags::read_only(),
+            )?;
+            let (result, proofs_examined) = {
+                let substate_ref = system_api.get_ref(handle)?;
+                substate_ref
+                    .auth_zone_stack()
+                    .check_auth_counting_proofs(false, vec![authorization])
+            };
+            system_api.consume_auth_check_cost(proofs_examined)?;
+            let is_authorized = result.is_ok();
+            system_api.drop_lock(handle)?;
+            Ok::<bool, RuntimeError>(is_authorized)
+        })?;
+
+        if is_authorized {
+            Ok(())
+        } else {
+            Err(RuntimeError::KernelError(
+                KernelError::PublishNotAuthorized(badge),
+            ))
+        }
+    }
+
     fn run<X: Executor>(
         &mut self,
         executor: X,
@@ -327,6 +398,7 @@ where
         self.execute_in_mode(ExecutionMode::AuthModule, |system_api| {
             AuthModule::on_before_frame_start(&actor, system_api)
         })?;
+        self.verify_publish_permission(&actor)?;
 
         // New Call Frame pre-processing
         {
@@ -397,14 +469,15 @@ where
 
         // Call Frame Pop
         {
-            let mut parent = self.prev_frame_stack.pop().unwrap();
-            CallFrame::update_upstream(&mut self.current_frame, &mut parent, update)?;
+            // Keep the parent on the stack until the frame is cleaned up
+            let parent = self.prev_frame_stack.last_mut().unwrap();
+            CallFrame::update_upstream(&mut self.current_frame, parent, update)?;
 
             // drop proofs and check resource leak
             self.drop_nodes_in_frame()?;
 
             // Restore previous frame
-            self.current_frame = parent;
+            self.current_frame = self.prev_frame_stack.pop().unwrap();
         }
 
         if let Some(derefed_lock) = derefed_lock {
@@ -512,31 +585,25 @@ where
                                 continue;
                             }
 
-                            let offset = SubstateOffset::Global(GlobalOffset::Global);
+                            let substate_id = SubstateId::global(*global_address);
                             self.track
-                                .acquire_lock(
-                                    SubstateId(*node_id, offset.clone()),
-                                    LockFlags::read_only(),
-                                )
+                                .acquire_lock(substate_id.clone(), LockFlags::read_only())
                                 .map_err(|_| KernelError::RENodeNotFound(*node_id))?;
                             self.track
-                                .release_lock(SubstateId(*node_id, offset), false)
+                                .release_lock(substate_id, false)
                                 .map_err(|_| KernelError::RENodeNotFound(*node_id))?;
                             self.current_frame
                                 .add_stored_ref(*node_id, RENodeVisibilityOrigin::Normal);
                         }
                     }
-                    RENodeId::Vault(..) => {
+                    RENodeId::Vault(vault_id) => {
                         if self.current_frame.get_node_location(*node_id).is_err() {
-                            let offset = SubstateOffset::Vault(VaultOffset::Vault);
+                            let substate_id = SubstateId::vault(*vault_id);
                             self.track
-                                .acquire_lock(
-                                    SubstateId(*node_id, offset.clone()),
-                                    LockFlags::read_only(),
-                                )
+                                .acquire_lock(substate_id.clone(), LockFlags::read_only())
                                 .map_err(|_| KernelError::RENodeNotFound(*node_id))?;
                             self.track
-                                .release_lock(SubstateId(*node_id, offset), false)
+                                .release_lock(substate_id, false)
                                 .map_err(|_| KernelError::RENodeNotFound(*node_id))?;
 
                             self.current_frame
@@ -580,6 +647,44 @@ where
         }
         Ok(())
     }
+
+    /// Checks the internal consistency of the call frame stack, heap and track, panicking with a
+    /// description of the first violation found.
+    ///
+    /// This is run at the start and end of every state changing system call in debug builds, so it
+    /// can serve as an oracle when fuzzing the kernel.
+    pub fn assert_invariants(&self) {
+        assert_eq!(
+            self.current_frame.depth,
+            self.prev_frame_stack.len(),
+            "Current frame depth does not match the call frame stack"
+        );
+
+        let mut owners: HashMap<RENodeId, usize> = HashMap::new();
+        for (depth, frame) in self
+            .prev_frame_stack
+            .iter()
+            .chain(iter::once(&self.current_frame))
+            .enumerate()
+        {
+            assert_eq!(
+                frame.depth, depth,
+                "Frame at position {} has depth {}",
+                depth, frame.depth
+            );
+
+            frame.assert_invariants(&self.heap, &self.track);
+
+            for node_id in frame.owned_nodes() {
+                if let Some(other_depth) = owners.insert(node_id, depth) {
+                    panic!(
+                        "Node {:?} is owned by both frame {} and frame {}",
+                        node_id, other_depth, depth
+                    );
+                }
+            }
+        }
+    }
 }
 
 impl<'g, 's, W, R> ResolverApi<W> for Kernel<'g, 's, W, R>
@@ -591,17 +696,34 @@ where
         self.node_method_deref(node_id)
     }
 
-    fn vm(&mut self) -> &ScryptoInterpreter<W> {
-        self.scrypto_interpreter
-    }
-
-    fn on_wasm_instantiation(&mut self, code: &[u8]) -> Result<(), RuntimeError> {
+    fn instantiate_wasm(
+        &mut self,
+        package_lock_handle: LockHandle,
+    ) -> Result<W::WasmInstance, RuntimeError> {
+        // Modules are notified with the code size only, as they need the track mutably while
+        // the code is borrowed from it
+        let code_size = self
+            .current_frame
+            .get_ref(package_lock_handle, &mut self.heap, &mut self.track)?
+            .package_info()
+            .code()
+            .len();
         for m in &mut self.modules {
-            m.on_wasm_instantiation(&self.current_frame, &mut self.heap, &mut self.track, code)
-                .map_err(RuntimeError::ModuleError)?;
+            m.on_wasm_instantiation(
+                &self.current_frame,
+                &mut self.heap,
+                &mut self.track,
+                code_size,
+            )
+            .map_err(RuntimeError::ModuleError)?;
         }
 
-        Ok(())
+        let substate_ref =
+            self.current_frame
+                .get_ref(package_lock_handle, &mut self.heap, &mut self.track)?;
+        Ok(self
+            .scrypto_interpreter
+            .instantiate(substate_ref.package_info().code()))
     }
 }
 
@@ -632,6 +754,18 @@ where
     N: ExecutableInvocation<W>,
 {
     fn invoke(&mut self, invocation: N) -> Result<<N as Invocation>::Output, RuntimeError> {
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+
+        if self.syscall_count >= self.max_syscalls_per_transaction {
+            return Err(RuntimeError::KernelError(
+                KernelError::SyscallLimitExceeded {
+                    count: self.syscall_count,
+                },
+            ));
+        }
+        self.syscall_count += 1;
+
         for m in &mut self.modules {
             m.pre_sys_call(
                 &self.current_frame,
@@ -668,6 +802,9 @@ where
             .map_err(RuntimeError::ModuleError)?;
         }
 
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+
         Ok(rtn)
     }
 }
@@ -709,12 +846,33 @@ where
         Ok(())
     }
 
+    fn consume_auth_check_cost(&mut self, proofs_examined: u32) -> Result<(), RuntimeError> {
+        for m in &mut self.modules {
+            m.on_auth_check(
+                &self.current_frame,
+                &mut self.heap,
+                &mut self.track,
+                proofs_examined,
+            )
+            .map_err(RuntimeError::ModuleError)?;
+        }
+
+        Ok(())
+    }
+
     fn lock_fee(
         &mut self,
         vault_id: VaultId,
+        resource_address: ResourceAddress,
         mut fee: Resource,
         contingent: bool,
     ) -> Result<Resource, RuntimeError> {
+        if resource_address != RADIX_TOKEN {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::VaultError(VaultError::FeeNotXrd(resource_address)),
+            ));
+        }
+
         for m in &mut self.modules {
             fee = m
                 .on_lock_fee(
@@ -722,6 +880,7 @@ where
                     &mut self.heap,
                     &mut self.track,
                     vault_id,
+                    resource_address,
                     fee,
                     contingent,
                 )
@@ -731,10 +890,29 @@ where
         Ok(fee)
     }
 
+    fn register_fee_callback(
+        &mut self,
+        vault_id: VaultId,
+        callback: ScryptoFunctionIdent,
+    ) -> Result<(), RuntimeError> {
+        self.track
+            .fee_reserve
+            .register_fee_callback(vault_id, callback);
+        Ok(())
+    }
+
+    fn fee_callbacks(&self) -> Vec<(ScryptoFunctionIdent, Decimal)> {
+        self.track.fee_reserve.fee_callbacks()
+    }
+
     fn get_actor(&self) -> &REActor {
         &self.current_frame.actor
     }
 
+    fn get_caller(&self) -> Option<&REActor> {
+        self.prev_frame_stack.last().map(|frame| &frame.actor)
+    }
+
     fn get_visible_node_ids(&mut self) -> Result<Vec<RENodeId>, RuntimeError> {
         for m in &mut self.modules {
             m.pre_sys_call(
@@ -769,7 +947,14 @@ where
         Ok(visibility)
     }
 
+    fn node_exists(&mut self, node_id: RENodeId) -> Result<bool, RuntimeError> {
+        Ok(self.heap.contains_node(&node_id) || self.track.is_node_loaded(node_id))
+    }
+
     fn drop_node(&mut self, node_id: RENodeId) -> Result<HeapRENode, RuntimeError> {
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+
         for m in &mut self.modules {
             m.pre_sys_call(
                 &self.current_frame,
@@ -813,6 +998,9 @@ where
             .map_err(RuntimeError::ModuleError)?;
         }
 
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+
         Ok(node)
     }
 
@@ -867,6 +1055,14 @@ where
                 .id_allocator
                 .new_component_id(self.transaction_hash)
                 .map(|id| RENodeId::Clock(id)),
+            RENodeType::Burner => self
+                .id_allocator
+                .new_component_id(self.transaction_hash)
+                .map(|id| RENodeId::Burner(id)),
+            RENodeType::TimeLockedVault => self
+                .id_allocator
+                .new_component_id(self.transaction_hash)
+                .map(|id| RENodeId::TimeLockedVault(id)),
             RENodeType::GlobalPackage => self
                 .id_allocator
                 .new_package_address(self.transaction_hash)
@@ -879,6 +1075,14 @@ where
                 .id_allocator
                 .new_clock_address(self.transaction_hash)
                 .map(|address| RENodeId::Global(GlobalAddress::System(address))),
+            RENodeType::GlobalBurner => self
+                .id_allocator
+                .new_burner_address(self.transaction_hash)
+                .map(|address| RENodeId::Global(GlobalAddress::System(address))),
+            RENodeType::GlobalTimeLockedVault => self
+                .id_allocator
+                .new_time_locked_vault_address(self.transaction_hash)
+                .map(|address| RENodeId::Global(GlobalAddress::System(address))),
             RENodeType::GlobalResourceManager => self
                 .id_allocator
                 .new_resource_address(self.transaction_hash)
@@ -900,6 +1104,9 @@ where
     }
 
     fn create_node(&mut self, node_id: RENodeId, re_node: RENode) -> Result<(), RuntimeError> {
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+
         for m in &mut self.modules {
             m.pre_sys_call(
                 &self.current_frame,
@@ -944,6 +1151,14 @@ where
                 RENodeId::Global(GlobalAddress::System(..)),
                 RENode::Global(GlobalAddressSubstate::Clock(..)),
             ) => {}
+            (
+                RENodeId::Global(GlobalAddress::System(..)),
+                RENode::Global(GlobalAddressSubstate::Burner(..)),
+            ) => {}
+            (
+                RENodeId::Global(GlobalAddress::System(..)),
+                RENode::Global(GlobalAddressSubstate::TimeLockedVault(..)),
+            ) => {}
             (
                 RENodeId::Global(address),
                 RENode::Global(GlobalAddressSubstate::Component(component)),
@@ -999,12 +1214,22 @@ where
             (RENodeId::ResourceManager(..), RENode::ResourceManager(..)) => {}
             (RENodeId::EpochManager(..), RENode::EpochManager(..)) => {}
             (RENodeId::Clock(..), RENode::Clock(..)) => {}
+            (RENodeId::Burner(..), RENode::Burner(..)) => {}
+            (RENodeId::TimeLockedVault(..), RENode::TimeLockedVault(..)) => {}
             _ => return Err(RuntimeError::KernelError(KernelError::InvalidId(node_id))),
         }
 
         // TODO: For Scrypto components, check state against blueprint schema
 
         let push_to_store = matches!(re_node, RENode::Global(..));
+        if !push_to_store && self.current_frame.owned_node_count() >= self.max_owned_nodes_per_frame
+        {
+            return Err(RuntimeError::KernelError(
+                KernelError::OwnedNodeLimitExceeded {
+                    limit: self.max_owned_nodes_per_frame,
+                },
+            ));
+        }
         self.current_frame.create_node(
             node_id,
             re_node,
@@ -1014,6 +1239,16 @@ where
             false,
         )?;
 
+        for m in &mut self.modules {
+            m.on_new_node(
+                &self.current_frame,
+                &mut self.heap,
+                &mut self.track,
+                node_id,
+            )
+            .map_err(RuntimeError::ModuleError)?;
+        }
+
         // Restore current mode
         self.execution_mode = current_mode;
 
@@ -1027,6 +1262,9 @@ where
             .map_err(RuntimeError::ModuleError)?;
         }
 
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+
         Ok(())
     }
 
@@ -1036,6 +1274,9 @@ where
         offset: SubstateOffset,
         flags: LockFlags,
     ) -> Result<LockHandle, RuntimeError> {
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+
         for m in &mut self.modules {
             m.pre_sys_call(
                 &self.current_frame,
@@ -1116,16 +1357,15 @@ where
                     RuntimeError::CallFrameError(CallFrameError::RENodeNotVisible(
                         RENodeId::Global(GlobalAddress::Package(package_address)),
                     )) => {
-                        let node_id = RENodeId::Global(GlobalAddress::Package(*package_address));
-                        let offset = SubstateOffset::Global(GlobalOffset::Global);
+                        let substate_id =
+                            SubstateId::global(GlobalAddress::Package(*package_address));
+                        let node_id = substate_id.node_id();
+                        let offset = substate_id.offset().clone();
                         self.track
-                            .acquire_lock(
-                                SubstateId(node_id, offset.clone()),
-                                LockFlags::read_only(),
-                            )
+                            .acquire_lock(substate_id.clone(), LockFlags::read_only())
                             .map_err(|_| err.clone())?;
                         self.track
-                            .release_lock(SubstateId(node_id, offset.clone()), false)
+                            .release_lock(substate_id, false)
                             .map_err(|_| err)?;
                         self.current_frame
                             .add_stored_ref(node_id, RENodeVisibilityOrigin::Normal);
@@ -1133,7 +1373,7 @@ where
                             &mut self.heap,
                             &mut self.track,
                             node_id,
-                            offset.clone(),
+                            offset,
                             flags,
                         )?
                     }
@@ -1160,6 +1400,9 @@ where
             .map_err(RuntimeError::ModuleError)?;
         }
 
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+
         Ok(lock_handle)
     }
 
@@ -1168,6 +1411,9 @@ where
     }
 
     fn drop_lock(&mut self, lock_handle: LockHandle) -> Result<(), RuntimeError> {
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+
         for m in &mut self.modules {
             m.pre_sys_call(
                 &self.current_frame,
@@ -1193,6 +1439,9 @@ where
             .map_err(RuntimeError::ModuleError)?;
         }
 
+        #[cfg(debug_assertions)]
+        self.assert_invariants();
+
         Ok(())
     }
 
@@ -1268,6 +1517,60 @@ where
         Ok(substate_ref_mut)
     }
 
+    fn read_epoch(&mut self) -> Result<u64, RuntimeError> {
+        for m in &mut self.modules {
+            m.pre_sys_call(
+                &self.current_frame,
+                &mut self.heap,
+                &mut self.track,
+                SysCallInput::ReadEpoch,
+            )
+            .map_err(RuntimeError::ModuleError)?;
+        }
+
+        // Read from the track directly, as the epoch manager need not be visible to the caller
+        let global_substate_id = SubstateId::global(GlobalAddress::System(EPOCH_MANAGER));
+        self.track
+            .acquire_lock(global_substate_id.clone(), LockFlags::read_only())
+            .map_err(KernelError::TrackError)?;
+        let node_id = self
+            .track
+            .get_substate(global_substate_id.node_id(), global_substate_id.offset())
+            .global_address()
+            .node_deref();
+        self.track
+            .release_lock(global_substate_id, false)
+            .map_err(KernelError::TrackError)?;
+
+        let substate_id = SubstateId::new(
+            node_id,
+            SubstateOffset::EpochManager(EpochManagerOffset::EpochManager),
+        );
+        self.track
+            .acquire_lock(substate_id.clone(), LockFlags::read_only())
+            .map_err(KernelError::TrackError)?;
+        let epoch = self
+            .track
+            .get_substate(substate_id.node_id(), substate_id.offset())
+            .epoch_manager()
+            .epoch;
+        self.track
+            .release_lock(substate_id, false)
+            .map_err(KernelError::TrackError)?;
+
+        for m in &mut self.modules {
+            m.post_sys_call(
+                &self.current_frame,
+                &mut self.heap,
+                &mut self.track,
+                SysCallOutput::ReadEpoch { epoch },
+            )
+            .map_err(RuntimeError::ModuleError)?;
+        }
+
+        Ok(epoch)
+    }
+
     fn read_transaction_hash(&mut self) -> Result<Hash, RuntimeError> {
         for m in &mut self.modules {
             m.pre_sys_call(
@@ -1351,6 +1654,42 @@ where
         Ok(uuid)
     }
 
+    fn hash(&mut self, algorithm: HashAlgorithm, data: &[u8]) -> Result<Hash, RuntimeError> {
+        if data.len() > MAX_HASH_INPUT_SIZE {
+            return Err(RuntimeError::KernelError(KernelError::HashInputTooLarge {
+                size: data.len(),
+                limit: MAX_HASH_INPUT_SIZE,
+            }));
+        }
+
+        for m in &mut self.modules {
+            m.pre_sys_call(
+                &self.current_frame,
+                &mut self.heap,
+                &mut self.track,
+                SysCallInput::Hash {
+                    algorithm: &algorithm,
+                    size: data.len(),
+                },
+            )
+            .map_err(RuntimeError::ModuleError)?;
+        }
+
+        let hash = algorithm.hash(data);
+
+        for m in &mut self.modules {
+            m.post_sys_call(
+                &self.current_frame,
+                &mut self.heap,
+                &mut self.track,
+                SysCallOutput::Hash { hash: &hash },
+            )
+            .map_err(RuntimeError::ModuleError)?;
+        }
+
+        Ok(hash)
+    }
+
     fn emit_event(&mut self, event: Event) -> Result<(), RuntimeError> {
         for m in &mut self.modules {
             m.pre_sys_call(
@@ -1378,6 +1717,27 @@ where
 
         Ok(())
     }
+
+    fn set_metadata(
+        &mut self,
+        node_id: RENodeId,
+        key: String,
+        value: String,
+    ) -> Result<(), RuntimeError> {
+        // TODO: Extend to the remaining native components
+        match node_id {
+            RENodeId::Package(..) | RENodeId::ResourceManager(..) => {}
+            _ => return Err(RuntimeError::KernelError(KernelError::InvalidId(node_id))),
+        }
+
+        let offset = SubstateOffset::Metadata(MetadataOffset::Metadata);
+        let handle = self.lock_substate(node_id, offset, LockFlags::MUTABLE)?;
+        {
+            let mut substate_ref_mut = self.get_ref_mut(handle)?;
+            substate_ref_mut.metadata().insert(key, value);
+        }
+        self.drop_lock(handle)
+    }
 }
 
 impl<'g, 's, W, R> LoggerApi<RuntimeError> for Kernel<'g, 's, W, R>
@@ -2,6 +2,7 @@ mod actor;
 mod call_frame;
 mod errors;
 mod event;
+mod gas_budget;
 mod heap;
 mod interpreters;
 mod kernel;
@@ -16,6 +17,7 @@ pub use actor::*;
 pub use call_frame::*;
 pub use errors::*;
 pub use event::*;
+pub use gas_budget::*;
 pub use heap::*;
 pub use interpreters::*;
 pub use kernel::*;
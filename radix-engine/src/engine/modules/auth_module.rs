@@ -41,10 +41,7 @@ impl AuthModule {
             .unwrap();
         call_frame_update.node_refs_to_copy.insert(auth_zone_id);
 
-        if !matches!(
-            actor,
-            REActor::Method(ResolvedMethod::Native(NativeMethod::AuthZoneStack(..)), ..)
-        ) {
+        if Self::needs_auth_zone_frame(actor) {
             let handle = system_api.lock_substate(
                 auth_zone_id,
                 SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack),
@@ -62,6 +59,59 @@ impl AuthModule {
         Ok(())
     }
 
+    /// Whether the actor gets an auth zone frame of its own.
+    ///
+    /// The auth zone natives work on the frame of their caller. The read-only natives below make
+    /// no calls and never touch the auth zone, so a frame of their own would go unused.
+    fn needs_auth_zone_frame(actor: &REActor) -> bool {
+        match actor {
+            REActor::Method(ResolvedMethod::Native(method), ..) => !matches!(
+                method,
+                NativeMethod::AuthZoneStack(..)
+                    | NativeMethod::Bucket(
+                        BucketMethod::GetAmount
+                            | BucketMethod::GetResourceAddress
+                            | BucketMethod::GetNonFungibleIds
+                    )
+                    | NativeMethod::Proof(
+                        ProofMethod::GetAmount
+                            | ProofMethod::GetResourceAddress
+                            | ProofMethod::GetNonFungibleIds
+                            | ProofMethod::IsValid
+                    )
+                    | NativeMethod::Vault(
+                        VaultMethod::GetAmount
+                            | VaultMethod::GetResourceAddress
+                            | VaultMethod::GetNonFungibleIds
+                    )
+                    | NativeMethod::Worktop(
+                        WorktopMethod::GetAmount
+                            | WorktopMethod::AssertContains
+                            | WorktopMethod::AssertContainsAmount
+                            | WorktopMethod::AssertContainsExactAmount
+                            | WorktopMethod::AssertContainsNonFungibles
+                    )
+                    | NativeMethod::ResourceManager(
+                        ResourceManagerMethod::GetResourceType
+                            | ResourceManagerMethod::GetTotalSupply
+                            | ResourceManagerMethod::GetNonFungible
+                            | ResourceManagerMethod::NonFungibleExists
+                            | ResourceManagerMethod::GetHolderVaultCount
+                            | ResourceManagerMethod::GetAmountMinted
+                            | ResourceManagerMethod::GetAmountBurned
+                    )
+                    | NativeMethod::Metadata(MetadataMethod::Get)
+                    | NativeMethod::Component(ComponentMethod::StateSizeBytes)
+                    | NativeMethod::Package(PackageMethod::GetBuildFingerprint)
+                    | NativeMethod::EpochManager(EpochManagerMethod::GetCurrentEpoch)
+                    | NativeMethod::Clock(
+                        ClockMethod::GetCurrentTime | ClockMethod::CompareCurrentTime
+                    )
+            ),
+            _ => true,
+        }
+    }
+
     fn is_barrier(actor: &REActor) -> bool {
         matches!(
             actor,
@@ -86,6 +136,21 @@ impl AuthModule {
             return Ok(());
         }
 
+        // The worktop belongs to the transaction processor, so only it may operate on it
+        if matches!(
+            actor,
+            REActor::Method(ResolvedMethod::Native(NativeMethod::Worktop(..)), ..)
+        ) && !matches!(
+            system_api.get_actor(),
+            REActor::Function(ResolvedFunction::Native(
+                NativeFunction::TransactionProcessor(TransactionProcessorFunction::Run),
+            ))
+        ) {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::WorktopError(WorktopError::InvalidCaller),
+            ));
+        }
+
         let method_auths = match actor.clone() {
             REActor::Function(function_ident) => match function_ident {
                 ResolvedFunction::Native(NativeFunction::EpochManager(epoch_manager_func)) => {
@@ -107,12 +172,55 @@ impl AuthModule {
                     ) => {
                         vec![]
                     }
+                    // Reading the size of a component's state or the build fingerprint of a
+                    // package is always allowed
+                    (
+                        ResolvedMethod::Native(
+                            NativeMethod::Component(ComponentMethod::StateSizeBytes)
+                            | NativeMethod::Package(PackageMethod::GetBuildFingerprint),
+                        ),
+                        ..,
+                    ) => {
+                        vec![]
+                    }
+                    // Access rules may be freely modified prior to globalization, after which
+                    // the auth mutability rule of the component must be satisfied
+                    (
+                        ResolvedMethod::Native(
+                            NativeMethod::AccessRulesChain(AccessRulesChainMethod::AddAccessCheck)
+                            | NativeMethod::Component(ComponentMethod::SetAuthMutability),
+                        ),
+                        ResolvedReceiver {
+                            receiver: RENodeId::Component(component_id),
+                            derefed_from,
+                        },
+                    ) => match derefed_from {
+                        Some((RENodeId::Global(GlobalAddress::Component(..)), ..)) => {
+                            let offset = SubstateOffset::Component(ComponentOffset::Info);
+                            let handle = system_api.lock_substate(
+                                RENodeId::Component(component_id),
+                                offset,
+                                LockFlags::read_only(),
+                            )?;
+                            let substate_ref = system_api.get_ref(handle)?;
+                            let auth_mutability =
+                                substate_ref.component_info().auth_mutability.clone();
+                            system_api.drop_lock(handle)?;
+                            vec![convert(
+                                &Type::Any,
+                                &IndexedScryptoValue::unit(),
+                                &auth_mutability,
+                            )]
+                        }
+                        _ => vec![],
+                    },
                     (ResolvedMethod::Native(method), ..)
                         if matches!(method, NativeMethod::Metadata(..))
                             || matches!(method, NativeMethod::EpochManager(..))
                             || matches!(method, NativeMethod::ResourceManager(..))
                             || matches!(method, NativeMethod::Package(..))
                             || matches!(method, NativeMethod::Clock(..))
+                            || matches!(method, NativeMethod::TimeLockedVault(..))
                             || matches!(method, NativeMethod::Component(..)) =>
                     {
                         let offset = SubstateOffset::AccessRulesChain(
@@ -263,34 +371,61 @@ impl AuthModule {
             SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack),
             LockFlags::read_only(),
         )?;
-        let substate_ref = system_api.get_ref(handle)?;
-        let auth_zone_stack = substate_ref.auth_zone_stack();
         let is_barrier = Self::is_barrier(actor);
+        let (result, proofs_examined) = {
+            let substate_ref = system_api.get_ref(handle)?;
+            let auth_zone_stack = substate_ref.auth_zone_stack();
+            auth_zone_stack.check_auth_counting_proofs(is_barrier, method_auths)
+        };
+        system_api.consume_auth_check_cost(proofs_examined)?;
 
         // Authorization check
-        auth_zone_stack
-            .check_auth(is_barrier, method_auths)
-            .map_err(|(authorization, error)| {
-                RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized {
-                    actor: actor.clone(),
-                    authorization,
-                    error,
-                }))
-            })?;
+        result.map_err(|(authorization, error)| {
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized {
+                actor: actor.clone(),
+                authorization,
+                error,
+            }))
+        })?;
 
         system_api.drop_lock(handle)?;
 
         Ok(())
     }
 
+    /// Returns the resources which virtual proofs can be created for in the auth zone stack
+    pub fn virtual_proof_resources<Y: SystemApi>(
+        system_api: &mut Y,
+    ) -> Result<Vec<ResourceAddress>, RuntimeError> {
+        let refed = system_api.get_visible_node_ids()?;
+        let auth_zone_id = refed
+            .into_iter()
+            .find(|e| matches!(e, RENodeId::AuthZoneStack(..)))
+            .unwrap();
+
+        let handle = system_api.lock_substate(
+            auth_zone_id,
+            SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack),
+            LockFlags::read_only(),
+        )?;
+        let resources = {
+            let substate_ref = system_api.get_ref(handle)?;
+            substate_ref
+                .auth_zone_stack()
+                .virtual_proof_resources()
+                .into_iter()
+                .collect()
+        };
+        system_api.drop_lock(handle)?;
+
+        Ok(resources)
+    }
+
     pub fn on_call_frame_exit<Y>(system_api: &mut Y) -> Result<(), RuntimeError>
     where
         Y: SystemApi,
     {
-        if matches!(
-            system_api.get_actor(),
-            REActor::Method(ResolvedMethod::Native(NativeMethod::AuthZoneStack(..)), ..)
-        ) {
+        if !Self::needs_auth_zone_frame(system_api.get_actor()) {
             return Ok(());
         }
 
@@ -1,8 +1,11 @@
 use crate::engine::*;
-use crate::fee::{FeeReserve, FeeReserveError, SystemApiCostingEntry};
+use crate::fee::{CostingActor, FeeReserve, FeeReserveError, SystemApiCostingEntry};
 use crate::model::Resource;
 use crate::types::*;
-use radix_engine_interface::api::types::{RENodeId, VaultId};
+use radix_engine_interface::api::types::{
+    AuthZoneStackMethod, GlobalAddress, NativeMethod, RENodeId, VaultId,
+};
+use sbor::rust::mem;
 
 #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeId)]
 pub enum CostingError {
@@ -10,7 +13,37 @@ pub enum CostingError {
 }
 
 #[derive(Default)]
-pub struct CostingModule;
+pub struct CostingModule {
+    /// The actors of the invocations in progress, used for cost attribution
+    actors: Vec<CostingActor>,
+    /// The execution cost units attributed to each actor
+    cost_units: HashMap<CostingActor, u32>,
+}
+
+impl CostingModule {
+    fn current_actor(&self) -> &CostingActor {
+        self.actors.last().unwrap_or(&CostingActor::Transaction)
+    }
+
+    fn consume_execution<R: FeeReserve>(
+        &mut self,
+        amount: u32,
+        multiplier: usize,
+        reason: &'static str,
+        track: &mut Track<R>,
+    ) -> Result<(), ModuleError> {
+        track
+            .fee_reserve
+            .consume_execution(amount, multiplier, reason, false)
+            .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))?;
+
+        // Cannot overflow, as the fee reserve has just consumed the same amount
+        let units = amount * multiplier as u32;
+        let actor = self.current_actor().clone();
+        *self.cost_units.entry(actor).or_default() += units;
+        Ok(())
+    }
+}
 
 impl<R: FeeReserve> Module<R> for CostingModule {
     fn pre_sys_call(
@@ -28,281 +61,269 @@ impl<R: FeeReserve> Module<R> for CostingModule {
                 ..
             } => {
                 if depth > 0 {
-                    track
-                        .fee_reserve
-                        .consume_execution(
-                            track
-                                .fee_table
-                                .system_api_cost(SystemApiCostingEntry::Invoke {
-                                    input_size,
-                                    value_count,
-                                }),
-                            1,
-                            "invoke",
-                            false,
-                        )
-                        .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))?;
-                }
-            }
-            SysCallInput::ReadOwnedNodes => {
-                track
-                    .fee_reserve
-                    .consume_execution(
+                    self.consume_execution(
                         track
                             .fee_table
-                            .system_api_cost(SystemApiCostingEntry::ReadOwnedNodes),
+                            .system_api_cost(SystemApiCostingEntry::Invoke {
+                                input_size,
+                                value_count,
+                            }),
                         1,
-                        "read_owned_nodes",
-                        false,
-                    )
-                    .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))?;
+                        "invoke",
+                        track,
+                    )?;
+                }
+            }
+            SysCallInput::ReadOwnedNodes => {
+                self.consume_execution(
+                    track
+                        .fee_table
+                        .system_api_cost(SystemApiCostingEntry::ReadOwnedNodes),
+                    1,
+                    "read_owned_nodes",
+                    track,
+                )?;
             }
             SysCallInput::BorrowNode { node_id } => {
-                track
-                    .fee_reserve
-                    .consume_execution(
-                        track.fee_table.system_api_cost({
-                            match node_id {
-                                RENodeId::Global(_) => SystemApiCostingEntry::BorrowNode {
-                                    // TODO: figure out loaded state and size
-                                    loaded: true,
-                                    size: 0,
-                                },
-                                RENodeId::AuthZoneStack(_) => SystemApiCostingEntry::BorrowNode {
-                                    // TODO: figure out loaded state and size
-                                    loaded: true,
-                                    size: 0,
-                                },
-                                RENodeId::FeeReserve(_) => SystemApiCostingEntry::BorrowNode {
-                                    // TODO: figure out loaded state and size
-                                    loaded: true,
-                                    size: 0,
-                                },
-                                RENodeId::Bucket(_) => SystemApiCostingEntry::BorrowNode {
-                                    // TODO: figure out loaded state and size
-                                    loaded: true,
-                                    size: 0,
-                                },
-                                RENodeId::Proof(_) => SystemApiCostingEntry::BorrowNode {
-                                    // TODO: figure out loaded state and size
-                                    loaded: true,
-                                    size: 0,
-                                },
-                                RENodeId::Worktop => SystemApiCostingEntry::BorrowNode {
-                                    // TODO: figure out loaded state and size
-                                    loaded: true,
-                                    size: 0,
-                                },
-                                RENodeId::Vault(_) => SystemApiCostingEntry::BorrowNode {
-                                    // TODO: figure out loaded state and size
-                                    loaded: false,
-                                    size: 0,
-                                },
-                                RENodeId::Component(_) => SystemApiCostingEntry::BorrowNode {
+                self.consume_execution(
+                    track.fee_table.system_api_cost({
+                        match node_id {
+                            RENodeId::Global(_) => SystemApiCostingEntry::BorrowNode {
+                                // TODO: figure out loaded state and size
+                                loaded: true,
+                                size: 0,
+                            },
+                            RENodeId::AuthZoneStack(_) => SystemApiCostingEntry::BorrowNode {
+                                // TODO: figure out loaded state and size
+                                loaded: true,
+                                size: 0,
+                            },
+                            RENodeId::FeeReserve(_) => SystemApiCostingEntry::BorrowNode {
+                                // TODO: figure out loaded state and size
+                                loaded: true,
+                                size: 0,
+                            },
+                            RENodeId::Bucket(_) => SystemApiCostingEntry::BorrowNode {
+                                // TODO: figure out loaded state and size
+                                loaded: true,
+                                size: 0,
+                            },
+                            RENodeId::Proof(_) => SystemApiCostingEntry::BorrowNode {
+                                // TODO: figure out loaded state and size
+                                loaded: true,
+                                size: 0,
+                            },
+                            RENodeId::Worktop => SystemApiCostingEntry::BorrowNode {
+                                // TODO: figure out loaded state and size
+                                loaded: true,
+                                size: 0,
+                            },
+                            RENodeId::Vault(_) => SystemApiCostingEntry::BorrowNode {
+                                // TODO: figure out loaded state and size
+                                loaded: false,
+                                size: 0,
+                            },
+                            RENodeId::Component(_) => SystemApiCostingEntry::BorrowNode {
+                                // TODO: figure out loaded state and size
+                                loaded: false,
+                                size: 0,
+                            },
+                            RENodeId::KeyValueStore(_) => SystemApiCostingEntry::BorrowNode {
+                                // TODO: figure out loaded state and size
+                                loaded: false,
+                                size: 0,
+                            },
+                            RENodeId::NonFungibleStore(_) => {
+                                SystemApiCostingEntry::BorrowNode {
                                     // TODO: figure out loaded state and size
                                     loaded: false,
                                     size: 0,
-                                },
-                                RENodeId::KeyValueStore(_) => SystemApiCostingEntry::BorrowNode {
-                                    // TODO: figure out loaded state and size
-                                    loaded: false,
-                                    size: 0,
-                                },
-                                RENodeId::NonFungibleStore(_) => {
-                                    SystemApiCostingEntry::BorrowNode {
-                                        // TODO: figure out loaded state and size
-                                        loaded: false,
-                                        size: 0,
-                                    }
                                 }
-                                RENodeId::ResourceManager(_) => SystemApiCostingEntry::BorrowNode {
-                                    // TODO: figure out loaded state and size
-                                    loaded: false,
-                                    size: 0,
-                                },
-                                RENodeId::Package(_) => SystemApiCostingEntry::BorrowNode {
-                                    // TODO: figure out loaded state and size
-                                    loaded: false,
-                                    size: 0,
-                                },
-                                RENodeId::EpochManager(..) => SystemApiCostingEntry::BorrowNode {
-                                    // TODO: figure out loaded state and size
-                                    loaded: false,
-                                    size: 0,
-                                },
-                                RENodeId::Clock(..) => SystemApiCostingEntry::BorrowNode {
-                                    // TODO: figure out loaded state and size
-                                    loaded: false,
-                                    size: 0,
-                                },
                             }
-                        }),
-                        1,
-                        "borrow_node",
-                        false,
-                    )
-                    .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))?;
+                            RENodeId::ResourceManager(_) => SystemApiCostingEntry::BorrowNode {
+                                // TODO: figure out loaded state and size
+                                loaded: false,
+                                size: 0,
+                            },
+                            RENodeId::Package(_) => SystemApiCostingEntry::BorrowNode {
+                                // TODO: figure out loaded state and size
+                                loaded: false,
+                                size: 0,
+                            },
+                            RENodeId::EpochManager(..) => SystemApiCostingEntry::BorrowNode {
+                                // TODO: figure out loaded state and size
+                                loaded: false,
+                                size: 0,
+                            },
+                            RENodeId::Clock(..) => SystemApiCostingEntry::BorrowNode {
+                                // TODO: figure out loaded state and size
+                                loaded: false,
+                                size: 0,
+                            },
+                            RENodeId::Burner(..) => SystemApiCostingEntry::BorrowNode {
+                                // TODO: figure out loaded state and size
+                                loaded: false,
+                                size: 0,
+                            },
+                            RENodeId::TimeLockedVault(..) => SystemApiCostingEntry::BorrowNode {
+                                // TODO: figure out loaded state and size
+                                loaded: false,
+                                size: 0,
+                            },
+                        }
+                    }),
+                    1,
+                    "borrow_node",
+                    track,
+                )?;
             }
             SysCallInput::DropNode { .. } => {
-                track
-                    .fee_reserve
-                    .consume_execution(
-                        track
-                            .fee_table
-                            .system_api_cost(SystemApiCostingEntry::DropNode { size: 0 }),
-                        1,
-                        "drop_node",
-                        false,
-                    )
-                    .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))?;
+                self.consume_execution(
+                    track
+                        .fee_table
+                        .system_api_cost(SystemApiCostingEntry::DropNode { size: 0 }),
+                    1,
+                    "drop_node",
+                    track,
+                )?;
             }
             SysCallInput::CreateNode { .. } => {
                 // Costing
-                track
-                    .fee_reserve
-                    .consume_execution(
-                        track
-                            .fee_table
-                            .system_api_cost(SystemApiCostingEntry::CreateNode {
-                                size: 0, // TODO: get size of the value
-                            }),
-                        1,
-                        "create_node",
-                        false,
-                    )
-                    .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))?;
+                self.consume_execution(
+                    track
+                        .fee_table
+                        .system_api_cost(SystemApiCostingEntry::CreateNode {
+                            size: 0, // TODO: get size of the value
+                        }),
+                    1,
+                    "create_node",
+                    track,
+                )?;
             }
             SysCallInput::LockSubstate { .. } => {
                 // Costing
-                track
-                    .fee_reserve
-                    .consume_execution(
-                        track
-                            .fee_table
-                            .system_api_cost(SystemApiCostingEntry::LockSubstate {
-                                size: 0, // TODO: get size of the value
-                            }),
-                        1,
-                        "lock_substate",
-                        false,
-                    )
-                    .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))?;
+                self.consume_execution(
+                    track
+                        .fee_table
+                        .system_api_cost(SystemApiCostingEntry::LockSubstate {
+                            size: 0, // TODO: get size of the value
+                        }),
+                    1,
+                    "lock_substate",
+                    track,
+                )?;
             }
             SysCallInput::GetRef { .. } => {
                 // Costing
-                track
-                    .fee_reserve
-                    .consume_execution(
-                        track
-                            .fee_table
-                            .system_api_cost(SystemApiCostingEntry::ReadSubstate {
-                                size: 0, // TODO: get size of the value
-                            }),
-                        1,
-                        "read_substate",
-                        false,
-                    )
-                    .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))?;
+                self.consume_execution(
+                    track
+                        .fee_table
+                        .system_api_cost(SystemApiCostingEntry::ReadSubstate {
+                            size: 0, // TODO: get size of the value
+                        }),
+                    1,
+                    "read_substate",
+                    track,
+                )?;
             }
             SysCallInput::GetRefMut { .. } => {
                 // Costing
-                track
-                    .fee_reserve
-                    .consume_execution(
-                        track
-                            .fee_table
-                            .system_api_cost(SystemApiCostingEntry::WriteSubstate {
-                                size: 0, // TODO: get size of the value
-                            }),
-                        1,
-                        "write_substate",
-                        false,
-                    )
-                    .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))?;
+                self.consume_execution(
+                    track
+                        .fee_table
+                        .system_api_cost(SystemApiCostingEntry::WriteSubstate {
+                            size: 0, // TODO: get size of the value
+                        }),
+                    1,
+                    "write_substate",
+                    track,
+                )?;
             }
             SysCallInput::DropLock { .. } => {
                 // Costing
-                track
-                    .fee_reserve
-                    .consume_execution(
-                        track
-                            .fee_table
-                            .system_api_cost(SystemApiCostingEntry::DropLock),
-                        1,
-                        "drop_lock",
-                        false,
-                    )
-                    .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))?;
+                self.consume_execution(
+                    track
+                        .fee_table
+                        .system_api_cost(SystemApiCostingEntry::DropLock),
+                    1,
+                    "drop_lock",
+                    track,
+                )?;
             }
             SysCallInput::TakeSubstate { .. } => {
                 // Costing
-                track
-                    .fee_reserve
-                    .consume_execution(
-                        track
-                            .fee_table
-                            .system_api_cost(SystemApiCostingEntry::TakeSubstate {
-                                size: 0, // TODO: get size of the value
-                            }),
-                        1,
-                        "take_substate",
-                        false,
-                    )
-                    .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))?;
+                self.consume_execution(
+                    track
+                        .fee_table
+                        .system_api_cost(SystemApiCostingEntry::TakeSubstate {
+                            size: 0, // TODO: get size of the value
+                        }),
+                    1,
+                    "take_substate",
+                    track,
+                )?;
+            }
+            SysCallInput::ReadEpoch => {
+                self.consume_execution(
+                    track
+                        .fee_table
+                        .system_api_cost(SystemApiCostingEntry::ReadEpoch),
+                    1,
+                    "read_epoch",
+                    track,
+                )?;
             }
             SysCallInput::ReadTransactionHash => {
-                track
-                    .fee_reserve
-                    .consume_execution(
-                        track
-                            .fee_table
-                            .system_api_cost(SystemApiCostingEntry::ReadTransactionHash),
-                        1,
-                        "read_transaction_hash",
-                        false,
-                    )
-                    .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))?;
+                self.consume_execution(
+                    track
+                        .fee_table
+                        .system_api_cost(SystemApiCostingEntry::ReadTransactionHash),
+                    1,
+                    "read_transaction_hash",
+                    track,
+                )?;
             }
             SysCallInput::ReadBlob { .. } => {
-                track
-                    .fee_reserve
-                    .consume_execution(
-                        track
-                            .fee_table
-                            .system_api_cost(SystemApiCostingEntry::ReadBlob { size: 0 }), // TODO pass the right size
-                        1,
-                        "read_blob",
-                        false,
-                    )
-                    .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))?;
+                self.consume_execution(
+                    track
+                        .fee_table
+                        .system_api_cost(SystemApiCostingEntry::ReadBlob { size: 0 }), // TODO pass the right size
+                    1,
+                    "read_blob",
+                    track,
+                )?;
             }
             SysCallInput::GenerateUuid => {
-                track
-                    .fee_reserve
-                    .consume_execution(
-                        track
-                            .fee_table
-                            .system_api_cost(SystemApiCostingEntry::GenerateUuid),
-                        1,
-                        "generate_uuid",
-                        false,
-                    )
-                    .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))?;
+                self.consume_execution(
+                    track
+                        .fee_table
+                        .system_api_cost(SystemApiCostingEntry::GenerateUuid),
+                    1,
+                    "generate_uuid",
+                    track,
+                )?;
+            }
+            SysCallInput::Hash { size, .. } => {
+                self.consume_execution(
+                    track
+                        .fee_table
+                        .system_api_cost(SystemApiCostingEntry::Hash { size: size as u32 }),
+                    1,
+                    "hash",
+                    track,
+                )?;
             }
             SysCallInput::EmitLog { message, .. } => {
-                track
-                    .fee_reserve
-                    .consume_execution(
-                        track
-                            .fee_table
-                            .system_api_cost(SystemApiCostingEntry::EmitLog {
-                                size: message.len() as u32,
-                            }),
-                        1,
-                        "emit_log",
-                        false,
-                    )
-                    .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))?;
+                self.consume_execution(
+                    track
+                        .fee_table
+                        .system_api_cost(SystemApiCostingEntry::EmitLog {
+                            size: message.len() as u32,
+                        }),
+                    1,
+                    "emit_log",
+                    track,
+                )?;
             }
             SysCallInput::EmitEvent { event } => {
                 let (native, tracked, size) = match event {
@@ -312,21 +333,18 @@ impl<R: FeeReserve> Module<R> for CostingModule {
                         (false, true, value.len() as u32)
                     }
                 };
-                track
-                    .fee_reserve
-                    .consume_execution(
-                        track
-                            .fee_table
-                            .system_api_cost(SystemApiCostingEntry::EmitEvent {
-                                native,
-                                tracked,
-                                size,
-                            }),
-                        1,
-                        "emit_event",
-                        false,
-                    )
-                    .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))?;
+                self.consume_execution(
+                    track
+                        .fee_table
+                        .system_api_cost(SystemApiCostingEntry::EmitEvent {
+                            native,
+                            tracked,
+                            size,
+                        }),
+                    1,
+                    "emit_event",
+                    track,
+                )?;
             }
         }
 
@@ -348,17 +366,14 @@ impl<R: FeeReserve> Module<R> for CostingModule {
         _call_frame: &CallFrame,
         _heap: &mut Heap,
         track: &mut Track<R>,
-        code: &[u8],
+        code_size: usize,
     ) -> Result<(), ModuleError> {
-        track
-            .fee_reserve
-            .consume_execution(
-                track.fee_table.wasm_instantiation_per_byte(),
-                code.len(),
-                "instantiate_wasm",
-                false,
-            )
-            .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))
+        self.consume_execution(
+            track.fee_table.wasm_instantiation_per_byte(),
+            code_size,
+            "instantiate_wasm",
+            track,
+        )
     }
 
     fn on_wasm_costing(
@@ -368,10 +383,22 @@ impl<R: FeeReserve> Module<R> for CostingModule {
         track: &mut Track<R>,
         units: u32,
     ) -> Result<(), ModuleError> {
-        track
-            .fee_reserve
-            .consume_execution(units, 1, "run_wasm", false)
-            .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))
+        self.consume_execution(units, 1, "run_wasm", track)
+    }
+
+    fn on_auth_check(
+        &mut self,
+        _call_frame: &CallFrame,
+        _heap: &mut Heap,
+        track: &mut Track<R>,
+        proofs_examined: u32,
+    ) -> Result<(), ModuleError> {
+        self.consume_execution(
+            track.fee_table.auth_check_per_proof(),
+            proofs_examined as usize,
+            "verify_auth",
+            track,
+        )
     }
 
     fn on_lock_fee(
@@ -380,12 +407,13 @@ impl<R: FeeReserve> Module<R> for CostingModule {
         _heap: &mut Heap,
         track: &mut Track<R>,
         vault_id: VaultId,
+        resource_address: ResourceAddress,
         fee: Resource,
         contingent: bool,
     ) -> Result<Resource, ModuleError> {
         track
             .fee_reserve
-            .lock_fee(vault_id, fee, contingent)
+            .lock_fee(vault_id, resource_address, fee, contingent)
             .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))
     }
 
@@ -397,26 +425,90 @@ impl<R: FeeReserve> Module<R> for CostingModule {
         _heap: &mut Heap,
         track: &mut Track<R>,
     ) -> Result<(), ModuleError> {
+        let costing_actor = match actor {
+            REActor::Function(ResolvedFunction::Scrypto {
+                package_address,
+                blueprint_name,
+                ..
+            }) => CostingActor::Scrypto {
+                package_address: *package_address,
+                blueprint_name: blueprint_name.clone(),
+                component_address: None,
+            },
+            REActor::Method(
+                ResolvedMethod::Scrypto {
+                    package_address,
+                    blueprint_name,
+                    ..
+                },
+                ResolvedReceiver { derefed_from, .. },
+            ) => CostingActor::Scrypto {
+                package_address: *package_address,
+                blueprint_name: blueprint_name.clone(),
+                component_address: match derefed_from {
+                    Some((RENodeId::Global(GlobalAddress::Component(address)), ..)) => {
+                        Some(*address)
+                    }
+                    _ => None,
+                },
+            },
+            _ => self.current_actor().clone(),
+        };
+        self.actors.push(costing_actor);
+
+        if let REActor::Method(
+            ResolvedMethod::Native(NativeMethod::AuthZoneStack(
+                AuthZoneStackMethod::Push
+                | AuthZoneStackMethod::CreateProof
+                | AuthZoneStackMethod::CreateProofByAmount
+                | AuthZoneStackMethod::CreateProofByIds,
+            )),
+            _,
+        ) = actor
+        {
+            self.consume_execution(
+                track.fee_table.auth_zone_per_proof(),
+                1,
+                "auth_zone_proof",
+                track,
+            )?;
+        }
+
         match actor {
-            REActor::Function(ResolvedFunction::Native(native_function)) => track
-                .fee_reserve
-                .consume_execution(
-                    track.fee_table.run_native_function_cost(&native_function),
-                    1,
-                    "run_native_function",
-                    false,
-                )
-                .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e))),
-            REActor::Method(ResolvedMethod::Native(native_method), _) => track
-                .fee_reserve
-                .consume_execution(
-                    track.fee_table.run_native_method_cost(&native_method),
-                    1,
-                    "run_native_method",
-                    false,
-                )
-                .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e))),
+            REActor::Function(ResolvedFunction::Native(native_function)) => self.consume_execution(
+                track.fee_table.run_native_function_cost(&native_function),
+                1,
+                "run_native_function",
+                track,
+            ),
+            REActor::Method(ResolvedMethod::Native(native_method), _) => self.consume_execution(
+                track.fee_table.run_native_method_cost(&native_method),
+                1,
+                "run_native_method",
+                track,
+            ),
             _ => Ok(()),
         }
     }
+
+    fn post_execute_invocation(
+        &mut self,
+        _caller: &REActor,
+        _update: &CallFrameUpdate,
+        _call_frame: &CallFrame,
+        _heap: &mut Heap,
+        _track: &mut Track<R>,
+    ) -> Result<(), ModuleError> {
+        self.actors.pop();
+        Ok(())
+    }
+
+    fn on_finished_processing(
+        &mut self,
+        _heap: &mut Heap,
+        track: &mut Track<R>,
+    ) -> Result<(), ModuleError> {
+        track.actor_cost_units = mem::take(&mut self.cost_units);
+        Ok(())
+    }
 }
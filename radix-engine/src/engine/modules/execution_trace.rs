@@ -2,13 +2,13 @@ use crate::engine::*;
 use crate::fee::FeeReserve;
 use crate::model::*;
 use crate::types::*;
-use indexmap::IndexMap;
 use radix_engine_interface::api::types::{
     BucketOffset, ComponentId, NativeMethod, RENodeId, SubstateId, SubstateOffset, VaultId,
-    VaultMethod, VaultOffset,
+    VaultMethod,
 };
 use radix_engine_interface::math::Decimal;
 use radix_engine_interface::model::*;
+use sbor::rust::collections::IndexMap;
 use sbor::rust::fmt::Debug;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -20,6 +20,56 @@ pub struct ResourceChange {
     pub amount: Decimal,
 }
 
+/// The actor to which a traced effect, such as the creation of a global entity, is attributed.
+///
+/// Native invocations are attributed to the Scrypto actor which (transitively) invoked them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum ActorSummary {
+    /// The transaction itself, i.e. the manifest instructions
+    Transaction,
+    Scrypto {
+        package_address: PackageAddress,
+        blueprint_name: String,
+        component_address: Option<ComponentAddress>,
+    },
+}
+
+impl ActorSummary {
+    /// Summarizes a Scrypto actor, returning `None` for native actors.
+    fn of_scrypto(actor: &REActor) -> Option<Self> {
+        match actor {
+            REActor::Function(ResolvedFunction::Scrypto {
+                package_address,
+                blueprint_name,
+                ..
+            }) => Some(ActorSummary::Scrypto {
+                package_address: *package_address,
+                blueprint_name: blueprint_name.clone(),
+                component_address: None,
+            }),
+            REActor::Method(
+                ResolvedMethod::Scrypto {
+                    package_address,
+                    blueprint_name,
+                    ..
+                },
+                ResolvedReceiver { derefed_from, .. },
+            ) => Some(ActorSummary::Scrypto {
+                package_address: *package_address,
+                blueprint_name: blueprint_name.clone(),
+                component_address: match derefed_from {
+                    Some((RENodeId::Global(GlobalAddress::Component(address)), ..)) => {
+                        Some(*address)
+                    }
+                    _ => None,
+                },
+            }),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ExecutionTraceReceipt {
     pub resource_changes: Vec<ResourceChange>,
@@ -59,6 +109,9 @@ pub struct ExecutionTraceModule {
 
     /// A mapping of complete SysCallTrace stacks (\w both inputs and outputs), indexed by depth.
     sys_call_traces_stacks: HashMap<usize, Vec<SysCallTrace>>,
+
+    /// The actors of the invocations in progress, used to attribute new global entities.
+    actors: Vec<ActorSummary>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -131,9 +184,20 @@ impl<R: FeeReserve> Module<R> for ExecutionTraceModule {
         &mut self,
         call_frame: &CallFrame,
         heap: &mut Heap,
-        _track: &mut Track<R>,
+        track: &mut Track<R>,
         output: SysCallOutput,
     ) -> Result<(), ModuleError> {
+        if let SysCallOutput::CreateNode {
+            node_id: RENodeId::Global(global_address),
+        } = output
+        {
+            // Recorded regardless of the trace depth, as entities can be created deep in the call tree
+            track.new_entity_creators.insert(
+                *global_address,
+                (self.current_actor().clone(), self.current_instruction_index),
+            );
+        }
+
         self.handle_post_sys_call(call_frame, heap, output)
     }
 
@@ -145,6 +209,10 @@ impl<R: FeeReserve> Module<R> for ExecutionTraceModule {
         heap: &mut Heap,
         track: &mut Track<R>,
     ) -> Result<(), ModuleError> {
+        let actor_summary =
+            ActorSummary::of_scrypto(actor).unwrap_or_else(|| self.current_actor().clone());
+        self.actors.push(actor_summary);
+
         if self.current_sys_call_depth <= self.max_sys_call_trace_depth {
             let origin = match actor {
                 REActor::Method(
@@ -195,9 +263,10 @@ impl<R: FeeReserve> Module<R> for ExecutionTraceModule {
                 (NativeMethod::Vault(VaultMethod::Put), RENodeId::Vault(vault_id)) => {
                     Self::handle_vault_put(update, heap, track, caller, &vault_id)
                 }
-                (NativeMethod::Vault(VaultMethod::LockFee), RENodeId::Vault(vault_id)) => {
-                    Self::handle_vault_lock_fee(track, caller, &vault_id)
-                }
+                (
+                    NativeMethod::Vault(VaultMethod::LockFee | VaultMethod::LockFeeWithCallback),
+                    RENodeId::Vault(vault_id),
+                ) => Self::handle_vault_lock_fee(track, caller, &vault_id),
                 _ => {}
             }
         }
@@ -224,6 +293,8 @@ impl<R: FeeReserve> Module<R> for ExecutionTraceModule {
             }
         }
 
+        self.actors.pop();
+
         // Important to always update the counter (even if we're over the depth limit).
         self.current_sys_call_depth -= 1;
 
@@ -241,7 +312,7 @@ impl<R: FeeReserve> Module<R> for ExecutionTraceModule {
         _call_frame: &CallFrame,
         _heap: &mut Heap,
         _track: &mut Track<R>,
-        _code: &[u8],
+        _code_size: usize,
     ) -> Result<(), ModuleError> {
         Ok(())
     }
@@ -262,6 +333,7 @@ impl<R: FeeReserve> Module<R> for ExecutionTraceModule {
         _heap: &mut Heap,
         _track: &mut Track<R>,
         _vault_id: VaultId,
+        _resource_address: ResourceAddress,
         fee: Resource,
         _contingent: bool,
     ) -> Result<Resource, ModuleError> {
@@ -285,9 +357,14 @@ impl ExecutionTraceModule {
             current_instruction_index: None,
             traced_sys_call_inputs_stack: vec![],
             sys_call_traces_stacks: HashMap::new(),
+            actors: vec![],
         }
     }
 
+    fn current_actor(&self) -> &ActorSummary {
+        self.actors.last().unwrap_or(&ActorSummary::Transaction)
+    }
+
     fn handle_pre_sys_call(
         &mut self,
         heap: &mut Heap,
@@ -588,7 +665,7 @@ impl ExecutionTraceReceipt {
         to_persist: &mut HashMap<SubstateId, (PersistedSubstate, Option<u32>)>,
         is_commit_success: bool,
     ) -> Self {
-        let mut vault_changes = HashMap::<ComponentId, HashMap<VaultId, Decimal>>::new();
+        let mut vault_changes = BTreeMap::<ComponentId, BTreeMap<VaultId, Decimal>>::new();
         let mut vault_locked_by = HashMap::<VaultId, ComponentId>::new();
         for (actor, vault_id, vault_op) in ops {
             if let REActor::Method(_, resolved_receiver) = actor {
@@ -662,10 +739,7 @@ impl ExecutionTraceReceipt {
         to_persist: &mut HashMap<SubstateId, (PersistedSubstate, Option<u32>)>,
     ) -> ResourceAddress {
         let (substate, _) = to_persist
-            .get(&SubstateId(
-                RENodeId::Vault(vault_id),
-                SubstateOffset::Vault(VaultOffset::Vault),
-            ))
+            .get(&SubstateId::vault(vault_id))
             .expect("Failed to find the vault substate");
         substate.vault().resource_address()
     }
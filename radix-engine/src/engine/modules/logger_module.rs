@@ -1,11 +1,22 @@
 use crate::engine::*;
 use crate::fee::FeeReserve;
+use crate::types::*;
+use utils::ContextualDisplay;
 
-pub struct LoggerModule {}
+pub struct LoggerModule {
+    /// Used to display global addresses in Bech32, if the network is known
+    encoder: Option<Bech32Encoder>,
+}
 
 impl LoggerModule {
     pub fn new() -> Self {
-        Self {}
+        Self { encoder: None }
+    }
+
+    pub fn with_encoder(encoder: Bech32Encoder) -> Self {
+        Self {
+            encoder: Some(encoder),
+        }
     }
 }
 
@@ -34,10 +45,18 @@ impl<R: FeeReserve> Module<R> for LoggerModule {
                 log!(call_frame, "Reading owned nodes");
             }
             SysCallInput::BorrowNode { node_id } => {
-                log!(call_frame, "Borrowing node: node_id = {:?}", node_id);
+                log!(
+                    call_frame,
+                    "Borrowing node: node_id = {}",
+                    node_id.display(self.encoder.as_ref())
+                );
             }
             SysCallInput::DropNode { node_id } => {
-                log!(call_frame, "Dropping node: node_id = {:?}", node_id);
+                log!(
+                    call_frame,
+                    "Dropping node: node_id = {}",
+                    node_id.display(self.encoder.as_ref())
+                );
             }
             SysCallInput::CreateNode { node } => {
                 log!(call_frame, "Creating node: node = {:?}", node);
@@ -49,9 +68,9 @@ impl<R: FeeReserve> Module<R> for LoggerModule {
             } => {
                 log!(
                     call_frame,
-                    "Lock substate: node_id = {:?} offset = {:?} flags = {:?}",
-                    node_id,
-                    offset,
+                    "Lock substate: node_id = {} offset = {} flags = {:?}",
+                    node_id.display(self.encoder.as_ref()),
+                    offset.display(self.encoder.as_ref()),
                     flags
                 );
             }
@@ -71,10 +90,14 @@ impl<R: FeeReserve> Module<R> for LoggerModule {
             SysCallInput::TakeSubstate { substate_id } => {
                 log!(
                     call_frame,
-                    "Taking substate: substate_id = {:?}",
-                    substate_id
+                    "Taking substate: node_id = {} offset = {}",
+                    substate_id.0.display(self.encoder.as_ref()),
+                    substate_id.1.display(self.encoder.as_ref())
                 );
             }
+            SysCallInput::ReadEpoch => {
+                log!(call_frame, "Reading epoch");
+            }
             SysCallInput::ReadTransactionHash => {
                 log!(call_frame, "Reading transaction hash");
             }
@@ -84,6 +107,14 @@ impl<R: FeeReserve> Module<R> for LoggerModule {
             SysCallInput::GenerateUuid => {
                 log!(call_frame, "Generating UUID");
             }
+            SysCallInput::Hash { algorithm, size } => {
+                log!(
+                    call_frame,
+                    "Hashing: algorithm = {:?}, size = {}",
+                    algorithm,
+                    size
+                );
+            }
             SysCallInput::EmitLog { .. } => {
                 log!(call_frame, "Emitting application log");
             }
@@ -120,9 +151,11 @@ impl<R: FeeReserve> Module<R> for LoggerModule {
             SysCallOutput::GetRef { .. } => {}
             SysCallOutput::GetRefMut { .. } => {}
             SysCallOutput::DropLock { .. } => {}
+            SysCallOutput::ReadEpoch { .. } => {}
             SysCallOutput::ReadTransactionHash { .. } => {}
             SysCallOutput::ReadBlob { .. } => {}
             SysCallOutput::GenerateUuid { .. } => {}
+            SysCallOutput::Hash { .. } => {}
             SysCallOutput::EmitLog { .. } => {}
             SysCallOutput::EmitEvent { .. } => {}
         }
@@ -0,0 +1,93 @@
+use crate::engine::*;
+use crate::fee::FeeReserve;
+use crate::types::*;
+use radix_engine_interface::api::types::RENodeId;
+
+/// Counters of the work the engine did for a transaction, for performance investigations.
+///
+/// All counts only depend on the transaction and the state it executes against.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct EngineMetrics {
+    pub wasm_instantiations: u32,
+    pub native_invocations: u32,
+    pub scrypto_invocations: u32,
+    /// Substates loaded from the substate store
+    pub substate_reads: u32,
+    /// The encoded size of the substates loaded from the substate store
+    pub substate_read_bytes: u64,
+    /// Substates written to the substate store on commit
+    pub substate_writes: u32,
+    /// The encoded size of the substates written to the substate store on commit
+    pub substate_write_bytes: u64,
+    pub lock_acquisitions: u32,
+    pub node_creations: u32,
+}
+
+/// Counts kernel events into the [`EngineMetrics`] of the track, if it collects them.
+///
+/// Substate reads and writes are counted by the track itself.
+#[derive(Default)]
+pub struct MetricsModule {}
+
+impl MetricsModule {
+    fn record<R: FeeReserve, F: FnOnce(&mut EngineMetrics)>(track: &mut Track<R>, f: F) {
+        if let Some(metrics) = track.engine_metrics.as_mut() {
+            f(metrics)
+        }
+    }
+}
+
+impl<R: FeeReserve> Module<R> for MetricsModule {
+    fn post_sys_call(
+        &mut self,
+        _call_frame: &CallFrame,
+        _heap: &mut Heap,
+        track: &mut Track<R>,
+        output: SysCallOutput,
+    ) -> Result<(), ModuleError> {
+        if let SysCallOutput::LockSubstate { .. } = output {
+            Self::record(track, |m| m.lock_acquisitions += 1);
+        }
+        Ok(())
+    }
+
+    fn pre_execute_invocation(
+        &mut self,
+        actor: &REActor,
+        _call_frame_update: &CallFrameUpdate,
+        _call_frame: &CallFrame,
+        _heap: &mut Heap,
+        track: &mut Track<R>,
+    ) -> Result<(), ModuleError> {
+        Self::record(track, |m| match actor {
+            REActor::Function(ResolvedFunction::Scrypto { .. })
+            | REActor::Method(ResolvedMethod::Scrypto { .. }, ..) => m.scrypto_invocations += 1,
+            REActor::Function(ResolvedFunction::Native(..))
+            | REActor::Method(ResolvedMethod::Native(..), ..) => m.native_invocations += 1,
+        });
+        Ok(())
+    }
+
+    fn on_wasm_instantiation(
+        &mut self,
+        _call_frame: &CallFrame,
+        _heap: &mut Heap,
+        track: &mut Track<R>,
+        _code_size: usize,
+    ) -> Result<(), ModuleError> {
+        Self::record(track, |m| m.wasm_instantiations += 1);
+        Ok(())
+    }
+
+    fn on_new_node(
+        &mut self,
+        _call_frame: &CallFrame,
+        _heap: &mut Heap,
+        track: &mut Track<R>,
+        _node_id: RENodeId,
+    ) -> Result<(), ModuleError> {
+        Self::record(track, |m| m.node_creations += 1);
+        Ok(())
+    }
+}
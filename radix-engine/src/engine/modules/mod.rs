@@ -2,13 +2,17 @@ mod auth_module;
 mod costing_module;
 mod execution_trace;
 mod logger_module;
+mod metrics_module;
 mod module;
 pub mod node_move_module;
 mod royalty_module;
+mod storage_fee_module;
 
 pub use auth_module::*;
 pub use costing_module::*;
 pub use execution_trace::*;
 pub use logger_module::*;
+pub use metrics_module::*;
 pub use module::*;
 pub use royalty_module::*;
+pub use storage_fee_module::*;
@@ -42,11 +42,16 @@ pub enum SysCallInput<'a> {
     TakeSubstate {
         substate_id: &'a SubstateId,
     },
+    ReadEpoch,
     ReadTransactionHash,
     ReadBlob {
         blob_hash: &'a Hash,
     },
     GenerateUuid,
+    Hash {
+        algorithm: &'a HashAlgorithm,
+        size: usize,
+    },
     EmitLog {
         level: &'a Level,
         message: &'a String,
@@ -67,9 +72,11 @@ pub enum SysCallOutput<'a> {
     GetRef { lock_handle: LockHandle },
     GetRefMut,
     DropLock,
+    ReadEpoch { epoch: u64 },
     ReadTransactionHash { hash: &'a Hash },
     ReadBlob { blob: &'a [u8] },
     GenerateUuid { uuid: u128 },
+    Hash { hash: &'a Hash },
     EmitLog,
     EmitEvent,
 }
@@ -122,7 +129,7 @@ pub trait Module<R: FeeReserve> {
         _call_frame: &CallFrame,
         _heap: &mut Heap,
         _track: &mut Track<R>,
-        _code: &[u8],
+        _code_size: usize,
     ) -> Result<(), ModuleError> {
         Ok(())
     }
@@ -137,12 +144,33 @@ pub trait Module<R: FeeReserve> {
         Ok(())
     }
 
+    fn on_auth_check(
+        &mut self,
+        _call_frame: &CallFrame,
+        _heap: &mut Heap,
+        _track: &mut Track<R>,
+        _proofs_examined: u32,
+    ) -> Result<(), ModuleError> {
+        Ok(())
+    }
+
+    fn on_new_node(
+        &mut self,
+        _call_frame: &CallFrame,
+        _heap: &mut Heap,
+        _track: &mut Track<R>,
+        _node_id: RENodeId,
+    ) -> Result<(), ModuleError> {
+        Ok(())
+    }
+
     fn on_lock_fee(
         &mut self,
         _call_frame: &CallFrame,
         _eap: &mut Heap,
         _rack: &mut Track<R>,
         _ault_id: VaultId,
+        _resource_address: ResourceAddress,
         fee: Resource,
         _ontingent: bool,
     ) -> Result<Resource, ModuleError> {
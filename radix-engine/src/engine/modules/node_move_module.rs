@@ -1,4 +1,6 @@
-use crate::engine::{CallFrameUpdate, LockFlags, ModuleError, REActor, RuntimeError, SystemApi};
+use crate::engine::{
+    CallFrameUpdate, LockFlags, ModuleError, REActor, ResolvedMethod, RuntimeError, SystemApi,
+};
 use crate::types::*;
 use radix_engine_interface::api::types::{BucketOffset, ProofOffset, RENodeId, SubstateOffset};
 
@@ -75,6 +77,8 @@ impl NodeMoveModule {
             | RENodeId::Worktop
             | RENodeId::EpochManager(..)
             | RENodeId::Clock(..)
+            | RENodeId::Burner(..)
+            | RENodeId::TimeLockedVault(..)
             | RENodeId::Global(..) => Err(RuntimeError::ModuleError(ModuleError::NodeMoveError(
                 NodeMoveError::CantMoveDownstream(node_id),
             ))),
@@ -115,6 +119,8 @@ impl NodeMoveModule {
             | RENodeId::Worktop
             | RENodeId::EpochManager(..)
             | RENodeId::Clock(..)
+            | RENodeId::Burner(..)
+            | RENodeId::TimeLockedVault(..)
             | RENodeId::Global(..) => Err(RuntimeError::ModuleError(ModuleError::NodeMoveError(
                 NodeMoveError::CantMoveUpstream(node_id),
             ))),
@@ -130,6 +136,20 @@ impl NodeMoveModule {
             Self::prepare_move_downstream(*node_id, actor, system_api)?;
         }
 
+        // The worktop may only be referenced by its own methods
+        if call_frame_update
+            .node_refs_to_copy
+            .contains(&RENodeId::Worktop)
+            && !matches!(
+                actor,
+                REActor::Method(ResolvedMethod::Native(NativeMethod::Worktop(..)), ..)
+            )
+        {
+            return Err(RuntimeError::ModuleError(ModuleError::NodeMoveError(
+                NodeMoveError::CantMoveDownstream(RENodeId::Worktop),
+            )));
+        }
+
         Ok(())
     }
 
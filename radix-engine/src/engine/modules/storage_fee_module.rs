@@ -0,0 +1,66 @@
+use crate::engine::*;
+use crate::fee::FeeReserve;
+use crate::model::RuntimeSubstate;
+use crate::types::*;
+use radix_engine_interface::api::types::{GlobalOffset, RENodeId, SubstateOffset};
+
+/// The fee units charged per byte of (estimated) node state
+pub const STORAGE_FEE_PER_BYTE: u32 = 10;
+
+/// Charges fee units for every node created, in proportion to the size of its state.
+#[derive(Default)]
+pub struct StorageFeeModule {}
+
+impl StorageFeeModule {
+    /// Estimates the size of a newly created node by encoding its persistable substates.
+    pub fn size_estimate<R: FeeReserve>(
+        heap: &Heap,
+        track: &mut Track<R>,
+        node_id: RENodeId,
+    ) -> usize {
+        if let Some(node) = heap.get_node(&node_id) {
+            node.substates
+                .values()
+                .map(|substate| match substate {
+                    // Transient substates are never stored
+                    RuntimeSubstate::AuthZoneStack(..)
+                    | RuntimeSubstate::Bucket(..)
+                    | RuntimeSubstate::Proof(..)
+                    | RuntimeSubstate::Worktop(..)
+                    | RuntimeSubstate::FeeReserve(..) => 0,
+                    _ => scrypto_encode(&substate.clone_to_persisted())
+                        .map(|encoded| encoded.len())
+                        .unwrap_or_default(),
+                })
+                .sum()
+        } else if let RENodeId::Global(..) = node_id {
+            let substate_ref =
+                track.get_substate(node_id, &SubstateOffset::Global(GlobalOffset::Global));
+            scrypto_encode(substate_ref.global_address())
+                .map(|encoded| encoded.len())
+                .unwrap_or_default()
+        } else {
+            0
+        }
+    }
+}
+
+impl<R: FeeReserve> Module<R> for StorageFeeModule {
+    fn on_new_node(
+        &mut self,
+        _call_frame: &CallFrame,
+        heap: &mut Heap,
+        track: &mut Track<R>,
+        node_id: RENodeId,
+    ) -> Result<(), ModuleError> {
+        let size = Self::size_estimate(heap, track, node_id);
+        let amount = u32::try_from(size)
+            .unwrap_or(u32::MAX)
+            .saturating_mul(STORAGE_FEE_PER_BYTE);
+
+        track
+            .fee_reserve
+            .consume_execution(amount, 1, "storage", false)
+            .map_err(|e| ModuleError::CostingError(CostingError::FeeReserveError(e)))
+    }
+}
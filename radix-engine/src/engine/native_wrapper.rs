@@ -79,6 +79,20 @@ where
                     .invoke(invocation)
                     .map(|a| IndexedScryptoValue::from_typed(&a))
             }
+            NativeFunction::Burner(BurnerFunction::Create) => {
+                let invocation: BurnerCreateInvocation = scrypto_decode(&args)
+                    .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                system_api
+                    .invoke(invocation)
+                    .map(|a| IndexedScryptoValue::from_typed(&a))
+            }
+            NativeFunction::TimeLockedVault(TimeLockedVaultFunction::Create) => {
+                let invocation: TimeLockedVaultCreateInvocation = scrypto_decode(&args)
+                    .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                system_api
+                    .invoke(invocation)
+                    .map(|a| IndexedScryptoValue::from_typed(&a))
+            }
             NativeFunction::TransactionProcessor(TransactionProcessorFunction::Run) => {
                 return Err(RuntimeError::InterpreterError(
                     InterpreterError::InvalidInvocation,
@@ -235,6 +249,13 @@ where
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a))
                 }
+                ProofMethod::IsValid => {
+                    let invocation: ProofIsValidInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
                 ProofMethod::Clone => {
                     let invocation: ProofCloneInvocation = scrypto_decode(&args)
                         .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
@@ -328,6 +349,13 @@ where
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a))
                 }
+                VaultMethod::LockFeeWithCallback => {
+                    let invocation: VaultLockFeeWithCallbackInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
             },
             NativeMethod::AccessRulesChain(component_method) => match component_method {
                 AccessRulesChainMethod::AddAccessCheck => {
@@ -458,6 +486,49 @@ where
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a))
                 }
+                ResourceManagerMethod::GetSummary => {
+                    let invocation: ResourceManagerGetSummaryInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                ResourceManagerMethod::GetHolderVaultCount => {
+                    let invocation: ResourceManagerGetHolderVaultCountInvocation =
+                        scrypto_decode(&args).map_err(|e| {
+                            RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                        })?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                ResourceManagerMethod::GetAmountMinted => {
+                    let invocation: ResourceManagerGetAmountMintedInvocation =
+                        scrypto_decode(&args).map_err(|e| {
+                            RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                        })?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                ResourceManagerMethod::GetAmountBurned => {
+                    let invocation: ResourceManagerGetAmountBurnedInvocation =
+                        scrypto_decode(&args).map_err(|e| {
+                            RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                        })?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                ResourceManagerMethod::CreateSyntheticProof => {
+                    let invocation: ResourceManagerCreateSyntheticProofInvocation =
+                        scrypto_decode(&args).map_err(|e| {
+                            RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                        })?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
                 ResourceManagerMethod::UpdateNonFungibleData => {
                     let invocation: ResourceManagerUpdateNonFungibleDataInvocation =
                         scrypto_decode(&args).map_err(|e| {
@@ -525,6 +596,71 @@ where
                         .map(|a| IndexedScryptoValue::from_typed(&a))
                 }
             },
+            NativeMethod::Burner(burner_method) => match burner_method {
+                BurnerMethod::Deposit => {
+                    let invocation: BurnerDepositInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                BurnerMethod::DepositBatch => {
+                    let invocation: BurnerDepositBatchInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+            },
+            NativeMethod::TimeLockedVault(time_locked_vault_method) => {
+                match time_locked_vault_method {
+                    TimeLockedVaultMethod::Deposit => {
+                        let invocation: TimeLockedVaultDepositInvocation = scrypto_decode(&args)
+                            .map_err(|e| {
+                                RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                            })?;
+                        system_api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a))
+                    }
+                    TimeLockedVaultMethod::Withdraw => {
+                        let invocation: TimeLockedVaultWithdrawInvocation = scrypto_decode(&args)
+                            .map_err(|e| {
+                            RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                        })?;
+                        system_api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a))
+                    }
+                    TimeLockedVaultMethod::ExtendLock => {
+                        let invocation: TimeLockedVaultExtendLockInvocation = scrypto_decode(&args)
+                            .map_err(|e| {
+                                RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                            })?;
+                        system_api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a))
+                    }
+                    TimeLockedVaultMethod::GetUnlockEpoch => {
+                        let invocation: TimeLockedVaultGetUnlockEpochInvocation =
+                            scrypto_decode(&args).map_err(|e| {
+                                RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                            })?;
+                        system_api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a))
+                    }
+                    TimeLockedVaultMethod::GetAmount => {
+                        let invocation: TimeLockedVaultGetAmountInvocation = scrypto_decode(&args)
+                            .map_err(|e| {
+                                RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                            })?;
+                        system_api
+                            .invoke(invocation)
+                            .map(|a| IndexedScryptoValue::from_typed(&a))
+                    }
+                }
+            }
             NativeMethod::Worktop(worktop_method) => match worktop_method {
                 WorktopMethod::TakeNonFungibles => {
                     let invocation: WorktopTakeNonFungiblesInvocation = scrypto_decode(&args)
@@ -572,6 +708,22 @@ where
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a))
                 }
+                WorktopMethod::AssertContainsExactAmount => {
+                    let invocation: WorktopAssertContainsExactAmountInvocation =
+                        scrypto_decode(&args).map_err(|e| {
+                            RuntimeError::KernelError(KernelError::InvalidSborValue(e))
+                        })?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                WorktopMethod::GetAmount => {
+                    let invocation: WorktopGetAmountInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
                 WorktopMethod::TakeAll => {
                     let invocation: WorktopTakeAllInvocation = scrypto_decode(&args)
                         .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
@@ -586,6 +738,13 @@ where
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a))
                 }
+                WorktopMethod::TakeOrDefault => {
+                    let invocation: WorktopTakeOrDefaultInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
             },
             NativeMethod::Component(component_method) => match component_method {
                 ComponentMethod::SetRoyaltyConfig => {
@@ -602,6 +761,20 @@ where
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a))
                 }
+                ComponentMethod::SetAuthMutability => {
+                    let invocation: ComponentSetAuthMutabilityInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
+                ComponentMethod::StateSizeBytes => {
+                    let invocation: ComponentStateSizeBytesInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
             },
             NativeMethod::Package(package_method) => match package_method {
                 PackageMethod::SetRoyaltyConfig => {
@@ -618,6 +791,13 @@ where
                         .invoke(invocation)
                         .map(|a| IndexedScryptoValue::from_typed(&a))
                 }
+                PackageMethod::GetBuildFingerprint => {
+                    let invocation: PackageGetBuildFingerprintInvocation = scrypto_decode(&args)
+                        .map_err(|e| RuntimeError::KernelError(KernelError::InvalidSborValue(e)))?;
+                    system_api
+                        .invoke(invocation)
+                        .map(|a| IndexedScryptoValue::from_typed(&a))
+                }
             },
         },
     }
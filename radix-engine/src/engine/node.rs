@@ -1,9 +1,9 @@
 use crate::model::*;
 use crate::types::*;
 use radix_engine_interface::api::types::{
-    AuthZoneStackOffset, BucketOffset, ComponentOffset, EpochManagerOffset, GlobalOffset,
-    KeyValueStoreOffset, NonFungibleStoreOffset, PackageOffset, ProofOffset, ResourceManagerOffset,
-    SubstateOffset, VaultOffset, WorktopOffset,
+    AuthZoneStackOffset, BucketOffset, BurnerOffset, ComponentOffset, EpochManagerOffset,
+    GlobalOffset, KeyValueStoreOffset, NonFungibleStoreOffset, PackageOffset, ProofOffset,
+    ResourceManagerOffset, SubstateOffset, TimeLockedVaultOffset, VaultOffset, WorktopOffset,
 };
 
 #[derive(Debug)]
@@ -43,6 +43,8 @@ pub enum RENode {
         CurrentTimeRoundedToMinutesSubstate,
         AccessRulesChainSubstate,
     ),
+    Burner(BurnerSubstate),
+    TimeLockedVault(TimeLockedVaultSubstate, AccessRulesChainSubstate),
 }
 
 impl RENode {
@@ -203,6 +205,19 @@ impl RENode {
                     access_rules_substate.into(),
                 );
             }
+            RENode::Burner(burner) => {
+                substates.insert(SubstateOffset::Burner(BurnerOffset::Burner), burner.into());
+            }
+            RENode::TimeLockedVault(time_locked_vault, access_rules_substate) => {
+                substates.insert(
+                    SubstateOffset::TimeLockedVault(TimeLockedVaultOffset::TimeLockedVault),
+                    time_locked_vault.into(),
+                );
+                substates.insert(
+                    SubstateOffset::AccessRulesChain(AccessRulesChainOffset::AccessRulesChain),
+                    access_rules_substate.into(),
+                );
+            }
             RENode::FeeReserve(fee_reserve) => {
                 substates.insert(
                     SubstateOffset::FeeReserve(FeeReserveOffset::FeeReserve),
@@ -6,8 +6,8 @@ use crate::model::GlobalAddressSubstate;
 use radix_engine_interface::api::types::{
     AccessRulesChainOffset, AuthZoneStackOffset, BucketOffset, ComponentOffset, GlobalOffset,
     KeyValueStoreOffset, NativeFunction, NativeMethod, PackageOffset, ProofOffset, RENodeId,
-    ResourceManagerOffset, SubstateOffset, TransactionProcessorFunction, VaultOffset,
-    WorktopOffset,
+    ResourceManagerOffset, SubstateOffset, TimeLockedVaultOffset, TransactionProcessorFunction,
+    VaultOffset, WorktopOffset,
 };
 
 pub struct VisibilityProperties;
@@ -42,7 +42,8 @@ impl VisibilityProperties {
                 REActor::Method(ResolvedMethod::Native(NativeMethod::Bucket(..)), ..)
                 | REActor::Method(ResolvedMethod::Native(NativeMethod::Worktop(..)), ..)
                 | REActor::Method(ResolvedMethod::Native(NativeMethod::ResourceManager(..)), ..)
-                | REActor::Method(ResolvedMethod::Native(NativeMethod::Vault(..)), ..) => true,
+                | REActor::Method(ResolvedMethod::Native(NativeMethod::Vault(..)), ..)
+                | REActor::Method(ResolvedMethod::Native(NativeMethod::Burner(..)), ..) => true,
                 _ => false,
             },
             RENodeId::Proof(..) => match actor {
@@ -270,12 +271,23 @@ impl SubstateProperties {
                     ))),
                 }
             }
+            SubstateOffset::TimeLockedVault(TimeLockedVaultOffset::TimeLockedVault) => {
+                match node_id {
+                    RENodeId::Vault(..) => Ok(()),
+                    _ => Err(RuntimeError::KernelError(KernelError::InvalidOwnership(
+                        offset.clone(),
+                        node_id,
+                    ))),
+                }
+            }
             SubstateOffset::Global(GlobalOffset::Global) => match node_id {
                 RENodeId::Component(..)
                 | RENodeId::Package(..)
                 | RENodeId::ResourceManager(..)
                 | RENodeId::EpochManager(..)
-                | RENodeId::Clock(..) => Ok(()),
+                | RENodeId::Clock(..)
+                | RENodeId::Burner(..)
+                | RENodeId::TimeLockedVault(..) => Ok(()),
                 _ => Err(RuntimeError::KernelError(KernelError::InvalidOwnership(
                     offset.clone(),
                     node_id,
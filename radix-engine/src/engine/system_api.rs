@@ -43,16 +43,36 @@ pub trait SystemApi {
 
     fn consume_cost_units(&mut self, units: u32) -> Result<(), RuntimeError>;
 
+    /// Charges for an authorization check which examined the given number of proofs
+    fn consume_auth_check_cost(&mut self, proofs_examined: u32) -> Result<(), RuntimeError>;
+
+    /// Locks XRD taken from the given vault to pay for the transaction fee
     fn lock_fee(
         &mut self,
         vault_id: VaultId,
+        resource_address: ResourceAddress,
         fee: Resource,
         contingent: bool,
     ) -> Result<Resource, RuntimeError>;
 
+    /// Registers a function to be called with the XRD charged to the vault at the end of
+    /// the transaction
+    fn register_fee_callback(
+        &mut self,
+        vault_id: VaultId,
+        callback: ScryptoFunctionIdent,
+    ) -> Result<(), RuntimeError>;
+
+    /// Retrieves the registered fee callbacks, along with the XRD charged so far to each
+    /// callback's vault
+    fn fee_callbacks(&self) -> Vec<(ScryptoFunctionIdent, Decimal)>;
+
     /// Retrieve the running actor for the current frame
     fn get_actor(&self) -> &REActor;
 
+    /// Retrieve the running actor of the parent frame, if any
+    fn get_caller(&self) -> Option<&REActor>;
+
     /// Retrieves all nodes referenceable by the current frame
     fn get_visible_node_ids(&mut self) -> Result<Vec<RENodeId>, RuntimeError>;
 
@@ -61,6 +81,10 @@ pub trait SystemApi {
         node_id: RENodeId,
     ) -> Result<RENodeVisibilityOrigin, RuntimeError>;
 
+    /// Checks whether an RENode is still alive, either on the heap or loaded into the track.
+    /// Unlike other node operations this does not require the node to be visible.
+    fn node_exists(&mut self, node_id: RENodeId) -> Result<bool, RuntimeError>;
+
     /// Removes an RENode and all of it's children from the Heap
     fn drop_node(&mut self, node_id: RENodeId) -> Result<HeapRENode, RuntimeError>;
 
@@ -90,18 +114,38 @@ pub trait SystemApi {
     /// Get a mutable reference to a locked substate
     fn get_ref_mut(&mut self, lock_handle: LockHandle) -> Result<SubstateRefMut, RuntimeError>;
 
+    /// Reads the current epoch straight from the epoch manager substate, without invoking the
+    /// epoch manager
+    fn read_epoch(&mut self) -> Result<u64, RuntimeError>;
+
     fn read_transaction_hash(&mut self) -> Result<Hash, RuntimeError>;
 
     fn read_blob(&mut self, blob_hash: &Hash) -> Result<&[u8], RuntimeError>;
 
     fn generate_uuid(&mut self) -> Result<u128, RuntimeError>;
 
+    /// Hashes data of at most [`MAX_HASH_INPUT_SIZE`] bytes
+    fn hash(&mut self, algorithm: HashAlgorithm, data: &[u8]) -> Result<Hash, RuntimeError>;
+
     fn emit_event(&mut self, event: Event) -> Result<(), RuntimeError>;
+
+    /// Sets a metadata entry on a native node which owns a metadata substate, without invoking
+    /// the metadata native methods
+    fn set_metadata(
+        &mut self,
+        node_id: RENodeId,
+        key: String,
+        value: String,
+    ) -> Result<(), RuntimeError>;
 }
 
 // TODO: Clean this up
 pub trait ResolverApi<W: WasmEngine> {
     fn deref(&mut self, node_id: RENodeId) -> Result<Option<(RENodeId, LockHandle)>, RuntimeError>;
-    fn vm(&mut self) -> &ScryptoInterpreter<W>;
-    fn on_wasm_instantiation(&mut self, code: &[u8]) -> Result<(), RuntimeError>;
+    /// Instantiates the code of the package locked by the given handle, borrowing it from the
+    /// package substate rather than copying it out
+    fn instantiate_wasm(
+        &mut self,
+        package_lock_handle: LockHandle,
+    ) -> Result<W::WasmInstance, RuntimeError>;
 }
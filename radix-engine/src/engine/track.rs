@@ -1,17 +1,17 @@
-use indexmap::IndexMap;
 use radix_engine_interface::api::types::{
     GlobalAddress, GlobalOffset, KeyValueStoreOffset, Level, NonFungibleStoreOffset, RENodeId,
     SubstateId, SubstateOffset, VaultId, VaultOffset,
 };
 use radix_engine_interface::crypto::hash;
 use radix_engine_interface::model::*;
+use sbor::rust::collections::IndexMap;
 use transaction::model::Executable;
 
 use crate::engine::*;
 use crate::fee::FeeReserveError;
 use crate::fee::FeeSummary;
 use crate::fee::FeeTable;
-use crate::fee::{FeeReserve, RoyaltyReceiver};
+use crate::fee::{CostingActor, FeeReserve, RoyaltyReceiver};
 use crate::ledger::*;
 use crate::model::Resource;
 use crate::model::RuntimeSubstate;
@@ -39,13 +39,13 @@ impl LockState {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ExistingMetaState {
     Loaded,
     Updated(Option<PersistedSubstate>),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SubstateMetaState {
     New,
     Existing {
@@ -61,6 +61,14 @@ pub struct LoadedSubstate {
     metastate: SubstateMetaState,
 }
 
+/// The substates and new global addresses of a track at some point during execution, which the
+/// track can later be reverted to.
+#[derive(Debug)]
+pub struct TrackCheckpoint {
+    loaded_substates: BTreeMap<SubstateId, (PersistedSubstate, SubstateMetaState)>,
+    new_global_addresses: usize,
+}
+
 /// Transaction-wide states and side effects
 pub struct Track<'s, R: FeeReserve> {
     application_logs: Vec<(Level, String)>,
@@ -70,7 +78,13 @@ pub struct Track<'s, R: FeeReserve> {
     pub fee_reserve: R,
     pub fee_table: FeeTable,
     pub vault_ops: Vec<(REActor, VaultId, VaultOp)>,
+    /// The actor and manifest instruction index which created each new global entity
+    pub new_entity_creators: HashMap<GlobalAddress, (ActorSummary, Option<usize>)>,
     pub events: Vec<TrackedEvent>,
+    /// Execution cost units attributed to actors during execution
+    pub actor_cost_units: HashMap<CostingActor, u32>,
+    /// Engine metrics, only collected when set
+    pub engine_metrics: Option<EngineMetrics>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -89,6 +103,7 @@ pub struct TrackReceipt {
     pub application_logs: Vec<(Level, String)>,
     pub result: TransactionResult,
     pub events: Vec<TrackedEvent>,
+    pub engine_metrics: Option<EngineMetrics>,
 }
 
 pub struct PreExecutionError {
@@ -110,7 +125,10 @@ impl<'s, R: FeeReserve> Track<'s, R> {
             fee_reserve,
             fee_table,
             vault_ops: Vec::new(),
+            new_entity_creators: HashMap::new(),
             events: Vec::new(),
+            actor_cost_units: HashMap::new(),
+            engine_metrics: None,
         }
     }
 
@@ -125,7 +143,12 @@ impl<'s, R: FeeReserve> Track<'s, R> {
 
     /// Returns a copy of the substate associated with the given address, if exists
     fn load_substate(&mut self, substate_id: &SubstateId) -> Option<OutputValue> {
-        self.substate_store.get_substate(substate_id)
+        let output = self.substate_store.get_substate(substate_id);
+        if let (Some(metrics), Some(output)) = (self.engine_metrics.as_mut(), &output) {
+            metrics.substate_reads += 1;
+            metrics.substate_read_bytes += encoded_size(&output.substate);
+        }
+        output
     }
 
     // TODO: to read/write a value owned by track requires three coordinated steps:
@@ -253,6 +276,96 @@ impl<'s, R: FeeReserve> Track<'s, R> {
         Ok(())
     }
 
+    /// Returns the lock state of a loaded substate, or `None` if it has not been loaded.
+    pub fn get_lock_state(&self, substate_id: &SubstateId) -> Option<LockState> {
+        self.loaded_substates
+            .get(substate_id)
+            .map(|loaded_substate| loaded_substate.lock_state)
+    }
+
+    /// Returns whether any substate of the given node has been loaded.
+    pub fn is_node_loaded(&self, node_id: RENodeId) -> bool {
+        self.loaded_substates
+            .keys()
+            .any(|SubstateId(loaded_node_id, _)| *loaded_node_id == node_id)
+    }
+
+    /// Captures the current substates and new global addresses, to be reverted to later within
+    /// the same transaction.
+    pub fn checkpoint(&self) -> TrackCheckpoint {
+        TrackCheckpoint {
+            loaded_substates: self
+                .loaded_substates
+                .iter()
+                .map(|(substate_id, loaded_substate)| {
+                    (
+                        substate_id.clone(),
+                        (
+                            loaded_substate.substate.clone_to_persisted(),
+                            loaded_substate.metastate.clone(),
+                        ),
+                    )
+                })
+                .collect(),
+            new_global_addresses: self.new_global_addresses.len(),
+        }
+    }
+
+    /// Discards all substate writes and new global addresses made since the checkpoint.
+    ///
+    /// Force-written substates (i.e. fee payments) are kept, as they are committed even if the
+    /// transaction fails. Logs, events and fee consumption are not affected either.
+    pub fn revert_to(&mut self, checkpoint: TrackCheckpoint) -> Result<(), TrackError> {
+        // A substate can't be reverted from under an outstanding lock
+        for (substate_id, loaded_substate) in &self.loaded_substates {
+            let locked = match loaded_substate.lock_state {
+                LockState::Write => true,
+                LockState::Read(n) => {
+                    n > 0 && !checkpoint.loaded_substates.contains_key(substate_id)
+                }
+            };
+            if locked {
+                return Err(TrackError::SubstateLocked(
+                    substate_id.clone(),
+                    loaded_substate.lock_state,
+                ));
+            }
+        }
+
+        self.loaded_substates
+            .retain(|substate_id, loaded_substate| {
+                checkpoint.loaded_substates.contains_key(substate_id)
+                    || Self::is_force_written(&loaded_substate.metastate)
+            });
+        for (substate_id, (substate, metastate)) in checkpoint.loaded_substates {
+            let loaded_substate = self
+                .loaded_substates
+                .get_mut(&substate_id)
+                .expect("Loaded substates are never unloaded");
+            if Self::is_force_written(&loaded_substate.metastate)
+                && loaded_substate.metastate != metastate
+            {
+                continue;
+            }
+            loaded_substate.substate = substate.to_runtime();
+            loaded_substate.metastate = metastate;
+        }
+        self.new_global_addresses
+            .truncate(checkpoint.new_global_addresses);
+
+        Ok(())
+    }
+
+    fn is_force_written(metastate: &SubstateMetaState) -> bool {
+        matches!(
+            metastate,
+            SubstateMetaState::Existing {
+                state: ExistingMetaState::Updated(Some(..)),
+                ..
+            }
+        )
+    }
+
     pub fn get_substate(&mut self, node_id: RENodeId, offset: &SubstateOffset) -> SubstateRef {
         let runtime_substate = match (node_id, offset) {
             (
@@ -336,7 +449,7 @@ impl<'s, R: FeeReserve> Track<'s, R> {
                     let output = self.load_substate(&substate_id);
                     let (substate, version) = output
                         .map(|o| (o.substate.to_runtime(), o.version))
-                        .unwrap_or((RuntimeSubstate::NonFungible(NonFungibleSubstate(None)), 0));
+                        .unwrap_or((RuntimeSubstate::NonFungible(NonFungibleSubstate::empty()), 0));
 
                     self.loaded_substates.insert(
                         substate_id.clone(),
@@ -401,7 +514,7 @@ impl<'s, R: FeeReserve> Track<'s, R> {
                     let output = self.load_substate(&substate_id);
                     let (substate, version) = output
                         .map(|o| (o.substate.to_runtime(), o.version))
-                        .unwrap_or((RuntimeSubstate::NonFungible(NonFungibleSubstate(None)), 0));
+                        .unwrap_or((RuntimeSubstate::NonFungible(NonFungibleSubstate::empty()), 0));
 
                     self.loaded_substates.insert(
                         substate_id.clone(),
@@ -533,6 +646,8 @@ impl<'s, R: FeeReserve> Track<'s, R> {
     pub fn finalize(self, invoke_result: InvokeResult) -> TrackReceipt {
         // Close fee reserve
         let mut fee_summary = self.fee_reserve.finalize();
+        fee_summary.actor_cost_unit_breakdown =
+            actor_cost_unit_breakdown(self.actor_cost_units, &fee_summary);
 
         let result = match check_for_rejection(invoke_result, &fee_summary) {
             Ok(invoke_result) => {
@@ -541,6 +656,7 @@ impl<'s, R: FeeReserve> Track<'s, R> {
                     new_global_addresses: self.new_global_addresses,
                     loaded_substates: self.loaded_substates,
                     vault_ops: self.vault_ops,
+                    new_entity_creators: self.new_entity_creators,
                 };
                 finalizing_track.calculate_commit_result(invoke_result, &mut fee_summary)
             }
@@ -549,15 +665,51 @@ impl<'s, R: FeeReserve> Track<'s, R> {
             }),
         };
 
+        let engine_metrics = self.engine_metrics.map(|mut metrics| {
+            if let TransactionResult::Commit(commit) = &result {
+                for output in commit.state_updates.up_substates.values() {
+                    metrics.substate_writes += 1;
+                    metrics.substate_write_bytes += encoded_size(&output.substate);
+                }
+            }
+            metrics
+        });
+
         TrackReceipt {
             fee_summary,
             application_logs: self.application_logs,
             result,
             events: self.events,
+            engine_metrics,
         }
     }
 }
 
+fn encoded_size(substate: &PersistedSubstate) -> u64 {
+    scrypto_encode(substate)
+        .map(|encoded| encoded.len() as u64)
+        .unwrap_or_default()
+}
+
+/// Orders the attributed cost units by consumption, attributing the execution costs charged
+/// outside of any invocation (e.g. transaction payload and signatures) to the transaction.
+fn actor_cost_unit_breakdown(
+    mut actor_cost_units: HashMap<CostingActor, u32>,
+    fee_summary: &FeeSummary,
+) -> Vec<(CostingActor, u32)> {
+    let total: u32 = fee_summary.execution_cost_unit_breakdown.values().sum();
+    let attributed: u32 = actor_cost_units.values().sum();
+    if total > attributed {
+        *actor_cost_units
+            .entry(CostingActor::Transaction)
+            .or_default() += total - attributed;
+    }
+
+    let mut breakdown: Vec<(CostingActor, u32)> = actor_cost_units.into_iter().collect();
+    breakdown.sort_by(|(a, x), (b, y)| y.cmp(x).then_with(|| a.cmp(b)));
+    breakdown
+}
+
 fn check_for_rejection(
     invoke_result: InvokeResult,
     fee_summary: &FeeSummary,
@@ -594,7 +746,7 @@ fn check_for_rejection(
     if !fee_summary.loan_fully_repaid() {
         return Err(match invoke_result {
             Ok(..) => RejectionError::SuccessButFeeLoanNotRepaid,
-            Err(error) => RejectionError::ErrorBeforeFeeLoanRepaid(error),
+            Err(error) => RejectionError::ErrorBeforeFeeLoanRepaid(error.cause().clone()),
         });
     }
 
@@ -607,6 +759,7 @@ struct FinalizingTrack<'s> {
     new_global_addresses: Vec<GlobalAddress>,
     loaded_substates: BTreeMap<SubstateId, LoadedSubstate>,
     vault_ops: Vec<(REActor, VaultId, VaultOp)>,
+    new_entity_creators: HashMap<GlobalAddress, (ActorSummary, Option<usize>)>,
 }
 
 impl<'s> FinalizingTrack<'s> {
@@ -755,8 +908,9 @@ impl<'s> FinalizingTrack<'s> {
                 Err(error) => TransactionOutcome::Failure(error),
             },
             state_updates: Self::generate_diff(self.substate_store, to_persist),
-            entity_changes: EntityChanges::new(new_global_addresses),
+            entity_changes: EntityChanges::new(new_global_addresses, self.new_entity_creators),
             resource_changes: execution_trace_receipt.resource_changes,
+            state_root_hash: None,
         })
     }
 
@@ -766,6 +920,9 @@ impl<'s> FinalizingTrack<'s> {
     ) -> StateDiff {
         let mut diff = StateDiff::new();
 
+        // Iterate in substate id order, so that `down_substates` is deterministic
+        let to_persist: BTreeMap<SubstateId, (PersistedSubstate, Option<u32>)> =
+            to_persist.into_iter().collect();
         for (substate_id, (substate, ..)) in to_persist {
             let next_version = if let Some(existing_output_id) =
                 Self::get_substate_output_id(substate_store, &substate_id)
@@ -799,3 +956,123 @@ impl<'s> FinalizingTrack<'s> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fee::SystemLoanFeeReserve;
+    use crate::ledger::TypedInMemorySubstateStore;
+    use crate::model::{GlobalAddressSubstate, MetadataSubstate};
+    use radix_engine_interface::api::types::MetadataOffset;
+
+    const COMPONENT_ID: ComponentId = [1u8; 36];
+
+    fn metadata_substate_id() -> SubstateId {
+        SubstateId(
+            RENodeId::Component(COMPONENT_ID),
+            SubstateOffset::Metadata(MetadataOffset::Metadata),
+        )
+    }
+
+    fn new_store_with_metadata(value: &str) -> TypedInMemorySubstateStore {
+        let mut store = TypedInMemorySubstateStore::new();
+        let mut metadata = BTreeMap::new();
+        metadata.insert("name".to_owned(), value.to_owned());
+        store.put_substate(
+            metadata_substate_id(),
+            OutputValue {
+                substate: PersistedSubstate::Metadata(MetadataSubstate { metadata }),
+                version: 0,
+            },
+        );
+        store
+    }
+
+    fn write_metadata(track: &mut Track<SystemLoanFeeReserve>, value: &str) {
+        let substate_id = metadata_substate_id();
+        track
+            .acquire_lock(substate_id.clone(), LockFlags::MUTABLE)
+            .unwrap();
+        track
+            .get_substate_mut(substate_id.0, &substate_id.1)
+            .metadata()
+            .metadata
+            .insert("name".to_owned(), value.to_owned());
+        track.release_lock(substate_id, false).unwrap();
+    }
+
+    fn committed_state(track: Track<SystemLoanFeeReserve>) -> StateDiff {
+        match track.finalize(Ok(vec![])).result {
+            TransactionResult::Commit(commit) => commit.state_updates,
+            TransactionResult::Reject(reject) => panic!("Rejected: {:?}", reject),
+        }
+    }
+
+    fn committed_metadata_value(state_updates: &StateDiff) -> Option<String> {
+        state_updates
+            .up_substates
+            .get(&metadata_substate_id())
+            .map(|output| match &output.substate {
+                PersistedSubstate::Metadata(metadata) => metadata.metadata["name"].clone(),
+                _ => panic!("Not metadata"),
+            })
+    }
+
+    #[test]
+    fn writes_after_checkpoint_should_not_be_committed_when_reverted() {
+        let store = new_store_with_metadata("original");
+        let mut track = Track::new(&store, SystemLoanFeeReserve::no_fee(), FeeTable::new());
+        write_metadata(&mut track, "before");
+
+        let checkpoint = track.checkpoint();
+        write_metadata(&mut track, "after");
+        track.revert_to(checkpoint).unwrap();
+
+        let state_updates = committed_state(track);
+        assert_eq!(
+            committed_metadata_value(&state_updates),
+            Some("before".to_owned())
+        );
+    }
+
+    #[test]
+    fn substates_loaded_after_checkpoint_should_not_be_committed_when_reverted() {
+        let store = new_store_with_metadata("original");
+        let mut track = Track::new(&store, SystemLoanFeeReserve::no_fee(), FeeTable::new());
+
+        let checkpoint = track.checkpoint();
+        write_metadata(&mut track, "after");
+        let global_address = GlobalAddress::Component(ComponentAddress::Normal([2u8; 26]));
+        track.insert_substate(
+            SubstateId(
+                RENodeId::Global(global_address),
+                SubstateOffset::Global(GlobalOffset::Global),
+            ),
+            RuntimeSubstate::Global(GlobalAddressSubstate::Component(COMPONENT_ID)),
+        );
+        track.revert_to(checkpoint).unwrap();
+
+        let state_updates = committed_state(track);
+        assert!(state_updates.up_substates.is_empty());
+        assert!(state_updates.down_substates.is_empty());
+    }
+
+    #[test]
+    fn revert_should_fail_while_substate_is_write_locked() {
+        let store = new_store_with_metadata("original");
+        let mut track = Track::new(&store, SystemLoanFeeReserve::no_fee(), FeeTable::new());
+
+        let checkpoint = track.checkpoint();
+        track
+            .acquire_lock(metadata_substate_id(), LockFlags::MUTABLE)
+            .unwrap();
+
+        assert_eq!(
+            track.revert_to(checkpoint),
+            Err(TrackError::SubstateLocked(
+                metadata_substate_id(),
+                LockState::Write
+            ))
+        );
+    }
+}
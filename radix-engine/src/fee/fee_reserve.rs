@@ -1,3 +1,4 @@
+use crate::engine::{BudgetExhausted, GasBudget};
 use crate::fee::FeeSummary;
 use crate::model::Resource;
 use crate::types::*;
@@ -18,6 +19,12 @@ pub enum FeeReserveError {
     NotXrd,
 }
 
+impl From<BudgetExhausted> for FeeReserveError {
+    fn from(_: BudgetExhausted) -> Self {
+        FeeReserveError::LimitExceeded
+    }
+}
+
 pub trait FeeReserve {
     fn consume_royalty(
         &mut self,
@@ -33,13 +40,21 @@ pub trait FeeReserve {
         deferred: bool,
     ) -> Result<(), FeeReserveError>;
 
+    /// Adds XRD locked from the vault of the given resource to the reserve
     fn lock_fee(
         &mut self,
         vault_id: VaultId,
+        resource_address: ResourceAddress,
         fee: Resource,
         contingent: bool,
     ) -> Result<Resource, FeeReserveError>;
 
+    fn register_fee_callback(&mut self, vault_id: VaultId, callback: ScryptoFunctionIdent);
+
+    /// Returns the registered fee callbacks, each with the amount of XRD that would be charged
+    /// to its vault if the transaction were to succeed with the fees consumed so far.
+    fn fee_callbacks(&self) -> Vec<(ScryptoFunctionIdent, Decimal)>;
+
     fn finalize(self) -> FeeSummary;
 }
 
@@ -59,6 +74,8 @@ pub struct SystemLoanFeeReserve {
 
     /// Payments made during the execution of a transaction.
     payments: Vec<(VaultId, Resource, bool)>,
+    /// Functions to call with the amount charged to a vault, at the end of the transaction.
+    fee_callbacks: Vec<(VaultId, ScryptoFunctionIdent)>,
 
     /// The cost unit balance (from system loan)
     loan_balance: u32,
@@ -67,10 +84,8 @@ pub struct SystemLoanFeeReserve {
     /// The amount of XRD owed to the system
     xrd_owed: u128,
 
-    /// The amount of cost units consumed
-    cost_unit_consumed: u32,
-    /// The max number of cost units that can be consumed
-    cost_unit_limit: u32,
+    /// The cost units consumed, against the cost unit limit
+    budget: GasBudget,
     /// At which point the system loan repayment is checked
     check_point: u32,
 
@@ -120,11 +135,11 @@ impl SystemLoanFeeReserve {
             cost_unit_price,
             tip_percentage,
             payments: Vec::new(),
+            fee_callbacks: Vec::new(),
             loan_balance: system_loan.into(),
             xrd_balance: 0,
             xrd_owed: 0,
-            cost_unit_consumed: 0,
-            cost_unit_limit: cost_unit_limit.into(),
+            budget: GasBudget::new(cost_unit_limit),
             check_point: system_loan.into(),
             execution_deferred: HashMap::new(),
             execution: HashMap::new(),
@@ -137,7 +152,7 @@ impl SystemLoanFeeReserve {
 
     fn consume(&mut self, n: u32, price: u128) -> Result<(), FeeReserveError> {
         // Check limit
-        if checked_add(self.cost_unit_consumed, n)? > self.cost_unit_limit {
+        if n > self.budget.remaining() {
             return Err(FeeReserveError::LimitExceeded);
         }
 
@@ -151,10 +166,10 @@ impl SystemLoanFeeReserve {
         }
 
         // Finally, apply state updates
+        self.budget.consume(n)?;
         self.loan_balance -= from_loan;
         self.xrd_balance -= from_locked;
         self.xrd_owed += price * from_loan as u128;
-        self.cost_unit_consumed += n;
         Ok(())
     }
 
@@ -211,7 +226,7 @@ impl FeeReserve for SystemLoanFeeReserve {
         self.consume(amount.into(), self.execution_price())?;
         checked_assign_add(self.royalty.entry(receiver).or_default(), amount)?;
 
-        if self.cost_unit_consumed >= self.check_point && !self.fully_repaid() {
+        if self.budget.consumed() >= self.check_point && !self.fully_repaid() {
             self.repay_all()?;
         }
         Ok(())
@@ -239,7 +254,7 @@ impl FeeReserve for SystemLoanFeeReserve {
             checked_assign_add(self.execution.entry(reason).or_default(), n)?;
         }
 
-        if self.cost_unit_consumed >= self.check_point && !self.fully_repaid() {
+        if self.budget.consumed() >= self.check_point && !self.fully_repaid() {
             self.repay_all()?;
         }
         Ok(())
@@ -248,10 +263,11 @@ impl FeeReserve for SystemLoanFeeReserve {
     fn lock_fee(
         &mut self,
         vault_id: VaultId,
+        resource_address: ResourceAddress,
         mut fee: Resource,
         contingent: bool,
     ) -> Result<Resource, FeeReserveError> {
-        if fee.resource_address() != RADIX_TOKEN {
+        if resource_address != RADIX_TOKEN || fee.resource_address() != RADIX_TOKEN {
             return Err(FeeReserveError::NotXrd);
         }
 
@@ -267,13 +283,40 @@ impl FeeReserve for SystemLoanFeeReserve {
         Ok(fee)
     }
 
+    fn register_fee_callback(&mut self, vault_id: VaultId, callback: ScryptoFunctionIdent) {
+        self.fee_callbacks.push((vault_id, callback));
+    }
+
+    fn fee_callbacks(&self) -> Vec<(ScryptoFunctionIdent, Decimal)> {
+        let mut required = self.execution_price()
+            * (self.execution.values().sum::<u32>() + self.execution_deferred.values().sum::<u32>())
+                as u128
+            + self.royalty_price() * self.royalty.values().sum::<u32>() as u128;
+
+        // Same order as the payments are charged on commit
+        let mut charged: HashMap<VaultId, u128> = HashMap::new();
+        for (vault_id, locked, _) in self.payments.iter().rev() {
+            let amount = min(decimal_to_u128(locked.amount()), required);
+            required -= amount;
+            *charged.entry(*vault_id).or_default() += amount;
+        }
+
+        self.fee_callbacks
+            .iter()
+            .map(|(vault_id, callback)| {
+                let amount = charged.get(vault_id).cloned().unwrap_or_default();
+                (callback.clone(), u128_to_decimal(amount))
+            })
+            .collect()
+    }
+
     fn finalize(mut self) -> FeeSummary {
         // In case the transaction finishes before check point.
         self.attempt_to_repay_all();
 
         FeeSummary {
-            cost_unit_limit: self.cost_unit_limit,
-            cost_unit_consumed: self.cost_unit_consumed,
+            cost_unit_limit: self.budget.limit(),
+            cost_unit_consumed: self.budget.consumed(),
             cost_unit_price: u128_to_decimal(self.cost_unit_price),
             tip_percentage: self.tip_percentage,
             total_execution_cost_xrd: u128_to_decimal(
@@ -291,6 +334,7 @@ impl FeeReserve for SystemLoanFeeReserve {
                 .map(|(k, v)| (k.to_string(), v))
                 .collect(),
             royalty_cost_unit_breakdown: self.royalty,
+            actor_cost_unit_breakdown: Vec::new(), // Resolved later
         }
     }
 }
@@ -309,7 +353,7 @@ impl Default for SystemLoanFeeReserve {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use radix_engine_interface::constants::RADIX_TOKEN;
+    use radix_engine_interface::constants::{ACCOUNT_PACKAGE, RADIX_TOKEN, SYSTEM_TOKEN};
 
     const TEST_VAULT_ID: VaultId = [0u8; 36];
 
@@ -321,7 +365,9 @@ mod tests {
     fn test_consume_and_repay() {
         let mut fee_reserve = SystemLoanFeeReserve::new(decimal_to_u128(dec!(1)), 2, 100, 5);
         fee_reserve.consume_execution(2, 1, "test", false).unwrap();
-        fee_reserve.lock_fee(TEST_VAULT_ID, xrd(3), false).unwrap();
+        fee_reserve
+            .lock_fee(TEST_VAULT_ID, RADIX_TOKEN, xrd(3), false)
+            .unwrap();
         let summary = fee_reserve.finalize();
         assert_eq!(summary.loan_fully_repaid(), true);
         assert_eq!(summary.cost_unit_consumed, 2);
@@ -349,7 +395,7 @@ mod tests {
     fn test_lock_fee() {
         let mut fee_reserve = SystemLoanFeeReserve::new(decimal_to_u128(dec!(1)), 2, 100, 500);
         fee_reserve
-            .lock_fee(TEST_VAULT_ID, xrd(100), false)
+            .lock_fee(TEST_VAULT_ID, RADIX_TOKEN, xrd(100), false)
             .unwrap();
         let summary = fee_reserve.finalize();
         assert_eq!(summary.loan_fully_repaid(), true);
@@ -359,11 +405,51 @@ mod tests {
         assert_eq!(summary.bad_debt_xrd, dec!("0"));
     }
 
+    #[test]
+    fn test_lock_fee_not_xrd() {
+        let mut fee_reserve = SystemLoanFeeReserve::new(decimal_to_u128(dec!(1)), 2, 100, 500);
+        let doge = Resource::new_fungible(SYSTEM_TOKEN, 18, dec!(100));
+        assert_eq!(
+            Err(FeeReserveError::NotXrd),
+            fee_reserve.lock_fee(TEST_VAULT_ID, SYSTEM_TOKEN, doge, false)
+        );
+        assert_eq!(
+            Err(FeeReserveError::NotXrd),
+            fee_reserve.lock_fee(TEST_VAULT_ID, SYSTEM_TOKEN, xrd(100), false)
+        );
+        let summary = fee_reserve.finalize();
+        assert_eq!(summary.vault_locks, vec![]);
+    }
+
+    #[test]
+    fn test_fee_callbacks() {
+        let first_vault_id: VaultId = [1u8; 36];
+        let callback = ScryptoFunctionIdent {
+            package: ScryptoPackage::Global(ACCOUNT_PACKAGE),
+            blueprint_name: "Test".to_owned(),
+            function_name: "on_fee".to_owned(),
+        };
+        let mut fee_reserve = SystemLoanFeeReserve::new(decimal_to_u128(dec!(1)), 0, 100, 5);
+        fee_reserve
+            .lock_fee(first_vault_id, RADIX_TOKEN, xrd(100), false)
+            .unwrap();
+        fee_reserve
+            .lock_fee(TEST_VAULT_ID, RADIX_TOKEN, xrd(3), false)
+            .unwrap();
+        fee_reserve.register_fee_callback(first_vault_id, callback.clone());
+        fee_reserve.register_fee_callback(TEST_VAULT_ID, callback.clone());
+        fee_reserve.consume_execution(10, 1, "test", false).unwrap();
+        assert_eq!(
+            fee_reserve.fee_callbacks(),
+            vec![(callback.clone(), dec!("7")), (callback, dec!("3"))]
+        );
+    }
+
     #[test]
     fn test_xrd_cost_unit_conversion() {
         let mut fee_reserve = SystemLoanFeeReserve::new(decimal_to_u128(dec!(5)), 0, 100, 500);
         fee_reserve
-            .lock_fee(TEST_VAULT_ID, xrd(100), false)
+            .lock_fee(TEST_VAULT_ID, RADIX_TOKEN, xrd(100), false)
             .unwrap();
         let summary = fee_reserve.finalize();
         assert_eq!(summary.loan_fully_repaid(), true);
@@ -398,7 +484,7 @@ mod tests {
             )
             .unwrap();
         fee_reserve
-            .lock_fee(TEST_VAULT_ID, xrd(100), false)
+            .lock_fee(TEST_VAULT_ID, RADIX_TOKEN, xrd(100), false)
             .unwrap();
         let summary = fee_reserve.finalize();
         assert_eq!(summary.loan_fully_repaid(), true);
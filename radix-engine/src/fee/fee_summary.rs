@@ -1,7 +1,7 @@
 use super::RoyaltyReceiver;
 use crate::model::Resource;
 use crate::types::*;
-use indexmap::IndexMap;
+use sbor::rust::collections::IndexMap;
 use radix_engine_interface::api::types::VaultId;
 
 #[derive(Debug, Clone)]
@@ -29,6 +29,23 @@ pub struct FeeSummary {
     pub execution_cost_unit_breakdown: HashMap<String, u32>,
     /// The royalty cost breakdown.
     pub royalty_cost_unit_breakdown: HashMap<RoyaltyReceiver, u32>,
+    /// The execution cost breakdown by actor (excluding royalties), by descending consumption
+    pub actor_cost_unit_breakdown: Vec<(CostingActor, u32)>,
+}
+
+/// The actor to which execution cost units are attributed.
+///
+/// Native invocations are attributed to the Scrypto actor which (transitively) invoked them.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum CostingActor {
+    /// The transaction itself, i.e. the manifest and the costs charged outside of any invocation
+    Transaction,
+    Scrypto {
+        package_address: PackageAddress,
+        blueprint_name: String,
+        component_address: Option<ComponentAddress>,
+    },
 }
 
 impl FeeSummary {
@@ -1,8 +1,9 @@
 use radix_engine_interface::api::types::{
-    AccessRulesChainMethod, AuthZoneStackMethod, BucketMethod, ClockFunction, ClockMethod,
-    ComponentFunction, ComponentMethod, EpochManagerFunction, EpochManagerMethod, MetadataMethod,
-    NativeFunction, NativeMethod, PackageFunction, PackageMethod, ProofMethod,
-    ResourceManagerFunction, ResourceManagerMethod, TransactionProcessorFunction, VaultMethod,
+    AccessRulesChainMethod, AuthZoneStackMethod, BucketMethod, BurnerFunction, BurnerMethod,
+    ClockFunction, ClockMethod, ComponentFunction, ComponentMethod, EpochManagerFunction,
+    EpochManagerMethod, MetadataMethod, NativeFunction, NativeMethod, PackageFunction,
+    PackageMethod, ProofMethod, ResourceManagerFunction, ResourceManagerMethod,
+    TimeLockedVaultFunction, TimeLockedVaultMethod, TransactionProcessorFunction, VaultMethod,
     WorktopMethod,
 };
 
@@ -80,6 +81,10 @@ pub enum SystemApiCostingEntry {
     },
     /// Generates a UUID.
     GenerateUuid,
+    /// Hashes data.
+    Hash {
+        size: u32,
+    },
     /// Emits a log.
     EmitLog {
         size: u32,
@@ -102,6 +107,8 @@ pub struct FeeTable {
     fixed_medium: u32,
     fixed_high: u32,
     wasm_instantiation_per_byte: u32,
+    auth_zone_per_proof: u32,
+    auth_check_per_proof: u32,
 }
 
 impl FeeTable {
@@ -116,6 +123,8 @@ impl FeeTable {
             fixed_low: 100,
             fixed_medium: 500,
             fixed_high: 1000,
+            auth_zone_per_proof: 500,
+            auth_check_per_proof: 50,
         }
     }
 
@@ -143,6 +152,16 @@ impl FeeTable {
         self.wasm_instantiation_per_byte
     }
 
+    /// The cost of pushing or creating a proof in an auth zone, on top of the method cost
+    pub fn auth_zone_per_proof(&self) -> u32 {
+        self.auth_zone_per_proof
+    }
+
+    /// The cost of each proof examined when checking an authorization
+    pub fn auth_check_per_proof(&self) -> u32 {
+        self.auth_check_per_proof
+    }
+
     pub fn run_native_function_cost(&self, native_function: &NativeFunction) -> u32 {
         match native_function {
             NativeFunction::Component(component_func) => match component_func {
@@ -164,6 +183,12 @@ impl FeeTable {
             NativeFunction::Clock(clock_fn) => match clock_fn {
                 ClockFunction::Create => self.fixed_low,
             },
+            NativeFunction::Burner(burner_fn) => match burner_fn {
+                BurnerFunction::Create => self.fixed_low,
+            },
+            NativeFunction::TimeLockedVault(time_locked_vault_fn) => match time_locked_vault_fn {
+                TimeLockedVaultFunction::Create => self.fixed_medium,
+            },
             NativeFunction::ResourceManager(resource_manager_ident) => {
                 match resource_manager_ident {
                     ResourceManagerFunction::Create => self.fixed_high, // TODO: more investigation about fungibility
@@ -197,6 +222,19 @@ impl FeeTable {
                 ClockMethod::GetCurrentTime => self.fixed_high,
                 ClockMethod::CompareCurrentTime => self.fixed_high,
             },
+            NativeMethod::Burner(burner_method) => match burner_method {
+                BurnerMethod::Deposit => self.fixed_medium,
+                BurnerMethod::DepositBatch => self.fixed_high,
+            },
+            NativeMethod::TimeLockedVault(time_locked_vault_method) => {
+                match time_locked_vault_method {
+                    TimeLockedVaultMethod::Deposit => self.fixed_medium,
+                    TimeLockedVaultMethod::Withdraw => self.fixed_medium,
+                    TimeLockedVaultMethod::ExtendLock => self.fixed_low,
+                    TimeLockedVaultMethod::GetUnlockEpoch => self.fixed_low,
+                    TimeLockedVaultMethod::GetAmount => self.fixed_low,
+                }
+            }
             NativeMethod::Bucket(bucket_ident) => match bucket_ident {
                 BucketMethod::Take => self.fixed_medium,
                 BucketMethod::TakeNonFungibles => self.fixed_medium,
@@ -210,6 +248,7 @@ impl FeeTable {
                 ProofMethod::GetAmount => self.fixed_low,
                 ProofMethod::GetNonFungibleIds => self.fixed_low,
                 ProofMethod::GetResourceAddress => self.fixed_low,
+                ProofMethod::IsValid => self.fixed_low,
                 ProofMethod::Clone => self.fixed_low,
             },
             NativeMethod::ResourceManager(resource_manager_ident) => match resource_manager_ident {
@@ -220,6 +259,11 @@ impl FeeTable {
                 ResourceManagerMethod::Mint => self.fixed_high,
                 ResourceManagerMethod::GetResourceType => self.fixed_low,
                 ResourceManagerMethod::GetTotalSupply => self.fixed_low,
+                ResourceManagerMethod::GetSummary => self.fixed_low,
+                ResourceManagerMethod::GetHolderVaultCount => self.fixed_low,
+                ResourceManagerMethod::GetAmountMinted => self.fixed_low,
+                ResourceManagerMethod::GetAmountBurned => self.fixed_low,
+                ResourceManagerMethod::CreateSyntheticProof => self.fixed_medium,
                 ResourceManagerMethod::UpdateNonFungibleData => self.fixed_medium,
                 ResourceManagerMethod::NonFungibleExists => self.fixed_low,
                 ResourceManagerMethod::GetNonFungible => self.fixed_medium,
@@ -228,11 +272,14 @@ impl FeeTable {
             NativeMethod::Worktop(worktop_ident) => match worktop_ident {
                 WorktopMethod::Put => self.fixed_medium,
                 WorktopMethod::TakeAmount => self.fixed_medium,
+                WorktopMethod::TakeOrDefault => self.fixed_medium,
                 WorktopMethod::TakeAll => self.fixed_medium,
                 WorktopMethod::TakeNonFungibles => self.fixed_medium,
                 WorktopMethod::AssertContains => self.fixed_low,
                 WorktopMethod::AssertContainsAmount => self.fixed_low,
+                WorktopMethod::AssertContainsExactAmount => self.fixed_low,
                 WorktopMethod::AssertContainsNonFungibles => self.fixed_low,
+                WorktopMethod::GetAmount => self.fixed_low,
                 WorktopMethod::Drain => self.fixed_low,
             },
             NativeMethod::AccessRulesChain(component_ident) => match component_ident {
@@ -250,10 +297,13 @@ impl FeeTable {
             NativeMethod::Component(method_ident) => match method_ident {
                 ComponentMethod::SetRoyaltyConfig => self.fixed_medium,
                 ComponentMethod::ClaimRoyalty => self.fixed_medium,
+                ComponentMethod::SetAuthMutability => self.fixed_low,
+                ComponentMethod::StateSizeBytes => self.fixed_low,
             },
             NativeMethod::Package(method_ident) => match method_ident {
                 PackageMethod::SetRoyaltyConfig => self.fixed_medium,
                 PackageMethod::ClaimRoyalty => self.fixed_medium,
+                PackageMethod::GetBuildFingerprint => self.fixed_low,
             },
             NativeMethod::Vault(vault_ident) => {
                 match vault_ident {
@@ -267,6 +317,7 @@ impl FeeTable {
                     VaultMethod::CreateProofByAmount => self.fixed_high,
                     VaultMethod::CreateProofByIds => self.fixed_high,
                     VaultMethod::LockFee => self.fixed_medium,
+                    VaultMethod::LockFeeWithCallback => self.fixed_medium,
                     VaultMethod::Recall => self.fixed_low,
                     VaultMethod::RecallNonFungibles => self.fixed_low,
                 }
@@ -313,6 +364,7 @@ impl FeeTable {
             SystemApiCostingEntry::ReadTransactionHash => self.fixed_low,
             SystemApiCostingEntry::ReadBlob { size } => self.fixed_low + size,
             SystemApiCostingEntry::GenerateUuid => self.fixed_low,
+            SystemApiCostingEntry::Hash { size } => self.fixed_low + size,
             SystemApiCostingEntry::EmitLog { size } => self.fixed_low + 10 * size,
             SystemApiCostingEntry::EmitEvent {
                 native,
@@ -1,7 +1,7 @@
 use crate::engine::ScryptoInterpreter;
-use crate::ledger::{ReadableSubstateStore, WriteableSubstateStore};
+use crate::ledger::{ReadableSubstateStore, StateTreeStore, WriteableSubstateStore};
 use crate::transaction::{
-    execute_transaction, ExecutionConfig, FeeReserveConfig, TransactionReceipt,
+    execute_transaction, ExecutionConfig, FeeReserveConfig, TransactionReceipt, TransactionResult,
 };
 use crate::types::*;
 use crate::wasm::{DefaultWasmEngine, InstructionCostRules, WasmInstrumenter, WasmMeteringConfig};
@@ -31,6 +31,7 @@ pub struct GenesisReceipt {
     pub faucet_component: ComponentAddress,
     pub epoch_manager: SystemAddress,
     pub clock: SystemAddress,
+    pub burner: SystemAddress,
     pub eddsa_ed25519_token: ResourceAddress,
 }
 
@@ -49,7 +50,7 @@ pub fn create_genesis() -> SystemTransaction {
                 code: Blob(hash(&faucet_code)),
                 abi: Blob(hash(&faucet_abi)),
                 royalty_config: HashMap::new(),
-                metadata: HashMap::new(),
+                metadata: BTreeMap::new(),
                 access_rules: AccessRules::new().default(AccessRule::DenyAll, AccessRule::DenyAll),
             })
             .unwrap(),
@@ -72,7 +73,7 @@ pub fn create_genesis() -> SystemTransaction {
                 code: Blob(hash(&account_code)),
                 abi: Blob(hash(&account_abi)),
                 royalty_config: HashMap::new(),
-                metadata: HashMap::new(),
+                metadata: BTreeMap::new(),
                 access_rules: AccessRules::new().default(AccessRule::DenyAll, AccessRule::DenyAll),
             })
             .unwrap(),
@@ -84,7 +85,7 @@ pub fn create_genesis() -> SystemTransaction {
         inst
     };
     let create_ecdsa_secp256k1_token = {
-        let metadata: HashMap<String, String> = HashMap::new();
+        let metadata: BTreeMap<String, String> = BTreeMap::new();
         let mut access_rules = HashMap::new();
         access_rules.insert(
             ResourceMethodAuthKey::Withdraw,
@@ -105,6 +106,8 @@ pub fn create_genesis() -> SystemTransaction {
                 metadata,
                 access_rules,
                 mint_params: initial_supply,
+                non_fungible_data_schema: None,
+                track_holders: false,
             })
             .unwrap(),
         }
@@ -112,7 +115,7 @@ pub fn create_genesis() -> SystemTransaction {
 
     // TODO: Perhaps combine with ecdsa token?
     let create_system_token = {
-        let metadata: HashMap<String, String> = HashMap::new();
+        let metadata: BTreeMap<String, String> = BTreeMap::new();
         let mut access_rules = HashMap::new();
         access_rules.insert(
             ResourceMethodAuthKey::Withdraw,
@@ -133,13 +136,15 @@ pub fn create_genesis() -> SystemTransaction {
                 metadata,
                 access_rules,
                 mint_params: initial_supply,
+                non_fungible_data_schema: None,
+                track_holders: false,
             })
             .unwrap(),
         }
     };
 
     let create_xrd_token = {
-        let mut metadata = HashMap::new();
+        let mut metadata = BTreeMap::new();
         metadata.insert("symbol".to_owned(), XRD_SYMBOL.to_owned());
         metadata.insert("name".to_owned(), XRD_NAME.to_owned());
         metadata.insert("description".to_owned(), XRD_DESCRIPTION.to_owned());
@@ -165,6 +170,8 @@ pub fn create_genesis() -> SystemTransaction {
                 metadata,
                 access_rules,
                 mint_params: initial_supply,
+                non_fungible_data_schema: None,
+                track_holders: false,
             })
             .unwrap(),
         }
@@ -207,7 +214,7 @@ pub fn create_genesis() -> SystemTransaction {
     };
 
     let create_eddsa_ed25519_token = {
-        let metadata: HashMap<String, String> = HashMap::new();
+        let metadata: BTreeMap<String, String> = BTreeMap::new();
         let mut access_rules = HashMap::new();
         access_rules.insert(
             ResourceMethodAuthKey::Withdraw,
@@ -228,11 +235,23 @@ pub fn create_genesis() -> SystemTransaction {
                 metadata,
                 access_rules,
                 mint_params: initial_supply,
+                non_fungible_data_schema: None,
+                track_holders: false,
             })
             .unwrap(),
         }
     };
 
+    let create_burner = {
+        Instruction::CallNativeFunction {
+            function_ident: NativeFunctionIdent {
+                blueprint_name: BURNER_BLUEPRINT.to_string(),
+                function_name: BurnerFunction::Create.to_string(),
+            },
+            args: args!(),
+        }
+    };
+
     let manifest = TransactionManifest {
         instructions: vec![
             create_faucet_package,
@@ -245,6 +264,7 @@ pub fn create_genesis() -> SystemTransaction {
             create_epoch_manager,
             create_clock,
             create_eddsa_ed25519_token,
+            create_burner,
         ],
         blobs,
     };
@@ -266,6 +286,7 @@ pub fn genesis_result(invoke_result: &Vec<Vec<u8>>) -> GenesisReceipt {
     let clock: SystemAddress = scrypto_decode(&invoke_result[8]).unwrap();
     let (eddsa_ed25519_token, _bucket): (ResourceAddress, Option<Bucket>) =
         scrypto_decode(&invoke_result[9]).unwrap();
+    let burner: SystemAddress = scrypto_decode(&invoke_result[10]).unwrap();
 
     GenesisReceipt {
         faucet_package,
@@ -277,18 +298,16 @@ pub fn genesis_result(invoke_result: &Vec<Vec<u8>>) -> GenesisReceipt {
         epoch_manager,
         clock,
         eddsa_ed25519_token,
+        burner,
     }
 }
 
 pub fn bootstrap<S>(substate_store: &mut S) -> Option<TransactionReceipt>
 where
-    S: ReadableSubstateStore + WriteableSubstateStore,
+    S: ReadableSubstateStore + WriteableSubstateStore + StateTreeStore,
 {
     if substate_store
-        .get_substate(&SubstateId(
-            RENodeId::Global(GlobalAddress::Resource(RADIX_TOKEN)),
-            SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager),
-        ))
+        .get_substate(&SubstateId::global(GlobalAddress::Resource(RADIX_TOKEN)))
         .is_none()
     {
         let scrypto_interpreter = ScryptoInterpreter {
@@ -302,7 +321,7 @@ where
 
         let genesis_transaction = create_genesis();
 
-        let transaction_receipt = execute_transaction(
+        let mut transaction_receipt = execute_transaction(
             substate_store,
             &scrypto_interpreter,
             &FeeReserveConfig::default(),
@@ -310,9 +329,17 @@ where
             &genesis_transaction.get_executable(),
         );
 
-        let commit_result = transaction_receipt.expect_commit();
-        commit_result.outcome.expect_success();
-        commit_result.state_updates.commit(substate_store);
+        if let TransactionResult::Commit(commit_result) = &mut transaction_receipt.result {
+            commit_result.outcome.expect_success();
+            commit_result.state_updates.commit(substate_store);
+            commit_result.state_root_hash = Some(
+                commit_result
+                    .state_updates
+                    .commit_to_state_tree(substate_store),
+            );
+        } else {
+            panic!("Genesis transaction was rejected");
+        }
 
         Some(transaction_receipt)
     } else {
@@ -360,6 +387,7 @@ mod tests {
         assert_eq!(genesis_receipt.faucet_component, FAUCET_COMPONENT);
         assert_eq!(genesis_receipt.epoch_manager, EPOCH_MANAGER);
         assert_eq!(genesis_receipt.clock, CLOCK);
+        assert_eq!(genesis_receipt.burner, SYS_BURNER_COMPONENT);
         assert_eq!(genesis_receipt.eddsa_ed25519_token, EDDSA_ED25519_TOKEN);
     }
 }
@@ -3,19 +3,22 @@ use crate::ledger::{OutputValue, WriteableSubstateStore};
 use crate::model::PersistedSubstate;
 use crate::types::*;
 use radix_engine_interface::api::types::{
-    KeyValueStoreId, KeyValueStoreOffset, RENodeId, SubstateId, SubstateOffset,
+    KeyValueStoreId, KeyValueStoreOffset, RENodeId, SubstateId, SubstateOffset, VaultId,
+    VaultOffset,
 };
 
 /// A substate store that stores all typed substates in host memory.
 #[derive(Debug, PartialEq, Eq)]
 pub struct TypedInMemorySubstateStore {
     substates: HashMap<SubstateId, OutputValue>,
+    state_tree: InMemoryStateTree,
 }
 
 impl TypedInMemorySubstateStore {
     pub fn new() -> Self {
         Self {
             substates: HashMap::new(),
+            state_tree: InMemoryStateTree::new(),
         }
     }
 
@@ -44,6 +47,16 @@ impl WriteableSubstateStore for TypedInMemorySubstateStore {
     }
 }
 
+impl StateTreeStore for TypedInMemorySubstateStore {
+    fn get_tree_node(&self, key: &StateTreeNodeKey) -> Option<Hash> {
+        self.state_tree.get_tree_node(key)
+    }
+
+    fn put_tree_node(&mut self, key: StateTreeNodeKey, hash: Hash) {
+        self.state_tree.put_tree_node(key, hash);
+    }
+}
+
 impl QueryableSubstateStore for TypedInMemorySubstateStore {
     fn get_kv_store_entries(
         &self,
@@ -52,13 +65,25 @@ impl QueryableSubstateStore for TypedInMemorySubstateStore {
         self.substates
             .iter()
             .filter_map(|(key, value)| {
-                if let SubstateId(
-                    RENodeId::KeyValueStore(id),
-                    SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(key)),
-                ) = key
-                {
-                    if id == kv_store_id {
-                        Some((key.clone(), value.substate.clone()))
+                if let Some((id, key)) = key.clone().try_into_kv_entry() {
+                    if id == *kv_store_id {
+                        Some((key, value.substate.clone()))
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+    fn get_resource_vaults(&self, resource_address: &ResourceAddress) -> Vec<VaultId> {
+        self.substates
+            .iter()
+            .filter_map(|(key, value)| {
+                if let Some(id) = key.clone().try_into_vault() {
+                    if value.substate.vault().0.resource_address() == *resource_address {
+                        Some(id)
                     } else {
                         None
                     }
@@ -68,4 +93,11 @@ impl QueryableSubstateStore for TypedInMemorySubstateStore {
             })
             .collect()
     }
+
+    fn get_all_substates(&self) -> Vec<(SubstateId, OutputValue)> {
+        self.substates
+            .iter()
+            .map(|(substate_id, output_value)| (substate_id.clone(), output_value.clone()))
+            .collect()
+    }
 }
@@ -1,9 +1,11 @@
 mod bootstrap;
 mod memory;
 mod query;
+mod state_tree;
 mod traits;
 
 pub use bootstrap::{bootstrap, genesis_result, GenesisReceipt};
 pub use memory::TypedInMemorySubstateStore;
 pub use query::*;
+pub use state_tree::*;
 pub use traits::*;
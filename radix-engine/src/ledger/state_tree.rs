@@ -0,0 +1,266 @@
+use crate::ledger::*;
+use crate::types::*;
+use radix_engine_interface::api::types::SubstateId;
+
+/// The depth of the state tree, i.e. the number of bits in a leaf path.
+pub const STATE_TREE_DEPTH: u16 = 256;
+
+/// Identifies a node of the sparse merkle state tree by its depth and the first `depth` bits of
+/// its path, with all remaining bits zeroed.
+///
+/// Leaves live at depth [`STATE_TREE_DEPTH`], at the path `hash(SubstateId)`. The root lives at
+/// depth zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct StateTreeNodeKey {
+    pub depth: u16,
+    pub path: [u8; Hash::LENGTH],
+}
+
+impl StateTreeNodeKey {
+    pub fn new(depth: u16, path: &[u8; Hash::LENGTH]) -> Self {
+        let mut masked = [0u8; Hash::LENGTH];
+        let full_bytes = (depth / 8) as usize;
+        masked[..full_bytes].copy_from_slice(&path[..full_bytes]);
+        if depth % 8 != 0 {
+            masked[full_bytes] = path[full_bytes] & (0xFF << (8 - depth % 8));
+        }
+        Self {
+            depth,
+            path: masked,
+        }
+    }
+
+    pub fn root() -> Self {
+        Self::new(0, &[0u8; Hash::LENGTH])
+    }
+}
+
+/// A store of state tree node hashes, kept alongside a substate store.
+///
+/// Nodes which have never been written are treated as empty subtrees.
+pub trait StateTreeStore {
+    fn get_tree_node(&self, key: &StateTreeNodeKey) -> Option<Hash>;
+
+    fn put_tree_node(&mut self, key: StateTreeNodeKey, hash: Hash);
+}
+
+/// A state tree which keeps all nodes in host memory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InMemoryStateTree {
+    nodes: HashMap<StateTreeNodeKey, Hash>,
+}
+
+impl InMemoryStateTree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl StateTreeStore for InMemoryStateTree {
+    fn get_tree_node(&self, key: &StateTreeNodeKey) -> Option<Hash> {
+        self.nodes.get(key).cloned()
+    }
+
+    fn put_tree_node(&mut self, key: StateTreeNodeKey, hash: Hash) {
+        self.nodes.insert(key, hash);
+    }
+}
+
+/// The root hash stored in the tree and the root hash recomputed from the substates disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateRootMismatch {
+    pub stored: Hash,
+    pub recomputed: Hash,
+}
+
+/// Computes the leaf hash of a substate, committing to its id, content and version.
+pub fn substate_leaf_hash(substate_id: &SubstateId, output_value: &OutputValue) -> Hash {
+    let mut bytes = scrypto_encode(substate_id).expect("Could not encode substate id");
+    bytes.extend(
+        scrypto_encode(&output_value.substate).expect("Could not encode substate for hashing"),
+    );
+    bytes.extend(output_value.version.to_le_bytes());
+    hash(bytes)
+}
+
+/// Writes the given leaves into the state tree, updating every node on their paths, and returns
+/// the new root hash.
+pub fn update_state_tree<S, I>(store: &mut S, leaves: I) -> Hash
+where
+    S: StateTreeStore,
+    I: IntoIterator<Item = (SubstateId, Hash)>,
+{
+    let empty_hashes = empty_subtree_hashes();
+
+    for (substate_id, leaf_hash) in leaves {
+        let path = hash(scrypto_encode(&substate_id).expect("Could not encode substate id")).0;
+        store.put_tree_node(StateTreeNodeKey::new(STATE_TREE_DEPTH, &path), leaf_hash);
+
+        let mut current = leaf_hash;
+        for depth in (0..STATE_TREE_DEPTH).rev() {
+            let child_depth = depth + 1;
+            let mut sibling_path = path;
+            sibling_path[(depth / 8) as usize] ^= 0x80 >> (depth % 8);
+            let sibling = store
+                .get_tree_node(&StateTreeNodeKey::new(child_depth, &sibling_path))
+                .unwrap_or(empty_hashes[child_depth as usize]);
+
+            current = if path_bit(&path, depth) {
+                hash_pair(&sibling, &current)
+            } else {
+                hash_pair(&current, &sibling)
+            };
+            store.put_tree_node(StateTreeNodeKey::new(depth, &path), current);
+        }
+    }
+
+    state_root_hash(store)
+}
+
+/// Removes the leaves of the given substates from the state tree, and returns the new root hash.
+pub fn remove_from_state_tree<S, I>(store: &mut S, substate_ids: I) -> Hash
+where
+    S: StateTreeStore,
+    I: IntoIterator<Item = SubstateId>,
+{
+    let empty_leaf = empty_subtree_hashes()[STATE_TREE_DEPTH as usize];
+    update_state_tree(
+        store,
+        substate_ids
+            .into_iter()
+            .map(|substate_id| (substate_id, empty_leaf)),
+    )
+}
+
+/// Returns the root hash of the state tree.
+pub fn state_root_hash<S: StateTreeStore>(store: &S) -> Hash {
+    store
+        .get_tree_node(&StateTreeNodeKey::root())
+        .unwrap_or(empty_subtree_hashes()[0])
+}
+
+/// Computes the state root hash of the given substates from scratch.
+pub fn compute_state_root<I>(substates: I) -> Hash
+where
+    I: IntoIterator<Item = (SubstateId, OutputValue)>,
+{
+    let mut tree = InMemoryStateTree::new();
+    update_state_tree(
+        &mut tree,
+        substates.into_iter().map(|(substate_id, output_value)| {
+            let leaf_hash = substate_leaf_hash(&substate_id, &output_value);
+            (substate_id, leaf_hash)
+        }),
+    )
+}
+
+/// Recomputes the state root hash from all substates in the store and checks it against the
+/// incrementally maintained one.
+pub fn verify_state_root<S>(store: &S) -> Result<Hash, StateRootMismatch>
+where
+    S: QueryableSubstateStore + StateTreeStore,
+{
+    let stored = state_root_hash(store);
+    let recomputed = compute_state_root(store.get_all_substates());
+    if stored == recomputed {
+        Ok(stored)
+    } else {
+        Err(StateRootMismatch { stored, recomputed })
+    }
+}
+
+fn path_bit(path: &[u8; Hash::LENGTH], bit: u16) -> bool {
+    path[(bit / 8) as usize] & (0x80 >> (bit % 8)) != 0
+}
+
+fn hash_pair(left: &Hash, right: &Hash) -> Hash {
+    let mut bytes = left.0.to_vec();
+    bytes.extend(right.0);
+    hash(bytes)
+}
+
+/// The hashes of empty subtrees, indexed by the depth of their root.
+fn empty_subtree_hashes() -> Vec<Hash> {
+    let mut hashes = vec![Hash([0u8; Hash::LENGTH]); STATE_TREE_DEPTH as usize + 1];
+    for depth in (0..STATE_TREE_DEPTH as usize).rev() {
+        hashes[depth] = hash_pair(&hashes[depth + 1], &hashes[depth + 1]);
+    }
+    hashes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{GlobalAddressSubstate, PersistedSubstate};
+    use radix_engine_interface::api::types::{GlobalOffset, RENodeId, SubstateOffset};
+
+    fn leaf(n: u8) -> (SubstateId, Hash) {
+        (
+            SubstateId(
+                RENodeId::Global(GlobalAddress::Package(PackageAddress::Normal([n; 26]))),
+                SubstateOffset::Global(GlobalOffset::Global),
+            ),
+            hash([n]),
+        )
+    }
+
+    #[test]
+    fn empty_tree_has_empty_subtree_root() {
+        let tree = InMemoryStateTree::new();
+        assert_eq!(state_root_hash(&tree), empty_subtree_hashes()[0]);
+    }
+
+    #[test]
+    fn root_is_independent_of_write_order() {
+        let mut forward = InMemoryStateTree::new();
+        let mut backward = InMemoryStateTree::new();
+
+        let forward_root = update_state_tree(&mut forward, (1..=5).map(leaf));
+        let backward_root = update_state_tree(&mut backward, (1..=5).rev().map(leaf));
+
+        assert_eq!(forward_root, backward_root);
+        assert_ne!(forward_root, empty_subtree_hashes()[0]);
+    }
+
+    #[test]
+    fn incremental_updates_match_from_scratch_computation() {
+        let mut tree = InMemoryStateTree::new();
+        update_state_tree(&mut tree, vec![leaf(1), leaf(2)]);
+        let incremental = update_state_tree(&mut tree, vec![(leaf(1).0, hash([9]))]);
+
+        let mut scratch = InMemoryStateTree::new();
+        let from_scratch = update_state_tree(&mut scratch, vec![(leaf(1).0, hash([9])), leaf(2)]);
+
+        assert_eq!(incremental, from_scratch);
+    }
+
+    #[test]
+    fn removing_leaves_restores_previous_root() {
+        let mut tree = InMemoryStateTree::new();
+        let before = update_state_tree(&mut tree, vec![leaf(1), leaf(2)]);
+        update_state_tree(&mut tree, vec![leaf(3)]);
+
+        let after = remove_from_state_tree(&mut tree, vec![leaf(3).0]);
+
+        assert_eq!(after, before);
+    }
+
+    #[test]
+    fn leaf_hash_commits_to_version() {
+        let (substate_id, _) = leaf(1);
+        let output_value = OutputValue {
+            substate: PersistedSubstate::Global(GlobalAddressSubstate::Package([0u8; 36])),
+            version: 0,
+        };
+        let bumped = OutputValue {
+            version: 1,
+            ..output_value.clone()
+        };
+
+        assert_ne!(
+            substate_leaf_hash(&substate_id, &output_value),
+            substate_leaf_hash(&substate_id, &bumped)
+        );
+    }
+}
@@ -1,12 +1,18 @@
 use crate::model::PersistedSubstate;
 use crate::types::*;
-use radix_engine_interface::api::types::{KeyValueStoreId, SubstateId};
+use radix_engine_interface::api::types::{KeyValueStoreId, SubstateId, VaultId};
 
 pub trait QueryableSubstateStore {
     fn get_kv_store_entries(
         &self,
         kv_store_id: &KeyValueStoreId,
     ) -> HashMap<Vec<u8>, PersistedSubstate>;
+
+    /// Returns the ids of all vaults holding the given resource.
+    fn get_resource_vaults(&self, resource_address: &ResourceAddress) -> Vec<VaultId>;
+
+    /// Returns every substate in the store, in no particular order.
+    fn get_all_substates(&self) -> Vec<(SubstateId, OutputValue)>;
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -22,15 +22,12 @@ pub fn export_abi<S: ReadableSubstateStore>(
     blueprint_name: &str,
 ) -> Result<abi::BlueprintAbi, ExportError> {
     let global_substate: RuntimeSubstate = substate_store
-        .get_substate(&SubstateId(
-            RENodeId::Global(GlobalAddress::Package(package_address)),
-            SubstateOffset::Global(GlobalOffset::Global),
-        ))
+        .get_substate(&SubstateId::global(GlobalAddress::Package(package_address)))
         .map(|s| s.substate.to_runtime())
         .ok_or(ExportError::PackageNotFound(package_address))?;
 
     let package_value: RuntimeSubstate = substate_store
-        .get_substate(&SubstateId(
+        .get_substate(&SubstateId::new(
             global_substate.global().node_deref(),
             SubstateOffset::Package(PackageOffset::Info),
         ))
@@ -53,18 +50,16 @@ pub fn export_abi_by_component<S: ReadableSubstateStore>(
     substate_store: &S,
     component_address: ComponentAddress,
 ) -> Result<abi::BlueprintAbi, ExportError> {
-    let node_id = RENodeId::Global(GlobalAddress::Component(component_address));
     let global = substate_store
-        .get_substate(&SubstateId(
-            node_id,
-            SubstateOffset::Global(GlobalOffset::Global),
-        ))
+        .get_substate(&SubstateId::global(GlobalAddress::Component(
+            component_address,
+        )))
         .map(|s| s.substate.to_runtime())
         .ok_or(ExportError::ComponentNotFound(component_address))?;
     let component_id = global.global().node_deref();
 
     let component_value: RuntimeSubstate = substate_store
-        .get_substate(&SubstateId(
+        .get_substate(&SubstateId::new(
             component_id,
             SubstateOffset::Component(ComponentOffset::Info),
         ))
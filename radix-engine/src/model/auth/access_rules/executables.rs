@@ -203,16 +203,18 @@ impl NativeProcedure for AccessRulesSetMethodAccessRuleInvocation {
 
             let offset = SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack);
             let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
-            let substate_ref = api.get_ref(handle)?;
-            let auth_zone_stack = substate_ref.auth_zone_stack();
-
-            auth_zone_stack.check_auth(false, authorization).map_err(
-                |(authorization, error)| {
-                    RuntimeError::ApplicationError(ApplicationError::AccessRulesChainError(
-                        AccessRulesChainError::Unauthorized(authorization, error),
-                    ))
-                },
-            )?;
+            let (result, proofs_examined) = {
+                let substate_ref = api.get_ref(handle)?;
+                let auth_zone_stack = substate_ref.auth_zone_stack();
+                auth_zone_stack.check_auth_counting_proofs(false, authorization)
+            };
+            api.consume_auth_check_cost(proofs_examined)?;
+
+            result.map_err(|(authorization, error)| {
+                RuntimeError::ApplicationError(ApplicationError::AccessRulesChainError(
+                    AccessRulesChainError::Unauthorized(authorization, error),
+                ))
+            })?;
         }
 
         let mut substate_ref_mut = api.get_ref_mut(handle)?;
@@ -295,16 +297,18 @@ impl NativeProcedure for AccessRulesSetGroupAccessRuleInvocation {
 
             let offset = SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack);
             let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
-            let substate_ref = api.get_ref(handle)?;
-            let auth_zone_substate = substate_ref.auth_zone_stack();
-
-            auth_zone_substate
-                .check_auth(false, authorization)
-                .map_err(|(authorization, error)| {
-                    RuntimeError::ApplicationError(ApplicationError::AccessRulesChainError(
-                        AccessRulesChainError::Unauthorized(authorization, error),
-                    ))
-                })?;
+            let (result, proofs_examined) = {
+                let substate_ref = api.get_ref(handle)?;
+                let auth_zone_stack = substate_ref.auth_zone_stack();
+                auth_zone_stack.check_auth_counting_proofs(false, authorization)
+            };
+            api.consume_auth_check_cost(proofs_examined)?;
+
+            result.map_err(|(authorization, error)| {
+                RuntimeError::ApplicationError(ApplicationError::AccessRulesChainError(
+                    AccessRulesChainError::Unauthorized(authorization, error),
+                ))
+            })?;
         }
 
         let mut substate_ref_mut = api.get_ref_mut(handle)?;
@@ -415,16 +419,18 @@ impl NativeProcedure for AccessRulesSetMethodMutabilityInvocation {
 
             let offset = SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack);
             let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
-            let substate_ref = api.get_ref(handle)?;
-            let auth_zone_stack = substate_ref.auth_zone_stack();
-
-            auth_zone_stack.check_auth(false, authorization).map_err(
-                |(authorization, error)| {
-                    RuntimeError::ApplicationError(ApplicationError::AccessRulesChainError(
-                        AccessRulesChainError::Unauthorized(authorization, error),
-                    ))
-                },
-            )?;
+            let (result, proofs_examined) = {
+                let substate_ref = api.get_ref(handle)?;
+                let auth_zone_stack = substate_ref.auth_zone_stack();
+                auth_zone_stack.check_auth_counting_proofs(false, authorization)
+            };
+            api.consume_auth_check_cost(proofs_examined)?;
+
+            result.map_err(|(authorization, error)| {
+                RuntimeError::ApplicationError(ApplicationError::AccessRulesChainError(
+                    AccessRulesChainError::Unauthorized(authorization, error),
+                ))
+            })?;
         }
 
         let mut substate_ref_mut = api.get_ref_mut(handle)?;
@@ -507,16 +513,18 @@ impl NativeProcedure for AccessRulesSetGroupMutabilityInvocation {
 
             let offset = SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack);
             let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
-            let substate_ref = api.get_ref(handle)?;
-            let auth_zone_substate = substate_ref.auth_zone_stack();
-
-            auth_zone_substate
-                .check_auth(false, authorization)
-                .map_err(|(authorization, error)| {
-                    RuntimeError::ApplicationError(ApplicationError::AccessRulesChainError(
-                        AccessRulesChainError::Unauthorized(authorization, error),
-                    ))
-                })?;
+            let (result, proofs_examined) = {
+                let substate_ref = api.get_ref(handle)?;
+                let auth_zone_stack = substate_ref.auth_zone_stack();
+                auth_zone_stack.check_auth_counting_proofs(false, authorization)
+            };
+            api.consume_auth_check_cost(proofs_examined)?;
+
+            result.map_err(|(authorization, error)| {
+                RuntimeError::ApplicationError(ApplicationError::AccessRulesChainError(
+                    AccessRulesChainError::Unauthorized(authorization, error),
+                ))
+            })?;
         }
 
         let mut substate_ref_mut = api.get_ref_mut(handle)?;
@@ -3,9 +3,7 @@ use crate::engine::{
     NativeProcedure, REActor, RENode, ResolvedMethod, ResolvedReceiver, ResolverApi, RuntimeError,
     SystemApi,
 };
-use crate::model::{
-    convert, InvokeError, MethodAuthorization, MethodAuthorizationError, ProofError,
-};
+use crate::model::{convert, MethodAuthorization, MethodAuthorizationError, ProofError};
 use crate::types::*;
 use crate::wasm::WasmEngine;
 use radix_engine_interface::api::types::{
@@ -27,6 +25,13 @@ pub enum AuthZoneError {
     CouldNotGetResource,
     NoMethodSpecified,
     AssertAccessRuleError(MethodAuthorization, MethodAuthorizationError),
+    ProofLimitExceeded { limit: usize },
+}
+
+impl From<ProofError> for AuthZoneError {
+    fn from(error: ProofError) -> Self {
+        Self::ProofError(error)
+    }
 }
 
 impl<W: WasmEngine> ExecutableInvocation<W> for AuthZonePopInvocation {
@@ -64,15 +69,7 @@ impl NativeProcedure for AuthZonePopInvocation {
         let proof = {
             let mut substate_mut = api.get_ref_mut(auth_zone_handle)?;
             let auth_zone_stack = substate_mut.auth_zone_stack();
-            let proof = auth_zone_stack
-                .cur_auth_zone_mut()
-                .pop()
-                .map_err(|e| match e {
-                    InvokeError::Downstream(runtime_error) => runtime_error,
-                    InvokeError::Error(e) => {
-                        RuntimeError::ApplicationError(ApplicationError::AuthZoneError(e))
-                    }
-                })?;
+            let proof = auth_zone_stack.cur_auth_zone_mut().pop()?;
             proof
         };
 
@@ -136,7 +133,9 @@ impl NativeProcedure for AuthZonePushInvocation {
 
         let mut substate_mut = system_api.get_ref_mut(auth_zone_handle)?;
         let auth_zone_stack = substate_mut.auth_zone_stack();
-        auth_zone_stack.cur_auth_zone_mut().push(cloned_proof);
+        auth_zone_stack
+            .push_proof(cloned_proof)
+            .map_err(|e| RuntimeError::ApplicationError(ApplicationError::AuthZoneError(e)))?;
 
         Ok(((), CallFrameUpdate::empty()))
     }
@@ -194,13 +193,7 @@ impl NativeProcedure for AuthZoneCreateProofInvocation {
             let auth_zone_stack = substate_mut.auth_zone_stack();
             let proof = auth_zone_stack
                 .cur_auth_zone()
-                .create_proof(self.resource_address, resource_type)
-                .map_err(|e| match e {
-                    InvokeError::Downstream(runtime_error) => runtime_error,
-                    InvokeError::Error(e) => {
-                        RuntimeError::ApplicationError(ApplicationError::AuthZoneError(e))
-                    }
-                })?;
+                .create_proof(self.resource_address, resource_type)?;
             proof
         };
 
@@ -265,15 +258,11 @@ impl NativeProcedure for AuthZoneCreateProofByAmountInvocation {
         let proof = {
             let mut substate_mut = api.get_ref_mut(auth_zone_handle)?;
             let auth_zone_stack = substate_mut.auth_zone_stack();
-            let proof = auth_zone_stack
-                .cur_auth_zone()
-                .create_proof_by_amount(self.amount, self.resource_address, resource_type)
-                .map_err(|e| match e {
-                    InvokeError::Downstream(runtime_error) => runtime_error,
-                    InvokeError::Error(e) => {
-                        RuntimeError::ApplicationError(ApplicationError::AuthZoneError(e))
-                    }
-                })?;
+            let proof = auth_zone_stack.cur_auth_zone().create_proof_by_amount(
+                self.amount,
+                self.resource_address,
+                resource_type,
+            )?;
 
             proof
         };
@@ -339,15 +328,11 @@ impl NativeProcedure for AuthZoneCreateProofByIdsInvocation {
         let proof = {
             let substate_ref = api.get_ref(auth_zone_handle)?;
             let auth_zone_stack = substate_ref.auth_zone_stack();
-            let proof = auth_zone_stack
-                .cur_auth_zone()
-                .create_proof_by_ids(&self.ids, self.resource_address, resource_type)
-                .map_err(|e| match e {
-                    InvokeError::Downstream(runtime_error) => runtime_error,
-                    InvokeError::Error(e) => {
-                        RuntimeError::ApplicationError(ApplicationError::AuthZoneError(e))
-                    }
-                })?;
+            let proof = auth_zone_stack.cur_auth_zone().create_proof_by_ids(
+                &self.ids,
+                self.resource_address,
+                resource_type,
+            )?;
 
             proof
         };
@@ -455,6 +440,7 @@ impl NativeProcedure for AuthZoneDrainInvocation {
             proof_ids,
             CallFrameUpdate {
                 nodes_to_move,
+                nodes_to_borrow: vec![],
                 node_refs_to_copy: HashSet::new(),
             },
         ))
@@ -494,18 +480,20 @@ impl NativeProcedure for AuthZoneAssertAccessRuleInvocation {
         let node_id = RENodeId::AuthZoneStack(self.receiver);
         let offset = SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack);
         let handle = api.lock_substate(node_id, offset, LockFlags::read_only())?;
-        let substate_ref = api.get_ref(handle)?;
-        let auth_zone_stack = substate_ref.auth_zone_stack();
         let authorization = convert(&Type::Any, &IndexedScryptoValue::unit(), &self.access_rule);
+        let (result, proofs_examined) = {
+            let substate_ref = api.get_ref(handle)?;
+            let auth_zone_stack = substate_ref.auth_zone_stack();
+            auth_zone_stack.check_auth_counting_proofs(false, vec![authorization])
+        };
+        api.consume_auth_check_cost(proofs_examined)?;
 
         // Authorization check
-        auth_zone_stack
-            .check_auth(false, vec![authorization])
-            .map_err(|(authorization, error)| {
-                RuntimeError::ApplicationError(ApplicationError::AuthZoneError(
-                    AuthZoneError::AssertAccessRuleError(authorization, error),
-                ))
-            })?;
+        result.map_err(|(authorization, error)| {
+            RuntimeError::ApplicationError(ApplicationError::AuthZoneError(
+                AuthZoneError::AssertAccessRuleError(authorization, error),
+            ))
+        })?;
 
         api.drop_lock(handle)?;
 
@@ -1,13 +1,16 @@
 use crate::model::MethodAuthorizationError::NotAuthorized;
 use crate::model::{
     AuthZoneError, HardAuthRule, HardCount, HardDecimal, HardProofRule, HardProofRuleResourceList,
-    HardResourceOrNonFungible, InvokeError, MethodAuthorization, MethodAuthorizationError,
-    ProofSubstate,
+    HardResourceOrNonFungible, IntoInvokeError, InvokeError, MethodAuthorization,
+    MethodAuthorizationError, ProofSubstate,
 };
 use crate::types::*;
 use sbor::rust::ops::Fn;
 
-struct AuthVerification;
+/// Verifies method authorizations against an auth zone stack, counting the proofs it examines.
+struct AuthVerification {
+    proofs_examined: u32,
+}
 
 impl AuthVerification {
     pub fn proof_matches(resource_rule: &HardResourceOrNonFungible, proof: &ProofSubstate) -> bool {
@@ -24,11 +27,12 @@ impl AuthVerification {
                 let proof_resource_address = proof.resource_address();
                 proof_resource_address == *resource_address
             }
-            HardResourceOrNonFungible::SoftResourceNotFound => false,
+            HardResourceOrNonFungible::SoftResourceNotFound(..) => false,
         }
     }
 
     pub fn check_auth_zones<P>(
+        &mut self,
         mut barriers_crossings_allowed: u32,
         auth_zones: &AuthZoneStackSubstate,
         check: P,
@@ -37,6 +41,7 @@ impl AuthVerification {
         P: Fn(&AuthZone) -> bool,
     {
         for auth_zone in auth_zones.auth_zones.iter().rev() {
+            self.proofs_examined += auth_zone.proofs.len() as u32;
             if check(auth_zone) {
                 return true;
             }
@@ -53,12 +58,13 @@ impl AuthVerification {
     }
 
     pub fn check_has_amount(
+        &mut self,
         barrier_crossings_allowed: u32,
         resource_rule: &HardResourceOrNonFungible,
         amount: Decimal,
         auth_zone: &AuthZoneStackSubstate,
     ) -> bool {
-        Self::check_auth_zones(barrier_crossings_allowed, auth_zone, |auth_zone| {
+        self.check_auth_zones(barrier_crossings_allowed, auth_zone, |auth_zone| {
             // FIXME: Need to check the composite max amount rather than just each proof individually
             auth_zone
                 .proofs
@@ -68,11 +74,12 @@ impl AuthVerification {
     }
 
     pub fn verify_resource_rule(
+        &mut self,
         barrier_crossings_allowed: u32,
         resource_rule: &HardResourceOrNonFungible,
         auth_zone: &AuthZoneStackSubstate,
     ) -> bool {
-        Self::check_auth_zones(barrier_crossings_allowed, auth_zone, |auth_zone| {
+        self.check_auth_zones(barrier_crossings_allowed, auth_zone, |auth_zone| {
             if let HardResourceOrNonFungible::NonFungible(non_fungible_address) = resource_rule {
                 if auth_zone
                     .virtual_non_fungibles
@@ -101,20 +108,21 @@ impl AuthVerification {
     }
 
     pub fn verify_proof_rule(
+        &mut self,
         barrier_crossings_allowed: u32,
         proof_rule: &HardProofRule,
         auth_zone: &AuthZoneStackSubstate,
     ) -> Result<(), MethodAuthorizationError> {
         match proof_rule {
             HardProofRule::Require(resource) => {
-                if Self::verify_resource_rule(barrier_crossings_allowed, resource, auth_zone) {
+                if self.verify_resource_rule(barrier_crossings_allowed, resource, auth_zone) {
                     Ok(())
                 } else {
                     Err(NotAuthorized)
                 }
             }
             HardProofRule::AmountOf(HardDecimal::Amount(amount), resource) => {
-                if Self::check_has_amount(barrier_crossings_allowed, resource, *amount, auth_zone) {
+                if self.check_has_amount(barrier_crossings_allowed, resource, *amount, auth_zone) {
                     Ok(())
                 } else {
                     Err(NotAuthorized)
@@ -122,7 +130,7 @@ impl AuthVerification {
             }
             HardProofRule::AllOf(HardProofRuleResourceList::List(resources)) => {
                 for resource in resources {
-                    if !Self::verify_resource_rule(barrier_crossings_allowed, resource, auth_zone) {
+                    if !self.verify_resource_rule(barrier_crossings_allowed, resource, auth_zone) {
                         return Err(NotAuthorized);
                     }
                 }
@@ -131,7 +139,7 @@ impl AuthVerification {
             }
             HardProofRule::AnyOf(HardProofRuleResourceList::List(resources)) => {
                 for resource in resources {
-                    if Self::verify_resource_rule(barrier_crossings_allowed, resource, auth_zone) {
+                    if self.verify_resource_rule(barrier_crossings_allowed, resource, auth_zone) {
                         return Ok(());
                     }
                 }
@@ -144,7 +152,7 @@ impl AuthVerification {
             ) => {
                 let mut left = count.clone();
                 for resource in resources {
-                    if Self::verify_resource_rule(barrier_crossings_allowed, resource, auth_zone) {
+                    if self.verify_resource_rule(barrier_crossings_allowed, resource, auth_zone) {
                         left -= 1;
                         if left == 0 {
                             return Ok(());
@@ -158,17 +166,19 @@ impl AuthVerification {
     }
 
     pub fn verify_auth_rule(
+        &mut self,
         barrier_crossings_allowed: u32,
         auth_rule: &HardAuthRule,
         auth_zone: &AuthZoneStackSubstate,
     ) -> Result<(), MethodAuthorizationError> {
         match auth_rule {
             HardAuthRule::ProofRule(rule) => {
-                Self::verify_proof_rule(barrier_crossings_allowed, rule, auth_zone)
+                self.verify_proof_rule(barrier_crossings_allowed, rule, auth_zone)
             }
             HardAuthRule::AnyOf(rules) => {
                 if !rules.iter().any(|r| {
-                    Self::verify_auth_rule(barrier_crossings_allowed, r, auth_zone).is_ok()
+                    self.verify_auth_rule(barrier_crossings_allowed, r, auth_zone)
+                        .is_ok()
                 }) {
                     return Err(NotAuthorized);
                 }
@@ -176,7 +186,8 @@ impl AuthVerification {
             }
             HardAuthRule::AllOf(rules) => {
                 if rules.iter().any(|r| {
-                    Self::verify_auth_rule(barrier_crossings_allowed, r, auth_zone).is_err()
+                    self.verify_auth_rule(barrier_crossings_allowed, r, auth_zone)
+                        .is_err()
                 }) {
                     return Err(NotAuthorized);
                 }
@@ -186,13 +197,17 @@ impl AuthVerification {
     }
 
     pub fn verify_method_auth(
+        &mut self,
         barrier_crossings_allowed: u32,
         method_auth: &MethodAuthorization,
         auth_zone: &AuthZoneStackSubstate,
     ) -> Result<(), MethodAuthorizationError> {
         match method_auth {
             MethodAuthorization::Protected(rule) => {
-                Self::verify_auth_rule(barrier_crossings_allowed, rule, auth_zone)
+                if let Some(error) = rule.unresolved_soft_rule() {
+                    return Err(error);
+                }
+                self.verify_auth_rule(barrier_crossings_allowed, rule, auth_zone)
             }
             MethodAuthorization::AllowAll => Ok(()),
             MethodAuthorization::DenyAll => Err(NotAuthorized),
@@ -205,6 +220,8 @@ impl AuthVerification {
 #[derive(Debug)]
 pub struct AuthZoneStackSubstate {
     auth_zones: Vec<AuthZone>,
+    /// The max number of proofs a single auth zone may hold
+    max_proofs: usize,
 }
 
 impl AuthZoneStackSubstate {
@@ -212,6 +229,7 @@ impl AuthZoneStackSubstate {
         proofs: Vec<ProofSubstate>,
         virtual_resources: BTreeSet<ResourceAddress>,
         virtual_non_fungibles: BTreeSet<NonFungibleAddress>,
+        max_proofs: usize,
     ) -> Self {
         Self {
             auth_zones: vec![AuthZone::new_with_virtual_proofs(
@@ -220,6 +238,7 @@ impl AuthZoneStackSubstate {
                 virtual_non_fungibles,
                 false,
             )],
+            max_proofs,
         }
     }
 
@@ -228,19 +247,55 @@ impl AuthZoneStackSubstate {
         is_barrier: bool,
         method_auths: Vec<MethodAuthorization>,
     ) -> Result<(), (MethodAuthorization, MethodAuthorizationError)> {
+        self.check_auth_counting_proofs(is_barrier, method_auths).0
+    }
+
+    /// Checks the method authorizations, also returning the number of proofs examined,
+    /// which the caller is expected to charge for.
+    pub fn check_auth_counting_proofs(
+        &self,
+        is_barrier: bool,
+        method_auths: Vec<MethodAuthorization>,
+    ) -> (
+        Result<(), (MethodAuthorization, MethodAuthorizationError)>,
+        u32,
+    ) {
         let mut barrier_crossings_allowed = 1u32;
         if is_barrier {
             barrier_crossings_allowed -= 1;
         }
 
+        let mut verification = AuthVerification { proofs_examined: 0 };
         for method_auth in method_auths {
-            AuthVerification::verify_method_auth(barrier_crossings_allowed, &method_auth, &self)
-                .map_err(|e| (method_auth, e))?;
+            if let Err(e) =
+                verification.verify_method_auth(barrier_crossings_allowed, &method_auth, &self)
+            {
+                return (Err((method_auth, e)), verification.proofs_examined);
+            }
         }
 
+        (Ok(()), verification.proofs_examined)
+    }
+
+    /// Pushes a proof onto the current auth zone, failing if the auth zone is full.
+    pub fn push_proof(&mut self, proof: ProofSubstate) -> Result<(), AuthZoneError> {
+        let max_proofs = self.max_proofs;
+        let auth_zone = self.cur_auth_zone_mut();
+        if auth_zone.proofs.len() >= max_proofs {
+            return Err(AuthZoneError::ProofLimitExceeded { limit: max_proofs });
+        }
+        auth_zone.push(proof);
         Ok(())
     }
 
+    /// Returns the resources which virtual proofs can be created for, across all auth zones
+    pub fn virtual_proof_resources(&self) -> BTreeSet<ResourceAddress> {
+        self.auth_zones
+            .iter()
+            .flat_map(|auth_zone| auth_zone.virtual_resources.iter().cloned())
+            .collect()
+    }
+
     pub fn new_frame(&mut self, barrier: bool) {
         let auth_zone = AuthZone::empty(barrier);
         self.auth_zones.push(auth_zone);
@@ -300,6 +355,10 @@ impl AuthZone {
         }
     }
 
+    pub fn add_virtual_non_fungible(&mut self, non_fungible_address: NonFungibleAddress) {
+        self.virtual_non_fungibles.insert(non_fungible_address);
+    }
+
     pub fn pop(&mut self) -> Result<ProofSubstate, InvokeError<AuthZoneError>> {
         if self.proofs.is_empty() {
             return Err(InvokeError::Error(AuthZoneError::EmptyAuthZone));
@@ -332,7 +391,7 @@ impl AuthZone {
         resource_type: ResourceType,
     ) -> Result<ProofSubstate, InvokeError<AuthZoneError>> {
         ProofSubstate::compose(&self.proofs, resource_address, resource_type)
-            .map_err(|e| InvokeError::Error(AuthZoneError::ProofError(e)))
+            .into_invoke::<AuthZoneError>()
     }
 
     pub fn create_proof_by_amount(
@@ -342,7 +401,7 @@ impl AuthZone {
         resource_type: ResourceType,
     ) -> Result<ProofSubstate, InvokeError<AuthZoneError>> {
         ProofSubstate::compose_by_amount(&self.proofs, amount, resource_address, resource_type)
-            .map_err(|e| InvokeError::Error(AuthZoneError::ProofError(e)))
+            .into_invoke::<AuthZoneError>()
     }
 
     pub fn create_proof_by_ids(
@@ -353,7 +412,7 @@ impl AuthZone {
     ) -> Result<ProofSubstate, InvokeError<AuthZoneError>> {
         let maybe_existing_proof =
             ProofSubstate::compose_by_ids(&self.proofs, ids, resource_address, resource_type)
-                .map_err(|e| InvokeError::Error(AuthZoneError::ProofError(e)));
+                .into_invoke::<AuthZoneError>();
 
         let proof = match maybe_existing_proof {
             Ok(proof) => proof,
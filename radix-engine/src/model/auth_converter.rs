@@ -22,12 +22,12 @@ fn soft_to_hard_decimal(
                 match sbor_path.get_from_value(&value.dom) {
                     Some(SborValue::Custom { value }) => match value {
                         ScryptoCustomValue::Decimal(v) => HardDecimal::Amount(v.clone()),
-                        _ => HardDecimal::SoftDecimalNotFound,
+                        _ => HardDecimal::SoftDecimalNotFound(schema_path.clone()),
                     },
-                    _ => HardDecimal::SoftDecimalNotFound,
+                    _ => HardDecimal::SoftDecimalNotFound(schema_path.clone()),
                 }
             } else {
-                return HardDecimal::SoftDecimalNotFound;
+                return HardDecimal::SoftDecimalNotFound(schema_path.clone());
             }
         }
     }
@@ -44,10 +44,10 @@ fn soft_to_hard_count(
             if let Some(sbor_path) = schema_path.to_sbor_path(schema) {
                 match sbor_path.get_from_value(&value.dom) {
                     Some(SborValue::U8 { value }) => HardCount::Count(value.clone()),
-                    _ => HardCount::SoftCountNotFound,
+                    _ => HardCount::SoftCountNotFound(schema_path.clone()),
                 }
             } else {
-                return HardCount::SoftCountNotFound;
+                return HardCount::SoftCountNotFound(schema_path.clone());
             }
         }
     }
@@ -106,12 +106,14 @@ fn soft_to_hard_resource_list(
                                     .collect(),
                             )
                         }
-                        _ => HardProofRuleResourceList::SoftResourceListNotFound,
+                        _ => {
+                            HardProofRuleResourceList::SoftResourceListNotFound(schema_path.clone())
+                        }
                     },
-                    _ => HardProofRuleResourceList::SoftResourceListNotFound,
+                    _ => HardProofRuleResourceList::SoftResourceListNotFound(schema_path.clone()),
                 }
             } else {
-                return HardProofRuleResourceList::SoftResourceListNotFound;
+                return HardProofRuleResourceList::SoftResourceListNotFound(schema_path.clone());
             }
         }
     }
@@ -128,12 +130,12 @@ fn soft_to_hard_resource(
                 match sbor_path.get_from_value(&value.dom) {
                     Some(SborValue::Custom { value }) => match value {
                         ScryptoCustomValue::ResourceAddress(address) => address.clone().into(),
-                        _ => HardResourceOrNonFungible::SoftResourceNotFound,
+                        _ => HardResourceOrNonFungible::SoftResourceNotFound(schema_path.clone()),
                     },
-                    _ => HardResourceOrNonFungible::SoftResourceNotFound,
+                    _ => HardResourceOrNonFungible::SoftResourceNotFound(schema_path.clone()),
                 }
             } else {
-                return HardResourceOrNonFungible::SoftResourceNotFound;
+                return HardResourceOrNonFungible::SoftResourceNotFound(schema_path.clone());
             }
         }
         SoftResource::Static(resource_def_id) => {
@@ -154,12 +156,12 @@ fn soft_to_hard_resource_or_non_fungible(
                     Some(SborValue::Custom { value }) => match value {
                         ScryptoCustomValue::ResourceAddress(address) => address.clone().into(),
                         ScryptoCustomValue::NonFungibleAddress(address) => address.clone().into(),
-                        _ => HardResourceOrNonFungible::SoftResourceNotFound,
+                        _ => HardResourceOrNonFungible::SoftResourceNotFound(schema_path.clone()),
                     },
-                    _ => HardResourceOrNonFungible::SoftResourceNotFound,
+                    _ => HardResourceOrNonFungible::SoftResourceNotFound(schema_path.clone()),
                 }
             } else {
-                return HardResourceOrNonFungible::SoftResourceNotFound;
+                return HardResourceOrNonFungible::SoftResourceNotFound(schema_path.clone());
             }
         }
         SoftResourceOrNonFungible::StaticNonFungible(non_fungible_address) => {
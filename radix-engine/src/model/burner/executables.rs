@@ -0,0 +1,264 @@
+use crate::engine::{
+    deref_and_update, CallFrameUpdate, ExecutableInvocation, LockFlags, NativeExecutor,
+    NativeProcedure, REActor, RENode, ResolvedFunction, ResolvedMethod, ResolverApi, RuntimeError,
+    SystemApi,
+};
+use crate::model::{
+    AuthZoneStackSubstate, BucketSubstate, BurnerSubstate, GlobalAddressSubstate, IntoInvokeError,
+    ResourceOperationError,
+};
+use crate::types::*;
+use crate::wasm::WasmEngine;
+use radix_engine_constants::DEFAULT_MAX_AUTH_ZONE_PROOFS;
+use radix_engine_interface::api::api::{EngineApi, InvokableModel, LoggerApi};
+use radix_engine_interface::api::types::{
+    AccessRulesChainOffset, AuthZoneStackOffset, BucketOffset, BurnerFunction, BurnerMethod,
+    BurnerOffset, GlobalAddress, Level, NativeFunction, NativeMethod, RENodeId,
+    ResourceManagerMethod, SubstateOffset,
+};
+use radix_engine_interface::model::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum BurnerError {
+    ResourceOperationError(ResourceOperationError),
+}
+
+impl From<ResourceOperationError> for BurnerError {
+    fn from(error: ResourceOperationError) -> Self {
+        Self::ResourceOperationError(error)
+    }
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for BurnerCreateInvocation {
+    type Exec = NativeExecutor<Self>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        _deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let actor = REActor::Function(ResolvedFunction::Native(NativeFunction::Burner(
+            BurnerFunction::Create,
+        )));
+        let call_frame_update = CallFrameUpdate::empty();
+        let executor = NativeExecutor(self);
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for BurnerCreateInvocation {
+    type Output = SystemAddress;
+
+    fn main<Y>(self, system_api: &mut Y) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError>,
+    {
+        let underlying_node_id = system_api.allocate_node_id(RENodeType::Burner)?;
+        system_api.create_node(
+            underlying_node_id,
+            RENode::Burner(BurnerSubstate {
+                locked: BTreeMap::new(),
+            }),
+        )?;
+
+        let global_node_id = system_api.allocate_node_id(RENodeType::GlobalBurner)?;
+        system_api.create_node(
+            global_node_id,
+            RENode::Global(GlobalAddressSubstate::Burner(underlying_node_id.into())),
+        )?;
+
+        let system_address: SystemAddress = global_node_id.into();
+        let mut node_refs_to_copy = HashSet::new();
+        node_refs_to_copy.insert(global_node_id);
+
+        let update = CallFrameUpdate {
+            node_refs_to_copy,
+            nodes_to_move: vec![],
+            nodes_to_borrow: vec![],
+        };
+
+        Ok((system_address, update))
+    }
+}
+
+pub struct BurnerDepositExecutable(RENodeId, Vec<Bucket>);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for BurnerDepositInvocation {
+    type Exec = NativeExecutor<BurnerDepositExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::move_node(RENodeId::Bucket(self.bucket.0));
+        let receiver = RENodeId::Global(GlobalAddress::System(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::Burner(BurnerMethod::Deposit)),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(BurnerDepositExecutable(
+            resolved_receiver.receiver,
+            vec![self.bucket],
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for BurnerDepositBatchInvocation {
+    type Exec = NativeExecutor<BurnerDepositExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        for bucket in &self.buckets {
+            call_frame_update
+                .nodes_to_move
+                .push(RENodeId::Bucket(bucket.0));
+        }
+        let receiver = RENodeId::Global(GlobalAddress::System(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::Burner(BurnerMethod::DepositBatch)),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(BurnerDepositExecutable(
+            resolved_receiver.receiver,
+            self.buckets,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for BurnerDepositExecutable {
+    type Output = ();
+
+    fn main<Y>(self, system_api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError> + LoggerApi<RuntimeError>,
+    {
+        let node_id = self.0;
+
+        // The burner presents its own badge to the resource managers it burns through
+        let auth_zone_id = system_api
+            .get_visible_node_ids()?
+            .into_iter()
+            .find(|e| matches!(e, RENodeId::AuthZoneStack(..)))
+            .unwrap();
+        let handle = system_api.lock_substate(
+            auth_zone_id,
+            SubstateOffset::AuthZoneStack(AuthZoneStackOffset::AuthZoneStack),
+            LockFlags::MUTABLE,
+        )?;
+        {
+            let mut substate_mut = system_api.get_ref_mut(handle)?;
+            substate_mut
+                .auth_zone_stack()
+                .cur_auth_zone_mut()
+                .add_virtual_non_fungible(burner_badge());
+        }
+        system_api.drop_lock(handle)?;
+
+        for bucket in self.1 {
+            let resource_address = {
+                let handle = system_api.lock_substate(
+                    RENodeId::Bucket(bucket.0),
+                    SubstateOffset::Bucket(BucketOffset::Bucket),
+                    LockFlags::read_only(),
+                )?;
+                let substate_ref = system_api.get_ref(handle)?;
+                let resource_address = substate_ref.bucket().resource_address();
+                system_api.drop_lock(handle)?;
+                resource_address
+            };
+
+            if Self::is_burnable(resource_address, system_api)? {
+                system_api.invoke(ResourceManagerBurnInvocation {
+                    receiver: resource_address,
+                    bucket,
+                })?;
+            } else {
+                let bucket: BucketSubstate =
+                    system_api.drop_node(RENodeId::Bucket(bucket.0))?.into();
+                let amount = bucket.total_amount();
+                let resource = bucket.resource().into_invoke::<BurnerError>()?;
+
+                let handle = system_api.lock_substate(
+                    node_id,
+                    SubstateOffset::Burner(BurnerOffset::Burner),
+                    LockFlags::MUTABLE,
+                )?;
+                {
+                    let mut substate_mut = system_api.get_ref_mut(handle)?;
+                    let locked = &mut substate_mut.burner().locked;
+                    if let Some(existing) = locked.get_mut(&resource_address) {
+                        existing.put(resource).into_invoke::<BurnerError>()?;
+                    } else {
+                        locked.insert(resource_address, resource);
+                    }
+                }
+                system_api.drop_lock(handle)?;
+
+                system_api.emit_log(
+                    Level::Info,
+                    format!(
+                        "Resource {:?} is not burnable by the burner, locked {} of it permanently",
+                        resource_address, amount
+                    ),
+                )?;
+            }
+        }
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+impl BurnerDepositExecutable {
+    /// Whether the burn rule of the resource is satisfied by nothing but the burner badge
+    fn is_burnable<Y>(
+        resource_address: ResourceAddress,
+        system_api: &mut Y,
+    ) -> Result<bool, RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let handle = system_api.lock_substate(
+            RENodeId::Global(GlobalAddress::Resource(resource_address)),
+            SubstateOffset::AccessRulesChain(AccessRulesChainOffset::AccessRulesChain),
+            LockFlags::read_only(),
+        )?;
+        let substate_ref = system_api.get_ref(handle)?;
+        let auths = substate_ref
+            .access_rules_chain()
+            .native_fn_authorization(NativeFn::Method(NativeMethod::ResourceManager(
+                ResourceManagerMethod::Burn,
+            )));
+        system_api.drop_lock(handle)?;
+
+        let mut virtual_non_fungibles = BTreeSet::new();
+        virtual_non_fungibles.insert(burner_badge());
+        let auth_zone = AuthZoneStackSubstate::new(
+            vec![],
+            BTreeSet::new(),
+            virtual_non_fungibles,
+            DEFAULT_MAX_AUTH_ZONE_PROOFS,
+        );
+        Ok(auth_zone.check_auth(false, auths).is_ok())
+    }
+}
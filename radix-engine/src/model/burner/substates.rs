@@ -0,0 +1,9 @@
+use crate::model::Resource;
+use crate::types::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct BurnerSubstate {
+    /// Deposits of resources which the burner is not allowed to burn, locked away for good
+    pub locked: BTreeMap<ResourceAddress, Resource>,
+}
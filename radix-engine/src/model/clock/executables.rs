@@ -99,6 +99,7 @@ impl NativeProcedure for ClockCreateInvocation {
         let update = CallFrameUpdate {
             node_refs_to_copy,
             nodes_to_move: vec![],
+            nodes_to_borrow: vec![],
         };
 
         Ok((system_address, update))
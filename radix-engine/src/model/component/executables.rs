@@ -217,6 +217,91 @@ impl NativeProcedure for ComponentSetRoyaltyConfigInvocation {
     }
 }
 
+impl<W: WasmEngine> ExecutableInvocation<W> for ComponentSetAuthMutabilityInvocation {
+    type Exec = NativeExecutor<Self>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = self.receiver;
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::Component(ComponentMethod::SetAuthMutability)),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(Self {
+            receiver: resolved_receiver.receiver,
+            access_rule: self.access_rule,
+        });
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for ComponentSetAuthMutabilityInvocation {
+    type Output = ();
+
+    fn main<Y>(self, system_api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let offset = SubstateOffset::Component(ComponentOffset::Info);
+        let handle = system_api.lock_substate(self.receiver, offset, LockFlags::MUTABLE)?;
+
+        let mut substate_mut = system_api.get_ref_mut(handle)?;
+        substate_mut.component_info().auth_mutability = self.access_rule;
+
+        system_api.drop_lock(handle)?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for ComponentStateSizeBytesInvocation {
+    type Exec = NativeExecutor<Self>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = self.receiver;
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::Component(ComponentMethod::StateSizeBytes)),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(Self {
+            receiver: resolved_receiver.receiver,
+        });
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for ComponentStateSizeBytesInvocation {
+    type Output = usize;
+
+    fn main<Y>(self, system_api: &mut Y) -> Result<(usize, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let offset = SubstateOffset::Component(ComponentOffset::State);
+        let handle = system_api.lock_substate(self.receiver, offset, LockFlags::read_only())?;
+
+        let substate_ref = system_api.get_ref(handle)?;
+        let size = substate_ref.component_state().raw.len();
+
+        system_api.drop_lock(handle)?;
+
+        Ok((size, CallFrameUpdate::empty()))
+    }
+}
+
 impl<W: WasmEngine> ExecutableInvocation<W> for ComponentClaimRoyaltyInvocation {
     type Exec = NativeExecutor<Self>;
 
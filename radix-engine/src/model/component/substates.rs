@@ -17,6 +17,9 @@ impl ComponentStateSubstate {
 pub struct ComponentInfoSubstate {
     pub package_address: PackageAddress,
     pub blueprint_name: String,
+    /// The rule which must be satisfied to modify the access rules of the component once
+    /// it has been globalized.
+    pub auth_mutability: AccessRule,
 }
 
 impl ComponentInfoSubstate {
@@ -24,6 +27,7 @@ impl ComponentInfoSubstate {
         Self {
             package_address,
             blueprint_name,
+            auth_mutability: AccessRule::DenyAll,
         }
     }
 }
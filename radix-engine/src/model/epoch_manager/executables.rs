@@ -98,13 +98,14 @@ impl NativeProcedure for EpochManagerCreateInvocation {
         let update = CallFrameUpdate {
             node_refs_to_copy,
             nodes_to_move: vec![],
+            nodes_to_borrow: vec![],
         };
 
         Ok((system_address, update))
     }
 }
 
-pub struct EpochManagerGetCurrentEpochExecutable(RENodeId);
+pub struct EpochManagerGetCurrentEpochExecutable;
 
 impl<W: WasmEngine> ExecutableInvocation<W> for EpochManagerGetCurrentEpochInvocation {
     type Exec = NativeExecutor<EpochManagerGetCurrentEpochExecutable>;
@@ -126,9 +127,7 @@ impl<W: WasmEngine> ExecutableInvocation<W> for EpochManagerGetCurrentEpochInvoc
             )),
             resolved_receiver,
         );
-        let executor = NativeExecutor(EpochManagerGetCurrentEpochExecutable(
-            resolved_receiver.receiver,
-        ));
+        let executor = NativeExecutor(EpochManagerGetCurrentEpochExecutable);
 
         Ok((actor, call_frame_update, executor))
     }
@@ -141,11 +140,8 @@ impl NativeProcedure for EpochManagerGetCurrentEpochExecutable {
     where
         Y: SystemApi,
     {
-        let offset = SubstateOffset::EpochManager(EpochManagerOffset::EpochManager);
-        let handle = system_api.lock_substate(self.0, offset, LockFlags::read_only())?;
-        let substate_ref = system_api.get_ref(handle)?;
-        let epoch_manager = substate_ref.epoch_manager();
-        Ok((epoch_manager.epoch, CallFrameUpdate::empty()))
+        let epoch = system_api.read_epoch()?;
+        Ok((epoch, CallFrameUpdate::empty()))
     }
 }
 
@@ -17,6 +17,12 @@ pub fn resolve_native_function(
         CLOCK_BLUEPRINT => ClockFunction::from_str(function_name)
             .ok()
             .map(NativeFunction::Clock),
+        BURNER_BLUEPRINT => BurnerFunction::from_str(function_name)
+            .ok()
+            .map(NativeFunction::Burner),
+        TIME_LOCKED_VAULT_BLUEPRINT => TimeLockedVaultFunction::from_str(function_name)
+            .ok()
+            .map(NativeFunction::TimeLockedVault),
         RESOURCE_MANAGER_BLUEPRINT => ResourceManagerFunction::from_str(function_name)
             .ok()
             .map(NativeFunction::ResourceManager),
@@ -58,6 +64,17 @@ pub fn resolve_native_method(receiver: RENodeId, method_name: &str) -> Option<Na
                 .ok()
                 .map(NativeMethod::Clock)
         }
+        RENodeId::Burner(_) | RENodeId::Global(GlobalAddress::System(SYS_BURNER_COMPONENT)) => {
+            BurnerMethod::from_str(method_name)
+                .ok()
+                .map(NativeMethod::Burner)
+        }
+        RENodeId::TimeLockedVault(_)
+        | RENodeId::Global(GlobalAddress::System(SystemAddress::TimeLockedVault(_))) => {
+            TimeLockedVaultMethod::from_str(method_name)
+                .ok()
+                .map(NativeMethod::TimeLockedVault)
+        }
         RENodeId::Bucket(_) => BucketMethod::from_str(method_name)
             .ok()
             .map(NativeMethod::Bucket),
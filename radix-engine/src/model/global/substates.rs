@@ -10,6 +10,8 @@ pub enum GlobalAddressSubstate {
     Package(PackageId),
     EpochManager(EpochManagerId),
     Clock(ClockId),
+    Burner(BurnerId),
+    TimeLockedVault(TimeLockedVaultId),
 }
 
 impl GlobalAddressSubstate {
@@ -20,6 +22,8 @@ impl GlobalAddressSubstate {
             GlobalAddressSubstate::Package(id) => RENodeId::Package(*id),
             GlobalAddressSubstate::EpochManager(id) => RENodeId::EpochManager(*id),
             GlobalAddressSubstate::Clock(id) => RENodeId::Clock(*id),
+            GlobalAddressSubstate::Burner(id) => RENodeId::Burner(*id),
+            GlobalAddressSubstate::TimeLockedVault(id) => RENodeId::TimeLockedVault(*id),
         }
     }
 }
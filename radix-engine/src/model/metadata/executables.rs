@@ -1,7 +1,9 @@
 use crate::engine::{
-    deref_and_update, CallFrameUpdate, ExecutableInvocation, InterpreterError, LockFlags,
-    NativeExecutor, NativeProcedure, REActor, ResolvedMethod, ResolverApi, RuntimeError, SystemApi,
+    deref_and_update, ApplicationError, CallFrameUpdate, ExecutableInvocation, InterpreterError,
+    LockFlags, NativeExecutor, NativeProcedure, REActor, ResolvedMethod, ResolverApi, RuntimeError,
+    SystemApi,
 };
+use crate::model::ResourceManagerError;
 use crate::types::*;
 use crate::wasm::WasmEngine;
 use radix_engine_interface::api::api::EngineApi;
@@ -52,6 +54,27 @@ impl NativeProcedure for MetadataSetInvocation {
 
         let mut substate_ref_mut = system_api.get_ref_mut(handle)?;
         let metadata = substate_ref_mut.metadata();
+
+        if let RENodeId::ResourceManager(..) = self.receiver {
+            let result = if !metadata.metadata.contains_key(&self.key)
+                && metadata.metadata.len() >= MAX_RESOURCE_METADATA_ENTRIES
+            {
+                Err(InvalidMetadataReason::TooManyEntries {
+                    limit: MAX_RESOURCE_METADATA_ENTRIES,
+                })
+            } else {
+                validate_resource_metadata_entry(&self.key, &self.value)
+            };
+            if let Err(reason) = result {
+                return Err(RuntimeError::ApplicationError(
+                    ApplicationError::ResourceManagerError(ResourceManagerError::InvalidMetadata {
+                        key: self.key,
+                        reason,
+                    }),
+                ));
+            }
+        }
+
         metadata.metadata.insert(self.key, self.value);
 
         Ok(((), CallFrameUpdate::empty()))
@@ -1,3 +1,4 @@
+use radix_engine_interface::data::SchemaPath;
 use radix_engine_interface::math::Decimal;
 use radix_engine_interface::model::*;
 use radix_engine_interface::scrypto;
@@ -8,19 +9,27 @@ use sbor::*;
 pub enum MethodAuthorizationError {
     NotAuthorized,
     UnsupportedMethod,
+    /// A soft decimal could not be resolved from the component state at the given path
+    SoftDecimalNotFound(SchemaPath),
+    /// A soft count could not be resolved from the component state at the given path
+    SoftCountNotFound(SchemaPath),
+    /// A soft resource could not be resolved from the component state at the given path
+    SoftResourceNotFound(SchemaPath),
+    /// A soft resource list could not be resolved from the component state at the given path
+    SoftResourceListNotFound(SchemaPath),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[scrypto(TypeId, Encode, Decode)]
 pub enum HardDecimal {
     Amount(Decimal),
-    SoftDecimalNotFound,
+    SoftDecimalNotFound(SchemaPath),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, TypeId, Encode, Decode)]
 pub enum HardCount {
     Count(u8),
-    SoftCountNotFound,
+    SoftCountNotFound(SchemaPath),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -28,7 +37,18 @@ pub enum HardCount {
 pub enum HardResourceOrNonFungible {
     NonFungible(NonFungibleAddress),
     Resource(ResourceAddress),
-    SoftResourceNotFound,
+    SoftResourceNotFound(SchemaPath),
+}
+
+impl HardResourceOrNonFungible {
+    fn unresolved_soft_rule(&self) -> Option<MethodAuthorizationError> {
+        match self {
+            HardResourceOrNonFungible::SoftResourceNotFound(path) => {
+                Some(MethodAuthorizationError::SoftResourceNotFound(path.clone()))
+            }
+            _ => None,
+        }
+    }
 }
 
 impl From<NonFungibleAddress> for HardResourceOrNonFungible {
@@ -47,7 +67,20 @@ impl From<ResourceAddress> for HardResourceOrNonFungible {
 #[scrypto(TypeId, Encode, Decode)]
 pub enum HardProofRuleResourceList {
     List(Vec<HardResourceOrNonFungible>),
-    SoftResourceListNotFound,
+    SoftResourceListNotFound(SchemaPath),
+}
+
+impl HardProofRuleResourceList {
+    fn unresolved_soft_rule(&self) -> Option<MethodAuthorizationError> {
+        match self {
+            HardProofRuleResourceList::List(resources) => resources
+                .iter()
+                .find_map(HardResourceOrNonFungible::unresolved_soft_rule),
+            HardProofRuleResourceList::SoftResourceListNotFound(path) => Some(
+                MethodAuthorizationError::SoftResourceListNotFound(path.clone()),
+            ),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -60,6 +93,29 @@ pub enum HardProofRule {
     CountOf(HardCount, HardProofRuleResourceList),
 }
 
+impl HardProofRule {
+    fn unresolved_soft_rule(&self) -> Option<MethodAuthorizationError> {
+        match self {
+            HardProofRule::Require(resource) => resource.unresolved_soft_rule(),
+            HardProofRule::AmountOf(amount, resource) => match amount {
+                HardDecimal::SoftDecimalNotFound(path) => {
+                    Some(MethodAuthorizationError::SoftDecimalNotFound(path.clone()))
+                }
+                HardDecimal::Amount(..) => resource.unresolved_soft_rule(),
+            },
+            HardProofRule::AllOf(resources) | HardProofRule::AnyOf(resources) => {
+                resources.unresolved_soft_rule()
+            }
+            HardProofRule::CountOf(count, resources) => match count {
+                HardCount::SoftCountNotFound(path) => {
+                    Some(MethodAuthorizationError::SoftCountNotFound(path.clone()))
+                }
+                HardCount::Count(..) => resources.unresolved_soft_rule(),
+            },
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[scrypto(TypeId, Encode, Decode)]
 pub enum HardAuthRule {
@@ -68,6 +124,19 @@ pub enum HardAuthRule {
     AllOf(Vec<HardAuthRule>),
 }
 
+impl HardAuthRule {
+    /// Returns the error for the first soft rule which could not be resolved from the component
+    /// state, if any
+    pub fn unresolved_soft_rule(&self) -> Option<MethodAuthorizationError> {
+        match self {
+            HardAuthRule::ProofRule(rule) => rule.unresolved_soft_rule(),
+            HardAuthRule::AnyOf(rules) | HardAuthRule::AllOf(rules) => {
+                rules.iter().find_map(HardAuthRule::unresolved_soft_rule)
+            }
+        }
+    }
+}
+
 /// Authorization of a method call
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[scrypto(TypeId, Encode, Decode)]
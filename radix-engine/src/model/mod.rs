@@ -1,6 +1,7 @@
 mod abi_extractor;
 mod auth;
 mod auth_converter;
+mod burner;
 mod clock;
 mod component;
 mod epoch_manager;
@@ -16,13 +17,15 @@ mod package_extractor;
 mod resources;
 mod scrypto;
 mod substates;
+mod time_locked_vault;
 mod transaction_processor;
 
 pub use self::scrypto::*;
-pub use crate::engine::InvokeError;
+pub use crate::engine::{IntoInvokeError, InvokeError};
 pub use abi_extractor::*;
 pub use auth::*;
 pub use auth_converter::convert;
+pub use burner::*;
 pub use clock::*;
 pub use component::*;
 pub use epoch_manager::*;
@@ -36,4 +39,5 @@ pub use method_authorization::*;
 pub use package::*;
 pub use package_extractor::{extract_abi, ExtractAbiError};
 pub use resources::*;
+pub use time_locked_vault::*;
 pub use transaction_processor::*;
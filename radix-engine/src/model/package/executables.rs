@@ -82,7 +82,7 @@ impl NativeProcedure for PackagePublishInvocation {
             royalty: Resource::new_empty(RADIX_TOKEN, ResourceType::Fungible { divisibility: 18 }),
         };
         let metadata_substate = MetadataSubstate {
-            metadata: self.metadata,
+            metadata: BTreeMap::new(),
         };
         let access_rules = AccessRulesChainSubstate {
             access_rules_chain: vec![self.access_rules],
@@ -106,6 +106,9 @@ impl NativeProcedure for PackagePublishInvocation {
                 access_rules,
             ),
         )?;
+        for (key, value) in self.metadata {
+            api.set_metadata(node_id, key, value)?;
+        }
         let package_id: PackageId = node_id.into();
 
         // Globalize
@@ -162,7 +165,7 @@ impl NativeProcedure for PackagePublishWithOwnerInvocation {
             royalty: Resource::new_empty(RADIX_TOKEN, ResourceType::Fungible { divisibility: 18 }),
         };
         let metadata_substate = MetadataSubstate {
-            metadata: self.metadata,
+            metadata: BTreeMap::new(),
         };
 
         let global_node_id = api.allocate_node_id(RENodeType::GlobalPackage)?;
@@ -213,6 +216,9 @@ impl NativeProcedure for PackagePublishWithOwnerInvocation {
                 },
             ),
         )?;
+        for (key, value) in self.metadata {
+            api.set_metadata(node_id, key, value)?;
+        }
         let package_id: PackageId = node_id.into();
 
         // Globalize
@@ -331,3 +337,54 @@ impl NativeProcedure for PackageClaimRoyaltyExecutable {
         ))
     }
 }
+
+impl<W: WasmEngine> ExecutableInvocation<W> for PackageGetBuildFingerprintInvocation {
+    type Exec = NativeExecutor<PackageGetBuildFingerprintExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        api: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::Package(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, api)?;
+
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::Package(PackageMethod::GetBuildFingerprint)),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(PackageGetBuildFingerprintExecutable {
+            receiver: resolved_receiver.receiver,
+        });
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for PackageGetBuildFingerprintExecutable {
+    type Output = Option<BuildFingerprint>;
+
+    fn main<Y>(
+        self,
+        system_api: &mut Y,
+    ) -> Result<(Option<BuildFingerprint>, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let offset = SubstateOffset::Package(PackageOffset::Info);
+        let handle = system_api.lock_substate(self.receiver, offset, LockFlags::read_only())?;
+
+        let substate_ref = system_api.get_ref(handle)?;
+        let fingerprint = WasmModule::init(&substate_ref.package_info().code)
+            .and_then(|module| module.build_fingerprint())
+            .map_err(|e| {
+                RuntimeError::ApplicationError(ApplicationError::PackageError(
+                    PackageError::InvalidWasm(e),
+                ))
+            })?;
+
+        system_api.drop_lock(handle)?;
+
+        Ok((fingerprint, CallFrameUpdate::empty()))
+    }
+}
@@ -1,9 +1,8 @@
 use crate::engine::{
-    ApplicationError, CallFrameUpdate, ExecutableInvocation, LockFlags, NativeExecutor,
-    NativeProcedure, REActor, RENode, ResolvedMethod, ResolvedReceiver, ResolverApi, RuntimeError,
-    SystemApi,
+    CallFrameUpdate, ExecutableInvocation, LockFlags, NativeExecutor, NativeProcedure, REActor,
+    RENode, ResolvedMethod, ResolvedReceiver, ResolverApi, RuntimeError, SystemApi,
 };
-use crate::model::{BucketSubstate, ProofError, ResourceOperationError};
+use crate::model::{BucketSubstate, IntoInvokeError, ProofError, ResourceOperationError};
 use crate::types::*;
 use crate::wasm::WasmEngine;
 use radix_engine_interface::api::types::{
@@ -24,6 +23,18 @@ pub enum BucketError {
     MethodNotFound(BucketMethod),
 }
 
+impl From<ResourceOperationError> for BucketError {
+    fn from(error: ResourceOperationError) -> Self {
+        Self::ResourceOperationError(error)
+    }
+}
+
+impl From<ProofError> for BucketError {
+    fn from(error: ProofError) -> Self {
+        Self::ProofError(error)
+    }
+}
+
 impl<W: WasmEngine> ExecutableInvocation<W> for BucketTakeInvocation {
     type Exec = NativeExecutor<Self>;
 
@@ -55,11 +66,7 @@ impl NativeProcedure for BucketTakeInvocation {
 
         let mut substate_mut = api.get_ref_mut(bucket_handle)?;
         let bucket = substate_mut.bucket();
-        let container = bucket.take(self.amount).map_err(|e| {
-            RuntimeError::ApplicationError(ApplicationError::BucketError(
-                BucketError::ResourceOperationError(e),
-            ))
-        })?;
+        let container = bucket.take(self.amount).into_invoke::<BucketError>()?;
 
         let node_id = api.allocate_node_id(RENodeType::Bucket)?;
         api.create_node(node_id, RENode::Bucket(BucketSubstate::new(container)))?;
@@ -102,11 +109,9 @@ impl NativeProcedure for BucketCreateProofInvocation {
 
         let mut substate_mut = api.get_ref_mut(bucket_handle)?;
         let bucket = substate_mut.bucket();
-        let proof = bucket.create_proof(self.receiver).map_err(|e| {
-            RuntimeError::ApplicationError(ApplicationError::BucketError(BucketError::ProofError(
-                e,
-            )))
-        })?;
+        let proof = bucket
+            .create_proof(self.receiver)
+            .into_invoke::<BucketError>()?;
 
         let node_id = api.allocate_node_id(RENodeType::Proof)?;
         api.create_node(node_id, RENode::Proof(proof))?;
@@ -150,11 +155,9 @@ impl NativeProcedure for BucketTakeNonFungiblesInvocation {
 
         let mut substate_mut = api.get_ref_mut(bucket_handle)?;
         let bucket = substate_mut.bucket();
-        let container = bucket.take_non_fungibles(&self.ids).map_err(|e| {
-            RuntimeError::ApplicationError(ApplicationError::BucketError(
-                BucketError::ResourceOperationError(e),
-            ))
-        })?;
+        let container = bucket
+            .take_non_fungibles(&self.ids)
+            .into_invoke::<BucketError>()?;
 
         let node_id = api.allocate_node_id(RENodeType::Bucket)?;
         api.create_node(node_id, RENode::Bucket(BucketSubstate::new(container)))?;
@@ -199,11 +202,7 @@ impl NativeProcedure for BucketGetNonFungibleIdsInvocation {
         let bucket_handle = system_api.lock_substate(node_id, offset, LockFlags::read_only())?;
         let substate_ref = system_api.get_ref(bucket_handle)?;
         let bucket = substate_ref.bucket();
-        let ids = bucket.total_ids().map_err(|e| {
-            RuntimeError::ApplicationError(ApplicationError::BucketError(
-                BucketError::ResourceOperationError(e),
-            ))
-        })?;
+        let ids = bucket.total_ids().into_invoke::<BucketError>()?;
 
         Ok((ids, CallFrameUpdate::empty()))
     }
@@ -281,11 +280,7 @@ impl NativeProcedure for BucketPutInvocation {
             .into();
         let mut substate_mut = system_api.get_ref_mut(bucket_handle)?;
         let bucket = substate_mut.bucket();
-        bucket.put(other_bucket).map_err(|e| {
-            RuntimeError::ApplicationError(ApplicationError::BucketError(
-                BucketError::ResourceOperationError(e),
-            ))
-        })?;
+        bucket.put(other_bucket).into_invoke::<BucketError>()?;
 
         Ok(((), CallFrameUpdate::empty()))
     }
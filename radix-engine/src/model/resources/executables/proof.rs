@@ -1,9 +1,8 @@
 use crate::engine::{
-    ApplicationError, CallFrameUpdate, ExecutableInvocation, LockFlags, NativeExecutor,
-    NativeProcedure, REActor, RENode, ResolvedMethod, ResolvedReceiver, ResolverApi, RuntimeError,
-    SystemApi,
+    CallFrameUpdate, ExecutableInvocation, LockFlags, NativeExecutor, NativeProcedure, REActor,
+    RENode, ResolvedMethod, ResolvedReceiver, ResolverApi, RuntimeError, SystemApi,
 };
-use crate::model::{InvokeError, ResourceOperationError};
+use crate::model::{ResourceContainerId, ResourceOperationError};
 use crate::types::*;
 use crate::wasm::WasmEngine;
 use radix_engine_interface::api::types::{
@@ -28,6 +27,12 @@ pub enum ProofError {
     InvalidRequestData(DecodeError),
 }
 
+impl From<ResourceOperationError> for ProofError {
+    fn from(error: ResourceOperationError) -> Self {
+        Self::ResourceOperationError(error)
+    }
+}
+
 impl<W: WasmEngine> ExecutableInvocation<W> for ProofGetAmountInvocation {
     type Exec = NativeExecutor<Self>;
 
@@ -96,12 +101,7 @@ impl NativeProcedure for ProofGetNonFungibleIdsInvocation {
         let handle = system_api.lock_substate(node_id, offset, LockFlags::read_only())?;
         let substate_ref = system_api.get_ref(handle)?;
         let proof = substate_ref.proof();
-        let ids = proof.total_ids().map_err(|e| match e {
-            InvokeError::Error(e) => {
-                RuntimeError::ApplicationError(ApplicationError::ProofError(e))
-            }
-            InvokeError::Downstream(runtime_error) => runtime_error,
-        })?;
+        let ids = proof.total_ids()?;
 
         Ok((ids, CallFrameUpdate::empty()))
     }
@@ -189,3 +189,56 @@ impl NativeProcedure for ProofCloneInvocation {
         ))
     }
 }
+
+impl<W: WasmEngine> ExecutableInvocation<W> for ProofIsValidInvocation {
+    type Exec = NativeExecutor<Self>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        _api: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let receiver = RENodeId::Proof(self.receiver);
+        let call_frame_update = CallFrameUpdate::copy_ref(receiver);
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::Proof(ProofMethod::IsValid)),
+            ResolvedReceiver::new(receiver),
+        );
+        let executor = NativeExecutor(self);
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for ProofIsValidInvocation {
+    type Output = bool;
+
+    fn main<Y>(self, system_api: &mut Y) -> Result<(bool, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let node_id = RENodeId::Proof(self.receiver);
+        let offset = SubstateOffset::Proof(ProofOffset::Proof);
+        let handle = system_api.lock_substate(node_id, offset, LockFlags::read_only())?;
+        let substate_ref = system_api.get_ref(handle)?;
+        let proof = substate_ref.proof();
+        let container_node_ids: Vec<RENodeId> = proof
+            .evidence
+            .keys()
+            .filter_map(|container_id| match container_id {
+                ResourceContainerId::Bucket(bucket_id) => Some(RENodeId::Bucket(*bucket_id)),
+                ResourceContainerId::Vault(vault_id) => Some(RENodeId::Vault(*vault_id)),
+                ResourceContainerId::Worktop(..) => Some(RENodeId::Worktop),
+                // Synthetic proofs aren't backed by any container
+                ResourceContainerId::ResourceManager(..) => None,
+            })
+            .collect();
+        system_api.drop_lock(handle)?;
+
+        for container_node_id in container_node_ids {
+            if !system_api.node_exists(container_node_id)? {
+                return Ok((false, CallFrameUpdate::empty()));
+            }
+        }
+
+        Ok((true, CallFrameUpdate::empty()))
+    }
+}
@@ -4,8 +4,9 @@ use crate::engine::{
     ResolverApi, RuntimeError, SystemApi,
 };
 use crate::model::{
-    AccessRulesChainSubstate, BucketSubstate, GlobalAddressSubstate, InvokeError, MetadataSubstate,
-    NonFungible, NonFungibleSubstate, Resource, VaultRuntimeSubstate,
+    AccessRulesChainSubstate, BucketSubstate, GlobalAddressSubstate, IntoInvokeError, InvokeError,
+    LockableResource, MetadataSubstate, NonFungible, NonFungibleSubstate, ProofError,
+    ProofSubstate, Resource, ResourceContainerId, ResourceOperationError, VaultRuntimeSubstate,
 };
 use crate::model::{NonFungibleStore, ResourceManagerSubstate};
 use crate::types::*;
@@ -28,7 +29,7 @@ use radix_engine_interface::{dec, rule, scrypto};
 #[scrypto(TypeId, Encode, Decode)]
 pub enum ResourceManagerError {
     InvalidDivisibility,
-    InvalidAmount(Decimal, u8),
+    ResourceOperationError(ResourceOperationError),
     InvalidResourceFlags(u64),
     InvalidMintPermission,
     ResourceTypeDoesNotMatch,
@@ -36,6 +37,8 @@ pub enum ResourceManagerError {
     InvalidNonFungibleData,
     NonFungibleAlreadyExists(NonFungibleAddress),
     NonFungibleNotFound(NonFungibleAddress),
+    NonFungibleAlreadyBurned(NonFungibleId),
+    NonFungibleNotMinted(NonFungibleId),
     InvalidRequestData(DecodeError),
     CouldNotCreateBucket,
     CouldNotCreateVault,
@@ -43,6 +46,17 @@ pub enum ResourceManagerError {
     MismatchingBucketResource,
     ResourceAddressAlreadySet,
     NonFungibleIdTypeDoesNotMatch(NonFungibleIdType, NonFungibleIdType),
+    NonFungibleDataDoesNotMatchSchema(NonFungibleAddress),
+    InvalidMetadata {
+        key: String,
+        reason: InvalidMetadataReason,
+    },
+}
+
+impl From<ResourceOperationError> for ResourceManagerError {
+    fn from(error: ResourceOperationError) -> Self {
+        Self::ResourceOperationError(error)
+    }
 }
 
 impl<W: WasmEngine> ExecutableInvocation<W> for ResourceManagerBucketBurnInvocation {
@@ -119,6 +133,10 @@ impl<W: WasmEngine> ExecutableInvocation<W> for ResourceManagerCreateWithOwnerIn
         );
         access_rules.insert(Mint, (DenyAll, rule!(require(owner_badge.clone()))));
         access_rules.insert(Burn, (DenyAll, rule!(require(owner_badge.clone()))));
+        access_rules.insert(
+            ResourceMethodAuthKey::CreateSyntheticProof,
+            (DenyAll, rule!(require(owner_badge.clone()))),
+        );
         access_rules.insert(
             UpdateNonFungibleData,
             (
@@ -139,6 +157,8 @@ impl<W: WasmEngine> ExecutableInvocation<W> for ResourceManagerCreateWithOwnerIn
             metadata: self.metadata,
             access_rules,
             mint_params: self.mint_params,
+            non_fungible_data_schema: self.non_fungible_data_schema,
+            track_holders: self.track_holders,
         };
 
         let executor = NativeExecutor(invocation);
@@ -150,6 +170,8 @@ fn build_resource_manager_substate<Y>(
     resource_address: ResourceAddress,
     resource_type: ResourceType,
     mint_params: Option<MintParams>,
+    non_fungible_data_schema: Option<NonFungibleDataSchema>,
+    track_holders: bool,
     api: &mut Y,
 ) -> Result<(ResourceManagerSubstate, Option<Bucket>), RuntimeError>
 where
@@ -163,14 +185,13 @@ where
         )?;
         let nf_store_id: NonFungibleStoreId = nf_store_node_id.into();
 
-        let mut resource_manager =
-            ResourceManagerSubstate::new(resource_type, Some(nf_store_id), resource_address)
-                .map_err(|e| match e {
-                    InvokeError::Error(e) => {
-                        RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(e))
-                    }
-                    InvokeError::Downstream(e) => e,
-                })?;
+        let mut resource_manager = ResourceManagerSubstate::new(
+            resource_type,
+            Some(nf_store_id),
+            resource_address,
+            non_fungible_data_schema,
+            track_holders,
+        )?;
 
         let bucket = if let Some(mint_params) = mint_params {
             if let MintParams::NonFungible { entries } = mint_params {
@@ -185,6 +206,7 @@ where
                             ),
                         ));
                     }
+                    resource_manager.check_non_fungible_data(non_fungible_id, &data.0, &data.1)?;
 
                     let offset = SubstateOffset::NonFungibleStore(NonFungibleStoreOffset::Entry(
                         non_fungible_id.clone(),
@@ -193,12 +215,11 @@ where
                         api.lock_substate(nf_store_node_id, offset, LockFlags::MUTABLE)?;
                     let mut substate_mut = api.get_ref_mut(non_fungible_handle)?;
                     let non_fungible_mut = substate_mut.non_fungible();
-                    *non_fungible_mut = NonFungibleSubstate(Some(
-                        NonFungible::new(data.0.clone(), data.1.clone()), // FIXME: verify data
-                    ));
+                    *non_fungible_mut =
+                        NonFungibleSubstate::new(NonFungible::new(data.0.clone(), data.1.clone()));
                     api.drop_lock(non_fungible_handle)?;
                 }
-                resource_manager.total_supply = entries.len().into();
+                resource_manager.set_initial_supply(entries.len().into());
                 let ids = entries.into_keys().collect();
                 let container = Resource::new_non_fungible(resource_address, ids, id_type);
                 let node_id = api.allocate_node_id(RENodeType::Bucket)?;
@@ -218,24 +239,17 @@ where
 
         (resource_manager, bucket)
     } else {
-        let mut resource_manager =
-            ResourceManagerSubstate::new(resource_type, None, resource_address).map_err(
-                |e| match e {
-                    InvokeError::Error(e) => {
-                        RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(e))
-                    }
-                    InvokeError::Downstream(e) => e,
-                },
-            )?;
+        let mut resource_manager = ResourceManagerSubstate::new(
+            resource_type,
+            None,
+            resource_address,
+            None,
+            track_holders,
+        )?;
 
         let bucket = if let Some(mint_params) = mint_params {
             if let MintParams::Fungible { amount } = mint_params {
-                resource_manager.check_amount(amount).map_err(|e| match e {
-                    InvokeError::Error(e) => {
-                        RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(e))
-                    }
-                    InvokeError::Downstream(e) => e,
-                })?;
+                resource_manager.check_amount(amount)?;
                 // TODO: refactor this into mint function
                 if amount > dec!("1000000000000000000") {
                     return Err(RuntimeError::ApplicationError(
@@ -244,7 +258,7 @@ where
                         ),
                     ));
                 }
-                resource_manager.total_supply = amount;
+                resource_manager.set_initial_supply(amount);
                 let container =
                     Resource::new_fungible(resource_address, resource_type.divisibility(), amount);
                 let node_id = api.allocate_node_id(RENodeType::Bucket)?;
@@ -284,6 +298,9 @@ fn build_substates(
     let (update_metadata_access_rule, update_metadata_mutability) = access_rules_map
         .remove(&UpdateMetadata)
         .unwrap_or((DenyAll, rule!(deny_all)));
+    let (create_synthetic_proof_access_rule, create_synthetic_proof_mutability) = access_rules_map
+        .remove(&ResourceMethodAuthKey::CreateSyntheticProof)
+        .unwrap_or((DenyAll, rule!(deny_all)));
 
     let mut access_rules = AccessRules::new();
     access_rules.set_access_rule_and_mutability(
@@ -321,6 +338,13 @@ fn build_substates(
         update_non_fungible_data_access_rule,
         update_non_fungible_data_mutability,
     );
+    access_rules.set_access_rule_and_mutability(
+        AccessRuleKey::Native(NativeFn::Method(NativeMethod::ResourceManager(
+            ResourceManagerMethod::CreateSyntheticProof,
+        ))),
+        create_synthetic_proof_access_rule,
+        create_synthetic_proof_mutability,
+    );
     access_rules.set_access_rule_and_mutability(
         AccessRuleKey::Native(NativeFn::Method(NativeMethod::ResourceManager(
             ResourceManagerMethod::CreateBucket,
@@ -349,6 +373,34 @@ fn build_substates(
         AllowAll,
         DenyAll,
     );
+    access_rules.set_access_rule_and_mutability(
+        AccessRuleKey::Native(NativeFn::Method(NativeMethod::ResourceManager(
+            ResourceManagerMethod::GetSummary,
+        ))),
+        AllowAll,
+        DenyAll,
+    );
+    access_rules.set_access_rule_and_mutability(
+        AccessRuleKey::Native(NativeFn::Method(NativeMethod::ResourceManager(
+            ResourceManagerMethod::GetHolderVaultCount,
+        ))),
+        AllowAll,
+        DenyAll,
+    );
+    access_rules.set_access_rule_and_mutability(
+        AccessRuleKey::Native(NativeFn::Method(NativeMethod::ResourceManager(
+            ResourceManagerMethod::GetAmountMinted,
+        ))),
+        AllowAll,
+        DenyAll,
+    );
+    access_rules.set_access_rule_and_mutability(
+        AccessRuleKey::Native(NativeFn::Method(NativeMethod::ResourceManager(
+            ResourceManagerMethod::GetAmountBurned,
+        ))),
+        AllowAll,
+        DenyAll,
+    );
     access_rules.set_access_rule_and_mutability(
         AccessRuleKey::Native(NativeFn::Method(NativeMethod::ResourceManager(
             ResourceManagerMethod::NonFungibleExists,
@@ -420,6 +472,13 @@ fn build_substates(
         "withdraw".to_string(),
         DenyAll,
     );
+    vault_access_rules.set_group_and_mutability(
+        AccessRuleKey::Native(NativeFn::Method(NativeMethod::Vault(
+            VaultMethod::LockFeeWithCallback,
+        ))),
+        "withdraw".to_string(),
+        DenyAll,
+    );
 
     vault_access_rules.set_access_rule_and_mutability(
         AccessRuleKey::Native(NativeFn::Method(NativeMethod::Vault(VaultMethod::Put))),
@@ -486,6 +545,12 @@ impl NativeProcedure for ResourceManagerCreateInvocation {
     where
         Y: SystemApi,
     {
+        validate_resource_metadata(&self.metadata).map_err(|(key, reason)| {
+            RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(
+                ResourceManagerError::InvalidMetadata { key, reason },
+            ))
+        })?;
+
         let global_node_id = api.allocate_node_id(RENodeType::GlobalResourceManager)?;
         let resource_address: ResourceAddress = global_node_id.into();
 
@@ -493,11 +558,13 @@ impl NativeProcedure for ResourceManagerCreateInvocation {
             resource_address,
             self.resource_type,
             self.mint_params,
+            self.non_fungible_data_schema,
+            self.track_holders,
             api,
         )?;
         let (substate, vault_substate) = build_substates(self.access_rules);
         let metadata_substate = MetadataSubstate {
-            metadata: self.metadata,
+            metadata: BTreeMap::new(),
         };
 
         let underlying_node_id = api.allocate_node_id(RENodeType::ResourceManager)?;
@@ -510,6 +577,9 @@ impl NativeProcedure for ResourceManagerCreateInvocation {
                 vault_substate,
             ),
         )?;
+        for (key, value) in self.metadata {
+            api.set_metadata(underlying_node_id, key, value)?;
+        }
 
         api.create_node(
             global_node_id,
@@ -528,6 +598,7 @@ impl NativeProcedure for ResourceManagerCreateInvocation {
             (resource_address, bucket),
             CallFrameUpdate {
                 nodes_to_move,
+                nodes_to_borrow: vec![],
                 node_refs_to_copy,
             },
         ))
@@ -594,7 +665,7 @@ impl NativeProcedure for ResourceManagerBurnExecutable {
         {
             let mut substate_mut = system_api.get_ref_mut(resman_handle)?;
             let resource_manager = substate_mut.resource_manager();
-            resource_manager.total_supply -= bucket.total_amount();
+            resource_manager.burn(bucket.total_amount());
         }
 
         // Burn non-fungible
@@ -603,17 +674,29 @@ impl NativeProcedure for ResourceManagerBurnExecutable {
         if let Some(nf_store_id) = resource_manager.nf_store_id {
             let node_id = RENodeId::NonFungibleStore(nf_store_id);
 
+            // Check every id before burning any of them, so a batch burn is all or nothing
+            let mut non_fungible_handles = Vec::new();
             for id in bucket
                 .total_ids()
                 .expect("Failed to list non-fungible IDs on non-fungible Bucket")
             {
-                let offset = SubstateOffset::NonFungibleStore(NonFungibleStoreOffset::Entry(id));
+                let offset =
+                    SubstateOffset::NonFungibleStore(NonFungibleStoreOffset::Entry(id.clone()));
                 let non_fungible_handle =
                     system_api.lock_substate(node_id, offset, LockFlags::MUTABLE)?;
-                let mut substate_mut = system_api.get_ref_mut(non_fungible_handle)?;
-                let non_fungible_mut = substate_mut.non_fungible();
+                let substate_ref = system_api.get_ref(non_fungible_handle)?;
+                substate_ref
+                    .non_fungible()
+                    .check_burnable(&id)
+                    .map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(e))
+                    })?;
+                non_fungible_handles.push(non_fungible_handle);
+            }
 
-                *non_fungible_mut = NonFungibleSubstate(None);
+            for non_fungible_handle in non_fungible_handles {
+                let mut substate_mut = system_api.get_ref_mut(non_fungible_handle)?;
+                substate_mut.non_fungible().burn();
                 system_api.drop_lock(non_fungible_handle)?;
             }
         }
@@ -961,14 +1044,7 @@ impl NativeProcedure for ResourceManagerMintExecutable {
         let (resource, non_fungibles) = {
             let mut substate_mut = api.get_ref_mut(resman_handle)?;
             let resource_manager = substate_mut.resource_manager();
-            let result = resource_manager
-                .mint(self.1, resource_manager.resource_address)
-                .map_err(|e| match e {
-                    InvokeError::Error(e) => {
-                        RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(e))
-                    }
-                    InvokeError::Downstream(runtime_error) => runtime_error,
-                })?;
+            let result = resource_manager.mint(self.1, resource_manager.resource_address)?;
             result
         };
 
@@ -995,7 +1071,7 @@ impl NativeProcedure for ResourceManagerMintExecutable {
                 let mut substate_mut = api.get_ref_mut(non_fungible_handle)?;
                 let non_fungible_mut = substate_mut.non_fungible();
 
-                if non_fungible_mut.0.is_some() {
+                if non_fungible_mut.non_fungible.is_some() {
                     return Err(RuntimeError::ApplicationError(
                         ApplicationError::ResourceManagerError(
                             ResourceManagerError::NonFungibleAlreadyExists(
@@ -1005,7 +1081,7 @@ impl NativeProcedure for ResourceManagerMintExecutable {
                     ));
                 }
 
-                *non_fungible_mut = NonFungibleSubstate(Some(non_fungible));
+                *non_fungible_mut = NonFungibleSubstate::new(non_fungible);
             }
 
             api.drop_lock(non_fungible_handle)?;
@@ -1110,6 +1186,319 @@ impl NativeProcedure for ResourceManagerGetTotalSupplyExecutable {
     }
 }
 
+impl<W: WasmEngine> ExecutableInvocation<W> for ResourceManagerGetSummaryInvocation {
+    type Exec = NativeExecutor<ResourceManagerGetSummaryExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        api: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let resolved_receiver = deref_and_update(
+            RENodeId::Global(GlobalAddress::Resource(self.receiver)),
+            &mut call_frame_update,
+            api,
+        )?;
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::ResourceManager(
+                ResourceManagerMethod::GetSummary,
+            )),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(ResourceManagerGetSummaryExecutable(
+            resolved_receiver.receiver,
+        ));
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+pub struct ResourceManagerGetSummaryExecutable(RENodeId);
+
+impl NativeProcedure for ResourceManagerGetSummaryExecutable {
+    type Output = ResourceManagerSummary;
+
+    fn main<'a, Y>(
+        self,
+        system_api: &mut Y,
+    ) -> Result<(ResourceManagerSummary, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let (resource_type, total_supply) = {
+            let offset = SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager);
+            let handle = system_api.lock_substate(self.0, offset, LockFlags::read_only())?;
+            let substate_ref = system_api.get_ref(handle)?;
+            let resource_manager = substate_ref.resource_manager();
+            let rtn = (
+                resource_manager.resource_type,
+                resource_manager.total_supply,
+            );
+            system_api.drop_lock(handle)?;
+            rtn
+        };
+
+        let metadata = {
+            let offset = SubstateOffset::Metadata(MetadataOffset::Metadata);
+            let handle = system_api.lock_substate(self.0, offset, LockFlags::read_only())?;
+            let substate_ref = system_api.get_ref(handle)?;
+            let metadata = substate_ref.metadata().metadata.clone();
+            system_api.drop_lock(handle)?;
+            metadata
+        };
+
+        let (mintable, burnable) = {
+            let offset = SubstateOffset::AccessRulesChain(AccessRulesChainOffset::AccessRulesChain);
+            let handle = system_api.lock_substate(self.0, offset, LockFlags::read_only())?;
+            let substate_ref = system_api.get_ref(handle)?;
+            let access_rules_chain = &substate_ref.access_rules_chain().access_rules_chain;
+            let is_allowed = |method: ResourceManagerMethod| {
+                let key =
+                    AccessRuleKey::Native(NativeFn::Method(NativeMethod::ResourceManager(method)));
+                access_rules_chain
+                    .iter()
+                    .all(|access_rules| !matches!(access_rules.get(&key), AccessRule::DenyAll))
+            };
+            let rtn = (
+                is_allowed(ResourceManagerMethod::Mint),
+                is_allowed(ResourceManagerMethod::Burn),
+            );
+            system_api.drop_lock(handle)?;
+            rtn
+        };
+
+        let summary = ResourceManagerSummary {
+            resource_type,
+            total_supply,
+            metadata,
+            mintable,
+            burnable,
+        };
+
+        Ok((summary, CallFrameUpdate::empty()))
+    }
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for ResourceManagerCreateSyntheticProofInvocation {
+    type Exec = NativeExecutor<ResourceManagerCreateSyntheticProofExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        api: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let resolved_receiver = deref_and_update(
+            RENodeId::Global(GlobalAddress::Resource(self.receiver)),
+            &mut call_frame_update,
+            api,
+        )?;
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::ResourceManager(
+                ResourceManagerMethod::CreateSyntheticProof,
+            )),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(ResourceManagerCreateSyntheticProofExecutable(
+            resolved_receiver.receiver,
+            self.amount,
+        ));
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+pub struct ResourceManagerCreateSyntheticProofExecutable(RENodeId, Decimal);
+
+impl NativeProcedure for ResourceManagerCreateSyntheticProofExecutable {
+    type Output = Proof;
+
+    fn main<'a, Y>(self, system_api: &mut Y) -> Result<(Proof, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let offset = SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager);
+        let resman_handle = system_api.lock_substate(self.0, offset, LockFlags::read_only())?;
+
+        let proof = {
+            let substate_ref = system_api.get_ref(resman_handle)?;
+            let resource_manager = substate_ref.resource_manager();
+            let resource_address = resource_manager.resource_address;
+            let divisibility = match resource_manager.resource_type {
+                ResourceType::Fungible { divisibility } => divisibility,
+                ResourceType::NonFungible { .. } => {
+                    return Err(RuntimeError::ApplicationError(
+                        ApplicationError::ResourceManagerError(
+                            ResourceManagerError::ResourceTypeDoesNotMatch,
+                        ),
+                    ));
+                }
+            };
+            resource_manager.check_amount(self.1)?;
+
+            // The evidence is a container created on the spot, holding the amount vouched for.
+            let container: Rc<RefCell<LockableResource>> = Rc::new(RefCell::new(
+                Resource::new_fungible(resource_address, divisibility, self.1).into(),
+            ));
+            let locked_amount = container
+                .borrow_mut()
+                .lock_by_amount(self.1)
+                .into_invoke::<ProofError>()?;
+            let mut evidence = HashMap::new();
+            evidence.insert(
+                ResourceContainerId::ResourceManager(resource_address),
+                (container, locked_amount.clone()),
+            );
+            ProofSubstate::new(
+                resource_address,
+                resource_manager.resource_type,
+                locked_amount,
+                evidence,
+            )
+            .map_err(|e| RuntimeError::ApplicationError(ApplicationError::ProofError(e)))?
+        };
+        system_api.drop_lock(resman_handle)?;
+
+        let node_id = system_api.allocate_node_id(RENodeType::Proof)?;
+        system_api.create_node(node_id, RENode::Proof(proof))?;
+        let proof_id = node_id.into();
+
+        Ok((
+            Proof(proof_id),
+            CallFrameUpdate::move_node(RENodeId::Proof(proof_id)),
+        ))
+    }
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for ResourceManagerGetHolderVaultCountInvocation {
+    type Exec = NativeExecutor<ResourceManagerGetHolderVaultCountExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        api: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let resolved_receiver = deref_and_update(
+            RENodeId::Global(GlobalAddress::Resource(self.receiver)),
+            &mut call_frame_update,
+            api,
+        )?;
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::ResourceManager(
+                ResourceManagerMethod::GetHolderVaultCount,
+            )),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(ResourceManagerGetHolderVaultCountExecutable(
+            resolved_receiver.receiver,
+        ));
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+pub struct ResourceManagerGetHolderVaultCountExecutable(RENodeId);
+
+impl NativeProcedure for ResourceManagerGetHolderVaultCountExecutable {
+    type Output = Option<u64>;
+
+    fn main<'a, Y>(self, system_api: &mut Y) -> Result<(Option<u64>, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let offset = SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager);
+        let handle = system_api.lock_substate(self.0, offset, LockFlags::read_only())?;
+        let substate_ref = system_api.get_ref(handle)?;
+        let holder_vault_count = substate_ref.resource_manager().holder_vault_count;
+
+        Ok((holder_vault_count, CallFrameUpdate::empty()))
+    }
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for ResourceManagerGetAmountMintedInvocation {
+    type Exec = NativeExecutor<ResourceManagerGetAmountMintedExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        api: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let resolved_receiver = deref_and_update(
+            RENodeId::Global(GlobalAddress::Resource(self.receiver)),
+            &mut call_frame_update,
+            api,
+        )?;
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::ResourceManager(
+                ResourceManagerMethod::GetAmountMinted,
+            )),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(ResourceManagerGetAmountMintedExecutable(
+            resolved_receiver.receiver,
+        ));
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+pub struct ResourceManagerGetAmountMintedExecutable(RENodeId);
+
+impl NativeProcedure for ResourceManagerGetAmountMintedExecutable {
+    type Output = Decimal;
+
+    fn main<'a, Y>(self, system_api: &mut Y) -> Result<(Decimal, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let offset = SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager);
+        let handle = system_api.lock_substate(self.0, offset, LockFlags::read_only())?;
+        let substate_ref = system_api.get_ref(handle)?;
+        let amount_minted = substate_ref.resource_manager().amount_minted;
+
+        Ok((amount_minted, CallFrameUpdate::empty()))
+    }
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for ResourceManagerGetAmountBurnedInvocation {
+    type Exec = NativeExecutor<ResourceManagerGetAmountBurnedExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        api: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let resolved_receiver = deref_and_update(
+            RENodeId::Global(GlobalAddress::Resource(self.receiver)),
+            &mut call_frame_update,
+            api,
+        )?;
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::ResourceManager(
+                ResourceManagerMethod::GetAmountBurned,
+            )),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(ResourceManagerGetAmountBurnedExecutable(
+            resolved_receiver.receiver,
+        ));
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+pub struct ResourceManagerGetAmountBurnedExecutable(RENodeId);
+
+impl NativeProcedure for ResourceManagerGetAmountBurnedExecutable {
+    type Output = Decimal;
+
+    fn main<'a, Y>(self, system_api: &mut Y) -> Result<(Decimal, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let offset = SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager);
+        let handle = system_api.lock_substate(self.0, offset, LockFlags::read_only())?;
+        let substate_ref = system_api.get_ref(handle)?;
+        let amount_burned = substate_ref.resource_manager().amount_burned;
+
+        Ok((amount_burned, CallFrameUpdate::empty()))
+    }
+}
+
 impl<W: WasmEngine> ExecutableInvocation<W> for ResourceManagerUpdateNonFungibleDataInvocation {
     type Exec = NativeExecutor<ResourceManagerUpdateNonFungibleDataExecutable>;
 
@@ -1154,13 +1543,8 @@ impl NativeProcedure for ResourceManagerUpdateNonFungibleDataExecutable {
         let resource_manager = substate_ref.resource_manager();
         let nf_store_id = resource_manager
             .nf_store_id
-            .ok_or(InvokeError::Error(ResourceManagerError::NotNonFungible))
-            .map_err(|e| match e {
-                InvokeError::Error(e) => {
-                    RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(e))
-                }
-                InvokeError::Downstream(runtime_error) => runtime_error,
-            })?;
+            .ok_or(InvokeError::Error(ResourceManagerError::NotNonFungible))?;
+        resource_manager.check_mutable_non_fungible_data(&self.1, &self.2)?;
         let resource_address = resource_manager.resource_address;
 
         let node_id = RENodeId::NonFungibleStore(nf_store_id);
@@ -1170,7 +1554,7 @@ impl NativeProcedure for ResourceManagerUpdateNonFungibleDataExecutable {
         let non_fungible_handle = system_api.lock_substate(node_id, offset, LockFlags::MUTABLE)?;
         let mut substate_mut = system_api.get_ref_mut(non_fungible_handle)?;
         let non_fungible_mut = substate_mut.non_fungible();
-        if let Some(ref mut non_fungible) = non_fungible_mut.0 {
+        if let Some(ref mut non_fungible) = non_fungible_mut.non_fungible {
             non_fungible.set_mutable_data(self.2);
         } else {
             let non_fungible_address = NonFungibleAddress::new(resource_address, self.1);
@@ -1230,20 +1614,14 @@ impl NativeProcedure for ResourceManagerNonFungibleExistsExecutable {
         let resource_manager = substate_ref.resource_manager();
         let nf_store_id = resource_manager
             .nf_store_id
-            .ok_or(InvokeError::Error(ResourceManagerError::NotNonFungible))
-            .map_err(|e| match e {
-                InvokeError::Error(e) => {
-                    RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(e))
-                }
-                InvokeError::Downstream(runtime_error) => runtime_error,
-            })?;
+            .ok_or(InvokeError::Error(ResourceManagerError::NotNonFungible))?;
 
         let node_id = RENodeId::NonFungibleStore(nf_store_id);
         let offset = SubstateOffset::NonFungibleStore(NonFungibleStoreOffset::Entry(self.1));
         let non_fungible_handle =
             system_api.lock_substate(node_id, offset, LockFlags::read_only())?;
         let substate = system_api.get_ref(non_fungible_handle)?;
-        let exists = substate.non_fungible().0.is_some();
+        let exists = substate.non_fungible().non_fungible.is_some();
 
         Ok((exists, CallFrameUpdate::empty()))
     }
@@ -1292,13 +1670,7 @@ impl NativeProcedure for ResourceManagerGetNonFungibleExecutable {
         let resource_manager = substate_ref.resource_manager();
         let nf_store_id = resource_manager
             .nf_store_id
-            .ok_or(InvokeError::Error(ResourceManagerError::NotNonFungible))
-            .map_err(|e| match e {
-                InvokeError::Error(e) => {
-                    RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(e))
-                }
-                InvokeError::Downstream(runtime_error) => runtime_error,
-            })?;
+            .ok_or(InvokeError::Error(ResourceManagerError::NotNonFungible))?;
 
         let non_fungible_address =
             NonFungibleAddress::new(resource_manager.resource_address, self.1.clone());
@@ -1309,11 +1681,19 @@ impl NativeProcedure for ResourceManagerGetNonFungibleExecutable {
             system_api.lock_substate(node_id, offset, LockFlags::read_only())?;
         let non_fungible_ref = system_api.get_ref(non_fungible_handle)?;
         let wrapper = non_fungible_ref.non_fungible();
-        if let Some(non_fungible) = wrapper.0.as_ref() {
+        if let Some(non_fungible) = wrapper.non_fungible.as_ref() {
             Ok((
                 [non_fungible.immutable_data(), non_fungible.mutable_data()],
                 CallFrameUpdate::empty(),
             ))
+        } else if wrapper.burned {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ResourceManagerError(
+                    ResourceManagerError::NonFungibleAlreadyBurned(
+                        non_fungible_address.non_fungible_id().clone(),
+                    ),
+                ),
+            ));
         } else {
             return Err(RuntimeError::ApplicationError(
                 ApplicationError::ResourceManagerError(ResourceManagerError::NonFungibleNotFound(
@@ -5,12 +5,13 @@ use crate::engine::{
 };
 use crate::fee::FeeReserveError;
 use crate::model::{
-    BucketSubstate, InvokeError, ProofError, ResourceContainerId, ResourceOperationError,
+    BucketSubstate, IntoInvokeError, ProofError, ResourceContainerId, ResourceOperationError,
 };
 use crate::types::*;
 use crate::wasm::WasmEngine;
 use radix_engine_interface::api::types::{
-    GlobalAddress, NativeMethod, RENodeId, SubstateOffset, VaultMethod, VaultOffset,
+    GlobalAddress, NativeMethod, RENodeId, ResourceManagerOffset, SubstateOffset, VaultMethod,
+    VaultOffset,
 };
 use radix_engine_interface::model::*;
 
@@ -23,11 +24,24 @@ pub enum VaultError {
     CouldNotTakeBucket,
     ProofError(ProofError),
     CouldNotCreateProof,
-    LockFeeNotRadixToken,
+    /// Fees can only be locked from XRD vaults
+    FeeNotXrd(ResourceAddress),
     LockFeeInsufficientBalance,
     LockFeeRepayFailure(FeeReserveError),
 }
 
+impl From<ResourceOperationError> for VaultError {
+    fn from(error: ResourceOperationError) -> Self {
+        Self::ResourceOperationError(error)
+    }
+}
+
+impl From<ProofError> for VaultError {
+    fn from(error: ProofError) -> Self {
+        Self::ProofError(error)
+    }
+}
+
 impl<W: WasmEngine> ExecutableInvocation<W> for VaultRecallInvocation {
     type Exec = NativeExecutor<VaultTakeInvocation>;
 
@@ -78,16 +92,19 @@ impl NativeProcedure for VaultTakeInvocation {
         let vault_handle =
             api.lock_substate(RENodeId::Vault(self.receiver), offset, LockFlags::MUTABLE)?;
 
-        let container = {
+        let (container, resource_address, was_empty, is_empty) = {
             let mut substate_mut = api.get_ref_mut(vault_handle)?;
             let vault = substate_mut.vault();
-            vault.take(self.amount).map_err(|e| match e {
-                InvokeError::Error(e) => {
-                    RuntimeError::ApplicationError(ApplicationError::VaultError(e))
-                }
-                InvokeError::Downstream(runtime_error) => runtime_error,
-            })?
+            let was_empty = vault.is_empty();
+            let container = vault.take(self.amount)?;
+            (
+                container,
+                vault.resource_address(),
+                was_empty,
+                vault.is_empty(),
+            )
         };
+        update_holder_vault_count(resource_address, was_empty, is_empty, api)?;
 
         let node_id = api.allocate_node_id(RENodeType::Bucket)?;
         api.create_node(node_id, RENode::Bucket(BucketSubstate::new(container)))?;
@@ -136,13 +153,14 @@ impl NativeProcedure for VaultPutInvocation {
             .drop_node(RENodeId::Bucket(self.bucket.0))?
             .into();
 
-        let mut substate_mut = system_api.get_ref_mut(vault_handle)?;
-        let vault = substate_mut.vault();
-        vault.put(bucket).map_err(|e| {
-            RuntimeError::ApplicationError(ApplicationError::VaultError(
-                VaultError::ResourceOperationError(e),
-            ))
-        })?;
+        let (resource_address, was_empty, is_empty) = {
+            let mut substate_mut = system_api.get_ref_mut(vault_handle)?;
+            let vault = substate_mut.vault();
+            let was_empty = vault.is_empty();
+            vault.put(bucket).into_invoke::<VaultError>()?;
+            (vault.resource_address(), was_empty, vault.is_empty())
+        };
+        update_holder_vault_count(resource_address, was_empty, is_empty, system_api)?;
 
         Ok(((), CallFrameUpdate::empty()))
     }
@@ -173,48 +191,132 @@ impl NativeProcedure for VaultLockFeeInvocation {
     where
         Y: SystemApi,
     {
-        let node_id = RENodeId::Vault(self.receiver);
-        let offset = SubstateOffset::Vault(VaultOffset::Vault);
-        let vault_handle = system_api.lock_substate(
-            node_id,
-            offset,
-            LockFlags::MUTABLE | LockFlags::UNMODIFIED_BASE | LockFlags::FORCE_WRITE,
-        )?;
+        lock_fee(self.receiver, self.amount, self.contingent, system_api)?;
 
-        let fee = {
-            let mut substate_mut = system_api.get_ref_mut(vault_handle)?;
-            let vault = substate_mut.vault();
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
 
-            // Check resource and take amount
-            if vault.resource_address() != RADIX_TOKEN {
-                return Err(RuntimeError::ApplicationError(
-                    ApplicationError::VaultError(VaultError::LockFeeNotRadixToken),
-                ));
-            }
+impl<W: WasmEngine> ExecutableInvocation<W> for VaultLockFeeWithCallbackInvocation {
+    type Exec = NativeExecutor<Self>;
 
-            // Take fee from the vault
-            vault.take(self.amount).map_err(|_| {
-                RuntimeError::ApplicationError(ApplicationError::VaultError(
-                    VaultError::LockFeeInsufficientBalance,
-                ))
-            })?
-        };
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        _api: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let receiver = RENodeId::Vault(self.receiver);
+        let call_frame_update = CallFrameUpdate::copy_ref(receiver);
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::Vault(VaultMethod::LockFeeWithCallback)),
+            ResolvedReceiver::new(receiver),
+        );
+        let executor = NativeExecutor(self);
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for VaultLockFeeWithCallbackInvocation {
+    type Output = ();
+
+    fn main<'a, Y>(self, system_api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        lock_fee(self.receiver, self.amount, self.contingent, system_api)?;
+        system_api.register_fee_callback(self.receiver, self.callback)?;
 
-        // Refill fee reserve
-        let changes = system_api.lock_fee(self.receiver, fee, self.contingent)?;
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
 
-        // Return changes
+/// Takes `amount` XRD from the vault and locks it in the fee reserve.
+/// Updates the holder vault count of a resource after one of its vaults went between empty and
+/// non-empty. The resource manager is only written to if the resource tracks its holders.
+fn update_holder_vault_count<Y: SystemApi>(
+    resource_address: ResourceAddress,
+    was_empty: bool,
+    is_empty: bool,
+    api: &mut Y,
+) -> Result<(), RuntimeError> {
+    if was_empty == is_empty {
+        return Ok(());
+    }
+
+    let node_id = RENodeId::Global(GlobalAddress::Resource(resource_address));
+    let offset = SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager);
+    let resman_handle = api.lock_substate(node_id, offset.clone(), LockFlags::read_only())?;
+    let tracks_holders = {
+        let substate_ref = api.get_ref(resman_handle)?;
+        substate_ref.resource_manager().holder_vault_count.is_some()
+    };
+    api.drop_lock(resman_handle)?;
+
+    if tracks_holders {
+        let resman_handle = api.lock_substate(node_id, offset, LockFlags::MUTABLE)?;
         {
-            let mut substate_mut = system_api.get_ref_mut(vault_handle)?;
-            let vault = substate_mut.vault();
-            vault
-                .borrow_resource_mut()
-                .put(changes)
-                .expect("Failed to return fee changes to a locking-fee vault");
+            let mut substate_mut = api.get_ref_mut(resman_handle)?;
+            let resource_manager = substate_mut.resource_manager();
+            if is_empty {
+                resource_manager.vault_emptied();
+            } else {
+                resource_manager.vault_filled();
+            }
         }
+        api.drop_lock(resman_handle)?;
+    }
 
-        Ok(((), CallFrameUpdate::empty()))
+    Ok(())
+}
+
+fn lock_fee<Y: SystemApi>(
+    receiver: VaultId,
+    amount: Decimal,
+    contingent: bool,
+    system_api: &mut Y,
+) -> Result<(), RuntimeError> {
+    let node_id = RENodeId::Vault(receiver);
+    let offset = SubstateOffset::Vault(VaultOffset::Vault);
+    let vault_handle = system_api.lock_substate(
+        node_id,
+        offset,
+        LockFlags::MUTABLE | LockFlags::UNMODIFIED_BASE | LockFlags::FORCE_WRITE,
+    )?;
+
+    let (resource_address, fee) = {
+        let mut substate_mut = system_api.get_ref_mut(vault_handle)?;
+        let vault = substate_mut.vault();
+
+        // Check resource and take amount
+        let resource_address = vault.resource_address();
+        if resource_address != RADIX_TOKEN {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::VaultError(VaultError::FeeNotXrd(resource_address)),
+            ));
+        }
+
+        // Take fee from the vault
+        let fee = vault.take(amount).map_err(|_| {
+            RuntimeError::ApplicationError(ApplicationError::VaultError(
+                VaultError::LockFeeInsufficientBalance,
+            ))
+        })?;
+        (resource_address, fee)
+    };
+
+    // Refill fee reserve
+    let changes = system_api.lock_fee(receiver, resource_address, fee, contingent)?;
+
+    // Return changes
+    {
+        let mut substate_mut = system_api.get_ref_mut(vault_handle)?;
+        let vault = substate_mut.vault();
+        vault
+            .borrow_resource_mut()
+            .put(changes)
+            .expect("Failed to return fee changes to a locking-fee vault");
     }
+
+    Ok(())
 }
 
 impl<W: WasmEngine> ExecutableInvocation<W> for VaultRecallNonFungiblesInvocation {
@@ -267,18 +369,19 @@ impl NativeProcedure for VaultTakeNonFungiblesInvocation {
         let offset = SubstateOffset::Vault(VaultOffset::Vault);
         let vault_handle = api.lock_substate(node_id, offset, LockFlags::MUTABLE)?;
 
-        let container = {
+        let (container, resource_address, was_empty, is_empty) = {
             let mut substate_mut = api.get_ref_mut(vault_handle)?;
             let vault = substate_mut.vault();
-            vault
-                .take_non_fungibles(&self.non_fungible_ids)
-                .map_err(|e| match e {
-                    InvokeError::Error(e) => {
-                        RuntimeError::ApplicationError(ApplicationError::VaultError(e))
-                    }
-                    InvokeError::Downstream(runtime_error) => runtime_error,
-                })?
+            let was_empty = vault.is_empty();
+            let container = vault.take_non_fungibles(&self.non_fungible_ids)?;
+            (
+                container,
+                vault.resource_address(),
+                was_empty,
+                vault.is_empty(),
+            )
         };
+        update_holder_vault_count(resource_address, was_empty, is_empty, api)?;
 
         let node_id = api.allocate_node_id(RENodeType::Bucket)?;
         api.create_node(node_id, RENode::Bucket(BucketSubstate::new(container)))?;
@@ -405,11 +508,7 @@ impl NativeProcedure for VaultGetNonFungibleIdsInvocation {
 
         let substate_ref = system_api.get_ref(vault_handle)?;
         let vault = substate_ref.vault();
-        let ids = vault.total_ids().map_err(|e| {
-            RuntimeError::ApplicationError(ApplicationError::VaultError(
-                VaultError::ResourceOperationError(e),
-            ))
-        })?;
+        let ids = vault.total_ids().into_invoke::<VaultError>()?;
 
         Ok((ids, CallFrameUpdate::empty()))
     }
@@ -449,11 +548,7 @@ impl NativeProcedure for VaultCreateProofInvocation {
             let vault = substate_mut.vault();
             vault
                 .create_proof(ResourceContainerId::Vault(self.receiver))
-                .map_err(|e| {
-                    RuntimeError::ApplicationError(ApplicationError::VaultError(
-                        VaultError::ProofError(e),
-                    ))
-                })?
+                .into_invoke::<VaultError>()?
         };
 
         let node_id = api.allocate_node_id(RENodeType::Proof)?;
@@ -501,11 +596,7 @@ impl NativeProcedure for VaultCreateProofByAmountInvocation {
             let vault = substate_mut.vault();
             vault
                 .create_proof_by_amount(self.amount, ResourceContainerId::Vault(self.receiver))
-                .map_err(|e| {
-                    RuntimeError::ApplicationError(ApplicationError::VaultError(
-                        VaultError::ProofError(e),
-                    ))
-                })?
+                .into_invoke::<VaultError>()?
         };
 
         let node_id = api.allocate_node_id(RENodeType::Proof)?;
@@ -553,11 +644,7 @@ impl NativeProcedure for VaultCreateProofByIdsInvocation {
             let vault = substate_mut.vault();
             vault
                 .create_proof_by_ids(&self.ids, ResourceContainerId::Vault(self.receiver))
-                .map_err(|e| {
-                    RuntimeError::ApplicationError(ApplicationError::VaultError(
-                        VaultError::ProofError(e),
-                    ))
-                })?
+                .into_invoke::<VaultError>()?
         };
 
         let node_id = api.allocate_node_id(RENodeType::Proof)?;
@@ -3,7 +3,7 @@ use crate::engine::{
     NativeProcedure, REActor, RENode, ResolvedMethod, ResolvedReceiver, ResolverApi, RuntimeError,
     SystemApi,
 };
-use crate::model::{BucketSubstate, Resource, ResourceOperationError};
+use crate::model::{BucketSubstate, IntoInvokeError, Resource, ResourceOperationError};
 use crate::types::*;
 use crate::wasm::WasmEngine;
 use radix_engine_interface::api::types::{
@@ -22,7 +22,19 @@ pub enum WorktopError {
     CouldNotCreateBucket,
     CouldNotTakeBucket,
     AssertionFailed,
+    ExactAmountAssertionFailed {
+        resource_address: ResourceAddress,
+        expected: Decimal,
+        actual: Decimal,
+    },
     CouldNotDrop,
+    InvalidCaller,
+}
+
+impl From<ResourceOperationError> for WorktopError {
+    fn from(error: ResourceOperationError) -> Self {
+        Self::ResourceOperationError(error)
+    }
 }
 
 impl<W: WasmEngine> ExecutableInvocation<W> for WorktopPutInvocation {
@@ -62,11 +74,7 @@ impl NativeProcedure for WorktopPutInvocation {
             .into();
         let mut substate_mut = system_api.get_ref_mut(worktop_handle)?;
         let worktop = substate_mut.worktop();
-        worktop.put(bucket).map_err(|e| {
-            RuntimeError::ApplicationError(ApplicationError::WorktopError(
-                WorktopError::ResourceOperationError(e),
-            ))
-        })?;
+        worktop.put(bucket).into_invoke::<WorktopError>()?;
 
         Ok(((), CallFrameUpdate::empty()))
     }
@@ -111,11 +119,79 @@ impl NativeProcedure for WorktopTakeAmountInvocation {
             let worktop = substate_mut.worktop();
             let maybe_resource = worktop
                 .take(self.amount, self.resource_address)
-                .map_err(|e| {
-                    RuntimeError::ApplicationError(ApplicationError::WorktopError(
-                        WorktopError::ResourceOperationError(e),
-                    ))
-                })?;
+                .into_invoke::<WorktopError>()?;
+            maybe_resource
+        };
+
+        let resource_resource = if let Some(resource) = maybe_resource {
+            resource
+        } else {
+            let resource_type = {
+                let resource_id = RENodeId::Global(GlobalAddress::Resource(self.resource_address));
+                let offset =
+                    SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager);
+                let resource_handle =
+                    api.lock_substate(resource_id, offset, LockFlags::read_only())?;
+                let substate_ref = api.get_ref(resource_handle)?;
+                substate_ref.resource_manager().resource_type
+            };
+
+            Resource::new_empty(self.resource_address, resource_type)
+        };
+        let node_id = api.allocate_node_id(RENodeType::Bucket)?;
+        api.create_node(
+            node_id,
+            RENode::Bucket(BucketSubstate::new(resource_resource)),
+        )?;
+        let bucket_id = node_id.into();
+
+        Ok((
+            Bucket(bucket_id),
+            CallFrameUpdate::move_node(RENodeId::Bucket(bucket_id)),
+        ))
+    }
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for WorktopTakeOrDefaultInvocation {
+    type Exec = NativeExecutor<Self>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        _api: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let receiver = RENodeId::Worktop;
+        let mut call_frame_update = CallFrameUpdate::copy_ref(receiver);
+        call_frame_update
+            .node_refs_to_copy
+            .insert(RENodeId::Global(GlobalAddress::Resource(
+                self.resource_address,
+            )));
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::Worktop(WorktopMethod::TakeOrDefault)),
+            ResolvedReceiver::new(receiver),
+        );
+        let executor = NativeExecutor(self);
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for WorktopTakeOrDefaultInvocation {
+    type Output = Bucket;
+
+    fn main<Y>(self, api: &mut Y) -> Result<(Bucket, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let node_id = RENodeId::Worktop;
+        let offset = SubstateOffset::Worktop(WorktopOffset::Worktop);
+        let worktop_handle = api.lock_substate(node_id, offset, LockFlags::MUTABLE)?;
+
+        let maybe_resource = {
+            let mut substate_mut = api.get_ref_mut(worktop_handle)?;
+            let worktop = substate_mut.worktop();
+            let maybe_resource = worktop
+                .take_or_default(self.amount, self.resource_address)
+                .into_invoke::<WorktopError>()?;
             maybe_resource
         };
 
@@ -185,11 +261,9 @@ impl NativeProcedure for WorktopTakeAllInvocation {
         let maybe_resource = {
             let mut substate_mut = api.get_ref_mut(worktop_handle)?;
             let worktop = substate_mut.worktop();
-            let maybe_resource = worktop.take_all(self.resource_address).map_err(|e| {
-                RuntimeError::ApplicationError(ApplicationError::WorktopError(
-                    WorktopError::ResourceOperationError(e),
-                ))
-            })?;
+            let maybe_resource = worktop
+                .take_all(self.resource_address)
+                .into_invoke::<WorktopError>()?;
             maybe_resource
         };
 
@@ -262,11 +336,7 @@ impl NativeProcedure for WorktopTakeNonFungiblesInvocation {
             let worktop = substate_mut.worktop();
             let maybe_resource = worktop
                 .take_non_fungibles(&self.ids, self.resource_address)
-                .map_err(|e| {
-                    RuntimeError::ApplicationError(ApplicationError::WorktopError(
-                        WorktopError::ResourceOperationError(e),
-                    ))
-                })?;
+                .into_invoke::<WorktopError>()?;
             maybe_resource
         };
 
@@ -392,6 +462,59 @@ impl NativeProcedure for WorktopAssertContainsAmountInvocation {
     }
 }
 
+impl<W: WasmEngine> ExecutableInvocation<W> for WorktopAssertContainsExactAmountInvocation {
+    type Exec = NativeExecutor<Self>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        _api: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let receiver = RENodeId::Worktop;
+        let mut call_frame_update = CallFrameUpdate::copy_ref(receiver);
+        call_frame_update
+            .node_refs_to_copy
+            .insert(RENodeId::Global(GlobalAddress::Resource(
+                self.resource_address,
+            )));
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::Worktop(
+                WorktopMethod::AssertContainsExactAmount,
+            )),
+            ResolvedReceiver::new(receiver),
+        );
+        let executor = NativeExecutor(self);
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for WorktopAssertContainsExactAmountInvocation {
+    type Output = ();
+
+    fn main<Y>(self, system_api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let node_id = RENodeId::Worktop;
+        let offset = SubstateOffset::Worktop(WorktopOffset::Worktop);
+        let worktop_handle = system_api.lock_substate(node_id, offset, LockFlags::read_only())?;
+
+        let substate_ref = system_api.get_ref(worktop_handle)?;
+        let worktop = substate_ref.worktop();
+        let actual = worktop.total_amount(self.resource_address);
+        if actual != self.amount {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::WorktopError(WorktopError::ExactAmountAssertionFailed {
+                    resource_address: self.resource_address,
+                    expected: self.amount,
+                    actual,
+                }),
+            ));
+        }
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
 impl<W: WasmEngine> ExecutableInvocation<W> for WorktopAssertContainsNonFungiblesInvocation {
     type Exec = NativeExecutor<Self>;
 
@@ -432,11 +555,7 @@ impl NativeProcedure for WorktopAssertContainsNonFungiblesInvocation {
         let worktop = substate_ref.worktop();
         if !worktop
             .total_ids(self.resource_address)
-            .map_err(|e| {
-                RuntimeError::ApplicationError(ApplicationError::WorktopError(
-                    WorktopError::ResourceOperationError(e),
-                ))
-            })?
+            .into_invoke::<WorktopError>()?
             .is_superset(&self.ids)
         {
             return Err(RuntimeError::ApplicationError(
@@ -448,6 +567,48 @@ impl NativeProcedure for WorktopAssertContainsNonFungiblesInvocation {
     }
 }
 
+impl<W: WasmEngine> ExecutableInvocation<W> for WorktopGetAmountInvocation {
+    type Exec = NativeExecutor<Self>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        _api: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError> {
+        let receiver = RENodeId::Worktop;
+        let mut call_frame_update = CallFrameUpdate::copy_ref(receiver);
+        call_frame_update
+            .node_refs_to_copy
+            .insert(RENodeId::Global(GlobalAddress::Resource(
+                self.resource_address,
+            )));
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::Worktop(WorktopMethod::GetAmount)),
+            ResolvedReceiver::new(receiver),
+        );
+        let executor = NativeExecutor(self);
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for WorktopGetAmountInvocation {
+    type Output = Decimal;
+
+    fn main<Y>(self, system_api: &mut Y) -> Result<(Decimal, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let node_id = RENodeId::Worktop;
+        let offset = SubstateOffset::Worktop(WorktopOffset::Worktop);
+        let worktop_handle = system_api.lock_substate(node_id, offset, LockFlags::read_only())?;
+
+        let substate_ref = system_api.get_ref(worktop_handle)?;
+        let worktop = substate_ref.worktop();
+        let amount = worktop.total_amount(self.resource_address);
+
+        Ok((amount, CallFrameUpdate::empty()))
+    }
+}
+
 impl<W: WasmEngine> ExecutableInvocation<W> for WorktopDrainInvocation {
     type Exec = NativeExecutor<Self>;
 
@@ -482,11 +643,10 @@ impl NativeProcedure for WorktopDrainInvocation {
             let mut substate_mut = api.get_ref_mut(worktop_handle)?;
             let worktop = substate_mut.worktop();
             for (_, resource) in worktop.resources.drain() {
-                let taken = resource.borrow_mut().take_all_liquid().map_err(|e| {
-                    RuntimeError::ApplicationError(ApplicationError::WorktopError(
-                        WorktopError::ResourceOperationError(e),
-                    ))
-                })?;
+                let taken = resource
+                    .borrow_mut()
+                    .take_all_liquid()
+                    .into_invoke::<WorktopError>()?;
                 if !taken.is_empty() {
                     resources.push(taken);
                 }
@@ -507,6 +667,7 @@ impl NativeProcedure for WorktopDrainInvocation {
             buckets,
             CallFrameUpdate {
                 nodes_to_move,
+                nodes_to_borrow: vec![],
                 node_refs_to_copy: HashSet::new(),
             },
         ))
@@ -7,8 +7,8 @@ use radix_engine_interface::model::*;
 pub enum ResourceOperationError {
     /// Resource addresses do not match.
     ResourceAddressNotMatching,
-    /// The amount is invalid, according to the resource divisibility.
-    InvalidAmount(Decimal, u8),
+    /// The amount is negative or more precise than the resource divisibility allows.
+    InvalidAmountPrecision { amount: Decimal, divisibility: u8 },
     /// The balance is not enough.
     InsufficientBalance,
     /// Fungible operation on non-fungible resource is not allowed.
@@ -21,6 +21,20 @@ pub enum ResourceOperationError {
     NonFungibleIdTypeNotMatching,
 }
 
+/// Checks that an amount is non-negative and has no more decimal places than the divisibility.
+pub fn check_amount(amount: Decimal, divisibility: u8) -> Result<(), ResourceOperationError> {
+    if amount.is_negative()
+        || amount.0 % I256::from(10i128.pow((18 - divisibility).into())) != I256::from(0)
+    {
+        Err(ResourceOperationError::InvalidAmountPrecision {
+            amount,
+            divisibility,
+        })
+    } else {
+        Ok(())
+    }
+}
+
 /// A raw record of resource persisted in the substate store
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[scrypto(TypeId, Encode, Decode)]
@@ -152,7 +166,7 @@ impl Resource {
     ) -> Result<Resource, ResourceOperationError> {
         // check amount granularity
         let divisibility = self.resource_type().divisibility();
-        Self::check_amount(amount_to_take, divisibility)?;
+        check_amount(amount_to_take, divisibility)?;
 
         // deduct from liquidity pool
         match self {
@@ -207,16 +221,6 @@ impl Resource {
         self.take_by_amount(self.amount())
             .expect("Take all from `Resource` should not fail")
     }
-
-    fn check_amount(amount: Decimal, divisibility: u8) -> Result<(), ResourceOperationError> {
-        if amount.is_negative()
-            || amount.0 % I256::from(10i128.pow((18 - divisibility).into())) != I256::from(0)
-        {
-            Err(ResourceOperationError::InvalidAmount(amount, divisibility))
-        } else {
-            Ok(())
-        }
-    }
 }
 
 impl Into<LockableResource> for Resource {
@@ -286,6 +290,8 @@ pub enum ResourceContainerId {
     Bucket(BucketId),
     Vault(VaultId),
     Worktop(u32, ResourceAddress),
+    /// The backing of synthetic proofs, which aren't locking any actual resource
+    ResourceManager(ResourceAddress),
 }
 
 impl LockedAmountOrIds {
@@ -330,7 +336,7 @@ impl LockableResource {
     pub fn take_by_amount(&mut self, amount: Decimal) -> Result<Resource, ResourceOperationError> {
         // check amount granularity
         let divisibility = self.resource_type().divisibility();
-        Self::check_amount(amount, divisibility)?;
+        check_amount(amount, divisibility)?;
 
         // deduct from liquidity pool
         match self {
@@ -395,7 +401,7 @@ impl LockableResource {
     ) -> Result<LockedAmountOrIds, ResourceOperationError> {
         // check amount granularity
         let divisibility = self.resource_type().divisibility();
-        Self::check_amount(amount, divisibility)?;
+        check_amount(amount, divisibility)?;
 
         match self {
             Self::Fungible {
@@ -601,16 +607,6 @@ impl LockableResource {
         }
     }
 
-    fn check_amount(amount: Decimal, divisibility: u8) -> Result<(), ResourceOperationError> {
-        if amount.is_negative()
-            || amount.0 % I256::from(10i128.pow((18 - divisibility).into())) != I256::from(0)
-        {
-            Err(ResourceOperationError::InvalidAmount(amount, divisibility))
-        } else {
-            Ok(())
-        }
-    }
-
     pub fn peek_resource(&self) -> Resource {
         match self {
             LockableResource::Fungible {
@@ -1,5 +1,132 @@
-use crate::model::NonFungible;
+use crate::model::{NonFungible, ResourceManagerError};
 use crate::types::*;
+use radix_engine_interface::data::ScryptoCustomTypeId;
 
-#[derive(Debug, Clone, TypeId, Encode, Decode, PartialEq, Eq)]
-pub struct NonFungibleSubstate(pub Option<NonFungible>);
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode)]
+pub struct NonFungibleSubstate {
+    pub non_fungible: Option<NonFungible>,
+    /// Tombstone marker, set when the non-fungible is burned so that a burned id can be told
+    /// apart from one which was never minted.
+    pub burned: bool,
+}
+
+/// The number of fields encoded by substates written before the burn tombstone was added.
+const LEGACY_FIELD_COUNT: usize = 1;
+const FIELD_COUNT: usize = 2;
+
+// Decoded by hand so that substates written before the burn tombstone existed can still be read,
+// with `burned` defaulting to `false`.
+impl<D: Decoder<ScryptoCustomTypeId>> Decode<ScryptoCustomTypeId, D> for NonFungibleSubstate {
+    fn decode_body_with_type_id(
+        decoder: &mut D,
+        type_id: SborTypeId<ScryptoCustomTypeId>,
+    ) -> Result<Self, DecodeError> {
+        decoder.check_preloaded_type_id(type_id, SborTypeId::Tuple)?;
+        let field_count = decoder.read_size()?;
+        if field_count != FIELD_COUNT && field_count != LEGACY_FIELD_COUNT {
+            return Err(DecodeError::UnexpectedSize {
+                expected: FIELD_COUNT,
+                actual: field_count,
+            });
+        }
+
+        let non_fungible = decoder.decode()?;
+        let burned = if field_count == FIELD_COUNT {
+            decoder.decode()?
+        } else {
+            false
+        };
+
+        Ok(Self {
+            non_fungible,
+            burned,
+        })
+    }
+}
+
+impl NonFungibleSubstate {
+    /// The substate of an id which has never been minted.
+    pub fn empty() -> Self {
+        Self {
+            non_fungible: None,
+            burned: false,
+        }
+    }
+
+    pub fn new(non_fungible: NonFungible) -> Self {
+        Self {
+            non_fungible: Some(non_fungible),
+            burned: false,
+        }
+    }
+
+    /// Checks that the non-fungible under `id` currently exists and so can be burned.
+    pub fn check_burnable(&self, id: &NonFungibleId) -> Result<(), ResourceManagerError> {
+        match (&self.non_fungible, self.burned) {
+            (Some(_), _) => Ok(()),
+            (None, true) => Err(ResourceManagerError::NonFungibleAlreadyBurned(id.clone())),
+            (None, false) => Err(ResourceManagerError::NonFungibleNotMinted(id.clone())),
+        }
+    }
+
+    /// Removes the non-fungible, leaving a tombstone behind.
+    pub fn burn(&mut self) {
+        self.non_fungible = None;
+        self.burned = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_burn_twice_reports_already_burned() {
+        let id = NonFungibleId::U32(1);
+        let mut substate = NonFungibleSubstate::new(NonFungible::new(vec![], vec![]));
+
+        assert_eq!(substate.check_burnable(&id), Ok(()));
+        substate.burn();
+
+        assert_eq!(substate.non_fungible, None);
+        assert_eq!(
+            substate.check_burnable(&id),
+            Err(ResourceManagerError::NonFungibleAlreadyBurned(id))
+        );
+    }
+
+    #[test]
+    fn test_burn_never_minted_reports_not_minted() {
+        let id = NonFungibleId::U32(1);
+
+        assert_eq!(
+            NonFungibleSubstate::empty().check_burnable(&id),
+            Err(ResourceManagerError::NonFungibleNotMinted(id))
+        );
+    }
+
+    #[test]
+    fn test_decode_legacy_substate_defaults_to_not_burned() {
+        #[derive(Debug)]
+        #[scrypto(TypeId, Encode)]
+        struct LegacyNonFungibleSubstate(Option<NonFungible>);
+        let legacy = LegacyNonFungibleSubstate(Some(NonFungible::new(vec![1], vec![2])));
+
+        let decoded: NonFungibleSubstate =
+            scrypto_decode(&scrypto_encode(&legacy).unwrap()).unwrap();
+
+        assert_eq!(decoded, NonFungibleSubstate::new(legacy.0.unwrap()));
+    }
+
+    #[test]
+    fn test_encode_decode_round_trip() {
+        let mut substate = NonFungibleSubstate::new(NonFungible::new(vec![1], vec![2]));
+        substate.burn();
+
+        let decoded: NonFungibleSubstate =
+            scrypto_decode(&scrypto_encode(&substate).unwrap()).unwrap();
+
+        assert_eq!(decoded, substate);
+    }
+}
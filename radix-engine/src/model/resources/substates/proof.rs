@@ -1,6 +1,6 @@
 use crate::engine::ProofSnapshot;
 use crate::model::{
-    InvokeError, LockableResource, LockedAmountOrIds, ProofError, ResourceContainerId,
+    check_amount, InvokeError, LockableResource, LockedAmountOrIds, ProofError, ResourceContainerId,
 };
 use crate::types::*;
 
@@ -128,6 +128,9 @@ impl ProofSubstate {
         resource_address: ResourceAddress,
         resource_type: ResourceType,
     ) -> Result<ProofSubstate, ProofError> {
+        check_amount(amount, resource_type.divisibility())
+            .map_err(ProofError::ResourceOperationError)?;
+
         let (total_locked, mut per_container) =
             Self::compute_total_locked(proofs, resource_address, resource_type);
 
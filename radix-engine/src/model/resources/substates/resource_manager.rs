@@ -1,16 +1,73 @@
-use crate::model::{InvokeError, NonFungible, Resource, ResourceManagerError};
+use crate::model::{
+    check_amount, IntoInvokeError, InvokeError, NonFungible, Resource, ResourceManagerError,
+};
 use crate::types::*;
 use radix_engine_interface::api::types::NonFungibleStoreId;
+use radix_engine_interface::data::{
+    match_schema_with_value, IndexedScryptoValue, ScryptoCustomTypeId,
+};
 use radix_engine_interface::math::Decimal;
 use radix_engine_interface::model::*;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-#[scrypto(TypeId, Encode, Decode)]
+#[scrypto(TypeId, Encode)]
 pub struct ResourceManagerSubstate {
     pub resource_type: ResourceType,
     pub resource_address: ResourceAddress, // TODO: Figure out a way to remove?
     pub total_supply: Decimal,
     pub nf_store_id: Option<NonFungibleStoreId>,
+    pub non_fungible_data_schema: Option<NonFungibleDataSchema>,
+    /// The number of vaults holding a non-zero balance of this resource, if tracked.
+    pub holder_vault_count: Option<u64>,
+    /// The cumulative amount ever minted, including any initial supply.
+    pub amount_minted: Decimal,
+    /// The cumulative amount ever burned.
+    pub amount_burned: Decimal,
+}
+
+/// The number of fields encoded by substates written before the mint/burn counters were added.
+const LEGACY_FIELD_COUNT: usize = 6;
+const FIELD_COUNT: usize = 8;
+
+// Decoded by hand so that substates written before the mint/burn counters existed can still be
+// read, with the counters defaulting to `minted = total_supply, burned = 0`.
+impl<D: Decoder<ScryptoCustomTypeId>> Decode<ScryptoCustomTypeId, D> for ResourceManagerSubstate {
+    fn decode_body_with_type_id(
+        decoder: &mut D,
+        type_id: SborTypeId<ScryptoCustomTypeId>,
+    ) -> Result<Self, DecodeError> {
+        decoder.check_preloaded_type_id(type_id, SborTypeId::Tuple)?;
+        let field_count = decoder.read_size()?;
+        if field_count != FIELD_COUNT && field_count != LEGACY_FIELD_COUNT {
+            return Err(DecodeError::UnexpectedSize {
+                expected: FIELD_COUNT,
+                actual: field_count,
+            });
+        }
+
+        let resource_type = decoder.decode()?;
+        let resource_address = decoder.decode()?;
+        let total_supply: Decimal = decoder.decode()?;
+        let nf_store_id = decoder.decode()?;
+        let non_fungible_data_schema = decoder.decode()?;
+        let holder_vault_count = decoder.decode()?;
+        let (amount_minted, amount_burned) = if field_count == FIELD_COUNT {
+            (decoder.decode()?, decoder.decode()?)
+        } else {
+            (total_supply, Decimal::zero())
+        };
+
+        Ok(Self {
+            resource_type,
+            resource_address,
+            total_supply,
+            nf_store_id,
+            non_fungible_data_schema,
+            holder_vault_count,
+            amount_minted,
+            amount_burned,
+        })
+    }
 }
 
 impl ResourceManagerSubstate {
@@ -18,34 +75,101 @@ impl ResourceManagerSubstate {
         resource_type: ResourceType,
         nf_store_id: Option<NonFungibleStoreId>,
         resource_address: ResourceAddress,
+        non_fungible_data_schema: Option<NonFungibleDataSchema>,
+        track_holders: bool,
     ) -> Result<ResourceManagerSubstate, InvokeError<ResourceManagerError>> {
+        if resource_type.divisibility() > 18 {
+            return Err(InvokeError::Error(
+                ResourceManagerError::InvalidDivisibility,
+            ));
+        }
+
         let resource_manager = ResourceManagerSubstate {
             resource_type,
             total_supply: 0.into(),
             nf_store_id,
             resource_address,
+            non_fungible_data_schema,
+            holder_vault_count: if track_holders { Some(0) } else { None },
+            amount_minted: Decimal::zero(),
+            amount_burned: Decimal::zero(),
         };
 
         Ok(resource_manager)
     }
 
+    /// Records that a vault of this resource went from empty to non-empty.
+    pub fn vault_filled(&mut self) {
+        if let Some(count) = &mut self.holder_vault_count {
+            *count += 1;
+        }
+    }
+
+    /// Records that a vault of this resource went from non-empty to empty.
+    pub fn vault_emptied(&mut self) {
+        if let Some(count) = &mut self.holder_vault_count {
+            *count = count.saturating_sub(1);
+        }
+    }
+
     pub fn check_amount(&self, amount: Decimal) -> Result<(), InvokeError<ResourceManagerError>> {
-        let divisibility = self.resource_type.divisibility();
-
-        if amount.is_negative()
-            || amount.0 % I256::from(10i128.pow((18 - divisibility).into())) != I256::from(0)
-        {
-            Err(InvokeError::Error(ResourceManagerError::InvalidAmount(
-                amount,
-                divisibility,
-            )))
-        } else {
-            Ok(())
+        check_amount(amount, self.resource_type.divisibility())
+            .into_invoke::<ResourceManagerError>()
+    }
+
+    /// Checks the data of a non-fungible being minted against the data schema, if any.
+    pub fn check_non_fungible_data(
+        &self,
+        id: &NonFungibleId,
+        immutable_data: &[u8],
+        mutable_data: &[u8],
+    ) -> Result<(), InvokeError<ResourceManagerError>> {
+        if let Some(schema) = &self.non_fungible_data_schema {
+            if !Self::data_matches_schema(&schema.immutable, immutable_data)
+                || !Self::data_matches_schema(&schema.mutable, mutable_data)
+            {
+                return Err(self.non_fungible_data_mismatch(id));
+            }
         }
+        Ok(())
+    }
+
+    /// Checks the new mutable data of a non-fungible being updated against the data schema, if
+    /// any.
+    pub fn check_mutable_non_fungible_data(
+        &self,
+        id: &NonFungibleId,
+        mutable_data: &[u8],
+    ) -> Result<(), InvokeError<ResourceManagerError>> {
+        if let Some(schema) = &self.non_fungible_data_schema {
+            if !Self::data_matches_schema(&schema.mutable, mutable_data) {
+                return Err(self.non_fungible_data_mismatch(id));
+            }
+        }
+        Ok(())
+    }
+
+    fn data_matches_schema(schema: &Type, data: &[u8]) -> bool {
+        IndexedScryptoValue::from_slice(data)
+            .map(|value| match_schema_with_value(schema, &value.dom))
+            .unwrap_or(false)
+    }
+
+    fn non_fungible_data_mismatch(&self, id: &NonFungibleId) -> InvokeError<ResourceManagerError> {
+        InvokeError::Error(ResourceManagerError::NonFungibleDataDoesNotMatchSchema(
+            NonFungibleAddress::new(self.resource_address, id.clone()),
+        ))
     }
 
     pub fn burn(&mut self, amount: Decimal) {
         self.total_supply -= amount;
+        self.amount_burned += amount;
+    }
+
+    /// Records the initial supply of a new resource, which counts as minted.
+    pub fn set_initial_supply(&mut self, amount: Decimal) {
+        self.total_supply = amount;
+        self.amount_minted = amount;
     }
 
     pub fn mint(
@@ -78,6 +202,7 @@ impl ResourceManagerSubstate {
             }
 
             self.total_supply += amount;
+            self.amount_minted += amount;
 
             Ok((
                 Resource::new_fungible(self_address, divisibility, amount),
@@ -111,6 +236,7 @@ impl ResourceManagerSubstate {
         self.check_amount(amount)?;
 
         self.total_supply += amount;
+        self.amount_minted += amount;
 
         // Allocate non-fungibles
         let mut ids = BTreeSet::new();
@@ -125,6 +251,8 @@ impl ResourceManagerSubstate {
                 ));
             }
 
+            self.check_non_fungible_data(&id, &data.0, &data.1)?;
+
             let non_fungible = NonFungible::new(data.0, data.1);
             ids.insert(id.clone());
             non_fungibles.insert(id, non_fungible);
@@ -136,3 +264,56 @@ impl ResourceManagerSubstate {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_legacy_substate_defaults_counters_to_supply() {
+        #[derive(Debug)]
+        #[scrypto(TypeId, Encode)]
+        struct LegacyResourceManagerSubstate {
+            resource_type: ResourceType,
+            resource_address: ResourceAddress,
+            total_supply: Decimal,
+            nf_store_id: Option<NonFungibleStoreId>,
+            non_fungible_data_schema: Option<NonFungibleDataSchema>,
+            holder_vault_count: Option<u64>,
+        }
+        let legacy = LegacyResourceManagerSubstate {
+            resource_type: ResourceType::Fungible { divisibility: 18 },
+            resource_address: RADIX_TOKEN,
+            total_supply: dec!("70"),
+            nf_store_id: None,
+            non_fungible_data_schema: None,
+            holder_vault_count: None,
+        };
+
+        let decoded: ResourceManagerSubstate =
+            scrypto_decode(&scrypto_encode(&legacy).unwrap()).unwrap();
+
+        assert_eq!(decoded.total_supply, dec!("70"));
+        assert_eq!(decoded.amount_minted, dec!("70"));
+        assert_eq!(decoded.amount_burned, Decimal::zero());
+    }
+
+    #[test]
+    fn test_encode_decode_round_trip() {
+        let mut substate = ResourceManagerSubstate::new(
+            ResourceType::Fungible { divisibility: 18 },
+            None,
+            RADIX_TOKEN,
+            None,
+            false,
+        )
+        .unwrap();
+        substate.set_initial_supply(dec!("100"));
+        substate.burn(dec!("30"));
+
+        let decoded: ResourceManagerSubstate =
+            scrypto_decode(&scrypto_encode(&substate).unwrap()).unwrap();
+
+        assert_eq!(decoded, substate);
+    }
+}
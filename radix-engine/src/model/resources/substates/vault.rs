@@ -1,4 +1,4 @@
-use crate::engine::InvokeError;
+use crate::engine::{IntoInvokeError, InvokeError};
 use crate::model::{
     BucketSubstate, LockableResource, ProofError, ProofSubstate, Resource, ResourceContainerId,
     ResourceOperationError, VaultError,
@@ -78,7 +78,7 @@ impl VaultRuntimeSubstate {
         let resource = self
             .borrow_resource_mut()
             .take_by_amount(amount)
-            .map_err(|e| InvokeError::Error(VaultError::ResourceOperationError(e)))?;
+            .into_invoke::<VaultError>()?;
         Ok(resource)
     }
 
@@ -89,7 +89,7 @@ impl VaultRuntimeSubstate {
         let resource = self
             .borrow_resource_mut()
             .take_by_ids(ids)
-            .map_err(|e| InvokeError::Error(VaultError::ResourceOperationError(e)))?;
+            .into_invoke::<VaultError>()?;
         Ok(resource)
     }
 
@@ -54,6 +54,19 @@ impl WorktopSubstate {
         }
     }
 
+    /// Takes an amount of a resource, or nothing if the worktop holds none of the resource.
+    pub fn take_or_default(
+        &mut self,
+        amount: Decimal,
+        resource_address: ResourceAddress,
+    ) -> Result<Option<Resource>, ResourceOperationError> {
+        if self.total_amount(resource_address).is_zero() {
+            Ok(None)
+        } else {
+            self.take(amount, resource_address)
+        }
+    }
+
     pub fn take_non_fungibles(
         &mut self,
         ids: &BTreeSet<NonFungibleId>,
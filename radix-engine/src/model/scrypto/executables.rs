@@ -1,6 +1,7 @@
 use radix_engine_interface::data::*;
 
 use crate::engine::*;
+use crate::model::{ComponentInfoSubstate, PackageInfoSubstate};
 use crate::types::*;
 use crate::wasm::*;
 
@@ -15,12 +16,12 @@ impl<W: WasmEngine> ExecutableInvocation<W> for ScryptoInvocation {
         let args = IndexedScryptoValue::from_slice(&self.args())
             .map_err(|e| RuntimeError::KernelError(KernelError::InvalidScryptoValue(e)))?;
 
-        let nodes_to_move = args.node_ids().into_iter().collect();
+        let mut nodes_to_move: Vec<RENodeId> = args.node_ids().into_iter().collect();
         for global_address in args.global_references() {
             node_refs_to_copy.insert(RENodeId::Global(global_address));
         }
 
-        let (executor, actor) = match &self {
+        let (nodes_to_borrow, executor, actor) = match &self {
             ScryptoInvocation::Function(function_ident, _) => {
                 // Load the package substate
                 // TODO: Move this in a better spot when more refactors are done
@@ -29,67 +30,19 @@ impl<W: WasmEngine> ExecutableInvocation<W> for ScryptoInvocation {
                 };
                 let global_node_id = RENodeId::Global(GlobalAddress::Package(package_address));
 
-                let package = api.execute_in_mode::<_, _, RuntimeError>(
-                    ExecutionMode::ScryptoInterpreter,
-                    |system_api| {
-                        let handle = system_api.lock_substate(
-                            global_node_id,
-                            SubstateOffset::Package(PackageOffset::Info),
-                            LockFlags::read_only(),
-                        )?;
-                        let substate_ref = system_api.get_ref(handle)?;
-                        let package = substate_ref.package_info().clone(); // TODO: Remove clone()
-                        system_api.drop_lock(handle)?;
-
-                        Ok(package)
-                    },
-                )?;
+                let (fn_abi, instance) = resolve_package(api, package_address, |package| {
+                    resolve_function_abi(package, function_ident, &args)
+                })?;
 
                 // Pass the package ref
                 // TODO: remove? currently needed for `Runtime::package_address()` API.
                 node_refs_to_copy.insert(global_node_id);
 
-                // Find the abi
-                let abi = package
-                    .blueprint_abi(&function_ident.blueprint_name)
-                    .ok_or(RuntimeError::InterpreterError(
-                        InterpreterError::InvalidScryptoFunctionInvocation(
-                            function_ident.clone(),
-                            ScryptoFnResolvingError::BlueprintNotFound,
-                        ),
-                    ))?;
-                let fn_abi = abi.get_fn_abi(&function_ident.function_name).ok_or(
-                    RuntimeError::InterpreterError(
-                        InterpreterError::InvalidScryptoFunctionInvocation(
-                            function_ident.clone(),
-                            ScryptoFnResolvingError::FunctionNotFound,
-                        ),
-                    ),
-                )?;
-                if fn_abi.mutability.is_some() {
-                    return Err(RuntimeError::InterpreterError(
-                        InterpreterError::InvalidScryptoFunctionInvocation(
-                            function_ident.clone(),
-                            ScryptoFnResolvingError::FunctionNotFound,
-                        ),
-                    ));
-                }
-                // Check input against the ABI
-
-                if !match_schema_with_value(&fn_abi.input, &args.dom) {
-                    return Err(RuntimeError::InterpreterError(
-                        InterpreterError::InvalidScryptoFunctionInvocation(
-                            function_ident.clone(),
-                            ScryptoFnResolvingError::InvalidInput,
-                        ),
-                    ));
-                }
-
-                // Emit event
-                api.on_wasm_instantiation(package.code())?;
+                let nodes_to_borrow = borrowed_buckets(&fn_abi.input, &args.dom);
 
                 (
-                    api.vm().create_executor(&package.code, args),
+                    nodes_to_borrow,
+                    ScryptoExecutor::new(instance, args),
                     REActor::Function(ResolvedFunction::Scrypto {
                         package_address,
                         blueprint_name: function_ident.blueprint_name.clone(),
@@ -116,91 +69,29 @@ impl<W: WasmEngine> ExecutableInvocation<W> for ScryptoInvocation {
                         ResolvedReceiver::new(original_node_id)
                     };
 
-                // Load the package substate
-                // TODO: Move this in a better spot when more refactors are done
-                let component_node_id = resolved_receiver.receiver;
-                let component_info = api.execute_in_mode::<_, _, RuntimeError>(
-                    ExecutionMode::ScryptoInterpreter,
-                    |system_api| {
-                        let handle = system_api.lock_substate(
-                            component_node_id,
-                            SubstateOffset::Component(ComponentOffset::Info),
-                            LockFlags::read_only(),
-                        )?;
-                        let substate_ref = system_api.get_ref(handle)?;
-                        let component_info = substate_ref.component_info().clone(); // TODO: Remove clone()
-                        system_api.drop_lock(handle)?;
-
-                        Ok(component_info)
-                    },
-                )?;
-                let package = api.execute_in_mode::<_, _, RuntimeError>(
-                    ExecutionMode::ScryptoInterpreter,
-                    |system_api| {
-                        let package_global = RENodeId::Global(GlobalAddress::Package(
-                            component_info.package_address,
-                        ));
-                        let handle = system_api.lock_substate(
-                            package_global,
-                            SubstateOffset::Package(PackageOffset::Info),
-                            LockFlags::read_only(),
-                        )?;
-                        let substate_ref = system_api.get_ref(handle)?;
-                        let package = substate_ref.package_info().clone(); // TODO: Remove clone()
-                        system_api.drop_lock(handle)?;
-
-                        Ok(package)
-                    },
-                )?;
+                // Release the deref lock if the method can't be resolved
+                let (component_info, fn_abi, instance) =
+                    match resolve_method(api, resolved_receiver.receiver, &method_ident, &args) {
+                        Ok(resolved) => resolved,
+                        Err(e) => {
+                            if let Some((_, derefed_lock)) = resolved_receiver.derefed_from {
+                                api.drop_lock(derefed_lock)?;
+                            }
+                            return Err(e);
+                        }
+                    };
 
                 // Pass the component ref
                 // TODO: remove? currently needed for `Runtime::package_address()` API.
-                let global_node_id =
-                    RENodeId::Global(GlobalAddress::Package(component_info.package_address));
-                node_refs_to_copy.insert(global_node_id);
-                node_refs_to_copy.insert(component_node_id);
-
-                // Find the abi
-                let abi = package
-                    .blueprint_abi(&component_info.blueprint_name)
-                    .ok_or(RuntimeError::InterpreterError(
-                        InterpreterError::InvalidScryptoMethodInvocation(
-                            method_ident.clone(),
-                            ScryptoFnResolvingError::BlueprintNotFound,
-                        ),
-                    ))?;
-                let fn_abi = abi.get_fn_abi(&method_ident.method_name).ok_or(
-                    RuntimeError::InterpreterError(
-                        InterpreterError::InvalidScryptoMethodInvocation(
-                            method_ident.clone(),
-                            ScryptoFnResolvingError::MethodNotFound,
-                        ),
-                    ),
-                )?;
-                if fn_abi.mutability.is_none() {
-                    return Err(RuntimeError::InterpreterError(
-                        InterpreterError::InvalidScryptoMethodInvocation(
-                            method_ident.clone(),
-                            ScryptoFnResolvingError::MethodNotFound,
-                        ),
-                    ));
-                }
-
-                // Check input against the ABI
-                if !match_schema_with_value(&fn_abi.input, &args.dom) {
-                    return Err(RuntimeError::InterpreterError(
-                        InterpreterError::InvalidScryptoMethodInvocation(
-                            method_ident.clone(),
-                            ScryptoFnResolvingError::InvalidInput,
-                        ),
-                    ));
-                }
-
-                // Emit event
-                api.on_wasm_instantiation(package.code())?;
+                node_refs_to_copy.insert(RENodeId::Global(GlobalAddress::Package(
+                    component_info.package_address,
+                )));
+                node_refs_to_copy.insert(resolved_receiver.receiver);
+                let nodes_to_borrow = borrowed_buckets(&fn_abi.input, &args.dom);
 
                 (
-                    api.vm().create_executor(&package.code, args),
+                    nodes_to_borrow,
+                    ScryptoExecutor::new(instance, args),
                     REActor::Method(
                         ResolvedMethod::Scrypto {
                             package_address: component_info.package_address,
@@ -226,10 +117,13 @@ impl<W: WasmEngine> ExecutableInvocation<W> for ScryptoInvocation {
         )));
         node_refs_to_copy.insert(RENodeId::Global(GlobalAddress::Package(ACCOUNT_PACKAGE)));
 
+        nodes_to_move.retain(|node_id| !nodes_to_borrow.contains(node_id));
+
         Ok((
             actor,
             CallFrameUpdate {
                 nodes_to_move,
+                nodes_to_borrow,
                 node_refs_to_copy,
             },
             executor,
@@ -246,12 +140,12 @@ impl<W: WasmEngine> ExecutableInvocation<W> for ParsedScryptoInvocation {
     ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError> {
         let mut node_refs_to_copy = HashSet::new();
 
-        let nodes_to_move = self.args().node_ids().into_iter().collect();
+        let mut nodes_to_move: Vec<RENodeId> = self.args().node_ids().into_iter().collect();
         for global_address in self.args().global_references() {
             node_refs_to_copy.insert(RENodeId::Global(global_address));
         }
 
-        let (executor, actor) = match self {
+        let (nodes_to_borrow, executor, actor) = match self {
             ParsedScryptoInvocation::Function(function_ident, args) => {
                 // Load the package substate
                 // TODO: Move this in a better spot when more refactors are done
@@ -260,67 +154,19 @@ impl<W: WasmEngine> ExecutableInvocation<W> for ParsedScryptoInvocation {
                 };
                 let global_node_id = RENodeId::Global(GlobalAddress::Package(package_address));
 
-                let package = api.execute_in_mode::<_, _, RuntimeError>(
-                    ExecutionMode::ScryptoInterpreter,
-                    |system_api| {
-                        let handle = system_api.lock_substate(
-                            global_node_id,
-                            SubstateOffset::Package(PackageOffset::Info),
-                            LockFlags::read_only(),
-                        )?;
-                        let substate_ref = system_api.get_ref(handle)?;
-                        let package = substate_ref.package_info().clone(); // TODO: Remove clone()
-                        system_api.drop_lock(handle)?;
-
-                        Ok(package)
-                    },
-                )?;
+                let (fn_abi, instance) = resolve_package(api, package_address, |package| {
+                    resolve_function_abi(package, &function_ident, &args)
+                })?;
 
                 // Pass the package ref
                 // TODO: remove? currently needed for `Runtime::package_address()` API.
                 node_refs_to_copy.insert(global_node_id);
 
-                // Find the abi
-                let abi = package
-                    .blueprint_abi(&function_ident.blueprint_name)
-                    .ok_or(RuntimeError::InterpreterError(
-                        InterpreterError::InvalidScryptoFunctionInvocation(
-                            function_ident.clone(),
-                            ScryptoFnResolvingError::BlueprintNotFound,
-                        ),
-                    ))?;
-                let fn_abi = abi.get_fn_abi(&function_ident.function_name).ok_or(
-                    RuntimeError::InterpreterError(
-                        InterpreterError::InvalidScryptoFunctionInvocation(
-                            function_ident.clone(),
-                            ScryptoFnResolvingError::FunctionNotFound,
-                        ),
-                    ),
-                )?;
-                if fn_abi.mutability.is_some() {
-                    return Err(RuntimeError::InterpreterError(
-                        InterpreterError::InvalidScryptoFunctionInvocation(
-                            function_ident.clone(),
-                            ScryptoFnResolvingError::FunctionNotFound,
-                        ),
-                    ));
-                }
-                // Check input against the ABI
-
-                if !match_schema_with_value(&fn_abi.input, &args.dom) {
-                    return Err(RuntimeError::InterpreterError(
-                        InterpreterError::InvalidScryptoFunctionInvocation(
-                            function_ident.clone(),
-                            ScryptoFnResolvingError::InvalidInput,
-                        ),
-                    ));
-                }
-
-                // Emit event
-                api.on_wasm_instantiation(package.code())?;
+                let nodes_to_borrow = borrowed_buckets(&fn_abi.input, &args.dom);
 
                 (
-                    api.vm().create_executor_to_parsed(&package.code, args),
+                    nodes_to_borrow,
+                    ScryptoExecutorToParsed::new(instance, args),
                     REActor::Function(ResolvedFunction::Scrypto {
                         package_address,
                         blueprint_name: function_ident.blueprint_name.clone(),
@@ -347,91 +193,29 @@ impl<W: WasmEngine> ExecutableInvocation<W> for ParsedScryptoInvocation {
                         ResolvedReceiver::new(original_node_id)
                     };
 
-                // Load the package substate
-                // TODO: Move this in a better spot when more refactors are done
-                let component_node_id = resolved_receiver.receiver;
-                let component_info = api.execute_in_mode::<_, _, RuntimeError>(
-                    ExecutionMode::ScryptoInterpreter,
-                    |system_api| {
-                        let handle = system_api.lock_substate(
-                            component_node_id,
-                            SubstateOffset::Component(ComponentOffset::Info),
-                            LockFlags::read_only(),
-                        )?;
-                        let substate_ref = system_api.get_ref(handle)?;
-                        let component_info = substate_ref.component_info().clone(); // TODO: Remove clone()
-                        system_api.drop_lock(handle)?;
-
-                        Ok(component_info)
-                    },
-                )?;
-                let package = api.execute_in_mode::<_, _, RuntimeError>(
-                    ExecutionMode::ScryptoInterpreter,
-                    |system_api| {
-                        let package_global = RENodeId::Global(GlobalAddress::Package(
-                            component_info.package_address,
-                        ));
-                        let handle = system_api.lock_substate(
-                            package_global,
-                            SubstateOffset::Package(PackageOffset::Info),
-                            LockFlags::read_only(),
-                        )?;
-                        let substate_ref = system_api.get_ref(handle)?;
-                        let package = substate_ref.package_info().clone(); // TODO: Remove clone()
-                        system_api.drop_lock(handle)?;
-
-                        Ok(package)
-                    },
-                )?;
+                // Release the deref lock if the method can't be resolved
+                let (component_info, fn_abi, instance) =
+                    match resolve_method(api, resolved_receiver.receiver, &method_ident, &args) {
+                        Ok(resolved) => resolved,
+                        Err(e) => {
+                            if let Some((_, derefed_lock)) = resolved_receiver.derefed_from {
+                                api.drop_lock(derefed_lock)?;
+                            }
+                            return Err(e);
+                        }
+                    };
 
                 // Pass the component ref
                 // TODO: remove? currently needed for `Runtime::package_address()` API.
-                let global_node_id =
-                    RENodeId::Global(GlobalAddress::Package(component_info.package_address));
-                node_refs_to_copy.insert(global_node_id);
-                node_refs_to_copy.insert(component_node_id);
-
-                // Find the abi
-                let abi = package
-                    .blueprint_abi(&component_info.blueprint_name)
-                    .ok_or(RuntimeError::InterpreterError(
-                        InterpreterError::InvalidScryptoMethodInvocation(
-                            method_ident.clone(),
-                            ScryptoFnResolvingError::BlueprintNotFound,
-                        ),
-                    ))?;
-                let fn_abi = abi.get_fn_abi(&method_ident.method_name).ok_or(
-                    RuntimeError::InterpreterError(
-                        InterpreterError::InvalidScryptoMethodInvocation(
-                            method_ident.clone(),
-                            ScryptoFnResolvingError::MethodNotFound,
-                        ),
-                    ),
-                )?;
-                if fn_abi.mutability.is_none() {
-                    return Err(RuntimeError::InterpreterError(
-                        InterpreterError::InvalidScryptoMethodInvocation(
-                            method_ident.clone(),
-                            ScryptoFnResolvingError::MethodNotFound,
-                        ),
-                    ));
-                }
-
-                // Check input against the ABI
-                if !match_schema_with_value(&fn_abi.input, &args.dom) {
-                    return Err(RuntimeError::InterpreterError(
-                        InterpreterError::InvalidScryptoMethodInvocation(
-                            method_ident.clone(),
-                            ScryptoFnResolvingError::InvalidInput,
-                        ),
-                    ));
-                }
-
-                // Emit event
-                api.on_wasm_instantiation(package.code())?;
+                node_refs_to_copy.insert(RENodeId::Global(GlobalAddress::Package(
+                    component_info.package_address,
+                )));
+                node_refs_to_copy.insert(resolved_receiver.receiver);
+                let nodes_to_borrow = borrowed_buckets(&fn_abi.input, &args.dom);
 
                 (
-                    api.vm().create_executor_to_parsed(&package.code, args),
+                    nodes_to_borrow,
+                    ScryptoExecutorToParsed::new(instance, args),
                     REActor::Method(
                         ResolvedMethod::Scrypto {
                             package_address: component_info.package_address,
@@ -457,13 +241,190 @@ impl<W: WasmEngine> ExecutableInvocation<W> for ParsedScryptoInvocation {
         )));
         node_refs_to_copy.insert(RENodeId::Global(GlobalAddress::Package(ACCOUNT_PACKAGE)));
 
+        nodes_to_move.retain(|node_id| !nodes_to_borrow.contains(node_id));
+
         Ok((
             actor,
             CallFrameUpdate {
                 nodes_to_move,
+                nodes_to_borrow,
                 node_refs_to_copy,
             },
             executor,
         ))
     }
 }
+
+/// Returns the buckets passed to parameters which the ABI declares as borrowed, which are lent to
+/// the callee rather than moved.
+fn borrowed_buckets(input: &Type, args: &ScryptoValue) -> Vec<RENodeId> {
+    let field_types: Vec<&Type> = match input {
+        Type::Struct {
+            fields: Fields::Named { named },
+            ..
+        } => named.iter().map(|(_, ty)| ty).collect(),
+        Type::Struct {
+            fields: Fields::Unnamed { unnamed },
+            ..
+        } => unnamed.iter().collect(),
+        _ => return Vec::new(),
+    };
+
+    match args {
+        SborValue::Tuple { fields } => field_types
+            .into_iter()
+            .zip(fields)
+            .filter_map(|(ty, value)| match (ty, value) {
+                (
+                    Type::BorrowedBucket,
+                    SborValue::Custom {
+                        value: ScryptoCustomValue::Bucket(bucket_id),
+                    },
+                ) => Some(RENodeId::Bucket(*bucket_id)),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Locks the package info substate, resolves a fn ABI against it and instantiates the package
+/// code, borrowing the package throughout rather than copying it out of the substate.
+fn resolve_package<W, D, F>(
+    api: &mut D,
+    package_address: PackageAddress,
+    resolve_fn_abi: F,
+) -> Result<(Fn, W::WasmInstance), RuntimeError>
+where
+    W: WasmEngine,
+    D: ResolverApi<W> + SystemApi,
+    F: FnOnce(&PackageInfoSubstate) -> Result<Fn, RuntimeError>,
+{
+    api.execute_in_mode::<_, _, RuntimeError>(ExecutionMode::ScryptoInterpreter, |system_api| {
+        let handle = system_api.lock_substate(
+            RENodeId::Global(GlobalAddress::Package(package_address)),
+            SubstateOffset::Package(PackageOffset::Info),
+            LockFlags::read_only(),
+        )?;
+        let fn_abi = {
+            let substate_ref = system_api.get_ref(handle)?;
+            resolve_fn_abi(substate_ref.package_info())
+        };
+        let resolved = fn_abi.and_then(|fn_abi| Ok((fn_abi, system_api.instantiate_wasm(handle)?)));
+        system_api.drop_lock(handle)?;
+
+        resolved
+    })
+}
+
+/// Finds the fn ABI of a Scrypto function, and checks the input against it.
+fn resolve_function_abi(
+    package: &PackageInfoSubstate,
+    function_ident: &ScryptoFunctionIdent,
+    args: &IndexedScryptoValue,
+) -> Result<Fn, RuntimeError> {
+    let abi = package
+        .blueprint_abi(&function_ident.blueprint_name)
+        .ok_or(RuntimeError::InterpreterError(
+            InterpreterError::InvalidScryptoFunctionInvocation(
+                function_ident.clone(),
+                ScryptoFnResolvingError::BlueprintNotFound,
+            ),
+        ))?;
+    let fn_abi =
+        abi.get_fn_abi(&function_ident.function_name)
+            .ok_or(RuntimeError::InterpreterError(
+                InterpreterError::InvalidScryptoFunctionInvocation(
+                    function_ident.clone(),
+                    ScryptoFnResolvingError::FunctionNotFound,
+                ),
+            ))?;
+    if fn_abi.mutability.is_some() {
+        return Err(RuntimeError::InterpreterError(
+            InterpreterError::InvalidScryptoFunctionInvocation(
+                function_ident.clone(),
+                ScryptoFnResolvingError::FunctionNotFound,
+            ),
+        ));
+    }
+
+    // Check input against the ABI
+    if !match_schema_with_value(&fn_abi.input, &args.dom) {
+        return Err(RuntimeError::InterpreterError(
+            InterpreterError::InvalidScryptoFunctionInvocation(
+                function_ident.clone(),
+                ScryptoFnResolvingError::InvalidInput,
+            ),
+        ));
+    }
+
+    Ok(fn_abi.clone())
+}
+
+/// Loads the component info and fn ABI of a Scrypto method, checks the input against the ABI,
+/// and instantiates the package code.
+fn resolve_method<W: WasmEngine, D: ResolverApi<W> + SystemApi>(
+    api: &mut D,
+    component_node_id: RENodeId,
+    method_ident: &ScryptoMethodIdent,
+    args: &IndexedScryptoValue,
+) -> Result<(ComponentInfoSubstate, Fn, W::WasmInstance), RuntimeError> {
+    // Load the package substate
+    // TODO: Move this in a better spot when more refactors are done
+    let component_info = api.execute_in_mode::<_, _, RuntimeError>(
+        ExecutionMode::ScryptoInterpreter,
+        |system_api| {
+            let handle = system_api.lock_substate(
+                component_node_id,
+                SubstateOffset::Component(ComponentOffset::Info),
+                LockFlags::read_only(),
+            )?;
+            let substate_ref = system_api.get_ref(handle)?;
+            let component_info = substate_ref.component_info().clone(); // TODO: Remove clone()
+            system_api.drop_lock(handle)?;
+
+            Ok(component_info)
+        },
+    )?;
+    let (fn_abi, instance) = resolve_package(api, component_info.package_address, |package| {
+        // Find the abi
+        let abi = package
+            .blueprint_abi(&component_info.blueprint_name)
+            .ok_or(RuntimeError::InterpreterError(
+                InterpreterError::InvalidScryptoMethodInvocation(
+                    method_ident.clone(),
+                    ScryptoFnResolvingError::BlueprintNotFound,
+                ),
+            ))?;
+        let fn_abi =
+            abi.get_fn_abi(&method_ident.method_name)
+                .ok_or(RuntimeError::InterpreterError(
+                    InterpreterError::InvalidScryptoMethodInvocation(
+                        method_ident.clone(),
+                        ScryptoFnResolvingError::MethodNotFound,
+                    ),
+                ))?;
+        if fn_abi.mutability.is_none() {
+            return Err(RuntimeError::InterpreterError(
+                InterpreterError::InvalidScryptoMethodInvocation(
+                    method_ident.clone(),
+                    ScryptoFnResolvingError::MethodNotFound,
+                ),
+            ));
+        }
+
+        // Check input against the ABI
+        if !match_schema_with_value(&fn_abi.input, &args.dom) {
+            return Err(RuntimeError::InterpreterError(
+                InterpreterError::InvalidScryptoMethodInvocation(
+                    method_ident.clone(),
+                    ScryptoFnResolvingError::InvalidInput,
+                ),
+            ));
+        }
+
+        Ok(fn_abi.clone())
+    })?;
+
+    Ok((component_info, fn_abi, instance))
+}
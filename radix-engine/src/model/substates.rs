@@ -13,6 +13,8 @@ pub enum PersistedSubstate {
     Global(GlobalAddressSubstate),
     EpochManager(EpochManagerSubstate),
     CurrentTimeRoundedToMinutes(CurrentTimeRoundedToMinutesSubstate),
+    Burner(BurnerSubstate),
+    TimeLockedVault(TimeLockedVaultSubstate),
     ResourceManager(ResourceManagerSubstate),
     AccessRulesChain(AccessRulesChainSubstate),
     Metadata(MetadataSubstate),
@@ -72,6 +74,8 @@ impl PersistedSubstate {
             PersistedSubstate::CurrentTimeRoundedToMinutes(value) => {
                 RuntimeSubstate::CurrentTimeRoundedToMinutes(value)
             }
+            PersistedSubstate::Burner(value) => RuntimeSubstate::Burner(value),
+            PersistedSubstate::TimeLockedVault(value) => RuntimeSubstate::TimeLockedVault(value),
             PersistedSubstate::AccessRulesChain(value) => RuntimeSubstate::AccessRulesChain(value),
             PersistedSubstate::Metadata(value) => RuntimeSubstate::Metadata(value),
             PersistedSubstate::ResourceManager(value) => RuntimeSubstate::ResourceManager(value),
@@ -110,6 +114,8 @@ pub enum RuntimeSubstate {
     Global(GlobalAddressSubstate),
     EpochManager(EpochManagerSubstate),
     CurrentTimeRoundedToMinutes(CurrentTimeRoundedToMinutesSubstate),
+    Burner(BurnerSubstate),
+    TimeLockedVault(TimeLockedVaultSubstate),
     ResourceManager(ResourceManagerSubstate),
     AccessRulesChain(AccessRulesChainSubstate),
     Metadata(MetadataSubstate),
@@ -141,6 +147,10 @@ impl RuntimeSubstate {
             RuntimeSubstate::CurrentTimeRoundedToMinutes(value) => {
                 PersistedSubstate::CurrentTimeRoundedToMinutes(value.clone())
             }
+            RuntimeSubstate::Burner(value) => PersistedSubstate::Burner(value.clone()),
+            RuntimeSubstate::TimeLockedVault(value) => {
+                PersistedSubstate::TimeLockedVault(value.clone())
+            }
             RuntimeSubstate::Metadata(value) => PersistedSubstate::Metadata(value.clone()),
             RuntimeSubstate::ResourceManager(value) => {
                 PersistedSubstate::ResourceManager(value.clone())
@@ -190,6 +200,8 @@ impl RuntimeSubstate {
             RuntimeSubstate::CurrentTimeRoundedToMinutes(value) => {
                 PersistedSubstate::CurrentTimeRoundedToMinutes(value)
             }
+            RuntimeSubstate::Burner(value) => PersistedSubstate::Burner(value),
+            RuntimeSubstate::TimeLockedVault(value) => PersistedSubstate::TimeLockedVault(value),
             RuntimeSubstate::Metadata(value) => PersistedSubstate::Metadata(value),
             RuntimeSubstate::ResourceManager(value) => PersistedSubstate::ResourceManager(value),
             RuntimeSubstate::ComponentInfo(value) => PersistedSubstate::ComponentInfo(value),
@@ -264,6 +276,8 @@ impl RuntimeSubstate {
             RuntimeSubstate::CurrentTimeRoundedToMinutes(value) => {
                 SubstateRefMut::CurrentTimeRoundedToMinutes(value)
             }
+            RuntimeSubstate::Burner(value) => SubstateRefMut::Burner(value),
+            RuntimeSubstate::TimeLockedVault(value) => SubstateRefMut::TimeLockedVault(value),
             RuntimeSubstate::AccessRulesChain(value) => SubstateRefMut::AccessRulesChain(value),
             RuntimeSubstate::Metadata(value) => SubstateRefMut::Metadata(value),
             RuntimeSubstate::ResourceManager(value) => SubstateRefMut::ResourceManager(value),
@@ -300,6 +314,8 @@ impl RuntimeSubstate {
             RuntimeSubstate::CurrentTimeRoundedToMinutes(value) => {
                 SubstateRef::CurrentTimeRoundedToMinutes(value)
             }
+            RuntimeSubstate::Burner(value) => SubstateRef::Burner(value),
+            RuntimeSubstate::TimeLockedVault(value) => SubstateRef::TimeLockedVault(value),
             RuntimeSubstate::AccessRulesChain(value) => SubstateRef::AccessRulesChain(value),
             RuntimeSubstate::Metadata(value) => SubstateRef::Metadata(value),
             RuntimeSubstate::ResourceManager(value) => SubstateRef::ResourceManager(value),
@@ -418,6 +434,18 @@ impl Into<RuntimeSubstate> for CurrentTimeRoundedToMinutesSubstate {
     }
 }
 
+impl Into<RuntimeSubstate> for BurnerSubstate {
+    fn into(self) -> RuntimeSubstate {
+        RuntimeSubstate::Burner(self)
+    }
+}
+
+impl Into<RuntimeSubstate> for TimeLockedVaultSubstate {
+    fn into(self) -> RuntimeSubstate {
+        RuntimeSubstate::TimeLockedVault(self)
+    }
+}
+
 impl Into<RuntimeSubstate> for PackageInfoSubstate {
     fn into(self) -> RuntimeSubstate {
         RuntimeSubstate::PackageInfo(self)
@@ -679,36 +707,36 @@ pub enum SubstateRef<'a> {
     ResourceManager(&'a ResourceManagerSubstate),
     EpochManager(&'a EpochManagerSubstate),
     CurrentTimeRoundedToMinutes(&'a CurrentTimeRoundedToMinutesSubstate),
+    Burner(&'a BurnerSubstate),
+    TimeLockedVault(&'a TimeLockedVaultSubstate),
     AccessRulesChain(&'a AccessRulesChainSubstate),
     Metadata(&'a MetadataSubstate),
     Global(&'a GlobalAddressSubstate),
 }
 
 impl<'a> SubstateRef<'a> {
-    pub fn to_scrypto_value(&self) -> IndexedScryptoValue {
-        match self {
-            SubstateRef::Global(value) => IndexedScryptoValue::from_typed(*value),
-            SubstateRef::EpochManager(value) => IndexedScryptoValue::from_typed(*value),
-            SubstateRef::CurrentTimeRoundedToMinutes(value) => {
-                IndexedScryptoValue::from_typed(*value)
-            }
-            SubstateRef::ResourceManager(value) => IndexedScryptoValue::from_typed(*value),
-            SubstateRef::ComponentInfo(value) => IndexedScryptoValue::from_typed(*value),
-            SubstateRef::ComponentState(value) => IndexedScryptoValue::from_typed(*value),
-            SubstateRef::ComponentRoyaltyConfig(value) => IndexedScryptoValue::from_typed(*value),
-            SubstateRef::ComponentRoyaltyAccumulator(value) => {
-                IndexedScryptoValue::from_typed(*value)
-            }
-            SubstateRef::PackageInfo(value) => IndexedScryptoValue::from_typed(*value),
-            SubstateRef::PackageRoyaltyConfig(value) => IndexedScryptoValue::from_typed(*value),
-            SubstateRef::PackageRoyaltyAccumulator(value) => {
-                IndexedScryptoValue::from_typed(*value)
-            }
-            SubstateRef::NonFungible(value) => IndexedScryptoValue::from_typed(*value),
-            SubstateRef::KeyValueStoreEntry(value) => IndexedScryptoValue::from_typed(*value),
-            SubstateRef::AccessRulesChain(value) => IndexedScryptoValue::from_typed(*value),
+    /// Encodes the substate directly, for reading from Scrypto; there's no need to index it
+    pub fn to_scrypto_bytes(&self) -> Vec<u8> {
+        let encoded = match self {
+            SubstateRef::Global(value) => scrypto_encode(*value),
+            SubstateRef::EpochManager(value) => scrypto_encode(*value),
+            SubstateRef::CurrentTimeRoundedToMinutes(value) => scrypto_encode(*value),
+            SubstateRef::Burner(value) => scrypto_encode(*value),
+            SubstateRef::TimeLockedVault(value) => scrypto_encode(*value),
+            SubstateRef::ResourceManager(value) => scrypto_encode(*value),
+            SubstateRef::ComponentInfo(value) => scrypto_encode(*value),
+            SubstateRef::ComponentState(value) => scrypto_encode(*value),
+            SubstateRef::ComponentRoyaltyConfig(value) => scrypto_encode(*value),
+            SubstateRef::ComponentRoyaltyAccumulator(value) => scrypto_encode(*value),
+            SubstateRef::PackageInfo(value) => scrypto_encode(*value),
+            SubstateRef::PackageRoyaltyConfig(value) => scrypto_encode(*value),
+            SubstateRef::PackageRoyaltyAccumulator(value) => scrypto_encode(*value),
+            SubstateRef::NonFungible(value) => scrypto_encode(*value),
+            SubstateRef::KeyValueStoreEntry(value) => scrypto_encode(*value),
+            SubstateRef::AccessRulesChain(value) => scrypto_encode(*value),
             _ => panic!("Unsupported scrypto value"),
-        }
+        };
+        encoded.expect("Failed to encode trusted substate")
     }
 
     pub fn non_fungible(&self) -> &NonFungibleSubstate {
@@ -851,6 +879,20 @@ impl<'a> SubstateRef<'a> {
         }
     }
 
+    pub fn burner(&self) -> &BurnerSubstate {
+        match self {
+            SubstateRef::Burner(substate) => *substate,
+            _ => panic!("Not a burner"),
+        }
+    }
+
+    pub fn time_locked_vault(&self) -> &TimeLockedVaultSubstate {
+        match self {
+            SubstateRef::TimeLockedVault(substate) => *substate,
+            _ => panic!("Not a time-locked vault"),
+        }
+    }
+
     pub fn references_and_owned_nodes(&self) -> (HashSet<GlobalAddress>, HashSet<RENodeId>) {
         match self {
             SubstateRef::Global(global) => {
@@ -868,6 +910,12 @@ impl<'a> SubstateRef<'a> {
                     GlobalAddressSubstate::Clock(clock_id) => {
                         owned_nodes.insert(RENodeId::Clock(*clock_id))
                     }
+                    GlobalAddressSubstate::Burner(burner_id) => {
+                        owned_nodes.insert(RENodeId::Burner(*burner_id))
+                    }
+                    GlobalAddressSubstate::TimeLockedVault(time_locked_vault_id) => {
+                        owned_nodes.insert(RENodeId::TimeLockedVault(*time_locked_vault_id))
+                    }
                     GlobalAddressSubstate::Package(package_id) => {
                         owned_nodes.insert(RENodeId::Package(*package_id))
                     }
@@ -902,6 +950,11 @@ impl<'a> SubstateRef<'a> {
                 }
                 (HashSet::new(), owned_nodes)
             }
+            SubstateRef::TimeLockedVault(substate) => {
+                let mut owned_nodes = HashSet::new();
+                owned_nodes.insert(RENodeId::Vault(substate.vault_id));
+                (HashSet::new(), owned_nodes)
+            }
             SubstateRef::ComponentState(substate) => {
                 let scrypto_value = IndexedScryptoValue::from_slice(&substate.raw).unwrap();
                 (scrypto_value.global_references(), scrypto_value.node_ids())
@@ -919,7 +972,7 @@ impl<'a> SubstateRef<'a> {
             }
             SubstateRef::NonFungible(substate) => {
                 let maybe_scrypto_value = substate
-                    .0
+                    .non_fungible
                     .as_ref()
                     .map(|non_fungible| IndexedScryptoValue::from_typed(non_fungible));
                 if let Some(scrypto_value) = maybe_scrypto_value {
@@ -947,6 +1000,8 @@ pub enum SubstateRefMut<'a> {
     ResourceManager(&'a mut ResourceManagerSubstate),
     EpochManager(&'a mut EpochManagerSubstate),
     CurrentTimeRoundedToMinutes(&'a mut CurrentTimeRoundedToMinutesSubstate),
+    Burner(&'a mut BurnerSubstate),
+    TimeLockedVault(&'a mut TimeLockedVaultSubstate),
     AccessRulesChain(&'a mut AccessRulesChainSubstate),
     Metadata(&'a mut MetadataSubstate),
     Global(&'a mut GlobalAddressSubstate),
@@ -1078,6 +1133,20 @@ impl<'a> SubstateRefMut<'a> {
         }
     }
 
+    pub fn burner(&mut self) -> &mut BurnerSubstate {
+        match self {
+            SubstateRefMut::Burner(value) => *value,
+            _ => panic!("Not a burner"),
+        }
+    }
+
+    pub fn time_locked_vault(&mut self) -> &mut TimeLockedVaultSubstate {
+        match self {
+            SubstateRefMut::TimeLockedVault(value) => *value,
+            _ => panic!("Not a time-locked vault"),
+        }
+    }
+
     pub fn access_rules_chain(&mut self) -> &mut AccessRulesChainSubstate {
         match self {
             SubstateRefMut::AccessRulesChain(value) => *value,
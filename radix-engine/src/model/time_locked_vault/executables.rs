@@ -0,0 +1,402 @@
+use crate::engine::{
+    deref_and_update, ApplicationError, CallFrameUpdate, ExecutableInvocation, LockFlags,
+    NativeExecutor, NativeProcedure, REActor, RENode, ResolvedFunction, ResolvedMethod,
+    ResolverApi, RuntimeError, SystemApi,
+};
+use crate::model::{AccessRulesChainSubstate, GlobalAddressSubstate, TimeLockedVaultSubstate};
+use crate::types::*;
+use crate::wasm::WasmEngine;
+use radix_engine_interface::api::api::{EngineApi, InvokableModel};
+use radix_engine_interface::api::types::{
+    GlobalAddress, NativeFunction, NativeMethod, RENodeId, SubstateOffset, TimeLockedVaultFunction,
+    TimeLockedVaultMethod, TimeLockedVaultOffset,
+};
+use radix_engine_interface::model::*;
+use radix_engine_interface::rule;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum TimeLockedVaultError {
+    VaultLocked {
+        unlock_epoch: u64,
+        current_epoch: u64,
+    },
+    LockCannotBeShortened {
+        unlock_epoch: u64,
+        requested_epoch: u64,
+    },
+}
+
+pub struct TimeLockedVault;
+
+impl TimeLockedVault {
+    /// Locks and reads the time-locked vault substate. The owned vault is only visible to the
+    /// current frame while the returned lock is held, so callers invoking the vault must drop the
+    /// lock afterwards.
+    fn lock_substate<Y>(
+        node_id: RENodeId,
+        system_api: &mut Y,
+    ) -> Result<(LockHandle, TimeLockedVaultSubstate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let handle = system_api.lock_substate(
+            node_id,
+            SubstateOffset::TimeLockedVault(TimeLockedVaultOffset::TimeLockedVault),
+            LockFlags::read_only(),
+        )?;
+        let substate_ref = system_api.get_ref(handle)?;
+        let substate = substate_ref.time_locked_vault().clone();
+        Ok((handle, substate))
+    }
+}
+
+impl<W: WasmEngine> ExecutableInvocation<W> for TimeLockedVaultCreateInvocation {
+    type Exec = NativeExecutor<Self>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        _deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let actor = REActor::Function(ResolvedFunction::Native(NativeFunction::TimeLockedVault(
+            TimeLockedVaultFunction::Create,
+        )));
+        let mut call_frame_update = CallFrameUpdate::empty();
+        call_frame_update
+            .node_refs_to_copy
+            .insert(RENodeId::Global(GlobalAddress::Resource(
+                self.resource_address,
+            )));
+        let executor = NativeExecutor(self);
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for TimeLockedVaultCreateInvocation {
+    type Output = SystemAddress;
+
+    fn main<Y>(self, system_api: &mut Y) -> Result<(Self::Output, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
+    {
+        let vault = system_api.invoke(ResourceManagerCreateVaultInvocation {
+            receiver: self.resource_address,
+        })?;
+
+        let mut access_rules = AccessRules::new();
+        for (method, access_rule) in [
+            (TimeLockedVaultMethod::Deposit, rule!(allow_all)),
+            (TimeLockedVaultMethod::Withdraw, self.beneficiary_rule),
+            (TimeLockedVaultMethod::ExtendLock, self.depositor_rule),
+            (TimeLockedVaultMethod::GetUnlockEpoch, rule!(allow_all)),
+            (TimeLockedVaultMethod::GetAmount, rule!(allow_all)),
+        ] {
+            access_rules.set_method_access_rule(
+                AccessRuleKey::Native(NativeFn::Method(NativeMethod::TimeLockedVault(method))),
+                access_rule,
+            );
+        }
+
+        let underlying_node_id = system_api.allocate_node_id(RENodeType::TimeLockedVault)?;
+        system_api.create_node(
+            underlying_node_id,
+            RENode::TimeLockedVault(
+                TimeLockedVaultSubstate {
+                    vault_id: vault.0,
+                    unlock_epoch: self.unlock_epoch,
+                },
+                AccessRulesChainSubstate {
+                    access_rules_chain: vec![access_rules],
+                },
+            ),
+        )?;
+
+        let global_node_id = system_api.allocate_node_id(RENodeType::GlobalTimeLockedVault)?;
+        system_api.create_node(
+            global_node_id,
+            RENode::Global(GlobalAddressSubstate::TimeLockedVault(
+                underlying_node_id.into(),
+            )),
+        )?;
+
+        let system_address: SystemAddress = global_node_id.into();
+        let mut node_refs_to_copy = HashSet::new();
+        node_refs_to_copy.insert(global_node_id);
+
+        let update = CallFrameUpdate {
+            node_refs_to_copy,
+            nodes_to_move: vec![],
+            nodes_to_borrow: vec![],
+        };
+
+        Ok((system_address, update))
+    }
+}
+
+pub struct TimeLockedVaultDepositExecutable(RENodeId, Bucket);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for TimeLockedVaultDepositInvocation {
+    type Exec = NativeExecutor<TimeLockedVaultDepositExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::move_node(RENodeId::Bucket(self.bucket.0));
+        let receiver = RENodeId::Global(GlobalAddress::System(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::TimeLockedVault(
+                TimeLockedVaultMethod::Deposit,
+            )),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(TimeLockedVaultDepositExecutable(
+            resolved_receiver.receiver,
+            self.bucket,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for TimeLockedVaultDepositExecutable {
+    type Output = ();
+
+    fn main<Y>(self, system_api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let (handle, substate) = TimeLockedVault::lock_substate(self.0, system_api)?;
+        system_api.invoke(VaultPutInvocation {
+            receiver: substate.vault_id,
+            bucket: self.1,
+        })?;
+        system_api.drop_lock(handle)?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+pub struct TimeLockedVaultWithdrawExecutable(RENodeId, Decimal);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for TimeLockedVaultWithdrawInvocation {
+    type Exec = NativeExecutor<TimeLockedVaultWithdrawExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::System(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::TimeLockedVault(
+                TimeLockedVaultMethod::Withdraw,
+            )),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(TimeLockedVaultWithdrawExecutable(
+            resolved_receiver.receiver,
+            self.amount,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for TimeLockedVaultWithdrawExecutable {
+    type Output = Bucket;
+
+    fn main<Y>(self, system_api: &mut Y) -> Result<(Bucket, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let (handle, substate) = TimeLockedVault::lock_substate(self.0, system_api)?;
+        let current_epoch = system_api.read_epoch()?;
+        if current_epoch < substate.unlock_epoch {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::TimeLockedVaultError(TimeLockedVaultError::VaultLocked {
+                    unlock_epoch: substate.unlock_epoch,
+                    current_epoch,
+                }),
+            ));
+        }
+
+        let bucket = system_api.invoke(VaultTakeInvocation {
+            receiver: substate.vault_id,
+            amount: self.1,
+        })?;
+        system_api.drop_lock(handle)?;
+
+        let bucket_id = bucket.0;
+
+        Ok((
+            bucket,
+            CallFrameUpdate::move_node(RENodeId::Bucket(bucket_id)),
+        ))
+    }
+}
+
+pub struct TimeLockedVaultExtendLockExecutable(RENodeId, u64);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for TimeLockedVaultExtendLockInvocation {
+    type Exec = NativeExecutor<TimeLockedVaultExtendLockExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::System(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::TimeLockedVault(
+                TimeLockedVaultMethod::ExtendLock,
+            )),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(TimeLockedVaultExtendLockExecutable(
+            resolved_receiver.receiver,
+            self.unlock_epoch,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for TimeLockedVaultExtendLockExecutable {
+    type Output = ();
+
+    fn main<Y>(self, system_api: &mut Y) -> Result<((), CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let handle = system_api.lock_substate(
+            self.0,
+            SubstateOffset::TimeLockedVault(TimeLockedVaultOffset::TimeLockedVault),
+            LockFlags::MUTABLE,
+        )?;
+        let mut substate_mut = system_api.get_ref_mut(handle)?;
+        let substate = substate_mut.time_locked_vault();
+        if self.1 < substate.unlock_epoch {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::TimeLockedVaultError(
+                    TimeLockedVaultError::LockCannotBeShortened {
+                        unlock_epoch: substate.unlock_epoch,
+                        requested_epoch: self.1,
+                    },
+                ),
+            ));
+        }
+        substate.unlock_epoch = self.1;
+        system_api.drop_lock(handle)?;
+
+        Ok(((), CallFrameUpdate::empty()))
+    }
+}
+
+pub struct TimeLockedVaultGetUnlockEpochExecutable(RENodeId);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for TimeLockedVaultGetUnlockEpochInvocation {
+    type Exec = NativeExecutor<TimeLockedVaultGetUnlockEpochExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::System(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::TimeLockedVault(
+                TimeLockedVaultMethod::GetUnlockEpoch,
+            )),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(TimeLockedVaultGetUnlockEpochExecutable(
+            resolved_receiver.receiver,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for TimeLockedVaultGetUnlockEpochExecutable {
+    type Output = u64;
+
+    fn main<Y>(self, system_api: &mut Y) -> Result<(u64, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi,
+    {
+        let (handle, substate) = TimeLockedVault::lock_substate(self.0, system_api)?;
+        system_api.drop_lock(handle)?;
+        Ok((substate.unlock_epoch, CallFrameUpdate::empty()))
+    }
+}
+
+pub struct TimeLockedVaultGetAmountExecutable(RENodeId);
+
+impl<W: WasmEngine> ExecutableInvocation<W> for TimeLockedVaultGetAmountInvocation {
+    type Exec = NativeExecutor<TimeLockedVaultGetAmountExecutable>;
+
+    fn resolve<D: ResolverApi<W>>(
+        self,
+        deref: &mut D,
+    ) -> Result<(REActor, CallFrameUpdate, Self::Exec), RuntimeError>
+    where
+        Self: Sized,
+    {
+        let mut call_frame_update = CallFrameUpdate::empty();
+        let receiver = RENodeId::Global(GlobalAddress::System(self.receiver));
+        let resolved_receiver = deref_and_update(receiver, &mut call_frame_update, deref)?;
+
+        let actor = REActor::Method(
+            ResolvedMethod::Native(NativeMethod::TimeLockedVault(
+                TimeLockedVaultMethod::GetAmount,
+            )),
+            resolved_receiver,
+        );
+        let executor = NativeExecutor(TimeLockedVaultGetAmountExecutable(
+            resolved_receiver.receiver,
+        ));
+
+        Ok((actor, call_frame_update, executor))
+    }
+}
+
+impl NativeProcedure for TimeLockedVaultGetAmountExecutable {
+    type Output = Decimal;
+
+    fn main<Y>(self, system_api: &mut Y) -> Result<(Decimal, CallFrameUpdate), RuntimeError>
+    where
+        Y: SystemApi + InvokableModel<RuntimeError>,
+    {
+        let (handle, substate) = TimeLockedVault::lock_substate(self.0, system_api)?;
+        let amount = system_api.invoke(VaultGetAmountInvocation {
+            receiver: substate.vault_id,
+        })?;
+        system_api.drop_lock(handle)?;
+        Ok((amount, CallFrameUpdate::empty()))
+    }
+}
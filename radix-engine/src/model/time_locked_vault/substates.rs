@@ -0,0 +1,10 @@
+use crate::types::*;
+use radix_engine_interface::api::types::VaultId;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct TimeLockedVaultSubstate {
+    pub vault_id: VaultId,
+    /// The first epoch at which the beneficiary may withdraw
+    pub unlock_epoch: u64,
+}
@@ -5,7 +5,10 @@ use radix_engine_interface::api::types::{
     BucketId, GlobalAddress, NativeFn, NativeFunction, NativeFunctionIdent, NativeMethodIdent,
     ProofId, RENodeId, TransactionProcessorFunction,
 };
-use radix_engine_interface::data::{IndexedScryptoValue, ValueReplacingError};
+use radix_engine_interface::args;
+use radix_engine_interface::data::{
+    IndexedScryptoValue, ScryptoCustomTypeId, ScryptoCustomValue, ScryptoValue, ValueReplacingError,
+};
 use radix_engine_interface::model::*;
 use sbor::rust::borrow::Cow;
 use transaction::errors::IdAllocationError;
@@ -15,7 +18,7 @@ use transaction::validation::*;
 use crate::engine::*;
 use crate::model::resolve_native_function;
 use crate::model::resolve_native_method;
-use crate::model::{InvokeError, WorktopSubstate};
+use crate::model::{IntoInvokeError, InvokeError, WorktopSubstate};
 use crate::types::*;
 use crate::wasm::WasmEngine;
 
@@ -45,6 +48,27 @@ pub enum TransactionProcessorError {
     NativeFunctionNotFound(NativeFunctionIdent),
     NativeMethodNotFound(NativeMethodIdent),
     IdAllocationError(IdAllocationError),
+    /// A call whose returned bucket is to be kept didn't return exactly one bucket.
+    InvalidReturnedBucketCount(usize),
+    /// A call whose returned proof is to be kept didn't return exactly one proof.
+    InvalidReturnedProofCount(usize),
+    /// The target of a worktop deposit is not an account component.
+    NotAnAccount(ComponentAddress),
+    /// A `Local` expression names a variable which hasn't been set.
+    LocalVariableNotFound(String),
+    /// A conditional block is nested in another; blocks are limited to one level.
+    NestedConditionalBlock,
+    InstructionFailed {
+        index: usize,
+        instruction: Instruction,
+        error: Box<RuntimeError>,
+    },
+}
+
+impl From<IdAllocationError> for TransactionProcessorError {
+    fn from(error: IdAllocationError) -> Self {
+        Self::IdAllocationError(error)
+    }
 }
 
 impl<'a> Invocation for TransactionProcessorRunInvocation<'a> {
@@ -68,44 +92,7 @@ impl<'a, W: WasmEngine> ExecutableInvocation<W> for TransactionProcessorRunInvoc
                 .insert(RENodeId::Global(global_address));
         }
 
-        // TODO: This can be refactored out once any type in sbor is implemented
-        for instruction in self.instructions.as_ref() {
-            match instruction {
-                Instruction::CallFunction { args, .. }
-                | Instruction::CallMethod { args, .. }
-                | Instruction::CallNativeFunction { args, .. } => {
-                    let scrypto_value =
-                        IndexedScryptoValue::from_slice(&args).expect("Invalid CALL arguments");
-                    for global_address in scrypto_value.global_references() {
-                        call_frame_update
-                            .node_refs_to_copy
-                            .insert(RENodeId::Global(global_address));
-                    }
-                }
-                Instruction::CallNativeMethod { args, method_ident } => {
-                    let scrypto_value =
-                        IndexedScryptoValue::from_slice(&args).expect("Invalid CALL arguments");
-                    for global_address in scrypto_value.global_references() {
-                        call_frame_update
-                            .node_refs_to_copy
-                            .insert(RENodeId::Global(global_address));
-                    }
-
-                    // TODO: This needs to be cleaned up
-                    // TODO: How does this relate to newly created vaults in the transaction frame?
-                    // TODO: Will probably want different spacing for refed vs. owned nodes
-                    match method_ident.receiver {
-                        RENodeId::Vault(..) => {
-                            call_frame_update
-                                .node_refs_to_copy
-                                .insert(method_ident.receiver);
-                        }
-                        _ => {}
-                    }
-                }
-                _ => {}
-            }
-        }
+        add_call_refs(self.instructions.as_ref(), &mut call_frame_update);
         call_frame_update
             .node_refs_to_copy
             .insert(RENodeId::Global(GlobalAddress::Resource(RADIX_TOKEN)));
@@ -153,6 +140,64 @@ impl<'a> NativeProcedure for TransactionProcessorRunInvocation<'a> {
     }
 }
 
+// TODO: This can be refactored out once any type in sbor is implemented
+fn add_call_refs(instructions: &[Instruction], call_frame_update: &mut CallFrameUpdate) {
+    for instruction in instructions {
+        match instruction {
+            Instruction::CallFunction { args, .. }
+            | Instruction::CallMethod { args, .. }
+            | Instruction::CallMethodWithReturn { args, .. }
+            | Instruction::CallMethodWithProofReturn { args, .. }
+            | Instruction::CallNativeFunction { args, .. } => {
+                let scrypto_value =
+                    IndexedScryptoValue::from_slice(&args).expect("Invalid CALL arguments");
+                for global_address in scrypto_value.global_references() {
+                    call_frame_update
+                        .node_refs_to_copy
+                        .insert(RENodeId::Global(global_address));
+                }
+            }
+            Instruction::CallNativeMethod { args, method_ident } => {
+                let scrypto_value =
+                    IndexedScryptoValue::from_slice(&args).expect("Invalid CALL arguments");
+                for global_address in scrypto_value.global_references() {
+                    call_frame_update
+                        .node_refs_to_copy
+                        .insert(RENodeId::Global(global_address));
+                }
+
+                // TODO: This needs to be cleaned up
+                // TODO: How does this relate to newly created vaults in the transaction frame?
+                // TODO: Will probably want different spacing for refed vs. owned nodes
+                match method_ident.receiver {
+                    RENodeId::Vault(..) => {
+                        call_frame_update
+                            .node_refs_to_copy
+                            .insert(method_ident.receiver);
+                    }
+                    _ => {}
+                }
+            }
+            Instruction::IfWorktopContains {
+                then_instructions,
+                else_instructions,
+                ..
+            } => {
+                add_call_refs(then_instructions, call_frame_update);
+                add_call_refs(else_instructions, call_frame_update);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn id_allocation_count(instructions: &[Instruction]) -> u32 {
+    instructions
+        .iter()
+        .map(Instruction::id_allocation_count)
+        .sum()
+}
+
 pub struct TransactionProcessor {}
 
 impl TransactionProcessor {
@@ -176,36 +221,58 @@ impl TransactionProcessor {
 
     fn process_expressions<'a, Y>(
         args: IndexedScryptoValue,
+        local_variables: &HashMap<String, ScryptoValue>,
         env: &mut Y,
     ) -> Result<IndexedScryptoValue, InvokeError<TransactionProcessorError>>
     where
         Y: EngineApi<RuntimeError> + InvokableModel<RuntimeError>,
     {
+        // Values without expressions are passed on as they are, without being re-encoded
+        if args.expressions.is_empty() {
+            return Ok(args);
+        }
+
         let mut value = args.dom;
         for (expression, path) in args.expressions {
+            if let Some(name) = expression.local_name() {
+                let local = local_variables.get(name).ok_or_else(|| {
+                    InvokeError::Error(TransactionProcessorError::LocalVariableNotFound(
+                        name.to_string(),
+                    ))
+                })?;
+                let val = path
+                    .get_from_value_mut(&mut value)
+                    .expect("Failed to locate an expression value using SBOR path");
+                *val = local.clone();
+                continue;
+            }
+
             match expression.0.as_str() {
                 "ENTIRE_WORKTOP" => {
-                    let buckets = Worktop::sys_drain(env).map_err(InvokeError::Downstream)?;
+                    let buckets = Worktop::sys_drain(env)?;
 
                     let val = path
                         .get_from_value_mut(&mut value)
                         .expect("Failed to locate an expression value using SBOR path");
-                    *val = scrypto_decode(
-                        &scrypto_encode(&buckets).expect("Failed to encode Vec<Bucket>"),
+                    *val = Self::node_array(
+                        ScryptoCustomTypeId::Bucket,
+                        buckets
+                            .into_iter()
+                            .map(|bucket| ScryptoCustomValue::Bucket(bucket.0)),
                     )
-                    .expect("Failed to decode Vec<Bucket>")
                 }
                 "ENTIRE_AUTH_ZONE" => {
-                    let proofs =
-                        ComponentAuthZone::sys_drain(env).map_err(InvokeError::Downstream)?;
+                    let proofs = ComponentAuthZone::sys_drain(env)?;
 
                     let val = path
                         .get_from_value_mut(&mut value)
                         .expect("Failed to locate an expression value using SBOR path");
-                    *val = scrypto_decode(
-                        &scrypto_encode(&proofs).expect("Failed to encode Vec<Proof>"),
+                    *val = Self::node_array(
+                        ScryptoCustomTypeId::Proof,
+                        proofs
+                            .into_iter()
+                            .map(|proof| ScryptoCustomValue::Proof(proof.0)),
                     )
-                    .expect("Failed to decode Vec<Proof>")
                 }
                 _ => {} // no-op
             }
@@ -215,6 +282,48 @@ impl TransactionProcessor {
             .expect("SborValue became invalid post expression transformation"))
     }
 
+    /// Builds the value of a `Vec<Bucket>` or `Vec<Proof>` directly, which is what encoding and
+    /// decoding the `Vec` would produce.
+    fn node_array<I: Iterator<Item = ScryptoCustomValue>>(
+        type_id: ScryptoCustomTypeId,
+        values: I,
+    ) -> ScryptoValue {
+        SborValue::Array {
+            element_type_id: SborTypeId::Custom(type_id),
+            elements: values.map(|value| SborValue::Custom { value }).collect(),
+        }
+    }
+
+    /// Checks that a component is an account, from its blueprint, before anything is moved to it.
+    fn check_is_account<Y>(
+        account: ComponentAddress,
+        api: &mut Y,
+    ) -> Result<(), InvokeError<TransactionProcessorError>>
+    where
+        Y: SystemApi,
+    {
+        let handle = api.lock_substate(
+            RENodeId::Global(GlobalAddress::Component(account)),
+            SubstateOffset::Component(ComponentOffset::Info),
+            LockFlags::read_only(),
+        )?;
+        let is_account = {
+            let substate_ref = api.get_ref(handle)?;
+            let component_info = substate_ref.component_info();
+            component_info.package_address == ACCOUNT_PACKAGE
+                && component_info.blueprint_name == ACCOUNT_BLUEPRINT
+        };
+        api.drop_lock(handle)?;
+
+        if is_account {
+            Ok(())
+        } else {
+            Err(InvokeError::Error(TransactionProcessorError::NotAnAccount(
+                account,
+            )))
+        }
+    }
+
     fn perform_validation<'a, Y>(
         request: &RuntimeValidationRequest,
         env: &mut Y,
@@ -259,6 +368,84 @@ impl TransactionProcessor {
         }
     }
 
+    fn evaluate_condition<Y>(
+        condition: &WorktopCondition,
+        api: &mut Y,
+    ) -> Result<bool, InvokeError<TransactionProcessorError>>
+    where
+        Y: InvokableModel<RuntimeError>,
+    {
+        let holds = match condition {
+            WorktopCondition::Contains { resource_address } => {
+                !Worktop::sys_get_amount(*resource_address, api)?.is_zero()
+            }
+            WorktopCondition::ContainsByAmount {
+                amount,
+                resource_address,
+            } => Worktop::sys_get_amount(*resource_address, api)? >= *amount,
+        };
+        Ok(holds)
+    }
+
+    /// Runs the instructions of a branch of a conditional block. At the end of the branch, the
+    /// proofs created in it are dropped and the buckets created in it are returned to the worktop.
+    fn execute_branch<Y>(
+        instructions: &[Instruction],
+        proof_id_mapping: &mut HashMap<ProofId, ProofId>,
+        bucket_id_mapping: &mut HashMap<BucketId, BucketId>,
+        local_variables: &mut HashMap<String, ScryptoValue>,
+        id_allocator: &mut IdAllocator,
+        api: &mut Y,
+    ) -> Result<(), InvokeError<TransactionProcessorError>>
+    where
+        Y: SystemApi
+            + EngineApi<RuntimeError>
+            + Invokable<ScryptoInvocation, RuntimeError>
+            + InvokableModel<RuntimeError>,
+    {
+        let proofs_before: HashSet<ProofId> = proof_id_mapping.keys().cloned().collect();
+        let buckets_before: HashSet<BucketId> = bucket_id_mapping.keys().cloned().collect();
+
+        for inst in instructions {
+            if let Instruction::IfWorktopContains { .. } = inst {
+                return Err(InvokeError::Error(
+                    TransactionProcessorError::NestedConditionalBlock,
+                ));
+            }
+            Self::execute_instruction(
+                inst,
+                proof_id_mapping,
+                bucket_id_mapping,
+                local_variables,
+                id_allocator,
+                api,
+            )?;
+        }
+
+        let mut new_proofs: Vec<ProofId> = proof_id_mapping
+            .keys()
+            .filter(|proof_id| !proofs_before.contains(proof_id))
+            .cloned()
+            .collect();
+        new_proofs.sort();
+        for proof_id in new_proofs {
+            let real_id = proof_id_mapping.remove(&proof_id).unwrap();
+            Proof(real_id).sys_drop(api)?;
+        }
+        let mut new_buckets: Vec<BucketId> = bucket_id_mapping
+            .keys()
+            .filter(|bucket_id| !buckets_before.contains(bucket_id))
+            .cloned()
+            .collect();
+        new_buckets.sort();
+        for bucket_id in new_buckets {
+            let real_id = bucket_id_mapping.remove(&bucket_id).unwrap();
+            Worktop::sys_put(Bucket(real_id), api)?;
+        }
+
+        Ok(())
+    }
+
     pub fn run<Y>(
         input: TransactionProcessorRunInvocation,
         api: &mut Y,
@@ -274,418 +461,547 @@ impl TransactionProcessor {
         }
         let mut proof_id_mapping = HashMap::new();
         let mut bucket_id_mapping = HashMap::new();
+        // Dropped along with the rest of the execution context at the end of the transaction
+        let mut local_variables = HashMap::<String, ScryptoValue>::new();
         let mut outputs = Vec::new();
         let mut id_allocator = IdAllocator::new(IdSpace::Transaction);
 
         let node_id = api.allocate_node_id(RENodeType::Worktop)?;
-        let _worktop_id = api
-            .create_node(node_id, RENode::Worktop(WorktopSubstate::new()))
-            .map_err(InvokeError::Downstream)?;
+        let _worktop_id = api.create_node(node_id, RENode::Worktop(WorktopSubstate::new()))?;
 
-        api.emit_event(Event::Runtime(RuntimeEvent::PreExecuteManifest))
-            .map_err(InvokeError::Downstream)?;
+        api.emit_event(Event::Runtime(RuntimeEvent::PreExecuteManifest))?;
 
         for (idx, inst) in input.instructions.as_ref().iter().enumerate() {
             api.emit_event(Event::Runtime(RuntimeEvent::PreExecuteInstruction {
                 instruction_index: idx,
                 instruction: &inst,
-            }))
-            .map_err(InvokeError::Downstream)?;
-
-            let result = match inst {
-                Instruction::TakeFromWorktop { resource_address } => id_allocator
-                    .new_bucket_id()
-                    .map_err(|e| {
-                        InvokeError::Error(TransactionProcessorError::IdAllocationError(e))
-                    })
-                    .and_then(|new_id| {
-                        Worktop::sys_take_all(*resource_address, api)
-                            .map_err(InvokeError::Downstream)
-                            .map(|bucket| {
-                                bucket_id_mapping.insert(new_id, bucket.0);
-                                IndexedScryptoValue::from_typed(&bucket)
-                            })
-                    }),
-                Instruction::TakeFromWorktopByAmount {
-                    amount,
-                    resource_address,
-                } => id_allocator
-                    .new_bucket_id()
-                    .map_err(|e| {
-                        InvokeError::Error(TransactionProcessorError::IdAllocationError(e))
-                    })
-                    .and_then(|new_id| {
-                        Worktop::sys_take_amount(*resource_address, *amount, api)
-                            .map_err(InvokeError::Downstream)
-                            .map(|bucket| {
-                                bucket_id_mapping.insert(new_id, bucket.0);
-                                IndexedScryptoValue::from_typed(&bucket)
-                            })
-                    }),
-                Instruction::TakeFromWorktopByIds {
-                    ids,
-                    resource_address,
-                } => id_allocator
-                    .new_bucket_id()
-                    .map_err(|e| {
-                        InvokeError::Error(TransactionProcessorError::IdAllocationError(e))
-                    })
-                    .and_then(|new_id| {
-                        Worktop::sys_take_non_fungibles(*resource_address, ids.clone(), api)
-                            .map_err(InvokeError::Downstream)
-                            .map(|bucket| {
-                                bucket_id_mapping.insert(new_id, bucket.0);
-                                IndexedScryptoValue::from_typed(&bucket)
-                            })
-                    }),
-                Instruction::ReturnToWorktop { bucket_id } => bucket_id_mapping
-                    .remove(bucket_id)
-                    .map(|real_id| {
-                        Worktop::sys_put(Bucket(real_id), api)
-                            .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
-                            .map_err(InvokeError::Downstream)
-                    })
-                    .unwrap_or(Err(InvokeError::Error(
-                        TransactionProcessorError::BucketNotFound(*bucket_id),
-                    ))),
-                Instruction::AssertWorktopContains { resource_address } => {
-                    Worktop::sys_assert_contains(*resource_address, api)
-                        .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
+            }))?;
+
+            let result = Self::execute_instruction(
+                inst,
+                &mut proof_id_mapping,
+                &mut bucket_id_mapping,
+                &mut local_variables,
+                &mut id_allocator,
+                api,
+            )
+            .map_err(|e| {
+                InvokeError::Error(TransactionProcessorError::InstructionFailed {
+                    index: idx,
+                    instruction: inst.clone(),
+                    error: Box::new(e.into()),
+                })
+            })?;
+            outputs.push(result);
+
+            api.emit_event(Event::Runtime(RuntimeEvent::PostExecuteInstruction {
+                instruction_index: idx,
+                instruction: &inst,
+            }))?;
+        }
+
+        for (callback, fee) in api.fee_callbacks() {
+            api.invoke(ScryptoInvocation::Function(callback, args!(fee)))?;
+        }
+
+        api.emit_event(Event::Runtime(RuntimeEvent::PostExecuteManifest))?;
+
+        Ok(outputs
+            .into_iter()
+            .map(|sv| sv.raw)
+            .collect::<Vec<Vec<u8>>>())
+    }
+
+    fn execute_instruction<Y>(
+        inst: &Instruction,
+        proof_id_mapping: &mut HashMap<ProofId, ProofId>,
+        bucket_id_mapping: &mut HashMap<BucketId, BucketId>,
+        local_variables: &mut HashMap<String, ScryptoValue>,
+        id_allocator: &mut IdAllocator,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, InvokeError<TransactionProcessorError>>
+    where
+        Y: SystemApi
+            + EngineApi<RuntimeError>
+            + Invokable<ScryptoInvocation, RuntimeError>
+            + InvokableModel<RuntimeError>,
+    {
+        match inst {
+            Instruction::TakeFromWorktop { resource_address } => id_allocator
+                .new_bucket_id()
+                .into_invoke::<TransactionProcessorError>()
+                .and_then(|new_id| {
+                    Worktop::sys_take_all(*resource_address, api)
                         .map_err(InvokeError::Downstream)
-                }
-                Instruction::AssertWorktopContainsByAmount {
-                    amount,
-                    resource_address,
-                } => Worktop::sys_assert_contains_amount(*resource_address, *amount, api)
-                    .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
-                    .map_err(InvokeError::Downstream),
-                Instruction::AssertWorktopContainsByIds {
-                    ids,
-                    resource_address,
-                } => {
-                    Worktop::sys_assert_contains_non_fungibles(*resource_address, ids.clone(), api)
+                        .map(|bucket| {
+                            bucket_id_mapping.insert(new_id, bucket.0);
+                            IndexedScryptoValue::from_typed(&bucket)
+                        })
+                }),
+            Instruction::TakeFromWorktopByAmount {
+                amount,
+                resource_address,
+            } => id_allocator
+                .new_bucket_id()
+                .into_invoke::<TransactionProcessorError>()
+                .and_then(|new_id| {
+                    Worktop::sys_take_amount(*resource_address, *amount, api)
+                        .map_err(InvokeError::Downstream)
+                        .map(|bucket| {
+                            bucket_id_mapping.insert(new_id, bucket.0);
+                            IndexedScryptoValue::from_typed(&bucket)
+                        })
+                }),
+            Instruction::TakeFromWorktopOrDefault {
+                amount,
+                resource_address,
+            } => id_allocator
+                .new_bucket_id()
+                .into_invoke::<TransactionProcessorError>()
+                .and_then(|new_id| {
+                    Worktop::sys_take_or_default(*resource_address, *amount, api)
+                        .map_err(InvokeError::Downstream)
+                        .map(|bucket| {
+                            bucket_id_mapping.insert(new_id, bucket.0);
+                            IndexedScryptoValue::from_typed(&bucket)
+                        })
+                }),
+            Instruction::TakeFromWorktopByIds {
+                ids,
+                resource_address,
+            } => id_allocator
+                .new_bucket_id()
+                .into_invoke::<TransactionProcessorError>()
+                .and_then(|new_id| {
+                    Worktop::sys_take_non_fungibles(*resource_address, ids.clone(), api)
+                        .map_err(InvokeError::Downstream)
+                        .map(|bucket| {
+                            bucket_id_mapping.insert(new_id, bucket.0);
+                            IndexedScryptoValue::from_typed(&bucket)
+                        })
+                }),
+            Instruction::ReturnToWorktop { bucket_id } => bucket_id_mapping
+                .remove(bucket_id)
+                .map(|real_id| {
+                    Worktop::sys_put(Bucket(real_id), api)
                         .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
                         .map_err(InvokeError::Downstream)
+                })
+                .unwrap_or(Err(InvokeError::Error(
+                    TransactionProcessorError::BucketNotFound(*bucket_id),
+                ))),
+            Instruction::AssertWorktopContains { resource_address } => {
+                Worktop::sys_assert_contains(*resource_address, api)
+                    .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
+                    .map_err(InvokeError::Downstream)
+            }
+            Instruction::AssertWorktopContainsByAmount {
+                amount,
+                resource_address,
+            } => Worktop::sys_assert_contains_amount(*resource_address, *amount, api)
+                .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
+                .map_err(InvokeError::Downstream),
+            Instruction::AssertWorktopContainsExactly {
+                amount,
+                resource_address,
+            } => Worktop::sys_assert_contains_exact_amount(*resource_address, *amount, api)
+                .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
+                .map_err(InvokeError::Downstream),
+            Instruction::AssertWorktopContainsByIds {
+                ids,
+                resource_address,
+            } => Worktop::sys_assert_contains_non_fungibles(*resource_address, ids.clone(), api)
+                .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
+                .map_err(InvokeError::Downstream),
+
+            Instruction::IfWorktopContains {
+                condition,
+                then_instructions,
+                else_instructions,
+            } => {
+                let holds = Self::evaluate_condition(condition, api)?;
+                // IDs are allocated as if both branches ran, then branch first
+                let (branch, skipped) = if holds {
+                    (then_instructions, else_instructions)
+                } else {
+                    id_allocator
+                        .skip(id_allocation_count(then_instructions))
+                        .into_invoke::<TransactionProcessorError>()?;
+                    (else_instructions, then_instructions)
+                };
+                Self::execute_branch(
+                    branch,
+                    proof_id_mapping,
+                    bucket_id_mapping,
+                    local_variables,
+                    id_allocator,
+                    api,
+                )?;
+                if holds {
+                    id_allocator
+                        .skip(id_allocation_count(skipped))
+                        .into_invoke::<TransactionProcessorError>()?;
                 }
+                Ok(IndexedScryptoValue::from_typed(&holds))
+            }
 
-                Instruction::PopFromAuthZone {} => id_allocator
-                    .new_proof_id()
-                    .map_err(|e| {
-                        InvokeError::Error(TransactionProcessorError::IdAllocationError(e))
-                    })
-                    .and_then(|new_id| {
-                        ComponentAuthZone::sys_pop(api)
-                            .map_err(InvokeError::Downstream)
-                            .map(|proof| {
-                                proof_id_mapping.insert(new_id, proof.0);
-                                IndexedScryptoValue::from_typed(&proof)
-                            })
-                    }),
-                Instruction::ClearAuthZone => {
-                    proof_id_mapping.clear();
-                    ComponentAuthZone::sys_clear(api)
+            Instruction::PopFromAuthZone {} => id_allocator
+                .new_proof_id()
+                .into_invoke::<TransactionProcessorError>()
+                .and_then(|new_id| {
+                    ComponentAuthZone::sys_pop(api)
+                        .map_err(InvokeError::Downstream)
+                        .map(|proof| {
+                            proof_id_mapping.insert(new_id, proof.0);
+                            IndexedScryptoValue::from_typed(&proof)
+                        })
+                }),
+            Instruction::ClearAuthZone => {
+                proof_id_mapping.clear();
+                ComponentAuthZone::sys_clear(api)
+                    .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
+                    .map_err(InvokeError::Downstream)
+            }
+            Instruction::PushToAuthZone { proof_id } => proof_id_mapping
+                .remove(proof_id)
+                .ok_or(InvokeError::Error(
+                    TransactionProcessorError::ProofNotFound(*proof_id),
+                ))
+                .and_then(|real_id| {
+                    let proof = Proof(real_id);
+                    ComponentAuthZone::sys_push(proof, api)
                         .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
                         .map_err(InvokeError::Downstream)
-                }
-                Instruction::PushToAuthZone { proof_id } => proof_id_mapping
-                    .remove(proof_id)
-                    .ok_or(InvokeError::Error(
-                        TransactionProcessorError::ProofNotFound(*proof_id),
-                    ))
-                    .and_then(|real_id| {
-                        let proof = Proof(real_id);
-                        ComponentAuthZone::sys_push(proof, api)
-                            .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
-                            .map_err(InvokeError::Downstream)
-                    }),
-                Instruction::CreateProofFromAuthZone { resource_address } => id_allocator
-                    .new_proof_id()
-                    .map_err(|e| {
-                        InvokeError::Error(TransactionProcessorError::IdAllocationError(e))
-                    })
-                    .and_then(|new_id| {
-                        ComponentAuthZone::sys_create_proof(*resource_address, api)
-                            .map_err(InvokeError::Downstream)
-                            .map(|proof| {
-                                proof_id_mapping.insert(new_id, proof.0);
-                                IndexedScryptoValue::from_typed(&proof)
-                            })
-                    }),
-                Instruction::CreateProofFromAuthZoneByAmount {
-                    amount,
-                    resource_address,
-                } => id_allocator
-                    .new_proof_id()
-                    .map_err(|e| {
-                        InvokeError::Error(TransactionProcessorError::IdAllocationError(e))
-                    })
-                    .and_then(|new_id| {
-                        ComponentAuthZone::sys_create_proof_by_amount(
-                            *amount,
-                            *resource_address,
-                            api,
-                        )
+                }),
+            Instruction::CreateProofFromAuthZone { resource_address } => id_allocator
+                .new_proof_id()
+                .into_invoke::<TransactionProcessorError>()
+                .and_then(|new_id| {
+                    ComponentAuthZone::sys_create_proof(*resource_address, api)
                         .map_err(InvokeError::Downstream)
                         .map(|proof| {
                             proof_id_mapping.insert(new_id, proof.0);
                             IndexedScryptoValue::from_typed(&proof)
                         })
-                    }),
-                Instruction::CreateProofFromAuthZoneByIds {
-                    ids,
-                    resource_address,
-                } => id_allocator
-                    .new_proof_id()
-                    .map_err(|e| {
-                        InvokeError::Error(TransactionProcessorError::IdAllocationError(e))
-                    })
-                    .and_then(|new_id| {
-                        ComponentAuthZone::sys_create_proof_by_ids(ids, *resource_address, api)
-                            .map_err(InvokeError::Downstream)
-                            .map(|proof| {
-                                proof_id_mapping.insert(new_id, proof.0);
-                                IndexedScryptoValue::from_typed(&proof)
-                            })
-                    }),
-                Instruction::CreateProofFromBucket { bucket_id } => id_allocator
-                    .new_proof_id()
-                    .map_err(|e| {
-                        InvokeError::Error(TransactionProcessorError::IdAllocationError(e))
-                    })
-                    .and_then(|new_id| {
-                        bucket_id_mapping
-                            .get(bucket_id)
-                            .cloned()
-                            .map(|real_bucket_id| (new_id, real_bucket_id))
-                            .ok_or(InvokeError::Error(
-                                TransactionProcessorError::BucketNotFound(new_id),
-                            ))
-                    })
-                    .and_then(|(new_id, real_bucket_id)| {
-                        let bucket = Bucket(real_bucket_id);
-                        bucket
-                            .sys_create_proof(api)
-                            .map_err(InvokeError::Downstream)
-                            .map(|proof| {
-                                proof_id_mapping.insert(new_id, proof.0);
-                                IndexedScryptoValue::from_typed(&proof)
-                            })
-                    }),
-                Instruction::CloneProof { proof_id } => id_allocator
-                    .new_proof_id()
-                    .map_err(|e| {
-                        InvokeError::Error(TransactionProcessorError::IdAllocationError(e))
-                    })
-                    .and_then(|new_id| {
-                        proof_id_mapping
-                            .get(proof_id)
-                            .cloned()
-                            .map(|real_id| {
-                                let proof = Proof(real_id);
-                                proof
-                                    .sys_clone(api)
-                                    .map_err(InvokeError::Downstream)
-                                    .map(|proof| {
-                                        proof_id_mapping.insert(new_id, proof.0);
-                                        IndexedScryptoValue::from_typed(&proof)
-                                    })
-                            })
-                            .unwrap_or(Err(InvokeError::Error(
-                                TransactionProcessorError::ProofNotFound(*proof_id),
-                            )))
-                    }),
-                Instruction::DropProof { proof_id } => proof_id_mapping
-                    .remove(proof_id)
-                    .map(|real_id| {
-                        let proof = Proof(real_id);
-                        proof
-                            .sys_drop(api)
-                            .map(|_| IndexedScryptoValue::unit())
-                            .map_err(InvokeError::Downstream)
-                    })
-                    .unwrap_or(Err(InvokeError::Error(
-                        TransactionProcessorError::ProofNotFound(*proof_id),
-                    ))),
-                Instruction::DropAllProofs => {
-                    for (_, real_id) in proof_id_mapping.drain() {
-                        let proof = Proof(real_id);
-                        proof
-                            .sys_drop(api)
-                            .map(|_| IndexedScryptoValue::unit())
-                            .map_err(InvokeError::Downstream)?;
-                    }
-                    ComponentAuthZone::sys_clear(api)
-                        .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
+                }),
+            Instruction::CreateProofFromAuthZoneByAmount {
+                amount,
+                resource_address,
+            } => id_allocator
+                .new_proof_id()
+                .into_invoke::<TransactionProcessorError>()
+                .and_then(|new_id| {
+                    ComponentAuthZone::sys_create_proof_by_amount(*amount, *resource_address, api)
                         .map_err(InvokeError::Downstream)
-                }
-                Instruction::CallFunction {
-                    function_ident,
-                    args,
-                } => {
-                    Self::replace_ids(
-                        &mut proof_id_mapping,
-                        &mut bucket_id_mapping,
-                        IndexedScryptoValue::from_slice(args)
-                            .expect("Invalid CALL_FUNCTION arguments"),
-                    )
-                    .and_then(|args| Self::process_expressions(args, api))
-                    .and_then(|args| {
-                        api.invoke(ParsedScryptoInvocation::Function(
-                            function_ident.clone(),
-                            args,
+                        .map(|proof| {
+                            proof_id_mapping.insert(new_id, proof.0);
+                            IndexedScryptoValue::from_typed(&proof)
+                        })
+                }),
+            Instruction::CreateProofFromAuthZoneByIds {
+                ids,
+                resource_address,
+            } => id_allocator
+                .new_proof_id()
+                .into_invoke::<TransactionProcessorError>()
+                .and_then(|new_id| {
+                    ComponentAuthZone::sys_create_proof_by_ids(ids, *resource_address, api)
+                        .map_err(InvokeError::Downstream)
+                        .map(|proof| {
+                            proof_id_mapping.insert(new_id, proof.0);
+                            IndexedScryptoValue::from_typed(&proof)
+                        })
+                }),
+            Instruction::CreateProofFromBucket { bucket_id } => id_allocator
+                .new_proof_id()
+                .into_invoke::<TransactionProcessorError>()
+                .and_then(|new_id| {
+                    bucket_id_mapping
+                        .get(bucket_id)
+                        .cloned()
+                        .map(|real_bucket_id| (new_id, real_bucket_id))
+                        .ok_or(InvokeError::Error(
+                            TransactionProcessorError::BucketNotFound(new_id),
                         ))
+                })
+                .and_then(|(new_id, real_bucket_id)| {
+                    let bucket = Bucket(real_bucket_id);
+                    bucket
+                        .sys_create_proof(api)
                         .map_err(InvokeError::Downstream)
-                    })
-                    .and_then(|result| {
-                        // Auto move into auth_zone
-                        for (proof_id, _) in &result.proof_ids {
-                            let proof = Proof(*proof_id);
-                            ComponentAuthZone::sys_push(proof, api)
-                                .map_err(InvokeError::Downstream)?;
-                        }
-                        // Auto move into worktop
-                        for (bucket_id, _) in &result.bucket_ids {
-                            Worktop::sys_put(Bucket(*bucket_id), api)
-                                .map_err(InvokeError::Downstream)?;
-                        }
-                        Ok(result)
-                    })
-                }
-                Instruction::CallMethod { method_ident, args } => {
-                    Self::replace_ids(
-                        &mut proof_id_mapping,
-                        &mut bucket_id_mapping,
-                        IndexedScryptoValue::from_slice(args)
-                            .expect("Invalid CALL_METHOD arguments"),
-                    )
-                    .and_then(|args| Self::process_expressions(args, api))
-                    .and_then(|args| {
-                        api.invoke(ParsedScryptoInvocation::Method(method_ident.clone(), args))
-                            .map_err(InvokeError::Downstream)
-                    })
-                    .and_then(|result| {
-                        // Auto move into auth_zone
-                        for (proof_id, _) in &result.proof_ids {
-                            let proof = Proof(*proof_id);
-                            ComponentAuthZone::sys_push(proof, api)
-                                .map_err(InvokeError::Downstream)?;
-                        }
-                        // Auto move into worktop
-                        for (bucket_id, _) in &result.bucket_ids {
-                            Worktop::sys_put(Bucket(*bucket_id), api)
-                                .map_err(InvokeError::downstream)?;
-                        }
-                        Ok(result)
-                    })
+                        .map(|proof| {
+                            proof_id_mapping.insert(new_id, proof.0);
+                            IndexedScryptoValue::from_typed(&proof)
+                        })
+                }),
+            Instruction::CloneProof { proof_id } => id_allocator
+                .new_proof_id()
+                .into_invoke::<TransactionProcessorError>()
+                .and_then(|new_id| {
+                    proof_id_mapping
+                        .get(proof_id)
+                        .cloned()
+                        .map(|real_id| {
+                            let proof = Proof(real_id);
+                            proof
+                                .sys_clone(api)
+                                .map_err(InvokeError::Downstream)
+                                .map(|proof| {
+                                    proof_id_mapping.insert(new_id, proof.0);
+                                    IndexedScryptoValue::from_typed(&proof)
+                                })
+                        })
+                        .unwrap_or(Err(InvokeError::Error(
+                            TransactionProcessorError::ProofNotFound(*proof_id),
+                        )))
+                }),
+            Instruction::DropProof { proof_id } => proof_id_mapping
+                .remove(proof_id)
+                .map(|real_id| {
+                    let proof = Proof(real_id);
+                    proof
+                        .sys_drop(api)
+                        .map(|_| IndexedScryptoValue::unit())
+                        .map_err(InvokeError::Downstream)
+                })
+                .unwrap_or(Err(InvokeError::Error(
+                    TransactionProcessorError::ProofNotFound(*proof_id),
+                ))),
+            Instruction::DropAllProofs => {
+                for (_, real_id) in proof_id_mapping.drain() {
+                    let proof = Proof(real_id);
+                    proof.sys_drop(api).map(|_| IndexedScryptoValue::unit())?;
                 }
-                Instruction::PublishPackageWithOwner {
-                    code,
-                    abi,
-                    owner_badge,
-                } => api
-                    .invoke(PackagePublishWithOwnerInvocation {
-                        code: code.clone(),
-                        abi: abi.clone(),
-                        royalty_config: HashMap::new(),
-                        metadata: HashMap::new(),
-                        owner_badge: owner_badge.clone(),
-                    })
-                    .map(|address| IndexedScryptoValue::from_typed(&address))
-                    .map_err(InvokeError::Downstream),
-                Instruction::CallNativeFunction {
-                    function_ident,
-                    args,
-                } => {
-                    Self::replace_ids(
-                        &mut proof_id_mapping,
-                        &mut bucket_id_mapping,
-                        IndexedScryptoValue::from_slice(args)
-                            .expect("Invalid CALL_NATIVE_FUNCTION arguments"),
-                    )
-                    .and_then(|args| Self::process_expressions(args, api))
-                    .and_then(|args| {
-                        let native_function = resolve_native_function(
-                            &function_ident.blueprint_name,
-                            &function_ident.function_name,
-                        )
-                        .ok_or(InvokeError::Error(
-                            TransactionProcessorError::NativeFunctionNotFound(
-                                function_ident.clone(),
+                ComponentAuthZone::sys_clear(api)
+                    .map(|rtn| IndexedScryptoValue::from_typed(&rtn))
+                    .map_err(InvokeError::Downstream)
+            }
+            Instruction::CallFunction {
+                function_ident,
+                args,
+            } => {
+                Self::replace_ids(
+                    proof_id_mapping,
+                    bucket_id_mapping,
+                    IndexedScryptoValue::from_slice(args).expect("Invalid CALL_FUNCTION arguments"),
+                )
+                .and_then(|args| Self::process_expressions(args, local_variables, api))
+                .and_then(|args| {
+                    api.invoke(ParsedScryptoInvocation::Function(
+                        function_ident.clone(),
+                        args,
+                    ))
+                    .map_err(InvokeError::Downstream)
+                })
+                .and_then(|result| {
+                    // Auto move into auth_zone
+                    for (proof_id, _) in &result.proof_ids {
+                        let proof = Proof(*proof_id);
+                        ComponentAuthZone::sys_push(proof, api)?;
+                    }
+                    // Auto move into worktop
+                    for (bucket_id, _) in &result.bucket_ids {
+                        Worktop::sys_put(Bucket(*bucket_id), api)?;
+                    }
+                    Ok(result)
+                })
+            }
+            Instruction::CallMethod { method_ident, args } => {
+                Self::replace_ids(
+                    proof_id_mapping,
+                    bucket_id_mapping,
+                    IndexedScryptoValue::from_slice(args).expect("Invalid CALL_METHOD arguments"),
+                )
+                .and_then(|args| Self::process_expressions(args, local_variables, api))
+                .and_then(|args| {
+                    api.invoke(ParsedScryptoInvocation::Method(method_ident.clone(), args))
+                        .map_err(InvokeError::Downstream)
+                })
+                .and_then(|result| {
+                    // Auto move into auth_zone
+                    for (proof_id, _) in &result.proof_ids {
+                        let proof = Proof(*proof_id);
+                        ComponentAuthZone::sys_push(proof, api)?;
+                    }
+                    // Auto move into worktop
+                    for (bucket_id, _) in &result.bucket_ids {
+                        Worktop::sys_put(Bucket(*bucket_id), api)
+                            .map_err(InvokeError::downstream)?;
+                    }
+                    Ok(result)
+                })
+            }
+            Instruction::CallMethodWithReturn { method_ident, args } => {
+                Self::replace_ids(
+                    proof_id_mapping,
+                    bucket_id_mapping,
+                    IndexedScryptoValue::from_slice(args)
+                        .expect("Invalid CALL_METHOD_WITH_RETURN arguments"),
+                )
+                .and_then(|args| Self::process_expressions(args, local_variables, api))
+                .and_then(|args| {
+                    api.invoke(ParsedScryptoInvocation::Method(method_ident.clone(), args))
+                        .map_err(InvokeError::Downstream)
+                })
+                .and_then(|result| {
+                    // Auto move into auth_zone
+                    for (proof_id, _) in &result.proof_ids {
+                        let proof = Proof(*proof_id);
+                        ComponentAuthZone::sys_push(proof, api)?;
+                    }
+                    // Keep the returned bucket in the transaction context
+                    if result.bucket_ids.len() != 1 {
+                        return Err(InvokeError::Error(
+                            TransactionProcessorError::InvalidReturnedBucketCount(
+                                result.bucket_ids.len(),
                             ),
-                        ))?;
-                        parse_and_invoke_native_fn(
-                            NativeFn::Function(native_function),
-                            args.raw,
-                            api,
-                        )
+                        ));
+                    }
+                    let new_id = id_allocator
+                        .new_bucket_id()
+                        .into_invoke::<TransactionProcessorError>()?;
+                    for (bucket_id, _) in &result.bucket_ids {
+                        bucket_id_mapping.insert(new_id, *bucket_id);
+                    }
+                    Ok(result)
+                })
+            }
+            Instruction::CallMethodWithProofReturn { method_ident, args } => {
+                Self::replace_ids(
+                    proof_id_mapping,
+                    bucket_id_mapping,
+                    IndexedScryptoValue::from_slice(args)
+                        .expect("Invalid CALL_METHOD_WITH_PROOF_RETURN arguments"),
+                )
+                .and_then(|args| Self::process_expressions(args, local_variables, api))
+                .and_then(|args| {
+                    api.invoke(ParsedScryptoInvocation::Method(method_ident.clone(), args))
                         .map_err(InvokeError::Downstream)
-                    })
-                    .and_then(|result| {
-                        // Auto move into auth_zone
-                        for (proof_id, _) in &result.proof_ids {
-                            let proof = Proof(*proof_id);
-                            ComponentAuthZone::sys_push(proof, api)
-                                .map_err(InvokeError::Downstream)?;
-                        }
-                        // Auto move into worktop
-                        for (bucket_id, _) in &result.bucket_ids {
-                            Worktop::sys_put(Bucket(*bucket_id), api)
-                                .map_err(InvokeError::Downstream)?;
-                        }
-                        Ok(result)
-                    })
-                }
-                Instruction::CallNativeMethod { method_ident, args } => {
-                    Self::replace_ids(
-                        &mut proof_id_mapping,
-                        &mut bucket_id_mapping,
-                        IndexedScryptoValue::from_slice(args)
-                            .expect("Invalid CALL_NATIVE_METHOD arguments"),
+                })
+                .and_then(|result| {
+                    // Keep the returned proof in the transaction context
+                    if result.proof_ids.len() != 1 {
+                        return Err(InvokeError::Error(
+                            TransactionProcessorError::InvalidReturnedProofCount(
+                                result.proof_ids.len(),
+                            ),
+                        ));
+                    }
+                    let new_id = id_allocator
+                        .new_proof_id()
+                        .into_invoke::<TransactionProcessorError>()?;
+                    for (proof_id, _) in &result.proof_ids {
+                        proof_id_mapping.insert(new_id, *proof_id);
+                    }
+                    // Auto move into worktop
+                    for (bucket_id, _) in &result.bucket_ids {
+                        Worktop::sys_put(Bucket(*bucket_id), api)
+                            .map_err(InvokeError::downstream)?;
+                    }
+                    Ok(result)
+                })
+            }
+            Instruction::DepositEntireWorktop { account } => Self::check_is_account(*account, api)
+                .and_then(|_| Worktop::sys_drain(api).map_err(InvokeError::Downstream))
+                .and_then(|buckets| {
+                    let method_ident = ScryptoMethodIdent {
+                        receiver: ScryptoReceiver::Global(*account),
+                        method_name: "deposit_batch".to_string(),
+                    };
+                    let args = IndexedScryptoValue::from_slice(&args!(buckets))
+                        .expect("Failed to encode deposit_batch arguments");
+                    api.invoke(ParsedScryptoInvocation::Method(method_ident, args))
+                        .map_err(InvokeError::Downstream)
+                }),
+            Instruction::SetLocal { name, value } => {
+                let value =
+                    IndexedScryptoValue::from_slice(value).expect("Invalid SET_LOCAL value");
+                local_variables.insert(name.clone(), value.dom);
+                Ok(IndexedScryptoValue::unit())
+            }
+            Instruction::PublishPackageWithOwner {
+                code,
+                abi,
+                owner_badge,
+            } => api
+                .invoke(PackagePublishWithOwnerInvocation {
+                    code: code.clone(),
+                    abi: abi.clone(),
+                    royalty_config: HashMap::new(),
+                    metadata: BTreeMap::new(),
+                    owner_badge: owner_badge.clone(),
+                })
+                .map(|address| IndexedScryptoValue::from_typed(&address))
+                .map_err(InvokeError::Downstream),
+            Instruction::CallNativeFunction {
+                function_ident,
+                args,
+            } => {
+                Self::replace_ids(
+                    proof_id_mapping,
+                    bucket_id_mapping,
+                    IndexedScryptoValue::from_slice(args)
+                        .expect("Invalid CALL_NATIVE_FUNCTION arguments"),
+                )
+                .and_then(|args| Self::process_expressions(args, local_variables, api))
+                .and_then(|args| {
+                    let native_function = resolve_native_function(
+                        &function_ident.blueprint_name,
+                        &function_ident.function_name,
                     )
-                    .and_then(|args| Self::process_expressions(args, api))
-                    .and_then(|args| {
-                        let native_method =
-                            resolve_native_method(method_ident.receiver, &method_ident.method_name)
-                                .ok_or(InvokeError::Error(
-                                    TransactionProcessorError::NativeMethodNotFound(
-                                        method_ident.clone(),
-                                    ),
-                                ))?;
-
-                        parse_and_invoke_native_fn(NativeFn::Method(native_method), args.raw, api)
-                            .map_err(InvokeError::Downstream)
-                    })
-                    .and_then(|result| {
-                        // Auto move into auth_zone
-                        for (proof_id, _) in &result.proof_ids {
-                            let proof = Proof(*proof_id);
-                            ComponentAuthZone::sys_push(proof, api)
-                                .map_err(InvokeError::Downstream)?;
-                        }
-                        // Auto move into worktop
-                        for (bucket_id, _) in &result.bucket_ids {
-                            Worktop::sys_put(Bucket(*bucket_id), api)
-                                .map_err(InvokeError::downstream)?;
-                        }
-                        Ok(result)
-                    })
-                }
-            }?;
-            outputs.push(result);
+                    .ok_or(InvokeError::Error(
+                        TransactionProcessorError::NativeFunctionNotFound(function_ident.clone()),
+                    ))?;
+                    parse_and_invoke_native_fn(NativeFn::Function(native_function), args.raw, api)
+                        .map_err(InvokeError::Downstream)
+                })
+                .and_then(|result| {
+                    // Auto move into auth_zone
+                    for (proof_id, _) in &result.proof_ids {
+                        let proof = Proof(*proof_id);
+                        ComponentAuthZone::sys_push(proof, api)?;
+                    }
+                    // Auto move into worktop
+                    for (bucket_id, _) in &result.bucket_ids {
+                        Worktop::sys_put(Bucket(*bucket_id), api)?;
+                    }
+                    Ok(result)
+                })
+            }
+            Instruction::CallNativeMethod { method_ident, args } => {
+                Self::replace_ids(
+                    proof_id_mapping,
+                    bucket_id_mapping,
+                    IndexedScryptoValue::from_slice(args)
+                        .expect("Invalid CALL_NATIVE_METHOD arguments"),
+                )
+                .and_then(|args| Self::process_expressions(args, local_variables, api))
+                .and_then(|args| {
+                    let native_method =
+                        resolve_native_method(method_ident.receiver, &method_ident.method_name)
+                            .ok_or(InvokeError::Error(
+                                TransactionProcessorError::NativeMethodNotFound(
+                                    method_ident.clone(),
+                                ),
+                            ))?;
 
-            api.emit_event(Event::Runtime(RuntimeEvent::PostExecuteInstruction {
-                instruction_index: idx,
-                instruction: &inst,
-            }))
-            .map_err(InvokeError::Downstream)?;
+                    parse_and_invoke_native_fn(NativeFn::Method(native_method), args.raw, api)
+                        .map_err(InvokeError::Downstream)
+                })
+                .and_then(|result| {
+                    // Auto move into auth_zone
+                    for (proof_id, _) in &result.proof_ids {
+                        let proof = Proof(*proof_id);
+                        ComponentAuthZone::sys_push(proof, api)?;
+                    }
+                    // Auto move into worktop
+                    for (bucket_id, _) in &result.bucket_ids {
+                        Worktop::sys_put(Bucket(*bucket_id), api)
+                            .map_err(InvokeError::downstream)?;
+                    }
+                    Ok(result)
+                })
+            }
         }
-
-        api.emit_event(Event::Runtime(RuntimeEvent::PostExecuteManifest))
-            .map_err(InvokeError::Downstream)?;
-
-        Ok(outputs
-            .into_iter()
-            .map(|sv| sv.raw)
-            .collect::<Vec<Vec<u8>>>())
     }
 }
@@ -7,6 +7,7 @@ struct StagedSubstateStoreNode {
     parent_id: u64,
     locked: bool,
     outputs: BTreeMap<SubstateId, OutputValue>,
+    tree_nodes: BTreeMap<StateTreeNodeKey, Hash>,
 }
 
 impl StagedSubstateStoreNode {
@@ -15,6 +16,7 @@ impl StagedSubstateStoreNode {
             parent_id,
             locked: false,
             outputs: BTreeMap::new(),
+            tree_nodes: BTreeMap::new(),
         }
     }
 }
@@ -63,6 +65,16 @@ impl<'s, S: ReadableSubstateStore> StagedSubstateStoreManager<'s, S> {
         StagedSubstateStore { stores: self, id }
     }
 
+    /// Drops the node along with all of its descendants, without merging anything
+    pub fn discard(&mut self, id: u64) {
+        if id == 0 {
+            panic!("Cannot discard root store");
+        }
+
+        self.remove_children(id);
+        self.nodes.remove(&id);
+    }
+
     fn remove_children(&mut self, id: u64) {
         let mut to_delete = Vec::new();
         for (to_delete_id, node) in &self.nodes {
@@ -77,7 +89,9 @@ impl<'s, S: ReadableSubstateStore> StagedSubstateStoreManager<'s, S> {
     }
 }
 
-impl<'s, S: ReadableSubstateStore + WriteableSubstateStore> StagedSubstateStoreManager<'s, S> {
+impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + StateTreeStore>
+    StagedSubstateStoreManager<'s, S>
+{
     fn set_root_parent(&mut self, id: u64) {
         for node in self.nodes.values_mut().filter(|node| id == node.parent_id) {
             node.parent_id = 0;
@@ -106,6 +120,9 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore> StagedSubstateStoreM
         for (substate_id, output) in node.outputs {
             self.root.put_substate(substate_id, output);
         }
+        for (key, hash) in node.tree_nodes {
+            self.root.put_tree_node(key, hash);
+        }
 
         if !remove_children {
             self.set_root_parent(id);
@@ -131,6 +148,22 @@ impl<'t, 's, S: ReadableSubstateStore> StagedSubstateStore<'t, 's, S> {
 
         self.get_substate_recurse(substate_id, node.parent_id)
     }
+
+    fn get_tree_node_recurse(&self, key: &StateTreeNodeKey, id: u64) -> Option<Hash>
+    where
+        S: StateTreeStore,
+    {
+        if id == 0 {
+            return self.stores.root.get_tree_node(key);
+        }
+
+        let node = self.stores.nodes.get(&id).unwrap();
+        if let Some(hash) = node.tree_nodes.get(key) {
+            return Some(*hash);
+        }
+
+        self.get_tree_node_recurse(key, node.parent_id)
+    }
 }
 
 impl<'t, 's, S: ReadableSubstateStore> ReadableSubstateStore for StagedSubstateStore<'t, 's, S> {
@@ -150,6 +183,23 @@ impl<'t, 's, S: ReadableSubstateStore> WriteableSubstateStore for StagedSubstate
     }
 }
 
+impl<'t, 's, S: ReadableSubstateStore + StateTreeStore> StateTreeStore
+    for StagedSubstateStore<'t, 's, S>
+{
+    fn get_tree_node(&self, key: &StateTreeNodeKey) -> Option<Hash> {
+        self.get_tree_node_recurse(key, self.id)
+    }
+
+    fn put_tree_node(&mut self, key: StateTreeNodeKey, hash: Hash) {
+        if self.id == 0 {
+            panic!("Cannot write to root store");
+        } else {
+            let node = self.stores.nodes.get_mut(&self.id).unwrap();
+            node.tree_nodes.insert(key, hash);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::ledger::TypedInMemorySubstateStore;
@@ -183,4 +233,22 @@ mod tests {
         let node = stores.nodes.get(&child_node10).expect("Should exist");
         assert_eq!(node.parent_id, child_node9);
     }
+
+    #[test]
+    fn test_discard_removes_descendants() {
+        // Arrange
+        let mut store = TypedInMemorySubstateStore::with_bootstrap();
+        let mut stores = StagedSubstateStoreManager::new(&mut store);
+        let child_node1 = stores.new_child_node(0);
+        let child_node2 = stores.new_child_node(child_node1);
+        let _child_node3 = stores.new_child_node(child_node2);
+        let child_node4 = stores.new_child_node(0);
+
+        // Act
+        stores.discard(child_node1);
+
+        // Assert
+        assert_eq!(stores.nodes.len(), 1);
+        assert!(stores.nodes.contains_key(&child_node4));
+    }
 }
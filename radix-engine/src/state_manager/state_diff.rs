@@ -45,4 +45,18 @@ impl StateDiff {
 
         receipt
     }
+
+    /// Folds the state changes into the state tree kept alongside some substate store, returning
+    /// the new state root hash.
+    pub fn commit_to_state_tree<S: StateTreeStore>(&self, store: &mut S) -> Hash {
+        update_state_tree(
+            store,
+            self.up_substates.iter().map(|(substate_id, output_value)| {
+                (
+                    substate_id.clone(),
+                    substate_leaf_hash(substate_id, output_value),
+                )
+            }),
+        )
+    }
 }
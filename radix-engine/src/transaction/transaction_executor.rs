@@ -1,13 +1,14 @@
 use crate::engine::Track;
 use crate::engine::*;
 use crate::fee::{FeeReserve, FeeTable, SystemLoanFeeReserve};
-use crate::ledger::{ReadableSubstateStore, WriteableSubstateStore};
+use crate::ledger::{ReadableSubstateStore, StateTreeStore, WriteableSubstateStore};
 use crate::model::*;
 use crate::transaction::*;
 use crate::types::*;
 use crate::wasm::*;
 use radix_engine_constants::{
-    DEFAULT_COST_UNIT_PRICE, DEFAULT_MAX_CALL_DEPTH, DEFAULT_SYSTEM_LOAN,
+    DEFAULT_COST_UNIT_PRICE, DEFAULT_MAX_AUTH_ZONE_PROOFS, DEFAULT_MAX_CALL_DEPTH,
+    DEFAULT_MAX_OWNED_NODES_PER_FRAME, DEFAULT_MAX_SYSCALLS_PER_TRANSACTION, DEFAULT_SYSTEM_LOAN,
 };
 use radix_engine_interface::api::api::Invokable;
 use sbor::rust::borrow::Cow;
@@ -35,8 +36,16 @@ impl FeeReserveConfig {
 
 pub struct ExecutionConfig {
     pub max_call_depth: usize,
+    pub max_owned_nodes_per_frame: usize,
+    pub max_auth_zone_proofs: usize,
+    pub max_syscalls_per_transaction: u32,
     pub trace: bool,
     pub max_sys_call_trace_depth: usize,
+    pub charge_storage_fees: bool,
+    /// When set, publishing a package requires a proof of this badge
+    pub publish_badge: Option<ResourceAddress>,
+    /// When set, engine metrics are collected into the receipt
+    pub debug_metrics: bool,
 }
 
 impl Default for ExecutionConfig {
@@ -49,18 +58,36 @@ impl ExecutionConfig {
     pub fn standard() -> Self {
         Self {
             max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+            max_owned_nodes_per_frame: DEFAULT_MAX_OWNED_NODES_PER_FRAME,
+            max_auth_zone_proofs: DEFAULT_MAX_AUTH_ZONE_PROOFS,
+            max_syscalls_per_transaction: DEFAULT_MAX_SYSCALLS_PER_TRANSACTION,
             trace: false,
             max_sys_call_trace_depth: 1,
+            charge_storage_fees: false,
+            publish_badge: None,
+            debug_metrics: false,
         }
     }
 
     pub fn debug() -> Self {
         Self {
             max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+            max_owned_nodes_per_frame: DEFAULT_MAX_OWNED_NODES_PER_FRAME,
+            max_auth_zone_proofs: DEFAULT_MAX_AUTH_ZONE_PROOFS,
+            max_syscalls_per_transaction: DEFAULT_MAX_SYSCALLS_PER_TRANSACTION,
             trace: true,
             max_sys_call_trace_depth: 1,
+            charge_storage_fees: false,
+            publish_badge: None,
+            debug_metrics: true,
         }
     }
+
+    /// Restricts package publishing to holders of the given badge, as on permissioned networks.
+    pub fn permissioned_publish(mut self, badge: ResourceAddress) -> Self {
+        self.publish_badge = Some(badge);
+        self
+    }
 }
 
 /// An executor that runs transactions.
@@ -135,7 +162,7 @@ where
 
         // Apply pre execution costing
         let pre_execution_result = track.apply_pre_execution_costs(transaction);
-        let track = match pre_execution_result {
+        let mut track = match pre_execution_result {
             Ok(track) => track,
             Err(err) => {
                 return TransactionReceipt {
@@ -146,6 +173,8 @@ where
                         fee_summary: err.fee_summary,
                         application_logs: vec![],
                         events: vec![],
+                        syscall_count: 0,
+                        debug_metrics: None,
                     },
                     result: TransactionResult::Reject(RejectResult {
                         error: RejectionError::ErrorBeforeFeeLoanRepaid(RuntimeError::ModuleError(
@@ -156,23 +185,37 @@ where
             }
         };
 
+        if execution_config.debug_metrics {
+            track.engine_metrics = Some(EngineMetrics::default());
+        }
+
         // Invoke the function/method
-        let track_receipt = {
+        let (track_receipt, syscall_count) = {
             let mut modules = Vec::<Box<dyn Module<R>>>::new();
             if execution_config.trace {
                 modules.push(Box::new(LoggerModule::new()));
             }
             modules.push(Box::new(CostingModule::default()));
             modules.push(Box::new(RoyaltyModule::default()));
+            if execution_config.charge_storage_fees {
+                modules.push(Box::new(StorageFeeModule::default()));
+            }
             modules.push(Box::new(ExecutionTraceModule::new(
                 execution_config.max_sys_call_trace_depth,
             )));
+            if execution_config.debug_metrics {
+                modules.push(Box::new(MetricsModule::default()));
+            }
 
             let mut kernel = Kernel::new(
                 transaction_hash.clone(),
                 auth_zone_params.clone(),
                 blobs,
                 execution_config.max_call_depth,
+                execution_config.max_owned_nodes_per_frame,
+                execution_config.max_auth_zone_proofs,
+                execution_config.max_syscalls_per_transaction,
+                execution_config.publish_badge,
                 track,
                 self.scrypto_interpreter,
                 modules,
@@ -183,7 +226,8 @@ where
                 instructions: Cow::Borrowed(instructions),
             });
 
-            kernel.finalize(invoke_result)
+            let syscall_count = kernel.syscall_count();
+            (kernel.finalize(invoke_result), syscall_count)
         };
 
         let receipt = TransactionReceipt {
@@ -194,6 +238,8 @@ where
                 fee_summary: track_receipt.fee_summary,
                 application_logs: track_receipt.application_logs,
                 events: track_receipt.events,
+                syscall_count,
+                debug_metrics: track_receipt.engine_metrics,
             },
             result: track_receipt.result,
         };
@@ -223,7 +269,7 @@ where
 }
 
 pub fn execute_and_commit_transaction<
-    S: ReadableSubstateStore + WriteableSubstateStore,
+    S: ReadableSubstateStore + WriteableSubstateStore + StateTreeStore,
     W: WasmEngine,
 >(
     substate_store: &mut S,
@@ -232,15 +278,16 @@ pub fn execute_and_commit_transaction<
     execution_config: &ExecutionConfig,
     transaction: &Executable,
 ) -> TransactionReceipt {
-    let receipt = execute_transaction(
+    let mut receipt = execute_transaction(
         substate_store,
         scrypto_interpreter,
         fee_reserve_config,
         execution_config,
         transaction,
     );
-    if let TransactionResult::Commit(commit) = &receipt.result {
+    if let TransactionResult::Commit(commit) = &mut receipt.result {
         commit.state_updates.commit(substate_store);
+        commit.state_root_hash = Some(commit.state_updates.commit_to_state_tree(substate_store));
     }
     receipt
 }
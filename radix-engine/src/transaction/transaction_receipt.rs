@@ -1,4 +1,7 @@
+#[cfg(not(feature = "alloc"))]
 use colored::*;
+#[cfg(feature = "alloc")]
+use no_color::*;
 use radix_engine_interface::address::{AddressDisplayContext, NO_NETWORK};
 use radix_engine_interface::api::types::{GlobalAddress, Level};
 use radix_engine_interface::data::{IndexedScryptoValue, ScryptoDecode};
@@ -7,11 +10,42 @@ use transaction::manifest::decompiler::{decompile_instruction, DecompilationCont
 use transaction::model::*;
 use utils::ContextualDisplay;
 
-use crate::engine::{RejectionError, ResourceChange, RuntimeError, TrackedEvent};
-use crate::fee::FeeSummary;
+use crate::engine::{
+    ActorSummary, EngineMetrics, RejectionError, ResourceChange, RuntimeError, TrackedEvent,
+};
+use crate::fee::{CostingActor, FeeSummary};
 use crate::state_manager::StateDiff;
 use crate::types::*;
 
+/// Without `std` there is no terminal, and receipts are displayed without color.
+#[cfg(feature = "alloc")]
+mod no_color {
+    use sbor::rust::string::{String, ToString};
+
+    pub trait Colorize: AsRef<str> {
+        fn normal(&self) -> String {
+            self.as_ref().to_string()
+        }
+        fn bold(&self) -> String {
+            self.normal()
+        }
+        fn red(&self) -> String {
+            self.normal()
+        }
+        fn green(&self) -> String {
+            self.normal()
+        }
+        fn yellow(&self) -> String {
+            self.normal()
+        }
+        fn cyan(&self) -> String {
+            self.normal()
+        }
+    }
+
+    impl<T: AsRef<str> + ?Sized> Colorize for T {}
+}
+
 #[derive(Debug, Clone)]
 #[scrypto(TypeId, Encode, Decode)]
 pub struct TransactionContents {
@@ -24,6 +58,10 @@ pub struct TransactionExecution {
     pub fee_summary: FeeSummary,
     pub application_logs: Vec<(Level, String)>,
     pub events: Vec<TrackedEvent>,
+    /// The number of invocations the transaction made
+    pub syscall_count: u32,
+    /// Engine metrics, if enabled in the execution config
+    pub debug_metrics: Option<EngineMetrics>,
 }
 
 /// Captures whether a transaction should be committed, and its other results
@@ -50,6 +88,9 @@ pub struct CommitResult {
     pub state_updates: StateDiff,
     pub entity_changes: EntityChanges,
     pub resource_changes: Vec<ResourceChange>,
+    /// The state root hash after the transaction was committed, if it has been committed to a
+    /// store which maintains a state tree.
+    pub state_root_hash: Option<Hash>,
 }
 
 /// Captures whether a transaction's commit outcome is Success or Failure
@@ -86,18 +127,61 @@ pub struct EntityChanges {
     pub new_component_addresses: Vec<ComponentAddress>,
     pub new_resource_addresses: Vec<ResourceAddress>,
     pub new_system_addresses: Vec<SystemAddress>,
+    /// All new global entities in creation order, with the actor which created them
+    pub new_entities: Vec<NewEntity>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum NewEntityKind {
+    Package,
+    Component,
+    Resource,
+    System,
+}
+
+/// A global entity created by a transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub struct NewEntity {
+    pub address: GlobalAddress,
+    pub kind: NewEntityKind,
+    /// The actor whose invocation (transitively) created the entity
+    pub created_by: ActorSummary,
+    /// The index of the manifest instruction during which the entity was created
+    pub instruction_index: Option<usize>,
 }
 
 impl EntityChanges {
-    pub fn new(new_global_addresses: Vec<GlobalAddress>) -> Self {
+    pub fn new(
+        new_global_addresses: Vec<GlobalAddress>,
+        mut creators: HashMap<GlobalAddress, (ActorSummary, Option<usize>)>,
+    ) -> Self {
         let mut entity_changes = Self {
             new_package_addresses: Vec::new(),
             new_component_addresses: Vec::new(),
             new_resource_addresses: Vec::new(),
             new_system_addresses: Vec::new(),
+            new_entities: Vec::new(),
         };
 
         for new_global_address in new_global_addresses {
+            let kind = match new_global_address {
+                GlobalAddress::Package(..) => NewEntityKind::Package,
+                GlobalAddress::Component(..) => NewEntityKind::Component,
+                GlobalAddress::Resource(..) => NewEntityKind::Resource,
+                GlobalAddress::System(..) => NewEntityKind::System,
+            };
+            let (created_by, instruction_index) = creators
+                .remove(&new_global_address)
+                .unwrap_or((ActorSummary::Transaction, None));
+            entity_changes.new_entities.push(NewEntity {
+                address: new_global_address,
+                kind,
+                created_by,
+                instruction_index,
+            });
+
             match new_global_address {
                 GlobalAddress::Package(package_address) => {
                     entity_changes.new_package_addresses.push(package_address)
@@ -197,6 +281,8 @@ impl TransactionReceipt {
         }
     }
 
+    /// Asserts that the transaction failed, with an error (stripped of any failed instruction
+    /// context) matching the given predicate.
     pub fn expect_specific_failure<F>(&self, f: F)
     where
         F: FnOnce(&RuntimeError) -> bool,
@@ -205,7 +291,7 @@ impl TransactionReceipt {
             TransactionResult::Commit(c) => match &c.outcome {
                 TransactionOutcome::Success(_) => panic!("Expected failure but was success"),
                 TransactionOutcome::Failure(err) => {
-                    if !f(&err) {
+                    if !f(err.cause()) {
                         panic!(
                             "Expected specific failure but was different error:\n{:?}",
                             self
@@ -217,11 +303,37 @@ impl TransactionReceipt {
         }
     }
 
+    /// Asserts that the transaction failed at the manifest instruction with the given index, with
+    /// an error matching the given predicate.
+    pub fn expect_specific_failure_at<F>(&self, index: usize, f: F)
+    where
+        F: FnOnce(&RuntimeError) -> bool,
+    {
+        let failed_index = self.expect_commit_failure().failed_instruction_index();
+        if failed_index != Some(index) {
+            panic!(
+                "Expected failure at instruction {} but was at {:?}:\n{:?}",
+                index, failed_index, self
+            );
+        }
+        self.expect_specific_failure(f);
+    }
+
     pub fn output<T: ScryptoDecode>(&self, nth: usize) -> T {
         scrypto_decode::<T>(&self.expect_commit_success()[nth][..])
             .expect("Wrong instruction output type!")
     }
 
+    /// Asserts that the transaction failed because a blueprint returned `Err`, and decodes the
+    /// error value.
+    pub fn expect_blueprint_error<T: ScryptoDecode>(&self) -> T {
+        let value = self
+            .expect_commit_failure()
+            .blueprint_error_value()
+            .expect("Expected failure to be a blueprint error");
+        scrypto_decode::<T>(value).expect("Wrong blueprint error type!")
+    }
+
     pub fn new_package_addresses(&self) -> &Vec<PackageAddress> {
         let commit = self.expect_commit();
         &commit.entity_changes.new_package_addresses
@@ -241,6 +353,29 @@ impl TransactionReceipt {
         let commit = self.expect_commit();
         &commit.entity_changes.new_system_addresses
     }
+
+    /// Returns all new global entities, attributed to the actors which created them.
+    pub fn new_entities(&self) -> &Vec<NewEntity> {
+        let commit = self.expect_commit();
+        &commit.entity_changes.new_entities
+    }
+
+    /// Returns the execution cost units consumed by each actor, in descending order.
+    pub fn cost_breakdown(&self) -> &Vec<(CostingActor, u32)> {
+        &self.execution.fee_summary.actor_cost_unit_breakdown
+    }
+
+    /// Returns the number of invocations the transaction made.
+    pub fn syscall_count(&self) -> u32 {
+        self.execution.syscall_count
+    }
+
+    pub fn expect_debug_metrics(&self) -> &EngineMetrics {
+        self.execution
+            .debug_metrics
+            .as_ref()
+            .expect("Engine metrics were not enabled")
+    }
 }
 
 macro_rules! prefix {
@@ -354,6 +489,18 @@ impl<'a> ContextualDisplay<AddressDisplayContext<'a>> for TransactionReceipt {
                     )?;
                 }
             }
+            if let TransactionOutcome::Failure(err) = &c.outcome {
+                if let Some(value) = err.blueprint_error_value() {
+                    write!(
+                        f,
+                        "\n{} {}",
+                        "Blueprint Error:".bold().green(),
+                        IndexedScryptoValue::from_slice(value)
+                            .expect("Failed to parse blueprint error")
+                            .display(decompilation_context.for_value_display())
+                    )?;
+                }
+            }
         }
 
         if let TransactionResult::Commit(c) = &result {
@@ -395,6 +542,32 @@ impl<'a> ContextualDisplay<AddressDisplayContext<'a>> for TransactionReceipt {
             }
         }
 
+        if let Some(metrics) = &execution.debug_metrics {
+            write!(f, "\n{}", "Engine Metrics:".bold().green())?;
+            write!(
+                f,
+                "\n├─ Invocations: {} native, {} scrypto",
+                metrics.native_invocations, metrics.scrypto_invocations
+            )?;
+            write!(
+                f,
+                "\n├─ WASM instantiations: {}",
+                metrics.wasm_instantiations
+            )?;
+            write!(
+                f,
+                "\n├─ Substate reads: {} ({} bytes)",
+                metrics.substate_reads, metrics.substate_read_bytes
+            )?;
+            write!(
+                f,
+                "\n├─ Substate writes: {} ({} bytes)",
+                metrics.substate_writes, metrics.substate_write_bytes
+            )?;
+            write!(f, "\n├─ Lock acquisitions: {}", metrics.lock_acquisitions)?;
+            write!(f, "\n└─ Node creations: {}", metrics.node_creations)?;
+        }
+
         Ok(())
     }
 }
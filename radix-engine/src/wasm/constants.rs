@@ -2,6 +2,8 @@ pub const RADIX_ENGINE_FUNCTION_INDEX: usize = 0;
 pub const RADIX_ENGINE_FUNCTION_NAME: &str = "radix_engine";
 pub const CONSUME_COST_UNITS_FUNCTION_INDEX: usize = 1;
 pub const CONSUME_COST_UNITS_FUNCTION_NAME: &str = "gas";
+pub const PANIC_FUNCTION_INDEX: usize = 2;
+pub const PANIC_FUNCTION_NAME: &str = "panic";
 
 pub const MODULE_ENV_NAME: &str = "env";
 
@@ -9,6 +11,9 @@ pub const EXPORT_MEMORY: &str = "memory";
 pub const EXPORT_SCRYPTO_ALLOC: &str = "scrypto_alloc";
 pub const EXPORT_SCRYPTO_FREE: &str = "scrypto_free";
 
+/// The name of the custom section holding the build fingerprint of a package
+pub const BUILD_FINGERPRINT_SECTION_NAME: &str = "scrypto_build_fingerprint";
+
 /// The maximum initial memory size: `64 Pages * 64 KiB per Page = 4 MiB`
 pub const DEFAULT_MAX_INITIAL_MEMORY_SIZE_PAGES: u32 = 64;
 
@@ -23,3 +28,6 @@ pub const DEFAULT_MAX_NUMBER_OF_GLOBALS: u32 = 512;
 
 /// The max number of functions
 pub const DEFAULT_MAX_NUMBER_OF_FUNCTIONS: u32 = 64 * 1024;
+
+/// The max size of a panic message reported by a Scrypto module, in bytes; longer messages are truncated
+pub const MAX_PANIC_MESSAGE_SIZE: usize = 1024;
@@ -34,8 +34,20 @@ pub enum PrepareError {
     TooManyGlobals,
     /// No export section
     NoExportSection,
-    /// Missing export
-    MissingExport { export_name: String },
+    /// An export referenced by a blueprint ABI is missing
+    MissingExport {
+        blueprint_name: String,
+        fn_ident: String,
+        export_name: String,
+    },
+    /// An export referenced by a blueprint ABI does not have the `(i32) -> i32` signature
+    InvalidExportSignature {
+        blueprint_name: String,
+        fn_ident: String,
+        export_name: String,
+    },
+    /// The same export is referenced by more than one function ABI
+    DuplicateExportName { export_name: String },
     /// The wasm module does not have the `scrypto_alloc` export.
     NoScryptoAllocExport,
     /// The wasm module does not have the `scrypto_free` export.
@@ -48,6 +60,8 @@ pub enum PrepareError {
     NotInstantiatable,
     /// Not compilable
     NotCompilable,
+    /// The build fingerprint section can't be decoded
+    InvalidBuildFingerprint,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, TypeId, Encode, Decode)]
@@ -92,6 +106,7 @@ pub enum WasmError {
     MissingReturnData,
     InvalidReturnData,
     CostingError(FeeReserveError),
+    Panic(String),
 }
 
 impl fmt::Display for WasmError {
@@ -170,7 +170,7 @@ impl WasmModule {
     }
 
     pub fn enforce_import_limit(self) -> Result<Self, PrepareError> {
-        // Only allow `env::radix_engine` import
+        // Only allow `env::radix_engine` and `env::panic` imports
         if let Some(sec) = self.module.import_section() {
             for entry in sec.entries() {
                 if entry.module() == MODULE_ENV_NAME && entry.field() == RADIX_ENGINE_FUNCTION_NAME
@@ -186,6 +186,18 @@ impl WasmModule {
                         }
                     }
                 }
+                if entry.module() == MODULE_ENV_NAME && entry.field() == PANIC_FUNCTION_NAME {
+                    if let External::Function(type_index) = entry.external() {
+                        if Self::function_type_matches(
+                            &self.module,
+                            *type_index as usize,
+                            vec![ValueType::I32, ValueType::I32],
+                            vec![],
+                        ) {
+                            continue;
+                        }
+                    }
+                }
 
                 return Err(PrepareError::InvalidImport(InvalidImport::ImportNotAllowed));
             }
@@ -305,25 +317,44 @@ impl WasmModule {
             .module
             .export_section()
             .ok_or(PrepareError::NoExportSection)?;
-        for (_, blueprint_abi) in blueprints {
-            for func in &blueprint_abi.fns {
-                let func_name = &func.export_name;
-                if !exports.entries().iter().any(|x| {
-                    x.field().eq(func_name) && {
-                        if let Internal::Function(func_index) = x.internal() {
-                            Self::function_matches(
-                                &self.module,
-                                *func_index as usize,
-                                vec![ValueType::I32],
-                                vec![ValueType::I32],
-                            )
-                        } else {
-                            false
-                        }
-                    }
-                }) {
-                    return Err(PrepareError::MissingExport {
-                        export_name: func_name.to_string(),
+
+        // Blueprints are visited in name order, so that the reported error is deterministic
+        let mut blueprint_names: Vec<&String> = blueprints.keys().collect();
+        blueprint_names.sort();
+
+        let mut export_names = HashSet::new();
+        for blueprint_name in blueprint_names {
+            for func in &blueprints[blueprint_name].fns {
+                let export_name = &func.export_name;
+                if !export_names.insert(export_name) {
+                    return Err(PrepareError::DuplicateExportName {
+                        export_name: export_name.to_string(),
+                    });
+                }
+
+                let export = exports
+                    .entries()
+                    .iter()
+                    .find(|x| x.field().eq(export_name))
+                    .ok_or_else(|| PrepareError::MissingExport {
+                        blueprint_name: blueprint_name.to_string(),
+                        fn_ident: func.ident.to_string(),
+                        export_name: export_name.to_string(),
+                    })?;
+                let signature_matches = match export.internal() {
+                    Internal::Function(func_index) => Self::function_matches(
+                        &self.module,
+                        *func_index as usize,
+                        vec![ValueType::I32],
+                        vec![ValueType::I32],
+                    ),
+                    _ => false,
+                };
+                if !signature_matches {
+                    return Err(PrepareError::InvalidExportSignature {
+                        blueprint_name: blueprint_name.to_string(),
+                        fn_ident: func.ident.to_string(),
+                        export_name: export_name.to_string(),
                     });
                 }
             }
@@ -388,6 +419,28 @@ impl WasmModule {
         Ok(self)
     }
 
+    /// Returns the build fingerprint embedded in the module, if any.
+    pub fn build_fingerprint(&self) -> Result<Option<BuildFingerprint>, PrepareError> {
+        self.module
+            .custom_sections()
+            .find(|section| section.name() == BUILD_FINGERPRINT_SECTION_NAME)
+            .map(|section| {
+                scrypto_decode(section.payload()).map_err(|_| PrepareError::InvalidBuildFingerprint)
+            })
+            .transpose()
+    }
+
+    /// Embeds the build fingerprint in the module, replacing any fingerprint already there.
+    pub fn with_build_fingerprint(
+        mut self,
+        fingerprint: &BuildFingerprint,
+    ) -> Result<Self, PrepareError> {
+        let payload = scrypto_encode(fingerprint).map_err(|_| PrepareError::SerializationError)?;
+        self.module
+            .set_custom_section(BUILD_FINGERPRINT_SECTION_NAME, payload);
+        Ok(self)
+    }
+
     pub fn to_bytes(self) -> Result<(Vec<u8>, Vec<String>), PrepareError> {
         let function_exports = self
             .module
@@ -528,6 +581,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_import() {
+        assert_invalid_wasm!(
+            r#"
+            (module
+                (import "env" "panic" (func $panic (param i32) (result i32)))
+            )
+            "#,
+            PrepareError::InvalidImport(InvalidImport::ImportNotAllowed),
+            WasmModule::enforce_import_limit
+        );
+
+        let code = wat2wasm(
+            r#"
+            (module
+                (import "env" "radix_engine" (func $radix_engine (param i32) (result i32)))
+                (import "env" "panic" (func $panic (param i32 i32)))
+            )
+            "#,
+        )
+        .unwrap();
+        assert!(WasmModule::init(&code)
+            .unwrap()
+            .enforce_import_limit()
+            .is_ok());
+    }
+
     #[test]
     fn test_memory() {
         assert_invalid_wasm!(
@@ -641,6 +721,8 @@ mod tests {
             )
             "#,
             PrepareError::MissingExport {
+                blueprint_name: "Test".to_string(),
+                fn_ident: "f".to_string(),
                 export_name: "Test_f".to_string()
             },
             |x| WasmModule::enforce_export_constraints(x, &blueprint_abis)
@@ -654,7 +736,26 @@ mod tests {
                 )
             )
             "#,
-            PrepareError::MissingExport {
+            PrepareError::InvalidExportSignature {
+                blueprint_name: "Test".to_string(),
+                fn_ident: "f".to_string(),
+                export_name: "Test_f".to_string()
+            },
+            |x| WasmModule::enforce_export_constraints(x, &blueprint_abis)
+        );
+        // export referenced by two blueprints
+        let mut other_blueprint_abi = blueprint_abis["Test"].clone();
+        other_blueprint_abi.fns[0].ident = "g".to_string();
+        blueprint_abis.insert("Other".to_string(), other_blueprint_abi);
+        assert_invalid_wasm!(
+            r#"
+            (module
+                (func (export "Test_f") (param i32) (result i32)
+                    (i32.const 0)
+                )
+            )
+            "#,
+            PrepareError::DuplicateExportName {
                 export_name: "Test_f".to_string()
             },
             |x| WasmModule::enforce_export_constraints(x, &blueprint_abis)
@@ -148,6 +148,31 @@ pub fn read_value(instance: &Instance, ptr: usize) -> Result<IndexedScryptoValue
     Err(WasmError::MemoryAccessError)
 }
 
+pub fn read_panic_message(
+    instance: &Instance,
+    ptr: usize,
+    len: usize,
+) -> Result<String, WasmError> {
+    let memory = instance
+        .exports
+        .get_memory(EXPORT_MEMORY)
+        .map_err(|_| WasmError::MemoryAccessError)?;
+    let size = memory.size().bytes().0;
+    let n = len.min(MAX_PANIC_MESSAGE_SIZE);
+    if size > ptr && size - ptr >= n {
+        let mut temp = Vec::with_capacity(n);
+        unsafe {
+            let from = memory.data_ptr().add(ptr);
+            ptr::copy(from, temp.as_mut_ptr(), n);
+            temp.set_len(n);
+        }
+
+        return Ok(String::from_utf8_lossy(&temp).into_owned());
+    }
+
+    Err(WasmError::MemoryAccessError)
+}
+
 impl WasmerEnv for WasmerInstanceEnv {
     fn init_with_instance(&mut self, instance: &Instance) -> Result<(), HostEnvInitError> {
         self.instance.initialize(instance.clone());
@@ -190,6 +215,20 @@ impl WasmerModule {
                 .map_err(|e| RuntimeError::user(Box::new(e)))
         }
 
+        fn panic(
+            env: &WasmerInstanceEnv,
+            message_ptr: i32,
+            message_len: i32,
+        ) -> Result<(), RuntimeError> {
+            let instance = unsafe { env.instance.get_unchecked() };
+            let message = read_panic_message(&instance, message_ptr as usize, message_len as usize)
+                .map_err(|e| RuntimeError::user(Box::new(InvokeError::Error(e))))?;
+
+            Err(RuntimeError::user(Box::new(InvokeError::Error(
+                WasmError::Panic(message),
+            ))))
+        }
+
         // env
         let env = WasmerInstanceEnv {
             instance: LazyInit::new(),
@@ -201,6 +240,7 @@ impl WasmerModule {
             MODULE_ENV_NAME => {
                 RADIX_ENGINE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), radix_engine),
                 CONSUME_COST_UNITS_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), consume_cost_units),
+                PANIC_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), panic),
             }
         };
 
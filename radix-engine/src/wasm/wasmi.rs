@@ -60,6 +60,19 @@ impl ModuleImportResolver for WasmiEnvModule {
                     CONSUME_COST_UNITS_FUNCTION_INDEX,
                 ))
             }
+            PANIC_FUNCTION_NAME => {
+                if signature.params() != [ValueType::I32, ValueType::I32]
+                    || signature.return_type() != None
+                {
+                    return Err(Error::Instantiation(
+                        "Function signature does not match".into(),
+                    ));
+                }
+                Ok(FuncInstance::alloc_host(
+                    signature.clone(),
+                    PANIC_FUNCTION_INDEX,
+                ))
+            }
             _ => Err(Error::Instantiation(format!(
                 "Function {} not found",
                 field_name
@@ -151,6 +164,20 @@ impl<'a, 'b, 'r> WasmiExternals<'a, 'b, 'r> {
 
         IndexedScryptoValue::from_slice(&buffer[start..end]).map_err(WasmError::InvalidScryptoValue)
     }
+
+    pub fn read_panic_message(&self, ptr: usize, len: usize) -> Result<String, WasmError> {
+        let end = ptr
+            .checked_add(len.min(MAX_PANIC_MESSAGE_SIZE))
+            .ok_or(WasmError::MemoryAccessError)?;
+
+        let direct = self.instance.memory_ref.direct_access();
+        let buffer = direct.as_ref();
+        if end > buffer.len() {
+            return Err(WasmError::MemoryAccessError);
+        }
+
+        Ok(String::from_utf8_lossy(&buffer[ptr..end]).into_owned())
+    }
 }
 
 impl<'a, 'b, 'r> Externals for WasmiExternals<'a, 'b, 'r> {
@@ -175,6 +202,14 @@ impl<'a, 'b, 'r> Externals for WasmiExternals<'a, 'b, 'r> {
                     .map(|_| Option::None)
                     .map_err(|e| e.into())
             }
+            PANIC_FUNCTION_INDEX => {
+                let message_ptr = args.nth_checked::<u32>(0)? as usize;
+                let message_len = args.nth_checked::<u32>(1)? as usize;
+                let message = self
+                    .read_panic_message(message_ptr, message_len)
+                    .map_err(InvokeError::Error)?;
+                Err(InvokeError::Error(WasmError::Panic(message)).into())
+            }
             _ => Err(WasmError::FunctionNotFound.into()),
         }
     }
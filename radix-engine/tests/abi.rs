@@ -189,3 +189,62 @@ fn test_input_arg_hash_set_succeeds() {
 fn test_invalid_input_arg_hash_set_fails() {
     test_arg("hash_set", scrypto_encode(&0u8).unwrap(), InvalidInput);
 }
+
+fn test_method_arg(args: Vec<u8>, expected_result: ExpectedResult) {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/abi");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "AbiComponent", "create_component", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component_address = receipt
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0];
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(component_address, "echo_u32", args)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    match expected_result {
+        Success => {
+            receipt.expect_commit_success();
+        }
+        InvalidInput => {
+            receipt.expect_specific_failure(|e| {
+                matches!(
+                    e,
+                    RuntimeError::InterpreterError(
+                        InterpreterError::InvalidScryptoMethodInvocation(
+                            _,
+                            ScryptoFnResolvingError::InvalidInput
+                        )
+                    )
+                )
+            });
+        }
+        InvalidOutput => panic!("Method output is not tested"),
+    }
+}
+
+#[test]
+fn test_method_input_arg_u32_succeeds() {
+    test_method_arg(args!(5u32), Success);
+}
+
+#[test]
+fn test_invalid_method_input_arg_u32_fails() {
+    test_method_arg(args!("5"), InvalidInput);
+}
+
+#[test]
+fn test_invalid_method_input_arg_count_fails() {
+    test_method_arg(args!(5u32, 6u32), InvalidInput);
+}
@@ -115,7 +115,7 @@ fn cannot_withdraw_from_other_account_internal(is_virtual: bool) {
     );
 
     // Assert
-    receipt.expect_specific_failure(is_auth_error);
+    receipt.expect_specific_failure_at(1, is_auth_error);
 }
 
 #[test]
@@ -220,3 +220,57 @@ fn assert_resource_changes_for_transfer(
             && r.component_id == target_account
             && r.amount == Decimal::from(transfer_amount)));
 }
+
+#[test]
+fn deterministic_test_runners_create_identical_accounts() {
+    // Arrange
+    let mut store1 = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner1 = TestRunner::new_deterministic(true, &mut store1);
+    let mut store2 = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner2 = TestRunner::new_deterministic(true, &mut store2);
+
+    // Act
+    let (public_key1, _, account1) = test_runner1.new_account(true);
+    let (public_key2, _, account2) = test_runner2.new_account(true);
+    let (_, _, indexed_account1) = test_runner1.new_account_deterministic(5);
+    let (_, _, indexed_account2) = test_runner2.new_account_deterministic(5);
+
+    // Assert
+    assert_eq!(public_key1, public_key2);
+    assert_eq!(account1, account2);
+    assert_eq!(indexed_account1, indexed_account2);
+    assert_eq!(
+        TestRunner::<TypedInMemorySubstateStore>::deterministic_key_pair(0).0,
+        public_key1
+    );
+    assert_eq!(
+        NonFungibleAddress::from_public_key(&public_key1),
+        NonFungibleAddress::from_public_key(&public_key2)
+    );
+}
+
+#[test]
+fn deterministic_test_runners_produce_identical_receipts() {
+    let run = || {
+        let mut store = TypedInMemorySubstateStore::with_bootstrap();
+        let mut test_runner = TestRunner::new_deterministic(true, &mut store);
+        let (public_key, _, account) = test_runner.new_account(false);
+        let (_, _, other_account) = test_runner.new_account(false);
+        let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+            .lock_fee_and_withdraw(account, 10.into(), RADIX_TOKEN)
+            .call_method(
+                other_account,
+                "deposit_batch",
+                args!(Expression::entire_worktop()),
+            )
+            .build();
+        let receipt = test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleAddress::from_public_key(&public_key)],
+        );
+        receipt.expect_commit_success();
+        scrypto_encode(&receipt.result).unwrap()
+    };
+
+    assert_eq!(run(), run());
+}
@@ -0,0 +1,284 @@
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::data::*;
+use radix_engine_interface::model::FromPublicKey;
+use radix_engine_interface::rule;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+struct AllowanceSetup {
+    owner_public_key: EcdsaSecp256k1PublicKey,
+    account: ComponentAddress,
+    spender_public_key: EcdsaSecp256k1PublicKey,
+    spender_account: ComponentAddress,
+    spender_badge: NonFungibleAddress,
+}
+
+/// Publishes the account package from source, so that these tests don't depend on the prebuilt
+/// package loaded at genesis, and creates an account holding 1000 XRD plus a spender with a badge.
+fn setup(test_runner: &mut TestRunner<TypedInMemorySubstateStore>) -> AllowanceSetup {
+    let package_address = test_runner.compile_and_publish("../assets/blueprints/account");
+    let (owner_public_key, _, owner_auth) = test_runner.new_key_pair_with_auth_address();
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(FAUCET_COMPONENT, "free", args!())
+        .take_from_worktop(RADIX_TOKEN, |builder, bucket_id| {
+            builder.call_function(
+                package_address,
+                "Account",
+                "new_with_resource",
+                args!(rule!(require(owner_auth)), Bucket(bucket_id)),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let account = receipt
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0];
+
+    let (spender_public_key, _, spender_account) = test_runner.new_allocated_account();
+    let badge_resource_address = test_runner.create_non_fungible_resource(spender_account);
+
+    AllowanceSetup {
+        owner_public_key,
+        account,
+        spender_public_key,
+        spender_account,
+        spender_badge: NonFungibleAddress::new(badge_resource_address, NonFungibleId::U32(1)),
+    }
+}
+
+fn create_allowance(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    setup: &AllowanceSetup,
+    per_period_amount: Decimal,
+    period_epochs: u64,
+) -> u64 {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .create_account_allowance(
+            setup.account,
+            setup.spender_badge.clone(),
+            RADIX_TOKEN,
+            per_period_amount,
+            period_epochs,
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&setup.owner_public_key)],
+    );
+    receipt.output(1)
+}
+
+fn pull(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    setup: &AllowanceSetup,
+    allowance_id: u64,
+    amount: Decimal,
+    badge_id: NonFungibleId,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .create_proof_from_account_by_ids(
+            setup.spender_account,
+            &BTreeSet::from([badge_id]),
+            setup.spender_badge.resource_address(),
+        )
+        .pop_from_auth_zone(|builder, proof_id| {
+            builder.pull_from_account_allowance(setup.account, allowance_id, amount, proof_id)
+        })
+        .call_method(
+            setup.spender_account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(
+            &setup.spender_public_key,
+        )],
+    )
+}
+
+#[test]
+fn can_pull_within_allowance() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let setup = setup(&mut test_runner);
+    let allowance_id = create_allowance(&mut test_runner, &setup, 100.into(), 10);
+
+    // Act
+    let receipt1 = pull(
+        &mut test_runner,
+        &setup,
+        allowance_id,
+        60.into(),
+        NonFungibleId::U32(1),
+    );
+    let receipt2 = pull(
+        &mut test_runner,
+        &setup,
+        allowance_id,
+        40.into(),
+        NonFungibleId::U32(1),
+    );
+
+    // Assert
+    receipt1.expect_commit_success();
+    receipt2.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_resources(setup.account)[&RADIX_TOKEN],
+        Decimal::from(900)
+    );
+}
+
+#[test]
+fn cannot_pull_beyond_allowance() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let setup = setup(&mut test_runner);
+    let allowance_id = create_allowance(&mut test_runner, &setup, 100.into(), 10);
+    pull(
+        &mut test_runner,
+        &setup,
+        allowance_id,
+        60.into(),
+        NonFungibleId::U32(1),
+    )
+    .expect_commit_success();
+
+    // Act
+    let receipt = pull(
+        &mut test_runner,
+        &setup,
+        allowance_id,
+        50.into(),
+        NonFungibleId::U32(1),
+    );
+
+    // Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn cannot_pull_without_spender_badge() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let setup = setup(&mut test_runner);
+    let allowance_id = create_allowance(&mut test_runner, &setup, 100.into(), 10);
+
+    // Act
+    let receipt = pull(
+        &mut test_runner,
+        &setup,
+        allowance_id,
+        10.into(),
+        NonFungibleId::U32(2),
+    );
+
+    // Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn allowance_resets_after_period() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let setup = setup(&mut test_runner);
+    let allowance_id = create_allowance(&mut test_runner, &setup, 100.into(), 10);
+    pull(
+        &mut test_runner,
+        &setup,
+        allowance_id,
+        100.into(),
+        NonFungibleId::U32(1),
+    )
+    .expect_commit_success();
+    let current_epoch = test_runner.get_current_epoch();
+
+    // Act
+    test_runner.set_current_epoch(current_epoch + 9);
+    let receipt_within_period = pull(
+        &mut test_runner,
+        &setup,
+        allowance_id,
+        1.into(),
+        NonFungibleId::U32(1),
+    );
+    test_runner.set_current_epoch(current_epoch + 10);
+    let receipt_next_period = pull(
+        &mut test_runner,
+        &setup,
+        allowance_id,
+        100.into(),
+        NonFungibleId::U32(1),
+    );
+
+    // Assert
+    receipt_within_period.expect_commit_failure();
+    receipt_next_period.expect_commit_success();
+}
+
+#[test]
+fn cannot_pull_after_allowance_is_revoked() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let setup = setup(&mut test_runner);
+    let allowance_id = create_allowance(&mut test_runner, &setup, 100.into(), 10);
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .revoke_account_allowance(setup.account, allowance_id)
+        .build();
+    test_runner
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleAddress::from_public_key(&setup.owner_public_key)],
+        )
+        .expect_commit_success();
+
+    // Act
+    let receipt = pull(
+        &mut test_runner,
+        &setup,
+        allowance_id,
+        10.into(),
+        NonFungibleId::U32(1),
+    );
+
+    // Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn cannot_revoke_allowance_without_owner_auth() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let setup = setup(&mut test_runner);
+    let allowance_id = create_allowance(&mut test_runner, &setup, 100.into(), 10);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .revoke_account_allowance(setup.account, allowance_id)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(
+            &setup.spender_public_key,
+        )],
+    );
+
+    // Assert
+    receipt.expect_commit_failure();
+}
@@ -0,0 +1,265 @@
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::data::*;
+use radix_engine_interface::model::FromPublicKey;
+use radix_engine_interface::rule;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+struct DepositRulesSetup {
+    owner_public_key: EcdsaSecp256k1PublicKey,
+    account: ComponentAddress,
+    sender_public_key: EcdsaSecp256k1PublicKey,
+    sender_account: ComponentAddress,
+    resource_address: ResourceAddress,
+}
+
+/// Publishes the account package from source, so that these tests don't depend on the prebuilt
+/// package loaded at genesis, and creates an account holding 1000 XRD plus a sender holding a
+/// fungible resource.
+fn setup(test_runner: &mut TestRunner<TypedInMemorySubstateStore>) -> DepositRulesSetup {
+    let package_address = test_runner.compile_and_publish("../assets/blueprints/account");
+    let (owner_public_key, _, owner_auth) = test_runner.new_key_pair_with_auth_address();
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(FAUCET_COMPONENT, "free", args!())
+        .take_from_worktop(RADIX_TOKEN, |builder, bucket_id| {
+            builder.call_function(
+                package_address,
+                "Account",
+                "new_with_resource",
+                args!(rule!(require(owner_auth)), Bucket(bucket_id)),
+            )
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let account = receipt
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0];
+
+    let (sender_public_key, _, sender_account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(1000.into(), 0, sender_account);
+
+    DepositRulesSetup {
+        owner_public_key,
+        account,
+        sender_public_key,
+        sender_account,
+        resource_address,
+    }
+}
+
+fn configure<F>(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    setup: &DepositRulesSetup,
+    f: F,
+) where
+    F: FnOnce(&mut ManifestBuilder) -> &mut ManifestBuilder,
+{
+    let mut builder = ManifestBuilder::new(&NetworkDefinition::simulator());
+    builder.lock_fee(FAUCET_COMPONENT, 10.into());
+    let manifest = f(&mut builder).build();
+    test_runner
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleAddress::from_public_key(&setup.owner_public_key)],
+        )
+        .expect_commit_success();
+}
+
+fn send(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    setup: &DepositRulesSetup,
+    resource_address: ResourceAddress,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(setup.sender_account, 10.into(), resource_address)
+        .call_method(
+            setup.account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(
+            &setup.sender_public_key,
+        )],
+    )
+}
+
+fn assert_deposit_not_allowed(receipt: &TransactionReceipt) {
+    receipt.expect_commit_failure();
+    assert!(receipt
+        .execution
+        .application_logs
+        .iter()
+        .any(|(_, message)| message.contains("DepositNotAllowed")));
+}
+
+#[test]
+fn account_accepts_all_deposits_by_default() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let setup = setup(&mut test_runner);
+
+    // Act
+    let receipt = send(&mut test_runner, &setup, setup.resource_address);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_resources(setup.account)[&setup.resource_address],
+        Decimal::from(10)
+    );
+}
+
+#[test]
+fn deny_all_mode_rejects_resource_not_on_allowlist() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let setup = setup(&mut test_runner);
+    configure(&mut test_runner, &setup, |builder| {
+        builder.set_account_deposit_mode(setup.account, AccountDepositMode::DenyAllExceptAllowlist)
+    });
+
+    // Act
+    let receipt = send(&mut test_runner, &setup, setup.resource_address);
+
+    // Assert
+    assert_deposit_not_allowed(&receipt);
+}
+
+#[test]
+fn deny_all_mode_accepts_resource_on_allowlist() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let setup = setup(&mut test_runner);
+    configure(&mut test_runner, &setup, |builder| {
+        builder
+            .set_account_deposit_mode(setup.account, AccountDepositMode::DenyAllExceptAllowlist)
+            .allow_account_deposits(setup.account, setup.resource_address)
+    });
+
+    // Act
+    let receipt = send(&mut test_runner, &setup, setup.resource_address);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn allow_all_except_denylist_mode_rejects_only_denied_resource() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let setup = setup(&mut test_runner);
+    configure(&mut test_runner, &setup, |builder| {
+        builder
+            .set_account_deposit_mode(setup.account, AccountDepositMode::AllowAllExceptDenylist)
+            .deny_account_deposits(setup.account, setup.resource_address)
+    });
+
+    // Act
+    let denied_receipt = send(&mut test_runner, &setup, setup.resource_address);
+    let allowed_receipt = send(&mut test_runner, &setup, RADIX_TOKEN);
+
+    // Assert
+    assert_deposit_not_allowed(&denied_receipt);
+    allowed_receipt.expect_commit_success();
+}
+
+#[test]
+fn try_deposit_or_refund_returns_rejected_bucket() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let setup = setup(&mut test_runner);
+    configure(&mut test_runner, &setup, |builder| {
+        builder.set_account_deposit_mode(setup.account, AccountDepositMode::DenyAllExceptAllowlist)
+    });
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(setup.sender_account, 10.into(), setup.resource_address)
+        .take_from_worktop(setup.resource_address, |builder, bucket_id| {
+            builder.try_deposit_to_account_or_refund(setup.account, bucket_id)
+        })
+        .call_method(
+            setup.sender_account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(
+            &setup.sender_public_key,
+        )],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert!(!test_runner
+        .get_component_resources(setup.account)
+        .contains_key(&setup.resource_address));
+    assert_eq!(
+        test_runner.get_component_resources(setup.sender_account)[&setup.resource_address],
+        Decimal::from(1000)
+    );
+}
+
+#[test]
+fn cannot_set_deposit_mode_without_owner_auth() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let setup = setup(&mut test_runner);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .set_account_deposit_mode(setup.account, AccountDepositMode::DenyAllExceptAllowlist)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(
+            &setup.sender_public_key,
+        )],
+    );
+
+    // Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn can_lock_fee_from_own_account_in_deny_all_mode() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let setup = setup(&mut test_runner);
+    configure(&mut test_runner, &setup, |builder| {
+        builder.set_account_deposit_mode(setup.account, AccountDepositMode::DenyAllExceptAllowlist)
+    });
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(setup.account, 10.into())
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&setup.owner_public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert!(test_runner.get_component_resources(setup.account)[&RADIX_TOKEN] < Decimal::from(1000));
+}
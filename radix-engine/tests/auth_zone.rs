@@ -0,0 +1,82 @@
+use radix_engine::engine::{ApplicationError, RuntimeError};
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::model::AuthZoneError;
+use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig, TransactionReceipt};
+use radix_engine::types::*;
+use radix_engine_constants::DEFAULT_COST_UNIT_LIMIT;
+use radix_engine_interface::core::NetworkDefinition;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+use transaction::model::TestTransaction;
+
+fn push_proofs_then_call_protected_method(
+    proof_count: usize,
+    execution_config: &ExecutionConfig,
+) -> TransactionReceipt {
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+
+    let mut builder = ManifestBuilder::new(&NetworkDefinition::simulator());
+    builder.lock_fee(account, 10.into());
+    for _ in 0..proof_count {
+        builder.create_proof_from_account(account, RADIX_TOKEN);
+    }
+    // Creating a proof from the account is protected, and checked against the full auth zone
+    builder.create_proof_from_account_by_amount(account, 1.into(), RADIX_TOKEN);
+    let transaction = TestTransaction::new(
+        builder.build(),
+        test_runner.next_transaction_nonce(),
+        DEFAULT_COST_UNIT_LIMIT,
+    );
+
+    test_runner.execute_transaction_with_config(
+        &transaction.get_executable(vec![NonFungibleAddress::from_public_key(&public_key)]),
+        &FeeReserveConfig::default(),
+        execution_config,
+    )
+}
+
+#[test]
+fn auth_checks_should_cost_more_with_more_proofs_in_auth_zone() {
+    // Act
+    let small = push_proofs_then_call_protected_method(2, &ExecutionConfig::default());
+    let large = push_proofs_then_call_protected_method(50, &ExecutionConfig::default());
+
+    // Assert
+    small.expect_commit_success();
+    large.expect_commit_success();
+    let small_fee_summary = &small.execution.fee_summary;
+    let large_fee_summary = &large.execution.fee_summary;
+    assert!(
+        large_fee_summary.execution_cost_unit_breakdown["verify_auth"]
+            > small_fee_summary.execution_cost_unit_breakdown["verify_auth"]
+    );
+    assert!(
+        large_fee_summary.execution_cost_unit_breakdown["auth_zone_proof"]
+            > small_fee_summary.execution_cost_unit_breakdown["auth_zone_proof"]
+    );
+    assert!(large_fee_summary.cost_unit_consumed > small_fee_summary.cost_unit_consumed);
+}
+
+#[test]
+fn pushing_more_proofs_than_limit_should_fail() {
+    // Arrange
+    let execution_config = ExecutionConfig {
+        max_auth_zone_proofs: 2,
+        ..ExecutionConfig::default()
+    };
+
+    // Act
+    let receipt = push_proofs_then_call_protected_method(3, &execution_config);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::AuthZoneError(
+                AuthZoneError::ProofLimitExceeded { limit: 2 }
+            ))
+        )
+    });
+}
@@ -1,7 +1,7 @@
 extern crate core;
 
 use radix_engine::ledger::{
-    QueryableSubstateStore, ReadableSubstateStore, TypedInMemorySubstateStore,
+    QueryableSubstateStore, ReadableSubstateStore, StateTreeStore, TypedInMemorySubstateStore,
     WriteableSubstateStore,
 };
 use radix_engine::types::*;
@@ -10,11 +10,11 @@ use radix_engine_interface::data::*;
 use radix_engine_interface::rule;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
-use transaction::model::AuthModule;
+use transaction::model::InitialProofs;
 
 fn test_auth_rule<
     's,
-    S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateStore,
+    S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateStore + StateTreeStore,
 >(
     test_runner: &mut TestRunner<'s, S>,
     auth_rule: &AccessRule,
@@ -35,8 +35,10 @@ fn test_auth_rule<
             args!(Expression::entire_worktop()),
         )
         .build();
-    let receipt =
-        test_runner.execute_manifest(manifest, AuthModule::pk_non_fungibles(signer_public_keys));
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        InitialProofs::from_public_keys(signer_public_keys.to_vec()),
+    );
 
     // Assert
     if should_succeed {
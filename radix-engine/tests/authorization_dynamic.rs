@@ -1,4 +1,7 @@
+use radix_engine::engine::{AuthError, ModuleError, RuntimeError};
 use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::model::MethodAuthorizationError;
+use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
 use radix_engine_interface::core::NetworkDefinition;
 use radix_engine_interface::data::*;
@@ -80,12 +83,11 @@ fn test_dynamic_auth(
     }
 }
 
-fn test_dynamic_authlist(
+fn call_get_secret_on_authlist_component(
     list_size: usize,
     auth_rule: AccessRule,
     signer_public_keys: &[usize],
-    should_succeed: bool,
-) {
+) -> TransactionReceipt {
     let mut store = TypedInMemorySubstateStore::with_bootstrap();
     let mut test_runner = TestRunner::new(true, &mut store);
     let key_and_addresses: Vec<(
@@ -128,7 +130,17 @@ fn test_dynamic_authlist(
         .lock_fee(FAUCET_COMPONENT, 10u32.into())
         .call_method(component, "get_secret", args!())
         .build();
-    let receipt = test_runner.execute_manifest(manifest2, initial_proofs);
+    test_runner.execute_manifest(manifest2, initial_proofs)
+}
+
+fn test_dynamic_authlist(
+    list_size: usize,
+    auth_rule: AccessRule,
+    signer_public_keys: &[usize],
+    should_succeed: bool,
+) {
+    // Act
+    let receipt = call_get_secret_on_authlist_component(list_size, auth_rule, signer_public_keys);
 
     // Assert
     if should_succeed {
@@ -168,6 +180,47 @@ fn dynamic_all_of_should_fail_on_nonexistent_resource() {
     test_dynamic_authlist(3, rule!(require("does_not_exist")), &[0, 1, 2], false);
 }
 
+#[test]
+fn dynamic_require_with_nonexistent_path_should_fail_with_soft_resource_not_found() {
+    // Act
+    let receipt =
+        call_get_secret_on_authlist_component(3, rule!(require("does_not_exist")), &[0, 1, 2]);
+
+    // Assert
+    let path: SchemaPath = "does_not_exist".parse().unwrap();
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized {
+                error: MethodAuthorizationError::SoftResourceNotFound(p),
+                ..
+            })) if *p == path
+        )
+    });
+}
+
+#[test]
+fn dynamic_min_n_of_with_nonexistent_count_path_should_fail_with_soft_count_not_found() {
+    // Act
+    let receipt = call_get_secret_on_authlist_component(
+        3,
+        rule!(require_n_of("does_not_exist", "auth")),
+        &[0, 1],
+    );
+
+    // Assert
+    let path: SchemaPath = "does_not_exist".parse().unwrap();
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized {
+                error: MethodAuthorizationError::SoftCountNotFound(p),
+                ..
+            })) if *p == path
+        )
+    });
+}
+
 #[test]
 fn dynamic_min_n_of_should_allow_me_to_call_method() {
     let auths = [
@@ -10,6 +10,7 @@ use radix_engine_interface::data::*;
 use radix_engine_interface::model::FromPublicKey;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
+use transaction::model::Instruction;
 
 enum ResourceAuth {
     Mint,
@@ -247,3 +248,135 @@ fn locked_update_metadata_auth_cannot_be_relocked() {
         )
     })
 }
+
+fn lock_resource_auth_with_instruction(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    auth_key: ResourceMethodAuthKey,
+) -> (
+    ComponentAddress,
+    EcdsaSecp256k1PublicKey,
+    ResourceAddress,
+    ResourceAddress,
+    ResourceAddress,
+) {
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (token_address, mint_auth, _, _, _, _, admin_auth) =
+        test_runner.create_restricted_token(account);
+
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 100u32.into())
+        .create_proof_from_account(account, admin_auth)
+        .lock_resource_auth(token_address, auth_key)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+    receipt.expect_commit_success();
+
+    (account, public_key, token_address, mint_auth, admin_auth)
+}
+
+#[test]
+fn lock_resource_auth_instruction_prevents_mint_rule_update() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (account, public_key, token_address, _, admin_auth) =
+        lock_resource_auth_with_instruction(&mut test_runner, ResourceMethodAuthKey::Mint);
+    let receiver = RENodeId::Global(GlobalAddress::Resource(token_address));
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 100u32.into())
+        .create_proof_from_account(account, admin_auth)
+        .add_instruction(Instruction::CallNativeMethod {
+            method_ident: NativeMethodIdent {
+                receiver,
+                method_name: AccessRulesChainMethod::SetMethodAccessRule.to_string(),
+            },
+            args: scrypto_encode(&AccessRulesSetMethodAccessRuleInvocation {
+                receiver,
+                index: 0,
+                key: AccessRuleKey::Native(NativeFn::Method(NativeMethod::ResourceManager(
+                    ResourceManagerMethod::Mint,
+                ))),
+                rule: AccessRule::AllowAll,
+            })
+            .unwrap(),
+        })
+        .0
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::AccessRulesChainError(
+                AccessRulesChainError::Unauthorized(..)
+            ))
+        )
+    })
+}
+
+#[test]
+fn lock_resource_auth_instruction_keeps_current_mint_rule() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (account, public_key, token_address, mint_auth, _) =
+        lock_resource_auth_with_instruction(&mut test_runner, ResourceMethodAuthKey::Mint);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 100u32.into())
+        .create_proof_from_account(account, mint_auth)
+        .mint(token_address, Decimal::one())
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn lock_resource_auth_instruction_prevents_withdraw_rule_update() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (account, public_key, token_address, _, admin_auth) =
+        lock_resource_auth_with_instruction(&mut test_runner, ResourceMethodAuthKey::Withdraw);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 100u32.into())
+        .create_proof_from_account(account, admin_auth)
+        .lock_resource_auth(token_address, ResourceMethodAuthKey::Withdraw)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::AuthZoneError(
+                AuthZoneError::AssertAccessRuleError(..)
+            ))
+        )
+    })
+}
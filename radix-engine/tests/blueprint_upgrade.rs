@@ -0,0 +1,27 @@
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::types::*;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::data::*;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+#[test]
+fn migration_should_copy_kept_fields_and_default_new_ones() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/blueprint_upgrade");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "Upgrade", "migrate_counter", args!(7u32))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let outputs = receipt.expect_commit_success();
+    let (count, step): (u32, u32) = scrypto_decode(&outputs[1]).unwrap();
+    assert_eq!(count, 7);
+    assert_eq!(step, 0);
+}
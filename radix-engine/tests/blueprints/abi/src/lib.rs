@@ -19,6 +19,10 @@ blueprint! {
             );
             component.globalize()
         }
+
+        pub fn echo_u32(&self, value: u32) -> u32 {
+            value
+        }
     }
 }
 
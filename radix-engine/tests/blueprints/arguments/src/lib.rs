@@ -1,3 +1,6 @@
+// Tests passing a `HashMap` argument
+#![allow(clippy::disallowed_types)]
+
 use scrypto::prelude::*;
 
 blueprint! {
@@ -0,0 +1,43 @@
+use scrypto::prelude::*;
+
+#[blueprint_upgrade(from = CounterV1, to = CounterV2)]
+mod counter_state {
+    use scrypto::prelude::*;
+
+    #[scrypto(TypeId, Encode, Decode, Describe)]
+    pub struct CounterV1 {
+        pub count: u32,
+        #[deprecated]
+        pub label: String,
+        pub owner: Option<String>,
+    }
+
+    #[scrypto(TypeId, Encode, Decode, Describe)]
+    pub struct CounterV2 {
+        pub count: u32,
+        pub step: u32,
+    }
+}
+
+use counter_state::*;
+
+blueprint! {
+    struct Upgrade {}
+
+    impl Upgrade {
+        /// Migrates a counter stored in the V1 layout, returning its count and step.
+        pub fn migrate_counter(count: u32) -> (u32, u32) {
+            #[allow(deprecated)]
+            let old_state = CounterV1 {
+                count,
+                label: "counter".to_owned(),
+                owner: None,
+            };
+            let old_bytes = scrypto_encode(&old_state).unwrap();
+
+            let old_state: CounterV1 = scrypto_decode(&old_bytes).unwrap();
+            let new_state = migrate_from_v1(old_state);
+            (new_state.count, new_state.step)
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct BorrowedBucketTest {
+        vault: Vault,
+    }
+
+    impl BorrowedBucketTest {
+        pub fn new() -> ComponentAddress {
+            Self {
+                vault: Vault::new(RADIX_TOKEN),
+            }
+            .instantiate()
+            .globalize()
+        }
+
+        pub fn inspect(bucket: &Bucket) -> Decimal {
+            bucket.amount()
+        }
+
+        pub fn deposit(&mut self, bucket: &Bucket) {
+            self.vault.put(Bucket(bucket.0));
+        }
+
+        pub fn lend_for_inspection(bucket: Bucket) -> (Decimal, Decimal, Bucket) {
+            let inspected: Decimal = Runtime::call_function(
+                Runtime::package_address(),
+                "BorrowedBucketTest",
+                "inspect",
+                args!(&bucket),
+            );
+            (inspected, bucket.amount(), bucket)
+        }
+
+        pub fn lend_for_deposit(component_address: ComponentAddress, bucket: Bucket) -> Bucket {
+            let component: BorrowedBucketTestGlobalComponentRef = component_address.into();
+            component.deposit(&bucket);
+            bucket
+        }
+    }
+}
@@ -1,2 +1,3 @@
 pub mod badge;
+pub mod borrowed;
 pub mod bucket;
@@ -6,3 +6,4 @@ pub mod cross_component;
 pub mod external_blueprint_target;
 pub mod mutable_access_rules;
 pub mod reentrant_component;
+pub mod state_size;
@@ -12,6 +12,18 @@ blueprint! {
             component.globalize()
         }
 
+        pub fn new_with_auth_mutability(
+            access_rules: Vec<AccessRules>,
+            auth_mutability: AccessRule,
+        ) -> ComponentAddress {
+            let mut component = Self {}.instantiate();
+            for access_rule in access_rules {
+                component.add_access_check(access_rule);
+            }
+            component.set_auth_mutability(auth_mutability);
+            component.globalize()
+        }
+
         pub fn access_rules_function(
             component_address: ComponentAddress,
         ) -> Vec<ComponentAccessRules> {
@@ -0,0 +1,67 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct EmptyState {}
+
+    impl EmptyState {
+        pub fn new() -> ComponentAddress {
+            Self {}.instantiate().globalize()
+        }
+
+        pub fn size(&self) -> usize {
+            self.state_size_bytes()
+        }
+    }
+}
+
+blueprint! {
+    struct VaultState {
+        vault: Vault,
+    }
+
+    impl VaultState {
+        pub fn new() -> ComponentAddress {
+            let bucket = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_MAXIMUM)
+                .initial_supply(100);
+            Self {
+                vault: Vault::with_bucket(bucket),
+            }
+            .instantiate()
+            .globalize()
+        }
+
+        pub fn size(&self) -> usize {
+            self.state_size_bytes()
+        }
+    }
+}
+
+blueprint! {
+    struct KeyValueStoreState {
+        stores: KeyValueStore<u32, KeyValueStore<u32, String>>,
+    }
+
+    impl KeyValueStoreState {
+        pub fn new() -> ComponentAddress {
+            Self {
+                stores: KeyValueStore::new(),
+            }
+            .instantiate()
+            .globalize()
+        }
+
+        pub fn insert_entries(&mut self, count: u32) -> usize {
+            for i in 0..count {
+                let store = KeyValueStore::new();
+                store.insert(i, "entry".repeat(10));
+                self.stores.insert(i, store);
+            }
+            self.state_size_bytes()
+        }
+
+        pub fn size(&self) -> usize {
+            self.state_size_bytes()
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use radix_engine_interface::api::types::CallerInfo;
 use scrypto::prelude::*;
 
 blueprint! {
@@ -55,5 +56,51 @@ blueprint! {
                 Runtime::generate_uuid(),
             )
         }
+
+        pub fn virtual_proof_resources() -> Vec<ResourceAddress> {
+            Runtime::virtual_proof_resources()
+        }
+    }
+}
+
+blueprint! {
+    struct CallerTest {
+        last_caller: Option<CallerInfo>,
+    }
+
+    impl CallerTest {
+        pub fn create() -> ComponentAddress {
+            Self { last_caller: None }.instantiate().globalize()
+        }
+
+        pub fn record_caller(&mut self) -> Option<CallerInfo> {
+            self.last_caller = Runtime::caller();
+            self.last_caller.clone()
+        }
+
+        pub fn call_record_caller(&self, other: ComponentAddress) -> Option<CallerInfo> {
+            Runtime::call_method(other, "record_caller", args!())
+        }
+    }
+}
+
+#[derive(Debug)]
+#[scrypto(TypeId, Encode, Decode, Describe)]
+pub enum WithdrawError {
+    InsufficientFunds { needed: Decimal },
+}
+
+blueprint! {
+    struct FallibleTest;
+
+    impl FallibleTest {
+        pub fn withdraw(available: Decimal, requested: Decimal) -> Result<Decimal, WithdrawError> {
+            if requested > available {
+                return Err(WithdrawError::InsufficientFunds {
+                    needed: requested - available,
+                });
+            }
+            Ok(available - requested)
+        }
     }
 }
@@ -0,0 +1,27 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct CryptoTest {}
+
+    impl CryptoTest {
+        pub fn sha256(data: Vec<u8>) -> Hash {
+            scrypto::crypto::sha256(data)
+        }
+
+        pub fn blake2b_256(data: Vec<u8>) -> Hash {
+            scrypto::crypto::blake2b_256(data)
+        }
+
+        pub fn keccak256(data: Vec<u8>) -> Hash {
+            scrypto::crypto::keccak256(data)
+        }
+
+        pub fn sha256_of_zeroes(size: u32) -> Hash {
+            scrypto::crypto::sha256(vec![0u8; size as usize])
+        }
+
+        pub fn sha256_of_zeroes_in_wasm(size: u32) -> Hash {
+            radix_engine_interface::crypto::sha256(vec![0u8; size as usize])
+        }
+    }
+}
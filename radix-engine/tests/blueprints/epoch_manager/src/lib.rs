@@ -1,3 +1,4 @@
+use radix_engine_interface::api::api::Invokable;
 use radix_engine_interface::wasm::*;
 use scrypto::engine::scrypto_env::*;
 use scrypto::prelude::*;
@@ -10,6 +11,30 @@ blueprint! {
             Runtime::current_epoch()
         }
 
+        pub fn get_epoch_via_invocation() -> u64 {
+            let mut env = ScryptoEnv;
+            env.invoke(EpochManagerGetCurrentEpochInvocation {
+                receiver: EPOCH_MANAGER,
+            })
+            .unwrap()
+        }
+
+        pub fn get_epoch_repeatedly(n: u32) -> u64 {
+            let mut epoch = 0;
+            for _ in 0..n {
+                epoch = Self::get_epoch();
+            }
+            epoch
+        }
+
+        pub fn get_epoch_via_invocation_repeatedly(n: u32) -> u64 {
+            let mut epoch = 0;
+            for _ in 0..n {
+                epoch = Self::get_epoch_via_invocation();
+            }
+            epoch
+        }
+
         pub fn set_epoch(epoch_manager: SystemAddress, epoch: u64) {
             let input = RadixEngineInput::Invoke(SerializedInvocation::Native(
                 NativeFnInvocation::Method(NativeMethodInvocation::EpochManager(
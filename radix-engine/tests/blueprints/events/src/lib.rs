@@ -0,0 +1,23 @@
+use scrypto::prelude::*;
+
+#[event]
+pub struct DepositEvent {
+    pub amount: Decimal,
+}
+
+#[event]
+pub struct WithdrawEvent {
+    pub amount: Decimal,
+}
+
+blueprint! {
+    struct EventEmitter {}
+
+    impl EventEmitter {
+        pub fn deposit_and_withdraw(deposit: Decimal, withdraw: Decimal) {
+            DepositEvent { amount: deposit }.emit();
+            WithdrawEvent { amount: withdraw }.emit();
+            DepositEvent { amount: deposit }.emit();
+        }
+    }
+}
@@ -47,6 +47,21 @@ blueprint! {
             ExecutionTraceTest { vault }.instantiate().globalize()
         }
 
+        pub fn create_resource_and_child_component(&self) -> (ResourceAddress, ComponentAddress) {
+            let bucket = ResourceBuilder::new_fungible()
+                .divisibility(DIVISIBILITY_MAXIMUM)
+                .initial_supply(100);
+            let resource_address = bucket.resource_address();
+
+            let child_component = ExecutionTraceTest {
+                vault: Vault::with_bucket(bucket),
+            }
+            .instantiate()
+            .globalize();
+
+            (resource_address, child_component)
+        }
+
         pub fn test_lock_contingent_fee(&mut self) {
             self.vault.lock_contingent_fee(dec!("10"));
         }
@@ -1,4 +1,5 @@
 use scrypto::prelude::*;
+use scrypto::radix_engine_interface::api::types::{ScryptoFunctionIdent, ScryptoPackage};
 
 blueprint! {
     struct Fee {
@@ -58,5 +59,39 @@ blueprint! {
             self.xrd.lock_fee(amount);
             info!("Balance: {}", self.xrd.amount());
         }
+
+        pub fn spin(&self, iterations: u32) -> u64 {
+            let mut x = 0u64;
+            for i in 0..iterations {
+                x = x.wrapping_mul(31).wrapping_add(i as u64);
+            }
+            x
+        }
+
+        pub fn lock_fee_with_callback(&mut self, amount: Decimal) {
+            self.xrd
+                .lock_fee_with_callback(amount, Self::callback("on_fee_charged"));
+        }
+
+        pub fn lock_fee_with_failing_callback(&mut self, amount: Decimal) {
+            self.xrd
+                .lock_fee_with_callback(amount, Self::callback("on_fee_charged_and_panic"));
+        }
+
+        pub fn on_fee_charged(fee: Decimal) {
+            info!("Fee charged: {}", fee);
+        }
+
+        pub fn on_fee_charged_and_panic(_fee: Decimal) {
+            panic!("Fee callback failed");
+        }
+
+        fn callback(function_name: &str) -> ScryptoFunctionIdent {
+            ScryptoFunctionIdent {
+                package: ScryptoPackage::Global(Runtime::package_address()),
+                blueprint_name: "Fee".to_owned(),
+                function_name: function_name.to_owned(),
+            }
+        }
     }
 }
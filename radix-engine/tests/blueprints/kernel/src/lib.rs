@@ -55,3 +55,19 @@ blueprint! {
         }
     }
 }
+
+blueprint! {
+    struct NodeHoard {
+        stores: Vec<KeyValueStore<u32, u32>>,
+    }
+
+    impl NodeHoard {
+        pub fn create_kv_stores(n: u32) -> ComponentAddress {
+            let mut stores = Vec::new();
+            for _ in 0..n {
+                stores.push(KeyValueStore::new());
+            }
+            NodeHoard { stores }.instantiate().globalize()
+        }
+    }
+}
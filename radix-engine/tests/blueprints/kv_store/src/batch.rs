@@ -0,0 +1,38 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct BatchKeyValueStore {
+        map: KeyValueStore<u32, String>,
+    }
+
+    impl BatchKeyValueStore {
+        pub fn insert_one_by_one(n: u32) -> ComponentAddress {
+            let map = KeyValueStore::new();
+            for i in 0..n {
+                map.insert(i, i.to_string());
+            }
+            BatchKeyValueStore { map }.instantiate().globalize()
+        }
+
+        pub fn batch_put(n: u32) -> ComponentAddress {
+            let mut map = KeyValueStore::new();
+            map.batch_put((0..n).map(|i| (i, i.to_string())));
+            BatchKeyValueStore { map }.instantiate().globalize()
+        }
+
+        pub fn batch_put_then_batch_get() -> ComponentAddress {
+            let mut map = KeyValueStore::new();
+            map.batch_put(vec![(1u32, "one".to_owned()), (3u32, "three".to_owned())]);
+            assert_eq!(
+                map.batch_get(&[1, 2, 3]),
+                vec![Some("one".to_owned()), None, Some("three".to_owned())]
+            );
+            assert_eq!(*map.get(&3).unwrap(), "three");
+            BatchKeyValueStore { map }.instantiate().globalize()
+        }
+
+        pub fn batch_get(&self, keys: Vec<u32>) -> Vec<Option<String>> {
+            self.map.batch_get(&keys)
+        }
+    }
+}
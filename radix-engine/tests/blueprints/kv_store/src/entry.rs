@@ -0,0 +1,26 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct KeyValueEntryTest {
+        map: KeyValueStore<u32, u32>,
+    }
+
+    impl KeyValueEntryTest {
+        pub fn new() -> ComponentAddress {
+            let map = KeyValueStore::new();
+            map.insert(1u32, 0u32);
+            KeyValueEntryTest { map }.instantiate().globalize()
+        }
+
+        pub fn read_via_get_mut(&mut self, key: u32) -> u32 {
+            let entry = self.map.get_mut(&key).unwrap();
+            *entry
+        }
+
+        pub fn increment_via_get_mut(&mut self, key: u32) -> u32 {
+            let mut entry = self.map.get_mut(&key).unwrap();
+            *entry += 1;
+            *entry
+        }
+    }
+}
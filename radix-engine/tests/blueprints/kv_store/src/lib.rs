@@ -1,4 +1,6 @@
+pub mod batch;
 pub mod cyclic_map;
+pub mod entry;
 pub mod kv_store;
 pub mod multiple_reads;
 pub mod precommitted;
@@ -0,0 +1,21 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct LoggerTest {}
+
+    impl LoggerTest {
+        pub fn log_at_every_level() {
+            error!("Error message");
+            warn!("Warn message");
+            info!("Info message");
+            debug!("Debug message");
+            trace!("Trace message");
+        }
+
+        pub fn log_compiled_out() {
+            debug!("Transaction hash: {:?}", Runtime::transaction_hash());
+        }
+
+        pub fn no_log() {}
+    }
+}
@@ -7,12 +7,51 @@ pub struct Sandwich {
     pub available: bool,
 }
 
+#[derive(NonFungibleData)]
+pub struct Burger {
+    pub weight: u32,
+}
+
 blueprint! {
     struct NonFungibleTest {
         vault: Vault,
     }
 
     impl NonFungibleTest {
+        pub fn create_sandwich_resource() -> ResourceAddress {
+            ResourceBuilder::new_non_fungible(NonFungibleIdType::U32)
+                .data_schema::<Sandwich>()
+                .mintable(rule!(allow_all), rule!(deny_all))
+                .updateable_non_fungible_data(rule!(allow_all), rule!(deny_all))
+                .no_initial_supply()
+        }
+
+        pub fn mint_sandwich(resource_address: ResourceAddress, id: u32) -> Bucket {
+            borrow_resource_manager!(resource_address).mint_non_fungible(
+                &NonFungibleId::U32(id),
+                Sandwich {
+                    name: "Test".to_owned(),
+                    available: false,
+                },
+            )
+        }
+
+        pub fn mint_burger(resource_address: ResourceAddress, id: u32) -> Bucket {
+            borrow_resource_manager!(resource_address)
+                .mint_non_fungible(&NonFungibleId::U32(id), Burger { weight: 200 })
+        }
+
+        pub fn make_sandwich_available(
+            resource_address: ResourceAddress,
+            id: u32,
+        ) -> (String, bool) {
+            let non_fungible: NonFungible<Sandwich> =
+                NonFungible::from(NonFungibleAddress::new(resource_address, NonFungibleId::U32(id)));
+            non_fungible.update_mutable_data(|data| data.available = true);
+            let data = non_fungible.data();
+            (data.name, data.available)
+        }
+
         pub fn create_non_fungible_mutable() -> (Bucket, ResourceAddress, Bucket) {
             // Create a mint badge
             let mint_badge = ResourceBuilder::new_fungible()
@@ -120,6 +159,11 @@ blueprint! {
             );
         }
 
+        pub fn get_non_fungible_data(address: NonFungibleAddress) -> Sandwich {
+            borrow_resource_manager!(address.resource_address())
+                .get_non_fungible_data(&address.non_fungible_id())
+        }
+
         pub fn update_and_get_non_fungible() -> (Bucket, Bucket) {
             let (mint_badge, resource_address, bucket) = Self::create_non_fungible_mutable();
             let mut data: Sandwich = borrow_resource_manager!(resource_address)
@@ -0,0 +1,15 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct PanicTest {}
+
+    impl PanicTest {
+        pub fn assert_false() {
+            assert!(false, "price cannot be zero");
+        }
+
+        pub fn panic_with_long_message() {
+            panic!("{}", "a".repeat(10_000));
+        }
+    }
+}
@@ -30,5 +30,22 @@ blueprint! {
             let _proof = bucket.create_proof();
             bucket
         }
+
+        pub fn check_bucket_proof_is_valid(bucket: Bucket) -> Bucket {
+            let proof = bucket.create_proof();
+            assert!(proof.is_valid());
+            proof.drop();
+            bucket
+        }
+
+        pub fn burn_bucket_while_locked() {
+            let bucket = ResourceBuilder::new_fungible()
+                .burnable(rule!(allow_all), LOCKED)
+                .initial_supply(1);
+            let proof = bucket.create_proof();
+            bucket.burn();
+            assert!(!proof.is_valid());
+            proof.drop();
+        }
     }
 }
@@ -27,6 +27,17 @@ blueprint! {
             proof.drop();
         }
 
+        pub fn check_vault_proof_is_valid_after_withdrawal(
+            &mut self,
+            proof_amount: Decimal,
+        ) -> Bucket {
+            let proof = self.vault.create_proof_by_amount(proof_amount);
+            let bucket = self.vault.take(self.vault.amount() - proof_amount);
+            assert!(proof.is_valid());
+            proof.drop();
+            bucket
+        }
+
         pub fn create_clone_drop_vault_proof_by_amount(
             &self,
             total_amount: Decimal,
@@ -0,0 +1,15 @@
+use scrypto::prelude::*;
+
+blueprint! {
+    struct ReferenceReturner {}
+
+    impl ReferenceReturner {
+        pub fn return_fabricated_address() -> ComponentAddress {
+            ComponentAddress::Normal([7u8; 26])
+        }
+
+        pub fn create_and_return() -> ComponentAddress {
+            Self {}.instantiate().globalize()
+        }
+    }
+}
@@ -93,6 +93,41 @@ blueprint! {
             )
         }
 
+        pub fn query_summary() -> (Bucket, ResourceManagerSummary) {
+            let (badge, resource_address) = Self::create_fungible();
+            let resource_manager = borrow_resource_manager!(resource_address);
+            assert_eq!(resource_manager.divisibility(), Some(DIVISIBILITY_MAXIMUM));
+            assert_eq!(
+                resource_manager.metadata().get("name"),
+                Some(&"TestToken".to_owned())
+            );
+            assert!(resource_manager.is_mintable());
+            assert!(resource_manager.is_burnable());
+            (badge, resource_manager.summary())
+        }
+
+        pub fn read_total_supply(resource_address: ResourceAddress, times: u32) -> Decimal {
+            let resource_manager = borrow_resource_manager!(resource_address);
+            let mut total_supply = Decimal::zero();
+            for _ in 0..times {
+                total_supply = resource_manager.total_supply();
+            }
+            total_supply
+        }
+
+        pub fn mint_and_read_total_supply() -> Bucket {
+            let (badge, resource_address) = Self::create_fungible();
+            let resource_manager = borrow_resource_manager!(resource_address);
+            assert_eq!(resource_manager.total_supply(), Decimal::zero());
+            badge.authorize(|| {
+                let bucket: Bucket = resource_manager.mint(5);
+                assert_eq!(resource_manager.total_supply(), Decimal::from(5));
+                resource_manager.burn(bucket);
+                assert_eq!(resource_manager.total_supply(), Decimal::zero());
+            });
+            badge
+        }
+
         pub fn burn() -> Bucket {
             let (badge, resource_address) = Self::create_fungible();
             let resource_manager = borrow_resource_manager!(resource_address);
@@ -131,5 +166,35 @@ blueprint! {
 
             badge
         }
+
+        pub fn create_ownable_fungible(owner_badge: ResourceAddress) -> Bucket {
+            ResourceBuilder::new_fungible()
+                .with_access_rules_from_template(ResourceAccessRulesTemplate::standard_ownable(
+                    owner_badge,
+                ))
+                .initial_supply(100)
+        }
+
+        pub fn create_fungible_mintable_by_admin(admin_badge: ResourceAddress) -> Bucket {
+            ResourceBuilder::new_fungible()
+                .with_access_rules_from_template(
+                    ResourceAccessRulesTemplate::standard_mintable_by_admin(admin_badge),
+                )
+                .initial_supply(100)
+        }
+
+        pub fn create_fungible_burnable_by_holder() -> Bucket {
+            ResourceBuilder::new_fungible()
+                .with_access_rules_from_template(
+                    ResourceAccessRulesTemplate::standard_burnable_by_holder(),
+                )
+                .initial_supply(100)
+        }
+
+        pub fn create_soulbound_fungible() -> Bucket {
+            ResourceBuilder::new_fungible()
+                .with_access_rules_from_template(ResourceAccessRulesTemplate::standard_soulbound())
+                .initial_supply(100)
+        }
     }
 }
@@ -1,3 +1,6 @@
+// `set_royalty_config` takes a `HashMap`
+#![allow(clippy::disallowed_types)]
+
 use scrypto::prelude::*;
 
 blueprint! {
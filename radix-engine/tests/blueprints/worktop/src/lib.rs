@@ -0,0 +1,16 @@
+use radix_engine_interface::api::api::Invokable;
+use radix_engine_interface::model::*;
+use scrypto::engine::scrypto_env::*;
+use scrypto::prelude::*;
+
+blueprint! {
+    struct WorktopThief {}
+
+    impl WorktopThief {
+        pub fn steal_from_worktop(resource_address: ResourceAddress) -> Bucket {
+            ScryptoEnv
+                .invoke(WorktopTakeAllInvocation { resource_address })
+                .unwrap()
+        }
+    }
+}
@@ -138,13 +138,13 @@ fn test_take_with_invalid_granularity() {
     // Assert
     receipt.expect_specific_failure(|e| {
         if let RuntimeError::ApplicationError(ApplicationError::BucketError(
-            BucketError::ResourceOperationError(ResourceOperationError::InvalidAmount(
+            BucketError::ResourceOperationError(ResourceOperationError::InvalidAmountPrecision {
                 amount,
-                granularity,
-            )),
+                divisibility,
+            }),
         )) = e
         {
-            amount.eq(&dec!("1.123")) && *granularity == 2
+            amount.eq(&dec!("1.123")) && *divisibility == 2
         } else {
             false
         }
@@ -183,13 +183,13 @@ fn test_take_with_negative_amount() {
     // Assert
     receipt.expect_specific_failure(|e| {
         if let RuntimeError::ApplicationError(ApplicationError::BucketError(
-            BucketError::ResourceOperationError(ResourceOperationError::InvalidAmount(
+            BucketError::ResourceOperationError(ResourceOperationError::InvalidAmountPrecision {
                 amount,
-                granularity,
-            )),
+                divisibility,
+            }),
         )) = e
         {
-            amount.eq(&dec!("-2")) && *granularity == 2
+            amount.eq(&dec!("-2")) && *divisibility == 2
         } else {
             false
         }
@@ -228,3 +228,92 @@ fn create_empty_bucket() {
     // Assert
     receipt.expect_commit_success();
 }
+
+#[test]
+fn borrowed_bucket_should_be_intact_in_caller_after_call() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/bucket");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(account, 10.into())
+        .call_method(FAUCET_COMPONENT, "free", args!())
+        .take_from_worktop_by_amount(100.into(), RADIX_TOKEN, |builder, bucket_id| {
+            builder.call_function(
+                package_address,
+                "BorrowedBucketTest",
+                "lend_for_inspection",
+                args!(Bucket(bucket_id)),
+            )
+        })
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let (inspected, remaining, _): (Decimal, Decimal, Bucket) = receipt.output(3);
+    assert_eq!(inspected, 100.into());
+    assert_eq!(remaining, 100.into());
+}
+
+#[test]
+fn depositing_borrowed_bucket_into_vault_should_fail() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/bucket");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(account, 10.into())
+        .call_function(package_address, "BorrowedBucketTest", "new", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component_address = receipt
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0];
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(account, 10.into())
+        .call_method(FAUCET_COMPONENT, "free", args!())
+        .take_from_worktop_by_amount(100.into(), RADIX_TOKEN, |builder, bucket_id| {
+            builder.call_function(
+                package_address,
+                "BorrowedBucketTest",
+                "lend_for_deposit",
+                args!(component_address, Bucket(bucket_id)),
+            )
+        })
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::CallFrameError(CallFrameError::MovingBorrowedRENode(RENodeId::Bucket(
+                ..
+            )))
+        )
+    });
+}
@@ -0,0 +1,123 @@
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::types::*;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::model::ResourceMethodAuthKey::*;
+use radix_engine_interface::rule;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+fn create_token_with_burn_rule(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    account: ComponentAddress,
+    burn_rule: AccessRule,
+) -> ResourceAddress {
+    let mut access_rules = HashMap::new();
+    access_rules.insert(Withdraw, (rule!(allow_all), rule!(deny_all)));
+    access_rules.insert(Deposit, (rule!(allow_all), rule!(deny_all)));
+    access_rules.insert(Burn, (burn_rule, rule!(deny_all)));
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .create_resource(
+            ResourceType::Fungible { divisibility: 18 },
+            BTreeMap::new(),
+            access_rules,
+            Some(MintParams::Fungible { amount: 100.into() }),
+        )
+        .deposit_entire_worktop(account)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+    receipt
+        .expect_commit()
+        .entity_changes
+        .new_resource_addresses[0]
+}
+
+fn test_deposit_to_burner(
+    burn_rule: AccessRule,
+    expected_supply: Decimal,
+    expected_locked: Decimal,
+) {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_token_with_burn_rule(&mut test_runner, account, burn_rule);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(account, 30.into(), resource_address)
+        .burn_via_sink(resource_address)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_total_supply(resource_address),
+        expected_supply
+    );
+    assert_eq!(
+        test_runner.get_burner_locked_amount(resource_address),
+        expected_locked
+    );
+}
+
+#[test]
+fn resource_burnable_by_all_should_be_burned_by_burner() {
+    test_deposit_to_burner(rule!(allow_all), 70.into(), Decimal::zero());
+}
+
+#[test]
+fn resource_burnable_by_burner_badge_should_be_burned_by_burner() {
+    test_deposit_to_burner(burnable_by_burner(), 70.into(), Decimal::zero());
+}
+
+#[test]
+fn non_burnable_resource_should_be_locked_by_burner() {
+    test_deposit_to_burner(rule!(deny_all), 100.into(), 30.into());
+}
+
+#[test]
+fn locked_deposits_of_the_same_resource_should_accumulate() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_token_with_burn_rule(&mut test_runner, account, rule!(deny_all));
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(account, 30.into(), resource_address)
+        .take_from_worktop_by_amount(10.into(), resource_address, |builder, bucket1| {
+            builder.take_from_worktop(resource_address, |builder, bucket2| {
+                builder.call_native_method(
+                    RENodeId::Global(GlobalAddress::System(SYS_BURNER_COMPONENT)),
+                    &BurnerMethod::DepositBatch.to_string(),
+                    scrypto_encode(&BurnerDepositBatchInvocation {
+                        receiver: SYS_BURNER_COMPONENT,
+                        buckets: vec![Bucket(bucket1), Bucket(bucket2)],
+                    })
+                    .unwrap(),
+                )
+            })
+        })
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(test_runner.get_total_supply(resource_address), 100.into());
+    assert_eq!(
+        test_runner.get_burner_locked_amount(resource_address),
+        30.into()
+    );
+}
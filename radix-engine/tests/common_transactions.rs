@@ -1,6 +1,6 @@
 use radix_engine::ledger::TypedInMemorySubstateStore;
 use radix_engine::types::{
-    require, Bech32Encoder, Blob, ComponentAddress, Decimal, FromPublicKey, HashMap,
+    require, BTreeMap, Bech32Encoder, Blob, ComponentAddress, Decimal, FromPublicKey, HashMap,
     NonFungibleAddress, NonFungibleId, ResourceAddress, ResourceMethodAuthKey, ResourceType,
     FAUCET_COMPONENT, RADIX_TOKEN,
 };
@@ -262,7 +262,7 @@ fn test_manifest_with_restricted_minting_resource<F>(
             ManifestBuilder::new(&network)
                 .create_resource(
                     resource_type,
-                    HashMap::from([
+                    BTreeMap::from([
                         (String::from("name"), String::from("Mintable Resource")),
                         (String::from("symbol"), String::from("MINT")),
                     ]),
@@ -13,7 +13,7 @@ use radix_engine_interface::{data::*, rule};
 use scrypto::component::ComponentAccessRules;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
-use transaction::model::TransactionManifest;
+use transaction::model::{Instruction, TransactionManifest};
 use transaction::signing::EcdsaSecp256k1PrivateKey;
 
 #[test]
@@ -584,6 +584,110 @@ fn user_can_not_mutate_auth_on_methods_that_control_auth() {
     }
 }
 
+#[test]
+fn access_checks_can_be_added_before_globalization() {
+    // Arrange
+    let access_rules = vec![
+        AccessRules::new().default(rule!(allow_all), rule!(deny_all)),
+        AccessRules::new().default(rule!(allow_all), rule!(deny_all)),
+    ];
+
+    // Act
+    let mut test_runner = MutableAccessRulesTestRunner::new(access_rules.clone());
+
+    // Assert
+    let access_rules_chain = test_runner.access_rules_chain(Call::Method);
+    assert_eq!(access_rules_chain.len(), 3);
+}
+
+#[test]
+fn access_checks_can_not_be_added_after_globalization_by_default() {
+    // Arrange
+    let mut test_runner = MutableAccessRulesTestRunner::new(vec![]);
+
+    // Act
+    let receipt =
+        test_runner.add_access_check(AccessRules::new().default(rule!(deny_all), rule!(deny_all)));
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized { .. }))
+        )
+    });
+}
+
+#[test]
+fn access_checks_can_be_added_after_globalization_when_auth_mutability_is_satisfied() {
+    // Arrange
+    let private_key = EcdsaSecp256k1PrivateKey::from_u64(709).unwrap();
+    let public_key = private_key.public_key();
+    let virtual_badge_non_fungible_address = NonFungibleAddress::from_public_key(&public_key);
+    let mut test_runner = MutableAccessRulesTestRunner::new_with_auth_mutability(
+        vec![],
+        rule!(require(virtual_badge_non_fungible_address.clone())),
+    );
+
+    // Act
+    let receipt =
+        test_runner.add_access_check(AccessRules::new().default(rule!(deny_all), rule!(deny_all)));
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized { .. }))
+        )
+    });
+    test_runner.add_initial_proof(virtual_badge_non_fungible_address);
+    let receipt =
+        test_runner.add_access_check(AccessRules::new().default(rule!(deny_all), rule!(deny_all)));
+
+    // Assert
+    receipt.expect_commit_success();
+    let receipt = test_runner.borrow_funds();
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized { .. }))
+        )
+    });
+}
+
+#[test]
+fn access_checks_can_not_be_added_to_a_globalized_account() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let receiver = RENodeId::Global(GlobalAddress::Component(account));
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_native_method(
+            receiver,
+            &AccessRulesChainMethod::AddAccessCheck.to_string(),
+            scrypto_encode(&AccessRulesAddAccessCheckInvocation {
+                receiver,
+                access_rules: AccessRules::new().default(rule!(allow_all), rule!(allow_all)),
+            })
+            .unwrap(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized { .. }))
+        )
+    });
+}
+
 struct MutableAccessRulesTestRunner {
     substate_store: TypedInMemorySubstateStore,
     package_address: PackageAddress,
@@ -595,17 +699,26 @@ impl MutableAccessRulesTestRunner {
     const BLUEPRINT_NAME: &'static str = "MutableAccessRulesComponent";
 
     pub fn new(access_rules: Vec<AccessRules>) -> Self {
+        Self::instantiate("new", args!(access_rules))
+    }
+
+    pub fn new_with_auth_mutability(
+        access_rules: Vec<AccessRules>,
+        auth_mutability: AccessRule,
+    ) -> Self {
+        Self::instantiate(
+            "new_with_auth_mutability",
+            args!(access_rules, auth_mutability),
+        )
+    }
+
+    fn instantiate(function_name: &str, args: Vec<u8>) -> Self {
         let mut store = TypedInMemorySubstateStore::with_bootstrap();
         let mut test_runner = TestRunner::new(true, &mut store);
         let package_address = test_runner.compile_and_publish("./tests/blueprints/component");
 
         let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
-            .call_function(
-                package_address,
-                Self::BLUEPRINT_NAME,
-                "new",
-                args!(access_rules),
-            )
+            .call_function(package_address, Self::BLUEPRINT_NAME, function_name, args)
             .build();
         let receipt = test_runner.execute_manifest_ignoring_fee(manifest, vec![]);
         let component_address = receipt.new_component_addresses()[0];
@@ -681,6 +794,22 @@ impl MutableAccessRulesTestRunner {
         self.execute_manifest(manifest)
     }
 
+    pub fn add_access_check(&mut self, access_rules: AccessRules) -> TransactionReceipt {
+        let receiver = RENodeId::Global(GlobalAddress::Component(self.component_address));
+        let manifest = Self::manifest_builder()
+            .call_native_method(
+                receiver,
+                &AccessRulesChainMethod::AddAccessCheck.to_string(),
+                scrypto_encode(&AccessRulesAddAccessCheckInvocation {
+                    receiver,
+                    access_rules,
+                })
+                .unwrap(),
+            )
+            .build();
+        self.execute_manifest(manifest)
+    }
+
     pub fn deposit_funds(&mut self) -> TransactionReceipt {
         let manifest = Self::manifest_builder()
             .call_method(self.component_address, "deposit_funds", args!())
@@ -709,3 +838,104 @@ enum Call {
     Method,
     Function,
 }
+
+fn new_component(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    package_address: PackageAddress,
+    blueprint_name: &str,
+) -> ComponentAddress {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, blueprint_name, "new", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0]
+}
+
+/// Returns the stored state size read natively, and the size computed by the blueprint itself.
+fn query_state_size(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    component_address: ComponentAddress,
+) -> (usize, usize) {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .add_instruction(Instruction::CallNativeMethod {
+            method_ident: NativeMethodIdent {
+                receiver: RENodeId::Global(GlobalAddress::Component(component_address)),
+                method_name: "state_size_bytes".to_string(),
+            },
+            args: scrypto_encode(&ComponentStateSizeBytesInvocation {
+                receiver: RENodeId::Global(GlobalAddress::Component(component_address)),
+            })
+            .unwrap(),
+        })
+        .0
+        .call_method(component_address, "size", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    (receipt.output(1), receipt.output(2))
+}
+
+#[test]
+fn state_size_of_empty_struct_should_be_that_of_an_empty_tuple() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/component");
+    let component_address = new_component(&mut test_runner, package_address, "EmptyState");
+
+    // Act
+    let (stored_size, own_size) = query_state_size(&mut test_runner, component_address);
+
+    // Assert
+    let expected_size = scrypto_encode(&ScryptoValue::Tuple { fields: vec![] })
+        .unwrap()
+        .len();
+    assert_eq!(stored_size, expected_size);
+    assert_eq!(own_size, expected_size);
+}
+
+#[test]
+fn state_size_of_struct_with_vault_should_match_encoded_state() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/component");
+    let empty_component = new_component(&mut test_runner, package_address, "EmptyState");
+    let component_address = new_component(&mut test_runner, package_address, "VaultState");
+
+    // Act
+    let (empty_size, _) = query_state_size(&mut test_runner, empty_component);
+    let (stored_size, own_size) = query_state_size(&mut test_runner, component_address);
+
+    // Assert
+    assert_eq!(stored_size, own_size);
+    assert!(stored_size > empty_size);
+}
+
+#[test]
+fn state_size_of_struct_with_nested_kv_stores_should_not_grow_with_entries() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/component");
+    let component_address = new_component(&mut test_runner, package_address, "KeyValueStoreState");
+    let (size_before, _) = query_state_size(&mut test_runner, component_address);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(component_address, "insert_entries", args!(10u32))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let size_while_inserting: usize = receipt.output(1);
+    let (stored_size, own_size) = query_state_size(&mut test_runner, component_address);
+
+    // Assert
+    assert_eq!(size_while_inserting, size_before);
+    assert_eq!(stored_size, size_before);
+    assert_eq!(own_size, size_before);
+}
@@ -1,10 +1,14 @@
+use radix_engine::engine::{ApplicationError, RuntimeError};
 use radix_engine::ledger::TypedInMemorySubstateStore;
 use radix_engine::types::*;
+use radix_engine_interface::api::types::CallerInfo;
 use radix_engine_interface::core::NetworkDefinition;
 use radix_engine_interface::data::*;
 use radix_engine_interface::model::FromPublicKey;
 use scrypto_unit::*;
-use transaction::builder::ManifestBuilder;
+use transaction::builder::{ManifestBuilder, TransactionBuilder};
+use transaction::model::{PreviewFlags, PreviewIntent, TransactionHeader};
+use transaction::signing::EcdsaSecp256k1PrivateKey;
 
 #[test]
 fn test_process_and_transaction() {
@@ -43,3 +47,215 @@ fn test_call() {
     );
     receipt.expect_commit_success();
 }
+
+#[test]
+fn test_caller_is_calling_component() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/core");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "CallerTest", "create", args!())
+        .call_function(package_address, "CallerTest", "create", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component_a = receipt.new_component_addresses()[0];
+    let component_b = receipt.new_component_addresses()[1];
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(component_a, "call_record_caller", args!(component_b))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let caller: Option<CallerInfo> = receipt.output(1);
+    assert_eq!(caller, Some(CallerInfo::Component(component_a)));
+}
+
+#[test]
+fn test_caller_is_none_when_called_from_manifest() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/core");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "CallerTest", "create", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component = receipt.new_component_addresses()[0];
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(component, "record_caller", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let caller: Option<CallerInfo> = receipt.output(1);
+    assert_eq!(caller, None);
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+enum WithdrawError {
+    InsufficientFunds { needed: Decimal },
+}
+
+#[test]
+fn returning_ok_from_fallible_function_should_succeed() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/core");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "FallibleTest",
+            "withdraw",
+            args!(Decimal::from(10), Decimal::from(4)),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let output: Result<Decimal, WithdrawError> = receipt.output(1);
+    assert_eq!(output, Ok(Decimal::from(6)));
+}
+
+#[test]
+fn returning_err_from_fallible_function_should_fail_with_decoded_error() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/core");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "FallibleTest",
+            "withdraw",
+            args!(Decimal::from(10), Decimal::from(15)),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::BlueprintError { .. })
+        )
+    });
+    assert_eq!(
+        receipt.expect_blueprint_error::<WithdrawError>(),
+        WithdrawError::InsufficientFunds {
+            needed: Decimal::from(5)
+        }
+    );
+}
+
+fn preview_virtual_proof_resources(assume_all_signature_proofs: bool) -> Vec<ResourceAddress> {
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/core");
+    let network = NetworkDefinition::simulator();
+    let manifest = ManifestBuilder::new(&network)
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "CoreTest",
+            "virtual_proof_resources",
+            args!(),
+        )
+        .build();
+    let private_key = EcdsaSecp256k1PrivateKey::from_u64(3).unwrap();
+    let transaction = TransactionBuilder::new()
+        .header(TransactionHeader {
+            version: 1,
+            network_id: network.id,
+            start_epoch_inclusive: 0,
+            end_epoch_exclusive: 99,
+            nonce: test_runner.next_transaction_nonce(),
+            notary_public_key: private_key.public_key().into(),
+            notary_as_signatory: false,
+            cost_unit_limit: 10_000_000,
+            tip_percentage: 0,
+        })
+        .manifest(manifest)
+        .notarize(&private_key)
+        .build();
+    let preview_intent = PreviewIntent {
+        intent: transaction.signed_intent.intent,
+        signer_public_keys: vec![private_key.public_key().into()],
+        flags: PreviewFlags {
+            unlimited_loan: true,
+            assume_all_signature_proofs,
+            permit_invalid_header_epoch: false,
+            permit_duplicate_intent_hash: false,
+        },
+    };
+
+    let receipt = test_runner
+        .execute_preview(preview_intent, &network)
+        .unwrap()
+        .receipt;
+    receipt.output(1)
+}
+
+#[test]
+fn test_virtual_proof_resources_is_empty_without_virtualizable_resources() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, _) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/core");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "CoreTest",
+            "virtual_proof_resources",
+            args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let resources: Vec<ResourceAddress> = receipt.output(1);
+    assert!(resources.is_empty());
+}
+
+#[test]
+fn test_virtual_proof_resources_when_assuming_all_signature_proofs() {
+    // Act
+    let resources = preview_virtual_proof_resources(true);
+
+    // Assert
+    assert_eq!(resources.len(), 2);
+    assert!(resources.contains(&ECDSA_SECP256K1_TOKEN));
+    assert!(resources.contains(&EDDSA_ED25519_TOKEN));
+}
+
+#[test]
+fn test_virtual_proof_resources_when_not_assuming_all_signature_proofs() {
+    // Act
+    let resources = preview_virtual_proof_resources(false);
+
+    // Assert
+    assert!(resources.is_empty());
+}
@@ -0,0 +1,106 @@
+use radix_engine::engine::{KernelError, RuntimeError};
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::types::*;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::data::*;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+#[test]
+fn hashing_in_blueprint_should_match_native_digest() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/crypto");
+    let data = b"Commit to this, then reveal it".to_vec();
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "CryptoTest", "sha256", args!(data.clone()))
+        .call_function(
+            package_address,
+            "CryptoTest",
+            "blake2b_256",
+            args!(data.clone()),
+        )
+        .call_function(
+            package_address,
+            "CryptoTest",
+            "keccak256",
+            args!(data.clone()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let outputs = receipt.expect_commit_success();
+    let sha256: Hash = scrypto_decode(&outputs[1]).unwrap();
+    let blake2b_256: Hash = scrypto_decode(&outputs[2]).unwrap();
+    let keccak256: Hash = scrypto_decode(&outputs[3]).unwrap();
+    assert_eq!(sha256, radix_engine_interface::crypto::sha256(&data));
+    assert_eq!(
+        blake2b_256,
+        radix_engine_interface::crypto::blake2b_256(&data)
+    );
+    assert_eq!(keccak256, radix_engine_interface::crypto::keccak256(&data));
+}
+
+#[test]
+fn hashing_by_engine_should_cost_less_than_hashing_in_wasm() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/crypto");
+    let size = 10 * 1024u32;
+
+    // Act
+    let mut hash_zeroes = |function_name: &str| {
+        let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+            .lock_fee(FAUCET_COMPONENT, 10.into())
+            .call_function(package_address, "CryptoTest", function_name, args!(size))
+            .build();
+        let receipt = test_runner.execute_manifest(manifest, vec![]);
+        let hash: Hash = scrypto_decode(&receipt.expect_commit_success()[1]).unwrap();
+        (hash, receipt.execution.fee_summary.cost_unit_consumed)
+    };
+    let (engine_hash, engine_cost) = hash_zeroes("sha256_of_zeroes");
+    let (wasm_hash, wasm_cost) = hash_zeroes("sha256_of_zeroes_in_wasm");
+
+    // Assert
+    assert_eq!(engine_hash, wasm_hash);
+    assert_eq!(
+        engine_hash,
+        radix_engine_interface::crypto::sha256(vec![0u8; size as usize])
+    );
+    assert!(engine_cost < wasm_cost);
+}
+
+#[test]
+fn hashing_too_much_data_should_fail() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/crypto");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "CryptoTest",
+            "sha256_of_zeroes",
+            args!((MAX_HASH_INPUT_SIZE + 1) as u32),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::KernelError(KernelError::HashInputTooLarge { size, limit })
+                if *size == MAX_HASH_INPUT_SIZE + 1 && *limit == MAX_HASH_INPUT_SIZE
+        )
+    });
+}
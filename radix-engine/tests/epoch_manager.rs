@@ -27,6 +27,35 @@ fn get_epoch_should_succeed() {
     assert_eq!(epoch, 0);
 }
 
+#[test]
+fn get_epoch_should_match_epoch_manager_invocation() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/epoch_manager");
+    test_runner.set_current_epoch(15);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "EpochManagerTest", "get_epoch", args![])
+        .call_function(
+            package_address,
+            "EpochManagerTest",
+            "get_epoch_via_invocation",
+            args![],
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let outputs = receipt.expect_commit_success();
+    let epoch: u64 = scrypto_decode(&outputs[1]).unwrap();
+    let invoked_epoch: u64 = scrypto_decode(&outputs[2]).unwrap();
+    assert_eq!(epoch, 15);
+    assert_eq!(invoked_epoch, 15);
+}
+
 #[test]
 fn set_epoch_without_supervisor_auth_fails() {
     // Arrange
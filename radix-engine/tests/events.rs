@@ -0,0 +1,56 @@
+use radix_engine::engine::TrackedEvent;
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::types::*;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::data::*;
+use radix_engine_interface::model::{scrypto_event_discriminant, split_scrypto_event};
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+#[test]
+fn events_can_be_filtered_by_discriminant() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/events");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "EventEmitter",
+            "deposit_and_withdraw",
+            args!(Decimal::from(5u32), Decimal::from(3u32)),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    let deposit_discriminant =
+        scrypto_event_discriminant(&package_address, "EventEmitter", "DepositEvent");
+    let withdraw_discriminant =
+        scrypto_event_discriminant(&package_address, "EventEmitter", "WithdrawEvent");
+    let amounts_of = |discriminant: Hash| -> Vec<Decimal> {
+        receipt
+            .execution
+            .events
+            .iter()
+            .filter_map(|e| match e {
+                TrackedEvent::Scrypto(payload) => split_scrypto_event(payload),
+                _ => None,
+            })
+            .filter(|(d, _)| *d == discriminant)
+            .map(|(_, event)| {
+                let (amount,): (Decimal,) = scrypto_decode(event).unwrap();
+                amount
+            })
+            .collect()
+    };
+    assert_eq!(
+        amounts_of(deposit_discriminant),
+        vec![Decimal::from(5u32), Decimal::from(5u32)]
+    );
+    assert_eq!(amounts_of(withdraw_discriminant), vec![Decimal::from(3u32)]);
+}
@@ -1,6 +1,9 @@
-use radix_engine::engine::{NativeEvent, SysCallTrace, SysCallTraceOrigin, TrackedEvent};
+use radix_engine::engine::{
+    ActorSummary, NativeEvent, SysCallTrace, SysCallTraceOrigin, TrackedEvent,
+};
 use radix_engine::ledger::TypedInMemorySubstateStore;
 use radix_engine::model::LockedAmountOrIds;
+use radix_engine::transaction::{NewEntity, NewEntityKind};
 use radix_engine::types::*;
 use radix_engine_interface::api::types::NativeMethod;
 use radix_engine_interface::core::NetworkDefinition;
@@ -352,6 +355,63 @@ fn test_instruction_traces() {
     }
 }
 
+#[test]
+fn test_new_entities_are_attributed_to_creating_component() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/execution_trace");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "ExecutionTraceTest",
+            "transfer_resource_between_two_components",
+            args!(1u8),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let (_, component_address, _): (ResourceAddress, ComponentAddress, ComponentAddress) =
+        receipt.output(1);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(
+            component_address,
+            "create_resource_and_child_component",
+            args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let (resource_address, child_component): (ResourceAddress, ComponentAddress) =
+        receipt.output(1);
+    assert_eq!(receipt.new_resource_addresses(), &vec![resource_address]);
+    assert_eq!(receipt.new_component_addresses(), &vec![child_component]);
+
+    let created_by = ActorSummary::Scrypto {
+        package_address,
+        blueprint_name: "ExecutionTraceTest".to_string(),
+        component_address: Some(component_address),
+    };
+    let new_entities = receipt.new_entities();
+    assert_eq!(2, new_entities.len());
+    assert!(new_entities.contains(&NewEntity {
+        address: GlobalAddress::Resource(resource_address),
+        kind: NewEntityKind::Resource,
+        created_by: created_by.clone(),
+        instruction_index: Some(1),
+    }));
+    assert!(new_entities.contains(&NewEntity {
+        address: GlobalAddress::Component(child_component),
+        kind: NewEntityKind::Component,
+        created_by,
+        instruction_index: Some(1),
+    }));
+}
+
 fn traces_for_instruction(
     traces: &Vec<SysCallTrace>,
     instruction_index: usize,
@@ -1,7 +1,8 @@
 use radix_engine::engine::{ApplicationError, KernelError, TrackError};
 use radix_engine::engine::{RejectionError, RuntimeError};
+use radix_engine::fee::CostingActor;
 use radix_engine::ledger::TypedInMemorySubstateStore;
-use radix_engine::model::WorktopError;
+use radix_engine::model::{VaultError, WorktopError};
 use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
 use radix_engine_interface::core::NetworkDefinition;
@@ -18,11 +19,22 @@ where
     // Basic setup
     let mut store = TypedInMemorySubstateStore::with_bootstrap();
     let mut test_runner = TestRunner::new(true, &mut store);
-    let (public_key, _, account) = test_runner.new_allocated_account();
 
     // Publish package and instantiate component
     let package_address = test_runner.compile_and_publish("./tests/blueprints/fee");
-    let receipt1 = test_runner.execute_manifest(
+    let component_address = instantiate_fee_component(&mut test_runner, package_address);
+
+    // Run the provided manifest
+    let manifest = f(component_address);
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+fn instantiate_fee_component(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    package_address: PackageAddress,
+) -> ComponentAddress {
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let receipt = test_runner.execute_manifest(
         ManifestBuilder::new(&NetworkDefinition::simulator())
             .lock_fee(account, 10u32.into())
             .withdraw_from_account_by_amount(account, 10u32.into(), RADIX_TOKEN)
@@ -33,14 +45,10 @@ where
             .build(),
         vec![NonFungibleAddress::from_public_key(&public_key)],
     );
-    let component_address = receipt1
+    receipt
         .expect_commit()
         .entity_changes
-        .new_component_addresses[0];
-
-    // Run the provided manifest
-    let manifest = f(component_address);
-    test_runner.execute_manifest(manifest, vec![])
+        .new_component_addresses[0]
 }
 
 #[test]
@@ -88,7 +96,14 @@ fn should_be_rejected_when_non_xrd() {
             .build()
     });
 
-    receipt.expect_rejection();
+    receipt.expect_specific_rejection(|e| {
+        matches!(
+            e,
+            RejectionError::ErrorBeforeFeeLoanRepaid(RuntimeError::ApplicationError(
+                ApplicationError::VaultError(VaultError::FeeNotXrd(resource_address))
+            )) if *resource_address != RADIX_TOKEN
+        )
+    });
 }
 
 #[test]
@@ -179,6 +194,101 @@ fn should_succeed_when_lock_fee_and_query_vault() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn should_call_fee_callback_with_charged_fee() {
+    let receipt = run_manifest(|component_address| {
+        ManifestBuilder::new(&NetworkDefinition::simulator())
+            .call_method(
+                component_address,
+                "lock_fee_with_callback",
+                args!(Decimal::from(10)),
+            )
+            .build()
+    });
+
+    receipt.expect_commit_success();
+    let logs = &receipt.execution.application_logs;
+    assert_eq!(logs.len(), 1);
+    let charged = Decimal::from_str(logs[0].1.strip_prefix("Fee charged: ").unwrap()).unwrap();
+    let paid: Decimal = receipt
+        .execution
+        .fee_summary
+        .vault_payments_xrd
+        .as_ref()
+        .unwrap()
+        .values()
+        .cloned()
+        .sum();
+    // The fee charged so far excludes the cost of running the callback
+    assert!(charged.is_positive());
+    assert!(charged < paid);
+}
+
+#[test]
+fn should_fail_when_fee_callback_fails() {
+    let receipt = run_manifest(|component_address| {
+        ManifestBuilder::new(&NetworkDefinition::simulator())
+            .call_method(
+                component_address,
+                "lock_fee_with_failing_callback",
+                args!(Decimal::from(10)),
+            )
+            .build()
+    });
+
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn should_attribute_cost_units_to_actors() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/fee");
+    let light_component = instantiate_fee_component(&mut test_runner, package_address);
+    let heavy_component = instantiate_fee_component(&mut test_runner, package_address);
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new(&NetworkDefinition::simulator())
+            .call_method(heavy_component, "lock_fee", args!(Decimal::from(10)))
+            .call_method(light_component, "spin", args!(10u32))
+            .call_method(heavy_component, "spin", args!(100000u32))
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let breakdown = receipt.cost_breakdown();
+    let units_of = |component_address| {
+        breakdown
+            .iter()
+            .find(|(actor, _)| match actor {
+                CostingActor::Scrypto {
+                    component_address: Some(address),
+                    ..
+                } => *address == component_address,
+                _ => false,
+            })
+            .map(|(_, units)| *units)
+            .unwrap()
+    };
+    assert!(units_of(heavy_component) > 10 * units_of(light_component));
+    assert_eq!(
+        breakdown[0].0,
+        CostingActor::Scrypto {
+            package_address,
+            blueprint_name: "Fee".to_owned(),
+            component_address: Some(heavy_component),
+        }
+    );
+    assert_eq!(
+        breakdown.iter().map(|(_, units)| units).sum::<u32>(),
+        receipt.execution.fee_summary.cost_unit_consumed
+    );
+}
+
 #[test]
 fn test_fee_accounting_success() {
     // Arrange
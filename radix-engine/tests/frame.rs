@@ -4,8 +4,10 @@ use radix_engine::types::*;
 use radix_engine_constants::DEFAULT_MAX_CALL_DEPTH;
 use radix_engine_interface::core::NetworkDefinition;
 use radix_engine_interface::data::*;
+use radix_engine_interface::model::FromPublicKey;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
+use transaction::model::*;
 
 #[test]
 fn test_max_call_depth_success() {
@@ -54,3 +56,41 @@ fn test_max_call_depth_failure() {
         )
     });
 }
+
+#[test]
+fn kernel_invariants_should_hold_across_nested_calls() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    // Act
+    // Invariants are asserted around every state changing system call in debug builds, so any
+    // violation along this path (buckets, proofs, auth zone, vaults) panics the test.
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(account, 10.into())
+        .withdraw_from_account_by_amount(account, 100.into(), RADIX_TOKEN)
+        .create_proof_from_account(account, RADIX_TOKEN)
+        .take_from_worktop_by_amount(60.into(), RADIX_TOKEN, |builder, bucket_id| {
+            builder
+                .create_proof_from_bucket(bucket_id, |builder, proof_id| {
+                    builder.drop_proof(proof_id)
+                })
+                .call_method(other_account, "deposit", args!(Bucket(bucket_id)))
+        })
+        .call_method(
+            other_account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .drop_all_proofs()
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
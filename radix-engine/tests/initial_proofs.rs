@@ -0,0 +1,101 @@
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::types::*;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::model::ResourceMethodAuthKey::*;
+use radix_engine_interface::rule;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+use transaction::model::InitialProofs;
+
+fn create_token_with_mint_rule(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    mint_rule: AccessRule,
+) -> ResourceAddress {
+    let mut access_rules = HashMap::new();
+    access_rules.insert(Mint, (mint_rule, rule!(deny_all)));
+    access_rules.insert(Burn, (rule!(allow_all), rule!(deny_all)));
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .create_resource(
+            ResourceType::Fungible { divisibility: 18 },
+            BTreeMap::new(),
+            access_rules,
+            None,
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, InitialProofs::none());
+    receipt.expect_commit_success();
+    receipt
+        .expect_commit()
+        .entity_changes
+        .new_resource_addresses[0]
+}
+
+fn mint_and_burn(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    token: ResourceAddress,
+    initial_proofs: InitialProofs,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .mint(token, 1.into())
+        .burn(token, 1.into())
+        .build();
+    test_runner.execute_manifest(manifest, initial_proofs)
+}
+
+#[test]
+fn custom_badge_passed_as_initial_proof_should_satisfy_rule() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let badge = NonFungibleAddress::new(ResourceAddress::Normal([9u8; 26]), NonFungibleId::U32(1));
+    let token = create_token_with_mint_rule(&mut test_runner, rule!(require(badge.clone())));
+
+    // Act
+    let receipt = mint_and_burn(
+        &mut test_runner,
+        token,
+        InitialProofs::from_non_fungible_addresses(vec![badge]),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn rule_requiring_custom_badge_should_fail_without_initial_proofs() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let badge = NonFungibleAddress::new(ResourceAddress::Normal([9u8; 26]), NonFungibleId::U32(1));
+    let token = create_token_with_mint_rule(&mut test_runner, rule!(require(badge)));
+
+    // Act
+    let receipt = mint_and_burn(&mut test_runner, token, InitialProofs::none());
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
+#[test]
+fn public_key_passed_as_initial_proof_should_satisfy_rule_on_its_virtual_signature_badge() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _) = test_runner.new_key_pair();
+    let token = create_token_with_mint_rule(
+        &mut test_runner,
+        rule!(require(virtual_signature_badge(&public_key))),
+    );
+
+    // Act
+    let receipt = mint_and_burn(
+        &mut test_runner,
+        token,
+        InitialProofs::from_public_keys(vec![public_key.into()]),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
@@ -1,6 +1,7 @@
 use radix_engine::engine::{KernelError, REActor, ResolvedFunction, RuntimeError};
 use radix_engine::ledger::TypedInMemorySubstateStore;
 use radix_engine::types::*;
+use radix_engine_constants::DEFAULT_MAX_OWNED_NODES_PER_FRAME;
 use radix_engine_interface::core::NetworkDefinition;
 use radix_engine_interface::data::*;
 use scrypto_unit::*;
@@ -75,3 +76,56 @@ fn should_not_be_able_to_node_create_with_invalid_package() {
         )
     });
 }
+
+#[test]
+fn should_be_able_to_own_nodes_below_limit() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/kernel");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "NodeHoard",
+            "create_kv_stores",
+            args!(100u32),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn should_not_be_able_to_own_more_nodes_than_limit() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/kernel");
+    let num_nodes = u32::try_from(DEFAULT_MAX_OWNED_NODES_PER_FRAME).unwrap() + 1;
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 100.into())
+        .call_function(
+            package_address,
+            "NodeHoard",
+            "create_kv_stores",
+            args!(num_nodes),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::KernelError(KernelError::OwnedNodeLimitExceeded { limit })
+                if *limit == DEFAULT_MAX_OWNED_NODES_PER_FRAME
+        )
+    });
+}
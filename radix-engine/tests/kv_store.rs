@@ -1,7 +1,10 @@
 use radix_engine::engine::{CallFrameError, KernelError, RuntimeError};
 use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
-use radix_engine_interface::api::types::RENodeId;
+use radix_engine_interface::api::types::{
+    KeyValueStoreOffset, RENodeId, SubstateId, SubstateOffset,
+};
 use radix_engine_interface::core::NetworkDefinition;
 use radix_engine_interface::data::*;
 use scrypto_unit::*;
@@ -384,3 +387,148 @@ fn multiple_reads_should_work() {
     // Assert
     receipt.expect_commit_success();
 }
+
+#[test]
+fn batch_put_then_batch_get_should_work() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/kv_store");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "BatchKeyValueStore",
+            "batch_put_then_batch_get",
+            args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn batch_put_entries_should_be_readable_in_later_transactions() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/kv_store");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "BatchKeyValueStore",
+            "batch_put",
+            args!(100u32),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component_address = receipt
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0];
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(
+            component_address,
+            "batch_get",
+            args!(vec![0u32, 99u32, 100u32]),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let values: Vec<Option<String>> = receipt.output(1);
+    assert_eq!(
+        values,
+        vec![Some("0".to_owned()), Some("99".to_owned()), None]
+    );
+}
+
+fn call_kv_entry_method(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    component_address: ComponentAddress,
+    method_name: &str,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(component_address, method_name, args!(1u32))
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+fn kv_store_entries_written(receipt: &TransactionReceipt) -> usize {
+    receipt
+        .expect_commit()
+        .state_updates
+        .up_substates
+        .keys()
+        .filter(|SubstateId(node_id, offset)| {
+            matches!(node_id, RENodeId::KeyValueStore(..))
+                && matches!(
+                    offset,
+                    SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(..))
+                )
+        })
+        .count()
+}
+
+#[test]
+fn read_only_get_mut_should_not_write_entry() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/kv_store");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "KeyValueEntryTest", "new", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component_address = receipt
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0];
+
+    // Act
+    let receipt = call_kv_entry_method(&mut test_runner, component_address, "read_via_get_mut");
+
+    // Assert
+    let value: u32 = receipt.output(1);
+    assert_eq!(value, 0);
+    assert_eq!(kv_store_entries_written(&receipt), 0);
+}
+
+#[test]
+fn mutated_get_mut_should_write_entry() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/kv_store");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "KeyValueEntryTest", "new", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component_address = receipt
+        .expect_commit()
+        .entity_changes
+        .new_component_addresses[0];
+
+    // Act
+    let receipt =
+        call_kv_entry_method(&mut test_runner, component_address, "increment_via_get_mut");
+    call_kv_entry_method(&mut test_runner, component_address, "increment_via_get_mut");
+    let read_receipt =
+        call_kv_entry_method(&mut test_runner, component_address, "read_via_get_mut");
+
+    // Assert
+    assert_eq!(kv_store_entries_written(&receipt), 1);
+    let value: u32 = read_receipt.output(1);
+    assert_eq!(value, 2);
+}
@@ -0,0 +1,53 @@
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::data::*;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+// The logger blueprint is built with the `max-level-info` scrypto feature.
+fn call_logger_function(function_name: &str) -> TransactionReceipt {
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/logger");
+
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "LoggerTest", function_name, args!())
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+#[test]
+fn logs_should_carry_their_level() {
+    // Act
+    let receipt = call_logger_function("log_at_every_level");
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        receipt.execution.application_logs,
+        vec![
+            (Level::Error, "Error message".to_owned()),
+            (Level::Warn, "Warn message".to_owned()),
+            (Level::Info, "Info message".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn compiled_out_logs_should_cost_nothing() {
+    // Act
+    let logged = call_logger_function("log_compiled_out");
+    let not_logged = call_logger_function("no_log");
+
+    // Assert
+    logged.expect_commit_success();
+    not_logged.expect_commit_success();
+    assert!(logged.execution.application_logs.is_empty());
+    assert_eq!(
+        logged.execution.fee_summary.cost_unit_consumed,
+        not_logged.execution.fee_summary.cost_unit_consumed
+    );
+}
@@ -17,7 +17,7 @@ fn cannot_set_package_metadata_with_no_owner() {
             code,
             HashMap::new(),
             HashMap::new(),
-            HashMap::new(),
+            BTreeMap::new(),
             AccessRules::new(),
         )
         .build();
@@ -18,7 +18,7 @@ fn test_loop() {
         code,
         generate_single_function_abi("Test", "f", Type::Unit),
         HashMap::new(),
-        HashMap::new(),
+        BTreeMap::new(),
         AccessRules::new().default(AccessRule::AllowAll, AccessRule::AllowAll),
     );
     let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
@@ -45,7 +45,7 @@ fn test_loop_out_of_cost_unit() {
         code,
         generate_single_function_abi("Test", "f", Type::Unit),
         HashMap::new(),
-        HashMap::new(),
+        BTreeMap::new(),
         AccessRules::new().default(AccessRule::AllowAll, AccessRule::AllowAll),
     );
     let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
@@ -71,7 +71,7 @@ fn test_recursion() {
         code,
         generate_single_function_abi("Test", "f", Type::Unit),
         HashMap::new(),
-        HashMap::new(),
+        BTreeMap::new(),
         AccessRules::new().default(AccessRule::AllowAll, AccessRule::AllowAll),
     );
     let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
@@ -96,7 +96,7 @@ fn test_recursion_stack_overflow() {
         code,
         generate_single_function_abi("Test", "f", Type::Unit),
         HashMap::new(),
-        HashMap::new(),
+        BTreeMap::new(),
         AccessRules::new().default(AccessRule::AllowAll, AccessRule::AllowAll),
     );
     let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
@@ -121,7 +121,7 @@ fn test_grow_memory() {
         code,
         generate_single_function_abi("Test", "f", Type::Unit),
         HashMap::new(),
-        HashMap::new(),
+        BTreeMap::new(),
         AccessRules::new().default(AccessRule::AllowAll, AccessRule::AllowAll),
     );
     let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
@@ -146,7 +146,7 @@ fn test_grow_memory_out_of_cost_unit() {
         code,
         generate_single_function_abi("Test", "f", Type::Unit),
         HashMap::new(),
-        HashMap::new(),
+        BTreeMap::new(),
         AccessRules::new().default(AccessRule::AllowAll, AccessRule::AllowAll),
     );
     let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
@@ -233,7 +233,7 @@ fn test_publish_large_package() {
             code,
             HashMap::new(),
             HashMap::new(),
-            HashMap::new(),
+            BTreeMap::new(),
             AccessRules::new().default(AccessRule::AllowAll, AccessRule::AllowAll),
         )
         .build();
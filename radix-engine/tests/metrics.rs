@@ -0,0 +1,74 @@
+use radix_engine::engine::EngineMetrics;
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::types::*;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::data::*;
+use radix_engine_interface::model::FromPublicKey;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+#[test]
+fn transfer_should_have_expected_engine_metrics() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(account, 10.into())
+        .withdraw_from_account_by_amount(account, 1.into(), RADIX_TOKEN)
+        .call_method(
+            other_account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        receipt.expect_debug_metrics(),
+        &EngineMetrics {
+            wasm_instantiations: 3,
+            native_invocations: 7,
+            scrypto_invocations: 3,
+            substate_reads: 22,
+            substate_read_bytes: 326902,
+            substate_writes: 22,
+            substate_write_bytes: 326902,
+            lock_acquisitions: 82,
+            node_creations: 4,
+        }
+    );
+}
+
+#[test]
+fn read_only_native_calls_should_not_push_auth_zone_frames() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let mut lock_acquisitions = |asserts: u32| {
+        let mut builder = ManifestBuilder::new(&NetworkDefinition::simulator());
+        builder.lock_fee(FAUCET_COMPONENT, 10.into());
+        for _ in 0..asserts {
+            builder.assert_worktop_contains_by_amount(Decimal::zero(), RADIX_TOKEN);
+        }
+        let receipt = test_runner.execute_manifest(builder.build(), vec![]);
+        receipt.expect_commit_success();
+        receipt.expect_debug_metrics().lock_acquisitions
+    };
+
+    // Act
+    let without_asserts = lock_acquisitions(0);
+    let with_asserts = lock_acquisitions(10);
+
+    // Assert
+    // Each assert only locks the worktop and the auth zone stack for its auth check
+    assert_eq!(with_asserts - without_asserts, 10 * 2);
+}
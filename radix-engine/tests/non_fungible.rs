@@ -1,10 +1,14 @@
+use radix_engine::engine::{ApplicationError, RuntimeError};
 use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::model::ResourceManagerError;
 use radix_engine::types::*;
+use radix_engine_interface::api::types::RENodeId;
 use radix_engine_interface::core::NetworkDefinition;
 use radix_engine_interface::data::*;
 use radix_engine_interface::model::FromPublicKey;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
+use transaction::model::Instruction;
 use utils::ContextualDisplay;
 
 #[test]
@@ -96,6 +100,126 @@ fn can_burn_non_fungible() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn reading_data_of_burned_non_fungible_should_fail_as_burned() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package = test_runner.compile_and_publish("./tests/blueprints/non_fungible");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package,
+            "NonFungibleTest",
+            "create_burnable_non_fungible",
+            args!(),
+        )
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let resource_address = receipt
+        .expect_commit()
+        .entity_changes
+        .new_resource_addresses[0];
+    let non_fungible_address = NonFungibleAddress::new(resource_address, NonFungibleId::U32(0));
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account(account, resource_address)
+        .burn_non_fungible(non_fungible_address.clone())
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    test_runner
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleAddress::from_public_key(&public_key)],
+        )
+        .expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package,
+            "NonFungibleTest",
+            "get_non_fungible_data",
+            args!(non_fungible_address),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(
+                ResourceManagerError::NonFungibleAlreadyBurned(id)
+            )) if *id == NonFungibleId::U32(0)
+        )
+    });
+}
+
+#[test]
+fn reading_data_of_never_minted_non_fungible_should_fail_as_not_found() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+    let package = test_runner.compile_and_publish("./tests/blueprints/non_fungible");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package,
+            "NonFungibleTest",
+            "create_burnable_non_fungible",
+            args!(),
+        )
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let resource_address = receipt
+        .expect_commit()
+        .entity_changes
+        .new_resource_addresses[0];
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package,
+            "NonFungibleTest",
+            "get_non_fungible_data",
+            args!(NonFungibleAddress::new(
+                resource_address,
+                NonFungibleId::U32(5)
+            )),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(
+                ResourceManagerError::NonFungibleNotFound(..)
+            ))
+        )
+    });
+}
+
 #[test]
 fn test_take_non_fungible() {
     // Arrange
@@ -437,3 +561,138 @@ fn cant_burn_non_fungible_with_wrong_non_fungible_id_type() {
     // Assert
     receipt.expect_commit_failure();
 }
+
+fn create_sandwich_resource(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    package: PackageAddress,
+    account: ComponentAddress,
+) -> ResourceAddress {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package,
+            "NonFungibleTest",
+            "create_sandwich_resource",
+            args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let resource_address = receipt.output(1);
+
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package,
+            "NonFungibleTest",
+            "mint_sandwich",
+            args!(resource_address, 0u32),
+        )
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    resource_address
+}
+
+fn is_data_schema_mismatch(e: &RuntimeError) -> bool {
+    matches!(
+        e,
+        RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(
+            ResourceManagerError::NonFungibleDataDoesNotMatchSchema(..)
+        ))
+    )
+}
+
+#[test]
+fn cannot_mint_non_fungible_with_data_not_matching_schema() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+    let package = test_runner.compile_and_publish("./tests/blueprints/non_fungible");
+    let resource_address = create_sandwich_resource(&mut test_runner, package, account);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package,
+            "NonFungibleTest",
+            "mint_burger",
+            args!(resource_address, 1u32),
+        )
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(is_data_schema_mismatch);
+}
+
+#[test]
+fn cannot_update_immutable_non_fungible_data() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+    let package = test_runner.compile_and_publish("./tests/blueprints/non_fungible");
+    let resource_address = create_sandwich_resource(&mut test_runner, package, account);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .add_instruction(Instruction::CallNativeMethod {
+            method_ident: NativeMethodIdent {
+                receiver: RENodeId::Global(GlobalAddress::Resource(resource_address)),
+                method_name: "update_non_fungible_data".to_string(),
+            },
+            args: scrypto_encode(&ResourceManagerUpdateNonFungibleDataInvocation {
+                receiver: resource_address,
+                id: NonFungibleId::U32(0),
+                data: scrypto_encode(&("Hacked".to_string(), true)).unwrap(),
+            })
+            .unwrap(),
+        })
+        .0
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(is_data_schema_mismatch);
+}
+
+#[test]
+fn can_update_mutable_non_fungible_data_matching_schema() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+    let package = test_runner.compile_and_publish("./tests/blueprints/non_fungible");
+    let resource_address = create_sandwich_resource(&mut test_runner, package, account);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package,
+            "NonFungibleTest",
+            "make_sandwich_available",
+            args!(resource_address, 0u32),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let output: (String, bool) = receipt.output(1);
+    assert_eq!(output, ("Test".to_string(), true));
+}
@@ -0,0 +1,139 @@
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::types::*;
+use radix_engine_constants::DEFAULT_MIN_COST_UNIT_LIMIT;
+use radix_engine_interface::core::NetworkDefinition;
+use scrypto_unit::*;
+use transaction::builder::{ManifestBuilder, TransactionBuilder};
+use transaction::errors::{SignatureValidationError, TransactionValidationError};
+use transaction::model::{
+    NotarizedTransaction, PreviewFlags, PreviewIntent, SignedTransactionIntent, TransactionHeader,
+    TransactionIntent, TransactionManifest,
+};
+use transaction::signing::EcdsaSecp256k1PrivateKey;
+
+fn transfer_manifest(account: ComponentAddress) -> TransactionManifest {
+    ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(account, 10.into())
+        .withdraw_from_account_by_amount(account, 1.into(), RADIX_TOKEN)
+        .deposit_entire_worktop(account)
+        .build()
+}
+
+fn header(notary: &EcdsaSecp256k1PrivateKey, cost_unit_limit: u32) -> TransactionHeader {
+    TransactionHeader {
+        version: 1,
+        network_id: NetworkDefinition::simulator().id,
+        start_epoch_inclusive: 0,
+        end_epoch_exclusive: 100,
+        nonce: 5,
+        notary_public_key: notary.public_key().into(),
+        notary_as_signatory: false,
+        cost_unit_limit,
+        tip_percentage: 0,
+    }
+}
+
+#[test]
+fn transaction_built_signed_and_notarized_separately_should_execute() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, private_key, account) = test_runner.new_allocated_account();
+    let notary = EcdsaSecp256k1PrivateKey::from_u64(100).unwrap();
+
+    // Build the intent and estimate its fee with a preview
+    let draft_intent = TransactionBuilder::new()
+        .header(header(&notary, DEFAULT_MIN_COST_UNIT_LIMIT))
+        .manifest(transfer_manifest(account))
+        .build_intent();
+    let preview = test_runner
+        .execute_preview(
+            PreviewIntent {
+                intent: draft_intent.clone(),
+                signer_public_keys: vec![public_key.into()],
+                flags: PreviewFlags {
+                    unlimited_loan: true,
+                    assume_all_signature_proofs: false,
+                    permit_duplicate_intent_hash: false,
+                    permit_invalid_header_epoch: false,
+                },
+            },
+            &NetworkDefinition::simulator(),
+        )
+        .unwrap();
+    let cost_unit_consumed = preview.receipt.execution.fee_summary.cost_unit_consumed;
+    let intent_bytes = TransactionBuilder::new()
+        .header(header(
+            &notary,
+            DEFAULT_MIN_COST_UNIT_LIMIT.max(cost_unit_consumed * 2),
+        ))
+        .manifest(draft_intent.manifest)
+        .build_intent()
+        .to_bytes()
+        .unwrap();
+
+    // Sign the intent elsewhere
+    let signed_intent_bytes = TransactionBuilder::new()
+        .intent(TransactionIntent::from_slice(&intent_bytes).unwrap())
+        .sign(&private_key)
+        .build_signed_intent()
+        .to_bytes()
+        .unwrap();
+
+    // Notarize the signed intent elsewhere
+    let transaction_bytes = TransactionBuilder::new()
+        .signed_intent(SignedTransactionIntent::from_slice(&signed_intent_bytes).unwrap())
+        .notarize(&notary)
+        .build()
+        .to_bytes()
+        .unwrap();
+
+    // Act
+    let transaction = NotarizedTransaction::from_slice(&transaction_bytes).unwrap();
+    let receipt = test_runner
+        .execute_notarized_transaction(&transaction)
+        .unwrap();
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        transaction.intent_hash().unwrap(),
+        TransactionIntent::from_slice(&intent_bytes)
+            .unwrap()
+            .hash()
+            .unwrap()
+    );
+}
+
+#[test]
+fn transaction_notarized_by_wrong_notary_should_fail_validation() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, private_key, account) = test_runner.new_allocated_account();
+    let notary = EcdsaSecp256k1PrivateKey::from_u64(100).unwrap();
+    let wrong_notary = EcdsaSecp256k1PrivateKey::from_u64(101).unwrap();
+
+    let signed_intent_bytes = TransactionBuilder::new()
+        .header(header(&notary, DEFAULT_MIN_COST_UNIT_LIMIT))
+        .manifest(transfer_manifest(account))
+        .sign(&private_key)
+        .build_signed_intent()
+        .to_bytes()
+        .unwrap();
+    let transaction = TransactionBuilder::new()
+        .signed_intent(SignedTransactionIntent::from_slice(&signed_intent_bytes).unwrap())
+        .notarize(&wrong_notary)
+        .build();
+
+    // Act
+    let result = test_runner.execute_notarized_transaction(&transaction);
+
+    // Assert
+    assert_eq!(
+        result.unwrap_err(),
+        TransactionValidationError::SignatureValidationError(
+            SignatureValidationError::InvalidNotarySignature
+        )
+    );
+}
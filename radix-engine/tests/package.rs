@@ -1,12 +1,16 @@
 use radix_engine::engine::{ApplicationError, KernelError, RuntimeError};
 use radix_engine::ledger::TypedInMemorySubstateStore;
 use radix_engine::model::PackageError;
+use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig, TransactionReceipt};
 use radix_engine::types::*;
 use radix_engine::wasm::*;
+use radix_engine_constants::DEFAULT_COST_UNIT_LIMIT;
 use radix_engine_interface::core::NetworkDefinition;
 use radix_engine_interface::data::*;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
+use transaction::model::TestTransaction;
+use transaction::validation::predict_package_address;
 
 #[test]
 fn missing_memory_should_cause_error() {
@@ -30,7 +34,7 @@ fn missing_memory_should_cause_error() {
             code,
             HashMap::new(),
             HashMap::new(),
-            HashMap::new(),
+            BTreeMap::new(),
             AccessRules::new().default(AccessRule::AllowAll, AccessRule::AllowAll),
         )
         .build();
@@ -132,7 +136,7 @@ fn test_basic_package() {
             code,
             HashMap::new(),
             HashMap::new(),
-            HashMap::new(),
+            BTreeMap::new(),
             AccessRules::new().default(AccessRule::AllowAll, AccessRule::AllowAll),
         )
         .build();
@@ -170,7 +174,7 @@ fn test_basic_package_missing_export() {
             code,
             blueprints,
             HashMap::new(),
-            HashMap::new(),
+            BTreeMap::new(),
             AccessRules::new().default(AccessRule::AllowAll, AccessRule::AllowAll),
         )
         .build();
@@ -181,8 +185,273 @@ fn test_basic_package_missing_export() {
         matches!(
             e,
             RuntimeError::ApplicationError(ApplicationError::PackageError(
-                PackageError::InvalidWasm(PrepareError::MissingExport { .. })
-            ))
+                PackageError::InvalidWasm(PrepareError::MissingExport {
+                    blueprint_name,
+                    fn_ident,
+                    export_name,
+                })
+            )) if blueprint_name == "some_blueprint" && fn_ident == "f" && export_name == "f"
+        )
+    });
+}
+
+fn basic_package_fn_abi(ident: &str, export_name: &str) -> Fn {
+    Fn {
+        ident: ident.to_string(),
+        mutability: Option::None,
+        input: Type::Unit,
+        output: Type::Unit,
+        export_name: export_name.to_string(),
+    }
+}
+
+fn publish_basic_package(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    blueprints: HashMap<String, BlueprintAbi>,
+) -> TransactionReceipt {
+    let code = wat2wasm(include_str!("wasm/basic_package.wat"));
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .publish_package(
+            code,
+            blueprints,
+            HashMap::new(),
+            BTreeMap::new(),
+            AccessRules::new().default(AccessRule::AllowAll, AccessRule::AllowAll),
+        )
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+#[test]
+fn test_basic_package_with_matching_abi() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let mut blueprints = HashMap::new();
+    blueprints.insert(
+        "Test".to_string(),
+        BlueprintAbi {
+            structure: Type::Unit,
+            fns: vec![basic_package_fn_abi("f", "Test_f")],
+        },
+    );
+
+    // Act
+    let receipt = publish_basic_package(&mut test_runner, blueprints);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_basic_package_invalid_export_signature() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let mut blueprints = HashMap::new();
+    blueprints.insert(
+        "Test".to_string(),
+        BlueprintAbi {
+            structure: Type::Unit,
+            fns: vec![basic_package_fn_abi("free", "scrypto_free")],
+        },
+    );
+
+    // Act
+    let receipt = publish_basic_package(&mut test_runner, blueprints);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::PackageError(
+                PackageError::InvalidWasm(PrepareError::InvalidExportSignature {
+                    export_name,
+                    ..
+                })
+            )) if export_name == "scrypto_free"
+        )
+    });
+}
+
+#[test]
+fn test_basic_package_duplicate_export_name() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let mut blueprints = HashMap::new();
+    for blueprint_name in ["Test", "Other"] {
+        blueprints.insert(
+            blueprint_name.to_string(),
+            BlueprintAbi {
+                structure: Type::Unit,
+                fns: vec![basic_package_fn_abi("f", "Test_f")],
+            },
+        );
+    }
+
+    // Act
+    let receipt = publish_basic_package(&mut test_runner, blueprints);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::PackageError(
+                PackageError::InvalidWasm(PrepareError::DuplicateExportName { export_name })
+            )) if export_name == "Test_f"
+        )
+    });
+}
+
+#[test]
+fn predicted_package_addresses_should_match_published_addresses() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let code = wat2wasm(include_str!("wasm/basic_package.wat"));
+    let mut builder = ManifestBuilder::new(&NetworkDefinition::simulator());
+    builder.lock_fee(FAUCET_COMPONENT, 10.into());
+    for _ in 0..3 {
+        builder.publish_package(
+            code.clone(),
+            HashMap::new(),
+            HashMap::new(),
+            BTreeMap::new(),
+            AccessRules::new().default(AccessRule::AllowAll, AccessRule::AllowAll),
+        );
+    }
+    let transaction = TestTransaction::new(
+        builder.build(),
+        test_runner.next_transaction_nonce(),
+        DEFAULT_COST_UNIT_LIMIT,
+    );
+    let executable = transaction.get_executable(vec![]);
+    let transaction_hash = *executable.transaction_hash();
+
+    // Act
+    let receipt = test_runner.execute_transaction(&executable);
+
+    // Assert
+    assert_eq!(
+        receipt.new_package_addresses(),
+        &vec![
+            predict_package_address(transaction_hash, 0),
+            predict_package_address(transaction_hash, 1),
+            predict_package_address(transaction_hash, 2),
+        ]
+    );
+}
+
+fn publish_with_permissioned_publish(present_badge: bool) -> TransactionReceipt {
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let badge = test_runner.create_non_fungible_resource(account);
+    let code = include_bytes!("../../assets/faucet.wasm").to_vec();
+    let abi: HashMap<String, BlueprintAbi> =
+        scrypto_decode(include_bytes!("../../assets/faucet.abi")).unwrap();
+
+    let mut builder = ManifestBuilder::new(&NetworkDefinition::simulator());
+    builder.lock_fee(account, 10.into());
+    if present_badge {
+        builder.create_proof_from_account(account, badge);
+    }
+    builder.publish_package(
+        code,
+        abi,
+        HashMap::new(),
+        BTreeMap::new(),
+        AccessRules::new().default(AccessRule::AllowAll, AccessRule::AllowAll),
+    );
+    let transaction = TestTransaction::new(
+        builder.build(),
+        test_runner.next_transaction_nonce(),
+        DEFAULT_COST_UNIT_LIMIT,
+    );
+
+    test_runner.execute_transaction_with_config(
+        &transaction.get_executable(vec![NonFungibleAddress::from_public_key(&public_key)]),
+        &FeeReserveConfig::default(),
+        &ExecutionConfig::default().permissioned_publish(badge),
+    )
+}
+
+#[test]
+fn publish_with_badge_on_permissioned_network_should_succeed() {
+    // Act
+    let receipt = publish_with_permissioned_publish(true);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(receipt.new_package_addresses().len(), 1);
+}
+
+#[test]
+fn publish_without_badge_on_permissioned_network_should_fail() {
+    // Act
+    let receipt = publish_with_permissioned_publish(false);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::KernelError(KernelError::PublishNotAuthorized(..))
         )
     });
 }
+
+fn get_build_fingerprint(code: Vec<u8>) -> Option<BuildFingerprint> {
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+    let owner_badge_resource = test_runner.create_non_fungible_resource(account);
+    let owner_badge_addr = NonFungibleAddress::new(owner_badge_resource, NonFungibleId::U32(1));
+    let abi: HashMap<String, BlueprintAbi> =
+        scrypto_decode(include_bytes!("../../assets/faucet.abi")).unwrap();
+    let package_address = test_runner.publish_package_with_owner(code, abi, owner_badge_addr);
+
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_native_method(
+            RENodeId::Global(GlobalAddress::Package(package_address)),
+            &PackageMethod::GetBuildFingerprint.to_string(),
+            scrypto_encode(&PackageGetBuildFingerprintInvocation {
+                receiver: package_address,
+            })
+            .unwrap(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.output(1)
+}
+
+#[test]
+fn build_fingerprint_should_be_readable_by_anyone_after_publish() {
+    // Arrange
+    let fingerprint = BuildFingerprint {
+        source_hash: hash("source"),
+        toolchain: "rustc 1.65.0 (897e37553 2022-11-02)".to_string(),
+    };
+    let code = WasmModule::init(include_bytes!("../../assets/faucet.wasm"))
+        .and_then(|module| module.with_build_fingerprint(&fingerprint))
+        .and_then(WasmModule::to_bytes)
+        .unwrap()
+        .0;
+
+    // Act
+    let output = get_build_fingerprint(code);
+
+    // Assert
+    assert_eq!(output, Some(fingerprint));
+}
+
+#[test]
+fn package_built_without_fingerprint_should_have_none() {
+    // Act
+    let output = get_build_fingerprint(include_bytes!("../../assets/faucet.wasm").to_vec());
+
+    // Assert
+    assert_eq!(output, None);
+}
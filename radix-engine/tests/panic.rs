@@ -0,0 +1,103 @@
+use radix_engine::engine::{KernelError, RuntimeError};
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine::wasm::*;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::data::*;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+fn call_panic_function(function_name: &str) -> TransactionReceipt {
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/panic");
+
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "PanicTest", function_name, args!())
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+fn panic_message(e: &RuntimeError) -> Option<&String> {
+    match e {
+        RuntimeError::KernelError(KernelError::WasmError(WasmError::Panic(message))) => {
+            Some(message)
+        }
+        _ => None,
+    }
+}
+
+#[test]
+fn failed_assert_should_report_panic_message() {
+    // Act
+    let receipt = call_panic_function("assert_false");
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        panic_message(e)
+            .map(|message| {
+                message.contains("price cannot be zero") && message.contains("src/lib.rs")
+            })
+            .unwrap_or(false)
+    });
+}
+
+#[test]
+fn long_panic_message_should_be_truncated() {
+    // Act
+    let receipt = call_panic_function("panic_with_long_message");
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        panic_message(e)
+            .map(|message| message.len() == MAX_PANIC_MESSAGE_SIZE)
+            .unwrap_or(false)
+    });
+}
+
+#[test]
+fn trap_without_panic_message_should_report_wasm_error() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let code = wat2wasm(
+        r#"
+            (module
+                (func $Test_f (param $0 i32) (result i32)
+                    unreachable
+                )
+                (memory $0 1)
+                (export "memory" (memory $0))
+                (export "scrypto_alloc" (func $scrypto_alloc))
+                (export "scrypto_free" (func $scrypto_free))
+                (export "Test_f" (func $Test_f))
+                ${memcpy}
+                ${buffer}
+            )
+            "#,
+    );
+    let package_address = test_runner.publish_package(
+        code,
+        generate_single_function_abi("Test", "f", Type::Unit),
+        HashMap::new(),
+        BTreeMap::new(),
+        AccessRules::new().default(AccessRule::AllowAll, AccessRule::AllowAll),
+    );
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "Test", "f", args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::KernelError(KernelError::WasmError(WasmError::WasmError(..)))
+        )
+    });
+}
@@ -1,14 +1,17 @@
 use radix_engine::engine::node_move_module::NodeMoveError;
 use radix_engine::engine::{ModuleError, RuntimeError};
 use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
 use radix_engine_interface::api::types::RENodeId;
 use radix_engine_interface::core::NetworkDefinition;
 use radix_engine_interface::data::*;
 use radix_engine_interface::model::FromPublicKey;
+use radix_engine_interface::rule;
 use scrypto::resource::DIVISIBILITY_MAXIMUM;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
+use transaction::model::Instruction;
 use utils::ContextualDisplay;
 
 #[test]
@@ -390,6 +393,117 @@ fn cant_move_locked_bucket() {
     });
 }
 
+#[test]
+fn bucket_proof_is_valid_while_bucket_is_held() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address =
+        test_runner.create_fungible_resource(100u32.into(), DIVISIBILITY_MAXIMUM, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .call_function_with_abi(
+            package_address,
+            "BucketProof",
+            "check_bucket_proof_is_valid",
+            vec![format!(
+                "1,{}",
+                resource_address.display(&Bech32Encoder::for_simulator())
+            )],
+            Some(account),
+            &test_runner.export_abi(package_address, "BucketProof"),
+        )
+        .unwrap()
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn cant_make_bucket_proof_stale_by_burning_locked_bucket() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .call_function(
+            package_address,
+            "BucketProof",
+            "burn_bucket_while_locked",
+            args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::NodeMoveError(
+                NodeMoveError::CantMoveDownstream(RENodeId::Bucket(..))
+            ))
+        )
+    });
+}
+
+#[test]
+fn vault_proof_is_valid_after_withdrawing_liquid_resource() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address =
+        test_runner.create_fungible_resource(100u32.into(), DIVISIBILITY_MAXIMUM, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+    let component_address = test_runner.instantiate_component(
+        package_address,
+        "VaultProof",
+        "new",
+        vec![format!(
+            "100,{}",
+            resource_address.display(&Bech32Encoder::for_simulator())
+        )],
+        account,
+        public_key,
+    );
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .call_method(
+            component_address,
+            "check_vault_proof_is_valid_after_withdrawal",
+            args!(Decimal::one()),
+        )
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
 #[test]
 fn can_compose_bucket_and_vault_proof() {
     // Arrange
@@ -608,3 +722,121 @@ fn can_create_auth_zone_proof_by_amount_from_non_fungibles() {
     // Assert
     receipt.expect_commit_success();
 }
+
+fn create_token_with_synthetic_proof_rule(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    synthetic_proof_rule: Option<AccessRule>,
+) -> ResourceAddress {
+    let mut access_rules = HashMap::new();
+    access_rules.insert(
+        ResourceMethodAuthKey::Withdraw,
+        (rule!(allow_all), rule!(deny_all)),
+    );
+    access_rules.insert(
+        ResourceMethodAuthKey::Deposit,
+        (rule!(allow_all), rule!(deny_all)),
+    );
+    if let Some(rule) = synthetic_proof_rule {
+        access_rules.insert(
+            ResourceMethodAuthKey::CreateSyntheticProof,
+            (rule, rule!(deny_all)),
+        );
+    }
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .create_resource(
+            ResourceType::Fungible { divisibility: 18 },
+            BTreeMap::new(),
+            access_rules,
+            None,
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+    receipt
+        .expect_commit()
+        .entity_changes
+        .new_resource_addresses[0]
+}
+
+fn withdraw_with_synthetic_proof(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    account: ComponentAddress,
+    token: ResourceAddress,
+    amount: Decimal,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10u32.into())
+        .add_instruction(Instruction::CallNativeMethod {
+            method_ident: NativeMethodIdent {
+                receiver: RENodeId::Global(GlobalAddress::Resource(token)),
+                method_name: "create_synthetic_proof".to_string(),
+            },
+            args: scrypto_encode(&ResourceManagerCreateSyntheticProofInvocation {
+                receiver: token,
+                amount,
+            })
+            .unwrap(),
+        })
+        .0
+        .withdraw_from_account_by_amount(account, Decimal::one(), RADIX_TOKEN)
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+#[test]
+fn synthetic_proof_should_pass_amount_of_rule() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let token = create_token_with_synthetic_proof_rule(&mut test_runner, Some(rule!(allow_all)));
+    let account =
+        test_runner.new_account_with_auth_rule(&rule!(require_amount(Decimal::from(100), token)));
+
+    // Act
+    let receipt = withdraw_with_synthetic_proof(&mut test_runner, account, token, 100.into());
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn synthetic_proof_of_insufficient_amount_should_fail_amount_of_rule() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let token = create_token_with_synthetic_proof_rule(&mut test_runner, Some(rule!(allow_all)));
+    let account =
+        test_runner.new_account_with_auth_rule(&rule!(require_amount(Decimal::from(100), token)));
+
+    // Act
+    let receipt = withdraw_with_synthetic_proof(&mut test_runner, account, token, 99.into());
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(e, RuntimeError::ModuleError(ModuleError::AuthError { .. }))
+    });
+}
+
+#[test]
+fn cannot_create_synthetic_proof_by_default() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let token = create_token_with_synthetic_proof_rule(&mut test_runner, None);
+    let account =
+        test_runner.new_account_with_auth_rule(&rule!(require_amount(Decimal::from(100), token)));
+
+    // Act
+    let receipt = withdraw_with_synthetic_proof(&mut test_runner, account, token, 100.into());
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(e, RuntimeError::ModuleError(ModuleError::AuthError { .. }))
+    });
+}
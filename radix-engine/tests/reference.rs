@@ -0,0 +1,66 @@
+use radix_engine::engine::{KernelError, RuntimeError};
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::types::*;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::data::*;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+#[test]
+fn returning_a_reference_not_visible_to_the_callee_should_fail() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/reference");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "ReferenceReturner",
+            "return_fabricated_address",
+            args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e.eq(&RuntimeError::KernelError(
+            KernelError::InvalidReferenceReturn(GlobalAddress::Component(
+                ComponentAddress::Normal([7u8; 26]),
+            )),
+        ))
+    });
+}
+
+#[test]
+fn returning_a_reference_created_by_the_callee_should_succeed() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/reference");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(
+            package_address,
+            "ReferenceReturner",
+            "create_and_return",
+            args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let component_address: ComponentAddress = receipt.output(1);
+    assert_eq!(
+        receipt
+            .expect_commit()
+            .entity_changes
+            .new_component_addresses,
+        vec![component_address]
+    );
+}
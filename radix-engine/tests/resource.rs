@@ -1,12 +1,15 @@
 use radix_engine::engine::{ApplicationError, RuntimeError};
 use radix_engine::ledger::TypedInMemorySubstateStore;
-use radix_engine::model::ResourceManagerError;
+use radix_engine::model::{ResourceManagerError, ResourceOperationError};
+use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
 use radix_engine_interface::core::NetworkDefinition;
 use radix_engine_interface::data::*;
 use radix_engine_interface::model::FromPublicKey;
+use radix_engine_interface::rule;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
+use transaction::manifest::compile;
 
 #[test]
 fn test_set_mintable_with_self_resource_address() {
@@ -70,6 +73,94 @@ fn test_resource_manager() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn test_resource_manager_summary() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/resource");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "ResourceTest", "query_summary", args!())
+        .call_function(
+            package_address,
+            "ResourceTest",
+            "mint_and_read_total_supply",
+            args!(),
+        )
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let (_, summary): (Bucket, ResourceManagerSummary) = receipt.output(1);
+    assert_eq!(
+        summary.resource_type,
+        ResourceType::Fungible { divisibility: 18 }
+    );
+    assert_eq!(summary.total_supply, Decimal::zero());
+    assert_eq!(summary.metadata.get("name"), Some(&"TestToken".to_owned()));
+    assert!(summary.mintable);
+    assert!(summary.burnable);
+}
+
+#[test]
+fn repeated_resource_manager_reads_should_be_cached() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/resource");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "ResourceTest", "create_fungible", args!())
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+    let resource_address = receipt
+        .expect_commit()
+        .entity_changes
+        .new_resource_addresses[1];
+
+    // Act
+    let mut native_costs = Vec::new();
+    for times in [1u32, 10u32] {
+        let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+            .lock_fee(FAUCET_COMPONENT, 10.into())
+            .call_function(
+                package_address,
+                "ResourceTest",
+                "read_total_supply",
+                args!(resource_address, times),
+            )
+            .build();
+        let receipt = test_runner.execute_manifest(manifest, vec![]);
+        receipt.expect_commit_success();
+        native_costs
+            .push(receipt.execution.fee_summary.execution_cost_unit_breakdown["run_native_method"]);
+    }
+
+    // Assert
+    assert_eq!(native_costs[0], native_costs[1]);
+}
+
 #[test]
 fn mint_with_bad_granularity_should_fail() {
     // Arrange
@@ -101,10 +192,15 @@ fn mint_with_bad_granularity_should_fail() {
     // Assert
     receipt.expect_specific_failure(|e| {
         if let RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(
-            ResourceManagerError::InvalidAmount(amount, granularity),
+            ResourceManagerError::ResourceOperationError(
+                ResourceOperationError::InvalidAmountPrecision {
+                    amount,
+                    divisibility,
+                },
+            ),
         )) = e
         {
-            amount.eq(&Decimal::from("0.1")) && *granularity == 0
+            amount.eq(&Decimal::from("0.1")) && *divisibility == 0
         } else {
             false
         }
@@ -149,3 +245,637 @@ fn mint_too_much_should_fail() {
         )
     })
 }
+
+fn mint_fungible(amount: Decimal) -> TransactionReceipt {
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_mintable_fungible_resource(100.into(), 2, account);
+
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .mint(resource_address, amount)
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    )
+}
+
+#[test]
+fn mint_with_excess_precision_should_fail() {
+    // Act
+    let receipt = mint_fungible(dec!("0.001"));
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ResourceManagerError(
+                ResourceManagerError::ResourceOperationError(
+                    ResourceOperationError::InvalidAmountPrecision {
+                        divisibility: 2,
+                        ..
+                    }
+                )
+            ))
+        )
+    });
+}
+
+#[test]
+fn mint_with_allowed_precision_should_succeed() {
+    // Act
+    let receipt = mint_fungible(dec!("0.01"));
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+fn create_fungible_resource_tracking_holders(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    track_holders: bool,
+    account: ComponentAddress,
+) -> ResourceAddress {
+    let mut access_rules = HashMap::new();
+    access_rules.insert(
+        ResourceMethodAuthKey::Withdraw,
+        (rule!(allow_all), rule!(deny_all)),
+    );
+    access_rules.insert(
+        ResourceMethodAuthKey::Deposit,
+        (rule!(allow_all), rule!(deny_all)),
+    );
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_native_function(
+            RESOURCE_MANAGER_BLUEPRINT,
+            &ResourceManagerFunction::Create.to_string(),
+            scrypto_encode(&ResourceManagerCreateInvocation {
+                resource_type: ResourceType::Fungible { divisibility: 18 },
+                metadata: BTreeMap::new(),
+                access_rules,
+                mint_params: Some(MintParams::fungible(100)),
+                non_fungible_data_schema: None,
+                track_holders,
+            })
+            .unwrap(),
+        )
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+    receipt
+        .expect_commit()
+        .entity_changes
+        .new_resource_addresses[0]
+}
+
+fn transfer(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    from: (EcdsaSecp256k1PublicKey, ComponentAddress),
+    to: ComponentAddress,
+    amount: Decimal,
+    resource_address: ResourceAddress,
+) {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(from.1, amount, resource_address)
+        .call_method(to, "deposit_batch", args!(Expression::entire_worktop()))
+        .build();
+    let receipt =
+        test_runner.execute_manifest(manifest, vec![NonFungibleAddress::from_public_key(&from.0)]);
+    receipt.expect_commit_success();
+}
+
+fn get_holder_vault_count(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    resource_address: ResourceAddress,
+) -> Option<u64> {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_native_method(
+            RENodeId::Global(GlobalAddress::Resource(resource_address)),
+            &ResourceManagerMethod::GetHolderVaultCount.to_string(),
+            scrypto_encode(&ResourceManagerGetHolderVaultCountInvocation {
+                receiver: resource_address,
+            })
+            .unwrap(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.output(1)
+}
+
+#[test]
+fn holder_vault_count_should_follow_vaults_emptying_and_refilling() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key1, _, account1) = test_runner.new_allocated_account();
+    let (public_key2, _, account2) = test_runner.new_allocated_account();
+    let resource_address =
+        create_fungible_resource_tracking_holders(&mut test_runner, true, account1);
+    assert_eq!(
+        get_holder_vault_count(&mut test_runner, resource_address),
+        Some(1)
+    );
+
+    // Act & Assert
+    transfer(
+        &mut test_runner,
+        (public_key1, account1),
+        account2,
+        dec!("40"),
+        resource_address,
+    );
+    assert_eq!(
+        get_holder_vault_count(&mut test_runner, resource_address),
+        Some(2)
+    );
+
+    transfer(
+        &mut test_runner,
+        (public_key1, account1),
+        account2,
+        dec!("60"),
+        resource_address,
+    );
+    assert_eq!(
+        get_holder_vault_count(&mut test_runner, resource_address),
+        Some(1)
+    );
+
+    transfer(
+        &mut test_runner,
+        (public_key2, account2),
+        account1,
+        dec!("10"),
+        resource_address,
+    );
+    assert_eq!(
+        get_holder_vault_count(&mut test_runner, resource_address),
+        Some(2)
+    );
+}
+
+#[test]
+fn holder_vault_count_should_be_none_when_not_tracked() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key1, _, account1) = test_runner.new_allocated_account();
+    let (_, _, account2) = test_runner.new_allocated_account();
+    let resource_address =
+        create_fungible_resource_tracking_holders(&mut test_runner, false, account1);
+
+    // Act
+    transfer(
+        &mut test_runner,
+        (public_key1, account1),
+        account2,
+        dec!("100"),
+        resource_address,
+    );
+
+    // Assert
+    assert_eq!(
+        get_holder_vault_count(&mut test_runner, resource_address),
+        None
+    );
+}
+
+#[test]
+fn resource_metadata_should_be_set_on_creation() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+    let mut metadata = BTreeMap::new();
+    metadata.insert("name".to_owned(), "TestToken".to_owned());
+    metadata.insert("symbol".to_owned(), "TT".to_owned());
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .new_token_fixed(metadata.clone(), 100.into())
+        .deposit_entire_worktop(account)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let resource_address = receipt
+        .expect_commit()
+        .entity_changes
+        .new_resource_addresses[0];
+    assert_eq!(
+        test_runner.get_metadata(GlobalAddress::Resource(resource_address)),
+        metadata
+    );
+}
+
+fn create_resource_natively(metadata: BTreeMap<String, String>) -> TransactionReceipt {
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_native_function(
+            RESOURCE_MANAGER_BLUEPRINT,
+            &ResourceManagerFunction::Create.to_string(),
+            scrypto_encode(&ResourceManagerCreateInvocation {
+                resource_type: ResourceType::Fungible { divisibility: 18 },
+                metadata,
+                access_rules: HashMap::new(),
+                mint_params: None,
+                non_fungible_data_schema: None,
+                track_holders: false,
+            })
+            .unwrap(),
+        )
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+fn create_resource_from_manifest_string(metadata: BTreeMap<String, String>) -> TransactionReceipt {
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let entries: Vec<String> = metadata
+        .iter()
+        .map(|(key, value)| format!("Tuple(\"{}\", \"{}\")", key, value))
+        .collect();
+    let manifest_string = format!(
+        r#"
+            CALL_METHOD ComponentAddress("{}") "lock_fee" Decimal("10");
+            CREATE_RESOURCE Enum("Fungible", 18u8) Array<Tuple>({}) Array<Tuple>() None;
+        "#,
+        Bech32Encoder::for_simulator().encode_component_address_to_string(&FAUCET_COMPONENT),
+        entries.join(", ")
+    );
+    let manifest = compile(&manifest_string, &NetworkDefinition::simulator(), vec![]).unwrap();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+fn assert_resource_creation<F>(metadata: BTreeMap<String, String>, assert: F)
+where
+    F: Fn(TransactionReceipt),
+{
+    assert(create_resource_natively(metadata.clone()));
+    assert(create_resource_from_manifest_string(metadata));
+}
+
+fn is_invalid_metadata(error: &RuntimeError, key: &str, reason: InvalidMetadataReason) -> bool {
+    error.eq(&RuntimeError::ApplicationError(
+        ApplicationError::ResourceManagerError(ResourceManagerError::InvalidMetadata {
+            key: key.to_owned(),
+            reason,
+        }),
+    ))
+}
+
+#[test]
+fn create_resource_with_well_formed_metadata_should_succeed() {
+    // Arrange
+    let mut metadata = BTreeMap::new();
+    metadata.insert("name".to_owned(), "Test Token".to_owned());
+    metadata.insert("symbol".to_owned(), "TT".to_owned());
+    metadata.insert(
+        "icon_url".to_owned(),
+        "https://example.com/icon.png".to_owned(),
+    );
+
+    // Act & Assert
+    assert_resource_creation(metadata, |receipt| {
+        receipt.expect_commit_success();
+    });
+}
+
+#[test]
+fn create_resource_with_over_long_metadata_value_should_fail() {
+    // Arrange
+    let mut metadata = BTreeMap::new();
+    metadata.insert(
+        "description".to_owned(),
+        "a".repeat(MAX_RESOURCE_METADATA_VALUE_LENGTH + 1),
+    );
+
+    // Act & Assert
+    assert_resource_creation(metadata, |receipt| {
+        receipt.expect_specific_failure(|e| {
+            is_invalid_metadata(
+                e,
+                "description",
+                InvalidMetadataReason::ValueTooLong {
+                    length: MAX_RESOURCE_METADATA_VALUE_LENGTH + 1,
+                    limit: MAX_RESOURCE_METADATA_VALUE_LENGTH,
+                },
+            )
+        });
+    });
+}
+
+#[test]
+fn create_resource_with_invalid_symbol_should_fail() {
+    // Arrange
+    let mut metadata = BTreeMap::new();
+    metadata.insert("symbol".to_owned(), "tt".to_owned());
+
+    // Act & Assert
+    assert_resource_creation(metadata, |receipt| {
+        receipt.expect_specific_failure(|e| {
+            is_invalid_metadata(e, "symbol", InvalidMetadataReason::InvalidSymbol)
+        });
+    });
+}
+
+#[test]
+fn create_resource_with_too_many_metadata_entries_should_fail() {
+    // Arrange
+    let metadata: BTreeMap<String, String> = (0..=MAX_RESOURCE_METADATA_ENTRIES)
+        .map(|i| (format!("key{:03}", i), "value".to_owned()))
+        .collect();
+
+    // Act & Assert
+    assert_resource_creation(metadata, |receipt| {
+        receipt.expect_specific_failure(|e| {
+            is_invalid_metadata(
+                e,
+                &format!("key{:03}", MAX_RESOURCE_METADATA_ENTRIES),
+                InvalidMetadataReason::TooManyEntries {
+                    limit: MAX_RESOURCE_METADATA_ENTRIES,
+                },
+            )
+        });
+    });
+}
+
+fn create_fungible_from_template(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    function_name: &str,
+    args: Vec<u8>,
+    account: ComponentAddress,
+) -> ResourceAddress {
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/resource");
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_function(package_address, "ResourceTest", function_name, args)
+        .deposit_entire_worktop(account)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+    receipt
+        .expect_commit()
+        .entity_changes
+        .new_resource_addresses[0]
+}
+
+fn mint_with_optional_badge(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    resource_address: ResourceAddress,
+    badge: Option<ResourceAddress>,
+    (public_key, account): (EcdsaSecp256k1PublicKey, ComponentAddress),
+) -> TransactionReceipt {
+    let mut builder = ManifestBuilder::new(&NetworkDefinition::simulator());
+    builder.lock_fee(FAUCET_COMPONENT, 10.into());
+    if let Some(badge) = badge {
+        builder.create_proof_from_account(account, badge);
+    }
+    let manifest = builder
+        .mint(resource_address, 10.into())
+        .deposit_entire_worktop(account)
+        .build();
+    test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    )
+}
+
+#[test]
+fn standard_ownable_template_should_only_allow_owner_to_mint() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let owner_badge = test_runner.create_fungible_resource(1.into(), 0, account);
+    let resource_address = create_fungible_from_template(
+        &mut test_runner,
+        "create_ownable_fungible",
+        args!(owner_badge),
+        account,
+    );
+
+    // Act
+    let receipt_without_badge = mint_with_optional_badge(
+        &mut test_runner,
+        resource_address,
+        None,
+        (public_key, account),
+    );
+    let receipt_with_badge = mint_with_optional_badge(
+        &mut test_runner,
+        resource_address,
+        Some(owner_badge),
+        (public_key, account),
+    );
+
+    // Assert
+    receipt_without_badge.expect_specific_failure(is_auth_error);
+    receipt_with_badge.expect_commit_success();
+}
+
+#[test]
+fn standard_mintable_by_admin_template_should_only_allow_admin_to_mint() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let admin_badge = test_runner.create_fungible_resource(1.into(), 0, account);
+    let resource_address = create_fungible_from_template(
+        &mut test_runner,
+        "create_fungible_mintable_by_admin",
+        args!(admin_badge),
+        account,
+    );
+
+    // Act
+    let receipt_without_badge = mint_with_optional_badge(
+        &mut test_runner,
+        resource_address,
+        None,
+        (public_key, account),
+    );
+    let receipt_with_badge = mint_with_optional_badge(
+        &mut test_runner,
+        resource_address,
+        Some(admin_badge),
+        (public_key, account),
+    );
+
+    // Assert
+    receipt_without_badge.expect_specific_failure(is_auth_error);
+    receipt_with_badge.expect_commit_success();
+}
+
+#[test]
+fn standard_burnable_by_holder_template_should_allow_holder_to_burn() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_fungible_from_template(
+        &mut test_runner,
+        "create_fungible_burnable_by_holder",
+        args!(),
+        account,
+    );
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(account, 10.into(), resource_address)
+        .burn(resource_address, 10.into())
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn standard_soulbound_template_should_deny_withdraw() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = create_fungible_from_template(
+        &mut test_runner,
+        "create_soulbound_fungible",
+        args!(),
+        account,
+    );
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(account, 10.into(), resource_address)
+        .deposit_entire_worktop(account)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
+fn get_amount_minted_and_burned(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    resource_address: ResourceAddress,
+) -> (Decimal, Decimal) {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_native_method(
+            RENodeId::Global(GlobalAddress::Resource(resource_address)),
+            &ResourceManagerMethod::GetAmountMinted.to_string(),
+            scrypto_encode(&ResourceManagerGetAmountMintedInvocation {
+                receiver: resource_address,
+            })
+            .unwrap(),
+        )
+        .call_native_method(
+            RENodeId::Global(GlobalAddress::Resource(resource_address)),
+            &ResourceManagerMethod::GetAmountBurned.to_string(),
+            scrypto_encode(&ResourceManagerGetAmountBurnedInvocation {
+                receiver: resource_address,
+            })
+            .unwrap(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    (receipt.output(1), receipt.output(2))
+}
+
+#[test]
+fn mint_and_burn_should_update_cumulative_counters() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+    let mut access_rules = HashMap::new();
+    access_rules.insert(
+        ResourceMethodAuthKey::Withdraw,
+        (rule!(allow_all), rule!(deny_all)),
+    );
+    access_rules.insert(
+        ResourceMethodAuthKey::Deposit,
+        (rule!(allow_all), rule!(deny_all)),
+    );
+    access_rules.insert(
+        ResourceMethodAuthKey::Mint,
+        (rule!(allow_all), rule!(deny_all)),
+    );
+    access_rules.insert(
+        ResourceMethodAuthKey::Burn,
+        (rule!(allow_all), rule!(deny_all)),
+    );
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .create_resource(
+            ResourceType::Fungible { divisibility: 18 },
+            BTreeMap::new(),
+            access_rules,
+            None,
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let resource_address = receipt
+        .expect_commit()
+        .entity_changes
+        .new_resource_addresses[0];
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .mint(resource_address, dec!("100"))
+        .burn(resource_address, dec!("30"))
+        .deposit_entire_worktop(account)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(test_runner.get_total_supply(resource_address), dec!("70"));
+    assert_eq!(
+        get_amount_minted_and_burned(&mut test_runner, resource_address),
+        (dec!("100"), dec!("30"))
+    );
+}
+
+#[test]
+fn initial_supply_should_count_as_minted() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let resource_address = test_runner.create_fungible_resource(dec!("100"), 18, account);
+
+    // Assert
+    assert_eq!(
+        get_amount_minted_and_burned(&mut test_runner, resource_address),
+        (dec!("100"), Decimal::zero())
+    );
+}
@@ -0,0 +1,99 @@
+use radix_engine::ledger::{
+    state_root_hash, verify_state_root, ReadableSubstateStore, TypedInMemorySubstateStore,
+    WriteableSubstateStore,
+};
+use radix_engine::types::*;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::data::*;
+use radix_engine_interface::model::FromPublicKey;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+/// Creates an account and mints a token into it, returning the state root hash of the last receipt.
+fn create_account_and_token(store: &mut TypedInMemorySubstateStore, supply: Decimal) -> Hash {
+    let mut test_runner = TestRunner::new_deterministic(false, store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(account, 10.into())
+        .new_token_fixed(BTreeMap::new(), supply)
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    receipt
+        .expect_commit()
+        .state_root_hash
+        .expect("Committed receipt should carry a state root hash")
+}
+
+#[test]
+fn replaying_the_same_transactions_produces_identical_state_roots() {
+    // Arrange
+    let mut store1 = TypedInMemorySubstateStore::with_bootstrap();
+    let mut store2 = TypedInMemorySubstateStore::with_bootstrap();
+    assert_eq!(state_root_hash(&store1), state_root_hash(&store2));
+
+    // Act
+    let receipt_root1 = create_account_and_token(&mut store1, 100.into());
+    let receipt_root2 = create_account_and_token(&mut store2, 100.into());
+
+    // Assert
+    assert_eq!(receipt_root1, receipt_root2);
+    assert_eq!(receipt_root1, state_root_hash(&store1));
+    assert_eq!(receipt_root2, state_root_hash(&store2));
+}
+
+#[test]
+fn divergent_write_changes_state_root() {
+    // Arrange
+    let mut store1 = TypedInMemorySubstateStore::with_bootstrap();
+    let mut store2 = TypedInMemorySubstateStore::with_bootstrap();
+
+    // Act
+    let receipt_root1 = create_account_and_token(&mut store1, 100.into());
+    let receipt_root2 = create_account_and_token(&mut store2, 200.into());
+
+    // Assert
+    assert_ne!(receipt_root1, receipt_root2);
+}
+
+#[test]
+fn recomputed_state_root_matches_incremental_state_root() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    assert_eq!(verify_state_root(&store), Ok(state_root_hash(&store)));
+
+    // Act
+    let receipt_root = create_account_and_token(&mut store, 100.into());
+
+    // Assert
+    assert_eq!(verify_state_root(&store), Ok(receipt_root));
+}
+
+#[test]
+fn write_bypassing_state_tree_fails_verification() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let substate_id = SubstateId(
+        RENodeId::Global(GlobalAddress::Resource(RADIX_TOKEN)),
+        SubstateOffset::Global(GlobalOffset::Global),
+    );
+    let mut output_value = store.get_substate(&substate_id).unwrap();
+    output_value.version += 1;
+
+    // Act
+    store.put_substate(substate_id, output_value);
+
+    // Assert
+    let mismatch = verify_state_root(&store).unwrap_err();
+    assert_eq!(mismatch.stored, state_root_hash(&store));
+    assert_ne!(mismatch.stored, mismatch.recomputed);
+}
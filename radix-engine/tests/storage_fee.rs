@@ -0,0 +1,100 @@
+use radix_engine::engine::{CostingError, ModuleError, RejectionError, RuntimeError};
+use radix_engine::fee::FeeReserveError;
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig, TransactionReceipt};
+use radix_engine::types::*;
+use radix_engine_constants::DEFAULT_COST_UNIT_LIMIT;
+use radix_engine_interface::core::NetworkDefinition;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+use transaction::model::{TestTransaction, TransactionManifest};
+
+fn execute_with_storage_fees(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    manifest: TransactionManifest,
+    cost_unit_limit: u32,
+    charge_storage_fees: bool,
+) -> TransactionReceipt {
+    let transaction = TestTransaction::new(manifest, 1, cost_unit_limit);
+    test_runner.execute_transaction_with_config(
+        &transaction.get_executable(vec![]),
+        &FeeReserveConfig::default(),
+        &ExecutionConfig {
+            charge_storage_fees,
+            ..ExecutionConfig::default()
+        },
+    )
+}
+
+fn create_token_manifest(account: ComponentAddress) -> TransactionManifest {
+    ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .new_token_fixed(BTreeMap::new(), 100.into())
+        .deposit_entire_worktop(account)
+        .build()
+}
+
+#[test]
+fn creating_nodes_with_storage_fees_should_cost_more() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let receipt_without = execute_with_storage_fees(
+        &mut test_runner,
+        create_token_manifest(account),
+        DEFAULT_COST_UNIT_LIMIT,
+        false,
+    );
+    let receipt_with = execute_with_storage_fees(
+        &mut test_runner,
+        create_token_manifest(account),
+        DEFAULT_COST_UNIT_LIMIT,
+        true,
+    );
+
+    // Assert
+    receipt_without.expect_commit_success();
+    receipt_with.expect_commit_success();
+    assert!(
+        receipt_with.execution.fee_summary.cost_unit_consumed
+            > receipt_without.execution.fee_summary.cost_unit_consumed
+    );
+}
+
+#[test]
+fn storage_fees_should_be_charged_against_cost_unit_limit() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+    let receipt = execute_with_storage_fees(
+        &mut test_runner,
+        create_token_manifest(account),
+        DEFAULT_COST_UNIT_LIMIT,
+        false,
+    );
+    let cost_unit_consumed = receipt.execution.fee_summary.cost_unit_consumed;
+
+    // Act
+    let receipt = execute_with_storage_fees(
+        &mut test_runner,
+        create_token_manifest(account),
+        cost_unit_consumed,
+        true,
+    );
+
+    // Assert
+    receipt.expect_specific_rejection(|e| {
+        matches!(
+            e,
+            RejectionError::ErrorBeforeFeeLoanRepaid(RuntimeError::ModuleError(
+                ModuleError::CostingError(CostingError::FeeReserveError(
+                    FeeReserveError::LimitExceeded
+                ))
+            ))
+        )
+    });
+}
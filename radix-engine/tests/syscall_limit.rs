@@ -0,0 +1,69 @@
+use radix_engine::engine::{KernelError, RuntimeError};
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig, TransactionReceipt};
+use radix_engine::types::*;
+use radix_engine_constants::DEFAULT_COST_UNIT_LIMIT;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::data::*;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+use transaction::model::TestTransaction;
+
+fn call_balance_repeatedly(
+    call_count: usize,
+    execution_config: &ExecutionConfig,
+) -> TransactionReceipt {
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+
+    let mut builder = ManifestBuilder::new(&NetworkDefinition::simulator());
+    builder.lock_fee(account, 10.into());
+    for _ in 0..call_count {
+        builder.call_method(account, "balance", args!(RADIX_TOKEN));
+    }
+    let transaction = TestTransaction::new(
+        builder.build(),
+        test_runner.next_transaction_nonce(),
+        DEFAULT_COST_UNIT_LIMIT,
+    );
+
+    test_runner.execute_transaction_with_config(
+        &transaction.get_executable(vec![NonFungibleAddress::from_public_key(&public_key)]),
+        &FeeReserveConfig::default(),
+        execution_config,
+    )
+}
+
+#[test]
+fn syscall_count_should_be_reported_in_receipt() {
+    // Act
+    let small = call_balance_repeatedly(1, &ExecutionConfig::default());
+    let large = call_balance_repeatedly(10, &ExecutionConfig::default());
+
+    // Assert
+    small.expect_commit_success();
+    large.expect_commit_success();
+    assert!(small.syscall_count() > 0);
+    assert!(large.syscall_count() > small.syscall_count());
+}
+
+#[test]
+fn exceeding_syscall_limit_should_fail() {
+    // Arrange
+    let execution_config = ExecutionConfig {
+        max_syscalls_per_transaction: 50,
+        ..ExecutionConfig::default()
+    };
+
+    // Act
+    let receipt = call_balance_repeatedly(100, &execution_config);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::KernelError(KernelError::SyscallLimitExceeded { count: 50 })
+        )
+    });
+}
@@ -0,0 +1,235 @@
+use radix_engine::engine::{ApplicationError, AuthError, ModuleError, RuntimeError};
+use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::model::TimeLockedVaultError;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::crypto::EcdsaSecp256k1PublicKey;
+use radix_engine_interface::rule;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+
+const UNLOCK_EPOCH: u64 = 10;
+
+struct TimeLockedVaultFixture {
+    depositor: (EcdsaSecp256k1PublicKey, ComponentAddress),
+    beneficiary: (EcdsaSecp256k1PublicKey, ComponentAddress),
+    resource_address: ResourceAddress,
+    time_locked_vault: SystemAddress,
+}
+
+/// Creates a time-locked vault for a fresh beneficiary account and deposits 30 tokens into it.
+fn create_funded_time_locked_vault(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+) -> TimeLockedVaultFixture {
+    let (depositor_key, _, depositor_account) = test_runner.new_allocated_account();
+    let (beneficiary_key, _, beneficiary_account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(100.into(), 18, depositor_account);
+
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .create_time_locked_vault(
+            resource_address,
+            rule!(require(NonFungibleAddress::from_public_key(
+                &beneficiary_key
+            ))),
+            rule!(require(NonFungibleAddress::from_public_key(&depositor_key))),
+            UNLOCK_EPOCH,
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+    let time_locked_vault = receipt.expect_commit().entity_changes.new_system_addresses[0];
+
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(depositor_account, 30.into(), resource_address)
+        .deposit_to_time_locked_vault(time_locked_vault, resource_address)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&depositor_key)],
+    );
+    receipt.expect_commit_success();
+
+    TimeLockedVaultFixture {
+        depositor: (depositor_key, depositor_account),
+        beneficiary: (beneficiary_key, beneficiary_account),
+        resource_address,
+        time_locked_vault,
+    }
+}
+
+fn withdraw(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    fixture: &TimeLockedVaultFixture,
+    signer: EcdsaSecp256k1PublicKey,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_time_locked_vault(fixture.time_locked_vault, 30.into())
+        .deposit_entire_worktop(fixture.beneficiary.1)
+        .build();
+    test_runner.execute_manifest(manifest, vec![NonFungibleAddress::from_public_key(&signer)])
+}
+
+fn extend_lock(
+    test_runner: &mut TestRunner<TypedInMemorySubstateStore>,
+    fixture: &TimeLockedVaultFixture,
+    signer: EcdsaSecp256k1PublicKey,
+    unlock_epoch: u64,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .extend_time_locked_vault_lock(fixture.time_locked_vault, unlock_epoch)
+        .build();
+    test_runner.execute_manifest(manifest, vec![NonFungibleAddress::from_public_key(&signer)])
+}
+
+#[test]
+fn withdraw_before_unlock_epoch_should_fail() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let fixture = create_funded_time_locked_vault(&mut test_runner);
+    test_runner.set_current_epoch(UNLOCK_EPOCH - 1);
+
+    // Act
+    let receipt = withdraw(&mut test_runner, &fixture, fixture.beneficiary.0);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::TimeLockedVaultError(
+                TimeLockedVaultError::VaultLocked {
+                    unlock_epoch: UNLOCK_EPOCH,
+                    current_epoch,
+                }
+            )) if *current_epoch == UNLOCK_EPOCH - 1
+        )
+    });
+}
+
+#[test]
+fn withdraw_at_unlock_epoch_should_succeed() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let fixture = create_funded_time_locked_vault(&mut test_runner);
+    test_runner.set_current_epoch(UNLOCK_EPOCH);
+
+    // Act
+    let receipt = withdraw(&mut test_runner, &fixture, fixture.beneficiary.0);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner
+            .get_component_resources(fixture.beneficiary.1)
+            .get(&fixture.resource_address),
+        Some(&Decimal::from(30))
+    );
+}
+
+#[test]
+fn withdraw_without_beneficiary_auth_should_fail() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let fixture = create_funded_time_locked_vault(&mut test_runner);
+    test_runner.set_current_epoch(UNLOCK_EPOCH);
+
+    // Act
+    let receipt = withdraw(&mut test_runner, &fixture, fixture.depositor.0);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized { .. }))
+        )
+    });
+}
+
+#[test]
+fn extend_lock_should_delay_withdrawal() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let fixture = create_funded_time_locked_vault(&mut test_runner);
+
+    // Act
+    let receipt = extend_lock(
+        &mut test_runner,
+        &fixture,
+        fixture.depositor.0,
+        UNLOCK_EPOCH + 5,
+    );
+    receipt.expect_commit_success();
+    test_runner.set_current_epoch(UNLOCK_EPOCH);
+    let receipt = withdraw(&mut test_runner, &fixture, fixture.beneficiary.0);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::TimeLockedVaultError(
+                TimeLockedVaultError::VaultLocked { unlock_epoch, .. }
+            )) if *unlock_epoch == UNLOCK_EPOCH + 5
+        )
+    });
+}
+
+#[test]
+fn extend_lock_to_earlier_epoch_should_fail() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let fixture = create_funded_time_locked_vault(&mut test_runner);
+
+    // Act
+    let receipt = extend_lock(
+        &mut test_runner,
+        &fixture,
+        fixture.depositor.0,
+        UNLOCK_EPOCH - 1,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::TimeLockedVaultError(
+                TimeLockedVaultError::LockCannotBeShortened {
+                    unlock_epoch: UNLOCK_EPOCH,
+                    requested_epoch,
+                }
+            )) if *requested_epoch == UNLOCK_EPOCH - 1
+        )
+    });
+}
+
+#[test]
+fn extend_lock_without_depositor_auth_should_fail() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let fixture = create_funded_time_locked_vault(&mut test_runner);
+
+    // Act
+    let receipt = extend_lock(
+        &mut test_runner,
+        &fixture,
+        fixture.beneficiary.0,
+        UNLOCK_EPOCH + 5,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ModuleError(ModuleError::AuthError(AuthError::Unauthorized { .. }))
+        )
+    });
+}
@@ -95,3 +95,73 @@ fn batched_executions_should_result_in_the_same_result() {
     assert_eq!(store2, store3);
     assert_eq!(store3, store4);
 }
+
+fn new_token_txn(account: ComponentAddress) -> TransactionManifest {
+    ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(account, 10.into())
+        .new_token_fixed(BTreeMap::new(), 100.into())
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build()
+}
+
+#[test]
+fn atomic_batch_with_failed_transaction_should_not_commit_anything() {
+    // Arrange
+    let mut store0 = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner0 = TestRunner::new(true, &mut store0);
+    let (public_key, _, account) = test_runner0.new_allocated_account();
+    let signers = vec![NonFungibleAddress::from_public_key(&public_key)];
+    let manifests = vec![
+        (new_token_txn(account), signers.clone()),
+        (new_token_txn(account), signers.clone()),
+        (
+            self_transfer_txn(account, Decimal::from(1_000_000_000u32)),
+            signers.clone(),
+        ),
+    ];
+    let mut store1 = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner1 = TestRunner::new(true, &mut store1);
+    let _ = test_runner1.new_allocated_account();
+
+    // Act
+    let batch_receipt = test_runner0.execute_atomic_batch(manifests, DEFAULT_COST_UNIT_LIMIT);
+
+    // Assert
+    assert!(!batch_receipt.is_committed());
+    batch_receipt.expect_failure_at(2).expect_commit_failure();
+    assert_eq!(batch_receipt.receipts.len(), 3);
+    assert_eq!(batch_receipt.receipts[0].new_resource_addresses().len(), 1);
+    assert_eq!(test_runner0.get_component_resources(account).len(), 1);
+    assert_eq!(store0, store1);
+}
+
+#[test]
+fn atomic_batch_with_successful_transactions_should_commit_all() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let signers = vec![NonFungibleAddress::from_public_key(&public_key)];
+    let manifests = vec![
+        (new_token_txn(account), signers.clone()),
+        (new_token_txn(account), signers.clone()),
+        (self_transfer_txn(account, Decimal::from(1u32)), signers),
+    ];
+
+    // Act
+    let batch_receipt = test_runner.execute_atomic_batch(manifests, DEFAULT_COST_UNIT_LIMIT);
+
+    // Assert
+    let receipts = batch_receipt.expect_committed();
+    assert_eq!(receipts.len(), 3);
+    let resources = test_runner.get_component_resources(account);
+    assert_eq!(resources.len(), 3);
+    for receipt in &receipts[0..2] {
+        let resource_address = receipt.new_resource_addresses()[0];
+        assert_eq!(resources[&resource_address], Decimal::from(100u32));
+    }
+}
@@ -1,7 +1,9 @@
+use radix_engine::engine::ApplicationError;
 use radix_engine::engine::KernelError;
 use radix_engine::engine::RejectionError;
 use radix_engine::engine::RuntimeError;
 use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::model::TransactionProcessorError;
 use radix_engine::types::*;
 use radix_engine_interface::core::NetworkDefinition;
 use radix_engine_interface::data::*;
@@ -132,7 +134,7 @@ fn test_non_existent_blob_hash() {
                 code: Blob(Hash([0; 32])),
                 abi: Blob(Hash([0; 32])),
                 royalty_config: HashMap::new(),
-                metadata: HashMap::new(),
+                metadata: BTreeMap::new(),
                 access_rules: AccessRules::new()
                     .default(AccessRule::AllowAll, AccessRule::AllowAll),
             })
@@ -208,3 +210,303 @@ fn test_faucet_drain_attempt_should_fail() {
     // Assert
     receipt.expect_commit_failure();
 }
+
+#[test]
+fn test_call_method_with_return_passes_returned_bucket_by_name() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, dec!("10"))
+        .call_method_with_return(
+            account,
+            "withdraw_by_amount",
+            args!(dec!("5"), RADIX_TOKEN),
+            |builder, bucket_id| {
+                builder.call_method(other_account, "deposit", args!(Bucket(bucket_id)))
+            },
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_resources(other_account)[&RADIX_TOKEN],
+        dec!("1005")
+    );
+}
+
+#[test]
+fn test_call_method_with_return_fails_if_no_bucket_is_returned() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, dec!("10"))
+        .call_method_with_return(
+            account,
+            "balance",
+            args!(RADIX_TOKEN),
+            |builder, bucket_id| builder.return_to_worktop(bucket_id),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e.cause(),
+            RuntimeError::ApplicationError(ApplicationError::TransactionProcessorError(
+                TransactionProcessorError::InvalidReturnedBucketCount(0)
+            ))
+        )
+    });
+}
+
+#[test]
+fn test_call_method_with_proof_return_passes_returned_proof_by_name() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!("100"), 18, account);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/proof");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, dec!("10"))
+        .create_proof_from_account(account, RADIX_TOKEN)
+        .create_proof_from_account_by_amount_returning(
+            account,
+            dec!("5"),
+            resource_address,
+            |builder, proof_id| {
+                builder.call_function(
+                    package_address,
+                    "Receiver",
+                    "assert_amount",
+                    args!(Proof(proof_id), dec!("5"), resource_address),
+                )
+            },
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_call_method_with_proof_return_fails_if_no_proof_is_returned() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, dec!("10"))
+        .call_method_with_proof_return(
+            account,
+            "balance",
+            args!(RADIX_TOKEN),
+            |builder, proof_id| builder.drop_proof(proof_id),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e.cause(),
+            RuntimeError::ApplicationError(ApplicationError::TransactionProcessorError(
+                TransactionProcessorError::InvalidReturnedProofCount(0)
+            ))
+        )
+    });
+}
+
+#[test]
+fn test_deposit_entire_worktop_into_account() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, dec!("10"))
+        .call_method(FAUCET_COMPONENT, "free", args!())
+        .deposit_entire_worktop(account)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_resources(account)[&RADIX_TOKEN],
+        dec!("2000")
+    );
+}
+
+#[test]
+fn test_deposit_entire_worktop_into_non_account_fails() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, dec!("10"))
+        .call_method(FAUCET_COMPONENT, "free", args!())
+        .deposit_entire_worktop(FAUCET_COMPONENT)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e.cause(),
+            RuntimeError::ApplicationError(ApplicationError::TransactionProcessorError(
+                TransactionProcessorError::NotAnAccount(address)
+            )) if *address == FAUCET_COMPONENT
+        )
+    });
+}
+
+#[test]
+fn test_deposit_batch_of_entire_worktop_still_succeeds() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, dec!("10"))
+        .call_method(FAUCET_COMPONENT, "free", args!())
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_resources(account)[&RADIX_TOKEN],
+        dec!("2000")
+    );
+}
+
+#[test]
+fn test_local_variables_are_passed_to_later_instructions() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, dec!("10"))
+        .set_local("amount", &dec!("5"))
+        .set_local("xrd", &RADIX_TOKEN)
+        .call_method(
+            account,
+            "withdraw_by_amount",
+            args!(Expression::local("amount"), Expression::local("xrd")),
+        )
+        .set_local("amount", &dec!("2"))
+        .call_method(
+            account,
+            "withdraw_by_amount",
+            args!(Expression::local("amount"), Expression::local("xrd")),
+        )
+        .deposit_entire_worktop(other_account)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_resources(other_account)[&RADIX_TOKEN],
+        dec!("1007")
+    );
+}
+
+#[test]
+fn test_unset_local_variable_fails() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, dec!("10"))
+        .call_method(account, "balance", args!(Expression::local("xrd")))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e.cause(),
+            RuntimeError::ApplicationError(ApplicationError::TransactionProcessorError(
+                TransactionProcessorError::LocalVariableNotFound(name)
+            )) if name == "xrd"
+        )
+    });
+}
+
+#[test]
+fn test_local_variables_are_not_kept_across_transactions() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (_, _, account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, dec!("10"))
+        .set_local("xrd", &RADIX_TOKEN)
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, dec!("10"))
+        .call_method(account, "balance", args!(Expression::local("xrd")))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e.cause(),
+            RuntimeError::ApplicationError(ApplicationError::TransactionProcessorError(
+                TransactionProcessorError::LocalVariableNotFound(_)
+            ))
+        )
+    });
+}
@@ -6,13 +6,17 @@ use radix_engine::transaction::{ExecutionConfig, FeeReserveConfig};
 use radix_engine::types::*;
 use radix_engine::wasm::WasmInstrumenter;
 use radix_engine::wasm::{DefaultWasmEngine, InstructionCostRules, WasmMeteringConfig};
-use radix_engine_constants::DEFAULT_COST_UNIT_LIMIT;
+use radix_engine_constants::{DEFAULT_COST_UNIT_LIMIT, DEFAULT_MAX_INSTRUCTIONS_PER_TRANSACTION};
 use radix_engine_interface::core::NetworkDefinition;
 use scrypto_unit::*;
+use std::sync::Arc;
+use std::thread;
 use transaction::builder::ManifestBuilder;
 use transaction::builder::TransactionBuilder;
 use transaction::errors::{HeaderValidationError, TransactionValidationError};
-use transaction::model::{Executable, NotarizedTransaction, TransactionHeader};
+use transaction::model::{
+    Executable, NotarizedTransaction, TransactionHeader, TransactionManifest,
+};
 use transaction::signing::EcdsaSecp256k1PrivateKey;
 use transaction::validation::{
     NotarizedTransactionValidator, TestIntentHashManager, TransactionValidator, ValidationConfig,
@@ -39,6 +43,60 @@ fn low_cost_unit_limit_should_result_in_rejection() {
     );
 }
 
+#[test]
+fn transaction_with_max_instructions_should_be_valid() {
+    // Arrange
+    let transaction =
+        create_transaction_with_instruction_count(DEFAULT_MAX_INSTRUCTIONS_PER_TRANSACTION);
+
+    // Act
+    let result = get_executable(&transaction);
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[test]
+fn transaction_with_too_many_instructions_should_be_rejected() {
+    // Arrange
+    let transaction =
+        create_transaction_with_instruction_count(DEFAULT_MAX_INSTRUCTIONS_PER_TRANSACTION + 1);
+
+    // Act
+    let result = get_executable(&transaction);
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(TransactionValidationError::TooManyInstructions {
+            count: DEFAULT_MAX_INSTRUCTIONS_PER_TRANSACTION + 1,
+            limit: DEFAULT_MAX_INSTRUCTIONS_PER_TRANSACTION,
+        })
+    );
+}
+
+#[test]
+fn instruction_limit_should_be_configurable() {
+    // Arrange
+    let validator = NotarizedTransactionValidator::new(ValidationConfig {
+        max_instructions_per_transaction: 3,
+        ..ValidationConfig::simulator()
+    });
+    let at_limit = create_transaction_with_instruction_count(3);
+    let over_limit = create_transaction_with_instruction_count(4);
+
+    // Act
+    let at_limit_result = validator.validate(&at_limit, &TestIntentHashManager::new());
+    let over_limit_result = validator.validate(&over_limit, &TestIntentHashManager::new());
+
+    // Assert
+    assert!(at_limit_result.is_ok());
+    assert_eq!(
+        over_limit_result,
+        Err(TransactionValidationError::TooManyInstructions { count: 4, limit: 3 })
+    );
+}
+
 #[test]
 fn transaction_executed_before_valid_returns_that_rejection_reason() {
     // Arrange
@@ -160,6 +218,40 @@ fn test_normal_transaction_flow() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn test_runners_sharing_an_interpreter_can_execute_concurrently() {
+    // Arrange
+    let scrypto_interpreter = Arc::new(new_scrypto_interpreter());
+
+    // Act
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let scrypto_interpreter = scrypto_interpreter.clone();
+            thread::spawn(move || {
+                let mut store = TypedInMemorySubstateStore::with_bootstrap();
+                let mut test_runner = TestRunner::new_with_scrypto_interpreter(
+                    false,
+                    false,
+                    &mut store,
+                    scrypto_interpreter,
+                );
+                let (_, _, account) = test_runner.new_allocated_account();
+                for _ in 0..20 {
+                    test_runner.load_account_from_faucet(account);
+                }
+                test_runner.get_component_resources(account)[&RADIX_TOKEN]
+            })
+        })
+        .collect();
+    let balances: Vec<Decimal> = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect();
+
+    // Assert
+    assert!(balances.iter().all(|balance| *balance == balances[0]));
+}
+
 fn get_executable<'a>(
     transaction: &'a NotarizedTransaction,
 ) -> Result<Executable<'a>, TransactionValidationError> {
@@ -175,6 +267,19 @@ struct TransactionParams {
 }
 
 fn create_notarized_transaction(params: TransactionParams) -> NotarizedTransaction {
+    create_notarized_transaction_with_manifest(
+        params,
+        ManifestBuilder::new(&NetworkDefinition::simulator())
+            .lock_fee(FAUCET_COMPONENT, 10.into())
+            .clear_auth_zone()
+            .build(),
+    )
+}
+
+fn create_notarized_transaction_with_manifest(
+    params: TransactionParams,
+    manifest: TransactionManifest,
+) -> NotarizedTransaction {
     // create key pairs
     let sk1 = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap();
     let sk2 = EcdsaSecp256k1PrivateKey::from_u64(2).unwrap();
@@ -192,14 +297,25 @@ fn create_notarized_transaction(params: TransactionParams) -> NotarizedTransacti
             cost_unit_limit: params.cost_unit_limit,
             tip_percentage: 5,
         })
-        .manifest(
-            ManifestBuilder::new(&NetworkDefinition::simulator())
-                .lock_fee(FAUCET_COMPONENT, 10.into())
-                .clear_auth_zone()
-                .build(),
-        )
+        .manifest(manifest)
         .sign(&sk1)
         .sign(&sk2)
         .notarize(&sk_notary)
         .build()
 }
+
+fn create_transaction_with_instruction_count(instruction_count: usize) -> NotarizedTransaction {
+    let mut builder = ManifestBuilder::new(&NetworkDefinition::simulator());
+    builder.lock_fee(FAUCET_COMPONENT, 10.into());
+    for _ in 1..instruction_count {
+        builder.clear_auth_zone();
+    }
+    create_notarized_transaction_with_manifest(
+        TransactionParams {
+            cost_unit_limit: DEFAULT_COST_UNIT_LIMIT,
+            start_epoch_inclusive: 0,
+            end_epoch_exclusive: 10,
+        },
+        builder.build(),
+    )
+}
@@ -1,5 +1,7 @@
-use radix_engine::engine::{CallFrameError, KernelError, RuntimeError};
+use radix_engine::engine::{ApplicationError, CallFrameError, KernelError, RuntimeError};
 use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::model::{ResourceOperationError, VaultError};
+use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
 use radix_engine_interface::api::types::RENodeId;
 use radix_engine_interface::core::NetworkDefinition;
@@ -456,3 +458,70 @@ fn create_mutable_vault_with_get_resource_manager() {
     // Assert
     receipt.expect_commit_success();
 }
+
+fn withdraw_from_vault_by_amount(amount: Decimal) -> TransactionReceipt {
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(100.into(), 2, account);
+
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(account, amount, resource_address)
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    )
+}
+
+#[test]
+fn take_from_vault_with_excess_precision_should_fail() {
+    // Act
+    let receipt = withdraw_from_vault_by_amount(dec!("0.001"));
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::VaultError(
+                VaultError::ResourceOperationError(
+                    ResourceOperationError::InvalidAmountPrecision {
+                        divisibility: 2,
+                        ..
+                    }
+                )
+            ))
+        )
+    });
+}
+
+#[test]
+fn take_from_vault_with_allowed_precision_should_succeed() {
+    // Act
+    let receipt = withdraw_from_vault_by_amount(dec!("0.01"));
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn vault_error_from_nested_call_should_surface_unchanged() {
+    // Act
+    let receipt = withdraw_from_vault_by_amount(dec!("1000"));
+
+    // Assert
+    let error = receipt.expect_commit_failure();
+    assert_eq!(error.failed_instruction_index(), Some(1));
+    assert_eq!(
+        error.cause(),
+        &RuntimeError::ApplicationError(ApplicationError::VaultError(
+            VaultError::ResourceOperationError(ResourceOperationError::InsufficientBalance)
+        ))
+    );
+}
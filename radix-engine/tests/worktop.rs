@@ -1,12 +1,16 @@
 use radix_engine::engine::KernelError;
-use radix_engine::engine::RuntimeError;
+use radix_engine::engine::{ApplicationError, RuntimeError};
 use radix_engine::ledger::TypedInMemorySubstateStore;
+use radix_engine::model::{ResourceOperationError, TransactionProcessorError, WorktopError};
+use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
 use radix_engine_interface::api::types::RENodeId;
 use radix_engine_interface::core::NetworkDefinition;
+use radix_engine_interface::data::*;
 use radix_engine_interface::model::FromPublicKey;
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
+use transaction::model::{Instruction, WorktopCondition};
 
 #[test]
 fn test_worktop_resource_leak() {
@@ -33,3 +37,410 @@ fn test_worktop_resource_leak() {
         )
     });
 }
+
+fn take_from_worktop_by_amount(amount: Decimal) -> TransactionReceipt {
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(100.into(), 2, account);
+
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(account, 1.into(), resource_address)
+        .take_from_worktop_by_amount(amount, resource_address, |builder, bucket_id| {
+            builder.call_method(account, "deposit", args!(Bucket(bucket_id)))
+        })
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    )
+}
+
+#[test]
+fn take_from_worktop_with_excess_precision_should_fail() {
+    // Act
+    let receipt = take_from_worktop_by_amount(dec!("0.001"));
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::WorktopError(
+                WorktopError::ResourceOperationError(
+                    ResourceOperationError::InvalidAmountPrecision {
+                        divisibility: 2,
+                        ..
+                    }
+                )
+            ))
+        )
+    });
+}
+
+#[test]
+fn take_from_worktop_with_allowed_precision_should_succeed() {
+    // Act
+    let receipt = take_from_worktop_by_amount(dec!("0.01"));
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+fn take_from_worktop_or_default(
+    withdraw_amount: Option<Decimal>,
+    take_amount: Decimal,
+) -> (TransactionReceipt, Decimal) {
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(100.into(), 18, account);
+
+    let mut builder = ManifestBuilder::new(&NetworkDefinition::simulator());
+    builder.lock_fee(FAUCET_COMPONENT, 10.into());
+    if let Some(amount) = withdraw_amount {
+        builder.withdraw_from_account_by_amount(account, amount, resource_address);
+    }
+    let manifest = builder
+        .take_from_worktop_or_default(take_amount, resource_address, |builder, bucket_id| {
+            builder.call_method(other_account, "deposit", args!(Bucket(bucket_id)))
+        })
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+    let balance = test_runner
+        .get_component_resources(other_account)
+        .get(&resource_address)
+        .cloned()
+        .unwrap_or_default();
+    (receipt, balance)
+}
+
+#[test]
+fn take_from_worktop_or_default_with_present_resource_should_take_amount() {
+    // Act
+    let (receipt, balance) = take_from_worktop_or_default(Some(10.into()), 4.into());
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(balance, 4.into());
+}
+
+#[test]
+fn take_from_worktop_or_default_with_absent_resource_should_return_empty_bucket() {
+    // Act
+    let (receipt, balance) = take_from_worktop_or_default(None, 4.into());
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(balance, Decimal::zero());
+}
+
+#[test]
+fn take_from_worktop_or_default_with_insufficient_resource_should_fail() {
+    // Act
+    let (receipt, _) = take_from_worktop_or_default(Some(2.into()), 4.into());
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::WorktopError(
+                WorktopError::ResourceOperationError(ResourceOperationError::InsufficientBalance)
+            ))
+        )
+    });
+}
+
+fn assert_worktop_contains(
+    exactly: bool,
+    withdraw_amount: Decimal,
+    assert_amount: Decimal,
+) -> (TransactionReceipt, ResourceAddress) {
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(100.into(), 18, account);
+
+    let mut builder = ManifestBuilder::new(&NetworkDefinition::simulator());
+    builder
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(account, withdraw_amount, resource_address);
+    if exactly {
+        builder.assert_worktop_contains_exactly(assert_amount, resource_address);
+    } else {
+        builder.assert_worktop_contains_by_amount(assert_amount, resource_address);
+    }
+    let manifest = builder
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+    (receipt, resource_address)
+}
+
+#[test]
+fn assert_worktop_contains_by_amount_with_less_should_fail() {
+    // Act
+    let (receipt, _) = assert_worktop_contains(false, 4.into(), 5.into());
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::WorktopError(
+                WorktopError::AssertionFailed
+            ))
+        )
+    });
+}
+
+#[test]
+fn assert_worktop_contains_by_amount_with_equal_should_succeed() {
+    // Act
+    let (receipt, _) = assert_worktop_contains(false, 5.into(), 5.into());
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn assert_worktop_contains_by_amount_with_more_should_succeed() {
+    // Act
+    let (receipt, _) = assert_worktop_contains(false, 6.into(), 5.into());
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn assert_worktop_contains_exactly_with_less_should_fail() {
+    // Act
+    let (receipt, resource_address) = assert_worktop_contains(true, 4.into(), 5.into());
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e.eq(&RuntimeError::ApplicationError(
+            ApplicationError::WorktopError(WorktopError::ExactAmountAssertionFailed {
+                resource_address,
+                expected: 5.into(),
+                actual: 4.into(),
+            }),
+        ))
+    });
+}
+
+#[test]
+fn assert_worktop_contains_exactly_with_equal_should_succeed() {
+    // Act
+    let (receipt, _) = assert_worktop_contains(true, 5.into(), 5.into());
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn assert_worktop_contains_exactly_with_more_should_fail() {
+    // Act
+    let (receipt, resource_address) = assert_worktop_contains(true, 6.into(), 5.into());
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e.eq(&RuntimeError::ApplicationError(
+            ApplicationError::WorktopError(WorktopError::ExactAmountAssertionFailed {
+                resource_address,
+                expected: 5.into(),
+                actual: 6.into(),
+            }),
+        ))
+    });
+}
+
+fn conditional_transfer(
+    required_amount: Decimal,
+) -> (
+    TransactionReceipt,
+    HashMap<ResourceAddress, Decimal>,
+    HashMap<ResourceAddress, Decimal>,
+    ResourceAddress,
+) {
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(100.into(), 18, account);
+
+    // Send the withdrawn tokens to the other account if there are enough of them
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(account, 5.into(), resource_address)
+        .if_worktop_contains(
+            WorktopCondition::ContainsByAmount {
+                amount: required_amount,
+                resource_address,
+            },
+            |builder| {
+                builder.take_from_worktop(resource_address, |builder, bucket_id| {
+                    builder.call_method(other_account, "deposit", args!(Bucket(bucket_id)))
+                })
+            },
+            |builder| {
+                builder.take_from_worktop(resource_address, |builder, bucket_id| {
+                    builder.call_method(account, "deposit", args!(Bucket(bucket_id)))
+                })
+            },
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+    let balances = test_runner.get_component_resources(account);
+    let other_balances = test_runner.get_component_resources(other_account);
+    (receipt, balances, other_balances, resource_address)
+}
+
+#[test]
+fn conditional_block_runs_then_branch_if_worktop_contains_enough() {
+    // Act
+    let (receipt, balances, other_balances, resource_address) = conditional_transfer(5.into());
+
+    // Assert
+    assert_eq!(receipt.output::<bool>(2), true);
+    assert_eq!(balances[&resource_address], 95.into());
+    assert_eq!(other_balances[&resource_address], 5.into());
+}
+
+#[test]
+fn conditional_block_runs_else_branch_if_worktop_contains_too_little() {
+    // Act
+    let (receipt, balances, other_balances, resource_address) = conditional_transfer(6.into());
+
+    // Assert
+    assert_eq!(receipt.output::<bool>(2), false);
+    assert_eq!(balances[&resource_address], 100.into());
+    assert!(!other_balances.contains_key(&resource_address));
+}
+
+#[test]
+fn bucket_taken_in_conditional_block_is_returned_to_worktop_at_its_end() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(100.into(), 18, account);
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .withdraw_from_account_by_amount(account, 5.into(), resource_address)
+        .if_worktop_contains(
+            WorktopCondition::Contains { resource_address },
+            |builder| builder.take_from_worktop(resource_address, |builder, _| builder),
+            |builder| builder,
+        )
+        .call_method(
+            account,
+            "deposit_batch",
+            args!(Expression::entire_worktop()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleAddress::from_public_key(&public_key)],
+    );
+
+    // Assert
+    assert_eq!(receipt.output::<bool>(2), true);
+    assert_eq!(
+        test_runner.get_component_resources(account)[&resource_address],
+        100.into()
+    );
+}
+
+#[test]
+fn nested_conditional_block_should_fail() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let condition = WorktopCondition::Contains {
+        resource_address: RADIX_TOKEN,
+    };
+    // The worktop holds XRD, so the outer block runs its then branch
+    let mut manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(FAUCET_COMPONENT, "free", args!())
+        .build();
+    manifest.instructions.push(Instruction::IfWorktopContains {
+        condition: condition.clone(),
+        then_instructions: vec![Instruction::IfWorktopContains {
+            condition,
+            then_instructions: vec![],
+            else_instructions: vec![],
+        }],
+        else_instructions: vec![],
+    });
+
+    // Act
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e.cause(),
+            RuntimeError::ApplicationError(ApplicationError::TransactionProcessorError(
+                TransactionProcessorError::NestedConditionalBlock
+            ))
+        )
+    });
+}
+
+#[test]
+fn component_should_not_be_able_to_take_from_worktop() {
+    // Arrange
+    let mut store = TypedInMemorySubstateStore::with_bootstrap();
+    let mut test_runner = TestRunner::new(true, &mut store);
+    let package_address = test_runner.compile_and_publish("./tests/blueprints/worktop");
+
+    // Act
+    let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+        .lock_fee(FAUCET_COMPONENT, 10.into())
+        .call_method(FAUCET_COMPONENT, "free", args!())
+        .call_function(
+            package_address,
+            "WorktopThief",
+            "steal_from_worktop",
+            args!(RADIX_TOKEN),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e.cause(),
+            RuntimeError::ApplicationError(ApplicationError::WorktopError(
+                WorktopError::InvalidCaller
+            ))
+        )
+    });
+}
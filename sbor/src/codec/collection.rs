@@ -35,6 +35,10 @@ impl<X: CustomTypeId, E: Encoder<X>, T: Encode<X, E> + TypeId<X>> Encode<X, E> f
     }
 }
 
+/// Encodes the elements in sorted order, so that the encoding matches that of a `BTreeSet` with
+/// the same elements, regardless of the iteration order of the set.
+///
+/// Prefer `BTreeSet`, which avoids sorting on every encode.
 impl<X: CustomTypeId, E: Encoder<X>, T: Encode<X, E> + TypeId<X> + Ord + Hash> Encode<X, E>
     for HashSet<T>
 {
@@ -47,7 +51,8 @@ impl<X: CustomTypeId, E: Encoder<X>, T: Encode<X, E> + TypeId<X> + Ord + Hash> E
     fn encode_body(&self, encoder: &mut E) -> Result<(), EncodeError> {
         encoder.write_type_id(T::type_id())?;
         encoder.write_size(self.len())?;
-        for v in self {
+        let values: BTreeSet<&T> = self.iter().collect();
+        for v in values {
             encoder.encode_deeper_body(v)?;
         }
         Ok(())
@@ -93,6 +98,10 @@ impl<X: CustomTypeId, E: Encoder<X>, K: Encode<X, E>, V: Encode<X, E>> Encode<X,
     }
 }
 
+/// Encodes the entries in key order, so that the encoding matches that of a `BTreeMap` with
+/// the same entries, regardless of the iteration order of the map.
+///
+/// Prefer `BTreeMap`, which avoids sorting on every encode.
 impl<X: CustomTypeId, E: Encoder<X>, K: Encode<X, E> + Ord + Hash, V: Encode<X, E>> Encode<X, E>
     for HashMap<K, V>
 {
@@ -3,6 +3,7 @@ use crate::rust::borrow::ToOwned;
 use crate::rust::boxed::Box;
 use crate::rust::cell::RefCell;
 use crate::rust::rc::Rc;
+use crate::rust::sync::Arc;
 use crate::type_id::*;
 use crate::*;
 
@@ -44,6 +45,11 @@ impl<X: CustomTypeId, E: Encoder<X>, T: Encode<X, E>> Encode<X, E> for Box<T> {
     }
 }
 
+/// Encodes the inner value, the same as `T` itself.
+///
+/// Sharing is not preserved: each `Rc` pointing to the same value is encoded as a separate copy,
+/// and there's no cycle detection, so encoding a reference cycle (e.g. through `Rc<RefCell<T>>`)
+/// never terminates.
 impl<X: CustomTypeId, E: Encoder<X>, T: Encode<X, E>> Encode<X, E> for Rc<T> {
     #[inline]
     fn encode_type_id(&self, encoder: &mut E) -> Result<(), EncodeError> {
@@ -56,6 +62,21 @@ impl<X: CustomTypeId, E: Encoder<X>, T: Encode<X, E>> Encode<X, E> for Rc<T> {
     }
 }
 
+/// Encodes the inner value, the same as `T` itself.
+///
+/// As with `Rc`, sharing is not preserved and there's no cycle detection.
+impl<X: CustomTypeId, E: Encoder<X>, T: Encode<X, E>> Encode<X, E> for Arc<T> {
+    #[inline]
+    fn encode_type_id(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.as_ref().encode_type_id(encoder)
+    }
+
+    #[inline]
+    fn encode_body(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.as_ref().encode_body(encoder)
+    }
+}
+
 impl<X: CustomTypeId, E: Encoder<X>, T: Encode<X, E>> Encode<X, E> for RefCell<T> {
     #[inline]
     fn encode_type_id(&self, encoder: &mut E) -> Result<(), EncodeError> {
@@ -90,6 +111,7 @@ impl<X: CustomTypeId, D: Decoder<X>, T: Decode<X, D>> Decode<X, D> for Box<T> {
     }
 }
 
+/// Decodes into a fresh `Rc`, so values that were shared before encoding are no longer shared.
 impl<X: CustomTypeId, D: Decoder<X>, T: Decode<X, D>> Decode<X, D> for Rc<T> {
     #[inline]
     fn decode_body_with_type_id(
@@ -100,6 +122,17 @@ impl<X: CustomTypeId, D: Decoder<X>, T: Decode<X, D>> Decode<X, D> for Rc<T> {
     }
 }
 
+/// Decodes into a fresh `Arc`, so values that were shared before encoding are no longer shared.
+impl<X: CustomTypeId, D: Decoder<X>, T: Decode<X, D>> Decode<X, D> for Arc<T> {
+    #[inline]
+    fn decode_body_with_type_id(
+        decoder: &mut D,
+        type_id: SborTypeId<X>,
+    ) -> Result<Self, DecodeError> {
+        Ok(Arc::new(T::decode_body_with_type_id(decoder, type_id)?))
+    }
+}
+
 impl<X: CustomTypeId, D: Decoder<X>, T: Decode<X, D>> Decode<X, D> for RefCell<T> {
     #[inline]
     fn decode_body_with_type_id(
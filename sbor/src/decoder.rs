@@ -389,6 +389,25 @@ mod tests {
         assert_eq!(Rc::new(5u8), x);
     }
 
+    #[test]
+    pub fn test_decode_arc() {
+        let bytes = vec![7u8, 5u8];
+        let mut dec = BasicDecoder::new(&bytes);
+        let x = dec.decode::<crate::rust::sync::Arc<u8>>().unwrap();
+        assert_eq!(crate::rust::sync::Arc::new(5u8), x);
+    }
+
+    #[test]
+    pub fn test_rc_round_trip() {
+        let shared = Rc::new("hello".to_owned());
+        let value = (shared.clone(), shared);
+        let bytes = basic_encode(&value).unwrap();
+        let decoded: (Rc<String>, Rc<String>) = basic_decode(&bytes).unwrap();
+        assert_eq!(decoded, value);
+        // Sharing is not preserved across encoding
+        assert!(!Rc::ptr_eq(&decoded.0, &decoded.1));
+    }
+
     #[test]
     pub fn test_decode_ref_cell() {
         let bytes = vec![7u8, 5u8];
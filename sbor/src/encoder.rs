@@ -165,6 +165,7 @@ mod tests {
     use crate::rust::boxed::Box;
     use crate::rust::collections::*;
     use crate::rust::string::String;
+    use crate::rust::string::ToString;
     use crate::rust::vec;
     use crate::BasicEncoder;
 
@@ -254,6 +255,45 @@ mod tests {
         ));
     }
 
+    #[test]
+    pub fn test_encode_hash_map_and_set_in_sorted_order() {
+        let keys = [5u32, 1, 4, 2, 3];
+
+        let mut hash_map = HashMap::<u32, String>::new();
+        let mut btree_map = BTreeMap::<u32, String>::new();
+        let mut hash_set = HashSet::<u32>::new();
+        let mut btree_set = BTreeSet::<u32>::new();
+        for key in keys {
+            hash_map.insert(key, key.to_string());
+            btree_set.insert(key);
+        }
+        for key in keys.iter().rev() {
+            btree_map.insert(*key, key.to_string());
+            hash_set.insert(*key);
+        }
+
+        let hash_map_bytes = basic_encode(&hash_map).unwrap();
+        let hash_set_bytes = basic_encode(&hash_set).unwrap();
+        assert_eq!(hash_map_bytes, basic_encode(&btree_map).unwrap());
+        assert_eq!(hash_set_bytes, basic_encode(&btree_set).unwrap());
+        assert_eq!(
+            basic_decode::<BTreeMap<u32, String>>(&hash_map_bytes).unwrap(),
+            btree_map
+        );
+        assert_eq!(
+            basic_decode::<HashMap<u32, String>>(&hash_map_bytes).unwrap(),
+            hash_map
+        );
+        assert_eq!(
+            basic_decode::<BTreeSet<u32>>(&hash_set_bytes).unwrap(),
+            btree_set
+        );
+        assert_eq!(
+            basic_decode::<HashSet<u32>>(&hash_set_bytes).unwrap(),
+            hash_set
+        );
+    }
+
     #[test]
     #[cfg(feature = "indexmap")]
     pub fn test_encode_index_map_and_set() {
@@ -325,6 +365,15 @@ mod tests {
         assert_eq!(bytes, vec![7, 5])
     }
 
+    #[test]
+    pub fn test_encode_arc() {
+        let x = crate::rust::sync::Arc::new(5u8);
+        let mut bytes = Vec::with_capacity(512);
+        let mut encoder = BasicEncoder::new(&mut bytes);
+        encoder.encode(&x).unwrap();
+        assert_eq!(bytes, vec![7, 5])
+    }
+
     #[test]
     pub fn test_encode_ref_cell() {
         let x = crate::rust::cell::RefCell::new(5u8);
@@ -7,6 +7,7 @@ use crate::rust::collections::*;
 use crate::rust::fmt::Debug;
 use crate::rust::rc::Rc;
 use crate::rust::string::String;
+use crate::rust::sync::Arc;
 use crate::rust::vec::Vec;
 
 #[cfg_attr(
@@ -216,6 +217,13 @@ impl<X: CustomTypeId, T: TypeId<X>> TypeId<X> for Rc<T> {
     }
 }
 
+impl<X: CustomTypeId, T: TypeId<X>> TypeId<X> for Arc<T> {
+    #[inline]
+    fn type_id() -> SborTypeId<X> {
+        T::type_id()
+    }
+}
+
 impl<X: CustomTypeId, T: TypeId<X>> TypeId<X> for RefCell<T> {
     #[inline]
     fn type_id() -> SborTypeId<X> {
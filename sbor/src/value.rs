@@ -2,7 +2,7 @@ use crate::decode::*;
 use crate::decoder::*;
 use crate::encode::*;
 use crate::encoder::*;
-use crate::path::SborPathBuf;
+use crate::path::{SborPath, SborPathBuf};
 use crate::rust::fmt::Debug;
 use crate::rust::string::String;
 use crate::rust::vec::Vec;
@@ -70,6 +70,17 @@ pub enum SborValue<X: CustomTypeId, Y> {
     },
 }
 
+impl<X: CustomTypeId, Y: Clone> SborValue<X, Y> {
+    /// Returns the sub-value at the given path, or `None` if the path doesn't exist.
+    ///
+    /// Each path index selects a struct/tuple field, an enum field or an array element. Maps are
+    /// encoded as arrays of `(key, value)` tuples, so a map entry is addressed by its index in the
+    /// (key-ordered) encoding, followed by `0` for the key or `1` for the value.
+    pub fn path_get(&self, path: &SborPath) -> Option<SborValue<X, Y>> {
+        path.get_from_value(self).cloned()
+    }
+}
+
 impl<X: CustomTypeId, E: Encoder<X>, Y: Encode<X, E>> Encode<X, E> for SborValue<X, Y> {
     #[inline]
     fn encode_type_id(&self, encoder: &mut E) -> Result<(), EncodeError> {
@@ -507,6 +518,48 @@ mod tests {
         assert_eq!(encoded_sbor_value, encoded_typed_value);
     }
 
+    #[test]
+    pub fn test_path_get() {
+        let mut map = BTreeMap::new();
+        map.insert(2u32, TestStruct { x: 20 });
+        map.insert(1, TestStruct { x: 10 });
+        let data = (
+            TestStruct { x: 1 },
+            vec![TestStruct { x: 2 }, TestStruct { x: 3 }],
+            map,
+        );
+        let sbor_value: BasicSborValue = basic_decode(&basic_encode(&data).unwrap()).unwrap();
+
+        // Nested struct
+        assert_eq!(
+            sbor_value.path_get(&SborPath::new(vec![0, 0])),
+            Some(BasicSborValue::U32 { value: 1 })
+        );
+        // Vector element
+        assert_eq!(
+            sbor_value.path_get(&SborPath::new(vec![1, 1])),
+            Some(BasicSborValue::Tuple {
+                fields: vec![BasicSborValue::U32 { value: 3 }]
+            })
+        );
+        // Map key and value
+        assert_eq!(
+            sbor_value.path_get(&SborPath::new(vec![2, 1, 0])),
+            Some(BasicSborValue::U32 { value: 2 })
+        );
+        assert_eq!(
+            sbor_value.path_get(&SborPath::new(vec![2, 1, 1, 0])),
+            Some(BasicSborValue::U32 { value: 20 })
+        );
+        // Root and invalid paths
+        assert_eq!(
+            sbor_value.path_get(&SborPath::new(vec![])),
+            Some(sbor_value.clone())
+        );
+        assert_eq!(sbor_value.path_get(&SborPath::new(vec![1, 2])), None);
+        assert_eq!(sbor_value.path_get(&SborPath::new(vec![0, 0, 0])), None);
+    }
+
     #[test]
     pub fn test_max_depth_array_decode_behaviour() {
         let allowable_payload = encode_array_of_depth(DEFAULT_BASIC_MAX_DEPTH).unwrap();
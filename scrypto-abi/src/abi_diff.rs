@@ -0,0 +1,841 @@
+use sbor::rust::fmt;
+use sbor::rust::format;
+use sbor::rust::string::{String, ToString};
+use sbor::rust::vec::Vec;
+
+use crate::blueprint_abi::*;
+use crate::schema_type::*;
+
+/// The changes between two versions of a blueprint ABI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiDiff {
+    pub changes: Vec<AbiChange>,
+}
+
+impl AbiDiff {
+    /// Whether callers and stored state of the old ABI remain valid with the new ABI.
+    pub fn is_compatible(&self) -> bool {
+        self.changes.iter().all(|change| !change.is_breaking())
+    }
+
+    pub fn breaking_changes(&self) -> Vec<&AbiChange> {
+        self.changes
+            .iter()
+            .filter(|change| change.is_breaking())
+            .collect()
+    }
+}
+
+/// A change to a blueprint ABI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbiChange {
+    FnAdded {
+        ident: String,
+    },
+    /// A function or method was removed, or renamed
+    FnRemoved {
+        ident: String,
+    },
+    /// A function became a method or vice versa, or the mutability of a method changed
+    FnMutabilityChanged {
+        ident: String,
+        old: Option<SelfMutability>,
+        new: Option<SelfMutability>,
+    },
+    FnInputChanged {
+        ident: String,
+        changes: Vec<TypeChange>,
+    },
+    FnOutputChanged {
+        ident: String,
+        changes: Vec<TypeChange>,
+    },
+    /// The type of the component state changed
+    StructureChanged {
+        changes: Vec<TypeChange>,
+    },
+}
+
+impl AbiChange {
+    pub fn is_breaking(&self) -> bool {
+        match self {
+            AbiChange::FnAdded { .. } => false,
+            AbiChange::FnRemoved { .. } => true,
+            AbiChange::FnMutabilityChanged { old, new, .. } => old.is_none() != new.is_none(),
+            // Old arguments and state still decode if the types only gained enum variants
+            AbiChange::FnInputChanged { changes, .. } | AbiChange::StructureChanged { changes } => {
+                !changes
+                    .iter()
+                    .all(|change| matches!(change.kind, TypeChangeKind::VariantAdded { .. }))
+            }
+            // Callers of a function which returned nothing can ignore its new output
+            AbiChange::FnOutputChanged { changes, .. } => !matches!(
+                changes.as_slice(),
+                [TypeChange {
+                    kind: TypeChangeKind::Changed {
+                        old: Type::Unit,
+                        ..
+                    },
+                    ..
+                }]
+            ),
+        }
+    }
+}
+
+/// A change to a type, at a path from the root of the type.
+///
+/// Paths start at `$`, and are extended with `.field` or `.0` for struct and tuple fields,
+/// `::Variant` for enum variants, `[]` for collection elements, `{key}` and `{value}` for map
+/// keys and values, `?` for optional values and `::Ok` or `::Err` for results.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeChange {
+    pub path: String,
+    pub kind: TypeChangeKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeChangeKind {
+    Changed { old: Type, new: Type },
+    FieldAdded { name: String },
+    FieldRemoved { name: String },
+    VariantAdded { name: String },
+    VariantRemoved { name: String },
+}
+
+/// Compares two versions of a blueprint ABI.
+pub fn compare_blueprint_abis(old: &BlueprintAbi, new: &BlueprintAbi) -> AbiDiff {
+    let mut changes = Vec::new();
+
+    let structure_changes = compare_types(&old.structure, &new.structure);
+    if !structure_changes.is_empty() {
+        changes.push(AbiChange::StructureChanged {
+            changes: structure_changes,
+        });
+    }
+
+    for old_fn in &old.fns {
+        let new_fn = match new.get_fn_abi(&old_fn.ident) {
+            Some(new_fn) => new_fn,
+            None => {
+                changes.push(AbiChange::FnRemoved {
+                    ident: old_fn.ident.clone(),
+                });
+                continue;
+            }
+        };
+
+        if old_fn.mutability != new_fn.mutability {
+            changes.push(AbiChange::FnMutabilityChanged {
+                ident: old_fn.ident.clone(),
+                old: old_fn.mutability.clone(),
+                new: new_fn.mutability.clone(),
+            });
+        }
+        let input_changes = compare_types(&old_fn.input, &new_fn.input);
+        if !input_changes.is_empty() {
+            changes.push(AbiChange::FnInputChanged {
+                ident: old_fn.ident.clone(),
+                changes: input_changes,
+            });
+        }
+        let output_changes = compare_types(&old_fn.output, &new_fn.output);
+        if !output_changes.is_empty() {
+            changes.push(AbiChange::FnOutputChanged {
+                ident: old_fn.ident.clone(),
+                changes: output_changes,
+            });
+        }
+    }
+
+    for new_fn in &new.fns {
+        if !old.contains_fn(&new_fn.ident) {
+            changes.push(AbiChange::FnAdded {
+                ident: new_fn.ident.clone(),
+            });
+        }
+    }
+
+    AbiDiff { changes }
+}
+
+/// Compares two versions of a type, returning the changes at the outermost paths at which they
+/// differ.
+pub fn compare_types(old: &Type, new: &Type) -> Vec<TypeChange> {
+    let mut changes = Vec::new();
+    compare_types_at("$", old, new, &mut changes);
+    changes
+}
+
+fn compare_types_at(path: &str, old: &Type, new: &Type, changes: &mut Vec<TypeChange>) {
+    if old == new {
+        return;
+    }
+
+    match (old, new) {
+        (
+            Type::Struct {
+                name: old_name,
+                fields: old_fields,
+            },
+            Type::Struct {
+                name: new_name,
+                fields: new_fields,
+            },
+        ) if old_name == new_name => {
+            compare_fields_at(path, old, new, old_fields, new_fields, changes)
+        }
+        (
+            Type::Enum {
+                name: old_name,
+                variants: old_variants,
+            },
+            Type::Enum {
+                name: new_name,
+                variants: new_variants,
+            },
+        ) if old_name == new_name => {
+            for old_variant in old_variants {
+                match new_variants.iter().find(|v| v.name == old_variant.name) {
+                    Some(new_variant) => compare_fields_at(
+                        &format!("{}::{}", path, old_variant.name),
+                        old,
+                        new,
+                        &old_variant.fields,
+                        &new_variant.fields,
+                        changes,
+                    ),
+                    None => changes.push(TypeChange {
+                        path: path.to_string(),
+                        kind: TypeChangeKind::VariantRemoved {
+                            name: old_variant.name.clone(),
+                        },
+                    }),
+                }
+            }
+            for new_variant in new_variants {
+                if !old_variants.iter().any(|v| v.name == new_variant.name) {
+                    changes.push(TypeChange {
+                        path: path.to_string(),
+                        kind: TypeChangeKind::VariantAdded {
+                            name: new_variant.name.clone(),
+                        },
+                    });
+                }
+            }
+        }
+        (
+            Type::Tuple {
+                element_types: old_types,
+            },
+            Type::Tuple {
+                element_types: new_types,
+            },
+        ) if old_types.len() == new_types.len() => {
+            for (i, (old_type, new_type)) in old_types.iter().zip(new_types).enumerate() {
+                compare_types_at(&format!("{}.{}", path, i), old_type, new_type, changes);
+            }
+        }
+        (
+            Type::Array {
+                element_type: old_type,
+                length: old_length,
+            },
+            Type::Array {
+                element_type: new_type,
+                length: new_length,
+            },
+        ) if old_length == new_length => {
+            compare_types_at(&format!("{}[]", path), old_type, new_type, changes)
+        }
+        (
+            Type::Vec {
+                element_type: old_type,
+            },
+            Type::Vec {
+                element_type: new_type,
+            },
+        )
+        | (
+            Type::TreeSet {
+                element_type: old_type,
+            },
+            Type::TreeSet {
+                element_type: new_type,
+            },
+        )
+        | (
+            Type::HashSet {
+                element_type: old_type,
+            },
+            Type::HashSet {
+                element_type: new_type,
+            },
+        ) => compare_types_at(&format!("{}[]", path), old_type, new_type, changes),
+        (
+            Type::TreeMap {
+                key_type: old_key,
+                value_type: old_value,
+            },
+            Type::TreeMap {
+                key_type: new_key,
+                value_type: new_value,
+            },
+        )
+        | (
+            Type::HashMap {
+                key_type: old_key,
+                value_type: old_value,
+            },
+            Type::HashMap {
+                key_type: new_key,
+                value_type: new_value,
+            },
+        )
+        | (
+            Type::KeyValueStore {
+                key_type: old_key,
+                value_type: old_value,
+            },
+            Type::KeyValueStore {
+                key_type: new_key,
+                value_type: new_value,
+            },
+        ) => {
+            compare_types_at(&format!("{}{{key}}", path), old_key, new_key, changes);
+            compare_types_at(&format!("{}{{value}}", path), old_value, new_value, changes);
+        }
+        (
+            Type::Option {
+                some_type: old_type,
+            },
+            Type::Option {
+                some_type: new_type,
+            },
+        ) => compare_types_at(&format!("{}?", path), old_type, new_type, changes),
+        (
+            Type::Result {
+                okay_type: old_okay,
+                err_type: old_err,
+            },
+            Type::Result {
+                okay_type: new_okay,
+                err_type: new_err,
+            },
+        ) => {
+            compare_types_at(&format!("{}::Ok", path), old_okay, new_okay, changes);
+            compare_types_at(&format!("{}::Err", path), old_err, new_err, changes);
+        }
+        _ => changes.push(TypeChange {
+            path: path.to_string(),
+            kind: TypeChangeKind::Changed {
+                old: old.clone(),
+                new: new.clone(),
+            },
+        }),
+    }
+}
+
+fn compare_fields_at(
+    path: &str,
+    old: &Type,
+    new: &Type,
+    old_fields: &Fields,
+    new_fields: &Fields,
+    changes: &mut Vec<TypeChange>,
+) {
+    match (old_fields, new_fields) {
+        (Fields::Named { named: old_named }, Fields::Named { named: new_named }) => {
+            for (name, old_type) in old_named {
+                match new_named.iter().find(|(n, _)| n == name) {
+                    Some((_, new_type)) => {
+                        compare_types_at(&format!("{}.{}", path, name), old_type, new_type, changes)
+                    }
+                    None => changes.push(TypeChange {
+                        path: path.to_string(),
+                        kind: TypeChangeKind::FieldRemoved { name: name.clone() },
+                    }),
+                }
+            }
+            for (name, _) in new_named {
+                if !old_named.iter().any(|(n, _)| n == name) {
+                    changes.push(TypeChange {
+                        path: path.to_string(),
+                        kind: TypeChangeKind::FieldAdded { name: name.clone() },
+                    });
+                }
+            }
+        }
+        (
+            Fields::Unnamed {
+                unnamed: old_unnamed,
+            },
+            Fields::Unnamed {
+                unnamed: new_unnamed,
+            },
+        ) => {
+            for (i, old_type) in old_unnamed.iter().enumerate() {
+                match new_unnamed.get(i) {
+                    Some(new_type) => {
+                        compare_types_at(&format!("{}.{}", path, i), old_type, new_type, changes)
+                    }
+                    None => changes.push(TypeChange {
+                        path: path.to_string(),
+                        kind: TypeChangeKind::FieldRemoved {
+                            name: i.to_string(),
+                        },
+                    }),
+                }
+            }
+            for i in old_unnamed.len()..new_unnamed.len() {
+                changes.push(TypeChange {
+                    path: path.to_string(),
+                    kind: TypeChangeKind::FieldAdded {
+                        name: i.to_string(),
+                    },
+                });
+            }
+        }
+        (Fields::Unit, Fields::Unit) => {}
+        _ => changes.push(TypeChange {
+            path: path.to_string(),
+            kind: TypeChangeKind::Changed {
+                old: old.clone(),
+                new: new.clone(),
+            },
+        }),
+    }
+}
+
+impl fmt::Display for AbiDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for change in &self.changes {
+            let class = if change.is_breaking() {
+                "breaking"
+            } else {
+                "compatible"
+            };
+            writeln!(f, "[{}] {}", class, change)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for AbiChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AbiChange::FnAdded { ident } => write!(f, "added `{}`", ident),
+            AbiChange::FnRemoved { ident } => write!(f, "removed `{}`", ident),
+            AbiChange::FnMutabilityChanged { ident, old, new } => write!(
+                f,
+                "changed receiver of `{}` from {} to {}",
+                ident,
+                receiver_name(old),
+                receiver_name(new)
+            ),
+            AbiChange::FnInputChanged { ident, changes } => {
+                write!(f, "changed input of `{}`: ", ident)?;
+                write_type_changes(f, changes)
+            }
+            AbiChange::FnOutputChanged { ident, changes } => {
+                write!(f, "changed output of `{}`: ", ident)?;
+                write_type_changes(f, changes)
+            }
+            AbiChange::StructureChanged { changes } => {
+                write!(f, "changed component state: ")?;
+                write_type_changes(f, changes)
+            }
+        }
+    }
+}
+
+impl fmt::Display for TypeChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            TypeChangeKind::Changed { old, new } => write!(
+                f,
+                "{} changed from {} to {}",
+                self.path,
+                type_name(old),
+                type_name(new)
+            ),
+            TypeChangeKind::FieldAdded { name } => {
+                write!(f, "{} gained field `{}`", self.path, name)
+            }
+            TypeChangeKind::FieldRemoved { name } => {
+                write!(f, "{} lost field `{}`", self.path, name)
+            }
+            TypeChangeKind::VariantAdded { name } => {
+                write!(f, "{} gained variant `{}`", self.path, name)
+            }
+            TypeChangeKind::VariantRemoved { name } => {
+                write!(f, "{} lost variant `{}`", self.path, name)
+            }
+        }
+    }
+}
+
+fn write_type_changes(f: &mut fmt::Formatter, changes: &[TypeChange]) -> fmt::Result {
+    for (i, change) in changes.iter().enumerate() {
+        if i > 0 {
+            write!(f, "; ")?;
+        }
+        write!(f, "{}", change)?;
+    }
+    Ok(())
+}
+
+fn receiver_name(mutability: &Option<SelfMutability>) -> &'static str {
+    match mutability {
+        None => "none",
+        Some(SelfMutability::Immutable) => "&self",
+        Some(SelfMutability::Mutable) => "&mut self",
+    }
+}
+
+/// A short, Rust-like name of a type, for reports.
+fn type_name(ty: &Type) -> String {
+    match ty {
+        Type::Unit => "()".to_string(),
+        Type::Bool => "bool".to_string(),
+        Type::I8 => "i8".to_string(),
+        Type::I16 => "i16".to_string(),
+        Type::I32 => "i32".to_string(),
+        Type::I64 => "i64".to_string(),
+        Type::I128 => "i128".to_string(),
+        Type::U8 => "u8".to_string(),
+        Type::U16 => "u16".to_string(),
+        Type::U32 => "u32".to_string(),
+        Type::U64 => "u64".to_string(),
+        Type::U128 => "u128".to_string(),
+        Type::Array {
+            element_type,
+            length,
+        } => format!("[{}; {}]", type_name(element_type), length),
+        Type::Tuple { element_types } => format!(
+            "({})",
+            element_types
+                .iter()
+                .map(type_name)
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        Type::Struct { name, .. } | Type::Enum { name, .. } => name.clone(),
+        Type::Option { some_type } => format!("Option<{}>", type_name(some_type)),
+        Type::Result {
+            okay_type,
+            err_type,
+        } => format!("Result<{}, {}>", type_name(okay_type), type_name(err_type)),
+        Type::Vec { element_type } => format!("Vec<{}>", type_name(element_type)),
+        Type::TreeSet { element_type } => format!("BTreeSet<{}>", type_name(element_type)),
+        Type::HashSet { element_type } => format!("HashSet<{}>", type_name(element_type)),
+        Type::TreeMap {
+            key_type,
+            value_type,
+        } => format!(
+            "BTreeMap<{}, {}>",
+            type_name(key_type),
+            type_name(value_type)
+        ),
+        Type::HashMap {
+            key_type,
+            value_type,
+        } => format!(
+            "HashMap<{}, {}>",
+            type_name(key_type),
+            type_name(value_type)
+        ),
+        Type::KeyValueStore {
+            key_type,
+            value_type,
+        } => format!(
+            "KeyValueStore<{}, {}>",
+            type_name(key_type),
+            type_name(value_type)
+        ),
+        _ => format!("{:?}", ty),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sbor::rust::boxed::Box;
+    use sbor::rust::vec;
+
+    fn struct_type(name: &str, fields: Vec<(&str, Type)>) -> Type {
+        Type::Struct {
+            name: name.to_string(),
+            fields: Fields::Named {
+                named: fields
+                    .into_iter()
+                    .map(|(name, ty)| (name.to_string(), ty))
+                    .collect(),
+            },
+        }
+    }
+
+    fn enum_type(name: &str, variants: &[&str]) -> Type {
+        Type::Enum {
+            name: name.to_string(),
+            variants: variants
+                .iter()
+                .map(|name| Variant {
+                    name: name.to_string(),
+                    fields: Fields::Unit,
+                })
+                .collect(),
+        }
+    }
+
+    fn func(ident: &str, input: Vec<(&str, Type)>, output: Type) -> Fn {
+        Fn {
+            ident: ident.to_string(),
+            mutability: Some(SelfMutability::Mutable),
+            input: struct_type(&format!("Pool_{}_Input", ident), input),
+            output,
+            export_name: format!("Pool_{}", ident),
+        }
+    }
+
+    fn abi(structure: Type, fns: Vec<Fn>) -> BlueprintAbi {
+        BlueprintAbi { structure, fns }
+    }
+
+    fn pool_state() -> Type {
+        struct_type(
+            "Pool",
+            vec![
+                ("fee", struct_type("Fee", vec![("rate", Type::Decimal)])),
+                ("vault", Type::Vault),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_identical_abis_have_no_changes() {
+        let old = abi(pool_state(), vec![func("swap", vec![], Type::Bucket)]);
+
+        let diff = compare_blueprint_abis(&old, &old.clone());
+
+        assert_eq!(diff.changes, vec![]);
+        assert!(diff.is_compatible());
+    }
+
+    #[test]
+    fn test_added_fn_is_compatible() {
+        let old = abi(pool_state(), vec![func("swap", vec![], Type::Bucket)]);
+        let new = abi(
+            pool_state(),
+            vec![
+                func("swap", vec![], Type::Bucket),
+                func("get_fee", vec![], Type::Decimal),
+            ],
+        );
+
+        let diff = compare_blueprint_abis(&old, &new);
+
+        assert_eq!(
+            diff.changes,
+            vec![AbiChange::FnAdded {
+                ident: "get_fee".to_string()
+            }]
+        );
+        assert!(diff.is_compatible());
+    }
+
+    #[test]
+    fn test_removed_fn_is_breaking() {
+        let old = abi(pool_state(), vec![func("swap", vec![], Type::Bucket)]);
+        let new = abi(pool_state(), vec![func("exchange", vec![], Type::Bucket)]);
+
+        let diff = compare_blueprint_abis(&old, &new);
+
+        assert_eq!(
+            diff.changes,
+            vec![
+                AbiChange::FnRemoved {
+                    ident: "swap".to_string()
+                },
+                AbiChange::FnAdded {
+                    ident: "exchange".to_string()
+                }
+            ]
+        );
+        assert_eq!(diff.breaking_changes(), vec![&diff.changes[0]]);
+    }
+
+    #[test]
+    fn test_changed_input_type_is_breaking() {
+        let old = abi(
+            pool_state(),
+            vec![func("swap", vec![("input", Type::Bucket)], Type::Bucket)],
+        );
+        let new = abi(
+            pool_state(),
+            vec![func(
+                "swap",
+                vec![("input", Type::Bucket), ("min_output", Type::Decimal)],
+                Type::Bucket,
+            )],
+        );
+
+        let diff = compare_blueprint_abis(&old, &new);
+
+        assert_eq!(
+            diff.changes,
+            vec![AbiChange::FnInputChanged {
+                ident: "swap".to_string(),
+                changes: vec![TypeChange {
+                    path: "$".to_string(),
+                    kind: TypeChangeKind::FieldAdded {
+                        name: "min_output".to_string()
+                    }
+                }]
+            }]
+        );
+        assert!(!diff.is_compatible());
+    }
+
+    #[test]
+    fn test_output_of_fn_returning_nothing_is_compatible() {
+        let old = abi(pool_state(), vec![func("deposit", vec![], Type::Unit)]);
+        let new = abi(pool_state(), vec![func("deposit", vec![], Type::Decimal)]);
+
+        let diff = compare_blueprint_abis(&old, &new);
+
+        assert_eq!(diff.changes.len(), 1);
+        assert!(diff.is_compatible());
+    }
+
+    #[test]
+    fn test_changed_output_type_is_breaking() {
+        let old = abi(
+            pool_state(),
+            vec![func(
+                "swap",
+                vec![],
+                Type::Vec {
+                    element_type: Box::new(Type::Bucket),
+                },
+            )],
+        );
+        let new = abi(
+            pool_state(),
+            vec![func(
+                "swap",
+                vec![],
+                Type::Vec {
+                    element_type: Box::new(Type::Proof),
+                },
+            )],
+        );
+
+        let diff = compare_blueprint_abis(&old, &new);
+
+        assert_eq!(
+            diff.changes,
+            vec![AbiChange::FnOutputChanged {
+                ident: "swap".to_string(),
+                changes: vec![TypeChange {
+                    path: "$[]".to_string(),
+                    kind: TypeChangeKind::Changed {
+                        old: Type::Bucket,
+                        new: Type::Proof
+                    }
+                }]
+            }]
+        );
+        assert!(!diff.is_compatible());
+    }
+
+    #[test]
+    fn test_changed_mutability() {
+        let old = abi(pool_state(), vec![func("swap", vec![], Type::Bucket)]);
+        let mut immutable = old.clone();
+        immutable.fns[0].mutability = Some(SelfMutability::Immutable);
+        let mut function = old.clone();
+        function.fns[0].mutability = None;
+
+        assert!(compare_blueprint_abis(&old, &immutable).is_compatible());
+        assert!(!compare_blueprint_abis(&old, &function).is_compatible());
+    }
+
+    #[test]
+    fn test_nested_state_field_change_is_breaking() {
+        let old = abi(pool_state(), vec![]);
+        let new = abi(
+            struct_type(
+                "Pool",
+                vec![
+                    ("fee", struct_type("Fee", vec![("rate", Type::U32)])),
+                    ("vault", Type::Vault),
+                ],
+            ),
+            vec![],
+        );
+
+        let diff = compare_blueprint_abis(&old, &new);
+
+        assert_eq!(
+            diff.changes,
+            vec![AbiChange::StructureChanged {
+                changes: vec![TypeChange {
+                    path: "$.fee.rate".to_string(),
+                    kind: TypeChangeKind::Changed {
+                        old: Type::Decimal,
+                        new: Type::U32
+                    }
+                }]
+            }]
+        );
+        assert!(!diff.is_compatible());
+        assert_eq!(
+            diff.to_string(),
+            "[breaking] changed component state: $.fee.rate changed from Decimal to u32\n"
+        );
+    }
+
+    #[test]
+    fn test_enum_variant_addition() {
+        let old_side = enum_type("Side", &["Buy", "Sell"]);
+        let new_side = enum_type("Side", &["Buy", "Sell", "Both"]);
+        let old = abi(
+            pool_state(),
+            vec![
+                func("trade", vec![("side", old_side.clone())], Type::Unit),
+                func("last_side", vec![], old_side),
+            ],
+        );
+        let new = abi(
+            pool_state(),
+            vec![
+                func("trade", vec![("side", new_side.clone())], Type::Unit),
+                func("last_side", vec![], new_side),
+            ],
+        );
+
+        let diff = compare_blueprint_abis(&old, &new);
+
+        let variant_added = vec![TypeChange {
+            path: "$.side".to_string(),
+            kind: TypeChangeKind::VariantAdded {
+                name: "Both".to_string(),
+            },
+        }];
+        assert_eq!(
+            diff.changes[0],
+            AbiChange::FnInputChanged {
+                ident: "trade".to_string(),
+                changes: variant_added,
+            }
+        );
+        assert!(!diff.changes[0].is_breaking());
+        // Callers may not be able to handle the new variant
+        assert!(diff.changes[1].is_breaking());
+        assert_eq!(
+            diff.changes[1].to_string(),
+            "changed output of `last_side`: $ gained variant `Both`"
+        );
+    }
+}
@@ -5,8 +5,10 @@ compile_error!("Either feature `std` or `alloc` must be enabled for this crate."
 #[cfg(all(feature = "std", feature = "alloc"))]
 compile_error!("Feature `std` and `alloc` can't be enabled at the same time.");
 
+mod abi_diff;
 mod blueprint_abi;
 mod schema_type;
 
+pub use abi_diff::*;
 pub use blueprint_abi::*;
 pub use schema_type::*;
@@ -90,6 +90,8 @@ pub enum Type {
         value_type: Box<Type>,
     },
     Bucket,
+    /// A bucket lent to the callee for the duration of a call, encoded as a `Bucket`.
+    BorrowedBucket,
     Proof,
     Vault,
 
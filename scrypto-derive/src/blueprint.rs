@@ -149,7 +149,11 @@ fn generate_method_input_structs(bp_ident: &Ident, items: &[ImplItem]) -> Vec<It
                     FnArg::Typed(ref t) => {
                         let arg_ident = format_ident!("arg{}", index);
                         index += 1;
-                        let arg_type = t.ty.as_ref();
+                        let arg_type: Type = if is_borrowed_bucket(t.ty.as_ref()) {
+                            parse_quote! { ::scrypto::model::BorrowedBucket }
+                        } else {
+                            t.ty.as_ref().clone()
+                        };
                         let arg: Field = Field::parse_named
                             .parse2(quote! {
                                 #arg_ident : #arg_type
@@ -220,12 +224,17 @@ fn generate_dispatcher(
                                 });
                             }
                         }
-                        FnArg::Typed(_) => {
+                        FnArg::Typed(ref t) => {
                             let arg_index = if get_state.is_some() { i - 1 } else { i };
                             let arg = format_ident!("arg{}", arg_index);
 
                             match_args.push(parse_quote! { #arg });
-                            dispatch_args.push(parse_quote! { input.#arg });
+                            if is_borrowed_bucket(t.ty.as_ref()) {
+                                dispatch_args
+                                    .push(parse_quote! { &::scrypto::model::Bucket(input.#arg.0) });
+                            } else {
+                                dispatch_args.push(parse_quote! { input.#arg });
+                            }
                         }
                     }
                 }
@@ -489,6 +498,10 @@ fn generate_stubs(
                 self.component.add_access_check(access_rules);
                 self
             }
+            fn set_auth_mutability(&mut self, access_rule: ::scrypto::model::AccessRule) -> &mut Self {
+                self.component.set_auth_mutability(access_rule);
+                self
+            }
             fn set_royalty_config(&mut self, royalty_config: ::scrypto::model::RoyaltyConfig) -> &mut Self {
                 self.component.set_royalty_config(royalty_config);
                 self
@@ -555,6 +568,22 @@ fn generate_stubs(
     Ok(output)
 }
 
+// Whether the type is `&Bucket`, which is passed using the borrowed-bucket calling convention.
+fn is_borrowed_bucket(t: &Type) -> bool {
+    match t {
+        Type::Reference(r) if r.mutability.is_none() => match r.elem.as_ref() {
+            Type::Path(tp) => tp
+                .path
+                .segments
+                .last()
+                .map(|s| s.ident == "Bucket" && s.arguments.is_empty())
+                .unwrap_or(false),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
 fn replace_self_with(t: &Type, name: &str) -> Type {
     match t {
         Type::Path(tp) => {
@@ -734,6 +763,10 @@ mod tests {
                         self.component.add_access_check(access_rules);
                         self
                     }
+                    fn set_auth_mutability(&mut self, access_rule: ::scrypto::model::AccessRule) -> &mut Self {
+                        self.component.set_auth_mutability(access_rule);
+                        self
+                    }
                     fn set_royalty_config(&mut self, royalty_config: ::scrypto::model::RoyaltyConfig) -> &mut Self {
                         self.component.set_royalty_config(royalty_config);
                         self
@@ -805,6 +838,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_borrowed_bucket_input() {
+        let bp_ident = format_ident!("Test");
+        let item: ImplItem = parse_quote! {
+            pub fn x(&self, a: &Bucket, b: Bucket) {}
+        };
+
+        let input_structs = generate_method_input_structs(&bp_ident, &[item.clone()]);
+        let dispatcher = generate_dispatcher(&format_ident!("test"), &bp_ident, &[item]).unwrap();
+
+        assert_code_eq(
+            quote! { #(#input_structs)* },
+            quote! {
+                #[allow(non_camel_case_types)]
+                #[derive(::sbor::TypeId, ::sbor::Encode, ::sbor::Decode, ::scrypto::Describe)]
+                #[sbor(custom_type_id = "::scrypto::data::ScryptoCustomTypeId")]
+                pub struct Test_x_Input {
+                    arg0: ::scrypto::model::BorrowedBucket,
+                    arg1: Bucket
+                }
+            },
+        );
+        assert!(dispatcher[0].to_string().contains(
+            &quote! { test::Test::x(state.deref(), &::scrypto::model::Bucket(input.arg0.0), input.arg1) }
+                .to_string()
+        ));
+    }
+
     #[test]
     fn test_empty_blueprint() {
         let input = TokenStream::from_str("struct Test {} impl Test {}").unwrap();
@@ -875,6 +936,10 @@ mod tests {
                         self.component.add_access_check(access_rules);
                         self
                     }
+                    fn set_auth_mutability(&mut self, access_rule: ::scrypto::model::AccessRule) -> &mut Self {
+                        self.component.set_auth_mutability(access_rule);
+                        self
+                    }
                     fn set_royalty_config(&mut self, royalty_config: ::scrypto::model::RoyaltyConfig) -> &mut Self {
                         self.component.set_royalty_config(royalty_config);
                         self
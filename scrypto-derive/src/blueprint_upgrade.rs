@@ -0,0 +1,252 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::*;
+
+macro_rules! trace {
+    ($($arg:expr),*) => {{
+        #[cfg(feature = "trace")]
+        println!($($arg),*);
+    }};
+}
+
+/// The `from = Old, to = New` arguments of the attribute.
+pub struct UpgradeArgs {
+    pub from: Ident,
+    pub to: Ident,
+}
+
+impl Parse for UpgradeArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut from = None;
+        let mut to = None;
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            let value: Ident = input.parse()?;
+            match key.to_string().as_str() {
+                "from" if from.is_none() => from = Some(value),
+                "to" if to.is_none() => to = Some(value),
+                _ => return Err(Error::new(key.span(), "Expected `from = ..., to = ...`")),
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        match (from, to) {
+            (Some(from), Some(to)) => Ok(Self { from, to }),
+            _ => Err(Error::new(
+                input.span(),
+                "Both `from` and `to` struct versions are required",
+            )),
+        }
+    }
+}
+
+pub fn handle_blueprint_upgrade(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
+    trace!("handle_blueprint_upgrade() starts");
+
+    let UpgradeArgs { from, to } = parse2(attr)?;
+    let mut module: ItemMod = parse2(item)?;
+    let mod_span = module.span();
+    let (_, items) = module.content.as_mut().ok_or(Error::new(
+        mod_span,
+        "The module must contain both struct versions inline",
+    ))?;
+
+    let old_fields = find_named_fields(items, &from, mod_span)?;
+    let new_fields = find_named_fields(items, &to, mod_span)?;
+    trace!("Migrating: {} -> {}", from, to);
+
+    let mut field_inits = Vec::new();
+    for field in &new_fields {
+        let ident = field.ident.as_ref().expect("Named field");
+        if old_fields.iter().any(|f| f.ident.as_ref() == Some(ident)) {
+            field_inits.push(quote! { #ident: old_state.#ident });
+        } else {
+            field_inits.push(quote! { #ident: ::core::default::Default::default() });
+        }
+    }
+    for field in &old_fields {
+        let ident = field.ident.as_ref().expect("Named field");
+        if !new_fields.iter().any(|f| f.ident.as_ref() == Some(ident)) {
+            if !is_option(&field.ty) && !is_deprecated(field) {
+                return Err(Error::new(
+                    field.span(),
+                    format!(
+                        "Field `{}` is removed in `{}`; it must be an `Option` or be marked `#[deprecated]`",
+                        ident, to
+                    ),
+                ));
+            }
+        }
+    }
+
+    let fn_ident = migrate_fn_ident(&from);
+    let doc = format!(
+        " Migrates `{}` to `{}`, keeping fields of the same name and defaulting new ones.",
+        from, to
+    );
+    items.push(parse_quote! {
+        #[doc = #doc]
+        #[allow(deprecated)]
+        pub fn #fn_ident(old_state: #from) -> #to {
+            #to {
+                #(#field_inits,)*
+            }
+        }
+    });
+
+    let output = quote! { #module };
+    trace!("handle_blueprint_upgrade() finishes");
+
+    #[cfg(feature = "trace")]
+    crate::utils::print_generated_code("blueprint_upgrade!", &output);
+
+    Ok(output)
+}
+
+fn find_named_fields(items: &[Item], ident: &Ident, span: Span) -> Result<Vec<Field>> {
+    for item in items {
+        if let Item::Struct(s) = item {
+            if &s.ident == ident {
+                return match &s.fields {
+                    Fields::Named(FieldsNamed { named, .. }) => Ok(named.iter().cloned().collect()),
+                    _ => Err(Error::new(
+                        s.span(),
+                        "Only structs with named fields can be migrated",
+                    )),
+                };
+            }
+        }
+    }
+    Err(Error::new(
+        span,
+        format!("Struct `{}` is not defined in the module", ident),
+    ))
+}
+
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(TypePath { path, .. }) => path
+            .segments
+            .last()
+            .map(|s| s.ident == "Option")
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+fn is_deprecated(field: &Field) -> bool {
+    field.attrs.iter().any(|a| a.path.is_ident("deprecated"))
+}
+
+/// `CounterV1` becomes `migrate_from_v1`, other names are converted to snake case.
+fn migrate_fn_ident(from: &Ident) -> Ident {
+    let name = from.to_string();
+    let digits = name.len() - name.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    let suffix = if digits > 0 && name[..name.len() - digits].ends_with('V') {
+        format!("v{}", &name[name.len() - digits..])
+    } else {
+        let mut snake = String::new();
+        for (i, c) in name.chars().enumerate() {
+            if c.is_uppercase() {
+                if i > 0 {
+                    snake.push('_');
+                }
+                snake.extend(c.to_lowercase());
+            } else {
+                snake.push(c);
+            }
+        }
+        snake
+    };
+    format_ident!("migrate_from_{}", suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::TokenStream;
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn assert_code_eq(a: TokenStream, b: TokenStream) {
+        assert_eq!(a.to_string(), b.to_string());
+    }
+
+    #[test]
+    fn test_blueprint_upgrade() {
+        let attr = TokenStream::from_str("from = CounterV1, to = CounterV2").unwrap();
+        let item = TokenStream::from_str(
+            "mod state { pub struct CounterV1 { count: u32, #[deprecated] label: String } pub struct CounterV2 { count: u32, step: u32 } }",
+        )
+        .unwrap();
+        let output = handle_blueprint_upgrade(attr, item).unwrap();
+
+        assert_code_eq(
+            output,
+            quote! {
+                mod state {
+                    pub struct CounterV1 {
+                        count: u32,
+                        #[deprecated]
+                        label: String
+                    }
+                    pub struct CounterV2 {
+                        count: u32,
+                        step: u32
+                    }
+                    #[doc = " Migrates `CounterV1` to `CounterV2`, keeping fields of the same name and defaulting new ones."]
+                    #[allow(deprecated)]
+                    pub fn migrate_from_v1(old_state: CounterV1) -> CounterV2 {
+                        CounterV2 {
+                            count: old_state.count,
+                            step: ::core::default::Default::default(),
+                        }
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_removed_option_field_is_allowed() {
+        let attr = TokenStream::from_str("from = Old, to = New").unwrap();
+        let item = TokenStream::from_str(
+            "mod state { struct Old { a: u32, b: Option<u32> } struct New { a: u32 } }",
+        )
+        .unwrap();
+        let output = handle_blueprint_upgrade(attr, item).unwrap();
+
+        assert!(output.to_string().contains("fn migrate_from_old"));
+    }
+
+    #[test]
+    fn test_removed_field_must_be_option_or_deprecated() {
+        let attr = TokenStream::from_str("from = CounterV1, to = CounterV2").unwrap();
+        let item = TokenStream::from_str(
+            "mod state { struct CounterV1 { count: u32, label: String } struct CounterV2 { count: u32 } }",
+        )
+        .unwrap();
+        let error = handle_blueprint_upgrade(attr, item).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Field `label` is removed in `CounterV2`; it must be an `Option` or be marked `#[deprecated]`"
+        );
+    }
+
+    #[test]
+    fn test_missing_struct_version() {
+        let attr = TokenStream::from_str("from = CounterV1, to = CounterV2").unwrap();
+        let item = TokenStream::from_str("mod state { struct CounterV1 { count: u32 } }").unwrap();
+        let error = handle_blueprint_upgrade(attr, item).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Struct `CounterV2` is not defined in the module"
+        );
+    }
+}
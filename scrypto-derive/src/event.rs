@@ -0,0 +1,95 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::*;
+
+macro_rules! trace {
+    ($($arg:expr),*) => {{
+        #[cfg(feature = "trace")]
+        println!($($arg),*);
+    }};
+}
+
+pub fn handle_event(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
+    trace!("handle_event() starts");
+
+    if !attr.is_empty() {
+        return Err(Error::new(
+            attr.span(),
+            "The event attribute takes no arguments",
+        ));
+    }
+    let item: Item = parse2(item)?;
+    let (ident, generics) = match &item {
+        Item::Struct(s) => (&s.ident, &s.generics),
+        Item::Enum(e) => (&e.ident, &e.generics),
+        _ => {
+            return Err(Error::new(
+                item.span(),
+                "Only structs and enums can be events",
+            ))
+        }
+    };
+    let event_name = ident.to_string();
+    trace!("Processing: {}", event_name);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let output = quote! {
+        #[derive(::sbor::TypeId, ::sbor::Encode, ::sbor::Decode, ::scrypto::Describe)]
+        #[sbor(custom_type_id = "::scrypto::data::ScryptoCustomTypeId")]
+        #item
+
+        impl #impl_generics ::scrypto::runtime::EmittableEvent for #ident #ty_generics #where_clause {
+            const EVENT_NAME: &'static str = #event_name;
+        }
+    };
+    trace!("handle_event() finishes");
+
+    #[cfg(feature = "trace")]
+    crate::utils::print_generated_code("event!", &output);
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::TokenStream;
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn assert_code_eq(a: TokenStream, b: TokenStream) {
+        assert_eq!(a.to_string(), b.to_string());
+    }
+
+    #[test]
+    fn test_event() {
+        let attr = TokenStream::new();
+        let item = TokenStream::from_str("pub struct DepositEvent { amount: Decimal }").unwrap();
+        let output = handle_event(attr, item).unwrap();
+
+        assert_code_eq(
+            output,
+            quote! {
+                #[derive(::sbor::TypeId, ::sbor::Encode, ::sbor::Decode, ::scrypto::Describe)]
+                #[sbor(custom_type_id = "::scrypto::data::ScryptoCustomTypeId")]
+                pub struct DepositEvent {
+                    amount: Decimal
+                }
+
+                impl ::scrypto::runtime::EmittableEvent for DepositEvent {
+                    const EVENT_NAME: &'static str = "DepositEvent";
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_event_on_function_is_rejected() {
+        let attr = TokenStream::new();
+        let item = TokenStream::from_str("fn deposit() {}").unwrap();
+        let error = handle_event(attr, item).unwrap_err();
+
+        assert_eq!(error.to_string(), "Only structs and enums can be events");
+    }
+}
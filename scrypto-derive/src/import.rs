@@ -344,6 +344,7 @@ fn get_native_type(ty: &SchemaType) -> Result<(Type, Vec<Item>)> {
             parse_quote! { ::scrypto::component::KeyValueStore<#k, #v> }
         }
         SchemaType::Bucket => parse_quote! {::scrypto::model::Bucket },
+        SchemaType::BorrowedBucket => parse_quote! { &::scrypto::model::Bucket },
         SchemaType::Proof => parse_quote! { ::scrypto::model::Proof},
         SchemaType::Vault => parse_quote! { ::scrypto::model::Vault},
         SchemaType::Expression => parse_quote! {::scrypto::runtime::Expression },
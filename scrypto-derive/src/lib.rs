@@ -1,5 +1,7 @@
 mod ast;
 mod blueprint;
+mod blueprint_upgrade;
+mod event;
 mod import;
 mod non_fungible_data;
 mod utils;
@@ -16,6 +18,10 @@ use proc_macro::TokenStream;
 /// This macro will derive the dispatcher method responsible for handling invocation
 /// according to Scrypto ABI.
 ///
+/// A function or method may return `Result<T, E>`. Returning `Ok` passes `T` back to
+/// the caller as usual, while returning `Err` fails the transaction, with the encoded
+/// `E` exposed in the receipt.
+///
 /// # Example
 /// ```ignore
 /// use scrypto::prelude::*;
@@ -100,6 +106,10 @@ pub fn import(input: TokenStream) -> TokenStream {
 
 /// Derive code that describe a non-fungible data structure.
 ///
+/// Fields marked `#[scrypto(mutable)]` form the mutable part of the data, which is the only
+/// part that can be updated after minting. The schemas of both parts are embedded in resources
+/// created with this data, so that mints and updates of a different shape are rejected.
+///
 /// # Example
 ///
 /// ```ignore
@@ -118,3 +128,69 @@ pub fn non_fungible_data(input: TokenStream) -> TokenStream {
         .unwrap_or_else(|err| err.to_compile_error())
         .into()
 }
+
+/// Generates a migration function between two versions of a blueprint state struct.
+///
+/// The attribute goes on an inline module which defines both versions. It adds a
+/// `migrate_from_<version>` function to the module, which copies fields of the same name,
+/// initializes new fields with `Default::default()` and drops removed fields. A removed field
+/// must be an `Option` or be marked `#[deprecated]`, so that dropping data is intentional.
+///
+/// # Example
+/// ```ignore
+/// use scrypto::prelude::*;
+///
+/// #[blueprint_upgrade(from = CounterV1, to = CounterV2)]
+/// mod counter_state {
+///     pub struct CounterV1 {
+///         pub count: u32,
+///         #[deprecated]
+///         pub label: String,
+///     }
+///
+///     pub struct CounterV2 {
+///         pub count: u32,
+///         pub step: u32,
+///     }
+/// }
+///
+/// // counter_state::migrate_from_v1(old_state: CounterV1) -> CounterV2
+/// ```
+#[proc_macro_attribute]
+pub fn blueprint_upgrade(attr: TokenStream, item: TokenStream) -> TokenStream {
+    blueprint_upgrade::handle_blueprint_upgrade(
+        proc_macro2::TokenStream::from(attr),
+        proc_macro2::TokenStream::from(item),
+    )
+    .unwrap_or_else(|err| err.to_compile_error())
+    .into()
+}
+
+/// Declares an event which can be emitted by a blueprint.
+///
+/// The attribute derives the SBOR encoding of the struct or enum and implements
+/// `EmittableEvent`, whose `emit` method records the event in the transaction receipt.
+/// The encoded event is prefixed with a discriminant, computed as
+/// `sha256("<package_address>::<blueprint_name>::<event_name>")` from the emitting
+/// blueprint, so that the events of a blueprint can be filtered by type.
+///
+/// # Example
+/// ```ignore
+/// use scrypto::prelude::*;
+///
+/// #[event]
+/// pub struct DepositEvent {
+///     pub amount: Decimal,
+/// }
+///
+/// // DepositEvent { amount: dec!("1") }.emit();
+/// ```
+#[proc_macro_attribute]
+pub fn event(attr: TokenStream, item: TokenStream) -> TokenStream {
+    event::handle_event(
+        proc_macro2::TokenStream::from(attr),
+        proc_macro2::TokenStream::from(item),
+    )
+    .unwrap_or_else(|err| err.to_compile_error())
+    .into()
+}
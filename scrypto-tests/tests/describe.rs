@@ -131,3 +131,19 @@ fn test_describe_enum() {
         }),
     );
 }
+
+#[test]
+fn test_describe_scrypto_hash_map() {
+    assert_json_eq(
+        ScryptoHashMap::<String, u32>::describe(),
+        json!({
+            "type": "TreeMap",
+            "key_type": {
+                "type": "String"
+            },
+            "value_type": {
+                "type": "U32"
+            }
+        }),
+    );
+}
@@ -0,0 +1,26 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use scrypto::prelude::*;
+use scrypto::radix_engine_interface::api::types::Level;
+use scrypto::runtime::MAX_LEVEL;
+
+// Only needs to compile, as logging requires the engine.
+#[allow(dead_code)]
+fn log_at_every_level() {
+    let amount = Decimal::from(5);
+    error!("error");
+    warn!("amount: {}", amount);
+    info!("amount: {}, doubled: {}", amount, amount * 2);
+    debug!("{:?}", amount);
+    trace!("{amount}");
+}
+
+#[test]
+fn test_all_levels_enabled_by_default() {
+    assert_eq!(MAX_LEVEL, Some(Level::Trace));
+    assert!(Logger::is_enabled(Level::Error));
+    assert!(Logger::is_enabled(Level::Warn));
+    assert!(Logger::is_enabled(Level::Info));
+    assert!(Logger::is_enabled(Level::Debug));
+    assert!(Logger::is_enabled(Level::Trace));
+}
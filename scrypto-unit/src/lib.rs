@@ -1,3 +1,5 @@
+mod snapshot;
 mod test_runner;
 
+pub use snapshot::*;
 pub use test_runner::*;
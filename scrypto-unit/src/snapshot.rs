@@ -0,0 +1,383 @@
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use radix_engine::transaction::{TransactionOutcome, TransactionReceipt, TransactionResult};
+use radix_engine::types::*;
+use radix_engine_interface::address::NO_NETWORK;
+use radix_engine_interface::data::IndexedScryptoValue;
+use radix_engine_interface::math::RoundingMode;
+use transaction::manifest::decompiler::{decompile_instruction, DecompilationContext};
+use utils::ContextualDisplay;
+
+/// The environment variable which, when set to `1`, makes snapshot assertions overwrite the
+/// snapshot files instead of comparing against them.
+pub const ENV_UPDATE_SNAPSHOTS: &'static str = "UPDATE_SNAPSHOTS";
+
+/// Controls how a receipt is rendered into a snapshot.
+#[derive(Debug, Clone)]
+pub struct SnapshotConfig {
+    /// The number of decimal places XRD fees are rounded to, or `None` to leave fees out.
+    pub fee_decimal_places: Option<u32>,
+}
+
+impl Default for SnapshotConfig {
+    fn default() -> Self {
+        Self {
+            fee_decimal_places: Some(1),
+        }
+    }
+}
+
+/// Asserts that a receipt matches the snapshot file at the given path, relative to the
+/// directory of the calling crate.
+///
+/// Run with `UPDATE_SNAPSHOTS=1` to create or regenerate the snapshot file.
+#[macro_export]
+macro_rules! assert_receipt_snapshot {
+    ($receipt:expr, $path:expr) => {
+        $crate::assert_receipt_snapshot!($receipt, $path, $crate::SnapshotConfig::default())
+    };
+    ($receipt:expr, $path:expr, $config:expr) => {
+        $crate::assert_snapshot(
+            &$crate::render_receipt_snapshot(&$receipt, &$config),
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($path),
+        )
+    };
+}
+
+/// Compares a rendered snapshot with the content of a snapshot file, or overwrites the file
+/// when `UPDATE_SNAPSHOTS=1` is set.
+pub fn assert_snapshot<P: AsRef<Path>>(rendered: &str, path: P) {
+    let path = path.as_ref();
+    if std::env::var(ENV_UPDATE_SNAPSHOTS).as_deref() == Ok("1") {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).expect("Failed to create snapshot directory");
+        }
+        fs::write(path, rendered).expect("Failed to write snapshot");
+        return;
+    }
+
+    let expected = fs::read_to_string(path).unwrap_or_else(|_| {
+        panic!(
+            "Snapshot {} not found, run with {}=1 to create it",
+            path.display(),
+            ENV_UPDATE_SNAPSHOTS
+        )
+    });
+    if expected != rendered {
+        panic!(
+            "Snapshot {} does not match, run with {}=1 to update it\n--- expected\n{}\n--- actual\n{}",
+            path.display(),
+            ENV_UPDATE_SNAPSHOTS,
+            expected,
+            rendered
+        );
+    }
+}
+
+/// Renders a receipt into a deterministic text form.
+///
+/// Fee payments are excluded from resource changes. Addresses created by tests and hashes are
+/// replaced with placeholders such as `<component#1>`, numbered in order of first appearance.
+/// Well-known system addresses are kept.
+pub fn render_receipt_snapshot(receipt: &TransactionReceipt, config: &SnapshotConfig) -> String {
+    let bech32_encoder = Bech32Encoder::for_simulator();
+    let mut out = String::new();
+
+    let outcome = match &receipt.result {
+        TransactionResult::Commit(c) => match &c.outcome {
+            TransactionOutcome::Success(_) => "COMMITTED SUCCESS".to_string(),
+            TransactionOutcome::Failure(e) => match e.failed_instruction_index() {
+                Some(index) => format!(
+                    "COMMITTED FAILURE at instruction {}: {:?}",
+                    index,
+                    e.cause()
+                ),
+                None => format!("COMMITTED FAILURE: {:?}", e),
+            },
+        },
+        TransactionResult::Reject(r) => format!("REJECTED: {:?}", r.error),
+    };
+    writeln!(out, "Outcome: {}", outcome).unwrap();
+
+    if let Some(decimal_places) = config.fee_decimal_places {
+        let round = |amount: Decimal| {
+            amount.round(
+                decimal_places,
+                RoundingMode::TowardsNearestAndHalfAwayFromZero,
+            )
+        };
+        let fee_summary = &receipt.execution.fee_summary;
+        writeln!(
+            out,
+            "Fee: {} XRD execution, {} XRD royalty, {} XRD bad debt",
+            round(fee_summary.total_execution_cost_xrd),
+            round(fee_summary.total_royalty_cost_xrd),
+            round(fee_summary.bad_debt_xrd),
+        )
+        .unwrap();
+    }
+
+    let mut context = DecompilationContext::new(&bech32_encoder);
+    writeln!(out, "Instructions:").unwrap();
+    for instruction in &receipt.contents.instructions {
+        out.push_str("- ");
+        if let Err(err) = decompile_instruction(&mut out, instruction, &mut context) {
+            write!(out, "[INVALID_INSTRUCTION({:?})]", err).unwrap();
+        }
+        out.push('\n');
+    }
+
+    if let TransactionResult::Commit(c) = &receipt.result {
+        if let TransactionOutcome::Success(outputs) = &c.outcome {
+            writeln!(out, "Outputs:").unwrap();
+            for output in outputs {
+                let value =
+                    IndexedScryptoValue::from_slice(output).expect("Failed to parse return data");
+                writeln!(out, "- {}", value.display(context.for_value_display())).unwrap();
+            }
+        }
+
+        writeln!(out, "New Entities:").unwrap();
+        for package_address in &c.entity_changes.new_package_addresses {
+            writeln!(
+                out,
+                "- Package: {}",
+                package_address.display(&bech32_encoder)
+            )
+            .unwrap();
+        }
+        for component_address in &c.entity_changes.new_component_addresses {
+            writeln!(
+                out,
+                "- Component: {}",
+                component_address.display(&bech32_encoder)
+            )
+            .unwrap();
+        }
+        for resource_address in &c.entity_changes.new_resource_addresses {
+            writeln!(
+                out,
+                "- Resource: {}",
+                resource_address.display(&bech32_encoder)
+            )
+            .unwrap();
+        }
+
+        // Fee payments are left out, as they change with every costing update
+        let vault_payments = receipt.execution.fee_summary.vault_payments_xrd.as_ref();
+        writeln!(out, "Resource Changes:").unwrap();
+        for resource_change in &c.resource_changes {
+            let fee_payment = vault_payments
+                .and_then(|payments| payments.get(&resource_change.vault_id))
+                .filter(|_| resource_change.resource_address == RADIX_TOKEN)
+                .cloned()
+                .unwrap_or_default();
+            let amount = resource_change.amount + fee_payment;
+            if !amount.is_zero() {
+                writeln!(
+                    out,
+                    "- {}: {}",
+                    resource_change.resource_address.display(&bech32_encoder),
+                    amount
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    writeln!(out, "Logs:").unwrap();
+    for (level, message) in &receipt.execution.application_logs {
+        writeln!(out, "- [{:?}] {}", level, message).unwrap();
+    }
+
+    Placeholders::new().substitute(&out)
+}
+
+/// Replaces addresses and hashes in a text with placeholders, in order of first appearance.
+struct Placeholders {
+    bech32_decoder: Bech32Decoder,
+    well_known: HashSet<String>,
+    placeholders: HashMap<String, String>,
+    counters: HashMap<&'static str, usize>,
+}
+
+impl Placeholders {
+    fn new() -> Self {
+        let well_known = [
+            FAUCET_PACKAGE.display(NO_NETWORK).to_string(),
+            ACCOUNT_PACKAGE.display(NO_NETWORK).to_string(),
+            ECDSA_SECP256K1_TOKEN.display(NO_NETWORK).to_string(),
+            EDDSA_ED25519_TOKEN.display(NO_NETWORK).to_string(),
+            SYSTEM_TOKEN.display(NO_NETWORK).to_string(),
+            RADIX_TOKEN.display(NO_NETWORK).to_string(),
+            FAUCET_COMPONENT.display(NO_NETWORK).to_string(),
+            EPOCH_MANAGER.display(NO_NETWORK).to_string(),
+            CLOCK.display(NO_NETWORK).to_string(),
+        ]
+        .into_iter()
+        .collect();
+
+        Self {
+            bech32_decoder: Bech32Decoder::for_simulator(),
+            well_known,
+            placeholders: HashMap::new(),
+            counters: HashMap::new(),
+        }
+    }
+
+    fn substitute(mut self, text: &str) -> String {
+        let mut out = String::new();
+        let mut rest = text;
+        while let Some(c) = rest.chars().next() {
+            let word_len = rest
+                .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                .unwrap_or(rest.len());
+            if word_len == 0 {
+                out.push(c);
+                rest = &rest[c.len_utf8()..];
+                continue;
+            }
+
+            let word = &rest[..word_len];
+            let (consumed, replacement) = self
+                .match_hash(rest)
+                .or_else(|| self.match_debug_address(rest, word))
+                .or_else(|| self.match_bech32_address(word))
+                .unwrap_or((word_len, word.to_string()));
+            out.push_str(&replacement);
+            rest = &rest[consumed..];
+        }
+        out
+    }
+
+    /// Matches `Hash("<hex>")`.
+    fn match_hash(&mut self, text: &str) -> Option<(usize, String)> {
+        let hex = text.strip_prefix("Hash(\"")?.get(..Hash::LENGTH * 2)?;
+        if !is_hex(hex) || !text[6 + hex.len()..].starts_with("\")") {
+            return None;
+        }
+        let placeholder = self.placeholder("hash", hex.to_string());
+        Some((8 + hex.len(), format!("Hash(\"{}\")", placeholder)))
+    }
+
+    /// Matches addresses displayed without a network, e.g. `NormalComponent[<hex>]`.
+    fn match_debug_address(&mut self, text: &str, word: &str) -> Option<(usize, String)> {
+        let hex_and_rest = text[word.len()..].strip_prefix('[')?;
+        let hex = &hex_and_rest[..hex_and_rest.find(']')?];
+        if !is_hex(hex) || !word.starts_with(|c: char| c.is_ascii_uppercase()) {
+            return None;
+        }
+        let debug_form = &text[..word.len() + hex.len() + 2];
+        Some((debug_form.len(), self.address_placeholder(debug_form)))
+    }
+
+    /// Matches bech32 addresses of the simulator network, e.g. `component_sim1...`.
+    fn match_bech32_address(&mut self, word: &str) -> Option<(usize, String)> {
+        if !word.contains("_sim1") {
+            return None;
+        }
+        let decoder = &self.bech32_decoder;
+        let debug_form = decoder
+            .validate_and_decode_component_address(word)
+            .map(|a| a.display(NO_NETWORK).to_string())
+            .or_else(|_| {
+                decoder
+                    .validate_and_decode_resource_address(word)
+                    .map(|a| a.display(NO_NETWORK).to_string())
+            })
+            .or_else(|_| {
+                decoder
+                    .validate_and_decode_package_address(word)
+                    .map(|a| a.display(NO_NETWORK).to_string())
+            })
+            .or_else(|_| {
+                decoder
+                    .validate_and_decode_system_address(word)
+                    .map(|a| a.display(NO_NETWORK).to_string())
+            })
+            .ok()?;
+        if self.well_known.contains(&debug_form) {
+            return Some((word.len(), word.to_string()));
+        }
+        Some((word.len(), self.address_placeholder(&debug_form)))
+    }
+
+    fn address_placeholder(&mut self, debug_form: &str) -> String {
+        if self.well_known.contains(debug_form) {
+            return debug_form.to_string();
+        }
+        let type_name = &debug_form[..debug_form.find('[').unwrap()];
+        let kind = if type_name.ends_with("Package") {
+            "package"
+        } else if type_name.ends_with("Resource") {
+            "resource"
+        } else if type_name.ends_with("System") {
+            "system"
+        } else if type_name == "NormalComponent" {
+            "component"
+        } else {
+            "account"
+        };
+        self.placeholder(kind, debug_form.to_string())
+    }
+
+    fn placeholder(&mut self, kind: &'static str, key: String) -> String {
+        if let Some(placeholder) = self.placeholders.get(&key) {
+            return placeholder.clone();
+        }
+        let counter = self.counters.entry(kind).or_insert(0);
+        *counter += 1;
+        let placeholder = format!("<{}#{}>", kind, counter);
+        self.placeholders.insert(key, placeholder.clone());
+        placeholder
+    }
+}
+
+fn is_hex(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_address_placeholders_follow_first_appearance() {
+        let encoder = Bech32Encoder::for_simulator();
+        let component = ComponentAddress::Normal([1u8; 26]);
+        let account = ComponentAddress::Account([2u8; 26]);
+        let text = format!(
+            "{} {} {:?} {} {}",
+            account.display(&encoder),
+            component.display(&encoder),
+            component,
+            FAUCET_COMPONENT.display(&encoder),
+            account.display(&encoder),
+        );
+
+        assert_eq!(
+            Placeholders::new().substitute(&text),
+            format!(
+                "<account#1> <component#1> <component#1> {} <account#1>",
+                FAUCET_COMPONENT.display(&encoder)
+            )
+        );
+    }
+
+    #[test]
+    fn test_hash_placeholders() {
+        let text = format!(
+            "Hash(\"{}\") Hash(\"{}\") Hash(\"{}\")",
+            "ab".repeat(32),
+            "cd".repeat(32),
+            "ab".repeat(32)
+        );
+
+        assert_eq!(
+            Placeholders::new().substitute(&text),
+            "Hash(\"<hash#1>\") Hash(\"<hash#2>\") Hash(\"<hash#1>\")"
+        );
+    }
+}
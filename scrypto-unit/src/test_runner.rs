@@ -2,6 +2,7 @@ use std::ffi::OsStr;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::Arc;
 
 use radix_engine::engine::{Kernel, KernelError, ModuleError, ScryptoInterpreter};
 use radix_engine::engine::{RuntimeError, Track};
@@ -9,11 +10,13 @@ use radix_engine::fee::{FeeTable, SystemLoanFeeReserve};
 use radix_engine::ledger::*;
 use radix_engine::model::{
     export_abi, export_abi_by_component, extract_abi, GlobalAddressSubstate, MetadataSubstate,
+    ResourceManagerSubstate,
 };
 use radix_engine::state_manager::StagedSubstateStoreManager;
 use radix_engine::transaction::{
-    execute_and_commit_transaction, execute_preview, execute_transaction, ExecutionConfig,
-    FeeReserveConfig, PreviewError, PreviewResult, TransactionReceipt, TransactionResult,
+    execute_and_commit_transaction, execute_preview, execute_transaction, CommitResult,
+    ExecutionConfig, FeeReserveConfig, PreviewError, PreviewResult, TransactionOutcome,
+    TransactionReceipt, TransactionResult,
 };
 use radix_engine::types::*;
 use radix_engine::wasm::{
@@ -33,10 +36,13 @@ use radix_engine_interface::{dec, rule};
 use scrypto::component::Mutability;
 use scrypto::component::Mutability::*;
 use transaction::builder::ManifestBuilder;
-use transaction::model::{AuthZoneParams, Executable, TransactionManifest};
-use transaction::model::{PreviewIntent, TestTransaction};
+use transaction::errors::TransactionValidationError;
+use transaction::model::{AuthZoneParams, Executable, NotarizedTransaction, TransactionManifest};
+use transaction::model::{InitialProofs, PreviewIntent, TestTransaction};
 use transaction::signing::EcdsaSecp256k1PrivateKey;
-use transaction::validation::TestIntentHashManager;
+use transaction::validation::{
+    NotarizedTransactionValidator, TestIntentHashManager, TransactionValidator, ValidationConfig,
+};
 
 pub struct Compile;
 
@@ -102,34 +108,104 @@ impl Compile {
     }
 }
 
+/// The seed from which deterministic test key pairs are derived.
+///
+/// The key pair for index `i` is derived from `hash(DETERMINISTIC_KEY_SEED ++ i.to_be_bytes())`.
+/// Both the seed and the derivation are part of the public contract of this crate, and must not
+/// change across releases, so that account addresses in tests remain reproducible.
+pub const DETERMINISTIC_KEY_SEED: &[u8] = b"scrypto-unit deterministic test keys";
+
+/// The receipts of a batch of transactions executed with all-or-nothing semantics.
+#[derive(Debug)]
+pub struct BatchReceipt {
+    /// The receipts of the executed transactions, ending with the failed one if any.
+    pub receipts: Vec<TransactionReceipt>,
+    /// The index of the transaction which failed, in which case nothing was committed.
+    pub failed_index: Option<usize>,
+}
+
+impl BatchReceipt {
+    pub fn is_committed(&self) -> bool {
+        self.failed_index.is_none()
+    }
+
+    pub fn expect_committed(&self) -> &Vec<TransactionReceipt> {
+        if let Some(index) = self.failed_index {
+            panic!(
+                "Batch was not committed, transaction {} failed: {:?}",
+                index, self.receipts[index]
+            );
+        }
+        &self.receipts
+    }
+
+    pub fn expect_failure_at(&self, index: usize) -> &TransactionReceipt {
+        match self.failed_index {
+            Some(failed_index) if failed_index == index => &self.receipts[index],
+            Some(failed_index) => panic!(
+                "Expected transaction {} to fail, but transaction {} failed",
+                index, failed_index
+            ),
+            None => panic!(
+                "Expected transaction {} to fail, but batch was committed",
+                index
+            ),
+        }
+    }
+}
+
 pub struct TestRunner<'s, S: ReadableSubstateStore + WriteableSubstateStore> {
     execution_stores: StagedSubstateStoreManager<'s, S>,
-    scrypto_interpreter: ScryptoInterpreter<DefaultWasmEngine>,
+    scrypto_interpreter: Arc<ScryptoInterpreter<DefaultWasmEngine>>,
     intent_hash_manager: TestIntentHashManager,
     next_private_key: u64,
     next_transaction_nonce: u64,
     trace: bool,
+    deterministic: bool,
 }
 
-impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateStore>
-    TestRunner<'s, S>
+impl<
+        's,
+        S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateStore + StateTreeStore,
+    > TestRunner<'s, S>
 {
     pub fn new(trace: bool, substate_store: &'s mut S) -> Self {
-        let scrypto_interpreter = ScryptoInterpreter {
-            wasm_metering_config: WasmMeteringConfig::new(
-                InstructionCostRules::tiered(1, 5, 10, 5000),
-                1024,
-            ),
-            wasm_engine: DefaultWasmEngine::default(),
-            wasm_instrumenter: WasmInstrumenter::default(),
-        };
+        Self::new_with_options(trace, false, substate_store)
+    }
+
+    /// Creates a test runner whose key pairs are derived from [`DETERMINISTIC_KEY_SEED`], using
+    /// an incrementing derivation index starting at zero.
+    pub fn new_deterministic(trace: bool, substate_store: &'s mut S) -> Self {
+        Self::new_with_options(trace, true, substate_store)
+    }
+
+    pub fn new_with_options(trace: bool, deterministic: bool, substate_store: &'s mut S) -> Self {
+        Self::new_with_scrypto_interpreter(
+            trace,
+            deterministic,
+            substate_store,
+            Arc::new(new_scrypto_interpreter()),
+        )
+    }
+
+    /// Creates a test runner which executes transactions with the given interpreter.
+    ///
+    /// The interpreter caches instrumented code and WASM modules, and may be shared between
+    /// test runners running on different threads, each against their own substate store.
+    pub fn new_with_scrypto_interpreter(
+        trace: bool,
+        deterministic: bool,
+        substate_store: &'s mut S,
+        scrypto_interpreter: Arc<ScryptoInterpreter<DefaultWasmEngine>>,
+    ) -> Self {
         Self {
             execution_stores: StagedSubstateStoreManager::new(substate_store),
             scrypto_interpreter,
             intent_hash_manager: TestIntentHashManager::new(),
-            next_private_key: 1, // 0 is invalid
+            next_private_key: if deterministic { 0 } else { 1 }, // 0 is invalid for `from_u64`
             next_transaction_nonce: 0,
             trace,
+            deterministic,
         }
     }
 
@@ -138,11 +214,26 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
     }
 
     pub fn new_key_pair(&mut self) -> (EcdsaSecp256k1PublicKey, EcdsaSecp256k1PrivateKey) {
-        let private_key = EcdsaSecp256k1PrivateKey::from_u64(self.next_private_key).unwrap();
-        let public_key = private_key.public_key();
+        let key_pair = if self.deterministic {
+            Self::deterministic_key_pair(self.next_private_key)
+        } else {
+            let private_key = EcdsaSecp256k1PrivateKey::from_u64(self.next_private_key).unwrap();
+            (private_key.public_key(), private_key)
+        };
 
         self.next_private_key += 1;
-        (public_key, private_key)
+        key_pair
+    }
+
+    /// Derives the key pair with the given derivation index from [`DETERMINISTIC_KEY_SEED`].
+    pub fn deterministic_key_pair(
+        index: u64,
+    ) -> (EcdsaSecp256k1PublicKey, EcdsaSecp256k1PrivateKey) {
+        let mut seed = DETERMINISTIC_KEY_SEED.to_vec();
+        seed.extend(index.to_be_bytes());
+        let private_key = EcdsaSecp256k1PrivateKey::from_bytes(&hash(seed).to_vec())
+            .expect("Derived an invalid private key");
+        (private_key.public_key(), private_key)
     }
 
     pub fn new_key_pair_with_auth_address(
@@ -160,7 +251,7 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
         )
     }
 
-    pub fn get_metadata(&mut self, address: GlobalAddress) -> HashMap<String, String> {
+    pub fn get_metadata(&mut self, address: GlobalAddress) -> BTreeMap<String, String> {
         let node_id = RENodeId::Global(address);
         let global = self
             .execution_stores
@@ -188,6 +279,60 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
         metadata.metadata
     }
 
+    pub fn get_total_supply(&mut self, resource_address: ResourceAddress) -> Decimal {
+        let global: GlobalAddressSubstate = self
+            .execution_stores
+            .get_root_store()
+            .get_substate(&SubstateId(
+                RENodeId::Global(GlobalAddress::Resource(resource_address)),
+                SubstateOffset::Global(GlobalOffset::Global),
+            ))
+            .map(|s| s.substate.to_runtime())
+            .unwrap()
+            .into();
+        let resource_manager: ResourceManagerSubstate = self
+            .execution_stores
+            .get_root_store()
+            .get_substate(&SubstateId(
+                global.node_deref(),
+                SubstateOffset::ResourceManager(ResourceManagerOffset::ResourceManager),
+            ))
+            .map(|s| s.substate.to_runtime())
+            .unwrap()
+            .into();
+        resource_manager.total_supply
+    }
+
+    /// Returns the amount of a resource the system burner has locked away instead of burning.
+    pub fn get_burner_locked_amount(&mut self, resource_address: ResourceAddress) -> Decimal {
+        let global: GlobalAddressSubstate = self
+            .execution_stores
+            .get_root_store()
+            .get_substate(&SubstateId(
+                RENodeId::Global(GlobalAddress::System(SYS_BURNER_COMPONENT)),
+                SubstateOffset::Global(GlobalOffset::Global),
+            ))
+            .map(|s| s.substate.to_runtime())
+            .unwrap()
+            .into();
+        let burner = self
+            .execution_stores
+            .get_root_store()
+            .get_substate(&SubstateId(
+                global.node_deref(),
+                SubstateOffset::Burner(BurnerOffset::Burner),
+            ))
+            .map(|s| s.substate.to_runtime())
+            .unwrap();
+        burner
+            .to_ref()
+            .burner()
+            .locked
+            .get(&resource_address)
+            .map(|resource| resource.amount())
+            .unwrap_or_default()
+    }
+
     pub fn deref_component(&mut self, component_address: ComponentAddress) -> Option<RENodeId> {
         let node_id = RENodeId::Global(GlobalAddress::Component(component_address));
         let global = self
@@ -328,6 +473,27 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
         (pub_key, priv_key, account)
     }
 
+    /// Creates a virtual account owned by the key pair with the given derivation index (see
+    /// [`TestRunner::deterministic_key_pair`]), and funds it from the faucet.
+    ///
+    /// The returned account address is the same in every run, regardless of the state of the
+    /// test runner.
+    pub fn new_account_deterministic(
+        &mut self,
+        index: u64,
+    ) -> (
+        EcdsaSecp256k1PublicKey,
+        EcdsaSecp256k1PrivateKey,
+        ComponentAddress,
+    ) {
+        let (pub_key, priv_key) = Self::deterministic_key_pair(index);
+        let account = ComponentAddress::virtual_account_from_public_key(
+            &PublicKey::EcdsaSecp256k1(pub_key.clone()),
+        );
+        self.load_account_from_faucet(account);
+        (pub_key, priv_key, account)
+    }
+
     pub fn deref_component_address(&mut self, component_address: ComponentAddress) -> RENodeId {
         let substate: GlobalAddressSubstate = self
             .execution_stores
@@ -389,7 +555,7 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
         code: Vec<u8>,
         abi: HashMap<String, BlueprintAbi>,
         royalty_config: HashMap<String, RoyaltyConfig>,
-        metadata: HashMap<String, String>,
+        metadata: BTreeMap<String, String>,
         access_rules: AccessRules,
     ) -> PackageAddress {
         let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
@@ -424,7 +590,7 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
             code,
             abi,
             HashMap::new(),
-            HashMap::new(),
+            BTreeMap::new(),
             AccessRules::new(),
         )
     }
@@ -438,30 +604,37 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
         self.publish_package_with_owner(code, abi, owner_badge)
     }
 
-    pub fn execute_manifest(
+    /// Executes a manifest with the given initial proofs in the auth zone.
+    ///
+    /// Use [`InitialProofs::from_public_keys`] to act as the signers of the transaction, or
+    /// [`InitialProofs::from_non_fungible_addresses`] to start with arbitrary badges.
+    pub fn execute_manifest<P: Into<InitialProofs>>(
         &mut self,
         manifest: TransactionManifest,
-        initial_proofs: Vec<NonFungibleAddress>,
+        initial_proofs: P,
     ) -> TransactionReceipt {
-        let mut receipts =
-            self.execute_batch(vec![(manifest, initial_proofs)], DEFAULT_COST_UNIT_LIMIT);
-        receipts.pop().unwrap()
+        self.execute_manifest_with_cost_unit_limit(
+            manifest,
+            initial_proofs,
+            DEFAULT_COST_UNIT_LIMIT,
+        )
     }
 
-    pub fn execute_manifest_with_cost_unit_limit(
+    pub fn execute_manifest_with_cost_unit_limit<P: Into<InitialProofs>>(
         &mut self,
         manifest: TransactionManifest,
-        initial_proofs: Vec<NonFungibleAddress>,
+        initial_proofs: P,
         limit: u32,
     ) -> TransactionReceipt {
+        let initial_proofs = initial_proofs.into().into_non_fungible_addresses();
         let mut receipts = self.execute_batch(vec![(manifest, initial_proofs)], limit);
         receipts.pop().unwrap()
     }
 
-    pub fn execute_manifest_ignoring_fee(
+    pub fn execute_manifest_ignoring_fee<P: Into<InitialProofs>>(
         &mut self,
         mut manifest: TransactionManifest,
-        initial_proofs: Vec<NonFungibleAddress>,
+        initial_proofs: P,
     ) -> TransactionReceipt {
         manifest.instructions.insert(
             0,
@@ -480,7 +653,10 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
         self.execute_transaction_with_config(
             transaction,
             &FeeReserveConfig::default(),
-            &ExecutionConfig::default(),
+            &ExecutionConfig {
+                debug_metrics: true,
+                ..ExecutionConfig::default()
+            },
         )
     }
 
@@ -501,6 +677,31 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
         )
     }
 
+    /// Validates and executes a fully notarized transaction, committing the result.
+    pub fn execute_notarized_transaction(
+        &mut self,
+        transaction: &NotarizedTransaction,
+    ) -> Result<TransactionReceipt, TransactionValidationError> {
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+        let executable = validator.validate(transaction, &self.intent_hash_manager)?;
+
+        let node_id = self.create_child_node(0);
+        let receipt = execute_and_commit_transaction(
+            &mut self.execution_stores.get_output_store(node_id),
+            &self.scrypto_interpreter,
+            &FeeReserveConfig::default(),
+            &ExecutionConfig {
+                trace: self.trace,
+                debug_metrics: true,
+                ..ExecutionConfig::default()
+            },
+            &executable,
+        );
+        self.merge_node(node_id);
+
+        Ok(receipt)
+    }
+
     pub fn execute_preview(
         &mut self,
         preview_intent: PreviewIntent,
@@ -510,7 +711,7 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
 
         execute_preview(
             &self.execution_stores.get_output_store(node_id),
-            &mut self.scrypto_interpreter,
+            &self.scrypto_interpreter,
             &self.intent_hash_manager,
             network,
             preview_intent,
@@ -528,6 +729,47 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
         receipts
     }
 
+    /// Executes the manifests in sequence on a staged store, committing their effects only if
+    /// all of them succeed. Execution stops at the first failed or rejected transaction.
+    pub fn execute_atomic_batch(
+        &mut self,
+        manifests: Vec<(TransactionManifest, Vec<NonFungibleAddress>)>,
+        cost_unit_limit: u32,
+    ) -> BatchReceipt {
+        let node_id = self.create_child_node(0);
+        let mut receipts = Vec::new();
+        let mut failed_index = None;
+        for (index, manifest) in manifests.into_iter().enumerate() {
+            let receipt = self
+                .execute_batch_on_node(node_id, vec![manifest], cost_unit_limit)
+                .pop()
+                .unwrap();
+            let is_success = matches!(
+                &receipt.result,
+                TransactionResult::Commit(CommitResult {
+                    outcome: TransactionOutcome::Success(..),
+                    ..
+                })
+            );
+            receipts.push(receipt);
+            if !is_success {
+                failed_index = Some(index);
+                break;
+            }
+        }
+
+        if failed_index.is_none() {
+            self.merge_node(node_id);
+        } else {
+            self.discard_node(node_id);
+        }
+
+        BatchReceipt {
+            receipts,
+            failed_index,
+        }
+    }
+
     pub fn create_child_node(&mut self, parent_id: u64) -> u64 {
         self.execution_stores.new_child_node(parent_id)
     }
@@ -546,15 +788,21 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
             self.next_transaction_nonce += 1;
             let receipt = execute_and_commit_transaction(
                 &mut store,
-                &mut self.scrypto_interpreter,
+                &self.scrypto_interpreter,
                 &FeeReserveConfig {
                     cost_unit_price: DEFAULT_COST_UNIT_PRICE,
                     system_loan: DEFAULT_SYSTEM_LOAN,
                 },
                 &ExecutionConfig {
                     max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+                    max_owned_nodes_per_frame: DEFAULT_MAX_OWNED_NODES_PER_FRAME,
+                    max_auth_zone_proofs: DEFAULT_MAX_AUTH_ZONE_PROOFS,
+                    max_syscalls_per_transaction: DEFAULT_MAX_SYSCALLS_PER_TRANSACTION,
                     trace: self.trace,
                     max_sys_call_trace_depth: 1,
+                    charge_storage_fees: false,
+                    publish_badge: None,
+                    debug_metrics: true,
                 },
                 &transaction.get_executable(initial_proofs),
             );
@@ -568,6 +816,10 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
         self.execution_stores.merge_to_parent(node_id);
     }
 
+    pub fn discard_node(&mut self, node_id: u64) {
+        self.execution_stores.discard(node_id);
+    }
+
     pub fn export_abi(
         &mut self,
         package_address: PackageAddress,
@@ -617,11 +869,7 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
             .lock_fee(FAUCET_COMPONENT, 100u32.into())
             .create_proof_from_account(account, auth)
             .call_function(package, "ResourceCreator", function, args!(token, set_auth))
-            .call_method(
-                account,
-                "deposit_batch",
-                args!(Expression::entire_worktop()),
-            )
+            .deposit_entire_worktop(account)
             .build();
         self.execute_manifest(
             manifest,
@@ -639,13 +887,13 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
             .lock_fee(FAUCET_COMPONENT, 100u32.into())
             .create_resource(
                 ResourceType::Fungible { divisibility: 0 },
-                HashMap::new(),
+                BTreeMap::new(),
                 access_rules,
                 Some(MintParams::Fungible {
                     amount: 5u32.into(),
                 }),
             )
-            .call_method(to, "deposit_batch", args!(Expression::entire_worktop()))
+            .deposit_entire_worktop(to)
             .build();
         let receipt = self.execute_manifest(manifest, vec![]);
         receipt.expect_commit_success();
@@ -794,15 +1042,11 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
                 ResourceType::NonFungible {
                     id_type: NonFungibleIdType::U32,
                 },
-                HashMap::new(),
+                BTreeMap::new(),
                 access_rules,
                 Some(MintParams::NonFungible { entries }),
             )
-            .call_method(
-                account,
-                "deposit_batch",
-                args!(Expression::entire_worktop()),
-            )
+            .deposit_entire_worktop(account)
             .build();
         let receipt = self.execute_manifest(manifest, vec![]);
         receipt.expect_commit_success();
@@ -825,15 +1069,11 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
             .lock_fee(FAUCET_COMPONENT, 100u32.into())
             .create_resource(
                 ResourceType::Fungible { divisibility },
-                HashMap::new(),
+                BTreeMap::new(),
                 access_rules,
                 Some(MintParams::Fungible { amount }),
             )
-            .call_method(
-                account,
-                "deposit_batch",
-                args!(Expression::entire_worktop()),
-            )
+            .deposit_entire_worktop(account)
             .build();
         let receipt = self.execute_manifest(manifest, vec![]);
         receipt.expect_commit_success();
@@ -857,15 +1097,11 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
             .lock_fee(FAUCET_COMPONENT, 100u32.into())
             .create_resource(
                 ResourceType::Fungible { divisibility },
-                HashMap::new(),
+                BTreeMap::new(),
                 access_rules,
                 Some(MintParams::Fungible { amount }),
             )
-            .call_method(
-                account,
-                "deposit_batch",
-                args!(Expression::entire_worktop()),
-            )
+            .deposit_entire_worktop(account)
             .build();
         let receipt = self.execute_manifest(manifest, vec![]);
         receipt.expect_commit_success();
@@ -895,11 +1131,7 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
                 &self.export_abi(package_address, blueprint_name),
             )
             .unwrap()
-            .call_method(
-                account,
-                "deposit_batch",
-                args!(Expression::entire_worktop()),
-            )
+            .deposit_entire_worktop(account)
             .build();
         let receipt = self.execute_manifest(
             manifest,
@@ -960,8 +1192,12 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
             auth_zone_params,
             &blobs,
             DEFAULT_MAX_CALL_DEPTH,
+            DEFAULT_MAX_OWNED_NODES_PER_FRAME,
+            DEFAULT_MAX_AUTH_ZONE_PROOFS,
+            DEFAULT_MAX_SYSCALLS_PER_TRANSACTION,
+            None,
             track,
-            &mut self.scrypto_interpreter,
+            &self.scrypto_interpreter,
             Vec::new(),
         );
 
@@ -976,12 +1212,25 @@ impl<'s, S: ReadableSubstateStore + WriteableSubstateStore + QueryableSubstateSt
         self.next_transaction_nonce += 1;
         if let TransactionResult::Commit(c) = receipt.result {
             c.state_updates.commit(substate_store);
+            c.state_updates.commit_to_state_tree(substate_store);
         }
 
         output
     }
 }
 
+/// Creates an interpreter with the WASM metering config used by test runners.
+pub fn new_scrypto_interpreter() -> ScryptoInterpreter<DefaultWasmEngine> {
+    ScryptoInterpreter {
+        wasm_metering_config: WasmMeteringConfig::new(
+            InstructionCostRules::tiered(1, 5, 10, 5000),
+            1024,
+        ),
+        wasm_engine: DefaultWasmEngine::default(),
+        wasm_instrumenter: WasmInstrumenter::default(),
+    }
+}
+
 pub fn is_auth_error(e: &RuntimeError) -> bool {
     matches!(e, RuntimeError::ModuleError(ModuleError::AuthError(_)))
 }
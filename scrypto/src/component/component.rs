@@ -5,7 +5,7 @@ use radix_engine_interface::api::types::{
     SubstateOffset,
 };
 use radix_engine_interface::data::{
-    scrypto_decode, ScryptoCustomTypeId, ScryptoDecode, ScryptoEncode,
+    scrypto_decode, scrypto_encode, ScryptoCustomTypeId, ScryptoDecode, ScryptoEncode,
 };
 use radix_engine_interface::model::*;
 use radix_engine_interface::scrypto_type;
@@ -30,6 +30,15 @@ use super::ComponentAccessRules;
 pub trait ComponentState<C: LocalComponent>: ScryptoEncode + ScryptoDecode {
     /// Instantiates a component from this data structure.
     fn instantiate(self) -> C;
+
+    /// Returns the length in bytes that this state takes up when stored.
+    ///
+    /// This encodes the state as it is now, including any changes not yet written back by
+    /// the running method. Use [`Component::state_size_bytes`] to read the size of the stored
+    /// state of another component without decoding it.
+    fn state_size_bytes(&self) -> usize {
+        scrypto_encode(self).unwrap().len()
+    }
 }
 
 /// A separate trait for standardized calls so that component methods don't
@@ -52,6 +61,7 @@ pub trait LocalComponent {
     fn blueprint_name(&self) -> String;
     fn metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, name: K, value: V) -> &mut Self;
     fn add_access_check(&mut self, access_rules: AccessRules) -> &mut Self;
+    fn set_auth_mutability(&mut self, access_rule: AccessRule) -> &mut Self;
     fn set_royalty_config(&mut self, royalty_config: RoyaltyConfig) -> &mut Self;
     fn globalize(self) -> ComponentAddress;
     fn globalize_with_owner(self, owner_badge: NonFungibleAddress) -> ComponentAddress;
@@ -63,6 +73,7 @@ pub trait LocalComponent {
 pub struct ComponentInfoSubstate {
     pub package_address: PackageAddress,
     pub blueprint_name: String,
+    pub auth_mutability: AccessRule,
 }
 
 // TODO: de-duplication
@@ -122,6 +133,18 @@ impl Component {
         self
     }
 
+    /// Set the rule which must be satisfied to add access checks to the component once it
+    /// has been globalized. Defaults to `DenyAll`.
+    pub fn set_auth_mutability(&mut self, access_rule: AccessRule) -> &mut Self {
+        ScryptoEnv
+            .invoke(ComponentSetAuthMutabilityInvocation {
+                receiver: RENodeId::Component(self.0),
+                access_rule,
+            })
+            .unwrap();
+        self
+    }
+
     /// Set the royalty configuration of the component.
     pub fn set_royalty_config(&mut self, royalty_config: RoyaltyConfig) -> &mut Self {
         ScryptoEnv
@@ -163,6 +186,15 @@ impl Component {
             .unwrap()
     }
 
+    /// Returns the length in bytes of the stored state of this component, without decoding it.
+    pub fn state_size_bytes(&self) -> usize {
+        ScryptoEnv
+            .invoke(ComponentStateSizeBytesInvocation {
+                receiver: RENodeId::Component(self.0),
+            })
+            .unwrap()
+    }
+
     /// Returns the layers of access rules on this component.
     pub fn access_rules_chain(&self) -> Vec<ComponentAccessRules> {
         let mut env = ScryptoEnv;
@@ -258,6 +290,15 @@ impl GlobalComponentRef {
         .unwrap()
     }
 
+    /// Returns the length in bytes of the stored state of this component, without decoding it.
+    pub fn state_size_bytes(&self) -> usize {
+        ScryptoEnv
+            .invoke(ComponentStateSizeBytesInvocation {
+                receiver: RENodeId::Global(GlobalAddress::Component(self.0)),
+            })
+            .unwrap()
+    }
+
     /// Returns the layers of access rules on this component.
     pub fn access_rules_chain(&self) -> Vec<ComponentAccessRules> {
         let mut env = ScryptoEnv;
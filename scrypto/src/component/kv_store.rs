@@ -1,6 +1,6 @@
 use radix_engine_interface::api::api::EngineApi;
 use radix_engine_interface::api::types::{
-    KeyValueStoreId, KeyValueStoreOffset, RENodeId, ScryptoRENode, SubstateOffset,
+    KeyValueStoreId, KeyValueStoreOffset, LockHandle, RENodeId, ScryptoRENode, SubstateOffset,
 };
 use radix_engine_interface::data::*;
 
@@ -8,6 +8,7 @@ use sbor::rust::borrow::ToOwned;
 use sbor::rust::boxed::Box;
 use sbor::rust::fmt;
 use sbor::rust::marker::PhantomData;
+use sbor::rust::ops::{Deref, DerefMut};
 use sbor::rust::str::FromStr;
 use sbor::rust::string::*;
 use sbor::rust::vec::Vec;
@@ -16,7 +17,7 @@ use utils::copy_u8_array;
 
 use crate::abi::*;
 use crate::engine::scrypto_env::ScryptoEnv;
-use crate::runtime::{DataRef, DataRefMut};
+use crate::runtime::DataRef;
 
 /// A scalable key-value map which loads entries on demand.
 pub struct KeyValueStore<K: ScryptoEncode + ScryptoDecode, V: ScryptoEncode + ScryptoDecode> {
@@ -62,12 +63,16 @@ impl<K: ScryptoEncode + ScryptoDecode, V: ScryptoEncode + ScryptoDecode> KeyValu
             .map(|raw| DataRef::new(lock_handle, scrypto_decode(&raw).unwrap()))
     }
 
-    pub fn get_mut(&mut self, key: &K) -> Option<DataRefMut<V>> {
+    /// Returns a handle to the value that is associated with the given key.
+    ///
+    /// The value is only written back when the handle is dropped, and only if it was mutably
+    /// accessed through the handle.
+    pub fn get_mut(&mut self, key: &K) -> Option<KeyValueEntry<K, V>> {
         let mut env = ScryptoEnv;
         let offset =
             SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(scrypto_encode(key).unwrap()));
         let lock_handle = env
-            .sys_lock_substate(RENodeId::KeyValueStore(self.id), offset.clone(), true)
+            .sys_lock_substate(RENodeId::KeyValueStore(self.id), offset.clone(), false)
             .unwrap();
         let raw_bytes = env.sys_read(lock_handle).unwrap();
         let value: KeyValueStoreEntrySubstate = scrypto_decode(&raw_bytes).unwrap();
@@ -76,9 +81,14 @@ impl<K: ScryptoEncode + ScryptoDecode, V: ScryptoEncode + ScryptoDecode> KeyValu
             env.sys_drop_lock(lock_handle).unwrap();
         }
 
-        value
-            .0
-            .map(|raw| DataRefMut::new(lock_handle, offset, scrypto_decode(&raw).unwrap()))
+        value.0.map(|raw| KeyValueEntry {
+            node_id: RENodeId::KeyValueStore(self.id),
+            offset,
+            lock_handle,
+            value: scrypto_decode(&raw).unwrap(),
+            dirty: false,
+            key: PhantomData,
+        })
     }
 
     /// Inserts a new key-value pair into this map.
@@ -95,6 +105,106 @@ impl<K: ScryptoEncode + ScryptoDecode, V: ScryptoEncode + ScryptoDecode> KeyValu
             .unwrap();
         env.sys_drop_lock(lock_handle).unwrap();
     }
+
+    /// Inserts many key-value pairs into this map with a single engine call.
+    pub fn batch_put<I: IntoIterator<Item = (K, V)>>(&mut self, entries: I) {
+        let mut env = ScryptoEnv;
+        let substates = entries
+            .into_iter()
+            .map(|(key, value)| {
+                let offset = SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(
+                    scrypto_encode(&key).unwrap(),
+                ));
+                let substate = KeyValueStoreEntrySubstate(Some(scrypto_encode(&value).unwrap()));
+                (
+                    RENodeId::KeyValueStore(self.id),
+                    offset,
+                    scrypto_encode(&substate).unwrap(),
+                )
+            })
+            .collect();
+        env.sys_batch_write_substates(substates).unwrap();
+    }
+
+    /// Returns the values that are associated with the given keys, with a single engine call.
+    pub fn batch_get(&self, keys: &[K]) -> Vec<Option<V>> {
+        let mut env = ScryptoEnv;
+        let substates = keys
+            .iter()
+            .map(|key| {
+                let offset = SubstateOffset::KeyValueStore(KeyValueStoreOffset::Entry(
+                    scrypto_encode(key).unwrap(),
+                ));
+                (RENodeId::KeyValueStore(self.id), offset)
+            })
+            .collect();
+        env.sys_batch_read_substates(substates)
+            .unwrap()
+            .into_iter()
+            .map(|raw_bytes| {
+                let value: KeyValueStoreEntrySubstate = scrypto_decode(&raw_bytes).unwrap();
+                value.0.map(|raw| scrypto_decode(&raw).unwrap())
+            })
+            .collect()
+    }
+}
+
+/// A handle to a value in a [`KeyValueStore`], which writes the value back on drop if it was
+/// mutably accessed.
+pub struct KeyValueEntry<K: ScryptoEncode + ScryptoDecode, V: ScryptoEncode + ScryptoDecode> {
+    node_id: RENodeId,
+    offset: SubstateOffset,
+    lock_handle: LockHandle,
+    value: V,
+    dirty: bool,
+    key: PhantomData<K>,
+}
+
+impl<K: ScryptoEncode + ScryptoDecode, V: ScryptoEncode + ScryptoDecode> Deref
+    for KeyValueEntry<K, V>
+{
+    type Target = V;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<K: ScryptoEncode + ScryptoDecode, V: ScryptoEncode + ScryptoDecode> DerefMut
+    for KeyValueEntry<K, V>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.dirty = true;
+        &mut self.value
+    }
+}
+
+impl<K: ScryptoEncode + ScryptoDecode, V: fmt::Display + ScryptoEncode + ScryptoDecode> fmt::Display
+    for KeyValueEntry<K, V>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<K: ScryptoEncode + ScryptoDecode, V: ScryptoEncode + ScryptoDecode> Drop
+    for KeyValueEntry<K, V>
+{
+    fn drop(&mut self) {
+        let mut env = ScryptoEnv;
+        env.sys_drop_lock(self.lock_handle).unwrap();
+
+        // The entry is read locked until now, so that an untouched entry is never written
+        if self.dirty {
+            let lock_handle = env
+                .sys_lock_substate(self.node_id, self.offset.clone(), true)
+                .unwrap();
+            let substate = KeyValueStoreEntrySubstate(Some(scrypto_encode(&self.value).unwrap()));
+            env.sys_write(lock_handle, scrypto_encode(&substate).unwrap())
+                .unwrap();
+            env.sys_drop_lock(lock_handle).unwrap();
+        }
+    }
 }
 
 //========
@@ -32,4 +32,12 @@ impl BorrowedPackage {
         env.invoke(PackageClaimRoyaltyInvocation { receiver: self.0 })
             .unwrap()
     }
+
+    /// Returns the fingerprint of the source and toolchain this package was built from, if
+    /// it was built with one embedded.
+    pub fn build_fingerprint(&self) -> Option<BuildFingerprint> {
+        let mut env = ScryptoEnv;
+        env.invoke(PackageGetBuildFingerprintInvocation { receiver: self.0 })
+            .unwrap()
+    }
 }
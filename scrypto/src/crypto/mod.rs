@@ -0,0 +1,31 @@
+use radix_engine_interface::api::api::EngineApi;
+use sbor::rust::vec::Vec;
+use scrypto::engine::scrypto_env::ScryptoEnv;
+
+pub use radix_engine_interface::crypto::*;
+
+/// Computes the SHA-256 digest of some data, outside of WASM.
+///
+/// The data can be at most [`MAX_HASH_INPUT_SIZE`] bytes.
+pub fn sha256<T: AsRef<[u8]>>(data: T) -> Hash {
+    hash_by_engine(HashAlgorithm::Sha256, data.as_ref().to_vec())
+}
+
+/// Computes the BLAKE2b-256 digest of some data, outside of WASM.
+///
+/// The data can be at most [`MAX_HASH_INPUT_SIZE`] bytes.
+pub fn blake2b_256<T: AsRef<[u8]>>(data: T) -> Hash {
+    hash_by_engine(HashAlgorithm::Blake2b256, data.as_ref().to_vec())
+}
+
+/// Computes the Keccak-256 digest of some data, outside of WASM.
+///
+/// The data can be at most [`MAX_HASH_INPUT_SIZE`] bytes.
+pub fn keccak256<T: AsRef<[u8]>>(data: T) -> Hash {
+    hash_by_engine(HashAlgorithm::Keccak256, data.as_ref().to_vec())
+}
+
+fn hash_by_engine(algorithm: HashAlgorithm, data: Vec<u8>) -> Hash {
+    let mut env = ScryptoEnv;
+    env.sys_hash(algorithm, data).unwrap()
+}
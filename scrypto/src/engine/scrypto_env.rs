@@ -1,9 +1,10 @@
 use radix_engine_interface::api::api::{EngineApi, Invokable, LoggerApi};
 use radix_engine_interface::api::types::{
-    Level, LockHandle, RENodeId, ScryptoActor, ScryptoRENode, SubstateOffset,
+    CallerInfo, Level, LockHandle, RENodeId, ScryptoActor, ScryptoRENode, SubstateOffset,
 };
-use radix_engine_interface::crypto::Hash;
+use radix_engine_interface::crypto::{Hash, HashAlgorithm};
 use radix_engine_interface::data::ScryptoDecode;
+use radix_engine_interface::model::ResourceAddress;
 use radix_engine_interface::wasm::*;
 use sbor::rust::fmt::Debug;
 use sbor::rust::string::String;
@@ -13,6 +14,9 @@ use sbor::*;
 #[cfg(target_arch = "wasm32")]
 extern "C" {
     pub fn radix_engine(input: *mut u8) -> *mut u8;
+
+    /// Reports a panic message to the engine, which aborts the execution with it.
+    pub fn panic(message: *const u8, message_len: u32);
 }
 
 /// Utility function for making a radix engine call.
@@ -107,11 +111,32 @@ impl EngineApi<EngineApiError> for ScryptoEnv {
         Ok(rtn)
     }
 
+    fn sys_batch_read_substates(
+        &mut self,
+        substates: Vec<(RENodeId, SubstateOffset)>,
+    ) -> Result<Vec<Vec<u8>>, EngineApiError> {
+        let rtn = call_engine(RadixEngineInput::BatchReadSubstates(substates));
+        Ok(rtn)
+    }
+
+    fn sys_batch_write_substates(
+        &mut self,
+        substates: Vec<(RENodeId, SubstateOffset, Vec<u8>)>,
+    ) -> Result<(), EngineApiError> {
+        let rtn = call_engine(RadixEngineInput::BatchWriteSubstates(substates));
+        Ok(rtn)
+    }
+
     fn sys_get_actor(&mut self) -> Result<ScryptoActor, EngineApiError> {
         let rtn = call_engine(RadixEngineInput::GetActor());
         Ok(rtn)
     }
 
+    fn sys_get_caller(&mut self) -> Result<Option<CallerInfo>, EngineApiError> {
+        let rtn = call_engine(RadixEngineInput::GetCaller());
+        Ok(rtn)
+    }
+
     fn sys_generate_uuid(&mut self) -> Result<u128, EngineApiError> {
         let rtn = call_engine(RadixEngineInput::GenerateUuid());
         Ok(rtn)
@@ -121,6 +146,30 @@ impl EngineApi<EngineApiError> for ScryptoEnv {
         let rtn = call_engine(RadixEngineInput::GetTransactionHash());
         Ok(rtn)
     }
+
+    fn sys_get_current_epoch(&mut self) -> Result<u64, EngineApiError> {
+        let rtn = call_engine(RadixEngineInput::GetCurrentEpoch());
+        Ok(rtn)
+    }
+
+    fn sys_get_virtual_proof_resources(&mut self) -> Result<Vec<ResourceAddress>, EngineApiError> {
+        let rtn = call_engine(RadixEngineInput::GetVirtualProofResources());
+        Ok(rtn)
+    }
+
+    fn sys_emit_event(&mut self, payload: Vec<u8>) -> Result<(), EngineApiError> {
+        let rtn = call_engine(RadixEngineInput::EmitEvent(payload));
+        Ok(rtn)
+    }
+
+    fn sys_hash(
+        &mut self,
+        algorithm: HashAlgorithm,
+        data: Vec<u8>,
+    ) -> Result<Hash, EngineApiError> {
+        let rtn = call_engine(RadixEngineInput::Hash(algorithm, data));
+        Ok(rtn)
+    }
 }
 
 impl LoggerApi<EngineApiError> for ScryptoEnv {
@@ -38,9 +38,8 @@ pub mod math {
 pub mod model {
     pub use radix_engine_interface::model::*;
 }
-pub mod crypto {
-    pub use radix_engine_interface::crypto::*;
-}
+/// Scrypto cryptography library.
+pub mod crypto;
 /// Scrypto RE abstraction.
 pub mod engine;
 /// Scrypto resource abstraction.
@@ -60,7 +59,7 @@ pub use radix_engine_derive::{scrypto, Describe};
 
 // Re-export Scrypto derive.
 extern crate scrypto_derive;
-pub use scrypto_derive::{blueprint, import, NonFungibleData};
+pub use scrypto_derive::{blueprint, blueprint_upgrade, event, import, NonFungibleData};
 
 pub extern crate radix_engine_interface;
 pub extern crate scrypto_abi;
@@ -91,10 +90,13 @@ pub fn set_up_panic_hook() {
             "<unknown>".to_owned()
         };
 
-        crate::runtime::Logger::error(sbor::rust::format!(
-            "Panicked at '{}', {}",
-            payload,
-            location
-        ));
+        let message = sbor::rust::format!("Panicked at '{}', {}", payload, location);
+        crate::runtime::Logger::error(message.clone());
+
+        // Hand the message to the engine so that it's carried by the resulting error
+        #[cfg(target_arch = "wasm32")]
+        unsafe {
+            crate::engine::scrypto_env::panic(message.as_ptr(), message.len() as u32);
+        }
     }));
 }
@@ -9,7 +9,9 @@
 #[macro_export]
 macro_rules! error {
     ($($args: expr),+) => {{
-        ::scrypto::runtime::Logger::log(radix_engine_interface::api::types::Level::Error, ::sbor::rust::format!($($args),+));
+        if ::scrypto::runtime::Logger::is_enabled(radix_engine_interface::api::types::Level::Error) {
+            ::scrypto::runtime::Logger::log(radix_engine_interface::api::types::Level::Error, ::sbor::rust::format!($($args),+));
+        }
     }};
 }
 
@@ -24,7 +26,9 @@ macro_rules! error {
 #[macro_export]
 macro_rules! warn {
     ($($args: expr),+) => {{
-        ::scrypto::runtime::Logger::log(radix_engine_interface::api::types::Level::Warn, ::sbor::rust::format!($($args),+));
+        if ::scrypto::runtime::Logger::is_enabled(radix_engine_interface::api::types::Level::Warn) {
+            ::scrypto::runtime::Logger::log(radix_engine_interface::api::types::Level::Warn, ::sbor::rust::format!($($args),+));
+        }
     }};
 }
 
@@ -39,7 +43,9 @@ macro_rules! warn {
 #[macro_export]
 macro_rules! info {
     ($($args: expr),+) => {{
-        ::scrypto::runtime::Logger::log(radix_engine_interface::api::types::Level::Info, ::sbor::rust::format!($($args),+));
+        if ::scrypto::runtime::Logger::is_enabled(radix_engine_interface::api::types::Level::Info) {
+            ::scrypto::runtime::Logger::log(radix_engine_interface::api::types::Level::Info, ::sbor::rust::format!($($args),+));
+        }
     }};
 }
 
@@ -54,7 +60,9 @@ macro_rules! info {
 #[macro_export]
 macro_rules! debug {
     ($($args: expr),+) => {{
-        ::scrypto::runtime::Logger::log(radix_engine_interface::api::types::Level::Debug, ::sbor::rust::format!($($args),+));
+        if ::scrypto::runtime::Logger::is_enabled(radix_engine_interface::api::types::Level::Debug) {
+            ::scrypto::runtime::Logger::log(radix_engine_interface::api::types::Level::Debug, ::sbor::rust::format!($($args),+));
+        }
     }};
 }
 
@@ -69,7 +77,9 @@ macro_rules! debug {
 #[macro_export]
 macro_rules! trace {
     ($($args: expr),+) => {{
-        ::scrypto::runtime::Logger::log(radix_engine_interface::api::types::Level::Trace, ::sbor::rust::format!($($args),+));
+        if ::scrypto::runtime::Logger::is_enabled(radix_engine_interface::api::types::Level::Trace) {
+            ::scrypto::runtime::Logger::log(radix_engine_interface::api::types::Level::Trace, ::sbor::rust::format!($($args),+));
+        }
     }};
 }
 
@@ -4,9 +4,9 @@ pub use crate::resource::non_fungible::ScryptoNonFungibleId;
 pub use crate::resource::*;
 pub use crate::runtime::*;
 pub use crate::{
-    blueprint, borrow_component, borrow_package, borrow_resource_manager, debug, error,
-    external_blueprint, external_component, import, include_abi, include_code, info, resource_list,
-    scrypto, this_package, trace, warn, NonFungibleData,
+    blueprint, blueprint_upgrade, borrow_component, borrow_package, borrow_resource_manager, debug,
+    error, event, external_blueprint, external_component, import, include_abi, include_code, info,
+    resource_list, scrypto, this_package, trace, warn, NonFungibleData,
 };
 pub use num_traits::{
     cast::FromPrimitive, cast::ToPrimitive, identities::One, identities::Zero, pow::Pow,
@@ -14,7 +14,7 @@ pub use num_traits::{
 };
 pub use radix_engine_derive::*;
 pub use radix_engine_interface::constants::*;
-pub use radix_engine_interface::crypto::*;
+pub use crate::crypto::*;
 pub use radix_engine_interface::data::*;
 pub use radix_engine_interface::math::integer::{
     CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedPow, CheckedRem, CheckedSub, Min,
@@ -35,3 +35,10 @@ pub use sbor::{Decode, DecodeError, Encode, TypeId};
 pub use super::radix_engine_derive;
 pub use super::radix_engine_interface;
 pub use super::scrypto_abi;
+
+/// A map with a deterministic iteration order, for use in blueprints.
+///
+/// Every node executes a transaction and must arrive at the same result, but the iteration order
+/// of a `HashMap` depends on its hasher. Blueprints should use this map instead of
+/// `std::collections::HashMap`, which the blueprint `clippy.toml` disallows.
+pub type ScryptoHashMap<K, V> = BTreeMap<K, V>;
@@ -3,9 +3,11 @@ pub mod bucket;
 pub mod non_fungible;
 mod proof;
 mod proof_rule;
+mod resource_access_rules_template;
 mod resource_builder;
 mod resource_manager;
 mod system;
+mod time_locked_vault;
 mod vault;
 
 pub use auth_zone::*;
@@ -13,7 +15,9 @@ pub use bucket::*;
 pub use non_fungible::NonFungible;
 pub use proof::*;
 pub use proof_rule::*;
+pub use resource_access_rules_template::ResourceAccessRulesTemplate;
 pub use resource_builder::{ResourceBuilder, DIVISIBILITY_MAXIMUM, DIVISIBILITY_NONE};
 pub use resource_manager::*;
 pub use system::{init_resource_system, resource_system, ResourceSystem};
+pub use time_locked_vault::TimeLockedVault;
 pub use vault::*;
@@ -58,4 +58,20 @@ impl<T: NonFungibleData> NonFungible<T> {
         borrow_resource_manager!(self.resource_address())
             .update_non_fungible_data(self.id(), new_data);
     }
+
+    /// Updates the mutable part of the associated data of this unit, by applying the given
+    /// function to the current data.
+    ///
+    /// # Panics
+    /// Panics if the function changes any immutable field.
+    pub fn update_mutable_data<F: FnOnce(&mut T)>(&self, f: F) {
+        let mut data = self.data();
+        let immutable_data = data.immutable_data().unwrap();
+        f(&mut data);
+        assert!(
+            data.immutable_data().unwrap() == immutable_data,
+            "Immutable non-fungible data cannot be updated"
+        );
+        self.update_data(data);
+    }
 }
@@ -42,6 +42,7 @@ pub trait ScryptoProof: Sized {
     fn amount(&self) -> Decimal;
     fn non_fungible_ids(&self) -> BTreeSet<NonFungibleId>;
     fn resource_address(&self) -> ResourceAddress;
+    fn is_valid(&self) -> bool;
     fn drop(self);
     fn authorize<F: FnOnce() -> O, O>(&self, f: F) -> O;
 }
@@ -219,6 +220,11 @@ impl ScryptoProof for Proof {
                 receiver: self.0
             }
         }
+        fn is_valid(&self) -> bool {
+            ProofIsValidInvocation {
+                receiver: self.0
+            }
+        }
     }
 
     fn drop(self) {
@@ -255,6 +261,11 @@ impl ValidatedProof {
                 receiver: self.proof_id(),
             }
         }
+        pub fn is_valid(&self) -> bool {
+            ProofIsValidInvocation {
+                receiver: self.proof_id(),
+            }
+        }
     }
 
     #[cfg(target_arch = "wasm32")]
@@ -0,0 +1,84 @@
+use radix_engine_interface::model::*;
+use radix_engine_interface::rule;
+use sbor::rust::collections::HashMap;
+
+use crate::component::Mutability::{self, *};
+
+/// A reusable set of resource access rules, applied to a resource through
+/// `with_access_rules_from_template` on the resource builders.
+///
+/// # Example
+/// ```ignore
+/// let resource_address = ResourceBuilder::new_fungible()
+///     .with_access_rules_from_template(ResourceAccessRulesTemplate::standard_ownable(owner_badge))
+///     .no_initial_supply();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ResourceAccessRulesTemplate {
+    pub rules: HashMap<ResourceMethodAuthKey, (AccessRule, Mutability)>,
+}
+
+impl ResourceAccessRulesTemplate {
+    /// Creates an empty template, which leaves every method with its default rule.
+    pub fn new() -> Self {
+        Self {
+            rules: HashMap::new(),
+        }
+    }
+
+    /// Sets the rule for a method, overwriting any rule the template already has for it.
+    pub fn rule(
+        mut self,
+        method: ResourceMethodAuthKey,
+        method_auth: AccessRule,
+        mutability: Mutability,
+    ) -> Self {
+        self.rules.insert(method, (method_auth, mutability));
+        self
+    }
+
+    /// The owner badge controls every privileged method, and can change any of the rules.
+    /// Anyone can withdraw and deposit.
+    pub fn standard_ownable(owner_badge: ResourceAddress) -> Self {
+        let owner_rule = rule!(require(owner_badge));
+        let mut template = Self::new();
+        for method in [Withdraw, Deposit] {
+            template = template.rule(method, rule!(allow_all), MUTABLE(owner_rule.clone()));
+        }
+        for method in [
+            Mint,
+            Burn,
+            Recall,
+            UpdateMetadata,
+            UpdateNonFungibleData,
+            CreateSyntheticProof,
+        ] {
+            template = template.rule(method, owner_rule.clone(), MUTABLE(owner_rule.clone()));
+        }
+        template
+    }
+
+    /// Only the admin badge can mint; the rule can't be changed.
+    pub fn standard_mintable_by_admin(admin_badge: ResourceAddress) -> Self {
+        Self::new().rule(Mint, rule!(require(admin_badge)), LOCKED)
+    }
+
+    /// Anyone can burn the tokens they hold; the rule can't be changed.
+    pub fn standard_burnable_by_holder() -> Self {
+        Self::new().rule(Burn, rule!(allow_all), LOCKED)
+    }
+
+    /// The resource can never be withdrawn from the vault it is first deposited to.
+    pub fn standard_soulbound() -> Self {
+        Self::new().rule(Withdraw, rule!(deny_all), LOCKED)
+    }
+
+    pub(crate) fn into_access_rules(
+        self,
+    ) -> HashMap<ResourceMethodAuthKey, (AccessRule, AccessRule)> {
+        self.rules
+            .into_iter()
+            .map(|(method, (method_auth, mutability))| (method, (method_auth, mutability.into())))
+            .collect()
+    }
+}
@@ -1,10 +1,11 @@
 use crate::engine::scrypto_env::ScryptoEnv;
 use crate::radix_engine_interface::api::api::Invokable;
+use crate::resource::ResourceAccessRulesTemplate;
 use radix_engine_interface::math::Decimal;
 use radix_engine_interface::model::*;
 use radix_engine_interface::rule;
 use sbor::rust::borrow::ToOwned;
-use sbor::rust::collections::HashMap;
+use sbor::rust::collections::{BTreeMap, HashMap};
 use sbor::rust::string::String;
 
 /// Not divisible.
@@ -37,14 +38,16 @@ impl ResourceBuilder {
 /// resource with an owner badge or without one.
 pub struct FungibleResourceBuilder {
     divisibility: u8,
-    metadata: HashMap<String, String>,
+    metadata: BTreeMap<String, String>,
+    track_holders: bool,
 }
 
 impl FungibleResourceBuilder {
     pub fn new() -> Self {
         Self {
             divisibility: DIVISIBILITY_MAXIMUM,
-            metadata: HashMap::new(),
+            metadata: BTreeMap::new(),
+            track_holders: false,
         }
     }
 
@@ -66,6 +69,13 @@ impl FungibleResourceBuilder {
         self
     }
 
+    /// Makes the resource manager keep a count of the vaults holding a non-zero balance of the
+    /// resource, readable through `ResourceManager::holder_vault_count`.
+    pub fn track_holders(mut self) -> Self {
+        self.track_holders = true;
+        self
+    }
+
     pub fn mintable<R: Into<AccessRule>>(
         self,
         method_auth: AccessRule,
@@ -77,6 +87,7 @@ impl FungibleResourceBuilder {
             divisibility: self.divisibility,
             metadata: self.metadata,
             authorization,
+            track_holders: self.track_holders,
         }
     }
 
@@ -91,6 +102,7 @@ impl FungibleResourceBuilder {
             divisibility: self.divisibility,
             metadata: self.metadata,
             authorization,
+            track_holders: self.track_holders,
         }
     }
 
@@ -105,6 +117,7 @@ impl FungibleResourceBuilder {
             divisibility: self.divisibility,
             metadata: self.metadata,
             authorization,
+            track_holders: self.track_holders,
         }
     }
 
@@ -119,6 +132,7 @@ impl FungibleResourceBuilder {
             divisibility: self.divisibility,
             metadata: self.metadata,
             authorization,
+            track_holders: self.track_holders,
         }
     }
 
@@ -133,6 +147,7 @@ impl FungibleResourceBuilder {
             divisibility: self.divisibility,
             metadata: self.metadata,
             authorization,
+            track_holders: self.track_holders,
         }
     }
 
@@ -147,6 +162,36 @@ impl FungibleResourceBuilder {
             divisibility: self.divisibility,
             metadata: self.metadata,
             authorization,
+            track_holders: self.track_holders,
+        }
+    }
+
+    /// Sets the rule for creating synthetic proofs, which are vouched for by the resource manager.
+    pub fn synthetic_proofs<R: Into<AccessRule>>(
+        self,
+        method_auth: AccessRule,
+        mutability: R,
+    ) -> FungibleResourceWithAuthBuilder {
+        let mut authorization = HashMap::new();
+        authorization.insert(CreateSyntheticProof, (method_auth, mutability.into()));
+        FungibleResourceWithAuthBuilder {
+            divisibility: self.divisibility,
+            metadata: self.metadata,
+            authorization,
+            track_holders: self.track_holders,
+        }
+    }
+
+    /// Applies the rules of a template, overwriting any rules already set for the same methods.
+    pub fn with_access_rules_from_template(
+        self,
+        template: ResourceAccessRulesTemplate,
+    ) -> FungibleResourceWithAuthBuilder {
+        FungibleResourceWithAuthBuilder {
+            divisibility: self.divisibility,
+            metadata: self.metadata,
+            authorization: template.into_access_rules(),
+            track_holders: self.track_holders,
         }
     }
 
@@ -170,6 +215,8 @@ impl FungibleResourceBuilder {
                 metadata: self.metadata,
                 access_rules: authorization,
                 mint_params: Some(MintParams::fungible(amount)),
+                non_fungible_data_schema: None,
+                track_holders: self.track_holders,
             })
             .unwrap();
 
@@ -185,6 +232,8 @@ impl FungibleResourceBuilder {
                 metadata: self.metadata,
                 access_rules: HashMap::new(),
                 mint_params: None,
+                non_fungible_data_schema: None,
+                track_holders: self.track_holders,
             })
             .unwrap();
 
@@ -204,6 +253,8 @@ impl FungibleResourceBuilder {
                 metadata: self.metadata,
                 owner_badge: owner_badge,
                 mint_params: Some(MintParams::fungible(amount)),
+                non_fungible_data_schema: None,
+                track_holders: self.track_holders,
             })
             .unwrap();
 
@@ -219,6 +270,8 @@ impl FungibleResourceBuilder {
                 metadata: self.metadata,
                 owner_badge: owner_badge,
                 mint_params: None,
+                non_fungible_data_schema: None,
+                track_holders: self.track_holders,
             })
             .unwrap();
 
@@ -235,8 +288,9 @@ impl FungibleResourceBuilder {
 /// offer the `initial_supply_with_owner` and `no_initial_supply_with_owner` methods.
 pub struct FungibleResourceWithAuthBuilder {
     divisibility: u8,
-    metadata: HashMap<String, String>,
+    metadata: BTreeMap<String, String>,
     authorization: HashMap<ResourceMethodAuthKey, (AccessRule, AccessRule)>,
+    track_holders: bool,
 }
 
 impl FungibleResourceWithAuthBuilder {
@@ -249,6 +303,13 @@ impl FungibleResourceWithAuthBuilder {
         self
     }
 
+    /// Makes the resource manager keep a count of the vaults holding a non-zero balance of the
+    /// resource, readable through `ResourceManager::holder_vault_count`.
+    pub fn track_holders(mut self) -> Self {
+        self.track_holders = true;
+        self
+    }
+
     pub fn mintable<R: Into<AccessRule>>(mut self, method_auth: AccessRule, mutability: R) -> Self {
         self.authorization
             .insert(Mint, (method_auth, mutability.into()));
@@ -301,6 +362,26 @@ impl FungibleResourceWithAuthBuilder {
         self
     }
 
+    /// Sets the rule for creating synthetic proofs, which are vouched for by the resource manager.
+    pub fn synthetic_proofs<R: Into<AccessRule>>(
+        mut self,
+        method_auth: AccessRule,
+        mutability: R,
+    ) -> Self {
+        self.authorization
+            .insert(CreateSyntheticProof, (method_auth, mutability.into()));
+        self
+    }
+
+    /// Applies the rules of a template, overwriting any rules already set for the same methods.
+    pub fn with_access_rules_from_template(
+        mut self,
+        template: ResourceAccessRulesTemplate,
+    ) -> Self {
+        self.authorization.extend(template.into_access_rules());
+        self
+    }
+
     pub fn initial_supply<T: Into<Decimal>>(self, amount: T) -> Bucket {
         self.build(Some(MintParams::fungible(amount))).1.unwrap()
     }
@@ -324,6 +405,8 @@ impl FungibleResourceWithAuthBuilder {
                 metadata: self.metadata,
                 access_rules: self.authorization,
                 mint_params,
+                non_fungible_data_schema: None,
+                track_holders: self.track_holders,
             })
             .unwrap()
     }
@@ -338,18 +421,31 @@ impl FungibleResourceWithAuthBuilder {
 /// If none of these methods are called, then the developer has the choice to either building a
 /// resource with an owner badge or without one.
 pub struct NonFungibleResourceBuilder {
-    metadata: HashMap<String, String>,
+    metadata: BTreeMap<String, String>,
     id_type: NonFungibleIdType,
+    data_schema: Option<NonFungibleDataSchema>,
+    track_holders: bool,
 }
 
 impl NonFungibleResourceBuilder {
     pub fn new(id_type: NonFungibleIdType) -> Self {
         Self {
-            metadata: HashMap::new(),
+            metadata: BTreeMap::new(),
             id_type,
+            data_schema: None,
+            track_holders: false,
         }
     }
 
+    /// Sets the type of the non-fungible data, which minted and updated data is then
+    /// validated against.
+    ///
+    /// This is implied by `initial_supply`, from the type of the entries.
+    pub fn data_schema<T: NonFungibleData>(mut self) -> Self {
+        self.data_schema = Some(NonFungibleDataSchema::new::<T>());
+        self
+    }
+
     /// Adds a resource metadata.
     ///
     /// If a previous attribute with the same name has been set, it will be overwritten.
@@ -359,6 +455,13 @@ impl NonFungibleResourceBuilder {
         self
     }
 
+    /// Makes the resource manager keep a count of the vaults holding a non-zero balance of the
+    /// resource, readable through `ResourceManager::holder_vault_count`.
+    pub fn track_holders(mut self) -> Self {
+        self.track_holders = true;
+        self
+    }
+
     pub fn mintable<R: Into<AccessRule>>(
         self,
         method_auth: AccessRule,
@@ -370,6 +473,8 @@ impl NonFungibleResourceBuilder {
             id_type: self.id_type,
             metadata: self.metadata,
             authorization,
+            data_schema: self.data_schema,
+            track_holders: self.track_holders,
         }
     }
 
@@ -384,6 +489,8 @@ impl NonFungibleResourceBuilder {
             id_type: self.id_type,
             metadata: self.metadata,
             authorization,
+            data_schema: self.data_schema,
+            track_holders: self.track_holders,
         }
     }
 
@@ -398,6 +505,8 @@ impl NonFungibleResourceBuilder {
             id_type: self.id_type,
             metadata: self.metadata,
             authorization,
+            data_schema: self.data_schema,
+            track_holders: self.track_holders,
         }
     }
 
@@ -412,6 +521,8 @@ impl NonFungibleResourceBuilder {
             id_type: self.id_type,
             metadata: self.metadata,
             authorization,
+            data_schema: self.data_schema,
+            track_holders: self.track_holders,
         }
     }
 
@@ -426,6 +537,8 @@ impl NonFungibleResourceBuilder {
             id_type: self.id_type,
             metadata: self.metadata,
             authorization,
+            data_schema: self.data_schema,
+            track_holders: self.track_holders,
         }
     }
 
@@ -440,6 +553,8 @@ impl NonFungibleResourceBuilder {
             id_type: self.id_type,
             metadata: self.metadata,
             authorization,
+            data_schema: self.data_schema,
+            track_holders: self.track_holders,
         }
     }
 
@@ -454,6 +569,22 @@ impl NonFungibleResourceBuilder {
             id_type: self.id_type,
             metadata: self.metadata,
             authorization,
+            data_schema: self.data_schema,
+            track_holders: self.track_holders,
+        }
+    }
+
+    /// Applies the rules of a template, overwriting any rules already set for the same methods.
+    pub fn with_access_rules_from_template(
+        self,
+        template: ResourceAccessRulesTemplate,
+    ) -> NonFungibleResourceWithAuthBuilder {
+        NonFungibleResourceWithAuthBuilder {
+            id_type: self.id_type,
+            metadata: self.metadata,
+            authorization: template.into_access_rules(),
+            data_schema: self.data_schema,
+            track_holders: self.track_holders,
         }
     }
 
@@ -468,11 +599,13 @@ impl NonFungibleResourceBuilder {
     ///         (NonFungibleId::from(2u128), "another_immutable_part", "another_mutable_part"),
     ///     ]);
     /// ```
-    pub fn initial_supply<T, V>(self, entries: T) -> Bucket
+    pub fn initial_supply<T, V>(mut self, entries: T) -> Bucket
     where
         T: IntoIterator<Item = (NonFungibleId, V)>,
         V: NonFungibleData,
     {
+        self.data_schema
+            .get_or_insert_with(NonFungibleDataSchema::new::<V>);
         let mut encoded = HashMap::new();
         for (id, e) in entries {
             encoded.insert(id, (e.immutable_data().unwrap(), e.mutable_data().unwrap()));
@@ -499,12 +632,14 @@ impl NonFungibleResourceBuilder {
                 metadata: self.metadata,
                 access_rules: authorization,
                 mint_params,
+                non_fungible_data_schema: self.data_schema,
+                track_holders: self.track_holders,
             })
             .unwrap()
     }
 
     pub fn initial_supply_with_owner<T, V>(
-        self,
+        mut self,
         entries: T,
         owner_badge: NonFungibleAddress,
     ) -> Bucket
@@ -512,6 +647,8 @@ impl NonFungibleResourceBuilder {
         T: IntoIterator<Item = (NonFungibleId, V)>,
         V: NonFungibleData,
     {
+        self.data_schema
+            .get_or_insert_with(NonFungibleDataSchema::new::<V>);
         let mut encoded = HashMap::new();
         for (id, e) in entries {
             encoded.insert(id, (e.immutable_data().unwrap(), e.mutable_data().unwrap()));
@@ -525,6 +662,8 @@ impl NonFungibleResourceBuilder {
                 metadata: self.metadata,
                 owner_badge: owner_badge,
                 mint_params: Some(MintParams::NonFungible { entries: encoded }),
+                non_fungible_data_schema: self.data_schema,
+                track_holders: self.track_holders,
             })
             .unwrap();
 
@@ -540,6 +679,8 @@ impl NonFungibleResourceBuilder {
                 metadata: self.metadata,
                 owner_badge: owner_badge,
                 mint_params: None,
+                non_fungible_data_schema: self.data_schema,
+                track_holders: self.track_holders,
             })
             .unwrap();
 
@@ -556,8 +697,10 @@ impl NonFungibleResourceBuilder {
 /// offer the `initial_supply_with_owner` and `no_initial_supply_with_owner` methods.
 pub struct NonFungibleResourceWithAuthBuilder {
     id_type: NonFungibleIdType,
-    metadata: HashMap<String, String>,
+    metadata: BTreeMap<String, String>,
     authorization: HashMap<ResourceMethodAuthKey, (AccessRule, AccessRule)>,
+    data_schema: Option<NonFungibleDataSchema>,
+    track_holders: bool,
 }
 
 impl NonFungibleResourceWithAuthBuilder {
@@ -570,6 +713,13 @@ impl NonFungibleResourceWithAuthBuilder {
         self
     }
 
+    /// Makes the resource manager keep a count of the vaults holding a non-zero balance of the
+    /// resource, readable through `ResourceManager::holder_vault_count`.
+    pub fn track_holders(mut self) -> Self {
+        self.track_holders = true;
+        self
+    }
+
     pub fn mintable<R: Into<AccessRule>>(mut self, method_auth: AccessRule, mutability: R) -> Self {
         self.authorization
             .insert(Mint, (method_auth, mutability.into()));
@@ -632,11 +782,31 @@ impl NonFungibleResourceWithAuthBuilder {
         self
     }
 
-    pub fn initial_supply<T, V>(self, entries: T) -> Bucket
+    /// Applies the rules of a template, overwriting any rules already set for the same methods.
+    pub fn with_access_rules_from_template(
+        mut self,
+        template: ResourceAccessRulesTemplate,
+    ) -> Self {
+        self.authorization.extend(template.into_access_rules());
+        self
+    }
+
+    /// Sets the type of the non-fungible data, which minted and updated data is then
+    /// validated against.
+    ///
+    /// This is implied by `initial_supply`, from the type of the entries.
+    pub fn data_schema<T: NonFungibleData>(mut self) -> Self {
+        self.data_schema = Some(NonFungibleDataSchema::new::<T>());
+        self
+    }
+
+    pub fn initial_supply<T, V>(mut self, entries: T) -> Bucket
     where
         T: IntoIterator<Item = (NonFungibleId, V)>,
         V: NonFungibleData,
     {
+        self.data_schema
+            .get_or_insert_with(NonFungibleDataSchema::new::<V>);
         let mut encoded = HashMap::new();
         for (id, e) in entries {
             encoded.insert(id, (e.immutable_data().unwrap(), e.mutable_data().unwrap()));
@@ -665,6 +835,8 @@ impl NonFungibleResourceWithAuthBuilder {
                 metadata: self.metadata,
                 access_rules: self.authorization,
                 mint_params,
+                non_fungible_data_schema: self.data_schema,
+                track_holders: self.track_holders,
             })
             .unwrap()
     }
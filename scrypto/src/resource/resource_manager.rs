@@ -6,7 +6,8 @@ use radix_engine_interface::math::Decimal;
 use radix_engine_interface::model::VaultMethodAuthKey::{Deposit, Recall, Withdraw};
 use radix_engine_interface::model::*;
 
-use sbor::rust::collections::HashMap;
+use sbor::rust::cell::RefCell;
+use sbor::rust::collections::{BTreeMap, HashMap};
 use sbor::rust::string::String;
 use sbor::rust::vec::Vec;
 use scrypto::engine::scrypto_env::ScryptoEnv;
@@ -15,11 +16,91 @@ use scrypto::scrypto_env_native_fn;
 use crate::scrypto;
 
 /// Represents a resource manager.
+///
+/// Reads of the resource type, total supply, metadata and mint/burn flags are served from a
+/// [`ResourceManagerSummary`] cached on first use, and the cache is dropped after any mutating call
+/// through this handle. Changes made elsewhere (e.g. by another component) are not observed until
+/// the cache is dropped.
 #[derive(Debug)]
-pub struct ResourceManager(pub(crate) ResourceAddress);
+pub struct ResourceManager(
+    pub(crate) ResourceAddress,
+    RefCell<Option<ResourceManagerSummary>>,
+);
 
 impl ResourceManager {
+    pub(crate) fn new(resource_address: ResourceAddress) -> Self {
+        Self(resource_address, RefCell::new(None))
+    }
+
+    /// Returns the summary of this resource manager, invoking the engine only if not yet cached.
+    pub fn summary(&self) -> ResourceManagerSummary {
+        if let Some(summary) = self.1.borrow().as_ref() {
+            return summary.clone();
+        }
+
+        let mut env = ScryptoEnv;
+        let summary = env
+            .invoke(ResourceManagerGetSummaryInvocation { receiver: self.0 })
+            .unwrap();
+        *self.1.borrow_mut() = Some(summary.clone());
+        summary
+    }
+
+    fn invalidate_summary(&self) {
+        *self.1.borrow_mut() = None;
+    }
+
+    pub fn resource_type(&self) -> ResourceType {
+        self.summary().resource_type
+    }
+
+    /// Returns the divisibility, or `None` for a non-fungible resource.
+    pub fn divisibility(&self) -> Option<u8> {
+        self.summary().divisibility()
+    }
+
+    pub fn total_supply(&self) -> Decimal {
+        self.summary().total_supply
+    }
+
+    pub fn metadata(&self) -> BTreeMap<String, String> {
+        self.summary().metadata
+    }
+
+    pub fn is_mintable(&self) -> bool {
+        self.summary().mintable
+    }
+
+    pub fn is_burnable(&self) -> bool {
+        self.summary().burnable
+    }
+
+    /// Returns the number of vaults holding a non-zero balance of this resource, or `None` if the
+    /// resource was not created with holder tracking.
+    ///
+    /// This is not cached, as it changes with every transfer between vaults.
+    pub fn holder_vault_count(&self) -> Option<u64> {
+        let mut env = ScryptoEnv;
+        env.invoke(ResourceManagerGetHolderVaultCountInvocation { receiver: self.0 })
+            .unwrap()
+    }
+
+    /// Returns the cumulative amount ever minted, including any initial supply.
+    pub fn amount_minted(&self) -> Decimal {
+        let mut env = ScryptoEnv;
+        env.invoke(ResourceManagerGetAmountMintedInvocation { receiver: self.0 })
+            .unwrap()
+    }
+
+    /// Returns the cumulative amount ever burned.
+    pub fn amount_burned(&self) -> Decimal {
+        let mut env = ScryptoEnv;
+        env.invoke(ResourceManagerGetAmountBurnedInvocation { receiver: self.0 })
+            .unwrap()
+    }
+
     pub fn set_metadata(&mut self, key: String, value: String) {
+        self.invalidate_summary();
         let mut env = ScryptoEnv;
         env.invoke(MetadataSetInvocation {
             receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
@@ -30,15 +111,11 @@ impl ResourceManager {
     }
 
     pub fn get_metadata(&mut self, key: String) -> Option<String> {
-        let mut env = ScryptoEnv;
-        env.invoke(MetadataGetInvocation {
-            receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
-            key,
-        })
-        .unwrap()
+        self.summary().metadata.remove(&key)
     }
 
     pub fn set_mintable(&mut self, access_rule: AccessRule) {
+        self.invalidate_summary();
         let mut env = ScryptoEnv;
         env.invoke(AccessRulesSetMethodAccessRuleInvocation {
             receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
@@ -52,6 +129,7 @@ impl ResourceManager {
     }
 
     pub fn set_burnable(&mut self, access_rule: AccessRule) -> () {
+        self.invalidate_summary();
         let mut env = ScryptoEnv;
         env.invoke(AccessRulesSetMethodAccessRuleInvocation {
             receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
@@ -65,6 +143,7 @@ impl ResourceManager {
     }
 
     pub fn set_withdrawable(&mut self, access_rule: AccessRule) -> () {
+        self.invalidate_summary();
         let mut env = ScryptoEnv;
         env.invoke(ResourceManagerUpdateVaultAuthInvocation {
             receiver: self.0,
@@ -75,6 +154,7 @@ impl ResourceManager {
     }
 
     pub fn set_depositable(&mut self, access_rule: AccessRule) {
+        self.invalidate_summary();
         let mut env = ScryptoEnv;
         env.invoke(ResourceManagerUpdateVaultAuthInvocation {
             receiver: self.0,
@@ -85,6 +165,7 @@ impl ResourceManager {
     }
 
     pub fn set_recallable(&mut self, access_rule: AccessRule) {
+        self.invalidate_summary();
         let mut env = ScryptoEnv;
         env.invoke(ResourceManagerUpdateVaultAuthInvocation {
             receiver: self.0,
@@ -95,6 +176,7 @@ impl ResourceManager {
     }
 
     pub fn set_updateable_metadata(&self, access_rule: AccessRule) {
+        self.invalidate_summary();
         let mut env = ScryptoEnv;
         env.invoke(AccessRulesSetMethodAccessRuleInvocation {
             receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
@@ -108,6 +190,7 @@ impl ResourceManager {
     }
 
     pub fn set_updateable_non_fungible_data(&self, access_rule: AccessRule) {
+        self.invalidate_summary();
         let mut env = ScryptoEnv;
         env.invoke(AccessRulesSetMethodAccessRuleInvocation {
             receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
@@ -121,6 +204,7 @@ impl ResourceManager {
     }
 
     pub fn lock_mintable(&mut self) {
+        self.invalidate_summary();
         let mut env = ScryptoEnv;
         env.invoke(AccessRulesSetMethodMutabilityInvocation {
             receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
@@ -134,6 +218,7 @@ impl ResourceManager {
     }
 
     pub fn lock_burnable(&mut self) {
+        self.invalidate_summary();
         let mut env = ScryptoEnv;
         env.invoke(AccessRulesSetMethodMutabilityInvocation {
             receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
@@ -147,6 +232,7 @@ impl ResourceManager {
     }
 
     pub fn lock_updateable_metadata(&mut self) {
+        self.invalidate_summary();
         let mut env = ScryptoEnv;
         env.invoke(AccessRulesSetMethodMutabilityInvocation {
             receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
@@ -160,6 +246,7 @@ impl ResourceManager {
     }
 
     pub fn lock_updateable_non_fungible_data(&mut self) {
+        self.invalidate_summary();
         let mut env = ScryptoEnv;
         env.invoke(AccessRulesSetMethodMutabilityInvocation {
             receiver: RENodeId::Global(GlobalAddress::Resource(self.0)),
@@ -173,6 +260,7 @@ impl ResourceManager {
     }
 
     pub fn lock_withdrawable(&mut self) {
+        self.invalidate_summary();
         let mut env = ScryptoEnv;
         env.invoke(ResourceManagerSetVaultAuthMutabilityInvocation {
             receiver: self.0,
@@ -183,6 +271,7 @@ impl ResourceManager {
     }
 
     pub fn lock_depositable(&mut self) {
+        self.invalidate_summary();
         let mut env = ScryptoEnv;
         env.invoke(ResourceManagerSetVaultAuthMutabilityInvocation {
             receiver: self.0,
@@ -193,6 +282,7 @@ impl ResourceManager {
     }
 
     pub fn lock_recallable(&mut self) {
+        self.invalidate_summary();
         let mut env = ScryptoEnv;
         env.invoke(ResourceManagerSetVaultAuthMutabilityInvocation {
             receiver: self.0,
@@ -203,6 +293,7 @@ impl ResourceManager {
     }
 
     fn mint_internal(&mut self, mint_params: MintParams) -> Bucket {
+        self.invalidate_summary();
         let mut env = ScryptoEnv;
         env.invoke(ResourceManagerMintInvocation {
             mint_params,
@@ -212,6 +303,7 @@ impl ResourceManager {
     }
 
     fn update_non_fungible_data_internal(&mut self, id: NonFungibleId, data: Vec<u8>) {
+        self.invalidate_summary();
         let mut env = ScryptoEnv;
         env.invoke(ResourceManagerUpdateNonFungibleDataInvocation {
             id,
@@ -230,29 +322,34 @@ impl ResourceManager {
         .unwrap()
     }
 
+    pub fn burn(&mut self, bucket: Bucket) {
+        self.invalidate_summary();
+        let mut env = ScryptoEnv;
+        env.invoke(ResourceManagerBurnInvocation {
+            receiver: self.0,
+            bucket: Bucket(bucket.0),
+        })
+        .unwrap()
+    }
+
+    /// Creates a proof of the given amount of this fungible resource, vouched for by the
+    /// resource manager rather than by any tokens in a vault or bucket.
+    pub fn create_proof_of_amount(&self, amount: Decimal) -> Proof {
+        let mut env = ScryptoEnv;
+        env.invoke(ResourceManagerCreateSyntheticProofInvocation {
+            receiver: self.0,
+            amount,
+        })
+        .unwrap()
+    }
+
     scrypto_env_native_fn! {
-        pub fn resource_type(&self) -> ResourceType {
-            ResourceManagerGetResourceTypeInvocation {
-                receiver: self.0,
-            }
-        }
-        pub fn total_supply(&self) -> Decimal {
-            ResourceManagerGetTotalSupplyInvocation {
-                receiver: self.0,
-            }
-        }
         pub fn non_fungible_exists(&self, id: &NonFungibleId) -> bool {
             ResourceManagerNonFungibleExistsInvocation {
                 receiver: self.0,
                 id: id.clone()
             }
         }
-        pub fn burn(&mut self, bucket: Bucket) -> () {
-            ResourceManagerBurnInvocation {
-                receiver: self.0,
-                bucket: Bucket(bucket.0),
-            }
-        }
     }
 
     /// Mints fungible resources
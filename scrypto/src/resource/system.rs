@@ -31,7 +31,7 @@ impl ResourceSystem {
     ) -> &mut ResourceManager {
         self.resource_managers
             .entry(resource_address)
-            .or_insert(ResourceManager(resource_address))
+            .or_insert_with(|| ResourceManager::new(resource_address))
     }
 }
 
@@ -0,0 +1,97 @@
+use radix_engine_derive::Describe;
+use radix_engine_interface::api::api::Invokable;
+use radix_engine_interface::math::Decimal;
+use radix_engine_interface::model::*;
+use sbor::rust::fmt::Debug;
+use sbor::*;
+use scrypto::engine::scrypto_env::ScryptoEnv;
+
+use crate::resource::*;
+use crate::scrypto;
+
+/// A vault whose contents are only released to its beneficiary once a target epoch is reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[scrypto(TypeId, Encode, Decode, Describe)]
+pub struct TimeLockedVault(pub SystemAddress);
+
+impl TimeLockedVault {
+    /// Creates an empty time-locked vault.
+    ///
+    /// Anyone may deposit into it. The `beneficiary_rule` must be met to withdraw once the epoch
+    /// reaches `unlock_epoch`, and the `depositor_rule` must be met to extend the lock.
+    pub fn new(
+        resource_address: ResourceAddress,
+        beneficiary_rule: AccessRule,
+        depositor_rule: AccessRule,
+        unlock_epoch: u64,
+    ) -> Self {
+        let mut env = ScryptoEnv;
+        let address = env
+            .invoke(TimeLockedVaultCreateInvocation {
+                resource_address,
+                beneficiary_rule,
+                depositor_rule,
+                unlock_epoch,
+            })
+            .unwrap();
+        Self(address)
+    }
+
+    /// Creates a time-locked vault and fills it with an initial bucket of resource.
+    pub fn with_bucket(
+        bucket: Bucket,
+        beneficiary_rule: AccessRule,
+        depositor_rule: AccessRule,
+        unlock_epoch: u64,
+    ) -> Self {
+        let vault = Self::new(
+            bucket.resource_address(),
+            beneficiary_rule,
+            depositor_rule,
+            unlock_epoch,
+        );
+        vault.deposit(bucket);
+        vault
+    }
+
+    pub fn deposit(&self, bucket: Bucket) {
+        let mut env = ScryptoEnv;
+        env.invoke(TimeLockedVaultDepositInvocation {
+            receiver: self.0,
+            bucket,
+        })
+        .unwrap()
+    }
+
+    /// Withdraws from the vault, which fails while the vault is still locked.
+    pub fn withdraw<A: Into<Decimal>>(&self, amount: A) -> Bucket {
+        let mut env = ScryptoEnv;
+        env.invoke(TimeLockedVaultWithdrawInvocation {
+            receiver: self.0,
+            amount: amount.into(),
+        })
+        .unwrap()
+    }
+
+    /// Moves the unlock epoch to `unlock_epoch`, which must not be earlier than the current one.
+    pub fn extend_lock(&self, unlock_epoch: u64) {
+        let mut env = ScryptoEnv;
+        env.invoke(TimeLockedVaultExtendLockInvocation {
+            receiver: self.0,
+            unlock_epoch,
+        })
+        .unwrap()
+    }
+
+    pub fn unlock_epoch(&self) -> u64 {
+        let mut env = ScryptoEnv;
+        env.invoke(TimeLockedVaultGetUnlockEpochInvocation { receiver: self.0 })
+            .unwrap()
+    }
+
+    pub fn amount(&self) -> Decimal {
+        let mut env = ScryptoEnv;
+        env.invoke(TimeLockedVaultGetAmountInvocation { receiver: self.0 })
+            .unwrap()
+    }
+}
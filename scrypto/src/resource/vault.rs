@@ -1,4 +1,5 @@
 use radix_engine_interface::api::api::Invokable;
+use radix_engine_interface::api::types::ScryptoFunctionIdent;
 use radix_engine_interface::math::Decimal;
 use radix_engine_interface::model::*;
 use sbor::rust::collections::BTreeSet;
@@ -16,6 +17,11 @@ pub trait ScryptoVault {
     fn take_internal(&mut self, amount: Decimal) -> Bucket;
     fn lock_fee_internal(&mut self, amount: Decimal) -> ();
     fn lock_contingent_fee_internal(&mut self, amount: Decimal) -> ();
+    fn lock_fee_with_callback_internal(
+        &mut self,
+        amount: Decimal,
+        callback: ScryptoFunctionIdent,
+    ) -> ();
     fn put(&mut self, bucket: Bucket) -> ();
     fn take_non_fungibles(&mut self, non_fungible_ids: &BTreeSet<NonFungibleId>) -> Bucket;
     fn resource_address(&self) -> ResourceAddress;
@@ -25,6 +31,11 @@ pub trait ScryptoVault {
     fn create_proof_by_ids(&self, ids: &BTreeSet<NonFungibleId>) -> Proof;
     fn lock_fee<A: Into<Decimal>>(&mut self, amount: A);
     fn lock_contingent_fee<A: Into<Decimal>>(&mut self, amount: A);
+    fn lock_fee_with_callback<A: Into<Decimal>>(
+        &mut self,
+        amount: A,
+        callback: ScryptoFunctionIdent,
+    );
     fn take<A: Into<Decimal>>(&mut self, amount: A) -> Bucket;
     fn take_all(&mut self) -> Bucket;
     fn take_non_fungible(&mut self, non_fungible_id: &NonFungibleId) -> Bucket;
@@ -80,6 +91,19 @@ impl ScryptoVault for Vault {
             }
         }
 
+        fn lock_fee_with_callback_internal(
+            &mut self,
+            amount: Decimal,
+            callback: ScryptoFunctionIdent
+        ) -> () {
+            VaultLockFeeWithCallbackInvocation {
+                receiver: self.0,
+                amount,
+                contingent: false,
+                callback,
+            }
+        }
+
 
         fn put(&mut self, bucket: Bucket) -> () {
             VaultPutInvocation {
@@ -137,6 +161,19 @@ impl ScryptoVault for Vault {
         self.lock_contingent_fee_internal(amount.into())
     }
 
+    /// Locks the specified amount as transaction fee, and registers a function to be called at
+    /// the end of the transaction with the amount charged to this vault.
+    ///
+    /// The callback takes a single `Decimal` argument. The amount excludes the cost of running
+    /// the callbacks themselves.
+    fn lock_fee_with_callback<A: Into<Decimal>>(
+        &mut self,
+        amount: A,
+        callback: ScryptoFunctionIdent,
+    ) {
+        self.lock_fee_with_callback_internal(amount.into(), callback)
+    }
+
     /// Takes some amount of resource from this vault into a bucket.
     fn take<A: Into<Decimal>>(&mut self, amount: A) -> Bucket {
         let bucket = self.take_internal(amount.into());
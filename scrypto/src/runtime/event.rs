@@ -0,0 +1,30 @@
+use radix_engine_interface::api::types::ScryptoActor;
+use radix_engine_interface::crypto::Hash;
+use radix_engine_interface::data::{scrypto_encode, ScryptoEncode};
+use radix_engine_interface::model::scrypto_event_discriminant;
+
+use crate::runtime::Runtime;
+
+/// An event which can be emitted by a blueprint, usually implemented with `#[event]`.
+pub trait EmittableEvent: ScryptoEncode + Sized {
+    /// The name of the event, which is unique within a blueprint.
+    const EVENT_NAME: &'static str;
+
+    /// Returns the discriminant of this event when emitted by the running blueprint.
+    fn discriminant() -> Hash {
+        let (package_address, blueprint_name) = match Runtime::actor() {
+            ScryptoActor::Blueprint(package_address, blueprint_name)
+            | ScryptoActor::Component(_, package_address, blueprint_name) => {
+                (package_address, blueprint_name)
+            }
+        };
+        scrypto_event_discriminant(&package_address, &blueprint_name, Self::EVENT_NAME)
+    }
+
+    /// Emits this event, prefixed with its discriminant.
+    fn emit(self) {
+        let mut payload = Self::discriminant().to_vec();
+        payload.extend(scrypto_encode(&self).unwrap());
+        Runtime::emit_event(payload);
+    }
+}
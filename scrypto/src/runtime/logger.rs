@@ -3,14 +3,41 @@ use radix_engine_interface::api::types::Level;
 use sbor::rust::string::String;
 use scrypto::engine::scrypto_env::ScryptoEnv;
 
+/// The most verbose log level compiled in, as selected by the `max-level-*` features.
+///
+/// When several are enabled, the most restrictive one wins.
+pub const MAX_LEVEL: Option<Level> = if cfg!(feature = "max-level-off") {
+    None
+} else if cfg!(feature = "max-level-error") {
+    Some(Level::Error)
+} else if cfg!(feature = "max-level-warn") {
+    Some(Level::Warn)
+} else if cfg!(feature = "max-level-info") {
+    Some(Level::Info)
+} else if cfg!(feature = "max-level-debug") {
+    Some(Level::Debug)
+} else {
+    Some(Level::Trace)
+};
+
 /// A utility for logging messages.
 #[derive(Debug)]
 pub struct Logger {}
 
 impl Logger {
-    /// Emits a log to console.
+    /// Returns whether messages of the given level are compiled in.
+    pub const fn is_enabled(level: Level) -> bool {
+        match MAX_LEVEL {
+            Some(max_level) => level as u8 <= max_level as u8,
+            None => false,
+        }
+    }
+
+    /// Emits a log to console, unless the level is compiled out.
     pub fn log(level: Level, message: String) {
-        ScryptoEnv.emit_log(level, message).unwrap();
+        if Self::is_enabled(level) {
+            ScryptoEnv.emit_log(level, message).unwrap();
+        }
     }
 
     /// Emits a trace message.
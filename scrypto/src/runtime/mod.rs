@@ -1,10 +1,12 @@
 mod clock;
 mod data;
+mod event;
 mod logger;
 mod runtime;
 
 pub use clock::*;
 pub use data::*;
-pub use logger::Logger;
+pub use event::EmittableEvent;
+pub use logger::{Logger, MAX_LEVEL};
 pub use radix_engine_interface::core::expression::*;
 pub use runtime::*;
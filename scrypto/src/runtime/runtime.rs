@@ -1,8 +1,8 @@
 use radix_engine_interface::api::api::{EngineApi, Invokable};
 use radix_engine_interface::api::types::{
-    ScryptoActor, ScryptoFunctionIdent, ScryptoMethodIdent, ScryptoPackage, ScryptoReceiver,
+    CallerInfo, ScryptoActor, ScryptoFunctionIdent, ScryptoMethodIdent, ScryptoPackage,
+    ScryptoReceiver,
 };
-use radix_engine_interface::constants::EPOCH_MANAGER;
 use radix_engine_interface::crypto::*;
 use radix_engine_interface::data::{scrypto_decode, ScryptoDecode};
 use radix_engine_interface::model::*;
@@ -20,10 +20,7 @@ impl Runtime {
     /// Returns the current epoch
     pub fn current_epoch() -> u64 {
         let mut env = ScryptoEnv;
-        env.invoke(EpochManagerGetCurrentEpochInvocation {
-            receiver: EPOCH_MANAGER,
-        })
-        .unwrap()
+        env.sys_get_current_epoch().unwrap()
     }
 
     /// Returns the running entity.
@@ -32,6 +29,13 @@ impl Runtime {
         env.sys_get_actor().unwrap()
     }
 
+    /// Returns the entity which directly invoked the running entity, or `None` if invoked
+    /// by the transaction processor.
+    pub fn caller() -> Option<CallerInfo> {
+        let mut env = ScryptoEnv;
+        env.sys_get_caller().unwrap()
+    }
+
     /// Returns the current package address.
     pub fn package_address() -> PackageAddress {
         match Self::actor() {
@@ -40,12 +44,24 @@ impl Runtime {
         }
     }
 
+    /// Returns the resources which the transaction can create virtual proofs for.
+    pub fn virtual_proof_resources() -> Vec<ResourceAddress> {
+        let mut env = ScryptoEnv;
+        env.sys_get_virtual_proof_resources().unwrap()
+    }
+
     /// Generates a UUID.
     pub fn generate_uuid() -> u128 {
         let mut env = ScryptoEnv;
         env.sys_generate_uuid().unwrap()
     }
 
+    /// Emits an event payload, which is recorded in the transaction receipt.
+    pub fn emit_event(payload: Vec<u8>) {
+        let mut env = ScryptoEnv;
+        env.sys_emit_event(payload).unwrap()
+    }
+
     /// Invokes a function on a blueprint.
     pub fn call_function<S1: AsRef<str>, S2: AsRef<str>, T: ScryptoDecode>(
         package_address: PackageAddress,
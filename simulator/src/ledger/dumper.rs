@@ -274,7 +274,7 @@ fn dump_resources<T: ReadableSubstateStore, O: std::io::Write>(
                     .map(|s| s.substate.to_runtime())
                     .map(|s| s.into())
                     .unwrap();
-                if let Some(non_fungible) = non_fungible.0 {
+                if let Some(non_fungible) = non_fungible.non_fungible {
                     let id = IndexedScryptoValue::from_typed(id);
                     let immutable_data =
                         IndexedScryptoValue::from_slice(&non_fungible.immutable_data()).unwrap();
@@ -359,5 +359,108 @@ pub fn dump_resource_manager<T: ReadableSubstateStore, O: std::io::Write>(
         "Total Supply".green().bold(),
         resource_manager.total_supply
     );
+    writeln!(
+        output,
+        "{}: {}",
+        "Amount Minted".green().bold(),
+        resource_manager.amount_minted
+    );
+    writeln!(
+        output,
+        "{}: {}",
+        "Amount Burned".green().bold(),
+        resource_manager.amount_burned
+    );
+    Ok(())
+}
+
+/// Dump the components holding a resource into console, largest balance first.
+pub fn dump_resource_holders<
+    T: ReadableSubstateStore + QueryableSubstateStore,
+    O: std::io::Write,
+>(
+    resource_address: ResourceAddress,
+    components: &[ComponentAddress],
+    top: Option<usize>,
+    substate_store: &T,
+    output: &mut O,
+) -> Result<(), DisplayError> {
+    let bech32_encoder = Bech32Encoder::new(&NetworkDefinition::simulator());
+
+    // Substates only reference their children, so map vaults to owners from the top down.
+    let mut owners: HashMap<VaultId, ComponentAddress> = HashMap::new();
+    for component_address in components {
+        for vault_id in owned_vaults(*component_address, substate_store) {
+            owners.insert(vault_id, *component_address);
+        }
+    }
+
+    let mut holders: HashMap<String, Decimal> = HashMap::new();
+    for vault_id in substate_store.get_resource_vaults(&resource_address) {
+        let vault: VaultSubstate = substate_store
+            .get_substate(&SubstateId(
+                RENodeId::Vault(vault_id),
+                SubstateOffset::Vault(VaultOffset::Vault),
+            ))
+            .map(|s| s.substate)
+            .map(|s| s.into())
+            .unwrap();
+        let holder = match owners.get(&vault_id) {
+            Some(component_address) => component_address.display(&bech32_encoder).to_string(),
+            None => hex::encode(vault_id),
+        };
+        *holders.entry(holder).or_default() += vault.0.amount();
+    }
+
+    let mut holders: Vec<(String, Decimal)> = holders.into_iter().collect();
+    holders.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    if let Some(top) = top {
+        holders.truncate(top);
+    }
+
+    writeln!(output, "{}: {}", "Holders".green().bold(), holders.len());
+    for (last, (holder, amount)) in holders.iter().identify_last() {
+        writeln!(output, "{} {}: {}", list_item_prefix(last), holder, amount);
+    }
     Ok(())
 }
+
+/// Find all vaults owned by a component, assuming a tree structure.
+fn owned_vaults<T: ReadableSubstateStore + QueryableSubstateStore>(
+    component_address: ComponentAddress,
+    substate_store: &T,
+) -> HashSet<VaultId> {
+    let mut vaults_found = HashSet::new();
+    let state: Option<ComponentStateSubstate> = substate_store
+        .get_substate(&SubstateId(
+            RENodeId::Global(GlobalAddress::Component(component_address)),
+            SubstateOffset::Global(GlobalOffset::Global),
+        ))
+        .map(|s| s.substate.to_runtime().global().node_deref())
+        .and_then(|component_id| {
+            substate_store.get_substate(&SubstateId(
+                component_id,
+                SubstateOffset::Component(ComponentOffset::State),
+            ))
+        })
+        .map(|s| s.substate)
+        .map(|s| s.to_runtime().into());
+    let state = match state {
+        Some(state) => state,
+        None => return vaults_found,
+    };
+
+    let state_data = IndexedScryptoValue::from_slice(&state.raw).unwrap();
+    vaults_found.extend(state_data.vault_ids.iter().cloned());
+    let mut queue: VecDeque<KeyValueStoreId> = state_data.kv_store_ids.iter().cloned().collect();
+    while let Some(kv_store_id) = queue.pop_front() {
+        for (_, v) in substate_store.get_kv_store_entries(&kv_store_id) {
+            if let Some(v) = &v.to_runtime().kv_store_entry().0 {
+                let value = IndexedScryptoValue::from_slice(v).unwrap();
+                queue.extend(value.kv_store_ids);
+                vaults_found.extend(value.vault_ids);
+            }
+        }
+    }
+    vaults_found
+}
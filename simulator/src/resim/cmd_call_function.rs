@@ -21,7 +21,7 @@ pub struct CallFunction {
     /// The call arguments, e.g. \"5\", \"hello\", \"amount,resource_address\" for Bucket, or \"#id1,#id2,..,resource_address\" for non-fungible Bucket
     arguments: Vec<String>,
 
-    /// The proofs to add to the auth zone
+    /// The proofs to add to the auth zone, as `<resource_address>[:<amount>|:#<id1>,#<id2>]`
     #[clap(short, long, multiple = true)]
     proofs: Option<Vec<String>>,
 
@@ -40,22 +40,32 @@ pub struct CallFunction {
     /// Turn on tracing
     #[clap(short, long)]
     trace: bool,
+
+    /// The package source directory to watch; the package is rebuilt and the function called again on every change
+    #[cfg(feature = "watch")]
+    #[clap(long)]
+    watch: Option<PathBuf>,
 }
 
 impl CallFunction {
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        #[cfg(feature = "watch")]
+        if let Some(source_dir) = &self.watch {
+            return watch_package(source_dir, || {
+                republish_package(source_dir, self.package_address.0, out)?;
+                self.call(out)
+            });
+        }
+
+        self.call(out)
+    }
+
+    fn call<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
         let default_account = get_default_account()?;
         let proofs = self.proofs.clone().unwrap_or_default();
 
-        let mut manifest_builder = &mut ManifestBuilder::new(&NetworkDefinition::simulator());
-        for resource_specifier in proofs {
-            manifest_builder = manifest_builder
-                .create_proof_from_account_by_resource_specifier(
-                    default_account,
-                    resource_specifier,
-                )
-                .map_err(Error::FailedToBuildArgs)?;
-        }
+        let mut manifest_builder = ManifestBuilder::new(&NetworkDefinition::simulator());
+        create_proofs_from_account(&mut manifest_builder, default_account, &proofs)?;
 
         let manifest = manifest_builder
             .lock_fee(FAUCET_COMPONENT, 100.into())
@@ -74,7 +84,7 @@ impl CallFunction {
                 args!(Expression::entire_worktop()),
             )
             .build();
-        handle_manifest(
+        let result = handle_manifest(
             manifest,
             &self.signing_keys,
             &self.network,
@@ -83,7 +93,7 @@ impl CallFunction {
             true,
             false,
             out,
-        )
-        .map(|_| ())
+        );
+        with_attempted_proofs(result, &proofs).map(|_| ())
     }
 }
@@ -20,7 +20,7 @@ pub struct CallMethod {
     /// The call arguments
     arguments: Vec<String>,
 
-    /// The proofs to add to the auth zone
+    /// The proofs to add to the auth zone, as `<resource_address>[:<amount>|:#<id1>,#<id2>]`
     #[clap(short, long, multiple = true)]
     proofs: Option<Vec<String>>,
 
@@ -46,15 +46,8 @@ impl CallMethod {
         let default_account = get_default_account()?;
         let proofs = self.proofs.clone().unwrap_or_default();
 
-        let mut manifest_builder = &mut ManifestBuilder::new(&NetworkDefinition::simulator());
-        for resource_specifier in proofs {
-            manifest_builder = manifest_builder
-                .create_proof_from_account_by_resource_specifier(
-                    default_account,
-                    resource_specifier,
-                )
-                .map_err(Error::FailedToBuildArgs)?;
-        }
+        let mut manifest_builder = ManifestBuilder::new(&NetworkDefinition::simulator());
+        create_proofs_from_account(&mut manifest_builder, default_account, &proofs)?;
 
         let manifest = manifest_builder
             .lock_fee(FAUCET_COMPONENT, 100.into())
@@ -72,7 +65,7 @@ impl CallMethod {
                 args!(Expression::entire_worktop()),
             )
             .build();
-        handle_manifest(
+        let result = handle_manifest(
             manifest,
             &self.signing_keys,
             &self.network,
@@ -81,7 +74,7 @@ impl CallMethod {
             true,
             false,
             out,
-        )
-        .map(|_| ())
+        );
+        with_attempted_proofs(result, &proofs).map(|_| ())
     }
 }
@@ -0,0 +1,139 @@
+use clap::{Parser, Subcommand};
+use colored::*;
+use radix_engine_stores::rocks_db::RadixEngineDB;
+
+use crate::resim::*;
+use crate::utils::*;
+
+/// Maintain the ledger database
+#[derive(Parser, Debug)]
+pub struct Db {
+    #[clap(subcommand)]
+    command: DbCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum DbCommand {
+    Stats(DbStats),
+    Prune(DbPrune),
+}
+
+/// Show statistics about the substates in the ledger
+#[derive(Parser, Debug)]
+struct DbStats {}
+
+/// Remove deleted key value store entries and burnt non-fungibles from the ledger
+#[derive(Parser, Debug)]
+struct DbPrune {
+    /// Only remove entries last written before this substate version
+    #[clap(long)]
+    before: u64,
+}
+
+impl Db {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let mut ledger = RadixEngineDB::with_bootstrap(get_data_dir()?);
+
+        match &self.command {
+            DbCommand::Stats(_) => {
+                let stats = ledger.stats();
+
+                writeln!(out, "{}:", "Substates".green().bold()).map_err(Error::IOError)?;
+                for (last, (substate_type, count)) in
+                    stats.substate_count_by_type.iter().identify_last()
+                {
+                    writeln!(
+                        out,
+                        "{} {}: {}",
+                        list_item_prefix(last),
+                        substate_type,
+                        count
+                    )
+                    .map_err(Error::IOError)?;
+                }
+                writeln!(
+                    out,
+                    "{}: {} bytes",
+                    "Total Size".green().bold(),
+                    stats.total_bytes
+                )
+                .map_err(Error::IOError)?;
+                writeln!(
+                    out,
+                    "{}: {} ({} bytes)",
+                    "Tombstones".green().bold(),
+                    stats.tombstone_count,
+                    stats.tombstone_bytes
+                )
+                .map_err(Error::IOError)?;
+            }
+            DbCommand::Prune(cmd) => {
+                let pruned = ledger.prune(cmd.before);
+                writeln!(out, "Pruned {} tombstones.", pruned).map_err(Error::IOError)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use radix_engine::ledger::*;
+
+    fn kv_entry(n: u8) -> SubstateId {
+        SubstateId::kv_entry([n; 36], scrypto_encode(&n).unwrap())
+    }
+
+    #[test]
+    fn test_prune_removes_old_tombstones_only() {
+        let mut data_dir = std::env::temp_dir();
+        data_dir.push(format!("resim-test-{}", uuid::Uuid::new_v4()));
+
+        // Arrange
+        let mut ledger = RadixEngineDB::with_bootstrap(data_dir.clone());
+        let entries = [
+            (kv_entry(1), Some(vec![1u8]), 3),
+            (kv_entry(2), None, 1),
+            (kv_entry(3), None, 5),
+        ];
+        let mut leaves = Vec::new();
+        for (substate_id, value, version) in entries {
+            let output_value = OutputValue {
+                substate: PersistedSubstate::KeyValueStoreEntry(KeyValueStoreEntrySubstate(value)),
+                version,
+            };
+            leaves.push((
+                substate_id.clone(),
+                substate_leaf_hash(&substate_id, &output_value),
+            ));
+            ledger.put_substate(substate_id, output_value);
+        }
+        update_state_tree(&mut ledger, leaves);
+        let before = ledger.stats();
+
+        // Act
+        let pruned = ledger.prune(2);
+
+        // Assert
+        let after = ledger.stats();
+        assert_eq!(pruned, 1);
+        assert_eq!(after.tombstone_count, before.tombstone_count - 1);
+        assert!(after.total_bytes < before.total_bytes);
+        assert_eq!(
+            after.substate_count_by_type["KeyValueStoreEntry"],
+            before.substate_count_by_type["KeyValueStoreEntry"] - 1
+        );
+        assert!(ledger.get_substate(&kv_entry(1)).is_some());
+        assert!(ledger.get_substate(&kv_entry(2)).is_none());
+        assert!(ledger.get_substate(&kv_entry(3)).is_some());
+        assert!(ledger
+            .get_substate(&SubstateId::global(GlobalAddress::Resource(RADIX_TOKEN)))
+            .is_some());
+        assert_eq!(verify_state_root(&ledger), Ok(state_root_hash(&ledger)));
+
+        drop(ledger);
+        fs::remove_dir_all(data_dir).unwrap();
+    }
+}
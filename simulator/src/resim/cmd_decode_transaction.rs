@@ -0,0 +1,130 @@
+use clap::Parser;
+use colored::*;
+use radix_engine::types::*;
+use transaction::manifest::decompile;
+use transaction::model::NotarizedTransaction;
+use transaction::validation::recover;
+
+use crate::resim::*;
+use crate::utils::*;
+
+/// Decodes a hex-encoded notarized transaction, printing its manifest, signers and hash
+#[derive(Parser, Debug)]
+pub struct DecodeTransaction {
+    /// The notarized transaction in hex, as produced by the `sign` command
+    #[clap(long)]
+    blob: String,
+
+    /// The network the transaction was signed for, [simulator | adapanet | nebunet | mainnet]
+    #[clap(short, long)]
+    network: Option<String>,
+}
+
+impl DecodeTransaction {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let network = match &self.network {
+            Some(n) => NetworkDefinition::from_str(&n).map_err(Error::ParseNetworkError)?,
+            None => NetworkDefinition::simulator(),
+        };
+        let transaction = decode_transaction(&self.blob)?;
+        let intent = &transaction.signed_intent.intent;
+        let intent_payload = intent.to_bytes().map_err(Error::EncodeError)?;
+        let manifest =
+            decompile(&intent.manifest.instructions, &network).map_err(Error::DecompileError)?;
+
+        writeln!(
+            out,
+            "{}: {}",
+            "Transaction Hash".green().bold(),
+            transaction.hash().map_err(Error::EncodeError)?
+        )
+        .map_err(Error::IOError)?;
+        writeln!(
+            out,
+            "{}: {}",
+            "Intent Hash".green().bold(),
+            transaction.intent_hash().map_err(Error::EncodeError)?
+        )
+        .map_err(Error::IOError)?;
+        writeln!(
+            out,
+            "{}: {}",
+            "Notary Key".green().bold(),
+            public_key_to_string(&intent.header.notary_public_key)
+        )
+        .map_err(Error::IOError)?;
+        writeln!(out, "{}:", "Signer Keys".green().bold()).map_err(Error::IOError)?;
+        for (last, signature) in transaction
+            .signed_intent
+            .intent_signatures
+            .iter()
+            .identify_last()
+        {
+            let public_key = recover(&intent_payload, signature)
+                .map(|public_key| public_key_to_string(&public_key))
+                .unwrap_or_else(|| "<invalid signature>".to_owned());
+            writeln!(out, "{} {}", list_item_prefix(last), public_key).map_err(Error::IOError)?;
+        }
+        writeln!(out, "{}:", "Manifest".green().bold()).map_err(Error::IOError)?;
+        write!(out, "{}", manifest).map_err(Error::IOError)?;
+        Ok(())
+    }
+}
+
+/// Decodes a hex-encoded notarized transaction.
+pub fn decode_transaction(blob: &str) -> Result<NotarizedTransaction, Error> {
+    let bytes = hex::decode(blob.trim()).map_err(|_| Error::InvalidTransactionBlob)?;
+    NotarizedTransaction::from_slice(&bytes).map_err(Error::DataError)
+}
+
+fn public_key_to_string(public_key: &PublicKey) -> String {
+    match public_key {
+        PublicKey::EcdsaSecp256k1(public_key) => public_key.to_string(),
+        PublicKey::EddsaEd25519(public_key) => public_key.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use transaction::builder::ManifestBuilder;
+    use transaction::manifest::compile;
+    use transaction::signing::EcdsaSecp256k1PrivateKey;
+
+    #[test]
+    fn test_decoded_transaction_matches_signed_manifest() {
+        let private_key = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap();
+        let network = NetworkDefinition::simulator();
+        let manifest = ManifestBuilder::new(&network)
+            .lock_fee(FAUCET_COMPONENT, 10.into())
+            .clear_auth_zone()
+            .build();
+        let blob = hex::encode(
+            sign_manifest(manifest.clone(), &private_key, &network, 0, 5)
+                .to_bytes()
+                .unwrap(),
+        );
+
+        let transaction = decode_transaction(&blob).unwrap();
+        let decompiled = decompile(
+            &transaction.signed_intent.intent.manifest.instructions,
+            &network,
+        )
+        .unwrap();
+        assert_eq!(
+            compile(&decompiled, &network, vec![]).unwrap().instructions,
+            manifest.instructions
+        );
+
+        let mut out = Vec::new();
+        DecodeTransaction {
+            blob,
+            network: None,
+        }
+        .run(&mut out)
+        .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains(&decompiled));
+        assert!(out.contains(&private_key.public_key().to_string()));
+    }
+}
@@ -1,6 +1,9 @@
 use clap::Parser;
+use colored::*;
 use radix_engine::types::*;
 use radix_engine_interface::abi;
+use std::fs;
+use std::path::PathBuf;
 
 use crate::resim::*;
 
@@ -13,6 +16,14 @@ pub struct ExportAbi {
     /// The blueprint name
     blueprint_name: String,
 
+    /// Also write Rust bindings for calling the blueprint to this file
+    #[clap(long)]
+    rust_bindings: Option<PathBuf>,
+
+    /// Instead of the ABI, output its changes from an ABI previously exported to this file
+    #[clap(long)]
+    diff: Option<PathBuf>,
+
     /// Turn on tracing.
     #[clap(short, long)]
     trace: bool,
@@ -27,15 +38,65 @@ impl ExportAbi {
                     blueprint_name: self.blueprint_name.clone(),
                     abi: a,
                 };
-                writeln!(
-                    out,
-                    "{}",
-                    serde_json::to_string_pretty(&blueprint).map_err(Error::JSONError)?
-                )
-                .map_err(Error::IOError)?;
+                if let Some(path) = &self.diff {
+                    let previous: abi::Blueprint = serde_json::from_slice(
+                        &fs::read(path).map_err(|err| Error::IOErrorAtPath(err, path.clone()))?,
+                    )
+                    .map_err(Error::JSONError)?;
+                    write_abi_diff(
+                        &abi::compare_blueprint_abis(&previous.abi, &blueprint.abi),
+                        out,
+                    )?;
+                } else {
+                    writeln!(
+                        out,
+                        "{}",
+                        serde_json::to_string_pretty(&blueprint).map_err(Error::JSONError)?
+                    )
+                    .map_err(Error::IOError)?;
+                }
+
+                if let Some(path) = &self.rust_bindings {
+                    let bindings = generate_rust_bindings(
+                        &self.package_address.to_string(),
+                        &self.blueprint_name,
+                        &blueprint.abi,
+                    );
+                    fs::write(path, bindings)
+                        .map_err(|err| Error::IOErrorAtPath(err, path.clone()))?;
+                }
                 Ok(())
             }
             Err(e) => Err(e),
         }
     }
 }
+
+fn write_abi_diff<O: std::io::Write>(diff: &abi::AbiDiff, out: &mut O) -> Result<(), Error> {
+    if diff.changes.is_empty() {
+        writeln!(out, "No ABI changes.").map_err(Error::IOError)?;
+        return Ok(());
+    }
+
+    for change in &diff.changes {
+        let class = if change.is_breaking() {
+            "breaking".red()
+        } else {
+            "compatible".green()
+        };
+        writeln!(out, "[{}] {}", class, change).map_err(Error::IOError)?;
+    }
+    let breaking = diff.breaking_changes().len();
+    if breaking > 0 {
+        writeln!(
+            out,
+            "{}",
+            format!("{} breaking change(s)", breaking).red().bold()
+        )
+        .map_err(Error::IOError)?;
+    } else {
+        writeln!(out, "{}", "The new ABI is backwards compatible.".green())
+            .map_err(Error::IOError)?;
+    }
+    Ok(())
+}
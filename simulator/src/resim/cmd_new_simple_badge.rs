@@ -57,7 +57,7 @@ impl NewSimpleBadge {
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
         let network_definition = NetworkDefinition::simulator();
         let default_account = get_default_account()?;
-        let mut metadata = HashMap::new();
+        let mut metadata = BTreeMap::new();
         if let Some(symbol) = self.symbol.clone() {
             metadata.insert("symbol".to_string(), symbol);
         }
@@ -102,6 +102,8 @@ impl NewSimpleBadge {
                             ),
                         )]),
                     }),
+                    non_fungible_data_schema: None,
+                    track_holders: false,
                 })
                 .unwrap(),
             })
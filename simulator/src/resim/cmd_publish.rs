@@ -2,10 +2,11 @@ use clap::Parser;
 use colored::*;
 use radix_engine::ledger::{OutputValue, ReadableSubstateStore, WriteableSubstateStore};
 use radix_engine::types::*;
+use radix_engine_interface::abi::compare_blueprint_abis;
 use radix_engine_interface::api::types::RENodeId;
 use std::ffi::OsStr;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use transaction::builder::ManifestBuilder;
 use utils::ContextualDisplay;
 
@@ -37,68 +38,39 @@ pub struct Publish {
     /// Turn on tracing
     #[clap(short, long)]
     trace: bool,
+
+    /// Keep running, and rebuild and republish the package in place whenever a source file changes
+    #[cfg(feature = "watch")]
+    #[clap(long, conflicts_with = "manifest")]
+    watch: bool,
+
+    /// A transaction manifest to run after each successful republish, with `--watch`
+    #[cfg(feature = "watch")]
+    #[clap(long, requires = "watch")]
+    then_run: Option<PathBuf>,
 }
 
 impl Publish {
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
-        let network = NetworkDefinition::simulator();
-        let bech32_decoder = Bech32Decoder::new(&network);
+        #[cfg(feature = "watch")]
+        if self.watch {
+            return self.watch(out);
+        }
 
-        // Load wasm code
-        let (code_path, abi_path) = if self.path.extension() != Some(OsStr::new("wasm")) {
-            build_package(&self.path, false, false).map_err(Error::BuildError)?
-        } else {
-            let code_path = self.path.clone();
-            let abi_path = code_path.with_extension("abi");
-            (code_path, abi_path)
-        };
+        self.publish(out).map(|_| ())
+    }
 
-        let code = fs::read(&code_path).map_err(Error::IOError)?;
-        let abi = scrypto_decode(
-            &fs::read(&abi_path).map_err(|err| Error::IOErrorAtPath(err, abi_path))?,
-        )
-        .map_err(Error::DataError)?;
+    /// Publishes the package, returning its address unless only a manifest was output.
+    fn publish<O: std::io::Write>(&self, out: &mut O) -> Result<Option<PackageAddress>, Error> {
+        let network = NetworkDefinition::simulator();
+        let bech32_decoder = Bech32Decoder::new(&network);
 
         if let Some(package_address) = self.package_address.clone() {
-            let mut substate_store = RadixEngineDB::with_bootstrap(get_data_dir()?);
-
-            let global: GlobalAddressSubstate = substate_store
-                .get_substate(&SubstateId(
-                    RENodeId::Global(GlobalAddress::Package(package_address.0)),
-                    SubstateOffset::Global(GlobalOffset::Global),
-                ))
-                .map(|s| s.substate)
-                .map(|s| s.to_runtime().into())
-                .ok_or(Error::PackageAddressNotFound)?;
-            let substate_id = SubstateId(
-                global.node_deref(),
-                SubstateOffset::Package(PackageOffset::Info),
-            );
-
-            let previous_version = substate_store
-                .get_substate(&substate_id)
-                .map(|output| output.version);
-
-            let validated_package = PackageInfoSubstate {
-                code,
-                blueprint_abis: abi,
-            };
-            let output_value = OutputValue {
-                substate: PersistedSubstate::PackageInfo(validated_package),
-                version: previous_version.unwrap_or(0),
-            };
-
-            // Overwrite package
-            // TODO: implement real package overwrite
-            substate_store.put_substate(
-                SubstateId(
-                    global.node_deref(),
-                    SubstateOffset::Package(PackageOffset::Info),
-                ),
-                output_value,
-            );
+            republish_package(&self.path, package_address.0, out)?;
             writeln!(out, "Package updated!").map_err(Error::IOError)?;
+            Ok(Some(package_address.0))
         } else {
+            let (code, abi) = load_package(&self.path)?;
             let owner_badge = self
                 .owner_badge
                 .as_ref()
@@ -125,18 +97,196 @@ impl Publish {
                 out,
             )?;
             if let Some(receipt) = receipt {
+                let package_address =
+                    receipt.expect_commit().entity_changes.new_package_addresses[0];
                 writeln!(
                     out,
                     "Success! New Package: {}",
-                    receipt.expect_commit().entity_changes.new_package_addresses[0]
+                    package_address
                         .display(&Bech32Encoder::for_simulator())
                         .to_string()
                         .green()
                 )
                 .map_err(Error::IOError)?;
+                Ok(Some(package_address))
+            } else {
+                Ok(None)
+            }
+        }
+    }
+
+    /// Publishes the package once, then republishes it in place on every source change. Stopped
+    /// with Ctrl-C; every republish is committed on its own, so there is nothing to clean up.
+    #[cfg(feature = "watch")]
+    fn watch<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let mut package_address = None;
+        watch_sources(&self.path, || {
+            match package_address {
+                Some(package_address) => {
+                    republish_package(&self.path, package_address, out)?;
+                    writeln!(out, "Package updated!").map_err(Error::IOError)?;
+                }
+                None => package_address = self.publish(out)?,
+            }
+            if let Some(manifest_path) = &self.then_run {
+                let manifest = compile_manifest_file(manifest_path, &None, &None)?;
+                let receipt =
+                    handle_manifest(manifest, &None, &None, &None, false, false, false, out)?;
+                if let Some(receipt) = receipt {
+                    writeln!(
+                        out,
+                        "{} ({} cost units consumed)",
+                        "Manifest executed successfully.".green(),
+                        receipt.execution.fee_summary.cost_unit_consumed
+                    )
+                    .map_err(Error::IOError)?;
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Loads the code and ABI of a package, building it first unless `path` is a `.wasm` file.
+pub fn load_package(path: &Path) -> Result<(Vec<u8>, HashMap<String, BlueprintAbi>), Error> {
+    let (code_path, abi_path) = if path.extension() != Some(OsStr::new("wasm")) {
+        build_package(path, false, false).map_err(Error::BuildError)?
+    } else {
+        let code_path = path.to_path_buf();
+        let abi_path = code_path.with_extension("abi");
+        (code_path, abi_path)
+    };
+
+    let code = fs::read(&code_path).map_err(Error::IOError)?;
+    let abi =
+        scrypto_decode(&fs::read(&abi_path).map_err(|err| Error::IOErrorAtPath(err, abi_path))?)
+            .map_err(Error::DataError)?;
+    Ok((code, abi))
+}
+
+/// Loads the package at `path`, as [`load_package`] does, and overwrites the existing package
+/// with it, keeping the package address. Warns about breaking changes to the blueprint ABIs.
+pub fn republish_package<O: std::io::Write>(
+    path: &Path,
+    package_address: PackageAddress,
+    out: &mut O,
+) -> Result<(), Error> {
+    let (code, abi) = load_package(path)?;
+    let previous_abi = overwrite_package(package_address, code, abi.clone())?;
+    warn_breaking_abi_changes(&previous_abi, &abi, out)
+}
+
+fn warn_breaking_abi_changes<O: std::io::Write>(
+    previous_abi: &HashMap<String, BlueprintAbi>,
+    abi: &HashMap<String, BlueprintAbi>,
+    out: &mut O,
+) -> Result<(), Error> {
+    let mut blueprint_names: Vec<&String> = previous_abi.keys().collect();
+    blueprint_names.sort();
+    for blueprint_name in blueprint_names {
+        match abi.get(blueprint_name) {
+            Some(blueprint_abi) => {
+                let diff = compare_blueprint_abis(&previous_abi[blueprint_name], blueprint_abi);
+                for change in diff.breaking_changes() {
+                    writeln!(
+                        out,
+                        "{} {}: {}",
+                        "Breaking ABI change in".yellow(),
+                        blueprint_name,
+                        change
+                    )
+                    .map_err(Error::IOError)?;
+                }
             }
+            None => writeln!(out, "{} {}", "Blueprint removed:".yellow(), blueprint_name)
+                .map_err(Error::IOError)?,
         }
+    }
+    Ok(())
+}
+
+/// Overwrites the code and ABI of an existing package in the ledger, returning the previous ABI.
+pub fn overwrite_package(
+    package_address: PackageAddress,
+    code: Vec<u8>,
+    blueprint_abis: HashMap<String, BlueprintAbi>,
+) -> Result<HashMap<String, BlueprintAbi>, Error> {
+    let mut substate_store = RadixEngineDB::with_bootstrap(get_data_dir()?);
+
+    let global: GlobalAddressSubstate = substate_store
+        .get_substate(&SubstateId(
+            RENodeId::Global(GlobalAddress::Package(package_address)),
+            SubstateOffset::Global(GlobalOffset::Global),
+        ))
+        .map(|s| s.substate)
+        .map(|s| s.to_runtime().into())
+        .ok_or(Error::PackageAddressNotFound)?;
+    let substate_id = SubstateId(
+        global.node_deref(),
+        SubstateOffset::Package(PackageOffset::Info),
+    );
+
+    let previous = substate_store.get_substate(&substate_id);
+    let previous_version = previous.as_ref().map(|output| output.version);
+    let previous_abi = previous
+        .map(|output| {
+            let package: PackageInfoSubstate = output.substate.to_runtime().into();
+            package.blueprint_abis
+        })
+        .unwrap_or_default();
+
+    let validated_package = PackageInfoSubstate {
+        code,
+        blueprint_abis,
+    };
+    let output_value = OutputValue {
+        substate: PersistedSubstate::PackageInfo(validated_package),
+        version: previous_version.unwrap_or(0),
+    };
+
+    // Overwrite package
+    // TODO: implement real package overwrite
+    substate_store.put_substate(substate_id, output_value);
+
+    Ok(previous_abi)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_republish_package_keeps_address() {
+        let mut data_dir = std::env::temp_dir();
+        data_dir.push(format!("resim-test-{}", uuid::Uuid::new_v4()));
+
+        temp_env::with_var(ENV_DATA_DIR, Some(&data_dir), || {
+            // Arrange
+            let path = Path::new("./tests/large_package.wasm");
+            let code = fs::read(path).unwrap();
+
+            // Act
+            republish_package(path, FAUCET_PACKAGE, &mut std::io::sink()).unwrap();
+
+            // Assert
+            let substate_store = RadixEngineDB::with_bootstrap(get_data_dir().unwrap());
+            let global: GlobalAddressSubstate = substate_store
+                .get_substate(&SubstateId(
+                    RENodeId::Global(GlobalAddress::Package(FAUCET_PACKAGE)),
+                    SubstateOffset::Global(GlobalOffset::Global),
+                ))
+                .map(|s| s.substate.to_runtime().into())
+                .unwrap();
+            let package: PackageInfoSubstate = substate_store
+                .get_substate(&SubstateId(
+                    global.node_deref(),
+                    SubstateOffset::Package(PackageOffset::Info),
+                ))
+                .map(|s| s.substate.to_runtime().into())
+                .unwrap();
+            assert_eq!(package.code, code);
+        });
 
-        Ok(())
+        fs::remove_dir_all(data_dir).unwrap();
     }
 }
@@ -1,7 +1,7 @@
 use clap::Parser;
 use regex::{Captures, Regex};
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::resim::*;
 
@@ -38,21 +38,7 @@ impl Run {
     }
 
     pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
-        let manifest = std::fs::read_to_string(&self.path).map_err(Error::IOError)?;
-        let pre_processed_manifest = Self::pre_process_manifest(&manifest);
-        let network = match &self.network {
-            Some(n) => NetworkDefinition::from_str(&n).map_err(Error::ParseNetworkError)?,
-            None => NetworkDefinition::simulator(),
-        };
-        let mut blobs = Vec::new();
-        if let Some(paths) = &self.blobs {
-            for path in paths {
-                blobs.push(std::fs::read(path).map_err(Error::IOError)?);
-            }
-        }
-        let compiled_manifest =
-            transaction::manifest::compile(&pre_processed_manifest, &network, blobs)
-                .map_err(Error::CompileError)?;
+        let compiled_manifest = compile_manifest_file(&self.path, &self.network, &self.blobs)?;
         handle_manifest(
             compiled_manifest,
             &self.signing_keys,
@@ -67,6 +53,28 @@ impl Run {
     }
 }
 
+/// Reads, pre-processes and compiles a transaction manifest file.
+pub fn compile_manifest_file(
+    path: &Path,
+    network: &Option<String>,
+    blob_paths: &Option<Vec<String>>,
+) -> Result<TransactionManifest, Error> {
+    let manifest = std::fs::read_to_string(path).map_err(Error::IOError)?;
+    let pre_processed_manifest = Run::pre_process_manifest(&manifest);
+    let network = match network {
+        Some(n) => NetworkDefinition::from_str(&n).map_err(Error::ParseNetworkError)?,
+        None => NetworkDefinition::simulator(),
+    };
+    let mut blobs = Vec::new();
+    if let Some(paths) = blob_paths {
+        for path in paths {
+            blobs.push(std::fs::read(path).map_err(Error::IOError)?);
+        }
+    }
+    transaction::manifest::compile(&pre_processed_manifest, &network, blobs)
+        .map_err(Error::CompileError)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
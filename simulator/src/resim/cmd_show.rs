@@ -10,6 +10,10 @@ use crate::resim::*;
 pub struct Show {
     /// The address of a package, component or resource manager
     address: String,
+
+    /// The number of top holders to show, for a resource manager
+    #[clap(long)]
+    top: Option<usize>,
 }
 
 impl Show {
@@ -29,9 +33,110 @@ impl Show {
         } else if let Ok(resource_address) =
             bech32_decoder.validate_and_decode_resource_address(&self.address)
         {
-            dump_resource_manager(resource_address, &ledger, out).map_err(Error::LedgerDumpError)
+            dump_resource_manager(resource_address, &ledger, out)
+                .and_then(|_| {
+                    dump_resource_holders(
+                        resource_address,
+                        &ledger.list_components(),
+                        self.top,
+                        &ledger,
+                        out,
+                    )
+                })
+                .map_err(Error::LedgerDumpError)
         } else {
             Err(Error::InvalidId(self.address.clone()))
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use radix_engine_interface::rule;
+    use transaction::builder::ManifestBuilder;
+
+    #[test]
+    fn test_show_resource_displays_mint_and_burn_counters() {
+        let mut data_dir = std::env::temp_dir();
+        data_dir.push(format!("resim-test-{}", uuid::Uuid::new_v4()));
+
+        temp_env::with_var(ENV_DATA_DIR, Some(&data_dir), || {
+            // Arrange
+            let mut access_rules = HashMap::new();
+            for method in [
+                ResourceMethodAuthKey::Withdraw,
+                ResourceMethodAuthKey::Deposit,
+                ResourceMethodAuthKey::Mint,
+                ResourceMethodAuthKey::Burn,
+            ] {
+                access_rules.insert(method, (rule!(allow_all), rule!(deny_all)));
+            }
+            let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+                .lock_fee(FAUCET_COMPONENT, 100.into())
+                .create_resource(
+                    ResourceType::Fungible { divisibility: 18 },
+                    BTreeMap::new(),
+                    access_rules,
+                    None,
+                )
+                .build();
+            let receipt = handle_manifest(
+                manifest,
+                &None,
+                &None,
+                &None,
+                false,
+                false,
+                false,
+                &mut std::io::sink(),
+            )
+            .unwrap()
+            .unwrap();
+            let resource_address = receipt
+                .expect_commit()
+                .entity_changes
+                .new_resource_addresses[0];
+            let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+                .lock_fee(FAUCET_COMPONENT, 100.into())
+                .mint(resource_address, dec!("100"))
+                .burn(resource_address, dec!("30"))
+                .take_from_worktop(resource_address, |builder, bucket_id| {
+                    builder.new_account_with_resource(&rule!(allow_all), bucket_id)
+                })
+                .build();
+            handle_manifest(
+                manifest,
+                &None,
+                &None,
+                &None,
+                false,
+                false,
+                false,
+                &mut std::io::sink(),
+            )
+            .unwrap()
+            .unwrap()
+            .expect_commit_success();
+
+            // Act
+            colored::control::set_override(false);
+            let mut out = Vec::new();
+            Show {
+                address: Bech32Encoder::for_simulator()
+                    .encode_resource_address_to_string(&resource_address),
+                top: None,
+            }
+            .run(&mut out)
+            .unwrap();
+
+            // Assert
+            let out = String::from_utf8(out).unwrap();
+            assert!(out.contains("Total Supply: 70\n"));
+            assert!(out.contains("Amount Minted: 100\n"));
+            assert!(out.contains("Amount Burned: 30\n"));
+        });
+
+        std::fs::remove_dir_all(data_dir).unwrap();
+    }
+}
@@ -0,0 +1,118 @@
+use clap::Parser;
+use radix_engine_constants::*;
+use rand::Rng;
+use std::path::PathBuf;
+use transaction::builder::TransactionBuilder;
+use transaction::model::{NotarizedTransaction, TransactionHeader, TransactionManifest};
+use transaction::signing::EcdsaSecp256k1PrivateKey;
+
+use crate::resim::*;
+
+/// Compiles a transaction manifest and signs it, printing the notarized transaction in hex
+#[derive(Parser, Debug)]
+pub struct Sign {
+    /// The path to a transaction manifest file
+    #[clap(long)]
+    manifest: PathBuf,
+
+    /// The private key used for both signing and notarizing
+    #[clap(long)]
+    private_key: String,
+
+    /// The network to sign for, [simulator | adapanet | nebunet | mainnet]
+    #[clap(short, long)]
+    network: Option<String>,
+
+    /// The paths to blobs
+    #[clap(short, long, multiple = true)]
+    blobs: Option<Vec<String>>,
+
+    /// The first epoch in which the transaction is valid
+    #[clap(long, default_value = "0")]
+    start_epoch: u64,
+}
+
+impl Sign {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let manifest = std::fs::read_to_string(&self.manifest).map_err(Error::IOError)?;
+        let network = match &self.network {
+            Some(n) => NetworkDefinition::from_str(&n).map_err(Error::ParseNetworkError)?,
+            None => NetworkDefinition::simulator(),
+        };
+        let mut blobs = Vec::new();
+        if let Some(paths) = &self.blobs {
+            for path in paths {
+                blobs.push(std::fs::read(path).map_err(Error::IOError)?);
+            }
+        }
+        let compiled_manifest = transaction::manifest::compile(&manifest, &network, blobs)
+            .map_err(Error::CompileError)?;
+        let private_key = get_signing_keys(&Some(self.private_key.clone()))?
+            .into_iter()
+            .next()
+            .ok_or(Error::InvalidPrivateKey)?;
+
+        let transaction = sign_manifest(
+            compiled_manifest,
+            &private_key,
+            &network,
+            self.start_epoch,
+            rand::thread_rng().gen(),
+        );
+        let bytes = transaction.to_bytes().map_err(Error::EncodeError)?;
+        writeln!(out, "{}", hex::encode(bytes)).map_err(Error::IOError)?;
+        Ok(())
+    }
+}
+
+/// Signs the manifest with the given key, which also notarizes it.
+pub fn sign_manifest(
+    manifest: TransactionManifest,
+    private_key: &EcdsaSecp256k1PrivateKey,
+    network: &NetworkDefinition,
+    start_epoch: u64,
+    nonce: u64,
+) -> NotarizedTransaction {
+    TransactionBuilder::new()
+        .header(TransactionHeader {
+            version: TRANSACTION_VERSION_V1,
+            network_id: network.id,
+            start_epoch_inclusive: start_epoch,
+            end_epoch_exclusive: start_epoch + DEFAULT_MAX_EPOCH_RANGE,
+            nonce,
+            notary_public_key: private_key.public_key().into(),
+            notary_as_signatory: false,
+            cost_unit_limit: DEFAULT_COST_UNIT_LIMIT,
+            tip_percentage: DEFAULT_MIN_TIP_PERCENTAGE,
+        })
+        .manifest(manifest)
+        .sign(private_key)
+        .notarize(private_key)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use transaction::builder::ManifestBuilder;
+    use transaction::validation::{NotarizedTransactionValidator, ValidationConfig};
+
+    #[test]
+    fn test_signed_transaction_round_trip() {
+        let private_key = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap();
+        let network = NetworkDefinition::simulator();
+        let manifest = ManifestBuilder::new(&network).clear_auth_zone().build();
+
+        let bytes = sign_manifest(manifest, &private_key, &network, 0, 5)
+            .to_bytes()
+            .unwrap();
+        let decoded =
+            NotarizedTransaction::from_slice(&hex::decode(hex::encode(bytes)).unwrap()).unwrap();
+
+        let validator = NotarizedTransactionValidator::new(ValidationConfig::simulator());
+        assert_eq!(
+            validator.validate_signatures(&decoded),
+            Ok(vec![private_key.public_key().into()])
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use clap::Parser;
+use colored::*;
+use std::path::PathBuf;
+
+use crate::resim::*;
+use crate::utils::*;
+
+/// Verify that a package was built from the given source
+#[derive(Parser, Debug)]
+pub struct VerifyPackage {
+    /// The package address
+    package_address: SimulatorPackageAddress,
+
+    /// The path to the source of the package
+    source_dir: PathBuf,
+}
+
+impl VerifyPackage {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let code = export_package_code(self.package_address.0)?;
+        match verify_package_source(&code, &self.source_dir) {
+            Ok(()) => {
+                writeln!(out, "{}", "The package matches the source.".green())
+                    .map_err(Error::IOError)?;
+                Ok(())
+            }
+            Err(report) => {
+                for mismatch in &report.mismatches {
+                    writeln!(out, "{} {}", "Mismatch:".red(), mismatch).map_err(Error::IOError)?;
+                }
+                Err(Error::PackageSourceMismatch(report))
+            }
+        }
+    }
+}
@@ -25,8 +25,13 @@ pub enum Error {
 
     IOErrorAtPath(io::Error, PathBuf),
 
+    #[cfg(feature = "watch")]
+    WatchError(notify::Error),
+
     DataError(DecodeError),
 
+    EncodeError(EncodeError),
+
     JSONError(serde_json::Error),
 
     BuildError(BuildError),
@@ -43,6 +48,12 @@ pub enum Error {
 
     TransactionExecutionError(RuntimeError),
 
+    /// An authorization failure, along with the `--proofs` entries that were put in the auth zone
+    AuthErrorWithAttemptedProofs {
+        error: RuntimeError,
+        attempted_proofs: Vec<String>,
+    },
+
     TransactionRejected(RejectionError),
 
     AbiExportError(ExportError),
@@ -57,13 +68,19 @@ pub enum Error {
 
     InvalidPrivateKey,
 
+    InvalidTransactionBlob,
+
     AddressError(AddressError),
 
     NonFungibleAddressError(ParseNonFungibleAddressError),
 
     FailedToBuildArgs(BuildArgsError),
 
+    InvalidProofSpecifier(String),
+
     ParseNetworkError(ParseNetworkError),
 
     OwnerBadgeNotSpecified,
+
+    PackageSourceMismatch(MismatchReport),
 }
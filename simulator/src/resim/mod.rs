@@ -1,6 +1,8 @@
 mod addressing;
 mod cmd_call_function;
 mod cmd_call_method;
+mod cmd_db;
+mod cmd_decode_transaction;
 mod cmd_export_abi;
 mod cmd_generate_key_pair;
 mod cmd_mint;
@@ -18,13 +20,20 @@ mod cmd_set_default_account;
 mod cmd_show;
 mod cmd_show_configs;
 mod cmd_show_ledger;
+mod cmd_sign;
 mod cmd_transfer;
+mod cmd_verify_package;
 mod config;
 mod error;
+mod rust_bindings;
+#[cfg(feature = "watch")]
+mod watch;
 
 pub use addressing::*;
 pub use cmd_call_function::*;
 pub use cmd_call_method::*;
+pub use cmd_db::*;
+pub use cmd_decode_transaction::*;
 pub use cmd_export_abi::*;
 pub use cmd_generate_key_pair::*;
 pub use cmd_mint::*;
@@ -42,16 +51,22 @@ pub use cmd_set_default_account::*;
 pub use cmd_show::*;
 pub use cmd_show_configs::*;
 pub use cmd_show_ledger::*;
+pub use cmd_sign::*;
 pub use cmd_transfer::*;
+pub use cmd_verify_package::*;
 pub use config::*;
 pub use error::*;
+pub use rust_bindings::*;
+#[cfg(feature = "watch")]
+pub use watch::*;
 
 pub const DEFAULT_SCRYPTO_DIR_UNDER_HOME: &'static str = ".scrypto";
 pub const ENV_DATA_DIR: &'static str = "DATA_DIR";
 pub const ENV_DISABLE_MANIFEST_OUTPUT: &'static str = "DISABLE_MANIFEST_OUTPUT";
 
 use clap::{Parser, Subcommand};
-use radix_engine::engine::ScryptoInterpreter;
+use radix_engine::engine::{ModuleError, RuntimeError, ScryptoInterpreter};
+use radix_engine::ledger::ReadableSubstateStore;
 use radix_engine::model::*;
 use radix_engine::transaction::execute_and_commit_transaction;
 use radix_engine::transaction::TransactionOutcome;
@@ -62,6 +77,7 @@ use radix_engine::types::*;
 use radix_engine::wasm::*;
 use radix_engine_constants::*;
 use radix_engine_interface::abi;
+use radix_engine_interface::api::types::RENodeId;
 use radix_engine_interface::core::NetworkDefinition;
 use radix_engine_interface::crypto::hash;
 use radix_engine_interface::model::FromPublicKey;
@@ -69,6 +85,7 @@ use radix_engine_stores::rocks_db::RadixEngineDB;
 use std::env;
 use std::fs;
 use std::path::PathBuf;
+use std::str::FromStr;
 use transaction::builder::ManifestBuilder;
 use transaction::manifest::decompile;
 use transaction::model::AuthModule;
@@ -95,6 +112,8 @@ impl ResimCli {
 pub enum Command {
     CallFunction(CallFunction),
     CallMethod(CallMethod),
+    Db(Db),
+    DecodeTransaction(DecodeTransaction),
     ExportAbi(ExportAbi),
     GenerateKeyPair(GenerateKeyPair),
     Mint(crate::resim::cmd_mint::Mint),
@@ -112,7 +131,9 @@ pub enum Command {
     ShowConfigs(ShowConfigs),
     ShowLedger(ShowLedger),
     Show(Show),
+    Sign(Sign),
     Transfer(Transfer),
+    VerifyPackage(VerifyPackage),
 }
 
 pub fn run() -> Result<(), Error> {
@@ -123,6 +144,8 @@ pub fn run() -> Result<(), Error> {
     match cli.command {
         Command::CallFunction(cmd) => cmd.run(&mut out),
         Command::CallMethod(cmd) => cmd.run(&mut out),
+        Command::Db(cmd) => cmd.run(&mut out),
+        Command::DecodeTransaction(cmd) => cmd.run(&mut out),
         Command::ExportAbi(cmd) => cmd.run(&mut out),
         Command::GenerateKeyPair(cmd) => cmd.run(&mut out),
         Command::Mint(cmd) => cmd.run(&mut out),
@@ -140,7 +163,9 @@ pub fn run() -> Result<(), Error> {
         Command::ShowConfigs(cmd) => cmd.run(&mut out),
         Command::ShowLedger(cmd) => cmd.run(&mut out),
         Command::Show(cmd) => cmd.run(&mut out),
+        Command::Sign(cmd) => cmd.run(&mut out),
         Command::Transfer(cmd) => cmd.run(&mut out),
+        Command::VerifyPackage(cmd) => cmd.run(&mut out),
     }
 }
 
@@ -205,8 +230,14 @@ pub fn handle_manifest<O: std::io::Write>(
                 &FeeReserveConfig::default(),
                 &ExecutionConfig {
                     max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+                    max_owned_nodes_per_frame: DEFAULT_MAX_OWNED_NODES_PER_FRAME,
+                    max_auth_zone_proofs: DEFAULT_MAX_AUTH_ZONE_PROOFS,
+                    max_syscalls_per_transaction: DEFAULT_MAX_SYSCALLS_PER_TRANSACTION,
                     trace,
                     max_sys_call_trace_depth: 1,
+                    charge_storage_fees: false,
+                    publish_badge: None,
+                    debug_metrics: false,
                 },
                 &transaction.get_executable(initial_proofs),
             );
@@ -263,6 +294,73 @@ pub fn get_signing_keys(
     Ok(private_keys)
 }
 
+/// Adds a proof from the given account to the auth zone for each `--proofs` entry.
+///
+/// Entries are either `<resource_address>[:<amount>|:#<id1>,#<id2>]` or the resource
+/// specifier format `<amount>,<resource_address>` / `#<id1>,#<id2>,<resource_address>`.
+/// A bare resource address creates a proof of the entire account balance.
+pub fn create_proofs_from_account<'a>(
+    mut manifest_builder: &'a mut ManifestBuilder,
+    account: ComponentAddress,
+    proofs: &[String],
+) -> Result<&'a mut ManifestBuilder, Error> {
+    for proof in proofs {
+        let (resource, specifier) = match proof.split_once(':') {
+            Some((resource, specifier)) => (resource, Some(specifier)),
+            None if !proof.contains(',') => (proof.as_str(), None),
+            None => {
+                manifest_builder = manifest_builder
+                    .create_proof_from_account_by_resource_specifier(account, proof.clone())
+                    .map_err(Error::FailedToBuildArgs)?;
+                continue;
+            }
+        };
+
+        let resource_address = SimulatorResourceAddress::from_str(resource.trim())
+            .map_err(|_| Error::InvalidProofSpecifier(proof.clone()))?
+            .0;
+        manifest_builder = match specifier.map(str::trim) {
+            None => manifest_builder.create_proof_from_account(account, resource_address),
+            Some(ids) if ids.starts_with('#') => {
+                let ids = ids
+                    .split(',')
+                    .map(|id| {
+                        NonFungibleId::try_from_combined_simple_string(
+                            id.trim().trim_start_matches('#'),
+                        )
+                        .map_err(|_| Error::InvalidProofSpecifier(proof.clone()))
+                    })
+                    .collect::<Result<BTreeSet<NonFungibleId>, Error>>()?;
+                manifest_builder.create_proof_from_account_by_ids(account, &ids, resource_address)
+            }
+            Some(amount) => {
+                let amount = Decimal::from_str(amount)
+                    .map_err(|_| Error::InvalidProofSpecifier(proof.clone()))?;
+                manifest_builder.create_proof_from_account_by_amount(
+                    account,
+                    amount,
+                    resource_address,
+                )
+            }
+        };
+    }
+
+    Ok(manifest_builder)
+}
+
+/// Attaches the attempted `--proofs` entries to an authorization failure, as a hint.
+pub fn with_attempted_proofs<T>(result: Result<T, Error>, proofs: &[String]) -> Result<T, Error> {
+    match result {
+        Err(Error::TransactionExecutionError(
+            error @ RuntimeError::ModuleError(ModuleError::AuthError(..)),
+        )) => Err(Error::AuthErrorWithAttemptedProofs {
+            error,
+            attempted_proofs: proofs.to_vec(),
+        }),
+        result => result,
+    }
+}
+
 pub fn export_abi(
     package_address: PackageAddress,
     blueprint_name: &str,
@@ -272,6 +370,25 @@ pub fn export_abi(
         .map_err(Error::AbiExportError)
 }
 
+pub fn export_package_code(package_address: PackageAddress) -> Result<Vec<u8>, Error> {
+    let substate_store = RadixEngineDB::with_bootstrap(get_data_dir()?);
+    let global: GlobalAddressSubstate = substate_store
+        .get_substate(&SubstateId(
+            RENodeId::Global(GlobalAddress::Package(package_address)),
+            SubstateOffset::Global(GlobalOffset::Global),
+        ))
+        .map(|s| s.substate.to_runtime().into())
+        .ok_or(Error::PackageAddressNotFound)?;
+    let package: PackageInfoSubstate = substate_store
+        .get_substate(&SubstateId(
+            global.node_deref(),
+            SubstateOffset::Package(PackageOffset::Info),
+        ))
+        .map(|s| s.substate.to_runtime().into())
+        .ok_or(Error::PackageAddressNotFound)?;
+    Ok(package.code)
+}
+
 pub fn export_abi_by_component(
     component_address: ComponentAddress,
 ) -> Result<abi::BlueprintAbi, Error> {
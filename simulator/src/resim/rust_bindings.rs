@@ -0,0 +1,682 @@
+use radix_engine_interface::abi::{BlueprintAbi, Fields, Fn, Type};
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// Names which are used as-is in the bindings, and so can't be given to a custom type.
+const RESERVED_TYPE_NAMES: [&str; 34] = [
+    "Self",
+    "Option",
+    "Result",
+    "Vec",
+    "String",
+    "BTreeSet",
+    "BTreeMap",
+    "HashSet",
+    "HashMap",
+    "PackageAddress",
+    "ComponentAddress",
+    "ResourceAddress",
+    "SystemAddress",
+    "Component",
+    "KeyValueStore",
+    "Bucket",
+    "Proof",
+    "Vault",
+    "Expression",
+    "Blob",
+    "NonFungibleAddress",
+    "Hash",
+    "EcdsaSecp256k1PublicKey",
+    "EcdsaSecp256k1Signature",
+    "EddsaEd25519PublicKey",
+    "EddsaEd25519Signature",
+    "Decimal",
+    "PreciseDecimal",
+    "NonFungibleId",
+    "Runtime",
+    "TypeId",
+    "Encode",
+    "Decode",
+    "Describe",
+];
+
+const KEYWORDS: [&str; 50] = [
+    "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for",
+    "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+    "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where",
+    "while", "async", "await", "dyn", "abstract", "become", "box", "do", "final", "macro",
+    "override", "priv", "typeof", "unsized", "virtual", "yield",
+];
+
+/// Renders the ABI of a blueprint as a Rust module, with a `<Blueprint>Blueprint` stub for its
+/// functions and a `<Blueprint>Component` stub for the methods of its components.
+///
+/// Custom types are declared alongside the stubs. A function which takes or returns a type that
+/// can't be represented in Rust is left out, with a `TODO` marker in its place.
+pub fn generate_rust_bindings(
+    package_address: &str,
+    blueprint_name: &str,
+    abi: &BlueprintAbi,
+) -> String {
+    let blueprint_ident = format!("{}Blueprint", blueprint_name);
+    let component_ident = format!("{}Component", blueprint_name);
+    let mut types = CustomTypes::new(&[&blueprint_ident, &component_ident]);
+
+    let mut functions = Vec::new();
+    let mut methods = Vec::new();
+    for function in &abi.fns {
+        let rendered = render_fn(function, blueprint_name, &mut types);
+        if function.mutability.is_none() {
+            functions.push(rendered);
+        } else {
+            methods.push(rendered);
+        }
+    }
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "//! Rust bindings for the `{}` blueprint of package `{}`.",
+        blueprint_name, package_address
+    )
+    .unwrap();
+    writeln!(out, "//!").unwrap();
+    writeln!(out, "//! Generated by `resim export-abi --rust-bindings`.").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "use scrypto::prelude::*;").unwrap();
+
+    for definition in &types.definitions {
+        writeln!(out).unwrap();
+        out.push_str(definition);
+    }
+
+    if !functions.is_empty() {
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "/// The functions of the `{}` blueprint.",
+            blueprint_name
+        )
+        .unwrap();
+        writeln!(out, "#[scrypto(TypeId, Encode, Decode, Describe)]").unwrap();
+        writeln!(out, "pub struct {} {{", blueprint_ident).unwrap();
+        writeln!(out, "    package_address: PackageAddress,").unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "#[allow(dead_code)]").unwrap();
+        writeln!(out, "impl {} {{", blueprint_ident).unwrap();
+        writeln!(
+            out,
+            "    pub fn at(package_address: PackageAddress) -> Self {{"
+        )
+        .unwrap();
+        writeln!(out, "        Self {{ package_address }}").unwrap();
+        writeln!(out, "    }}").unwrap();
+        for function in &functions {
+            writeln!(out).unwrap();
+            out.push_str(function);
+        }
+        writeln!(out, "}}").unwrap();
+    }
+
+    if !methods.is_empty() {
+        writeln!(out).unwrap();
+        writeln!(out, "/// The methods of a `{}` component.", blueprint_name).unwrap();
+        writeln!(out, "#[scrypto(TypeId, Encode, Decode, Describe)]").unwrap();
+        writeln!(out, "pub struct {} {{", component_ident).unwrap();
+        writeln!(out, "    component_address: ComponentAddress,").unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "#[allow(dead_code)]").unwrap();
+        writeln!(out, "impl {} {{", component_ident).unwrap();
+        writeln!(
+            out,
+            "    pub fn at(component_address: ComponentAddress) -> Self {{"
+        )
+        .unwrap();
+        writeln!(out, "        Self {{ component_address }}").unwrap();
+        writeln!(out, "    }}").unwrap();
+        for method in &methods {
+            writeln!(out).unwrap();
+            out.push_str(method);
+        }
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "impl From<ComponentAddress> for {} {{",
+            component_ident
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "    fn from(component_address: ComponentAddress) -> Self {{"
+        )
+        .unwrap();
+        writeln!(out, "        Self {{ component_address }}").unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "impl From<{}> for ComponentAddress {{",
+            component_ident
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "    fn from(component: {}) -> Self {{",
+            component_ident
+        )
+        .unwrap();
+        writeln!(out, "        component.component_address").unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}").unwrap();
+    }
+
+    out
+}
+
+/// Renders a function or method of a stub, indented to sit in its `impl` block.
+fn render_fn(function: &Fn, blueprint_name: &str, types: &mut CustomTypes) -> String {
+    let signature = match render_signature(function, types) {
+        Ok(signature) => signature,
+        Err(unrepresentable) => {
+            return format!(
+                "    // TODO: `{}` is left out, as `{}` can't be represented in Rust\n",
+                function.ident, unrepresentable
+            );
+        }
+    };
+    let (args, output) = signature;
+
+    // `at` is taken by the constructor of the stub
+    let mut ident = escape_ident(&function.ident);
+    if ident == "at" {
+        ident = "at_".to_owned();
+    }
+
+    let mut out = String::new();
+    if ident != function.ident {
+        writeln!(out, "    /// Calls `{}`.", function.ident).unwrap();
+    }
+    let params: Vec<String> = ["&self".to_owned()]
+        .into_iter()
+        .chain(
+            args.iter()
+                .enumerate()
+                .map(|(i, ty)| format!("arg{}: {}", i, ty)),
+        )
+        .collect();
+    let arg_names: Vec<String> = (0..args.len()).map(|i| format!("arg{}", i)).collect();
+    let call = if function.mutability.is_none() {
+        format!(
+            "Runtime::call_function(self.package_address, {:?}, {:?}, args!({}))",
+            blueprint_name,
+            function.ident,
+            arg_names.join(", ")
+        )
+    } else {
+        format!(
+            "Runtime::call_method(self.component_address, {:?}, args!({}))",
+            function.ident,
+            arg_names.join(", ")
+        )
+    };
+    let output = if output == "()" {
+        String::new()
+    } else {
+        format!(" -> {}", output)
+    };
+    writeln!(
+        out,
+        "    pub fn {}({}){} {{",
+        ident,
+        params.join(", "),
+        output
+    )
+    .unwrap();
+    writeln!(out, "        {}", call).unwrap();
+    writeln!(out, "    }}").unwrap();
+    out
+}
+
+/// Returns the argument and output types of a function, or the first type which can't be
+/// represented.
+fn render_signature(
+    function: &Fn,
+    types: &mut CustomTypes,
+) -> Result<(Vec<String>, String), String> {
+    let args = match &function.input {
+        Type::Struct {
+            fields: Fields::Named { named },
+            ..
+        } => named
+            .iter()
+            .map(|(_, ty)| types.rust_type(ty))
+            .collect::<Result<Vec<String>, String>>()?,
+        Type::Struct {
+            fields: Fields::Unnamed { unnamed },
+            ..
+        } => unnamed
+            .iter()
+            .map(|ty| types.rust_type(ty))
+            .collect::<Result<Vec<String>, String>>()?,
+        Type::Struct {
+            fields: Fields::Unit,
+            ..
+        } => Vec::new(),
+        _ => return Err("the function input".to_owned()),
+    };
+    let output = types.rust_type(&function.output)?;
+    Ok((args, output))
+}
+
+/// The custom (struct and enum) types of a blueprint ABI, and their Rust declarations.
+struct CustomTypes {
+    used_names: HashSet<String>,
+    /// The ABI types declared so far, with the name of their Rust declaration
+    declared: Vec<(Type, String)>,
+    definitions: Vec<String>,
+}
+
+impl CustomTypes {
+    fn new(stub_names: &[&str]) -> Self {
+        Self {
+            used_names: RESERVED_TYPE_NAMES
+                .iter()
+                .chain(stub_names)
+                .map(|name| name.to_string())
+                .collect(),
+            declared: Vec::new(),
+            definitions: Vec::new(),
+        }
+    }
+
+    /// Returns the Rust type of an ABI type, declaring any custom types it uses.
+    fn rust_type(&mut self, ty: &Type) -> Result<String, String> {
+        let rust_type = match ty {
+            Type::Unit => "()".to_owned(),
+            Type::Bool => "bool".to_owned(),
+            Type::I8 => "i8".to_owned(),
+            Type::I16 => "i16".to_owned(),
+            Type::I32 => "i32".to_owned(),
+            Type::I64 => "i64".to_owned(),
+            Type::I128 => "i128".to_owned(),
+            Type::U8 => "u8".to_owned(),
+            Type::U16 => "u16".to_owned(),
+            Type::U32 => "u32".to_owned(),
+            Type::U64 => "u64".to_owned(),
+            Type::U128 => "u128".to_owned(),
+            Type::String => "String".to_owned(),
+            Type::Array {
+                element_type,
+                length,
+            } => format!("[{}; {}]", self.rust_type(element_type)?, length),
+            Type::Tuple { element_types } => {
+                let element_types = element_types
+                    .iter()
+                    .map(|ty| self.rust_type(ty))
+                    .collect::<Result<Vec<String>, String>>()?;
+                if element_types.len() == 1 {
+                    format!("({},)", element_types[0])
+                } else {
+                    format!("({})", element_types.join(", "))
+                }
+            }
+            Type::Struct { name, .. } | Type::Enum { name, .. } => self.declare(name, ty)?,
+            Type::Option { some_type } => format!("Option<{}>", self.rust_type(some_type)?),
+            Type::Result {
+                okay_type,
+                err_type,
+            } => format!(
+                "Result<{}, {}>",
+                self.rust_type(okay_type)?,
+                self.rust_type(err_type)?
+            ),
+            Type::Vec { element_type } => format!("Vec<{}>", self.rust_type(element_type)?),
+            Type::TreeSet { element_type } => {
+                format!("BTreeSet<{}>", self.rust_type(element_type)?)
+            }
+            Type::TreeMap {
+                key_type,
+                value_type,
+            } => format!(
+                "BTreeMap<{}, {}>",
+                self.rust_type(key_type)?,
+                self.rust_type(value_type)?
+            ),
+            Type::HashSet { element_type } => {
+                format!("HashSet<{}>", self.rust_type(element_type)?)
+            }
+            Type::HashMap {
+                key_type,
+                value_type,
+            } => format!(
+                "HashMap<{}, {}>",
+                self.rust_type(key_type)?,
+                self.rust_type(value_type)?
+            ),
+            Type::PackageAddress => "PackageAddress".to_owned(),
+            Type::ComponentAddress => "ComponentAddress".to_owned(),
+            Type::ResourceAddress => "ResourceAddress".to_owned(),
+            Type::SystemAddress => "SystemAddress".to_owned(),
+            Type::Component => "Component".to_owned(),
+            Type::KeyValueStore {
+                key_type,
+                value_type,
+            } => format!(
+                "KeyValueStore<{}, {}>",
+                self.rust_type(key_type)?,
+                self.rust_type(value_type)?
+            ),
+            Type::Bucket => "Bucket".to_owned(),
+            Type::BorrowedBucket => "&Bucket".to_owned(),
+            Type::Proof => "Proof".to_owned(),
+            Type::Vault => "Vault".to_owned(),
+            Type::Expression => "Expression".to_owned(),
+            Type::Blob => "Blob".to_owned(),
+            Type::NonFungibleAddress => "NonFungibleAddress".to_owned(),
+            Type::Hash => "Hash".to_owned(),
+            Type::EcdsaSecp256k1PublicKey => "EcdsaSecp256k1PublicKey".to_owned(),
+            Type::EcdsaSecp256k1Signature => "EcdsaSecp256k1Signature".to_owned(),
+            Type::EddsaEd25519PublicKey => "EddsaEd25519PublicKey".to_owned(),
+            Type::EddsaEd25519Signature => "EddsaEd25519Signature".to_owned(),
+            Type::Decimal => "Decimal".to_owned(),
+            Type::PreciseDecimal => "PreciseDecimal".to_owned(),
+            Type::NonFungibleId => "NonFungibleId".to_owned(),
+            Type::Any => return Err("Any".to_owned()),
+        };
+        Ok(rust_type)
+    }
+
+    /// Declares a struct or enum, unless an identical one is already declared, and returns its
+    /// Rust name.
+    fn declare(&mut self, name: &str, ty: &Type) -> Result<String, String> {
+        if let Some((_, rust_name)) = self.declared.iter().find(|(declared, _)| declared == ty) {
+            return Ok(rust_name.clone());
+        }
+
+        let mut definition = String::new();
+        match ty {
+            Type::Struct { fields, .. } => {
+                let fields = self.render_fields(fields, "pub ")?;
+                let rust_name = self.allocate_name(name);
+                Self::write_header(&mut definition, name, &rust_name);
+                let separator = if fields.starts_with(" {") { "" } else { ";" };
+                writeln!(
+                    definition,
+                    "pub struct {}{}{}",
+                    rust_name, fields, separator
+                )
+                .unwrap();
+                self.finish(ty, rust_name, definition)
+            }
+            Type::Enum { variants, .. } => {
+                let variants = variants
+                    .iter()
+                    .map(|variant| {
+                        self.render_fields(&variant.fields, "")
+                            .map(|fields| format!("{}{}", escape_ident(&variant.name), fields))
+                    })
+                    .collect::<Result<Vec<String>, String>>()?;
+                let rust_name = self.allocate_name(name);
+                Self::write_header(&mut definition, name, &rust_name);
+                writeln!(definition, "pub enum {} {{", rust_name).unwrap();
+                for variant in variants {
+                    writeln!(definition, "    {},", variant.replace('\n', "\n    ")).unwrap();
+                }
+                writeln!(definition, "}}").unwrap();
+                self.finish(ty, rust_name, definition)
+            }
+            _ => unreachable!("Only structs and enums are declared"),
+        }
+    }
+
+    /// Renders the fields of a struct or enum variant, in the form following its name.
+    fn render_fields(&mut self, fields: &Fields, visibility: &str) -> Result<String, String> {
+        let rendered = match fields {
+            Fields::Named { named } => {
+                let mut rendered = " {\n".to_owned();
+                for (name, ty) in named {
+                    writeln!(
+                        rendered,
+                        "    {}{}: {},",
+                        visibility,
+                        escape_ident(name),
+                        self.rust_type(ty)?
+                    )
+                    .unwrap();
+                }
+                rendered.push('}');
+                rendered
+            }
+            Fields::Unnamed { unnamed } => {
+                let types = unnamed
+                    .iter()
+                    .map(|ty| self.rust_type(ty).map(|ty| format!("{}{}", visibility, ty)))
+                    .collect::<Result<Vec<String>, String>>()?;
+                format!("({})", types.join(", "))
+            }
+            Fields::Unit => String::new(),
+        };
+        Ok(rendered)
+    }
+
+    fn allocate_name(&mut self, name: &str) -> String {
+        let mut rust_name = name.to_owned();
+        let mut suffix = 2;
+        while self.used_names.contains(&rust_name) || KEYWORDS.contains(&rust_name.as_str()) {
+            rust_name = format!("{}{}", name, suffix);
+            suffix += 1;
+        }
+        self.used_names.insert(rust_name.clone());
+        rust_name
+    }
+
+    fn write_header(definition: &mut String, name: &str, rust_name: &str) {
+        if rust_name != name {
+            writeln!(
+                definition,
+                "/// The `{}` type of the blueprint, renamed as its name is taken.",
+                name
+            )
+            .unwrap();
+        }
+        writeln!(
+            definition,
+            "#[scrypto(TypeId, Encode, Decode, Describe)]"
+        )
+        .unwrap();
+    }
+
+    fn finish(
+        &mut self,
+        ty: &Type,
+        rust_name: String,
+        definition: String,
+    ) -> Result<String, String> {
+        self.declared.push((ty.clone(), rust_name.clone()));
+        self.definitions.push(definition);
+        Ok(rust_name)
+    }
+}
+
+/// Escapes a field, variant or function name which is a Rust keyword.
+fn escape_ident(name: &str) -> String {
+    match name {
+        // These can't be raw identifiers
+        "self" | "Self" | "super" | "crate" | "_" => format!("{}_", name),
+        _ if KEYWORDS.contains(&name) => format!("r#{}", name),
+        _ => name.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use radix_engine_interface::abi::{SelfMutability, Variant};
+
+    fn function(
+        ident: &str,
+        mutability: Option<SelfMutability>,
+        inputs: Vec<Type>,
+        output: Type,
+    ) -> Fn {
+        Fn {
+            ident: ident.to_owned(),
+            mutability,
+            input: Type::Struct {
+                name: format!("Fixture_{}_Input", ident),
+                fields: Fields::Named {
+                    named: inputs
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, ty)| (format!("arg{}", i), ty))
+                        .collect(),
+                },
+            },
+            output,
+            export_name: format!("Fixture_{}", ident),
+        }
+    }
+
+    fn fixture_abi() -> BlueprintAbi {
+        let config = Type::Struct {
+            name: "Config".to_owned(),
+            fields: Fields::Named {
+                named: vec![
+                    ("type".to_owned(), Type::U8),
+                    ("fee".to_owned(), Type::Decimal),
+                ],
+            },
+        };
+        let status = Type::Enum {
+            name: "Status".to_owned(),
+            variants: vec![
+                Variant {
+                    name: "Open".to_owned(),
+                    fields: Fields::Unit,
+                },
+                Variant {
+                    name: "Closed".to_owned(),
+                    fields: Fields::Unnamed {
+                        unnamed: vec![Type::Option {
+                            some_type: Box::new(Type::String),
+                        }],
+                    },
+                },
+            ],
+        };
+        let shadowing = Type::Struct {
+            name: "Decimal".to_owned(),
+            fields: Fields::Unnamed {
+                unnamed: vec![Type::I128],
+            },
+        };
+        BlueprintAbi {
+            structure: Type::Unit,
+            fns: vec![
+                function(
+                    "instantiate",
+                    None,
+                    vec![config.clone(), Type::Bucket],
+                    Type::Tuple {
+                        element_types: vec![Type::ComponentAddress, Type::Bucket],
+                    },
+                ),
+                function("at", None, vec![], Type::Unit),
+                function(
+                    "status",
+                    Some(SelfMutability::Immutable),
+                    vec![],
+                    status.clone(),
+                ),
+                function(
+                    "swap",
+                    Some(SelfMutability::Mutable),
+                    vec![
+                        Type::BorrowedBucket,
+                        Type::HashMap {
+                            key_type: Box::new(Type::ResourceAddress),
+                            value_type: Box::new(Type::Vec {
+                                element_type: Box::new(Type::NonFungibleId),
+                            }),
+                        },
+                    ],
+                    Type::Result {
+                        okay_type: Box::new(Type::Bucket),
+                        err_type: Box::new(status),
+                    },
+                ),
+                function(
+                    "raw",
+                    Some(SelfMutability::Immutable),
+                    vec![shadowing],
+                    Type::Unit,
+                ),
+                function("match", Some(SelfMutability::Immutable), vec![], config),
+                function(
+                    "anything",
+                    Some(SelfMutability::Immutable),
+                    vec![Type::Any],
+                    Type::Unit,
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_generate_rust_bindings() {
+        let bindings = generate_rust_bindings("package_sim1fixture", "Fixture", &fixture_abi());
+
+        assert!(bindings.starts_with(
+            "//! Rust bindings for the `Fixture` blueprint of package `package_sim1fixture`."
+        ));
+        assert!(bindings.contains("\nuse scrypto::prelude::*;\n"));
+
+        // Stubs
+        assert!(bindings
+            .contains("pub struct FixtureBlueprint {\n    package_address: PackageAddress,\n}"));
+        assert!(bindings.contains(
+            "pub struct FixtureComponent {\n    component_address: ComponentAddress,\n}"
+        ));
+        assert!(bindings.contains("impl From<ComponentAddress> for FixtureComponent {"));
+
+        // Functions and methods
+        assert!(bindings.contains(
+            "    pub fn instantiate(&self, arg0: Config, arg1: Bucket) -> (ComponentAddress, Bucket) {\n        \
+             Runtime::call_function(self.package_address, \"Fixture\", \"instantiate\", args!(arg0, arg1))\n    }"
+        ));
+        assert!(bindings.contains(
+            "    /// Calls `at`.\n    pub fn at_(&self) {\n        \
+             Runtime::call_function(self.package_address, \"Fixture\", \"at\", args!())\n    }"
+        ));
+        assert!(bindings.contains(
+            "    pub fn status(&self) -> Status {\n        \
+             Runtime::call_method(self.component_address, \"status\", args!())\n    }"
+        ));
+        assert!(bindings.contains(
+            "    pub fn swap(&self, arg0: &Bucket, arg1: HashMap<ResourceAddress, Vec<NonFungibleId>>) -> Result<Bucket, Status> {"
+        ));
+        assert!(bindings.contains("    pub fn raw(&self, arg0: Decimal2) {"));
+        assert!(bindings.contains(
+            "    /// Calls `match`.\n    pub fn r#match(&self) -> Config {\n        \
+             Runtime::call_method(self.component_address, \"match\", args!())\n    }"
+        ));
+        assert!(bindings.contains(
+            "    // TODO: `anything` is left out, as `Any` can't be represented in Rust\n"
+        ));
+
+        // Custom types are declared once, and escaped
+        assert_eq!(bindings.matches("pub struct Config {").count(), 1);
+        assert!(bindings.contains(
+            "#[scrypto(TypeId, Encode, Decode, Describe)]\npub struct Config {\n    pub r#type: u8,\n    pub fee: Decimal,\n}\n"
+        ));
+        assert!(bindings.contains("pub enum Status {\n    Open,\n    Closed(Option<String>),\n}\n"));
+        assert!(bindings.contains(
+            "/// The `Decimal` type of the blueprint, renamed as its name is taken.\n\
+             #[scrypto(TypeId, Encode, Decode, Describe)]\npub struct Decimal2(pub i128);\n"
+        ));
+    }
+}
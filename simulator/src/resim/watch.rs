@@ -0,0 +1,126 @@
+use notify::{Event, RecursiveMode, Watcher};
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::mpsc::channel;
+use std::time::Duration;
+
+use crate::resim::*;
+
+/// Changes arriving within this window are handled by a single rebuild.
+const DEBOUNCE_DURATION: Duration = Duration::from_millis(200);
+
+/// Calls `on_change` once, and again every time a `.rs` file under `source_dir` changes.
+///
+/// Each call runs against a fresh copy of the ledger, so the simulator state is left untouched.
+/// This function only returns when the watcher fails.
+pub fn watch_package<F>(source_dir: &Path, mut on_change: F) -> Result<(), Error>
+where
+    F: FnMut() -> Result<(), Error>,
+{
+    let data_dir = get_data_dir()?;
+    let mut snapshot_dir = std::env::temp_dir();
+    snapshot_dir.push(format!("resim-watch-{}", uuid::Uuid::new_v4()));
+
+    watch_sources(source_dir, || {
+        if snapshot_dir.exists() {
+            fs::remove_dir_all(&snapshot_dir).map_err(Error::IOError)?;
+        }
+        copy_dir(&data_dir, &snapshot_dir)?;
+
+        temp_env::with_var(ENV_DATA_DIR, Some(&snapshot_dir), || on_change())
+    })
+}
+
+/// Calls `on_change` once, and again every time a `.rs` file under `source_dir` changes.
+///
+/// Unlike [`watch_package`], each call runs against the simulator ledger itself. Errors returned
+/// by `on_change` are printed, and do not stop the watch. This function only returns when the
+/// watcher fails.
+pub fn watch_sources<F>(source_dir: &Path, mut on_change: F) -> Result<(), Error>
+where
+    F: FnMut() -> Result<(), Error>,
+{
+    let (sender, receiver) = channel();
+    let mut watcher = notify::recommended_watcher(sender).map_err(Error::WatchError)?;
+    watcher
+        .watch(source_dir, RecursiveMode::Recursive)
+        .map_err(Error::WatchError)?;
+
+    loop {
+        if let Err(error) = on_change() {
+            eprintln!("{:?}", error);
+        }
+        eprintln!("Waiting for changes in {}...", source_dir.display());
+
+        wait_for_source_change(&receiver, source_dir)?;
+        std::thread::sleep(DEBOUNCE_DURATION);
+        while receiver.try_recv().is_ok() {}
+    }
+}
+
+fn wait_for_source_change(
+    receiver: &std::sync::mpsc::Receiver<notify::Result<Event>>,
+    source_dir: &Path,
+) -> Result<(), Error> {
+    loop {
+        let event = receiver
+            .recv()
+            .map_err(|_| Error::WatchError(notify::Error::generic("Watcher disconnected")))?
+            .map_err(Error::WatchError)?;
+        if event
+            .paths
+            .iter()
+            .any(|path| is_source_file(path, source_dir))
+        {
+            return Ok(());
+        }
+    }
+}
+
+/// Whether the path is a `.rs` file of the package, ignoring build outputs.
+fn is_source_file(path: &Path, source_dir: &Path) -> bool {
+    let in_target_dir = path
+        .strip_prefix(source_dir)
+        .unwrap_or(path)
+        .components()
+        .any(|c| c == Component::Normal(OsStr::new("target")));
+    path.extension() == Some(OsStr::new("rs")) && !in_target_dir
+}
+
+fn copy_dir(from: &Path, to: &PathBuf) -> Result<(), Error> {
+    fs::create_dir_all(to).map_err(Error::IOError)?;
+    for entry in fs::read_dir(from).map_err(Error::IOError)? {
+        let entry = entry.map_err(Error::IOError)?;
+        let mut target = to.clone();
+        target.push(entry.file_name());
+        if entry.file_type().map_err(Error::IOError)?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), &target).map_err(Error::IOError)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_source_file() {
+        let source_dir = Path::new("/tmp/hello");
+        assert!(is_source_file(
+            Path::new("/tmp/hello/src/lib.rs"),
+            source_dir
+        ));
+        assert!(!is_source_file(
+            Path::new("/tmp/hello/Cargo.toml"),
+            source_dir
+        ));
+        assert!(!is_source_file(
+            Path::new("/tmp/hello/target/release/build/out.rs"),
+            source_dir
+        ));
+    }
+}
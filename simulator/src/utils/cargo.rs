@@ -1,3 +1,4 @@
+use std::env;
 use std::ffi::OsStr;
 use std::fs;
 use std::io;
@@ -10,6 +11,7 @@ use cargo_toml::Manifest;
 use radix_engine::model::extract_abi;
 use radix_engine::model::ExtractAbiError;
 use radix_engine::types::*;
+use radix_engine::wasm::{PrepareError, WasmModule};
 
 #[derive(Debug)]
 pub enum BuildError {
@@ -30,6 +32,10 @@ pub enum BuildError {
     AbiEncodeError(sbor::EncodeError),
 
     InvalidManifestFile(PathBuf),
+
+    RustcFailure(ExitStatus),
+
+    FingerprintEmbedError(PrepareError),
 }
 
 #[derive(Debug)]
@@ -57,6 +63,8 @@ fn run_cargo_build(
     target_path: impl AsRef<OsStr>,
     trace: bool,
     no_abi_gen: bool,
+    locked: bool,
+    rustflags: &[String],
 ) -> Result<(), BuildError> {
     let mut features = Vec::<String>::new();
     if trace {
@@ -69,6 +77,10 @@ fn run_cargo_build(
         features.insert(0, "--features".to_owned());
     }
 
+    if locked {
+        features.push("--locked".to_owned());
+    }
+
     let status = Command::new("cargo")
         .arg("build")
         .arg("--target")
@@ -79,6 +91,7 @@ fn run_cargo_build(
         .arg("--manifest-path")
         .arg(manifest_path.as_ref())
         .args(features)
+        .env("CARGO_ENCODED_RUSTFLAGS", rustflags.join("\x1f"))
         .status()
         .map_err(BuildError::IOError)?;
     if status.success() {
@@ -116,13 +129,143 @@ pub fn get_default_target_directory(
     }
 }
 
+/// Returns the rustflags to build a package with: those of the environment, plus flags that
+/// replace the paths of the package and the cargo home directory in the output, so that the
+/// code doesn't depend on where it was built.
+fn get_rustflags(base_path: &Path) -> Result<Vec<String>, BuildError> {
+    let mut rustflags: Vec<String> = match env::var("CARGO_ENCODED_RUSTFLAGS") {
+        Ok(flags) => flags
+            .split('\x1f')
+            .filter(|f| !f.is_empty())
+            .map(str::to_owned)
+            .collect(),
+        Err(_) => env::var("RUSTFLAGS")
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(str::to_owned)
+            .collect(),
+    };
+
+    let package_dir = base_path
+        .canonicalize()
+        .map_err(|err| BuildError::IOErrorAtPath(err, base_path.to_owned()))?;
+    rustflags.push(format!(
+        "--remap-path-prefix={}=/package",
+        package_dir.display()
+    ));
+    let cargo_home = env::var_os("CARGO_HOME")
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|home| home.join(".cargo")));
+    if let Some(cargo_home) = cargo_home {
+        rustflags.push(format!(
+            "--remap-path-prefix={}=/cargo",
+            cargo_home.display()
+        ));
+    }
+    Ok(rustflags)
+}
+
+/// Gets the version of the Rust toolchain that builds the package, e.g.
+/// `rustc 1.65.0 (897e37553 2022-11-02)`.
+/// This respects any toolchain override of the package directory.
+fn get_toolchain_version(base_path: &Path) -> Result<String, BuildError> {
+    let output = Command::new("rustc")
+        .current_dir(base_path)
+        .arg("--version")
+        .output()
+        .map_err(BuildError::IOError)?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_owned())
+    } else {
+        Err(BuildError::RustcFailure(output.status))
+    }
+}
+
+/// Collects the source files of a package, along with their paths relative to the package
+/// using `/` as separator. Hidden files and the `target` directory are skipped.
+fn collect_source_files(
+    base_path: &Path,
+    dir: &Path,
+    files: &mut Vec<(String, PathBuf)>,
+) -> Result<(), BuildError> {
+    for entry in fs::read_dir(dir).map_err(|err| BuildError::IOErrorAtPath(err, dir.to_owned()))? {
+        let path = entry.map_err(BuildError::IOError)?.path();
+        let relative_path = path
+            .strip_prefix(base_path)
+            .expect("Source file is outside of the package")
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        if relative_path.split('/').any(|name| name.starts_with('.')) {
+            continue;
+        }
+        if path.is_dir() {
+            if relative_path != "target" {
+                collect_source_files(base_path, &path, files)?;
+            }
+        } else {
+            files.push((relative_path, path));
+        }
+    }
+    Ok(())
+}
+
+/// Computes the build fingerprint of a package.
+///
+/// The source hash is taken over the source files sorted by relative path, each contributing
+/// its path, its length and its content, so that it doesn't depend on the file system.
+pub fn compute_build_fingerprint<P: AsRef<Path>>(
+    base_path: P,
+) -> Result<BuildFingerprint, BuildError> {
+    let base_path = base_path.as_ref();
+
+    let mut files = Vec::new();
+    collect_source_files(base_path, base_path, &mut files)?;
+    files.sort();
+
+    let mut source = Vec::new();
+    for (relative_path, path) in files {
+        let content =
+            fs::read(&path).map_err(|err| BuildError::IOErrorAtPath(err, path.clone()))?;
+        source.extend(relative_path.as_bytes());
+        source.push(0);
+        source.extend((content.len() as u64).to_le_bytes());
+        source.extend(content);
+    }
+
+    Ok(BuildFingerprint {
+        source_hash: hash(source),
+        toolchain: get_toolchain_version(base_path)?,
+    })
+}
+
 /// Builds a package.
+///
+/// The build fingerprint of the package is embedded in the resulting WASM.
 pub fn build_package<P: AsRef<Path>>(
     base_path: P,
     trace: bool,
     force_local_target: bool,
 ) -> Result<(PathBuf, PathBuf), BuildError> {
-    let base_path = base_path.as_ref().to_owned();
+    build(base_path.as_ref(), trace, force_local_target, false)
+}
+
+/// Builds a package as [`build_package`] does, but with the dependency versions of the
+/// existing `Cargo.lock`, failing if it needs to be updated.
+pub fn build_package_locked<P: AsRef<Path>>(
+    base_path: P,
+) -> Result<(PathBuf, PathBuf), BuildError> {
+    build(base_path.as_ref(), false, false, true)
+}
+
+fn build(
+    base_path: &Path,
+    trace: bool,
+    force_local_target: bool,
+    locked: bool,
+) -> Result<(PathBuf, PathBuf), BuildError> {
+    let base_path = base_path.to_owned();
 
     let mut manifest_path = base_path.clone();
     manifest_path.push("Cargo.toml");
@@ -146,8 +289,17 @@ pub fn build_package<P: AsRef<Path>>(
     out_path.push("wasm32-unknown-unknown");
     out_path.push("release");
 
+    let rustflags = get_rustflags(&base_path)?;
+
     // Build with ABI
-    run_cargo_build(&manifest_path, &target_path, trace, false)?;
+    run_cargo_build(
+        &manifest_path,
+        &target_path,
+        trace,
+        false,
+        locked,
+        &rustflags,
+    )?;
 
     // Find the binary paths
     let manifest = Manifest::from_path(&manifest_path)
@@ -178,7 +330,24 @@ pub fn build_package<P: AsRef<Path>>(
     .map_err(|err| BuildError::IOErrorAtPath(err, abi_path.clone()))?;
 
     // Build without ABI
-    run_cargo_build(&manifest_path, &target_path, trace, true)?;
+    run_cargo_build(
+        &manifest_path,
+        &target_path,
+        trace,
+        true,
+        locked,
+        &rustflags,
+    )?;
+
+    // Embed the build fingerprint, which is computed after building as that may create `Cargo.lock`
+    let fingerprint = compute_build_fingerprint(&base_path)?;
+    let wasm =
+        fs::read(&wasm_path).map_err(|err| BuildError::IOErrorAtPath(err, wasm_path.clone()))?;
+    let (wasm, _) = WasmModule::init(&wasm)
+        .and_then(|module| module.with_build_fingerprint(&fingerprint))
+        .and_then(WasmModule::to_bytes)
+        .map_err(BuildError::FingerprintEmbedError)?;
+    fs::write(&wasm_path, wasm).map_err(|err| BuildError::IOErrorAtPath(err, wasm_path.clone()))?;
 
     Ok((wasm_path, abi_path))
 }
@@ -1,7 +1,9 @@
 mod cargo;
 mod display;
 mod iter;
+mod verify;
 
 pub use cargo::*;
 pub use display::list_item_prefix;
 pub use iter::{IdentifyLast, Iter};
+pub use verify::*;
@@ -0,0 +1,198 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use radix_engine::types::*;
+use radix_engine::wasm::{PrepareError, WasmModule};
+
+use crate::utils::*;
+
+/// A way in which package code differs from the code built from a source.
+#[derive(Debug)]
+pub enum Mismatch {
+    /// The package code has no build fingerprint.
+    MissingFingerprint,
+
+    /// The build fingerprint of the package code can't be read.
+    InvalidFingerprint(PrepareError),
+
+    /// The source could not be built.
+    BuildError(BuildError),
+
+    /// The package was built from a different source.
+    SourceHash { expected: Hash, actual: Hash },
+
+    /// The package was built with a different toolchain.
+    Toolchain { expected: String, actual: String },
+
+    /// The package code differs from the rebuilt code.
+    CodeHash { expected: Hash, actual: Hash },
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Mismatch::MissingFingerprint => write!(f, "The package has no build fingerprint"),
+            Mismatch::InvalidFingerprint(e) => {
+                write!(
+                    f,
+                    "The build fingerprint of the package is invalid: {:?}",
+                    e
+                )
+            }
+            Mismatch::BuildError(e) => write!(f, "Failed to build the source: {:?}", e),
+            Mismatch::SourceHash { expected, actual } => write!(
+                f,
+                "Source hash differs: expected {}, actual {}",
+                expected, actual
+            ),
+            Mismatch::Toolchain { expected, actual } => write!(
+                f,
+                "Toolchain differs: expected {}, actual {}",
+                expected, actual
+            ),
+            Mismatch::CodeHash { expected, actual } => write!(
+                f,
+                "Code hash differs: expected {}, actual {}",
+                expected, actual
+            ),
+        }
+    }
+}
+
+/// All the ways in which package code differs from the code built from a source.
+#[derive(Debug)]
+pub struct MismatchReport {
+    pub mismatches: Vec<Mismatch>,
+}
+
+/// Rebuilds the package at `source_dir` with its `Cargo.lock` and checks that both the build
+/// fingerprint and the code match `package_code`.
+///
+/// A different toolchain usually produces different code from the same source, so a toolchain
+/// mismatch is reported alongside the code hash mismatch it causes.
+pub fn verify_package_source(package_code: &[u8], source_dir: &Path) -> Result<(), MismatchReport> {
+    let mut mismatches = Vec::new();
+
+    let expected_fingerprint =
+        match WasmModule::init(package_code).and_then(|module| module.build_fingerprint()) {
+            Ok(Some(fingerprint)) => Some(fingerprint),
+            Ok(None) => {
+                mismatches.push(Mismatch::MissingFingerprint);
+                None
+            }
+            Err(e) => {
+                mismatches.push(Mismatch::InvalidFingerprint(e));
+                None
+            }
+        };
+
+    let rebuilt = build_package_locked(source_dir).and_then(|(wasm_path, _)| {
+        let code = fs::read(&wasm_path)
+            .map_err(|err| BuildError::IOErrorAtPath(err, wasm_path.clone()))?;
+        let fingerprint = compute_build_fingerprint(source_dir)?;
+        Ok((code, fingerprint))
+    });
+    let (code, fingerprint) = match rebuilt {
+        Ok(rebuilt) => rebuilt,
+        Err(e) => {
+            mismatches.push(Mismatch::BuildError(e));
+            return Err(MismatchReport { mismatches });
+        }
+    };
+
+    if let Some(expected) = expected_fingerprint {
+        if expected.source_hash != fingerprint.source_hash {
+            mismatches.push(Mismatch::SourceHash {
+                expected: expected.source_hash,
+                actual: fingerprint.source_hash,
+            });
+        }
+        if expected.toolchain != fingerprint.toolchain {
+            mismatches.push(Mismatch::Toolchain {
+                expected: expected.toolchain,
+                actual: fingerprint.toolchain,
+            });
+        }
+    }
+
+    let expected_code_hash = hash(package_code);
+    let actual_code_hash = hash(&code);
+    if expected_code_hash != actual_code_hash {
+        mismatches.push(Mismatch::CodeHash {
+            expected: expected_code_hash,
+            actual: actual_code_hash,
+        });
+    }
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(MismatchReport { mismatches })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn copy_dir(from: &Path, to: &Path) {
+        fs::create_dir_all(to).unwrap();
+        for entry in fs::read_dir(from).unwrap() {
+            let path = entry.unwrap().path();
+            let target = to.join(path.file_name().unwrap());
+            if path.is_dir() {
+                if path.file_name().unwrap() != "target" {
+                    copy_dir(&path, &target);
+                }
+            } else {
+                fs::copy(&path, &target).unwrap();
+            }
+        }
+    }
+
+    /// Copies the test blueprints to a fresh directory at the same depth, so that their path
+    /// dependencies still resolve, and builds them.
+    fn build_fixture() -> (PathBuf, Vec<u8>) {
+        let source_dir = PathBuf::from(format!("./target/verify-{}", uuid::Uuid::new_v4()));
+        copy_dir(Path::new("./tests/blueprints"), &source_dir);
+        let (wasm_path, _) = build_package(&source_dir, false, false).unwrap();
+        let code = fs::read(wasm_path).unwrap();
+        (source_dir, code)
+    }
+
+    #[test]
+    fn test_verify_package_built_from_source() {
+        // Arrange
+        let (source_dir, code) = build_fixture();
+
+        // Act
+        let result = verify_package_source(&code, &source_dir);
+
+        // Assert
+        assert!(result.is_ok(), "{:?}", result);
+        fs::remove_dir_all(source_dir).unwrap();
+    }
+
+    #[test]
+    fn test_verify_package_detects_source_change() {
+        // Arrange
+        let (source_dir, code) = build_fixture();
+        let lib_path = source_dir.join("src/lib.rs");
+        let mut lib = fs::read_to_string(&lib_path).unwrap();
+        lib.push_str("\n// One more line\n");
+        fs::write(&lib_path, lib).unwrap();
+
+        // Act
+        let result = verify_package_source(&code, &source_dir);
+
+        // Assert
+        let report = result.unwrap_err();
+        assert!(report
+            .mismatches
+            .iter()
+            .any(|mismatch| matches!(mismatch, Mismatch::SourceHash { .. })));
+        fs::remove_dir_all(source_dir).unwrap();
+    }
+}
@@ -40,6 +40,10 @@ $resim call-method $component free_token
 
 # Test - export abi
 $resim export-abi $package Hello
+mkdir -p target
+$resim export-abi $package Hello --rust-bindings ./target/hello_bindings.rs
+grep -q 'pub fn instantiate_hello(&self) -> ComponentAddress' ./target/hello_bindings.rs
+grep -q 'pub fn free_token(&self) -> Bucket' ./target/hello_bindings.rs
 
 # Test - dump component state
 $resim show $package
@@ -48,6 +52,12 @@ $resim show $account
 $resim show $account2
 $resim show $token_address
 
+# Test - show the top holders of a resource
+fixed_token=`$resim new-token-fixed 1000 | awk '/Resource:/ {print $NF}'`
+$resim transfer 300 $fixed_token $account2
+holders=`$resim show $fixed_token --top 2 | grep -A2 'Holders' | tail -n 2 | awk '{print $2 $3}' | tr '\n' ' '`
+test "$holders" = "$account:700 $account2:300 "
+
 # Test - output manifest
 mkdir -p target
 $resim new-badge-fixed 1 --name 'MintBadge' --manifest ./target/temp.rtm
@@ -83,6 +93,12 @@ $resim call-method $component organizational_authenticated_method --proofs 1,$su
 $resim transfer 2 $token $account2 --proofs 1,$supervisor_badge 1,$admin_badge 1,$superadmin_badge
 $resim mint 100000 $token --proofs 1,$supervisor_badge 1,$admin_badge 1,$superadmin_badge
 
+# Test - the authenticated method can only be called with the badges put in the auth zone
+if $resim call-method $component organizational_authenticated_method; then
+    exit 1
+fi
+$resim call-method $component organizational_authenticated_method --proofs $supervisor_badge:1 $admin_badge:1 $superadmin_badge
+
 # Test - publishing a large package
 $resim publish ./tests/large_package.wasm --owner-badge $owner_badge
 
@@ -19,8 +19,12 @@ test_crates_features \
     radix-engine-derive \
     radix-engine-interface \
     radix-engine \
+    radix-engine-ffi \
     transaction"
 
+echo "Checking test vectors..."
+(cd radix-engine; cargo run --features test-vectors --bin generate_test_vectors -- --check)
+
 echo "Testing scrypto packages..."
 test_packages \
     "assets/blueprints/account \
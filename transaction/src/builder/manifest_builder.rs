@@ -2,9 +2,10 @@ use radix_engine_interface::abi;
 use radix_engine_interface::abi::*;
 use radix_engine_interface::address::Bech32Decoder;
 use radix_engine_interface::api::types::{
-    BucketId, GlobalAddress, NativeFunctionIdent, NativeMethodIdent, PackageFunction, ProofId,
-    RENodeId, ResourceManagerFunction, ResourceManagerMethod, ScryptoFunctionIdent,
-    ScryptoMethodIdent, ScryptoPackage, ScryptoReceiver,
+    BucketId, BurnerMethod, GlobalAddress, NativeFunctionIdent, NativeMethodIdent, PackageFunction,
+    ProofId, RENodeId, ResourceManagerFunction, ResourceManagerMethod, ScryptoFunctionIdent,
+    ScryptoMethodIdent, ScryptoPackage, ScryptoReceiver, TimeLockedVaultFunction,
+    TimeLockedVaultMethod,
 };
 use radix_engine_interface::constants::*;
 use radix_engine_interface::core::NetworkDefinition;
@@ -22,6 +23,7 @@ use sbor::rust::string::ToString;
 use sbor::rust::vec::Vec;
 
 use crate::errors::*;
+use crate::manifest::generator::generate_lock_resource_auth;
 use crate::model::*;
 use crate::validation::*;
 
@@ -39,6 +41,8 @@ macro_rules! args_from_bytes_vec {
 
 /// Utility for building transaction manifest.
 pub struct ManifestBuilder {
+    /// The network the manifest is built for
+    network: NetworkDefinition,
     /// The decoder used by the manifest (mainly for the `call_*_with_abi)
     decoder: Bech32Decoder,
     /// ID validator for calculating transaction object id
@@ -46,17 +50,18 @@ pub struct ManifestBuilder {
     /// Instructions generated.
     instructions: Vec<Instruction>,
     /// Blobs
-    blobs: HashMap<Hash, Vec<u8>>,
+    blobs: BTreeMap<Hash, Vec<u8>>,
 }
 
 impl ManifestBuilder {
     /// Starts a new transaction builder.
     pub fn new(network: &NetworkDefinition) -> Self {
         Self {
+            network: network.clone(),
             decoder: Bech32Decoder::new(network),
             id_validator: IdValidator::new(),
             instructions: Vec::new(),
-            blobs: HashMap::default(),
+            blobs: BTreeMap::default(),
         }
     }
 
@@ -71,15 +76,42 @@ impl ManifestBuilder {
         match inst.clone() {
             Instruction::TakeFromWorktop { .. }
             | Instruction::TakeFromWorktopByAmount { .. }
+            | Instruction::TakeFromWorktopOrDefault { .. }
             | Instruction::TakeFromWorktopByIds { .. } => {
                 new_bucket_id = Some(self.id_validator.new_bucket().unwrap());
             }
+            Instruction::CallMethodWithProofReturn { args, .. } => {
+                let scrypt_value = IndexedScryptoValue::from_slice(&args).unwrap();
+                self.id_validator.move_resources(&scrypt_value).unwrap();
+                new_proof_id = Some(
+                    self.id_validator
+                        .new_proof(ProofKind::ReturnedProof)
+                        .unwrap(),
+                );
+            }
             Instruction::ReturnToWorktop { bucket_id } => {
                 self.id_validator.drop_bucket(bucket_id).unwrap();
             }
             Instruction::AssertWorktopContains { .. }
             | Instruction::AssertWorktopContainsByAmount { .. }
+            | Instruction::AssertWorktopContainsExactly { .. }
             | Instruction::AssertWorktopContainsByIds { .. } => {}
+            Instruction::IfWorktopContains {
+                then_instructions,
+                else_instructions,
+                ..
+            } => {
+                let mut then_builder = self.branch(None);
+                for inst in then_instructions {
+                    then_builder.add_instruction(inst);
+                }
+                let mut else_builder = self.branch(Some(&then_builder));
+                for inst in else_instructions {
+                    else_builder.add_instruction(inst);
+                }
+                self.id_validator
+                    .merge_branches(&[then_builder.id_validator, else_builder.id_validator]);
+            }
             Instruction::PopFromAuthZone { .. } => {
                 new_proof_id = Some(
                     self.id_validator
@@ -123,7 +155,14 @@ impl ManifestBuilder {
                 let scrypt_value = IndexedScryptoValue::from_slice(&args).unwrap();
                 self.id_validator.move_resources(&scrypt_value).unwrap();
             }
-            Instruction::PublishPackageWithOwner { .. } => {}
+            Instruction::CallMethodWithReturn { args, .. } => {
+                let scrypt_value = IndexedScryptoValue::from_slice(&args).unwrap();
+                self.id_validator.move_resources(&scrypt_value).unwrap();
+                new_bucket_id = Some(self.id_validator.new_bucket().unwrap());
+            }
+            Instruction::DepositEntireWorktop { .. }
+            | Instruction::SetLocal { .. }
+            | Instruction::PublishPackageWithOwner { .. } => {}
         }
 
         self.instructions.push(inst);
@@ -158,6 +197,23 @@ impl ManifestBuilder {
         then(builder, bucket_id.unwrap())
     }
 
+    /// Takes resource from worktop, by amount, or an empty bucket if the worktop has none of it.
+    pub fn take_from_worktop_or_default<F>(
+        &mut self,
+        amount: Decimal,
+        resource_address: ResourceAddress,
+        then: F,
+    ) -> &mut Self
+    where
+        F: FnOnce(&mut Self, BucketId) -> &mut Self,
+    {
+        let (builder, bucket_id, _) = self.add_instruction(Instruction::TakeFromWorktopOrDefault {
+            amount,
+            resource_address,
+        });
+        then(builder, bucket_id.unwrap())
+    }
+
     /// Takes resource from worktop, by non-fungible ids.
     pub fn take_from_worktop_by_ids<F>(
         &mut self,
@@ -187,7 +243,7 @@ impl ManifestBuilder {
             .0
     }
 
-    /// Asserts that worktop contains resource.
+    /// Asserts that worktop contains at least the given amount of resource.
     pub fn assert_worktop_contains_by_amount(
         &mut self,
         amount: Decimal,
@@ -200,6 +256,64 @@ impl ManifestBuilder {
         .0
     }
 
+    /// Asserts that worktop contains exactly the given amount of resource.
+    pub fn assert_worktop_contains_exactly(
+        &mut self,
+        amount: Decimal,
+        resource_address: ResourceAddress,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::AssertWorktopContainsExactly {
+            amount,
+            resource_address,
+        })
+        .0
+    }
+
+    /// Adds a conditional block, which runs the instructions added by `then_branch` if the worktop
+    /// satisfies the condition, or else those added by `else_branch`.
+    ///
+    /// Each branch is built on a builder which sees the buckets and proofs from before the block.
+    /// Buckets and proofs created in a branch can't be used after the block.
+    pub fn if_worktop_contains<F, G>(
+        &mut self,
+        condition: WorktopCondition,
+        then_branch: F,
+        else_branch: G,
+    ) -> &mut Self
+    where
+        F: FnOnce(&mut Self) -> &mut Self,
+        G: FnOnce(&mut Self) -> &mut Self,
+    {
+        let mut then_builder = self.branch(None);
+        then_branch(&mut then_builder);
+        let mut else_builder = self.branch(Some(&then_builder));
+        else_branch(&mut else_builder);
+
+        self.blobs.extend(then_builder.blobs);
+        self.blobs.extend(else_builder.blobs);
+        self.add_instruction(Instruction::IfWorktopContains {
+            condition,
+            then_instructions: then_builder.instructions,
+            else_instructions: else_builder.instructions,
+        })
+        .0
+    }
+
+    /// Creates the builder for a branch of a conditional block.
+    fn branch(&self, preceding: Option<&ManifestBuilder>) -> Self {
+        Self {
+            network: self.network.clone(),
+            decoder: Bech32Decoder {
+                hrp_set: self.decoder.hrp_set.clone(),
+            },
+            id_validator: self
+                .id_validator
+                .branch(preceding.map(|builder| &builder.id_validator)),
+            instructions: Vec::new(),
+            blobs: BTreeMap::default(),
+        }
+    }
+
     /// Asserts that worktop contains resource.
     pub fn assert_worktop_contains_by_ids(
         &mut self,
@@ -315,7 +429,7 @@ impl ManifestBuilder {
     pub fn create_resource<R: Into<AccessRule>>(
         &mut self,
         resource_type: ResourceType,
-        metadata: HashMap<String, String>,
+        metadata: BTreeMap<String, String>,
         access_rules: HashMap<ResourceMethodAuthKey, (AccessRule, R)>,
         mint_params: Option<MintParams>,
     ) -> &mut Self {
@@ -327,6 +441,8 @@ impl ManifestBuilder {
                 .map(|(k, v)| (k, (v.0, v.1.into())))
                 .collect(),
             mint_params,
+            non_fungible_data_schema: None,
+            track_holders: false,
         };
 
         self.add_instruction(Instruction::CallNativeFunction {
@@ -438,6 +554,50 @@ impl ManifestBuilder {
         self
     }
 
+    /// Calls a scrypto method which returns a bucket, and passes the returned bucket on rather
+    /// than moving it to the worktop.
+    pub fn call_method_with_return<F>(
+        &mut self,
+        component_address: ComponentAddress,
+        method_name: &str,
+        args: Vec<u8>,
+        then: F,
+    ) -> &mut Self
+    where
+        F: FnOnce(&mut Self, BucketId) -> &mut Self,
+    {
+        let (builder, bucket_id, _) = self.add_instruction(Instruction::CallMethodWithReturn {
+            method_ident: ScryptoMethodIdent {
+                receiver: ScryptoReceiver::Global(component_address),
+                method_name: method_name.to_owned(),
+            },
+            args,
+        });
+        then(builder, bucket_id.unwrap())
+    }
+
+    /// Calls a scrypto method which returns a proof, and passes the returned proof on rather than
+    /// pushing it onto the auth zone.
+    pub fn call_method_with_proof_return<F>(
+        &mut self,
+        component_address: ComponentAddress,
+        method_name: &str,
+        args: Vec<u8>,
+        then: F,
+    ) -> &mut Self
+    where
+        F: FnOnce(&mut Self, ProofId) -> &mut Self,
+    {
+        let (builder, _, proof_id) = self.add_instruction(Instruction::CallMethodWithProofReturn {
+            method_ident: ScryptoMethodIdent {
+                receiver: ScryptoReceiver::Global(component_address),
+                method_name: method_name.to_owned(),
+            },
+            args,
+        });
+        then(builder, proof_id.unwrap())
+    }
+
     /// Calls a native method where the arguments should be an array of encoded Scrypto value.
     pub fn call_native_method(
         &mut self,
@@ -498,7 +658,7 @@ impl ManifestBuilder {
         code: Vec<u8>,
         abi: HashMap<String, BlueprintAbi>,
         royalty_config: HashMap<String, RoyaltyConfig>,
-        metadata: HashMap<String, String>,
+        metadata: BTreeMap<String, String>,
         access_rules: AccessRules,
     ) -> &mut Self {
         let code_hash = hash(&code);
@@ -559,7 +719,7 @@ impl ManifestBuilder {
     /// Creates a token resource with mutable supply.
     pub fn new_token_mutable(
         &mut self,
-        metadata: HashMap<String, String>,
+        metadata: BTreeMap<String, String>,
         minter_rule: AccessRule,
     ) -> &mut Self {
         let mut resource_auth = HashMap::new();
@@ -581,6 +741,8 @@ impl ManifestBuilder {
                 metadata,
                 access_rules: resource_auth,
                 mint_params,
+                non_fungible_data_schema: None,
+                track_holders: false,
             })
             .unwrap(),
         })
@@ -590,7 +752,7 @@ impl ManifestBuilder {
     /// Creates a token resource with fixed supply.
     pub fn new_token_fixed(
         &mut self,
-        metadata: HashMap<String, String>,
+        metadata: BTreeMap<String, String>,
         initial_supply: Decimal,
     ) -> &mut Self {
         let mut resource_auth = HashMap::new();
@@ -611,6 +773,8 @@ impl ManifestBuilder {
                 mint_params: Option::Some(MintParams::Fungible {
                     amount: initial_supply.into(),
                 }),
+                non_fungible_data_schema: None,
+                track_holders: false,
             })
             .unwrap(),
         })
@@ -620,7 +784,7 @@ impl ManifestBuilder {
     /// Creates a badge resource with mutable supply.
     pub fn new_badge_mutable(
         &mut self,
-        metadata: HashMap<String, String>,
+        metadata: BTreeMap<String, String>,
         minter_rule: AccessRule,
     ) -> &mut Self {
         let mut resource_auth = HashMap::new();
@@ -643,6 +807,8 @@ impl ManifestBuilder {
                 metadata,
                 access_rules: resource_auth,
                 mint_params,
+                non_fungible_data_schema: None,
+                track_holders: false,
             })
             .unwrap(),
         })
@@ -652,7 +818,7 @@ impl ManifestBuilder {
     /// Creates a badge resource with fixed supply.
     pub fn new_badge_fixed(
         &mut self,
-        metadata: HashMap<String, String>,
+        metadata: BTreeMap<String, String>,
         initial_supply: Decimal,
     ) -> &mut Self {
         let mut resource_auth = HashMap::new();
@@ -673,6 +839,8 @@ impl ManifestBuilder {
                 mint_params: Option::Some(MintParams::Fungible {
                     amount: initial_supply.into(),
                 }),
+                non_fungible_data_schema: None,
+                track_holders: false,
             })
             .unwrap(),
         })
@@ -695,6 +863,26 @@ impl ManifestBuilder {
         self
     }
 
+    /// Mints non-fungible resource, with the given ids and (immutable, mutable) data.
+    pub fn mint_non_fungible(
+        &mut self,
+        resource_address: ResourceAddress,
+        entries: HashMap<NonFungibleId, (Vec<u8>, Vec<u8>)>,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::CallNativeMethod {
+            method_ident: NativeMethodIdent {
+                receiver: RENodeId::Global(GlobalAddress::Resource(resource_address)),
+                method_name: ResourceManagerMethod::Mint.to_string(),
+            },
+            args: scrypto_encode(&ResourceManagerMintInvocation {
+                receiver: resource_address,
+                mint_params: MintParams::NonFungible { entries },
+            })
+            .unwrap(),
+        });
+        self
+    }
+
     /// Burns a resource.
     pub fn burn(&mut self, resource_address: ResourceAddress, amount: Decimal) -> &mut Self {
         self.take_from_worktop_by_amount(amount, resource_address, |builder, bucket_id| {
@@ -740,6 +928,127 @@ impl ManifestBuilder {
         )
     }
 
+    /// Sends all of a resource on the worktop to the system burner, which burns it if permitted
+    /// and otherwise locks it away for good.
+    pub fn burn_via_sink(&mut self, resource_address: ResourceAddress) -> &mut Self {
+        self.take_from_worktop(resource_address, |builder, bucket_id| {
+            builder
+                .add_instruction(Instruction::CallNativeMethod {
+                    method_ident: NativeMethodIdent {
+                        receiver: RENodeId::Global(GlobalAddress::System(SYS_BURNER_COMPONENT)),
+                        method_name: BurnerMethod::Deposit.to_string(),
+                    },
+                    args: scrypto_encode(&BurnerDepositInvocation {
+                        receiver: SYS_BURNER_COMPONENT,
+                        bucket: Bucket(bucket_id),
+                    })
+                    .unwrap(),
+                })
+                .0
+        })
+    }
+
+    /// Creates a time-locked vault of a resource, which its beneficiary can only withdraw from
+    /// once the epoch reaches `unlock_epoch`.
+    pub fn create_time_locked_vault(
+        &mut self,
+        resource_address: ResourceAddress,
+        beneficiary_rule: AccessRule,
+        depositor_rule: AccessRule,
+        unlock_epoch: u64,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::CallNativeFunction {
+            function_ident: NativeFunctionIdent {
+                blueprint_name: TIME_LOCKED_VAULT_BLUEPRINT.to_string(),
+                function_name: TimeLockedVaultFunction::Create.to_string(),
+            },
+            args: scrypto_encode(&TimeLockedVaultCreateInvocation {
+                resource_address,
+                beneficiary_rule,
+                depositor_rule,
+                unlock_epoch,
+            })
+            .unwrap(),
+        });
+        self
+    }
+
+    /// Deposits all of a resource on the worktop into a time-locked vault.
+    pub fn deposit_to_time_locked_vault(
+        &mut self,
+        time_locked_vault: SystemAddress,
+        resource_address: ResourceAddress,
+    ) -> &mut Self {
+        self.take_from_worktop(resource_address, |builder, bucket_id| {
+            builder
+                .add_instruction(Instruction::CallNativeMethod {
+                    method_ident: NativeMethodIdent {
+                        receiver: RENodeId::Global(GlobalAddress::System(time_locked_vault)),
+                        method_name: TimeLockedVaultMethod::Deposit.to_string(),
+                    },
+                    args: scrypto_encode(&TimeLockedVaultDepositInvocation {
+                        receiver: time_locked_vault,
+                        bucket: Bucket(bucket_id),
+                    })
+                    .unwrap(),
+                })
+                .0
+        })
+    }
+
+    /// Withdraws from an unlocked time-locked vault onto the worktop.
+    pub fn withdraw_from_time_locked_vault(
+        &mut self,
+        time_locked_vault: SystemAddress,
+        amount: Decimal,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::CallNativeMethod {
+            method_ident: NativeMethodIdent {
+                receiver: RENodeId::Global(GlobalAddress::System(time_locked_vault)),
+                method_name: TimeLockedVaultMethod::Withdraw.to_string(),
+            },
+            args: scrypto_encode(&TimeLockedVaultWithdrawInvocation {
+                receiver: time_locked_vault,
+                amount,
+            })
+            .unwrap(),
+        });
+        self
+    }
+
+    /// Moves the unlock epoch of a time-locked vault to `unlock_epoch`, which must not be earlier
+    /// than the current one.
+    pub fn extend_time_locked_vault_lock(
+        &mut self,
+        time_locked_vault: SystemAddress,
+        unlock_epoch: u64,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::CallNativeMethod {
+            method_ident: NativeMethodIdent {
+                receiver: RENodeId::Global(GlobalAddress::System(time_locked_vault)),
+                method_name: TimeLockedVaultMethod::ExtendLock.to_string(),
+            },
+            args: scrypto_encode(&TimeLockedVaultExtendLockInvocation {
+                receiver: time_locked_vault,
+                unlock_epoch,
+            })
+            .unwrap(),
+        });
+        self
+    }
+
+    /// Locks an auth rule of a resource, so that it can never be changed again.
+    ///
+    /// The current rule stays in effect.
+    pub fn lock_resource_auth(
+        &mut self,
+        resource_address: ResourceAddress,
+        auth_key: ResourceMethodAuthKey,
+    ) -> &mut Self {
+        self.add_instruction(generate_lock_resource_auth(resource_address, auth_key));
+        self
+    }
+
     /// Creates an account.
     pub fn new_account(&mut self, withdraw_auth: &AccessRuleNode) -> &mut Self {
         self.add_instruction(Instruction::CallFunction {
@@ -843,6 +1152,21 @@ impl ManifestBuilder {
         .0
     }
 
+    /// Deposits everything on the worktop into an account.
+    pub fn deposit_entire_worktop(&mut self, account: ComponentAddress) -> &mut Self {
+        self.add_instruction(Instruction::DepositEntireWorktop { account })
+            .0
+    }
+
+    /// Sets a local variable, which later instructions can read with `Expression::local(name)`.
+    pub fn set_local<T: ScryptoEncode>(&mut self, name: &str, value: &T) -> &mut Self {
+        self.add_instruction(Instruction::SetLocal {
+            name: name.to_string(),
+            value: scrypto_encode(value).unwrap(),
+        })
+        .0
+    }
+
     /// Withdraws resource from an account.
     pub fn withdraw_from_account(
         &mut self,
@@ -893,6 +1217,128 @@ impl ManifestBuilder {
         .0
     }
 
+    /// Creates an allowance for the holder of a badge to pull resource from an account.
+    pub fn create_account_allowance(
+        &mut self,
+        account: ComponentAddress,
+        spender_badge: NonFungibleAddress,
+        resource_address: ResourceAddress,
+        per_period_amount: Decimal,
+        period_epochs: u64,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::CallMethod {
+            method_ident: ScryptoMethodIdent {
+                receiver: ScryptoReceiver::Global(account),
+                method_name: "create_allowance".to_string(),
+            },
+            args: args!(
+                spender_badge,
+                resource_address,
+                per_period_amount,
+                period_epochs
+            ),
+        })
+        .0
+    }
+
+    /// Pulls resource from an account against an allowance, presenting a proof of the spender badge.
+    pub fn pull_from_account_allowance(
+        &mut self,
+        account: ComponentAddress,
+        allowance_id: u64,
+        amount: Decimal,
+        spender_proof_id: ProofId,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::CallMethod {
+            method_ident: ScryptoMethodIdent {
+                receiver: ScryptoReceiver::Global(account),
+                method_name: "pull".to_string(),
+            },
+            args: args!(allowance_id, amount, Proof(spender_proof_id)),
+        })
+        .0
+    }
+
+    /// Revokes an allowance of an account.
+    pub fn revoke_account_allowance(
+        &mut self,
+        account: ComponentAddress,
+        allowance_id: u64,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::CallMethod {
+            method_ident: ScryptoMethodIdent {
+                receiver: ScryptoReceiver::Global(account),
+                method_name: "revoke_allowance".to_string(),
+            },
+            args: args!(allowance_id),
+        })
+        .0
+    }
+
+    /// Sets the deposit mode of an account.
+    pub fn set_account_deposit_mode(
+        &mut self,
+        account: ComponentAddress,
+        deposit_mode: AccountDepositMode,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::CallMethod {
+            method_ident: ScryptoMethodIdent {
+                receiver: ScryptoReceiver::Global(account),
+                method_name: "set_deposit_mode".to_string(),
+            },
+            args: args!(deposit_mode),
+        })
+        .0
+    }
+
+    /// Adds a resource to the allowlist of an account.
+    pub fn allow_account_deposits(
+        &mut self,
+        account: ComponentAddress,
+        resource_address: ResourceAddress,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::CallMethod {
+            method_ident: ScryptoMethodIdent {
+                receiver: ScryptoReceiver::Global(account),
+                method_name: "allow_resource".to_string(),
+            },
+            args: args!(resource_address),
+        })
+        .0
+    }
+
+    /// Adds a resource to the denylist of an account.
+    pub fn deny_account_deposits(
+        &mut self,
+        account: ComponentAddress,
+        resource_address: ResourceAddress,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::CallMethod {
+            method_ident: ScryptoMethodIdent {
+                receiver: ScryptoReceiver::Global(account),
+                method_name: "deny_resource".to_string(),
+            },
+            args: args!(resource_address),
+        })
+        .0
+    }
+
+    /// Deposits a bucket into an account, returning it to the worktop if the account refuses it.
+    pub fn try_deposit_to_account_or_refund(
+        &mut self,
+        account: ComponentAddress,
+        bucket_id: BucketId,
+    ) -> &mut Self {
+        self.add_instruction(Instruction::CallMethod {
+            method_ident: ScryptoMethodIdent {
+                receiver: ScryptoReceiver::Global(account),
+                method_name: "try_deposit_or_refund".to_string(),
+            },
+            args: args!(Bucket(bucket_id)),
+        })
+        .0
+    }
+
     /// Creates resource proof from an account.
     pub fn create_proof_from_account(
         &mut self,
@@ -943,14 +1389,75 @@ impl ManifestBuilder {
         .0
     }
 
+    /// Creates resource proof from an account, and passes the proof on rather than pushing it onto
+    /// the auth zone.
+    pub fn create_proof_from_account_returning<F>(
+        &mut self,
+        account: ComponentAddress,
+        resource_address: ResourceAddress,
+        then: F,
+    ) -> &mut Self
+    where
+        F: FnOnce(&mut Self, ProofId) -> &mut Self,
+    {
+        self.call_method_with_proof_return(account, "create_proof", args!(resource_address), then)
+    }
+
+    /// Creates resource proof from an account, by amount, and passes the proof on rather than
+    /// pushing it onto the auth zone.
+    pub fn create_proof_from_account_by_amount_returning<F>(
+        &mut self,
+        account: ComponentAddress,
+        amount: Decimal,
+        resource_address: ResourceAddress,
+        then: F,
+    ) -> &mut Self
+    where
+        F: FnOnce(&mut Self, ProofId) -> &mut Self,
+    {
+        self.call_method_with_proof_return(
+            account,
+            "create_proof_by_amount",
+            args!(amount, resource_address),
+            then,
+        )
+    }
+
+    /// Creates resource proof from an account, by non-fungible ids, and passes the proof on rather
+    /// than pushing it onto the auth zone.
+    pub fn create_proof_from_account_by_ids_returning<F>(
+        &mut self,
+        account: ComponentAddress,
+        ids: &BTreeSet<NonFungibleId>,
+        resource_address: ResourceAddress,
+        then: F,
+    ) -> &mut Self
+    where
+        F: FnOnce(&mut Self, ProofId) -> &mut Self,
+    {
+        self.call_method_with_proof_return(
+            account,
+            "create_proof_by_ids",
+            args!(ids.clone(), resource_address),
+            then,
+        )
+    }
+
     /// Creates resource proof from an account.
     pub fn create_proof_from_account_by_resource_specifier(
         &mut self,
         account: ComponentAddress,
         resource_specifier: String,
     ) -> Result<&mut Self, BuildArgsError> {
-        let resource_specifier = parse_resource_specifier(&resource_specifier, &self.decoder)
-            .map_err(|_| BuildArgsError::InvalidResourceSpecifier(resource_specifier))?;
+        let resource_specifier =
+            parse_resource_specifier(&resource_specifier, &self.network, &self.decoder).map_err(
+                |error| match error {
+                    ParseResourceSpecifierError::WrongNetwork { expected, found } => {
+                        BuildArgsError::WrongNetwork { expected, found }
+                    }
+                    _ => BuildArgsError::InvalidResourceSpecifier(resource_specifier),
+                },
+            )?;
         let builder = match resource_specifier {
             ResourceSpecifier::Amount(amount, resource_address) => {
                 self.create_proof_from_account_by_amount(account, amount, resource_address)
@@ -962,6 +1469,39 @@ impl ManifestBuilder {
         Ok(builder)
     }
 
+    /// Parses a package address, checking that it belongs to the manifest's network.
+    pub fn parse_package_address(
+        &self,
+        address: &str,
+    ) -> Result<PackageAddress, ParseAddressError> {
+        check_address_network(&self.network, address)?;
+        self.decoder
+            .validate_and_decode_package_address(address)
+            .map_err(ParseAddressError::InvalidAddress)
+    }
+
+    /// Parses a component address, checking that it belongs to the manifest's network.
+    pub fn parse_component_address(
+        &self,
+        address: &str,
+    ) -> Result<ComponentAddress, ParseAddressError> {
+        check_address_network(&self.network, address)?;
+        self.decoder
+            .validate_and_decode_component_address(address)
+            .map_err(ParseAddressError::InvalidAddress)
+    }
+
+    /// Parses a resource address, checking that it belongs to the manifest's network.
+    pub fn parse_resource_address(
+        &self,
+        address: &str,
+    ) -> Result<ResourceAddress, ParseAddressError> {
+        check_address_network(&self.network, address)?;
+        self.decoder
+            .validate_and_decode_resource_address(address)
+            .map_err(ParseAddressError::InvalidAddress)
+    }
+
     //===============================
     // private methods below
     //===============================
@@ -1010,29 +1550,20 @@ impl ManifestBuilder {
                         }
                         Type::PackageAddress => {
                             let value = self
-                                .decoder
-                                .validate_and_decode_package_address(arg)
-                                .map_err(|_| {
-                                    BuildArgsError::FailedToParse(i, t.clone(), arg.to_owned())
-                                })?;
+                                .parse_package_address(arg)
+                                .map_err(|error| Self::address_arg_error(error, i, t, arg))?;
                             Ok(scrypto_encode(&value).unwrap())
                         }
                         Type::ComponentAddress => {
                             let value = self
-                                .decoder
-                                .validate_and_decode_component_address(arg)
-                                .map_err(|_| {
-                                    BuildArgsError::FailedToParse(i, t.clone(), arg.to_owned())
-                                })?;
+                                .parse_component_address(arg)
+                                .map_err(|error| Self::address_arg_error(error, i, t, arg))?;
                             Ok(scrypto_encode(&value).unwrap())
                         }
                         Type::ResourceAddress => {
                             let value = self
-                                .decoder
-                                .validate_and_decode_resource_address(arg)
-                                .map_err(|_| {
-                                    BuildArgsError::FailedToParse(i, t.clone(), arg.to_owned())
-                                })?;
+                                .parse_resource_address(arg)
+                                .map_err(|error| Self::address_arg_error(error, i, t, arg))?;
                             Ok(scrypto_encode(&value).unwrap())
                         }
                         Type::Hash => {
@@ -1049,6 +1580,9 @@ impl ManifestBuilder {
                             Ok(scrypto_encode(&value).unwrap())
                         }
                         Type::NonFungibleAddress => {
+                            let resource_address = arg.split(':').next().unwrap_or_default();
+                            check_address_network(&self.network, resource_address)
+                                .map_err(|error| Self::address_arg_error(error, i, t, arg))?;
                             let value = NonFungibleAddress::try_from_canonical_combined_string(
                                 &self.decoder,
                                 arg,
@@ -1059,10 +1593,19 @@ impl ManifestBuilder {
                             Ok(scrypto_encode(&value).unwrap())
                         }
                         Type::Bucket => {
-                            let resource_specifier = parse_resource_specifier(arg, &self.decoder)
-                                .map_err(|_| {
-                                BuildArgsError::FailedToParse(i, t.clone(), arg.to_owned())
-                            })?;
+                            let resource_specifier =
+                                parse_resource_specifier(arg, &self.network, &self.decoder)
+                                    .map_err(|error| match error {
+                                        ParseResourceSpecifierError::WrongNetwork {
+                                            expected,
+                                            found,
+                                        } => BuildArgsError::WrongNetwork { expected, found },
+                                        _ => BuildArgsError::FailedToParse(
+                                            i,
+                                            t.clone(),
+                                            arg.to_owned(),
+                                        ),
+                                    })?;
                             let bucket_id = match resource_specifier {
                                 ResourceSpecifier::Amount(amount, resource_address) => {
                                     if let Some(account) = account {
@@ -1098,10 +1641,19 @@ impl ManifestBuilder {
                             Ok(scrypto_encode(&Bucket(bucket_id)).unwrap())
                         }
                         Type::Proof => {
-                            let resource_specifier = parse_resource_specifier(arg, &self.decoder)
-                                .map_err(|_| {
-                                BuildArgsError::FailedToParse(i, t.clone(), arg.to_owned())
-                            })?;
+                            let resource_specifier =
+                                parse_resource_specifier(arg, &self.network, &self.decoder)
+                                    .map_err(|error| match error {
+                                        ParseResourceSpecifierError::WrongNetwork {
+                                            expected,
+                                            found,
+                                        } => BuildArgsError::WrongNetwork { expected, found },
+                                        _ => BuildArgsError::FailedToParse(
+                                            i,
+                                            t.clone(),
+                                            arg.to_owned(),
+                                        ),
+                                    })?;
                             let proof_id = match resource_specifier {
                                 ResourceSpecifier::Amount(amount, resource_address) => {
                                     if let Some(account) = account {
@@ -1146,6 +1698,22 @@ impl ManifestBuilder {
         Ok(encoded)
     }
 
+    fn address_arg_error(
+        error: ParseAddressError,
+        i: usize,
+        t: &Type,
+        arg: &str,
+    ) -> BuildArgsError {
+        match error {
+            ParseAddressError::WrongNetwork { expected, found } => {
+                BuildArgsError::WrongNetwork { expected, found }
+            }
+            ParseAddressError::InvalidAddress(_) => {
+                BuildArgsError::FailedToParse(i, t.clone(), arg.to_owned())
+            }
+        }
+    }
+
     fn parse_basic_ty<T>(
         &mut self,
         i: usize,
@@ -1174,10 +1742,12 @@ enum ParseResourceSpecifierError {
     InvalidAmount(String),
     InvalidNonFungibleId(String),
     MoreThanOneAmountSpecified,
+    WrongNetwork { expected: String, found: String },
 }
 
 fn parse_resource_specifier(
     input: &str,
+    network: &NetworkDefinition,
     decoder: &Bech32Decoder,
 ) -> Result<ResourceSpecifier, ParseResourceSpecifierError> {
     let tokens: Vec<&str> = input.trim().split(',').map(|s| s.trim()).collect();
@@ -1189,6 +1759,11 @@ fn parse_resource_specifier(
 
     // parse resource address
     let resource_address_token = tokens[tokens.len() - 1];
+    if let Err(ParseAddressError::WrongNetwork { expected, found }) =
+        check_address_network(network, resource_address_token)
+    {
+        return Err(ParseResourceSpecifierError::WrongNetwork { expected, found });
+    }
     let resource_address = decoder
         .validate_and_decode_resource_address(resource_address_token)
         .map_err(|_| {
@@ -1222,3 +1797,81 @@ fn parse_resource_specifier(
         Ok(ResourceSpecifier::Amount(amount, resource_address))
     }
 }
+
+/// Checks the HRP of a Bech32 address string against the network, so that an address for another
+/// network is reported as such rather than as malformed. Strings which don't look like addresses
+/// are left for the decoder to reject.
+fn check_address_network(
+    network: &NetworkDefinition,
+    address: &str,
+) -> Result<(), ParseAddressError> {
+    let address = address.to_lowercase();
+    // Bech32 data never contains a `1`, so the last one separates the HRP from the data
+    let hrp_suffix = address
+        .rfind('1')
+        .and_then(|separator| address[..separator].split_once('_'))
+        .map(|(_, hrp_suffix)| hrp_suffix);
+    match hrp_suffix {
+        Some(hrp_suffix) if hrp_suffix != network.hrp_suffix => {
+            Err(ParseAddressError::WrongNetwork {
+                expected: network.logical_name.clone(),
+                found: NetworkDefinition::from_hrp_suffix(hrp_suffix)
+                    .map(|network| network.logical_name)
+                    .unwrap_or_else(|| hrp_suffix.to_owned()),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use radix_engine_interface::address::Bech32Encoder;
+
+    #[test]
+    fn test_parse_address_for_manifest_network() {
+        let address =
+            Bech32Encoder::for_simulator().encode_resource_address_to_string(&RADIX_TOKEN);
+
+        let builder = ManifestBuilder::new(&NetworkDefinition::simulator());
+
+        assert_eq!(builder.parse_resource_address(&address), Ok(RADIX_TOKEN));
+    }
+
+    #[test]
+    fn test_parse_address_for_other_network_fails() {
+        let address = Bech32Encoder::new(&NetworkDefinition::mainnet())
+            .encode_resource_address_to_string(&RADIX_TOKEN);
+
+        let builder = ManifestBuilder::new(&NetworkDefinition::simulator());
+
+        assert_eq!(
+            builder.parse_resource_address(&address),
+            Err(ParseAddressError::WrongNetwork {
+                expected: "simulator".to_owned(),
+                found: "mainnet".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_resource_specifier_for_other_network_fails_at_build_time() {
+        let address = Bech32Encoder::new(&NetworkDefinition::nebunet())
+            .encode_resource_address_to_string(&RADIX_TOKEN);
+
+        let mut builder = ManifestBuilder::new(&NetworkDefinition::simulator());
+        let result = builder
+            .create_proof_from_account_by_resource_specifier(
+                FAUCET_COMPONENT,
+                format!("1,{}", address),
+            )
+            .map(|_| ());
+
+        assert!(matches!(
+            result,
+            Err(BuildArgsError::WrongNetwork { expected, found })
+                if expected == "simulator" && found == "nebunet"
+        ));
+    }
+}
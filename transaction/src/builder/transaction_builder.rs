@@ -30,6 +30,19 @@ impl TransactionBuilder {
         self
     }
 
+    /// Starts from an intent built elsewhere, e.g. decoded from bytes for offline signing.
+    pub fn intent(mut self, intent: TransactionIntent) -> Self {
+        self.header = Some(intent.header);
+        self.manifest = Some(intent.manifest);
+        self
+    }
+
+    /// Starts from a signed intent built elsewhere, e.g. decoded from bytes for notarization.
+    pub fn signed_intent(mut self, signed_intent: SignedTransactionIntent) -> Self {
+        self.intent_signatures = signed_intent.intent_signatures;
+        self.intent(signed_intent.intent)
+    }
+
     pub fn sign<S: Signer>(mut self, signer: &S) -> Self {
         let intent = self.transaction_intent();
         let intent_payload = scrypto_encode(&intent).unwrap();
@@ -61,6 +74,16 @@ impl TransactionBuilder {
         }
     }
 
+    /// Builds the intent only, to be signed separately.
+    pub fn build_intent(&self) -> TransactionIntent {
+        self.transaction_intent()
+    }
+
+    /// Builds the signed intent only, to be notarized separately.
+    pub fn build_signed_intent(&self) -> SignedTransactionIntent {
+        self.signed_transaction_intent()
+    }
+
     fn transaction_intent(&self) -> TransactionIntent {
         TransactionIntent {
             manifest: self.manifest.clone().expect("Manifest not specified"),
@@ -112,4 +135,56 @@ mod tests {
         let bytes = transaction.to_bytes().unwrap();
         NotarizedTransaction::from_slice(&bytes).unwrap();
     }
+
+    #[test]
+    fn build_in_separate_stages() {
+        let signer = EcdsaSecp256k1PrivateKey::from_u64(1).unwrap();
+        let notary = EcdsaSecp256k1PrivateKey::from_u64(2).unwrap();
+        let header = TransactionHeader {
+            version: 1,
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: 0,
+            end_epoch_exclusive: 100,
+            nonce: 5,
+            notary_public_key: notary.public_key().into(),
+            notary_as_signatory: false,
+            cost_unit_limit: 1_000_000,
+            tip_percentage: 5,
+        };
+        let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+            .clear_auth_zone()
+            .build();
+
+        let intent_bytes = TransactionBuilder::new()
+            .header(header.clone())
+            .manifest(manifest.clone())
+            .build_intent()
+            .to_bytes()
+            .unwrap();
+        let signed_intent_bytes = TransactionBuilder::new()
+            .intent(TransactionIntent::from_slice(&intent_bytes).unwrap())
+            .sign(&signer)
+            .build_signed_intent()
+            .to_bytes()
+            .unwrap();
+        let transaction = TransactionBuilder::new()
+            .signed_intent(SignedTransactionIntent::from_slice(&signed_intent_bytes).unwrap())
+            .notarize(&notary)
+            .build();
+
+        let expected = TransactionBuilder::new()
+            .header(header)
+            .manifest(manifest)
+            .sign(&signer)
+            .notarize(&notary)
+            .build();
+        assert_eq!(transaction, expected);
+        assert_eq!(
+            transaction.intent_hash().unwrap(),
+            TransactionIntent::from_slice(&intent_bytes)
+                .unwrap()
+                .hash()
+                .unwrap()
+        );
+    }
 }
@@ -1,5 +1,7 @@
 use radix_engine_interface::abi::Type;
+use radix_engine_interface::address::AddressError;
 use radix_engine_interface::api::types::{BucketId, KeyValueStoreId, ProofId, VaultId};
+use radix_engine_interface::core::Expression;
 use radix_engine_interface::data::ScryptoValueDecodeError;
 use radix_engine_interface::model::*;
 use sbor::rust::string::String;
@@ -50,6 +52,13 @@ pub enum CallDataValidationError {
     IdValidationError(IdValidationError),
     VaultNotAllowed(VaultId),
     KeyValueStoreNotAllowed(KeyValueStoreId),
+    BucketNotAllowed(BucketId),
+    ProofNotAllowed(ProofId),
+    ExpressionNotAllowed(Expression),
+    InvalidResourceMetadata {
+        key: String,
+        reason: InvalidMetadataReason,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -62,6 +71,8 @@ pub enum TransactionValidationError {
     SignatureValidationError(SignatureValidationError),
     IdValidationError(IdValidationError),
     CallDataValidationError(CallDataValidationError),
+    TooManyInstructions { count: usize, limit: usize },
+    NestedConditionalBlock,
 }
 
 impl From<EncodeError> for TransactionValidationError {
@@ -86,6 +97,22 @@ pub enum BuildArgsError {
 
     /// Failed to interpret this string as a resource specifier
     InvalidResourceSpecifier(String),
+
+    /// An address argument belongs to a different network than the manifest.
+    WrongNetwork {
+        expected: String,
+        found: String,
+    },
+}
+
+/// Represents an error when parsing an address for a manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseAddressError {
+    /// The address belongs to a different network than the manifest.
+    WrongNetwork { expected: String, found: String },
+
+    /// The string is not a valid address of the requested type.
+    InvalidAddress(AddressError),
 }
 
 /// Represents an error when building a transaction.
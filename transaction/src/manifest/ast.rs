@@ -11,6 +11,12 @@ pub enum Instruction {
         new_bucket: Value,
     },
 
+    TakeFromWorktopOrDefault {
+        amount: Value,
+        resource_address: Value,
+        new_bucket: Value,
+    },
+
     TakeFromWorktopByIds {
         ids: Value,
         resource_address: Value,
@@ -30,11 +36,29 @@ pub enum Instruction {
         resource_address: Value,
     },
 
+    AssertWorktopContainsExactly {
+        amount: Value,
+        resource_address: Value,
+    },
+
     AssertWorktopContainsByIds {
         ids: Value,
         resource_address: Value,
     },
 
+    IfWorktopContains {
+        resource_address: Value,
+        then_instructions: Vec<Instruction>,
+        else_instructions: Vec<Instruction>,
+    },
+
+    IfWorktopContainsByAmount {
+        amount: Value,
+        resource_address: Value,
+        then_instructions: Vec<Instruction>,
+        else_instructions: Vec<Instruction>,
+    },
+
     PopFromAuthZone {
         new_proof: Value,
     },
@@ -91,6 +115,29 @@ pub enum Instruction {
         args: Vec<Value>,
     },
 
+    CallMethodWithReturn {
+        receiver: ScryptoReceiver,
+        method: Value,
+        args: Vec<Value>,
+        new_bucket: Value,
+    },
+
+    CallMethodWithProofReturn {
+        receiver: ScryptoReceiver,
+        method: Value,
+        args: Vec<Value>,
+        new_proof: Value,
+    },
+
+    DepositEntireWorktop {
+        account: Value,
+    },
+
+    SetLocal {
+        name: Value,
+        value: Value,
+    },
+
     CallNativeFunction {
         blueprint_name: Value,
         function_name: Value,
@@ -124,6 +171,16 @@ pub enum Instruction {
         resource_address: Value,
         amount: Value,
     },
+
+    MintNonFungible {
+        resource_address: Value,
+        entries: Value,
+    },
+
+    LockResourceAuth {
+        resource_address: Value,
+        auth_key: Value,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -152,6 +209,8 @@ pub enum RENode {
     Package(Value),
     EpochManager(Value),
     Clock(Value),
+    Burner(Value),
+    TimeLockedVault(Value),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -246,6 +305,11 @@ pub enum Value {
 
     Bytes(Box<Value>),
 
+    /// A pre-encoded value, in hex, whose type is only known once decoded.
+    Raw(Box<Value>),
+
+    Local(Box<Value>),
+
     // ==============
     // Custom Types
     // ==============
@@ -280,8 +344,9 @@ pub enum Value {
 }
 
 impl Value {
-    pub const fn kind(&self) -> Type {
-        match self {
+    /// Returns the type of this value, or `None` for a raw value.
+    pub const fn kind(&self) -> Option<Type> {
+        let ty = match self {
             // ==============
             // Basic Types
             // ==============
@@ -310,6 +375,8 @@ impl Value {
             Value::Ok(_) => Type::Enum,
             Value::Err(_) => Type::Enum,
             Value::Bytes(_) => Type::Bytes,
+            Value::Raw(_) => return None,
+            Value::Local(_) => Type::Expression,
 
             // ==============
             // Custom Types
@@ -342,6 +409,7 @@ impl Value {
             Value::Decimal(_) => Type::Decimal,
             Value::PreciseDecimal(_) => Type::PreciseDecimal,
             Value::NonFungibleId(_) => Type::NonFungibleId,
-        }
+        };
+        Some(ty)
     }
 }
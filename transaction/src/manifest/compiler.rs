@@ -168,11 +168,13 @@ mod tests {
                     },
                     args: scrypto_encode(&ResourceManagerCreateInvocation {
                         resource_type: ResourceType::Fungible { divisibility: 0 },
-                        metadata: HashMap::new(),
+                        metadata: BTreeMap::new(),
                         access_rules: HashMap::new(),
                         mint_params: Some(MintParams::Fungible {
                             amount: "1.0".into()
-                        })
+                        }),
+                        non_fungible_data_schema: None,
+                        track_holders: false,
                     }).unwrap(),
                 },
                 Instruction::CallMethod {
@@ -1,7 +1,8 @@
 use radix_engine_interface::address::{AddressError, Bech32Encoder};
 use radix_engine_interface::api::types::{
-    BucketId, GlobalAddress, NativeFunctionIdent, NativeMethodIdent, ProofId, RENodeId,
-    ScryptoFunctionIdent, ScryptoMethodIdent, ScryptoPackage, ScryptoReceiver,
+    BucketId, GlobalAddress, NativeFunction, NativeFunctionIdent, NativeMethodIdent, ProofId,
+    RENodeId, ResourceManagerFunction, ScryptoFunctionIdent, ScryptoMethodIdent, ScryptoPackage,
+    ScryptoReceiver,
 };
 use radix_engine_interface::core::NetworkDefinition;
 use radix_engine_interface::data::{
@@ -15,6 +16,7 @@ use sbor::*;
 use utils::ContextualDisplay;
 
 use crate::errors::*;
+use crate::manifest::generator::generate_lock_resource_auth;
 use crate::model::*;
 use crate::validation::*;
 
@@ -98,6 +100,24 @@ pub fn decompile(
     Ok(buf)
 }
 
+/// Writes the instructions of a branch as a `{ ... }` block, one indented instruction per line.
+fn decompile_block<F: fmt::Write>(
+    f: &mut F,
+    instructions: &[Instruction],
+    context: &mut DecompilationContext,
+) -> Result<(), DecompileError> {
+    f.write_str("{\n")?;
+    for inst in instructions {
+        let mut buf = String::new();
+        decompile_instruction(&mut buf, inst, context)?;
+        for line in buf.lines() {
+            writeln!(f, "    {}", line)?;
+        }
+    }
+    f.write_str("}")?;
+    Ok(())
+}
+
 pub fn decompile_instruction<F: fmt::Write>(
     f: &mut F,
     instruction: &Instruction,
@@ -136,6 +156,24 @@ pub fn decompile_instruction<F: fmt::Write>(
                 name
             )?;
         }
+        Instruction::TakeFromWorktopOrDefault {
+            amount,
+            resource_address,
+        } => {
+            let bucket_id = context
+                .id_allocator
+                .new_bucket_id()
+                .map_err(DecompileError::IdAllocationError)?;
+            let name = format!("bucket{}", context.bucket_names.len() + 1);
+            context.bucket_names.insert(bucket_id, name.clone());
+            write!(
+                f,
+                "TAKE_FROM_WORKTOP_OR_DEFAULT Decimal(\"{}\") ResourceAddress(\"{}\") Bucket(\"{}\");",
+                amount,
+                resource_address.display(context.bech32_encoder),
+                name
+            )?;
+        }
         Instruction::TakeFromWorktopByIds {
             ids,
             resource_address,
@@ -175,6 +213,34 @@ pub fn decompile_instruction<F: fmt::Write>(
                 resource_address.display(context.bech32_encoder)
             )?;
         }
+        Instruction::IfWorktopContains {
+            condition,
+            then_instructions,
+            else_instructions,
+        } => {
+            match condition {
+                WorktopCondition::Contains { resource_address } => write!(
+                    f,
+                    "IF_WORKTOP_CONTAINS ResourceAddress(\"{}\") ",
+                    resource_address.display(context.bech32_encoder)
+                )?,
+                WorktopCondition::ContainsByAmount {
+                    amount,
+                    resource_address,
+                } => write!(
+                    f,
+                    "IF_WORKTOP_CONTAINS_BY_AMOUNT Decimal(\"{}\") ResourceAddress(\"{}\") ",
+                    amount,
+                    resource_address.display(context.bech32_encoder)
+                )?,
+            }
+            decompile_block(f, then_instructions, context)?;
+            if !else_instructions.is_empty() {
+                f.write_str(" ELSE ")?;
+                decompile_block(f, else_instructions, context)?;
+            }
+            f.write_str(";")?;
+        }
         Instruction::AssertWorktopContainsByAmount {
             amount,
             resource_address,
@@ -186,6 +252,17 @@ pub fn decompile_instruction<F: fmt::Write>(
                 resource_address.display(context.bech32_encoder)
             )?;
         }
+        Instruction::AssertWorktopContainsExactly {
+            amount,
+            resource_address,
+        } => {
+            write!(
+                f,
+                "ASSERT_WORKTOP_CONTAINS_EXACTLY Decimal(\"{}\") ResourceAddress(\"{}\");",
+                amount,
+                resource_address.display(context.bech32_encoder)
+            )?;
+        }
         Instruction::AssertWorktopContainsByIds {
             ids,
             resource_address,
@@ -333,6 +410,53 @@ pub fn decompile_instruction<F: fmt::Write>(
         Instruction::CallMethod { method_ident, args } => {
             decompile_call_scrypto_method(f, context, method_ident, args)?
         }
+        Instruction::CallMethodWithReturn { method_ident, args } => {
+            f.write_str(&format!(
+                "CALL_METHOD_WITH_RETURN {} \"{}\"",
+                format_scrypto_receiver(context, &method_ident.receiver),
+                method_ident.method_name
+            ))?;
+            format_args(f, context, args)?;
+            let bucket_id = context
+                .id_allocator
+                .new_bucket_id()
+                .map_err(DecompileError::IdAllocationError)?;
+            let name = format!("bucket{}", context.bucket_names.len() + 1);
+            write!(f, " Bucket(\"{}\");", name)?;
+            context.bucket_names.insert(bucket_id, name);
+        }
+        Instruction::CallMethodWithProofReturn { method_ident, args } => {
+            f.write_str(&format!(
+                "CALL_METHOD_WITH_PROOF_RETURN {} \"{}\"",
+                format_scrypto_receiver(context, &method_ident.receiver),
+                method_ident.method_name
+            ))?;
+            format_args(f, context, args)?;
+            let proof_id = context
+                .id_allocator
+                .new_proof_id()
+                .map_err(DecompileError::IdAllocationError)?;
+            let name = format!("proof{}", context.proof_names.len() + 1);
+            write!(f, " Proof(\"{}\");", name)?;
+            context.proof_names.insert(proof_id, name);
+        }
+        Instruction::DepositEntireWorktop { account } => {
+            write!(
+                f,
+                "DEPOSIT_ENTIRE_WORKTOP {};",
+                format_scrypto_receiver(context, &ScryptoReceiver::Global(*account))
+            )?;
+        }
+        Instruction::SetLocal { name, value } => {
+            let value = IndexedScryptoValue::from_slice(&value)
+                .map_err(|_| DecompileError::InvalidArguments)?;
+            write!(
+                f,
+                "SET_LOCAL \"{}\" {};",
+                name,
+                value.display(context.for_value_display())
+            )?;
+        }
         Instruction::CallNativeFunction {
             function_ident,
             args,
@@ -389,9 +513,9 @@ pub fn decompile_call_native_function<F: fmt::Write>(
     // Try to recognize the invocation
     let blueprint_name = &function_ident.blueprint_name;
     let function_name = &function_ident.function_name;
-    match (blueprint_name.as_str(), function_name.as_ref()) {
-        ("ResourceManager", "burn") => {
-            if let Ok(input) = scrypto_decode::<ResourceManagerBurnInvocation>(&args) {
+    match NativeFunction::from_ident(blueprint_name, function_name) {
+        Some(NativeFunction::ResourceManager(ResourceManagerFunction::BurnBucket)) => {
+            if let Ok(input) = scrypto_decode::<ResourceManagerBucketBurnInvocation>(&args) {
                 write!(
                     f,
                     "BURN_BUCKET Bucket({});",
@@ -404,8 +528,16 @@ pub fn decompile_call_native_function<F: fmt::Write>(
                 return Ok(());
             }
         }
-        ("ResourceManager", "create") => {
-            if let Ok(input) = scrypto_decode::<ResourceManagerCreateInvocation>(&args) {
+        Some(NativeFunction::ResourceManager(ResourceManagerFunction::Create)) => {
+            // A non-fungible data schema or holder tracking can't be expressed with CREATE_RESOURCE
+            if let Ok(
+                input @ ResourceManagerCreateInvocation {
+                    non_fungible_data_schema: None,
+                    track_holders: false,
+                    ..
+                },
+            ) = scrypto_decode::<ResourceManagerCreateInvocation>(&args)
+            {
                 f.write_str(&format!(
                     "CREATE_RESOURCE {} {} {} {};",
                     IndexedScryptoValue::from_typed(&input.resource_type)
@@ -440,7 +572,18 @@ pub fn decompile_call_scrypto_method<F: fmt::Write>(
     method_ident: &ScryptoMethodIdent,
     args: &Vec<u8>,
 ) -> Result<(), DecompileError> {
-    let receiver = match method_ident.receiver {
+    f.write_str(&format!(
+        "CALL_METHOD {} \"{}\"",
+        format_scrypto_receiver(context, &method_ident.receiver),
+        method_ident.method_name
+    ))?;
+    format_args(f, context, args)?;
+    f.write_str(";")?;
+    Ok(())
+}
+
+fn format_scrypto_receiver(context: &DecompilationContext, receiver: &ScryptoReceiver) -> String {
+    match receiver {
         ScryptoReceiver::Global(address) => {
             format!(
                 "ComponentAddress(\"{}\")",
@@ -448,16 +591,9 @@ pub fn decompile_call_scrypto_method<F: fmt::Write>(
             )
         }
         ScryptoReceiver::Component(id) => {
-            format!("Component(\"{}\")", format_id(&id))
+            format!("Component(\"{}\")", format_id(id))
         }
-    };
-    f.write_str(&format!(
-        "CALL_METHOD {} \"{}\"",
-        receiver, method_ident.method_name
-    ))?;
-    format_args(f, context, args)?;
-    f.write_str(";")?;
-    Ok(())
+    }
 }
 
 pub fn decompile_call_native_method<F: fmt::Write>(
@@ -470,14 +606,56 @@ pub fn decompile_call_native_method<F: fmt::Write>(
     match (method_ident.receiver, method_ident.method_name.as_ref()) {
         (RENodeId::Global(GlobalAddress::Resource(resource_address)), "mint") => {
             if let Ok(input) = scrypto_decode::<ResourceManagerMintInvocation>(&args) {
-                if let MintParams::Fungible { amount } = input.mint_params {
-                    write!(
-                        f,
-                        "MINT_FUNGIBLE ResourceAddress(\"{}\") Decimal(\"{}\");",
-                        resource_address.display(context.bech32_encoder),
-                        amount,
-                    )?;
+                match input.mint_params {
+                    MintParams::Fungible { amount } => {
+                        write!(
+                            f,
+                            "MINT_FUNGIBLE ResourceAddress(\"{}\") Decimal(\"{}\");",
+                            resource_address.display(context.bech32_encoder),
+                            amount,
+                        )?;
+                        return Ok(());
+                    }
+                    MintParams::NonFungible { entries } => {
+                        if let Some(entries) = format_non_fungible_mint_entries(entries) {
+                            write!(
+                                f,
+                                "MINT_NON_FUNGIBLE ResourceAddress(\"{}\") {};",
+                                resource_address.display(context.bech32_encoder),
+                                entries.display(context.for_value_display()),
+                            )?;
+                            return Ok(());
+                        }
+                    }
                 }
+            }
+        }
+        (RENodeId::Global(GlobalAddress::Resource(resource_address)), _) => {
+            let instruction = Instruction::CallNativeMethod {
+                method_ident: method_ident.clone(),
+                args: args.clone(),
+            };
+            let auth_key = [
+                ResourceMethodAuthKey::Mint,
+                ResourceMethodAuthKey::Burn,
+                ResourceMethodAuthKey::UpdateNonFungibleData,
+                ResourceMethodAuthKey::UpdateMetadata,
+                ResourceMethodAuthKey::Withdraw,
+                ResourceMethodAuthKey::Deposit,
+                ResourceMethodAuthKey::Recall,
+                ResourceMethodAuthKey::CreateSyntheticProof,
+            ]
+            .into_iter()
+            .find(|auth_key| {
+                generate_lock_resource_auth(resource_address, *auth_key) == instruction
+            });
+            if let Some(auth_key) = auth_key {
+                write!(
+                    f,
+                    "LOCK_RESOURCE_AUTH ResourceAddress(\"{}\") Enum(\"{:?}\");",
+                    resource_address.display(context.bech32_encoder),
+                    auth_key,
+                )?;
                 return Ok(());
             }
         }
@@ -517,6 +695,34 @@ pub fn format_args<F: fmt::Write>(
     Ok(())
 }
 
+/// Converts non-fungible mint entries into an `Array<Tuple>` of `(id, (immutable, mutable))`,
+/// with the data decoded, or `None` if any of the data isn't a valid SBOR value.
+fn format_non_fungible_mint_entries(
+    entries: HashMap<NonFungibleId, (Vec<u8>, Vec<u8>)>,
+) -> Option<IndexedScryptoValue> {
+    let entries: BTreeMap<NonFungibleId, (Vec<u8>, Vec<u8>)> = entries.into_iter().collect();
+    let mut elements = Vec::new();
+    for (non_fungible_id, (immutable_data, mutable_data)) in entries {
+        elements.push(SborValue::Tuple {
+            fields: vec![
+                IndexedScryptoValue::from_typed(&non_fungible_id).dom,
+                SborValue::Tuple {
+                    fields: vec![
+                        IndexedScryptoValue::from_slice(&immutable_data).ok()?.dom,
+                        IndexedScryptoValue::from_slice(&mutable_data).ok()?.dom,
+                    ],
+                },
+            ],
+        });
+    }
+
+    IndexedScryptoValue::from_value(SborValue::Array {
+        element_type_id: SborTypeId::Tuple,
+        elements,
+    })
+    .ok()
+}
+
 fn format_node_id(node_id: &RENodeId, context: &mut DecompilationContext) -> String {
     match node_id {
         RENodeId::Global(global_address) => match global_address {
@@ -548,6 +754,8 @@ fn format_node_id(node_id: &RENodeId, context: &mut DecompilationContext) -> Str
         RENodeId::Component(id) => format!("Component(\"{}\")", format_id(id)),
         RENodeId::EpochManager(id) => format!("EpochManager(\"{}\")", format_id(id)),
         RENodeId::Clock(id) => format!("Clock(\"{}\")", format_id(id)),
+        RENodeId::Burner(id) => format!("Burner(\"{}\")", format_id(id)),
+        RENodeId::TimeLockedVault(id) => format!("TimeLockedVault(\"{}\")", format_id(id)),
         RENodeId::Vault(id) => format!("Vault(\"{}\")", format_id(id)),
         RENodeId::ResourceManager(id) => format!("ResourceManager(\"{}\")", format_id(id)),
         RENodeId::Package(id) => format!("Package(\"{}\")", format_id(id)),
@@ -562,6 +770,7 @@ fn format_id(id: &[u8; 36]) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::builder::ManifestBuilder;
     use crate::manifest::*;
     use radix_engine_interface::api::types::ResourceManagerFunction;
     use radix_engine_interface::core::NetworkDefinition;
@@ -571,7 +780,7 @@ mod tests {
     #[scrypto(TypeId, Encode, Decode)]
     struct BadResourceManagerCreateInput {
         pub resource_type: ResourceType,
-        pub metadata: HashMap<String, String>,
+        pub metadata: BTreeMap<String, String>,
         pub access_rules: HashMap<ResourceMethodAuthKey, (AccessRule, AccessRule)>,
         // pub mint_params: Option<MintParams>,
     }
@@ -589,7 +798,7 @@ mod tests {
                     resource_type: ResourceType::NonFungible {
                         id_type: NonFungibleIdType::UUID,
                     },
-                    metadata: HashMap::new(),
+                    metadata: BTreeMap::new(),
                     access_rules: HashMap::new(),
                 })
                 .unwrap(),
@@ -602,6 +811,76 @@ mod tests {
         compile_and_decompile_with_inversion_test(&manifest, &network, vec![]);
     }
 
+    #[scrypto(TypeId, Encode, Decode)]
+    struct SwordImmutableData {
+        pub name: String,
+        pub damage: u32,
+    }
+
+    #[scrypto(TypeId, Encode, Decode)]
+    struct SwordMutableData {
+        pub level: u8,
+    }
+
+    #[test]
+    fn test_decompile_mint_non_fungible() {
+        let network = NetworkDefinition::simulator();
+        let resource_address = ResourceAddress::Normal([1u8; 26]);
+        let mut entries = HashMap::new();
+        for (id, name) in [(2u32, "Excalibur"), (1u32, "Durendal")] {
+            entries.insert(
+                NonFungibleId::U32(id),
+                (
+                    scrypto_encode(&SwordImmutableData {
+                        name: name.to_owned(),
+                        damage: id * 10,
+                    })
+                    .unwrap(),
+                    scrypto_encode(&SwordMutableData { level: 1 }).unwrap(),
+                ),
+            );
+        }
+        let manifest = ManifestBuilder::new(&network)
+            .mint_non_fungible(resource_address, entries)
+            .build();
+
+        let decompiled = decompile(&manifest.instructions, &network).unwrap();
+        let recompiled = compile(&decompiled, &network, vec![]).unwrap();
+
+        assert_eq!(
+            decompiled,
+            format!(
+                "MINT_NON_FUNGIBLE ResourceAddress(\"{}\") Array<Tuple>(Tuple(NonFungibleId(1u32), Tuple(Tuple(\"Durendal\", 10u32), Tuple(1u8))), Tuple(NonFungibleId(2u32), Tuple(Tuple(\"Excalibur\", 20u32), Tuple(1u8))));\n",
+                resource_address.display(&Bech32Encoder::new(&network))
+            )
+        );
+        assert_eq!(recompiled.instructions, manifest.instructions);
+    }
+
+    #[test]
+    fn test_decompile_lock_resource_auth() {
+        let network = NetworkDefinition::simulator();
+        let resource_address = ResourceAddress::Normal([1u8; 26]);
+        let manifest = ManifestBuilder::new(&network)
+            .lock_resource_auth(resource_address, ResourceMethodAuthKey::Mint)
+            .lock_resource_auth(resource_address, ResourceMethodAuthKey::Withdraw)
+            .build();
+
+        let decompiled = decompile(&manifest.instructions, &network).unwrap();
+        let recompiled = compile(&decompiled, &network, vec![]).unwrap();
+
+        let bech32_encoder = Bech32Encoder::new(&network);
+        let address = resource_address.display(&bech32_encoder);
+        assert_eq!(
+            decompiled,
+            format!(
+                "LOCK_RESOURCE_AUTH ResourceAddress(\"{}\") Enum(\"Mint\");\nLOCK_RESOURCE_AUTH ResourceAddress(\"{}\") Enum(\"Withdraw\");\n",
+                address, address
+            )
+        );
+        assert_eq!(recompiled.instructions, manifest.instructions);
+    }
+
     #[test]
     fn test_recompile_many_blobs() {
         // This test is mostly to prevent a regression whereby the blobs were re-ordered at compilation
@@ -713,6 +992,118 @@ CALL_NATIVE_METHOD Global("resource_sim1qrc4s082h9trka3yrghwragylm3sdne0u668h2sy
         )
     }
 
+    #[test]
+    fn test_decompile_call_method_with_return() {
+        let canonical_manifest = compile_and_decompile_with_inversion_test(
+            include_str!("../../examples/test-cases/call_method_with_return.rtm"),
+            &NetworkDefinition::simulator(),
+            vec![],
+        );
+
+        assert_eq!(
+            canonical_manifest,
+            r#"CALL_METHOD_WITH_RETURN ComponentAddress("account_sim1q02r73u7nv47h80e30pc3q6ylsj7mgvparm3pnsm780qgsy064") "withdraw_by_amount" Decimal("5") ResourceAddress("resource_sim1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzqu57yag") Bucket("bucket1");
+CALL_METHOD ComponentAddress("component_sim1q2f9vmyrmeladvz0ejfttcztqv3genlsgpu9vue83mcs835hum") "buy_gumball" Bucket("bucket1");
+"#
+        )
+    }
+
+    #[test]
+    fn test_decompile_call_method_with_proof_return() {
+        let canonical_manifest = compile_and_decompile_with_inversion_test(
+            include_str!("../../examples/test-cases/call_method_with_proof_return.rtm"),
+            &NetworkDefinition::simulator(),
+            vec![],
+        );
+
+        assert_eq!(
+            canonical_manifest,
+            r#"CALL_METHOD_WITH_PROOF_RETURN ComponentAddress("account_sim1q02r73u7nv47h80e30pc3q6ylsj7mgvparm3pnsm780qgsy064") "create_proof_by_amount" Decimal("1") ResourceAddress("resource_sim1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzqu57yag") Proof("proof1");
+CALL_METHOD ComponentAddress("component_sim1q2f9vmyrmeladvz0ejfttcztqv3genlsgpu9vue83mcs835hum") "claim" Proof("proof1");
+"#
+        )
+    }
+
+    #[test]
+    fn test_decompile_deposit_entire_worktop() {
+        let canonical_manifest = compile_and_decompile_with_inversion_test(
+            include_str!("../../examples/test-cases/deposit_entire_worktop.rtm"),
+            &NetworkDefinition::simulator(),
+            vec![],
+        );
+
+        assert_eq!(
+            canonical_manifest,
+            r#"CALL_METHOD ComponentAddress("component_sim1q2f9vmyrmeladvz0ejfttcztqv3genlsgpu9vue83mcs835hum") "free";
+DEPOSIT_ENTIRE_WORKTOP ComponentAddress("account_sim1q02r73u7nv47h80e30pc3q6ylsj7mgvparm3pnsm780qgsy064");
+"#
+        )
+    }
+
+    #[test]
+    fn test_decompile_local_variables() {
+        let canonical_manifest = compile_and_decompile_with_inversion_test(
+            include_str!("../../examples/test-cases/local_variables.rtm"),
+            &NetworkDefinition::simulator(),
+            vec![],
+        );
+
+        assert_eq!(
+            canonical_manifest,
+            r#"SET_LOCAL "amount" Decimal("5");
+SET_LOCAL "xrd" ResourceAddress("resource_sim1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzqu57yag");
+CALL_METHOD ComponentAddress("account_sim1q02r73u7nv47h80e30pc3q6ylsj7mgvparm3pnsm780qgsy064") "withdraw_by_amount" Local("amount") Local("xrd");
+CALL_METHOD ComponentAddress("account_sim1q02r73u7nv47h80e30pc3q6ylsj7mgvparm3pnsm780qgsy064") "deposit_batch" Expression("ENTIRE_WORKTOP");
+"#
+        )
+    }
+
+    #[test]
+    fn test_decompile_worktop_assertions() {
+        let canonical_manifest = compile_and_decompile_with_inversion_test(
+            include_str!("../../examples/test-cases/worktop_assertions.rtm"),
+            &NetworkDefinition::simulator(),
+            vec![],
+        );
+
+        assert_eq!(
+            canonical_manifest,
+            r#"CALL_METHOD ComponentAddress("account_sim1q02r73u7nv47h80e30pc3q6ylsj7mgvparm3pnsm780qgsy064") "withdraw_by_amount" Decimal("5") ResourceAddress("resource_sim1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzqu57yag");
+ASSERT_WORKTOP_CONTAINS_BY_AMOUNT Decimal("4") ResourceAddress("resource_sim1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzqu57yag");
+ASSERT_WORKTOP_CONTAINS_EXACTLY Decimal("5") ResourceAddress("resource_sim1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzqu57yag");
+CALL_METHOD ComponentAddress("account_sim1q02r73u7nv47h80e30pc3q6ylsj7mgvparm3pnsm780qgsy064") "deposit_batch" Expression("ENTIRE_WORKTOP");
+"#
+        )
+    }
+
+    #[test]
+    fn test_decompile_conditional_blocks() {
+        let canonical_manifest = compile_and_decompile_with_inversion_test(
+            include_str!("../../examples/test-cases/conditional_blocks.rtm"),
+            &NetworkDefinition::simulator(),
+            vec![],
+        );
+
+        assert_eq!(
+            canonical_manifest,
+            r#"CALL_METHOD ComponentAddress("account_sim1q02r73u7nv47h80e30pc3q6ylsj7mgvparm3pnsm780qgsy064") "withdraw_by_amount" Decimal("5") ResourceAddress("resource_sim1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzqu57yag");
+TAKE_FROM_WORKTOP_BY_AMOUNT Decimal("1") ResourceAddress("resource_sim1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzqu57yag") Bucket("bucket1");
+IF_WORKTOP_CONTAINS_BY_AMOUNT Decimal("2") ResourceAddress("resource_sim1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzqu57yag") {
+    TAKE_FROM_WORKTOP ResourceAddress("resource_sim1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzqu57yag") Bucket("bucket2");
+    CALL_METHOD ComponentAddress("account_sim1q02r73u7nv47h80e30pc3q6ylsj7mgvparm3pnsm780qgsy064") "deposit" Bucket("bucket2");
+} ELSE {
+    TAKE_FROM_WORKTOP ResourceAddress("resource_sim1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzqu57yag") Bucket("bucket3");
+    CREATE_PROOF_FROM_BUCKET Bucket("bucket3") Proof("proof1");
+};
+IF_WORKTOP_CONTAINS ResourceAddress("resource_sim1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzqu57yag") {
+    ASSERT_WORKTOP_CONTAINS ResourceAddress("resource_sim1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzqu57yag");
+};
+RETURN_TO_WORKTOP Bucket("bucket1");
+CALL_METHOD ComponentAddress("account_sim1q02r73u7nv47h80e30pc3q6ylsj7mgvparm3pnsm780qgsy064") "deposit_batch" Expression("ENTIRE_WORKTOP");
+"#
+        )
+    }
+
     #[test]
     fn test_decompile_any_value() {
         let canonical_manifest = compile_and_decompile_with_inversion_test(
@@ -1,18 +1,19 @@
 use radix_engine_interface::address::Bech32Decoder;
 use radix_engine_interface::api::types::{
-    BucketId, ComponentId, GlobalAddress, KeyValueStoreId, NativeFunctionIdent, NativeMethodIdent,
-    ProofId, RENodeId, ResourceManagerFunction, ResourceManagerMethod, ScryptoFunctionIdent,
-    ScryptoMethodIdent, ScryptoPackage, ScryptoReceiver,
+    AccessRulesChainMethod, BucketId, ComponentId, GlobalAddress, KeyValueStoreId, MetadataMethod,
+    NativeFn, NativeFunctionIdent, NativeMethod, NativeMethodIdent, ProofId, RENodeId,
+    ResourceManagerFunction, ResourceManagerMethod, ScryptoFunctionIdent, ScryptoMethodIdent,
+    ScryptoPackage, ScryptoReceiver,
 };
 use radix_engine_interface::crypto::{
     Blob, EcdsaSecp256k1PublicKey, EcdsaSecp256k1Signature, EddsaEd25519PublicKey,
     EddsaEd25519Signature, Hash,
 };
 use radix_engine_interface::data::{
-    scrypto_decode, scrypto_encode, IndexedScryptoValue, ScryptoCustomTypeId, ScryptoCustomValue,
-    ScryptoSborTypeId, ScryptoValue,
+    scrypto_decode, scrypto_encode, IndexedScryptoValue, RawSborValue, RawSborValueError,
+    ScryptoCustomTypeId, ScryptoCustomValue, ScryptoSborTypeId, ScryptoValue,
 };
-use radix_engine_interface::math::{Decimal, PreciseDecimal};
+use radix_engine_interface::math::{Decimal, ParseDecimalError, PreciseDecimal};
 use radix_engine_interface::model::*;
 
 use radix_engine_interface::core::Expression;
@@ -51,6 +52,7 @@ pub enum GeneratorError {
     InvalidComponentAddress(String),
     InvalidResourceAddress(String),
     InvalidDecimal(String),
+    TooManyDecimalPlaces(String),
     InvalidPreciseDecimal(String),
     InvalidHash(String),
     InvalidNodeId(String),
@@ -67,6 +69,7 @@ pub enum GeneratorError {
     InvalidEddsaEd25519PublicKey(String),
     InvalidEddsaEd25519Signature(String),
     InvalidBytesHex(String),
+    InvalidRawValue(RawSborValueError),
     SborEncodeError(EncodeError),
     BlobNotFound(String),
     NameResolverError(NameResolverError),
@@ -76,6 +79,8 @@ pub enum GeneratorError {
     UnknownNativeFunction(String, String),
     UnknownMethod(String),
     InvalidGlobal(String),
+    /// A conditional block is nested in another; blocks are limited to one level.
+    NestedConditionalBlock,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -85,6 +90,7 @@ pub enum NameResolverError {
     NamedAlreadyDefined(String),
 }
 
+#[derive(Clone)]
 pub struct NameResolver {
     named_buckets: HashMap<String, BucketId>,
     named_proofs: HashMap<String, ProofId>,
@@ -200,6 +206,21 @@ pub fn generate_instruction(
                 resource_address: generate_resource_address(resource_address, bech32_decoder)?,
             }
         }
+        ast::Instruction::TakeFromWorktopOrDefault {
+            amount,
+            resource_address,
+            new_bucket,
+        } => {
+            let bucket_id = id_validator
+                .new_bucket()
+                .map_err(GeneratorError::IdValidationError)?;
+            declare_bucket(new_bucket, resolver, bucket_id)?;
+
+            Instruction::TakeFromWorktopOrDefault {
+                amount: generate_decimal(amount)?,
+                resource_address: generate_resource_address(resource_address, bech32_decoder)?,
+            }
+        }
         ast::Instruction::TakeFromWorktopByIds {
             ids,
             resource_address,
@@ -234,6 +255,13 @@ pub fn generate_instruction(
             amount: generate_decimal(amount)?,
             resource_address: generate_resource_address(resource_address, bech32_decoder)?,
         },
+        ast::Instruction::AssertWorktopContainsExactly {
+            amount,
+            resource_address,
+        } => Instruction::AssertWorktopContainsExactly {
+            amount: generate_decimal(amount)?,
+            resource_address: generate_resource_address(resource_address, bech32_decoder)?,
+        },
         ast::Instruction::AssertWorktopContainsByIds {
             ids,
             resource_address,
@@ -241,6 +269,38 @@ pub fn generate_instruction(
             ids: generate_non_fungible_ids(ids)?,
             resource_address: generate_resource_address(resource_address, bech32_decoder)?,
         },
+        ast::Instruction::IfWorktopContains {
+            resource_address,
+            then_instructions,
+            else_instructions,
+        } => generate_conditional_block(
+            WorktopCondition::Contains {
+                resource_address: generate_resource_address(resource_address, bech32_decoder)?,
+            },
+            then_instructions,
+            else_instructions,
+            id_validator,
+            resolver,
+            bech32_decoder,
+            blobs,
+        )?,
+        ast::Instruction::IfWorktopContainsByAmount {
+            amount,
+            resource_address,
+            then_instructions,
+            else_instructions,
+        } => generate_conditional_block(
+            WorktopCondition::ContainsByAmount {
+                amount: generate_decimal(amount)?,
+                resource_address: generate_resource_address(resource_address, bech32_decoder)?,
+            },
+            then_instructions,
+            else_instructions,
+            id_validator,
+            resolver,
+            bech32_decoder,
+            blobs,
+        )?,
         ast::Instruction::PopFromAuthZone { new_proof } => {
             let proof_id = id_validator
                 .new_proof(ProofKind::AuthZoneProof)
@@ -388,6 +448,80 @@ pub fn generate_instruction(
                 args: args_from_value_vec!(fields),
             }
         }
+        ast::Instruction::CallMethodWithReturn {
+            receiver,
+            method,
+            args,
+            new_bucket,
+        } => {
+            let receiver = generate_scrypto_receiver(receiver, bech32_decoder)?;
+            let method_name = generate_string(&method)?;
+            let args = generate_args(args, resolver, bech32_decoder, blobs)?;
+            let mut fields = Vec::new();
+            for arg in &args {
+                let validated_arg = IndexedScryptoValue::from_slice(arg).unwrap();
+                id_validator
+                    .move_resources(&validated_arg)
+                    .map_err(GeneratorError::IdValidationError)?;
+                fields.push(validated_arg.dom);
+            }
+            let bucket_id = id_validator
+                .new_bucket()
+                .map_err(GeneratorError::IdValidationError)?;
+            declare_bucket(new_bucket, resolver, bucket_id)?;
+
+            Instruction::CallMethodWithReturn {
+                method_ident: ScryptoMethodIdent {
+                    receiver,
+                    method_name,
+                },
+                args: args_from_value_vec!(fields),
+            }
+        }
+        ast::Instruction::CallMethodWithProofReturn {
+            receiver,
+            method,
+            args,
+            new_proof,
+        } => {
+            let receiver = generate_scrypto_receiver(receiver, bech32_decoder)?;
+            let method_name = generate_string(&method)?;
+            let args = generate_args(args, resolver, bech32_decoder, blobs)?;
+            let mut fields = Vec::new();
+            for arg in &args {
+                let validated_arg = IndexedScryptoValue::from_slice(arg).unwrap();
+                id_validator
+                    .move_resources(&validated_arg)
+                    .map_err(GeneratorError::IdValidationError)?;
+                fields.push(validated_arg.dom);
+            }
+            let proof_id = id_validator
+                .new_proof(ProofKind::ReturnedProof)
+                .map_err(GeneratorError::IdValidationError)?;
+            declare_proof(new_proof, resolver, proof_id)?;
+
+            Instruction::CallMethodWithProofReturn {
+                method_ident: ScryptoMethodIdent {
+                    receiver,
+                    method_name,
+                },
+                args: args_from_value_vec!(fields),
+            }
+        }
+        ast::Instruction::DepositEntireWorktop { account } => Instruction::DepositEntireWorktop {
+            account: generate_component_address(account, bech32_decoder)?,
+        },
+        ast::Instruction::SetLocal { name, value } => Instruction::SetLocal {
+            name: generate_string(name)?,
+            value: scrypto_encode(&generate_value(
+                value,
+                None,
+                resolver,
+                bech32_decoder,
+                blobs,
+            )?)
+            .unwrap(),
+        },
         ast::Instruction::CallNativeFunction {
             blueprint_name,
             function_name,
@@ -469,6 +603,12 @@ pub fn generate_instruction(
                     .map_err(GeneratorError::IdValidationError)?;
                 args.push(validated_arg.dom);
             }
+            // No non-fungible data schema or holder tracking can be given in a manifest
+            args.push(SborValue::Enum {
+                discriminator: "None".to_owned(),
+                fields: vec![],
+            });
+            args.push(SborValue::Bool { value: false });
             let args = args_from_value_vec!(args);
 
             // Check if call data matches ABI
@@ -517,9 +657,159 @@ pub fn generate_instruction(
                 args: scrypto_encode(&input).unwrap(),
             }
         }
+        ast::Instruction::MintNonFungible {
+            resource_address,
+            entries,
+        } => {
+            let resource_address = generate_resource_address(resource_address, bech32_decoder)?;
+            let input = ResourceManagerMintInvocation {
+                receiver: resource_address,
+                mint_params: MintParams::NonFungible {
+                    entries: generate_non_fungible_mint_entries(
+                        entries,
+                        resolver,
+                        bech32_decoder,
+                        blobs,
+                    )?,
+                },
+            };
+
+            Instruction::CallNativeMethod {
+                method_ident: NativeMethodIdent {
+                    receiver: RENodeId::Global(GlobalAddress::Resource(resource_address)),
+                    method_name: ResourceManagerMethod::Mint.to_string(),
+                },
+                args: scrypto_encode(&input).unwrap(),
+            }
+        }
+        ast::Instruction::LockResourceAuth {
+            resource_address,
+            auth_key,
+        } => generate_lock_resource_auth(
+            generate_resource_address(resource_address, bech32_decoder)?,
+            generate_resource_method_auth_key(auth_key)?,
+        ),
+    })
+}
+
+fn generate_conditional_block(
+    condition: WorktopCondition,
+    then_instructions: &[ast::Instruction],
+    else_instructions: &[ast::Instruction],
+    id_validator: &mut IdValidator,
+    resolver: &mut NameResolver,
+    bech32_decoder: &Bech32Decoder,
+    blobs: &IndexMap<Hash, Vec<u8>>,
+) -> Result<Instruction, GeneratorError> {
+    let mut then_validator = id_validator.branch(None);
+    let then_instructions = generate_branch(
+        then_instructions,
+        &mut then_validator,
+        resolver,
+        bech32_decoder,
+        blobs,
+    )?;
+    let mut else_validator = id_validator.branch(Some(&then_validator));
+    let else_instructions = generate_branch(
+        else_instructions,
+        &mut else_validator,
+        resolver,
+        bech32_decoder,
+        blobs,
+    )?;
+    id_validator.merge_branches(&[then_validator, else_validator]);
+
+    Ok(Instruction::IfWorktopContains {
+        condition,
+        then_instructions,
+        else_instructions,
     })
 }
 
+/// Generates the instructions of a branch. Names declared in the branch are not visible after it.
+fn generate_branch(
+    instructions: &[ast::Instruction],
+    id_validator: &mut IdValidator,
+    resolver: &NameResolver,
+    bech32_decoder: &Bech32Decoder,
+    blobs: &IndexMap<Hash, Vec<u8>>,
+) -> Result<Vec<Instruction>, GeneratorError> {
+    let mut resolver = resolver.clone();
+    instructions
+        .iter()
+        .map(|instruction| match instruction {
+            ast::Instruction::IfWorktopContains { .. }
+            | ast::Instruction::IfWorktopContainsByAmount { .. } => {
+                Err(GeneratorError::NestedConditionalBlock)
+            }
+            _ => generate_instruction(
+                instruction,
+                id_validator,
+                &mut resolver,
+                bech32_decoder,
+                blobs,
+            ),
+        })
+        .collect()
+}
+
+/// Sets the mutability of a resource auth rule to `DenyAll`, so that the rule can never
+/// change again. The current rule itself is kept.
+pub(crate) fn generate_lock_resource_auth(
+    resource_address: ResourceAddress,
+    auth_key: ResourceMethodAuthKey,
+) -> Instruction {
+    let receiver = RENodeId::Global(GlobalAddress::Resource(resource_address));
+    let native_method = match auth_key {
+        ResourceMethodAuthKey::Withdraw => Err(VaultMethodAuthKey::Withdraw),
+        ResourceMethodAuthKey::Deposit => Err(VaultMethodAuthKey::Deposit),
+        ResourceMethodAuthKey::Recall => Err(VaultMethodAuthKey::Recall),
+        ResourceMethodAuthKey::UpdateMetadata => Ok(NativeMethod::Metadata(MetadataMethod::Set)),
+        ResourceMethodAuthKey::Mint => {
+            Ok(NativeMethod::ResourceManager(ResourceManagerMethod::Mint))
+        }
+        ResourceMethodAuthKey::Burn => {
+            Ok(NativeMethod::ResourceManager(ResourceManagerMethod::Burn))
+        }
+        ResourceMethodAuthKey::UpdateNonFungibleData => Ok(NativeMethod::ResourceManager(
+            ResourceManagerMethod::UpdateNonFungibleData,
+        )),
+        ResourceMethodAuthKey::CreateSyntheticProof => Ok(NativeMethod::ResourceManager(
+            ResourceManagerMethod::CreateSyntheticProof,
+        )),
+    };
+
+    match native_method {
+        // Resource manager rules live in the access rules chain of the resource manager
+        Ok(native_method) => Instruction::CallNativeMethod {
+            method_ident: NativeMethodIdent {
+                receiver,
+                method_name: AccessRulesChainMethod::SetMethodMutability.to_string(),
+            },
+            args: scrypto_encode(&AccessRulesSetMethodMutabilityInvocation {
+                receiver,
+                index: 0,
+                key: AccessRuleKey::Native(NativeFn::Method(native_method)),
+                mutability: AccessRule::DenyAll,
+            })
+            .unwrap(),
+        },
+        // Vault rules are shared by all vaults and are updated through the resource manager
+        Err(vault_method) => Instruction::CallNativeMethod {
+            method_ident: NativeMethodIdent {
+                receiver,
+                method_name: ResourceManagerMethod::LockAuth.to_string(),
+            },
+            args: scrypto_encode(&ResourceManagerSetVaultAuthMutabilityInvocation {
+                receiver: resource_address,
+                method: vault_method,
+                mutability: AccessRule::DenyAll,
+            })
+            .unwrap(),
+        },
+    }
+}
+
 #[macro_export]
 macro_rules! invalid_type {
     ( $v:expr, $($exp:expr),+ ) => {
@@ -552,12 +842,36 @@ fn generate_string(value: &ast::Value) -> Result<String, GeneratorError> {
     }
 }
 
+fn generate_resource_method_auth_key(
+    value: &ast::Value,
+) -> Result<ResourceMethodAuthKey, GeneratorError> {
+    match value {
+        ast::Value::Enum(discriminator, fields) if fields.is_empty() => {
+            match discriminator.as_str() {
+                "Mint" => Ok(ResourceMethodAuthKey::Mint),
+                "Burn" => Ok(ResourceMethodAuthKey::Burn),
+                "UpdateNonFungibleData" => Ok(ResourceMethodAuthKey::UpdateNonFungibleData),
+                "UpdateMetadata" => Ok(ResourceMethodAuthKey::UpdateMetadata),
+                "Withdraw" => Ok(ResourceMethodAuthKey::Withdraw),
+                "Deposit" => Ok(ResourceMethodAuthKey::Deposit),
+                "Recall" => Ok(ResourceMethodAuthKey::Recall),
+                "CreateSyntheticProof" => Ok(ResourceMethodAuthKey::CreateSyntheticProof),
+                _ => invalid_type!(value, ast::Type::Enum),
+            }
+        }
+        v => invalid_type!(v, ast::Type::Enum),
+    }
+}
+
 fn generate_decimal(value: &ast::Value) -> Result<Decimal, GeneratorError> {
     match value {
         ast::Value::Decimal(inner) => match &**inner {
-            ast::Value::String(s) => {
-                Decimal::from_str(s).map_err(|_| GeneratorError::InvalidDecimal(s.into()))
-            }
+            ast::Value::String(s) => Decimal::from_str_exact(s).map_err(|e| match e {
+                ParseDecimalError::TooManyDecimalPlaces { .. } => {
+                    GeneratorError::TooManyDecimalPlaces(s.into())
+                }
+                _ => GeneratorError::InvalidDecimal(s.into()),
+            }),
             v => invalid_type!(v, ast::Type::String),
         },
         v => invalid_type!(v, ast::Type::Decimal),
@@ -770,6 +1084,10 @@ fn generate_re_node_id(
             Ok(RENodeId::EpochManager(generate_node_id(node_id)?))
         }
         ast::RENode::Clock(node_id) => Ok(RENodeId::Clock(generate_node_id(node_id)?)),
+        ast::RENode::Burner(node_id) => Ok(RENodeId::Burner(generate_node_id(node_id)?)),
+        ast::RENode::TimeLockedVault(node_id) => {
+            Ok(RENodeId::TimeLockedVault(generate_node_id(node_id)?))
+        }
         ast::RENode::Vault(node_id) => Ok(RENodeId::Vault(generate_node_id(node_id)?)),
         ast::RENode::ResourceManager(node_id) => {
             Ok(RENodeId::ResourceManager(generate_node_id(node_id)?))
@@ -979,6 +1297,10 @@ fn generate_expression(value: &ast::Value) -> Result<Expression, GeneratorError>
             }
             v => invalid_type!(v, ast::Type::String),
         },
+        ast::Value::Local(inner) => match &**inner {
+            ast::Value::String(s) => Ok(Expression::local(s)),
+            v => invalid_type!(v, ast::Type::String),
+        },
         v => invalid_type!(v, ast::Type::Expression),
     }
 }
@@ -1020,6 +1342,51 @@ fn generate_non_fungible_ids(
     }
 }
 
+fn generate_non_fungible_mint_entries(
+    value: &ast::Value,
+    resolver: &mut NameResolver,
+    bech32_decoder: &Bech32Decoder,
+    blobs: &IndexMap<Hash, Vec<u8>>,
+) -> Result<HashMap<NonFungibleId, (Vec<u8>, Vec<u8>)>, GeneratorError> {
+    match value {
+        ast::Value::Array(kind, elements) => {
+            if kind != &ast::Type::Tuple {
+                return Err(GeneratorError::InvalidType {
+                    expected_type: ast::Type::Tuple,
+                    actual: kind.clone(),
+                });
+            }
+
+            let mut entries = HashMap::new();
+            for element in elements {
+                match element {
+                    ast::Value::Tuple(fields) if fields.len() == 2 => {
+                        let non_fungible_id = generate_non_fungible_id(&fields[0])?;
+                        let data = match &fields[1] {
+                            ast::Value::Tuple(data) if data.len() == 2 => data,
+                            v => return invalid_type!(v, ast::Type::Tuple),
+                        };
+                        let immutable_data =
+                            generate_value(&data[0], None, resolver, bech32_decoder, blobs)?;
+                        let mutable_data =
+                            generate_value(&data[1], None, resolver, bech32_decoder, blobs)?;
+                        entries.insert(
+                            non_fungible_id,
+                            (
+                                scrypto_encode(&immutable_data).unwrap(),
+                                scrypto_encode(&mutable_data).unwrap(),
+                            ),
+                        );
+                    }
+                    v => return invalid_type!(v, ast::Type::Tuple),
+                }
+            }
+            Ok(entries)
+        }
+        v => invalid_type!(v, ast::Type::Array),
+    }
+}
+
 fn generate_byte_vec_from_hex(value: &ast::Value) -> Result<Vec<u8>, GeneratorError> {
     let bytes = match value {
         ast::Value::String(s) => {
@@ -1037,8 +1404,8 @@ pub fn generate_value(
     bech32_decoder: &Bech32Decoder,
     blobs: &IndexMap<Hash, Vec<u8>>,
 ) -> Result<ScryptoValue, GeneratorError> {
-    if let Some(ty) = expected {
-        if ty != value.kind() {
+    if let (Some(ty), Some(kind)) = (expected, value.kind()) {
+        if ty != kind {
             return Err(GeneratorError::InvalidValue {
                 expected_type: vec![ty],
                 actual: value.clone(),
@@ -1126,6 +1493,22 @@ pub fn generate_value(
                 elements: bytes.iter().map(|i| SborValue::U8 { value: *i }).collect(),
             })
         }
+        ast::Value::Raw(hex) => {
+            let raw = RawSborValue::new(generate_byte_vec_from_hex(hex)?)
+                .map_err(GeneratorError::InvalidRawValue)?;
+            if let Some(ty) = expected {
+                if generate_type_id(&ty).as_u8() != raw.as_slice()[0] {
+                    return Err(GeneratorError::InvalidValue {
+                        expected_type: vec![ty],
+                        actual: value.clone(),
+                    });
+                }
+            }
+            Ok(raw.to_value())
+        }
+        ast::Value::Local(_) => generate_expression(value).map(|v| SborValue::Custom {
+            value: ScryptoCustomValue::Expression(v),
+        }),
         // ==============
         // Custom Types
         // ==============
@@ -1307,7 +1690,7 @@ mod tests {
     use radix_engine_interface::address::Bech32Decoder;
     use radix_engine_interface::args;
     use radix_engine_interface::core::NetworkDefinition;
-    use radix_engine_interface::pdec;
+    use radix_engine_interface::{dec, pdec};
 
     #[macro_export]
     macro_rules! generate_value_ok {
@@ -1394,6 +1777,14 @@ mod tests {
                 ]
             }
         );
+        generate_value_ok!(
+            r#"Decimal("0.000000000000000001")"#,
+            SborValue::Custom {
+                value: ScryptoCustomValue::Decimal(
+                    Decimal::from_str("0.000000000000000001").unwrap()
+                )
+            }
+        );
         generate_value_ok!(
             r#"Tuple(Decimal("1.0"), Hash("aa37f5a71083a9aa044fb936678bfd74f848e930d2de482a49a73540ea72aa5c"))"#,
             SborValue::Tuple {
@@ -1435,6 +1826,23 @@ mod tests {
                 value: ScryptoCustomValue::Expression(Expression("ENTIRE_WORKTOP".to_owned()))
             }
         );
+        generate_value_ok!(
+            r#"Local("price")"#,
+            SborValue::Custom {
+                value: ScryptoCustomValue::Expression(Expression::local("price"))
+            }
+        );
+        generate_value_ok!(
+            r#"Raw("210209010000000c0161")"#,
+            SborValue::Tuple {
+                fields: vec![
+                    SborValue::U32 { value: 1 },
+                    SborValue::String {
+                        value: "a".to_owned()
+                    }
+                ]
+            }
+        );
     }
 
     #[test]
@@ -1454,6 +1862,23 @@ mod tests {
             r#"Decimal("invalid_decimal")"#,
             GeneratorError::InvalidDecimal("invalid_decimal".into())
         );
+        generate_value_error!(
+            r#"Decimal("1.0000000000000000001")"#,
+            GeneratorError::TooManyDecimalPlaces("1.0000000000000000001".into())
+        );
+        generate_value_error!(
+            r#"Raw("210209010000000c01")"#,
+            GeneratorError::InvalidRawValue(RawSborValueError::DecodeError(
+                DecodeError::BufferUnderflow {
+                    required: 1,
+                    remaining: 0
+                }
+            ))
+        );
+        generate_value_error!(
+            r#"Raw("210209010000009201000000")"#,
+            GeneratorError::InvalidRawValue(RawSborValueError::BucketNotAllowed)
+        );
     }
 
     #[test]
@@ -1477,6 +1902,13 @@ mod tests {
                 resource_address: resource,
             }
         );
+        generate_instruction_ok!(
+            r#"TAKE_FROM_WORKTOP_OR_DEFAULT  Decimal("1.0")  ResourceAddress("resource_sim1qr9alp6h38ggejqvjl3fzkujpqj2d84gmqy72zuluzwsykwvak")  Bucket("xrd_bucket");"#,
+            Instruction::TakeFromWorktopOrDefault {
+                amount: Decimal::from(1),
+                resource_address: resource,
+            }
+        );
         generate_instruction_ok!(
             r#"TAKE_FROM_WORKTOP  ResourceAddress("resource_sim1qr9alp6h38ggejqvjl3fzkujpqj2d84gmqy72zuluzwsykwvak")  Bucket("xrd_bucket");"#,
             Instruction::TakeFromWorktop {
@@ -1490,6 +1922,13 @@ mod tests {
                 resource_address: resource,
             }
         );
+        generate_instruction_ok!(
+            r#"ASSERT_WORKTOP_CONTAINS_EXACTLY  Decimal("1.0")  ResourceAddress("resource_sim1qr9alp6h38ggejqvjl3fzkujpqj2d84gmqy72zuluzwsykwvak");"#,
+            Instruction::AssertWorktopContainsExactly {
+                amount: Decimal::from(1),
+                resource_address: resource,
+            }
+        );
         generate_instruction_ok!(
             r#"CALL_FUNCTION  PackageAddress("package_sim1q8gl2qqsusgzmz92es68wy2fr7zjc523xj57eanm597qrz3dx7")  "Airdrop"  "new"  500u32  PreciseDecimal("120");"#,
             Instruction::CallFunction {
@@ -1518,6 +1957,37 @@ mod tests {
                 args: args!()
             }
         );
+        generate_instruction_ok!(
+            r#"CALL_METHOD_WITH_RETURN  ComponentAddress("component_sim1q2f9vmyrmeladvz0ejfttcztqv3genlsgpu9vue83mcs835hum")  "withdraw"  Decimal("5")  Bucket("xrd_bucket");"#,
+            Instruction::CallMethodWithReturn {
+                method_ident: ScryptoMethodIdent {
+                    receiver: ScryptoReceiver::Global(component),
+                    method_name: "withdraw".to_string(),
+                },
+                args: args!(dec!("5"))
+            }
+        );
+        generate_instruction_ok!(
+            r#"CALL_METHOD_WITH_PROOF_RETURN  ComponentAddress("component_sim1q2f9vmyrmeladvz0ejfttcztqv3genlsgpu9vue83mcs835hum")  "create_proof_by_amount"  Decimal("1")  Proof("badge");"#,
+            Instruction::CallMethodWithProofReturn {
+                method_ident: ScryptoMethodIdent {
+                    receiver: ScryptoReceiver::Global(component),
+                    method_name: "create_proof_by_amount".to_string(),
+                },
+                args: args!(dec!("1"))
+            }
+        );
+        generate_instruction_ok!(
+            r#"DEPOSIT_ENTIRE_WORKTOP  ComponentAddress("component_sim1q2f9vmyrmeladvz0ejfttcztqv3genlsgpu9vue83mcs835hum");"#,
+            Instruction::DepositEntireWorktop { account: component }
+        );
+        generate_instruction_ok!(
+            r#"SET_LOCAL  "price"  Decimal("5");"#,
+            Instruction::SetLocal {
+                name: "price".to_string(),
+                value: scrypto_encode(&dec!("5")).unwrap(),
+            }
+        );
         generate_instruction_ok!(
             r#"MINT_FUNGIBLE  ResourceAddress("resource_sim1qr9alp6h38ggejqvjl3fzkujpqj2d84gmqy72zuluzwsykwvak")  Decimal("100");"#,
             Instruction::CallNativeMethod {
@@ -1535,4 +2005,53 @@ mod tests {
             }
         );
     }
+
+    fn generate_manifest_error(s: &str) -> GeneratorError {
+        let instructions = Parser::new(tokenize(s).unwrap()).parse_manifest().unwrap();
+        generate_manifest(
+            &instructions,
+            &Bech32Decoder::new(&NetworkDefinition::simulator()),
+            IndexMap::new(),
+        )
+        .expect_err("Expected a generator error")
+    }
+
+    #[test]
+    fn test_conditional_block_ids_are_scoped() {
+        // Names declared in a branch are not visible after the block
+        assert_eq!(
+            generate_manifest_error(
+                r#"IF_WORKTOP_CONTAINS ResourceAddress("resource_sim1qr9alp6h38ggejqvjl3fzkujpqj2d84gmqy72zuluzwsykwvak") {
+                    TAKE_FROM_WORKTOP ResourceAddress("resource_sim1qr9alp6h38ggejqvjl3fzkujpqj2d84gmqy72zuluzwsykwvak") Bucket("xrd");
+                };
+                RETURN_TO_WORKTOP Bucket("xrd");"#
+            ),
+            GeneratorError::NameResolverError(NameResolverError::UndefinedBucket("xrd".into()))
+        );
+        // A bucket consumed by one of the branches can't be used after the block
+        assert_eq!(
+            generate_manifest_error(
+                r#"TAKE_FROM_WORKTOP ResourceAddress("resource_sim1qr9alp6h38ggejqvjl3fzkujpqj2d84gmqy72zuluzwsykwvak") Bucket("xrd");
+                IF_WORKTOP_CONTAINS ResourceAddress("resource_sim1qr9alp6h38ggejqvjl3fzkujpqj2d84gmqy72zuluzwsykwvak") {
+                } ELSE {
+                    RETURN_TO_WORKTOP Bucket("xrd");
+                };
+                RETURN_TO_WORKTOP Bucket("xrd");"#
+            ),
+            GeneratorError::IdValidationError(IdValidationError::BucketNotFound(512))
+        );
+    }
+
+    #[test]
+    fn test_conditional_blocks_cannot_be_nested() {
+        assert_eq!(
+            generate_manifest_error(
+                r#"IF_WORKTOP_CONTAINS ResourceAddress("resource_sim1qr9alp6h38ggejqvjl3fzkujpqj2d84gmqy72zuluzwsykwvak") {
+                    IF_WORKTOP_CONTAINS ResourceAddress("resource_sim1qr9alp6h38ggejqvjl3fzkujpqj2d84gmqy72zuluzwsykwvak") {
+                    };
+                };"#
+            ),
+            GeneratorError::NestedConditionalBlock
+        );
+    }
 }
@@ -54,6 +54,8 @@ pub enum TokenKind {
     Ok,
     Err,
     Bytes,
+    Raw,
+    Local,
 
     // ==============
     // SBOR custom types
@@ -79,6 +81,8 @@ pub enum TokenKind {
     ResourceManager,
     Package,
     Clock,
+    Burner,
+    TimeLockedVault,
 
     /* Other interpreted */
     Expression,
@@ -98,6 +102,8 @@ pub enum TokenKind {
     /* Punctuations */
     OpenParenthesis,
     CloseParenthesis,
+    OpenBrace,
+    CloseBrace,
     LessThan,
     GreaterThan,
     Comma,
@@ -106,11 +112,16 @@ pub enum TokenKind {
     /* Instructions */
     TakeFromWorktop,
     TakeFromWorktopByAmount,
+    TakeFromWorktopOrDefault,
     TakeFromWorktopByIds,
     ReturnToWorktop,
     AssertWorktopContains,
     AssertWorktopContainsByAmount,
+    AssertWorktopContainsExactly,
     AssertWorktopContainsByIds,
+    IfWorktopContains,
+    IfWorktopContainsByAmount,
+    Else,
     PopFromAuthZone,
     PushToAuthZone,
     ClearAuthZone,
@@ -123,12 +134,18 @@ pub enum TokenKind {
     DropAllProofs,
     CallFunction,
     CallMethod,
+    CallMethodWithReturn,
+    CallMethodWithProofReturn,
+    DepositEntireWorktop,
+    SetLocal,
     CallNativeFunction,
     CallNativeMethod,
     PublishPackageWithOwner,
     CreateResource,
     BurnBucket,
     MintFungible,
+    MintNonFungible,
+    LockResourceAuth,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -408,6 +425,8 @@ impl Lexer {
             "Ok" => Ok(TokenKind::Ok),
             "Err" => Ok(TokenKind::Err),
             "Bytes" => Ok(TokenKind::Bytes),
+            "Raw" => Ok(TokenKind::Raw),
+            "Local" => Ok(TokenKind::Local),
 
             "PackageAddress" => Ok(TokenKind::PackageAddress),
             "SystemAddress" => Ok(TokenKind::SystemAddress),
@@ -427,6 +446,8 @@ impl Lexer {
             "ResourceManager" => Ok(TokenKind::ResourceManager),
             "Package" => Ok(TokenKind::Package),
             "Clock" => Ok(TokenKind::Clock),
+            "Burner" => Ok(TokenKind::Burner),
+            "TimeLockedVault" => Ok(TokenKind::TimeLockedVault),
 
             "Expression" => Ok(TokenKind::Expression),
             "Blob" => Ok(TokenKind::Blob),
@@ -443,11 +464,16 @@ impl Lexer {
 
             "TAKE_FROM_WORKTOP" => Ok(TokenKind::TakeFromWorktop),
             "TAKE_FROM_WORKTOP_BY_AMOUNT" => Ok(TokenKind::TakeFromWorktopByAmount),
+            "TAKE_FROM_WORKTOP_OR_DEFAULT" => Ok(TokenKind::TakeFromWorktopOrDefault),
             "TAKE_FROM_WORKTOP_BY_IDS" => Ok(TokenKind::TakeFromWorktopByIds),
             "RETURN_TO_WORKTOP" => Ok(TokenKind::ReturnToWorktop),
             "ASSERT_WORKTOP_CONTAINS" => Ok(TokenKind::AssertWorktopContains),
             "ASSERT_WORKTOP_CONTAINS_BY_AMOUNT" => Ok(TokenKind::AssertWorktopContainsByAmount),
+            "ASSERT_WORKTOP_CONTAINS_EXACTLY" => Ok(TokenKind::AssertWorktopContainsExactly),
             "ASSERT_WORKTOP_CONTAINS_BY_IDS" => Ok(TokenKind::AssertWorktopContainsByIds),
+            "IF_WORKTOP_CONTAINS" => Ok(TokenKind::IfWorktopContains),
+            "IF_WORKTOP_CONTAINS_BY_AMOUNT" => Ok(TokenKind::IfWorktopContainsByAmount),
+            "ELSE" => Ok(TokenKind::Else),
             "POP_FROM_AUTH_ZONE" => Ok(TokenKind::PopFromAuthZone),
             "PUSH_TO_AUTH_ZONE" => Ok(TokenKind::PushToAuthZone),
             "CLEAR_AUTH_ZONE" => Ok(TokenKind::ClearAuthZone),
@@ -462,12 +488,18 @@ impl Lexer {
             "DROP_ALL_PROOFS" => Ok(TokenKind::DropAllProofs),
             "CALL_FUNCTION" => Ok(TokenKind::CallFunction),
             "CALL_METHOD" => Ok(TokenKind::CallMethod),
+            "CALL_METHOD_WITH_RETURN" => Ok(TokenKind::CallMethodWithReturn),
+            "CALL_METHOD_WITH_PROOF_RETURN" => Ok(TokenKind::CallMethodWithProofReturn),
+            "DEPOSIT_ENTIRE_WORKTOP" => Ok(TokenKind::DepositEntireWorktop),
+            "SET_LOCAL" => Ok(TokenKind::SetLocal),
             "CALL_NATIVE_FUNCTION" => Ok(TokenKind::CallNativeFunction),
             "CALL_NATIVE_METHOD" => Ok(TokenKind::CallNativeMethod),
             "PUBLISH_PACKAGE_WITH_OWNER" => Ok(TokenKind::PublishPackageWithOwner),
             "CREATE_RESOURCE" => Ok(TokenKind::CreateResource),
             "BURN_BUCKET" => Ok(TokenKind::BurnBucket),
             "MINT_FUNGIBLE" => Ok(TokenKind::MintFungible),
+            "MINT_NON_FUNGIBLE" => Ok(TokenKind::MintNonFungible),
+            "LOCK_RESOURCE_AUTH" => Ok(TokenKind::LockResourceAuth),
 
             s @ _ => Err(LexerError::UnknownIdentifier(s.into())),
         }
@@ -480,6 +512,8 @@ impl Lexer {
         let token_kind = match self.advance()? {
             '(' => TokenKind::OpenParenthesis,
             ')' => TokenKind::CloseParenthesis,
+            '{' => TokenKind::OpenBrace,
+            '}' => TokenKind::CloseBrace,
             '<' => TokenKind::LessThan,
             '>' => TokenKind::GreaterThan,
             ',' => TokenKind::Comma,
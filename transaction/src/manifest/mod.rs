@@ -4,6 +4,8 @@ pub mod decompiler;
 pub mod generator;
 pub mod lexer;
 pub mod parser;
+pub mod summary;
 
 pub use compiler::{compile, CompileError};
 pub use decompiler::{decompile, DecompileError};
+pub use summary::{summarize, ManifestSummary};
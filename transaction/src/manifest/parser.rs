@@ -79,6 +79,11 @@ impl Parser {
                 resource_address: self.parse_value()?,
                 new_bucket: self.parse_value()?,
             },
+            TokenKind::TakeFromWorktopOrDefault => Instruction::TakeFromWorktopOrDefault {
+                amount: self.parse_value()?,
+                resource_address: self.parse_value()?,
+                new_bucket: self.parse_value()?,
+            },
             TokenKind::TakeFromWorktopByIds => Instruction::TakeFromWorktopByIds {
                 ids: self.parse_value()?,
                 resource_address: self.parse_value()?,
@@ -96,10 +101,25 @@ impl Parser {
                     resource_address: self.parse_value()?,
                 }
             }
+            TokenKind::AssertWorktopContainsExactly => Instruction::AssertWorktopContainsExactly {
+                amount: self.parse_value()?,
+                resource_address: self.parse_value()?,
+            },
             TokenKind::AssertWorktopContainsByIds => Instruction::AssertWorktopContainsByIds {
                 ids: self.parse_value()?,
                 resource_address: self.parse_value()?,
             },
+            TokenKind::IfWorktopContains => Instruction::IfWorktopContains {
+                resource_address: self.parse_value()?,
+                then_instructions: self.parse_block()?,
+                else_instructions: self.parse_else_block()?,
+            },
+            TokenKind::IfWorktopContainsByAmount => Instruction::IfWorktopContainsByAmount {
+                amount: self.parse_value()?,
+                resource_address: self.parse_value()?,
+                then_instructions: self.parse_block()?,
+                else_instructions: self.parse_else_block()?,
+            },
             TokenKind::PopFromAuthZone => Instruction::PopFromAuthZone {
                 new_proof: self.parse_value()?,
             },
@@ -158,6 +178,51 @@ impl Parser {
                     values
                 },
             },
+            TokenKind::CallMethodWithReturn => {
+                let receiver = self.parse_scrypto_receiver()?;
+                let method = self.parse_value()?;
+                let mut args = vec![];
+                while self.peek()?.kind != TokenKind::Semicolon {
+                    args.push(self.parse_value()?);
+                }
+                // The last value names the returned bucket
+                let new_bucket = match args.pop() {
+                    Some(new_bucket) => new_bucket,
+                    None => return Err(ParserError::UnexpectedToken(self.peek()?)),
+                };
+                Instruction::CallMethodWithReturn {
+                    receiver,
+                    method,
+                    args,
+                    new_bucket,
+                }
+            }
+            TokenKind::CallMethodWithProofReturn => {
+                let receiver = self.parse_scrypto_receiver()?;
+                let method = self.parse_value()?;
+                let mut args = vec![];
+                while self.peek()?.kind != TokenKind::Semicolon {
+                    args.push(self.parse_value()?);
+                }
+                // The last value names the returned proof
+                let new_proof = match args.pop() {
+                    Some(new_proof) => new_proof,
+                    None => return Err(ParserError::UnexpectedToken(self.peek()?)),
+                };
+                Instruction::CallMethodWithProofReturn {
+                    receiver,
+                    method,
+                    args,
+                    new_proof,
+                }
+            }
+            TokenKind::DepositEntireWorktop => Instruction::DepositEntireWorktop {
+                account: self.parse_value()?,
+            },
+            TokenKind::SetLocal => Instruction::SetLocal {
+                name: self.parse_value()?,
+                value: self.parse_value()?,
+            },
             TokenKind::CallNativeFunction => Instruction::CallNativeFunction {
                 blueprint_name: self.parse_value()?,
                 function_name: self.parse_value()?,
@@ -199,6 +264,14 @@ impl Parser {
                 resource_address: self.parse_value()?,
                 amount: self.parse_value()?,
             },
+            TokenKind::MintNonFungible => Instruction::MintNonFungible {
+                resource_address: self.parse_value()?,
+                entries: self.parse_value()?,
+            },
+            TokenKind::LockResourceAuth => Instruction::LockResourceAuth {
+                resource_address: self.parse_value()?,
+                auth_key: self.parse_value()?,
+            },
             _ => {
                 return Err(ParserError::UnexpectedToken(token));
             }
@@ -207,6 +280,27 @@ impl Parser {
         Ok(instruction)
     }
 
+    /// Parses the instructions of a `{ ... }` block.
+    pub fn parse_block(&mut self) -> Result<Vec<Instruction>, ParserError> {
+        advance_match!(self, TokenKind::OpenBrace);
+        let mut instructions = Vec::<Instruction>::new();
+        while self.peek()?.kind != TokenKind::CloseBrace {
+            instructions.push(self.parse_instruction()?);
+        }
+        advance_match!(self, TokenKind::CloseBrace);
+        Ok(instructions)
+    }
+
+    /// Parses an optional `ELSE { ... }` block, which is empty if absent.
+    pub fn parse_else_block(&mut self) -> Result<Vec<Instruction>, ParserError> {
+        if self.peek()?.kind == TokenKind::Else {
+            advance_match!(self, TokenKind::Else);
+            self.parse_block()
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
     pub fn parse_scrypto_receiver(&mut self) -> Result<ScryptoReceiver, ParserError> {
         let token = self.advance()?;
         match token.kind {
@@ -288,7 +382,9 @@ impl Parser {
             TokenKind::None |
             TokenKind::Ok |
             TokenKind::Err |
-            TokenKind::Bytes => self.parse_alias(),
+            TokenKind::Bytes |
+            TokenKind::Raw |
+            TokenKind::Local => self.parse_alias(),
 
             // ==============
             // Custom Types
@@ -361,6 +457,8 @@ impl Parser {
             TokenKind::Ok => Ok(Value::Ok(Box::new(self.parse_values_one()?))),
             TokenKind::Err => Ok(Value::Err(Box::new(self.parse_values_one()?))),
             TokenKind::Bytes => Ok(Value::Bytes(Box::new(self.parse_values_one()?))),
+            TokenKind::Raw => Ok(Value::Raw(Box::new(self.parse_values_one()?))),
+            TokenKind::Local => Ok(Value::Local(Box::new(self.parse_values_one()?))),
             _ => Err(ParserError::UnexpectedToken(token)),
         }
     }
@@ -660,6 +758,29 @@ mod tests {
                 new_bucket: Value::Bucket(Value::String("xrd_bucket".into()).into()),
             }
         );
+        parse_instruction_ok!(
+            r#"CALL_METHOD_WITH_PROOF_RETURN  ComponentAddress("0292566c83de7fd6b04fcc92b5e04b03228ccff040785673278ef1")  "create_proof_by_amount"  Decimal("1.0")  Proof("admin_auth");"#,
+            Instruction::CallMethodWithProofReturn {
+                receiver: ScryptoReceiver::Global(
+                    Value::String("0292566c83de7fd6b04fcc92b5e04b03228ccff040785673278ef1".into())
+                        .into()
+                ),
+                method: Value::String("create_proof_by_amount".into()),
+                args: vec![Value::Decimal(Value::String("1.0".into()).into())],
+                new_proof: Value::Proof(Value::String("admin_auth".into()).into()),
+            }
+        );
+        parse_instruction_ok!(
+            r#"TAKE_FROM_WORKTOP_OR_DEFAULT  Decimal("1.0")  ResourceAddress("03cbdf875789d08cc80c97e2915b920824a69ea8d809e50b9fe09d")  Bucket("xrd_bucket");"#,
+            Instruction::TakeFromWorktopOrDefault {
+                amount: Value::Decimal(Value::String("1.0".into()).into()),
+                resource_address: Value::ResourceAddress(
+                    Value::String("03cbdf875789d08cc80c97e2915b920824a69ea8d809e50b9fe09d".into())
+                        .into()
+                ),
+                new_bucket: Value::Bucket(Value::String("xrd_bucket".into()).into()),
+            }
+        );
         parse_instruction_ok!(
             r#"TAKE_FROM_WORKTOP  ResourceAddress("03cbdf875789d08cc80c97e2915b920824a69ea8d809e50b9fe09d")  Bucket("xrd_bucket");"#,
             Instruction::TakeFromWorktop {
@@ -680,6 +801,16 @@ mod tests {
                 ),
             }
         );
+        parse_instruction_ok!(
+            r#"ASSERT_WORKTOP_CONTAINS_EXACTLY  Decimal("1.0")  ResourceAddress("03cbdf875789d08cc80c97e2915b920824a69ea8d809e50b9fe09d");"#,
+            Instruction::AssertWorktopContainsExactly {
+                amount: Value::Decimal(Value::String("1.0".into()).into()),
+                resource_address: Value::ResourceAddress(
+                    Value::String("03cbdf875789d08cc80c97e2915b920824a69ea8d809e50b9fe09d".into())
+                        .into()
+                ),
+            }
+        );
         parse_instruction_ok!(
             r#"CREATE_PROOF_FROM_BUCKET  Bucket("xrd_bucket")  Proof("admin_auth");"#,
             Instruction::CreateProofFromBucket {
@@ -741,6 +872,99 @@ mod tests {
                 ]
             }
         );
+        parse_instruction_ok!(
+            r#"CALL_METHOD_WITH_RETURN  ComponentAddress("0292566c83de7fd6b04fcc92b5e04b03228ccff040785673278ef1")  "withdraw_by_amount"  Decimal("5.0")  Bucket("xrd_bucket");"#,
+            Instruction::CallMethodWithReturn {
+                receiver: ScryptoReceiver::Global(
+                    Value::String("0292566c83de7fd6b04fcc92b5e04b03228ccff040785673278ef1".into())
+                        .into()
+                ),
+                method: Value::String("withdraw_by_amount".into()),
+                args: vec![Value::Decimal(Value::String("5.0".into()).into())],
+                new_bucket: Value::Bucket(Value::String("xrd_bucket".into()).into()),
+            }
+        );
+        parse_instruction_ok!(
+            r#"DEPOSIT_ENTIRE_WORKTOP  ComponentAddress("0292566c83de7fd6b04fcc92b5e04b03228ccff040785673278ef1");"#,
+            Instruction::DepositEntireWorktop {
+                account: Value::ComponentAddress(
+                    Value::String("0292566c83de7fd6b04fcc92b5e04b03228ccff040785673278ef1".into())
+                        .into()
+                ),
+            }
+        );
+        parse_instruction_ok!(
+            r#"SET_LOCAL  "price"  Decimal("5.0");"#,
+            Instruction::SetLocal {
+                name: Value::String("price".into()),
+                value: Value::Decimal(Value::String("5.0".into()).into()),
+            }
+        );
+        parse_instruction_ok!(
+            r#"CALL_METHOD  ComponentAddress("0292566c83de7fd6b04fcc92b5e04b03228ccff040785673278ef1")  "withdraw_by_amount"  Local("price");"#,
+            Instruction::CallMethod {
+                receiver: ScryptoReceiver::Global(
+                    Value::String("0292566c83de7fd6b04fcc92b5e04b03228ccff040785673278ef1".into())
+                        .into()
+                ),
+                method: Value::String("withdraw_by_amount".into()),
+                args: vec![Value::Local(Value::String("price".into()).into())]
+            }
+        );
+    }
+
+    #[test]
+    fn test_call_method_with_return_requires_new_bucket() {
+        let mut parser = Parser::new(
+            tokenize(r#"CALL_METHOD_WITH_RETURN ComponentAddress("0292566c83de7fd6b04fcc92b5e04b03228ccff040785673278ef1") "free";"#)
+                .unwrap(),
+        );
+        assert!(matches!(
+            parser.parse_instruction(),
+            Err(ParserError::UnexpectedToken(Token {
+                kind: TokenKind::Semicolon,
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_conditional_block() {
+        parse_instruction_ok!(
+            r#"IF_WORKTOP_CONTAINS_BY_AMOUNT Decimal("1.0") ResourceAddress("03cbdf875789d08cc80c97e2915b920824a69ea8d809e50b9fe09d") {
+                TAKE_FROM_WORKTOP ResourceAddress("03cbdf875789d08cc80c97e2915b920824a69ea8d809e50b9fe09d") Bucket("xrd");
+            } ELSE {
+                CLEAR_AUTH_ZONE;
+            };"#,
+            Instruction::IfWorktopContainsByAmount {
+                amount: Value::Decimal(Value::String("1.0".into()).into()),
+                resource_address: Value::ResourceAddress(
+                    Value::String("03cbdf875789d08cc80c97e2915b920824a69ea8d809e50b9fe09d".into())
+                        .into()
+                ),
+                then_instructions: vec![Instruction::TakeFromWorktop {
+                    resource_address: Value::ResourceAddress(
+                        Value::String(
+                            "03cbdf875789d08cc80c97e2915b920824a69ea8d809e50b9fe09d".into()
+                        )
+                        .into()
+                    ),
+                    new_bucket: Value::Bucket(Value::String("xrd".into()).into()),
+                }],
+                else_instructions: vec![Instruction::ClearAuthZone],
+            }
+        );
+        parse_instruction_ok!(
+            r#"IF_WORKTOP_CONTAINS ResourceAddress("03cbdf875789d08cc80c97e2915b920824a69ea8d809e50b9fe09d") {};"#,
+            Instruction::IfWorktopContains {
+                resource_address: Value::ResourceAddress(
+                    Value::String("03cbdf875789d08cc80c97e2915b920824a69ea8d809e50b9fe09d".into())
+                        .into()
+                ),
+                then_instructions: vec![],
+                else_instructions: vec![],
+            }
+        );
     }
 
     #[test]
@@ -0,0 +1,745 @@
+//! A static summary of what a manifest does, for wallets to display before signing.
+//!
+//! The summary is a heuristic over the instructions: it does not execute the transaction,
+//! so it cannot tell what a component method returns or what is left on the worktop. Account
+//! methods are recognised by name (`withdraw*`, `lock_fee*`, `deposit*` and `create_proof*`)
+//! on account component addresses. Any instruction that moves resources in a way the summary
+//! cannot follow is reported as an unknown action, which wallets should warn about.
+
+use radix_engine_interface::api::types::{
+    BucketId, ProofId, ResourceManagerFunction, ScryptoMethodIdent, ScryptoReceiver,
+};
+use radix_engine_interface::core::Expression;
+use radix_engine_interface::crypto::Blob;
+use radix_engine_interface::data::{scrypto_decode, IndexedScryptoValue};
+use radix_engine_interface::math::Decimal;
+use radix_engine_interface::model::*;
+use sbor::rust::collections::*;
+use sbor::rust::string::String;
+use sbor::rust::vec::Vec;
+
+use crate::model::*;
+use crate::validation::*;
+
+/// An amount of a resource, as far as it can be told from the manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceQuantity {
+    /// All of the resource available, e.g. everything of it on the worktop.
+    All(ResourceAddress),
+    Amount(Decimal, ResourceAddress),
+    Ids(BTreeSet<NonFungibleId>, ResourceAddress),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountWithdrawal {
+    pub instruction_index: usize,
+    pub account: ComponentAddress,
+    pub resource: ResourceQuantity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountFeeLock {
+    pub instruction_index: usize,
+    pub account: ComponentAddress,
+    pub amount: Decimal,
+    pub contingent: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DepositedResources {
+    /// Everything left on the worktop, which is only known after execution.
+    EntireWorktop,
+    /// Buckets taken from the worktop earlier in the manifest.
+    Buckets(Vec<ResourceQuantity>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountDeposit {
+    pub instruction_index: usize,
+    pub account: ComponentAddress,
+    pub resources: DepositedResources,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProofSource {
+    Account(ComponentAddress),
+    AuthZone,
+    Bucket(BucketId),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofCreation {
+    pub instruction_index: usize,
+    pub source: ProofSource,
+    pub resource: ResourceQuantity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceCreation {
+    pub instruction_index: usize,
+    pub resource_type: ResourceType,
+    pub metadata: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackagePublish {
+    pub instruction_index: usize,
+    pub code: Blob,
+    pub abi: Blob,
+    pub owner_badge: NonFungibleAddress,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownAction {
+    pub instruction_index: usize,
+    pub instruction: Instruction,
+}
+
+/// What a manifest does, grouped by kind of action.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ManifestSummary {
+    pub withdrawals: Vec<AccountWithdrawal>,
+    pub fee_locks: Vec<AccountFeeLock>,
+    pub deposits: Vec<AccountDeposit>,
+    pub proofs: Vec<ProofCreation>,
+    pub resource_creations: Vec<ResourceCreation>,
+    pub package_publishes: Vec<PackagePublish>,
+    pub unknown_actions: Vec<UnknownAction>,
+}
+
+impl ManifestSummary {
+    /// Whether every instruction of the manifest could be classified.
+    pub fn is_fully_understood(&self) -> bool {
+        self.unknown_actions.is_empty()
+    }
+}
+
+/// Summarizes the withdrawals, deposits, proofs, resource creations and package publishes
+/// of a manifest.
+///
+/// This is a heuristic over the instructions and does not execute anything; see the module
+/// documentation for what it can and cannot recognise.
+pub fn summarize(manifest: &TransactionManifest) -> ManifestSummary {
+    let mut summarizer = Summarizer {
+        summary: ManifestSummary::default(),
+        id_allocator: IdAllocator::new(IdSpace::Transaction),
+        buckets: HashMap::new(),
+    };
+    for (instruction_index, instruction) in manifest.instructions.iter().enumerate() {
+        if !summarizer.summarize_instruction(instruction_index, instruction) {
+            summarizer.summary.unknown_actions.push(UnknownAction {
+                instruction_index,
+                instruction: instruction.clone(),
+            });
+        }
+    }
+    summarizer.summary
+}
+
+struct Summarizer {
+    summary: ManifestSummary,
+    /// Mirrors the bucket and proof ids handed out by the transaction processor
+    id_allocator: IdAllocator,
+    buckets: HashMap<BucketId, ResourceQuantity>,
+}
+
+impl Summarizer {
+    /// Returns whether the instruction could be classified.
+    fn summarize_instruction(
+        &mut self,
+        instruction_index: usize,
+        instruction: &Instruction,
+    ) -> bool {
+        match instruction {
+            Instruction::TakeFromWorktop { resource_address } => {
+                self.new_bucket(ResourceQuantity::All(*resource_address))
+            }
+            Instruction::TakeFromWorktopByAmount {
+                amount,
+                resource_address,
+            }
+            | Instruction::TakeFromWorktopOrDefault {
+                amount,
+                resource_address,
+            } => self.new_bucket(ResourceQuantity::Amount(*amount, *resource_address)),
+            Instruction::TakeFromWorktopByIds {
+                ids,
+                resource_address,
+            } => self.new_bucket(ResourceQuantity::Ids(ids.clone(), *resource_address)),
+            Instruction::ReturnToWorktop { bucket_id } => {
+                self.buckets.remove(bucket_id);
+                true
+            }
+            Instruction::AssertWorktopContains { .. }
+            | Instruction::AssertWorktopContainsByAmount { .. }
+            | Instruction::AssertWorktopContainsExactly { .. }
+            | Instruction::AssertWorktopContainsByIds { .. }
+            | Instruction::PushToAuthZone { .. }
+            | Instruction::ClearAuthZone
+            | Instruction::DropProof { .. }
+            | Instruction::DropAllProofs => true,
+            Instruction::IfWorktopContains { .. } => {
+                // Which branch runs is only known after execution
+                let _ = self.id_allocator.skip(instruction.id_allocation_count());
+                false
+            }
+            Instruction::PopFromAuthZone | Instruction::CloneProof { .. } => {
+                self.new_proof().is_some()
+            }
+            Instruction::CreateProofFromAuthZone { resource_address } => self.proof_created(
+                instruction_index,
+                ProofSource::AuthZone,
+                ResourceQuantity::All(*resource_address),
+            ),
+            Instruction::CreateProofFromAuthZoneByAmount {
+                amount,
+                resource_address,
+            } => self.proof_created(
+                instruction_index,
+                ProofSource::AuthZone,
+                ResourceQuantity::Amount(*amount, *resource_address),
+            ),
+            Instruction::CreateProofFromAuthZoneByIds {
+                ids,
+                resource_address,
+            } => self.proof_created(
+                instruction_index,
+                ProofSource::AuthZone,
+                ResourceQuantity::Ids(ids.clone(), *resource_address),
+            ),
+            Instruction::CreateProofFromBucket { bucket_id } => match self.buckets.get(bucket_id) {
+                Some(resource) => {
+                    let resource = resource.clone();
+                    self.proof_created(instruction_index, ProofSource::Bucket(*bucket_id), resource)
+                }
+                None => false,
+            },
+            Instruction::CallMethod { method_ident, args } => {
+                self.summarize_method(instruction_index, method_ident, args)
+            }
+            Instruction::CallMethodWithReturn { method_ident, args } => {
+                let understood = self.summarize_method(instruction_index, method_ident, args);
+                // The returned bucket can only be followed if it's a recognised withdrawal
+                let resource = self
+                    .summary
+                    .withdrawals
+                    .last()
+                    .filter(|withdrawal| withdrawal.instruction_index == instruction_index)
+                    .map(|withdrawal| withdrawal.resource.clone());
+                match resource {
+                    Some(resource) => understood && self.new_bucket(resource),
+                    None => {
+                        // Keeps the bucket ids in step with the transaction processor
+                        let _ = self.id_allocator.new_bucket_id();
+                        false
+                    }
+                }
+            }
+            Instruction::CallMethodWithProofReturn { method_ident, args } => {
+                let understood = self.summarize_method(instruction_index, method_ident, args);
+                // Keeps the proof ids in step with the transaction processor
+                let allocated = self.new_proof().is_some();
+                understood
+                    && allocated
+                    && self
+                        .summary
+                        .proofs
+                        .last()
+                        .map_or(false, |proof| proof.instruction_index == instruction_index)
+            }
+            Instruction::DepositEntireWorktop { account } => {
+                self.summary.deposits.push(AccountDeposit {
+                    instruction_index,
+                    account: *account,
+                    resources: DepositedResources::EntireWorktop,
+                });
+                true
+            }
+            Instruction::SetLocal { .. } => true,
+            Instruction::CallNativeFunction {
+                function_ident,
+                args,
+            } => {
+                if function_ident.blueprint_name != "ResourceManager"
+                    || function_ident.function_name != ResourceManagerFunction::Create.to_string()
+                {
+                    return false;
+                }
+                match scrypto_decode::<ResourceManagerCreateInvocation>(args) {
+                    Ok(invocation) => {
+                        self.summary.resource_creations.push(ResourceCreation {
+                            instruction_index,
+                            resource_type: invocation.resource_type,
+                            metadata: invocation.metadata,
+                        });
+                        true
+                    }
+                    Err(_) => false,
+                }
+            }
+            Instruction::PublishPackageWithOwner {
+                code,
+                abi,
+                owner_badge,
+            } => {
+                self.summary.package_publishes.push(PackagePublish {
+                    instruction_index,
+                    code: code.clone(),
+                    abi: abi.clone(),
+                    owner_badge: owner_badge.clone(),
+                });
+                true
+            }
+            Instruction::CallFunction { .. } | Instruction::CallNativeMethod { .. } => false,
+        }
+    }
+
+    fn summarize_method(
+        &mut self,
+        instruction_index: usize,
+        method_ident: &ScryptoMethodIdent,
+        args: &[u8],
+    ) -> bool {
+        let account = match method_ident.receiver {
+            ScryptoReceiver::Global(
+                address @ (ComponentAddress::Account(..)
+                | ComponentAddress::EcdsaSecp256k1VirtualAccount(..)
+                | ComponentAddress::EddsaEd25519VirtualAccount(..)),
+            ) => address,
+            _ => return false,
+        };
+
+        let method_name = method_ident.method_name.as_str();
+        if method_name.starts_with("deposit") {
+            return self.deposit(instruction_index, account, args);
+        }
+
+        let (fee, withdrawal, proof) = match method_name {
+            "lock_fee" => match scrypto_decode::<(Decimal,)>(args) {
+                Ok((amount,)) => (Some((amount, false)), None, None),
+                Err(_) => return false,
+            },
+            "lock_contingent_fee" => match scrypto_decode::<(Decimal,)>(args) {
+                Ok((amount,)) => (Some((amount, true)), None, None),
+                Err(_) => return false,
+            },
+            "withdraw" => match scrypto_decode::<(ResourceAddress,)>(args) {
+                Ok((resource_address,)) => {
+                    (None, Some(ResourceQuantity::All(resource_address)), None)
+                }
+                Err(_) => return false,
+            },
+            "withdraw_by_amount" => match scrypto_decode::<(Decimal, ResourceAddress)>(args) {
+                Ok((amount, resource_address)) => (
+                    None,
+                    Some(ResourceQuantity::Amount(amount, resource_address)),
+                    None,
+                ),
+                Err(_) => return false,
+            },
+            "withdraw_by_ids" => {
+                match scrypto_decode::<(BTreeSet<NonFungibleId>, ResourceAddress)>(args) {
+                    Ok((ids, resource_address)) => (
+                        None,
+                        Some(ResourceQuantity::Ids(ids, resource_address)),
+                        None,
+                    ),
+                    Err(_) => return false,
+                }
+            }
+            "lock_fee_and_withdraw" => match scrypto_decode::<(Decimal, ResourceAddress)>(args) {
+                Ok((fee, resource_address)) => (
+                    Some((fee, false)),
+                    Some(ResourceQuantity::All(resource_address)),
+                    None,
+                ),
+                Err(_) => return false,
+            },
+            "lock_fee_and_withdraw_by_amount" => {
+                match scrypto_decode::<(Decimal, Decimal, ResourceAddress)>(args) {
+                    Ok((fee, amount, resource_address)) => (
+                        Some((fee, false)),
+                        Some(ResourceQuantity::Amount(amount, resource_address)),
+                        None,
+                    ),
+                    Err(_) => return false,
+                }
+            }
+            "lock_fee_and_withdraw_by_ids" => {
+                match scrypto_decode::<(Decimal, BTreeSet<NonFungibleId>, ResourceAddress)>(args) {
+                    Ok((fee, ids, resource_address)) => (
+                        Some((fee, false)),
+                        Some(ResourceQuantity::Ids(ids, resource_address)),
+                        None,
+                    ),
+                    Err(_) => return false,
+                }
+            }
+            "create_proof" => match scrypto_decode::<(ResourceAddress,)>(args) {
+                Ok((resource_address,)) => {
+                    (None, None, Some(ResourceQuantity::All(resource_address)))
+                }
+                Err(_) => return false,
+            },
+            "create_proof_by_amount" => match scrypto_decode::<(Decimal, ResourceAddress)>(args) {
+                Ok((amount, resource_address)) => (
+                    None,
+                    None,
+                    Some(ResourceQuantity::Amount(amount, resource_address)),
+                ),
+                Err(_) => return false,
+            },
+            "create_proof_by_ids" => {
+                match scrypto_decode::<(BTreeSet<NonFungibleId>, ResourceAddress)>(args) {
+                    Ok((ids, resource_address)) => (
+                        None,
+                        None,
+                        Some(ResourceQuantity::Ids(ids, resource_address)),
+                    ),
+                    Err(_) => return false,
+                }
+            }
+            _ => return false,
+        };
+
+        if let Some((amount, contingent)) = fee {
+            self.summary.fee_locks.push(AccountFeeLock {
+                instruction_index,
+                account,
+                amount,
+                contingent,
+            });
+        }
+        if let Some(resource) = withdrawal {
+            self.summary.withdrawals.push(AccountWithdrawal {
+                instruction_index,
+                account,
+                resource,
+            });
+        }
+        if let Some(resource) = proof {
+            self.summary.proofs.push(ProofCreation {
+                instruction_index,
+                source: ProofSource::Account(account),
+                resource,
+            });
+        }
+        true
+    }
+
+    fn deposit(
+        &mut self,
+        instruction_index: usize,
+        account: ComponentAddress,
+        args: &[u8],
+    ) -> bool {
+        let args = match IndexedScryptoValue::from_slice(args) {
+            Ok(args) => args,
+            Err(_) => return false,
+        };
+        if !args.proof_ids.is_empty() {
+            return false;
+        }
+
+        let resources = if args
+            .expressions
+            .iter()
+            .any(|(expression, _)| *expression == Expression::entire_worktop())
+        {
+            DepositedResources::EntireWorktop
+        } else {
+            let mut bucket_ids: Vec<BucketId> = args.bucket_ids.keys().cloned().collect();
+            bucket_ids.sort();
+            let mut resources = Vec::new();
+            for bucket_id in bucket_ids {
+                match self.buckets.remove(&bucket_id) {
+                    Some(resource) => resources.push(resource),
+                    None => return false,
+                }
+            }
+            if resources.is_empty() {
+                return false;
+            }
+            DepositedResources::Buckets(resources)
+        };
+
+        self.summary.deposits.push(AccountDeposit {
+            instruction_index,
+            account,
+            resources,
+        });
+        true
+    }
+
+    fn new_bucket(&mut self, resource: ResourceQuantity) -> bool {
+        match self.id_allocator.new_bucket_id() {
+            Ok(bucket_id) => {
+                self.buckets.insert(bucket_id, resource);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    fn new_proof(&mut self) -> Option<ProofId> {
+        self.id_allocator.new_proof_id().ok()
+    }
+
+    fn proof_created(
+        &mut self,
+        instruction_index: usize,
+        source: ProofSource,
+        resource: ResourceQuantity,
+    ) -> bool {
+        if self.new_proof().is_none() {
+            return false;
+        }
+        self.summary.proofs.push(ProofCreation {
+            instruction_index,
+            source,
+            resource,
+        });
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::ManifestBuilder;
+    use radix_engine_interface::address::Bech32Decoder;
+    use radix_engine_interface::core::NetworkDefinition;
+    use radix_engine_interface::crypto::hash;
+    use radix_engine_interface::data::*;
+
+    #[test]
+    fn test_summarize_complex_manifest() {
+        let bech32_decoder = Bech32Decoder::new(&NetworkDefinition::simulator());
+        let manifest = include_str!("../../examples/test-cases/complex.rtm");
+        let blobs = vec![
+            include_bytes!("../../examples/test-cases/code.blob").to_vec(),
+            include_bytes!("../../examples/test-cases/abi.blob").to_vec(),
+        ];
+        let code_hash = hash(&blobs[0]);
+        let abi_hash = hash(&blobs[1]);
+        let manifest =
+            crate::manifest::compile(manifest, &NetworkDefinition::simulator(), blobs).unwrap();
+
+        let account = bech32_decoder
+            .validate_and_decode_component_address(
+                "account_sim1q02r73u7nv47h80e30pc3q6ylsj7mgvparm3pnsm780qgsy064",
+            )
+            .unwrap();
+        let xrd = bech32_decoder
+            .validate_and_decode_resource_address(
+                "resource_sim1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzqu57yag",
+            )
+            .unwrap();
+
+        let summary = summarize(&manifest);
+
+        assert_eq!(
+            summary.withdrawals,
+            vec![AccountWithdrawal {
+                instruction_index: 0,
+                account,
+                resource: ResourceQuantity::Amount(Decimal::from(5), xrd),
+            }]
+        );
+        assert!(summary.fee_locks.is_empty());
+        assert_eq!(
+            summary.deposits,
+            vec![AccountDeposit {
+                instruction_index: 16,
+                account,
+                resources: DepositedResources::EntireWorktop,
+            }]
+        );
+        assert_eq!(
+            summary.proofs,
+            vec![
+                ProofCreation {
+                    instruction_index: 6,
+                    source: ProofSource::Bucket(513),
+                    resource: ResourceQuantity::All(xrd),
+                },
+                ProofCreation {
+                    instruction_index: 10,
+                    source: ProofSource::Account(account),
+                    resource: ResourceQuantity::Amount(Decimal::from(5), xrd),
+                },
+            ]
+        );
+        assert_eq!(
+            summary.resource_creations,
+            vec![ResourceCreation {
+                instruction_index: 15,
+                resource_type: ResourceType::Fungible { divisibility: 0 },
+                metadata: BTreeMap::new(),
+            }]
+        );
+        assert_eq!(summary.package_publishes.len(), 1);
+        assert_eq!(summary.package_publishes[0].instruction_index, 19);
+        assert_eq!(summary.package_publishes[0].code, Blob(code_hash));
+        assert_eq!(summary.package_publishes[0].abi, Blob(abi_hash));
+
+        // The gumball and complicated method calls are on non-account components
+        let unknown_indices: Vec<usize> = summary
+            .unknown_actions
+            .iter()
+            .map(|action| action.instruction_index)
+            .collect();
+        assert_eq!(unknown_indices, vec![2, 18]);
+        assert!(!summary.is_fully_understood());
+    }
+
+    #[test]
+    fn test_summarize_bucket_deposit() {
+        let account = ComponentAddress::Account([1u8; 26]);
+        let other_account = ComponentAddress::Account([2u8; 26]);
+        let resource_address = ResourceAddress::Normal([3u8; 26]);
+        let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+            .lock_fee(account, 10.into())
+            .withdraw_from_account_by_amount(account, 5.into(), resource_address)
+            .take_from_worktop_by_amount(5.into(), resource_address, |builder, bucket_id| {
+                builder.call_method(other_account, "deposit", args!(Bucket(bucket_id)))
+            })
+            .build();
+
+        let summary = summarize(&manifest);
+
+        assert!(summary.is_fully_understood());
+        assert_eq!(
+            summary.fee_locks,
+            vec![AccountFeeLock {
+                instruction_index: 0,
+                account,
+                amount: Decimal::from(10),
+                contingent: false,
+            }]
+        );
+        assert_eq!(
+            summary.deposits,
+            vec![AccountDeposit {
+                instruction_index: 3,
+                account: other_account,
+                resources: DepositedResources::Buckets(vec![ResourceQuantity::Amount(
+                    Decimal::from(5),
+                    resource_address
+                )]),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_summarize_entire_worktop_deposit() {
+        let account = ComponentAddress::Account([1u8; 26]);
+        let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+            .deposit_entire_worktop(account)
+            .build();
+
+        let summary = summarize(&manifest);
+
+        assert!(summary.is_fully_understood());
+        assert_eq!(
+            summary.deposits,
+            vec![AccountDeposit {
+                instruction_index: 0,
+                account,
+                resources: DepositedResources::EntireWorktop,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_summarize_returned_bucket_deposit() {
+        let account = ComponentAddress::Account([1u8; 26]);
+        let other_account = ComponentAddress::Account([2u8; 26]);
+        let resource_address = ResourceAddress::Normal([3u8; 26]);
+        let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+            .call_method_with_return(
+                account,
+                "withdraw_by_amount",
+                args!(Decimal::from(5), resource_address),
+                |builder, bucket_id| {
+                    builder.call_method(other_account, "deposit", args!(Bucket(bucket_id)))
+                },
+            )
+            .build();
+
+        let summary = summarize(&manifest);
+
+        assert!(summary.is_fully_understood());
+        assert_eq!(
+            summary.withdrawals,
+            vec![AccountWithdrawal {
+                instruction_index: 0,
+                account,
+                resource: ResourceQuantity::Amount(Decimal::from(5), resource_address),
+            }]
+        );
+        assert_eq!(
+            summary.deposits,
+            vec![AccountDeposit {
+                instruction_index: 1,
+                account: other_account,
+                resources: DepositedResources::Buckets(vec![ResourceQuantity::Amount(
+                    Decimal::from(5),
+                    resource_address
+                )]),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_summarize_returned_proof() {
+        let account = ComponentAddress::Account([1u8; 26]);
+        let resource_address = ResourceAddress::Normal([3u8; 26]);
+        let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+            .create_proof_from_account_by_amount_returning(
+                account,
+                Decimal::from(1),
+                resource_address,
+                |builder, proof_id| builder.drop_proof(proof_id),
+            )
+            .build();
+
+        let summary = summarize(&manifest);
+
+        assert!(summary.is_fully_understood());
+        assert_eq!(
+            summary.proofs,
+            vec![ProofCreation {
+                instruction_index: 0,
+                source: ProofSource::Account(account),
+                resource: ResourceQuantity::Amount(Decimal::from(1), resource_address),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_summarize_exotic_manifest_flags_unknown_actions() {
+        let account = ComponentAddress::Account([1u8; 26]);
+        let component = ComponentAddress::Normal([2u8; 26]);
+        let package_address = PackageAddress::Normal([3u8; 26]);
+        let resource_address = ResourceAddress::Normal([4u8; 26]);
+        let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+            .call_function(package_address, "Blueprint", "new", args!())
+            .call_method(component, "drain", args!(account))
+            .call_method(account, "balance", args!(resource_address))
+            .call_method(account, "withdraw_by_amount", args!("five"))
+            .mint(resource_address, 5.into())
+            .build();
+
+        let summary = summarize(&manifest);
+
+        assert!(summary.withdrawals.is_empty());
+        assert!(summary.deposits.is_empty());
+        let unknown_indices: Vec<usize> = summary
+            .unknown_actions
+            .iter()
+            .map(|action| action.instruction_index)
+            .collect();
+        assert_eq!(unknown_indices, vec![0, 1, 2, 3, 4]);
+    }
+}
@@ -1,6 +1,5 @@
 use radix_engine_interface::constants::SYSTEM_TOKEN;
 use radix_engine_interface::crypto::PublicKey;
-use radix_engine_interface::model::FromPublicKey;
 use radix_engine_interface::model::*;
 
 pub struct AuthModule;
@@ -18,7 +17,7 @@ impl AuthModule {
     pub fn pk_non_fungibles(signer_public_keys: &[PublicKey]) -> Vec<NonFungibleAddress> {
         signer_public_keys
             .iter()
-            .map(NonFungibleAddress::from_public_key)
+            .map(virtual_signature_badge)
             .collect()
     }
 }
@@ -0,0 +1,69 @@
+use radix_engine_interface::crypto::PublicKey;
+use radix_engine_interface::model::*;
+use sbor::rust::vec::Vec;
+
+use crate::model::AuthModule;
+
+/// The proofs a test transaction starts with in its auth zone.
+///
+/// Signatures are not checked for test transactions, so these stand in for the virtual
+/// signature badges a notarized transaction would get from its signers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InitialProofs {
+    /// The virtual signature badges of the given public keys, as if they had signed.
+    PublicKeys(Vec<PublicKey>),
+    /// The given non-fungibles, of any resource.
+    NonFungibleAddresses(Vec<NonFungibleAddress>),
+}
+
+impl InitialProofs {
+    pub fn from_public_keys(public_keys: Vec<PublicKey>) -> Self {
+        Self::PublicKeys(public_keys)
+    }
+
+    pub fn from_non_fungible_addresses(non_fungible_addresses: Vec<NonFungibleAddress>) -> Self {
+        Self::NonFungibleAddresses(non_fungible_addresses)
+    }
+
+    pub fn none() -> Self {
+        Self::NonFungibleAddresses(Vec::new())
+    }
+
+    /// Returns the non-fungibles to create the initial proofs of the auth zone from.
+    pub fn into_non_fungible_addresses(self) -> Vec<NonFungibleAddress> {
+        match self {
+            Self::PublicKeys(public_keys) => AuthModule::pk_non_fungibles(&public_keys),
+            Self::NonFungibleAddresses(non_fungible_addresses) => non_fungible_addresses,
+        }
+    }
+}
+
+/// Kept so that callers passing a list of badges directly still compile; prefer
+/// [`InitialProofs::from_non_fungible_addresses`] or [`InitialProofs::from_public_keys`].
+impl From<Vec<NonFungibleAddress>> for InitialProofs {
+    fn from(non_fungible_addresses: Vec<NonFungibleAddress>) -> Self {
+        Self::NonFungibleAddresses(non_fungible_addresses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use radix_engine_interface::crypto::EcdsaSecp256k1PublicKey;
+
+    #[test]
+    fn public_keys_map_to_their_virtual_signature_badges() {
+        let public_key: PublicKey = EcdsaSecp256k1PublicKey([1u8; 33]).into();
+        assert_eq!(
+            InitialProofs::from_public_keys(vec![public_key]).into_non_fungible_addresses(),
+            vec![virtual_signature_badge(&public_key)]
+        );
+    }
+
+    #[test]
+    fn none_has_no_proofs() {
+        assert!(InitialProofs::none()
+            .into_non_fungible_addresses()
+            .is_empty());
+    }
+}
@@ -10,6 +10,20 @@ use sbor::rust::collections::BTreeSet;
 use sbor::rust::vec::Vec;
 use sbor::*;
 
+/// A check of the worktop contents, which selects the branch of a conditional block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[scrypto(TypeId, Encode, Decode)]
+pub enum WorktopCondition {
+    /// The worktop contains some of the resource.
+    Contains { resource_address: ResourceAddress },
+
+    /// The worktop contains at least the given amount of the resource.
+    ContainsByAmount {
+        amount: Decimal,
+        resource_address: ResourceAddress,
+    },
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[scrypto(TypeId, Encode, Decode)]
 pub enum Instruction {
@@ -22,6 +36,13 @@ pub enum Instruction {
         resource_address: ResourceAddress,
     },
 
+    /// Takes resource from worktop by the given amount, or an empty bucket if the worktop has none
+    /// of the resource.
+    TakeFromWorktopOrDefault {
+        amount: Decimal,
+        resource_address: ResourceAddress,
+    },
+
     /// Takes resource from worktop by the given non-fungible IDs.
     TakeFromWorktopByIds {
         ids: BTreeSet<NonFungibleId>,
@@ -40,12 +61,30 @@ pub enum Instruction {
         resource_address: ResourceAddress,
     },
 
+    /// Asserts worktop contains resource by exactly the given amount.
+    AssertWorktopContainsExactly {
+        amount: Decimal,
+        resource_address: ResourceAddress,
+    },
+
     /// Asserts worktop contains resource by at least the given non-fungible IDs.
     AssertWorktopContainsByIds {
         ids: BTreeSet<NonFungibleId>,
         resource_address: ResourceAddress,
     },
 
+    /// Runs the `then_instructions` if the worktop satisfies the condition, or else the
+    /// `else_instructions`. Outputs whether the condition held.
+    ///
+    /// Buckets and proofs created in a branch don't outlive it: buckets are returned to the
+    /// worktop and proofs are dropped at the end of the branch. IDs are allocated as if both
+    /// branches ran, `then_instructions` first. Blocks can't be nested.
+    IfWorktopContains {
+        condition: WorktopCondition,
+        then_instructions: Vec<Instruction>,
+        else_instructions: Vec<Instruction>,
+    },
+
     /// Takes the last proof from the auth zone.
     PopFromAuthZone,
 
@@ -99,6 +138,38 @@ pub enum Instruction {
         args: Vec<u8>,
     },
 
+    /// Calls a scrypto method which returns a bucket, and keeps the bucket in the transaction
+    /// context rather than moving it to the worktop.
+    ///
+    /// Buckets and proofs in arguments moves from transaction context to the callee. Returned
+    /// proofs are moved into the auth zone.
+    CallMethodWithReturn {
+        method_ident: ScryptoMethodIdent,
+        args: Vec<u8>,
+    },
+
+    /// Calls a scrypto method which returns a proof, and keeps the proof in the transaction
+    /// context rather than pushing it onto the auth zone, so that it can be passed to later calls.
+    ///
+    /// Buckets and proofs in arguments moves from transaction context to the callee. Returned
+    /// buckets are moved into the worktop.
+    CallMethodWithProofReturn {
+        method_ident: ScryptoMethodIdent,
+        args: Vec<u8>,
+    },
+
+    /// Deposits everything on the worktop into an account, through its `deposit_batch` method.
+    ///
+    /// Fails without moving any resource if the component is not an account.
+    DepositEntireWorktop { account: ComponentAddress },
+
+    /// Sets a manifest local variable, which later instructions can read through the
+    /// `Local("name")` expression. Overwrites any previous value of the same name.
+    ///
+    /// The value must not contain buckets, proofs or expressions. Local variables are dropped at
+    /// the end of the transaction.
+    SetLocal { name: String, value: Vec<u8> },
+
     /// Calls a native function.
     ///
     /// Buckets and proofs in arguments moves from transaction context to the callee.
@@ -123,3 +194,52 @@ pub enum Instruction {
         owner_badge: NonFungibleAddress,
     },
 }
+
+impl Instruction {
+    /// The number of bucket and proof IDs the instruction allocates, counting every branch of a
+    /// conditional block.
+    pub fn id_allocation_count(&self) -> u32 {
+        match self {
+            Instruction::TakeFromWorktop { .. }
+            | Instruction::TakeFromWorktopByAmount { .. }
+            | Instruction::TakeFromWorktopOrDefault { .. }
+            | Instruction::TakeFromWorktopByIds { .. }
+            | Instruction::PopFromAuthZone
+            | Instruction::CreateProofFromAuthZone { .. }
+            | Instruction::CreateProofFromAuthZoneByAmount { .. }
+            | Instruction::CreateProofFromAuthZoneByIds { .. }
+            | Instruction::CreateProofFromBucket { .. }
+            | Instruction::CloneProof { .. }
+            | Instruction::CallMethodWithReturn { .. }
+            | Instruction::CallMethodWithProofReturn { .. } => 1,
+            Instruction::IfWorktopContains {
+                then_instructions,
+                else_instructions,
+                ..
+            } => then_instructions
+                .iter()
+                .chain(else_instructions.iter())
+                .map(Instruction::id_allocation_count)
+                .sum(),
+            _ => 0,
+        }
+    }
+
+    /// The number of instructions, counting those in the branches of a conditional block.
+    pub fn instruction_count(&self) -> usize {
+        match self {
+            Instruction::IfWorktopContains {
+                then_instructions,
+                else_instructions,
+                ..
+            } => {
+                1 + then_instructions
+                    .iter()
+                    .chain(else_instructions.iter())
+                    .map(Instruction::instruction_count)
+                    .sum::<usize>()
+            }
+            _ => 1,
+        }
+    }
+}
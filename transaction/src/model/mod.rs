@@ -1,5 +1,6 @@
 mod auth_module;
 mod executable;
+mod initial_proofs;
 mod instruction;
 mod manifest;
 mod notarized_transaction;
@@ -10,6 +11,7 @@ mod test_transaction;
 pub use self::notarized_transaction::*;
 pub use auth_module::*;
 pub use executable::*;
+pub use initial_proofs::*;
 pub use instruction::*;
 pub use manifest::*;
 pub use preview_transaction::*;
@@ -101,6 +101,10 @@ impl SignedTransactionIntent {
         scrypto_decode(slice)
     }
 
+    pub fn intent_hash(&self) -> Result<Hash, EncodeError> {
+        self.intent.hash()
+    }
+
     pub fn hash(&self) -> Result<Hash, EncodeError> {
         Ok(hash(self.to_bytes()?))
     }
@@ -115,6 +119,10 @@ impl NotarizedTransaction {
         scrypto_decode(slice)
     }
 
+    pub fn intent_hash(&self) -> Result<Hash, EncodeError> {
+        self.signed_intent.intent_hash()
+    }
+
     pub fn hash(&self) -> Result<Hash, EncodeError> {
         Ok(hash(self.to_bytes()?))
     }
@@ -44,6 +44,14 @@ impl IdAllocator {
         }
     }
 
+    /// Skips over IDs, as if they had been allocated.
+    pub fn skip(&mut self, count: u32) -> Result<(), IdAllocationError> {
+        for _ in 0..count {
+            self.next()?;
+        }
+        Ok(())
+    }
+
     fn next_id(&mut self, transaction_hash: Hash) -> Result<[u8; 36], IdAllocationError> {
         let mut buf = [0u8; 36];
         (&mut buf[0..32]).copy_from_slice(&transaction_hash.0);
@@ -99,6 +107,24 @@ impl IdAllocator {
         Ok(SystemAddress::Clock(hash(data).lower_26_bytes()))
     }
 
+    pub fn new_burner_address(
+        &mut self,
+        transaction_hash: Hash,
+    ) -> Result<SystemAddress, IdAllocationError> {
+        let mut data = transaction_hash.to_vec();
+        data.extend(self.next()?.to_le_bytes());
+        Ok(SystemAddress::Burner(hash(data).lower_26_bytes()))
+    }
+
+    pub fn new_time_locked_vault_address(
+        &mut self,
+        transaction_hash: Hash,
+    ) -> Result<SystemAddress, IdAllocationError> {
+        let mut data = transaction_hash.to_vec();
+        data.extend(self.next()?.to_le_bytes());
+        Ok(SystemAddress::TimeLockedVault(hash(data).lower_26_bytes()))
+    }
+
     /// Creates a new resource address.
     pub fn new_resource_address(
         &mut self,
@@ -179,3 +205,33 @@ impl IdAllocator {
         self.next_id(transaction_hash)
     }
 }
+
+/// Predicts the address the `nth_publication_in_tx` (zero-based) package publication of a
+/// transaction will be given, replicating the kernel's ID allocation.
+///
+/// Package addresses are derived from the transaction hash and the allocation counter only,
+/// so the prediction holds as long as no other node is allocated by the kernel before or
+/// between the publications, other than the root auth zone.
+pub fn predict_package_address(
+    transaction_hash: Hash,
+    nth_publication_in_tx: u32,
+) -> PackageAddress {
+    let mut id_allocator = IdAllocator::new(IdSpace::Application);
+    id_allocator
+        .new_auth_zone_id()
+        .expect("Failed to allocate auth zone ID");
+    for _ in 0..nth_publication_in_tx {
+        id_allocator
+            .new_package_id(transaction_hash)
+            .expect("Failed to allocate package ID");
+        id_allocator
+            .new_package_address(transaction_hash)
+            .expect("Failed to allocate package address");
+    }
+    id_allocator
+        .new_package_id(transaction_hash)
+        .expect("Failed to allocate package ID");
+    id_allocator
+        .new_package_address(transaction_hash)
+        .expect("Failed to allocate package address")
+}
@@ -13,8 +13,11 @@ pub enum ProofKind {
     BucketProof(BucketId),
     /// Proof taken or derived from auth zone.
     AuthZoneProof,
+    /// Proof returned by a called method.
+    ReturnedProof,
 }
 
+#[derive(Debug, Clone)]
 pub struct IdValidator {
     id_allocator: IdAllocator,
     bucket_ids: HashMap<BucketId, usize>,
@@ -61,7 +64,7 @@ impl IdValidator {
                     return Err(IdValidationError::BucketNotFound(*bucket_id));
                 }
             }
-            ProofKind::AuthZoneProof | ProofKind::VirtualProof => {}
+            ProofKind::AuthZoneProof | ProofKind::VirtualProof | ProofKind::ReturnedProof => {}
         }
 
         let proof_id = self
@@ -112,6 +115,38 @@ impl IdValidator {
         Ok(())
     }
 
+    /// Creates the validator for a branch of a conditional block, which starts from the IDs live
+    /// before the block. The branch allocates IDs after those of the `preceding` branch.
+    pub fn branch(&self, preceding: Option<&IdValidator>) -> Self {
+        let mut branch = self.clone();
+        if let Some(preceding) = preceding {
+            branch.id_allocator = preceding.id_allocator.clone();
+        }
+        branch
+    }
+
+    /// Ends a conditional block. Only the IDs from before the block which are still live at the
+    /// end of every branch can be used after it.
+    pub fn merge_branches(&mut self, branches: &[IdValidator]) {
+        if let Some(last) = branches.last() {
+            self.id_allocator = last.id_allocator.clone();
+        }
+        self.proof_ids
+            .retain(|proof_id, _| branches.iter().all(|b| b.proof_ids.contains_key(proof_id)));
+        self.bucket_ids.retain(|bucket_id, _| {
+            branches
+                .iter()
+                .all(|b| b.bucket_ids.contains_key(bucket_id))
+        });
+        for (bucket_id, cnt) in self.bucket_ids.iter_mut() {
+            *cnt = self
+                .proof_ids
+                .values()
+                .filter(|kind| **kind == ProofKind::BucketProof(*bucket_id))
+                .count();
+        }
+    }
+
     pub fn move_resources(&mut self, args: &IndexedScryptoValue) -> Result<(), IdValidationError> {
         for (bucket_id, _) in &args.bucket_ids {
             self.drop_bucket(*bucket_id)?;
@@ -1,9 +1,12 @@
 use radix_engine_constants::*;
+use radix_engine_interface::api::types::{NativeFunctionIdent, ResourceManagerFunction};
 use radix_engine_interface::constants::*;
 use radix_engine_interface::core::NetworkDefinition;
 use radix_engine_interface::crypto::{Hash, PublicKey};
 use radix_engine_interface::data::*;
+use radix_engine_interface::model::*;
 use sbor::rust::collections::{BTreeSet, HashSet};
+use sbor::rust::str::FromStr;
 
 use crate::errors::{SignatureValidationError, *};
 use crate::model::*;
@@ -41,6 +44,7 @@ pub struct ValidationConfig {
     pub min_tip_percentage: u8,
     pub max_tip_percentage: u8,
     pub max_epoch_range: u64,
+    pub max_instructions_per_transaction: usize,
 }
 
 impl ValidationConfig {
@@ -52,6 +56,7 @@ impl ValidationConfig {
             min_tip_percentage: DEFAULT_MIN_TIP_PERCENTAGE,
             max_tip_percentage: DEFAULT_MAX_TIP_PERCENTAGE,
             max_epoch_range: DEFAULT_MAX_EPOCH_RANGE,
+            max_instructions_per_transaction: DEFAULT_MAX_INSTRUCTIONS_PER_TRANSACTION,
         }
     }
 
@@ -184,17 +189,45 @@ impl NotarizedTransactionValidator {
         self.validate_header(&intent)
             .map_err(TransactionValidationError::HeaderValidationError)?;
 
+        Self::validate_instruction_count(
+            &intent.manifest.instructions,
+            self.config.max_instructions_per_transaction,
+        )?;
+
         Self::validate_manifest(&intent.manifest)?;
 
         return Ok(());
     }
 
+    /// Rejects a manifest with more than `max` instructions, before any instruction is looked at.
+    pub fn validate_instruction_count(
+        instructions: &[Instruction],
+        max: usize,
+    ) -> Result<(), TransactionValidationError> {
+        let count = instructions
+            .iter()
+            .map(Instruction::instruction_count)
+            .sum::<usize>();
+        if count > max {
+            return Err(TransactionValidationError::TooManyInstructions { count, limit: max });
+        }
+        Ok(())
+    }
+
     pub fn validate_manifest(
         manifest: &TransactionManifest,
     ) -> Result<(), TransactionValidationError> {
         // semantic analysis
         let mut id_validator = IdValidator::new();
-        for inst in &manifest.instructions {
+        Self::validate_instructions(&manifest.instructions, &mut id_validator, false)
+    }
+
+    fn validate_instructions(
+        instructions: &[Instruction],
+        id_validator: &mut IdValidator,
+        in_block: bool,
+    ) -> Result<(), TransactionValidationError> {
+        for inst in instructions {
             match inst {
                 Instruction::TakeFromWorktop { .. } => {
                     id_validator
@@ -206,6 +239,11 @@ impl NotarizedTransactionValidator {
                         .new_bucket()
                         .map_err(TransactionValidationError::IdValidationError)?;
                 }
+                Instruction::TakeFromWorktopOrDefault { .. } => {
+                    id_validator
+                        .new_bucket()
+                        .map_err(TransactionValidationError::IdValidationError)?;
+                }
                 Instruction::TakeFromWorktopByIds { .. } => {
                     id_validator
                         .new_bucket()
@@ -218,7 +256,22 @@ impl NotarizedTransactionValidator {
                 }
                 Instruction::AssertWorktopContains { .. } => {}
                 Instruction::AssertWorktopContainsByAmount { .. } => {}
+                Instruction::AssertWorktopContainsExactly { .. } => {}
                 Instruction::AssertWorktopContainsByIds { .. } => {}
+                Instruction::IfWorktopContains {
+                    then_instructions,
+                    else_instructions,
+                    ..
+                } => {
+                    if in_block {
+                        return Err(TransactionValidationError::NestedConditionalBlock);
+                    }
+                    let mut then_validator = id_validator.branch(None);
+                    Self::validate_instructions(then_instructions, &mut then_validator, true)?;
+                    let mut else_validator = id_validator.branch(Some(&then_validator));
+                    Self::validate_instructions(else_instructions, &mut else_validator, true)?;
+                    id_validator.merge_branches(&[then_validator, else_validator]);
+                }
                 Instruction::PopFromAuthZone => {
                     id_validator
                         .new_proof(ProofKind::AuthZoneProof)
@@ -265,15 +318,41 @@ impl NotarizedTransactionValidator {
                         .drop_all_proofs()
                         .map_err(TransactionValidationError::IdValidationError)?;
                 }
+                Instruction::CallNativeFunction {
+                    function_ident,
+                    args,
+                } => {
+                    Self::validate_call_data(&args, id_validator)
+                        .and_then(|_| Self::validate_native_function_args(function_ident, args))
+                        .map_err(TransactionValidationError::CallDataValidationError)?;
+                }
                 Instruction::CallFunction { args, .. }
                 | Instruction::CallMethod { args, .. }
-                | Instruction::CallNativeFunction { args, .. }
                 | Instruction::CallNativeMethod { args, .. } => {
                     // TODO: decode into Value
-                    Self::validate_call_data(&args, &mut id_validator)
+                    Self::validate_call_data(&args, id_validator)
+                        .map_err(TransactionValidationError::CallDataValidationError)?;
+                }
+                Instruction::CallMethodWithReturn { args, .. } => {
+                    Self::validate_call_data(&args, id_validator)
+                        .map_err(TransactionValidationError::CallDataValidationError)?;
+                    id_validator
+                        .new_bucket()
+                        .map_err(TransactionValidationError::IdValidationError)?;
+                }
+                Instruction::CallMethodWithProofReturn { args, .. } => {
+                    Self::validate_call_data(&args, id_validator)
+                        .map_err(TransactionValidationError::CallDataValidationError)?;
+                    id_validator
+                        .new_proof(ProofKind::ReturnedProof)
+                        .map_err(TransactionValidationError::IdValidationError)?;
+                }
+                Instruction::SetLocal { value, .. } => {
+                    Self::validate_local_value(&value)
                         .map_err(TransactionValidationError::CallDataValidationError)?;
                 }
-                Instruction::PublishPackageWithOwner { .. } => {}
+                Instruction::DepositEntireWorktop { .. }
+                | Instruction::PublishPackageWithOwner { .. } => {}
             }
         }
 
@@ -379,11 +458,66 @@ impl NotarizedTransactionValidator {
         }
         Ok(())
     }
+
+    /// Rejects resource metadata the engine would reject, before the transaction is executed.
+    /// Arguments which don't decode are left to the engine.
+    pub fn validate_native_function_args(
+        function_ident: &NativeFunctionIdent,
+        args: &[u8],
+    ) -> Result<(), CallDataValidationError> {
+        if function_ident.blueprint_name != "ResourceManager" {
+            return Ok(());
+        }
+        let metadata = match ResourceManagerFunction::from_str(&function_ident.function_name) {
+            Ok(ResourceManagerFunction::Create) => {
+                scrypto_decode::<ResourceManagerCreateInvocation>(args).map(|i| i.metadata)
+            }
+            Ok(ResourceManagerFunction::CreateWithOwner) => {
+                scrypto_decode::<ResourceManagerCreateWithOwnerInvocation>(args).map(|i| i.metadata)
+            }
+            _ => return Ok(()),
+        };
+        if let Ok(metadata) = metadata {
+            validate_resource_metadata(&metadata).map_err(|(key, reason)| {
+                CallDataValidationError::InvalidResourceMetadata { key, reason }
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Local variables outlive the instruction which sets them, so they may only hold plain data.
+    pub fn validate_local_value(value: &[u8]) -> Result<(), CallDataValidationError> {
+        let value = IndexedScryptoValue::from_slice(value)
+            .map_err(CallDataValidationError::InvalidScryptoValue)?;
+        if let Some(bucket_id) = value.bucket_ids.keys().nth(0) {
+            return Err(CallDataValidationError::BucketNotAllowed(*bucket_id));
+        }
+        if let Some(proof_id) = value.proof_ids.keys().nth(0) {
+            return Err(CallDataValidationError::ProofNotAllowed(*proof_id));
+        }
+        if let Some((expression, _)) = value.expressions.iter().nth(0) {
+            return Err(CallDataValidationError::ExpressionNotAllowed(
+                expression.clone(),
+            ));
+        }
+        if let Some(vault_id) = value.vault_ids.iter().nth(0) {
+            return Err(CallDataValidationError::VaultNotAllowed(vault_id.clone()));
+        }
+        if let Some(kv_store_id) = value.kv_store_ids.iter().nth(0) {
+            return Err(CallDataValidationError::KeyValueStoreNotAllowed(
+                kv_store_id.clone(),
+            ));
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use radix_engine_interface::core::NetworkDefinition;
+    use radix_engine_interface::core::{Expression, NetworkDefinition};
+    use radix_engine_interface::math::Decimal;
+    use radix_engine_interface::model::Bucket;
+    use sbor::rust::collections::HashMap;
 
     use super::*;
     use crate::{
@@ -450,6 +584,137 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_instruction_count_limit() {
+        let instructions = vec![Instruction::ClearAuthZone; 3];
+        assert_eq!(
+            NotarizedTransactionValidator::validate_instruction_count(&instructions, 3),
+            Ok(())
+        );
+        assert_eq!(
+            NotarizedTransactionValidator::validate_instruction_count(&instructions, 2),
+            Err(TransactionValidationError::TooManyInstructions { count: 3, limit: 2 })
+        );
+    }
+
+    #[test]
+    fn test_conditional_block_ids_are_scoped() {
+        let validate = |instructions: Vec<Instruction>| {
+            NotarizedTransactionValidator::validate_manifest(&TransactionManifest {
+                instructions,
+                blobs: vec![],
+            })
+        };
+        let take = Instruction::TakeFromWorktop {
+            resource_address: RADIX_TOKEN,
+        };
+        let block = |then_instructions, else_instructions| Instruction::IfWorktopContains {
+            condition: WorktopCondition::Contains {
+                resource_address: RADIX_TOKEN,
+            },
+            then_instructions,
+            else_instructions,
+        };
+
+        // IDs are allocated as if both branches ran, and don't outlive the block
+        assert_eq!(
+            validate(vec![
+                block(vec![take.clone()], vec![take.clone()]),
+                take.clone(),
+                Instruction::ReturnToWorktop { bucket_id: 514 },
+            ]),
+            Ok(())
+        );
+        assert_eq!(
+            validate(vec![
+                block(vec![take.clone()], vec![take.clone()]),
+                Instruction::ReturnToWorktop { bucket_id: 513 },
+            ]),
+            Err(TransactionValidationError::IdValidationError(
+                IdValidationError::BucketNotFound(513)
+            ))
+        );
+        // A bucket from before the block can be used after it, unless a branch consumed it
+        assert_eq!(
+            validate(vec![
+                take.clone(),
+                block(vec![], vec![]),
+                Instruction::ReturnToWorktop { bucket_id: 512 },
+            ]),
+            Ok(())
+        );
+        assert_eq!(
+            validate(vec![
+                take.clone(),
+                block(
+                    vec![Instruction::ReturnToWorktop { bucket_id: 512 }],
+                    vec![]
+                ),
+                Instruction::ReturnToWorktop { bucket_id: 512 },
+            ]),
+            Err(TransactionValidationError::IdValidationError(
+                IdValidationError::BucketNotFound(512)
+            ))
+        );
+        assert_eq!(
+            validate(vec![block(vec![block(vec![], vec![])], vec![])]),
+            Err(TransactionValidationError::NestedConditionalBlock)
+        );
+    }
+
+    #[test]
+    fn test_resource_metadata_is_validated() {
+        let validate = |metadata: &[(&str, &str)]| {
+            let manifest = ManifestBuilder::new(&NetworkDefinition::simulator())
+                .create_resource(
+                    ResourceType::Fungible { divisibility: 18 },
+                    metadata
+                        .iter()
+                        .map(|(k, v)| (k.to_string(), v.to_string()))
+                        .collect(),
+                    HashMap::<_, (_, AccessRule)>::new(),
+                    None,
+                )
+                .build();
+            NotarizedTransactionValidator::validate_manifest(&manifest)
+        };
+
+        assert_eq!(validate(&[("name", "Token"), ("symbol", "TT")]), Ok(()));
+        assert_eq!(
+            validate(&[("symbol", "tt")]),
+            Err(TransactionValidationError::CallDataValidationError(
+                CallDataValidationError::InvalidResourceMetadata {
+                    key: "symbol".to_string(),
+                    reason: InvalidMetadataReason::InvalidSymbol,
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_local_value_must_be_plain_data() {
+        assert_eq!(
+            NotarizedTransactionValidator::validate_local_value(
+                &scrypto_encode(&Decimal::ONE).unwrap()
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            NotarizedTransactionValidator::validate_local_value(
+                &scrypto_encode(&Bucket(1)).unwrap()
+            ),
+            Err(CallDataValidationError::BucketNotAllowed(1))
+        );
+        assert_eq!(
+            NotarizedTransactionValidator::validate_local_value(
+                &scrypto_encode(&Expression::entire_worktop()).unwrap()
+            ),
+            Err(CallDataValidationError::ExpressionNotAllowed(
+                Expression::entire_worktop()
+            ))
+        );
+    }
+
     #[test]
     fn test_valid_preview() {
         let mut intent_hash_manager: TestIntentHashManager = TestIntentHashManager::new();